@@ -16,3 +16,15 @@ pub enum CoralDiagonal {
     UnitDiagonal, 
     NonUnitDiagonal,
 }
+
+/// How the compensated-capable kernels accumulate.
+///
+/// - `Fast`        : plain working-precision sums, the default everywhere.
+/// - `Compensated` : error-free transformations (`Dot2`), as if in twice
+///   the working precision.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Accuracy {
+    #[default]
+    Fast,
+    Compensated,
+}
@@ -0,0 +1,244 @@
+//! Argument validation and error reporting.
+//!
+//! Every public routine checks its arguments before it touches memory, in
+//! release builds too. A zero increment to a level2 routine, a leading
+//! dimension smaller than the matrix it describes, or a slice shorter than
+//! its dimensions and increment require is reported as a [`CoralError`] to
+//! the error handler, and the routine returns without modifying its
+//! outputs. Routines that return a value return zero.
+//!
+//! Parameters are numbered from 1 in the order of the routine's signature,
+//! as reference BLAS `xerbla` does; for every routine with a reference
+//! counterpart the numbering is the same. Leading dimensions and increments
+//! are checked first, in parameter order, then slice lengths.
+//!
+//! The default handler, [`panic_handler`], panics with the error message.
+//! Install another with [`set_error_handler`] to log and continue instead.
+//!
+//! # Notes
+//! - Level1 routines take a zero increment as reference BLAS does: the
+//!   vector is its first entry repeated. Those documented to quick return
+//!   on it, such as `isamax` and `snrm2`, still do so.
+//! - The raw-pointer level3 routines can only check leading dimensions;
+//!   the wrappers in [`crate::level3::slice`] check lengths too.
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::errors::{set_error_handler, CoralError};
+//!
+//! fn log(e: &CoralError) {
+//!     eprintln!("{e}");
+//! }
+//!
+//! fn main() {
+//!     let previous = set_error_handler(log);
+//!
+//!     // ... report and continue ...
+//!
+//!     set_error_handler(previous);
+//! }
+//! ```
+
+use std::sync::RwLock;
+use core::fmt;
+
+/// an invalid argument to a coral routine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoralError {
+    /// a level2 vector increment is zero.
+    ZeroIncrement {
+        routine : &'static str,
+        param   : usize,
+    },
+    /// a leading dimension is smaller than `max(1, rows)`.
+    LeadingDimension {
+        routine : &'static str,
+        param   : usize,
+        ld      : usize,
+        min     : usize,
+    },
+    /// a slice is shorter than its dimensions and increment or leading
+    /// dimension require.
+    BufferTooShort {
+        routine  : &'static str,
+        param    : usize,
+        len      : usize,
+        required : usize,
+    },
+}
+
+impl CoralError {
+    /// name of the routine that rejected its arguments.
+    pub fn routine(&self) -> &'static str {
+        match *self {
+            CoralError::ZeroIncrement    { routine, .. } => routine,
+            CoralError::LeadingDimension { routine, .. } => routine,
+            CoralError::BufferTooShort   { routine, .. } => routine,
+        }
+    }
+
+    /// 1-based position of the invalid parameter.
+    pub fn param(&self) -> usize {
+        match *self {
+            CoralError::ZeroIncrement    { param, .. } => param,
+            CoralError::LeadingDimension { param, .. } => param,
+            CoralError::BufferTooShort   { param, .. } => param,
+        }
+    }
+}
+
+impl fmt::Display for CoralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parameter {} to {} is invalid: ", self.param(), self.routine())?;
+
+        match *self {
+            CoralError::ZeroIncrement { .. } => {
+                write!(f, "increment is zero")
+            }
+            CoralError::LeadingDimension { ld, min, .. } => {
+                write!(f, "leading dimension {ld} is smaller than {min}")
+            }
+            CoralError::BufferTooShort { len, required, .. } => {
+                write!(f, "slice has length {len}, needs {required}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CoralError {}
+
+/// called with every [`CoralError`] before the routine returns.
+pub type ErrorHandler = fn(&CoralError);
+
+static HANDLER: RwLock<ErrorHandler> = RwLock::new(panic_handler);
+
+/// the default handler. Panics with the error message.
+pub fn panic_handler(e: &CoralError) {
+    panic!("coral: {e}");
+}
+
+/// installs `handler` for every thread and returns the previous one.
+pub fn set_error_handler(handler: ErrorHandler) -> ErrorHandler {
+    let mut h = HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    core::mem::replace(&mut *h, handler)
+}
+
+#[cold]
+#[inline(never)]
+fn report(e: &CoralError) {
+    let handler = *HANDLER.read().unwrap_or_else(|e| e.into_inner());
+    handler(e);
+}
+
+/// first failing argument check of a routine.
+///
+/// checks are chained in parameter order and the first failure is kept;
+/// [`Check::ok`] reports it.
+#[must_use]
+pub(crate) struct Check {
+    routine : &'static str,
+    err     : Option<CoralError>,
+}
+
+impl Check {
+    #[inline(always)]
+    pub(crate) fn new(routine: &'static str) -> Self {
+        Check { routine, err: None }
+    }
+
+    #[inline(always)]
+    fn fail(mut self, err: CoralError) -> Self {
+        if self.err.is_none() {
+            self.err = Some(err);
+        }
+        self
+    }
+
+    /// `inc` is nonzero. Level2 only; level1 allows a zero increment.
+    #[inline(always)]
+    pub(crate) fn inc(self, param: usize, inc: isize) -> Self {
+        if inc == 0 {
+            let routine = self.routine;
+            return self.fail(CoralError::ZeroIncrement { routine, param });
+        }
+        self
+    }
+
+    /// `ld >= max(1, rows)`.
+    #[inline(always)]
+    pub(crate) fn ld(self, param: usize, ld: usize, rows: usize) -> Self {
+        let min = rows.max(1);
+
+        if ld < min {
+            let routine = self.routine;
+            return self.fail(CoralError::LeadingDimension { routine, param, ld, min });
+        }
+        self
+    }
+
+    #[inline(always)]
+    fn len(self, param: usize, len: usize, required: usize) -> Self {
+        if len < required {
+            let routine = self.routine;
+            return self.fail(CoralError::BufferTooShort { routine, param, len, required });
+        }
+        self
+    }
+
+    /// real vector of `n` elements at stride `|inc|`.
+    #[inline(always)]
+    pub(crate) fn vec(self, param: usize, len: usize, n: usize, inc: isize) -> Self {
+        self.len(param, len, vec_len(n, inc, 1))
+    }
+
+    /// complex vector of `n` interleaved elements at stride `|inc|`.
+    #[inline(always)]
+    pub(crate) fn cvec(self, param: usize, len: usize, n: usize, inc: isize) -> Self {
+        self.len(param, len, vec_len(n, inc, 2))
+    }
+
+    /// real column-major `rows x cols` matrix with leading dimension `ld`.
+    #[inline(always)]
+    pub(crate) fn mat(self, param: usize, len: usize, rows: usize, cols: usize, ld: usize) -> Self {
+        self.len(param, len, mat_len(rows, cols, ld, 1))
+    }
+
+    /// complex column-major `rows x cols` matrix with leading dimension `ld`.
+    #[inline(always)]
+    pub(crate) fn cmat(self, param: usize, len: usize, rows: usize, cols: usize, ld: usize) -> Self {
+        self.len(param, len, mat_len(rows, cols, ld, 2))
+    }
+
+    /// `true` if every check passed; otherwise reports the first failure
+    /// to the error handler and returns `false`.
+    #[inline(always)]
+    pub(crate) fn ok(self) -> bool {
+        match self.err {
+            None    => true,
+            Some(e) => {
+                report(&e);
+                false
+            }
+        }
+    }
+}
+
+/// scalars spanned by `n` elements of `w` scalars at stride `|inc|`.
+#[inline(always)]
+fn vec_len(n: usize, inc: isize, w: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        w.saturating_mul((n - 1).saturating_mul(inc.unsigned_abs()).saturating_add(1))
+    }
+}
+
+/// scalars spanned by a column-major `rows x cols` matrix, `w` per entry.
+#[inline(always)]
+fn mat_len(rows: usize, cols: usize, ld: usize, w: usize) -> usize {
+    if rows == 0 || cols == 0 {
+        0
+    } else {
+        w.saturating_mul((cols - 1).saturating_mul(ld).saturating_add(rows))
+    }
+}
@@ -6,9 +6,9 @@
 //! # Arguments 
 //! - `n`    (usize)      : Number of complex elements to copy.
 //! - `x`    (&[f32])     : Input slice containing interleaved complex vector elements.
-//! - `incx` (usize)      : Stride between consecutive complex elements of $x$; complex units.
+//! - `incx` (isize)      : Stride between consecutive complex elements of $x$; complex units.
 //! - `y`    (&mut [f32]) : Output slice to receive copied complex elements.
-//! - `incy` (usize)      : Stride between consecutive complex elements of $y$; complex units.
+//! - `incy` (isize)      : Stride between consecutive complex elements of $y$; complex units.
 //! 
 //! # Returns
 //! - Nothing. The contents of $y$ are overwritten with elements from $x$.
@@ -18,27 +18,52 @@
 //!   contiguous memory copying of real and imag parts.
 //! - For non unit or negative strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns immediately without modifying $y$.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
 
 
-use crate::level1::assert_length_helpers::required_len_ok_cplx; 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 #[inline(always)]
 pub fn ccopy(
     n       : usize, 
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     y       : &mut [f32],
-    incy    : usize
+    incy    : isize
 ) {
     // quick return 
     if n == 0 { return; }
 
-    debug_assert!(incx != 0 && incy != 0, "BLAS increments must be non-zero");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx (complex)");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y too short for n/incy (complex)");
+    if !Check::new("ccopy")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 2);
+        ccopy(n, &xs, 1, y, incy);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        ccopy(n, x, incx, &mut ys, 1);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     unsafe {
         if incx == 1 && incy == 1 {
@@ -0,0 +1,198 @@
+//! Internal helpers for the compensated (`*_accurate`) routines.
+//!
+//! Error-free transformations and the cascaded algorithms of Ogita, Rump and
+//! Oishi, "Accurate Sum and Dot Product" (SIAM J. Sci. Comput., 2005).
+//!
+//! - `two_sum_*`  : `a + b = s + e` exactly, with `s = fl(a + b)`.
+//! - `two_prod_*` : `a * b = p + e` exactly, with `p = fl(a * b)`, via FMA.
+//! - `dot2_*`     : `Dot2`, the dot product in twice the working precision.
+//! - `sum2_abs_*` : `Sum2` over `|x_i|`.
+//! - `ssq2_*`     : `Sum2` over `x_i^2` after an exact power-of-two scaling.
+//!
+//! Vectors are walked forward at stride `inc`; callers deal with signs.
+//! Each routine returns the unevaluated pair `(hi, lo)`; `hi + lo` rounded
+//! once is the result.
+
+
+#[inline(always)]
+pub(crate) fn two_sum_f32(a: f32, b: f32) -> (f32, f32) {
+    let s  = a + b;
+    let bb = s - a;
+
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[inline(always)]
+pub(crate) fn two_sum_f64(a: f64, b: f64) -> (f64, f64) {
+    let s  = a + b;
+    let bb = s - a;
+
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[inline(always)]
+pub(crate) fn two_prod_f32(a: f32, b: f32) -> (f32, f32) {
+    let p = a * b;
+
+    (p, a.mul_add(b, -p))
+}
+
+#[inline(always)]
+pub(crate) fn two_prod_f64(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+
+    (p, a.mul_add(b, -p))
+}
+
+/// `Dot2` over `n` pairs, `x` at stride `incx` and `y` at stride `incy`.
+#[inline]
+pub(crate) fn dot2_f32(n: usize, x: &[f32], incx: usize, y: &[f32], incy: usize) -> (f32, f32) {
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let (p, e) = two_prod_f32(x[i * incx], y[i * incy]);
+        let (t, q) = two_sum_f32(s, p);
+
+        s  = t;
+        c += q + e;
+    }
+
+    (s, c)
+}
+
+/// `Dot2` over `n` pairs, `x` at stride `incx` and `y` at stride `incy`.
+#[inline]
+pub(crate) fn dot2_f64(n: usize, x: &[f64], incx: usize, y: &[f64], incy: usize) -> (f64, f64) {
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let (p, e) = two_prod_f64(x[i * incx], y[i * incy]);
+        let (t, q) = two_sum_f64(s, p);
+
+        s  = t;
+        c += q + e;
+    }
+
+    (s, c)
+}
+
+/// `Sum2` over `|x_i|`.
+#[inline]
+pub(crate) fn sum2_abs_f32(n: usize, x: &[f32], incx: usize) -> (f32, f32) {
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let (t, q) = two_sum_f32(s, x[i * incx].abs());
+
+        s  = t;
+        c += q;
+    }
+
+    (s, c)
+}
+
+/// `Sum2` over `|x_i|`.
+#[inline]
+pub(crate) fn sum2_abs_f64(n: usize, x: &[f64], incx: usize) -> (f64, f64) {
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let (t, q) = two_sum_f64(s, x[i * incx].abs());
+
+        s  = t;
+        c += q;
+    }
+
+    (s, c)
+}
+
+/// `Sum2` over `(x_i / scale)^2` with `scale` the power of two at or above
+/// `max |x_i|`, so the scaling itself is exact. Returns `(scale, hi, lo)`.
+#[inline]
+pub(crate) fn ssq2_f32(n: usize, x: &[f32], incx: usize) -> (f32, f32, f32) {
+    // `max` drops a NaN, which must reach the result
+    let mut amax: f32 = 0.0;
+    for i in 0..n {
+        let v = x[i * incx];
+        amax = if amax.is_nan() || v.is_nan() { f32::NAN } else { amax.max(v.abs()) };
+    }
+
+    if amax == 0.0 || !amax.is_finite() {
+        return (amax, 1.0, 0.0);
+    }
+
+    // 2^k >= amax; the reciprocal is exact while 2^-k is normal
+    let scale = pow2_above_f32(amax);
+    let inv   = 1.0 / scale;
+
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let v = x[i * incx] * inv;
+        let (p, e) = two_prod_f32(v, v);
+        let (t, q) = two_sum_f32(s, p);
+
+        s  = t;
+        c += q + e;
+    }
+
+    (scale, s, c)
+}
+
+/// `Sum2` over `(x_i / scale)^2` with `scale` the power of two at or above
+/// `max |x_i|`, so the scaling itself is exact. Returns `(scale, hi, lo)`.
+#[inline]
+pub(crate) fn ssq2_f64(n: usize, x: &[f64], incx: usize) -> (f64, f64, f64) {
+    // `max` drops a NaN, which must reach the result
+    let mut amax: f64 = 0.0;
+    for i in 0..n {
+        let v = x[i * incx];
+        amax = if amax.is_nan() || v.is_nan() { f64::NAN } else { amax.max(v.abs()) };
+    }
+
+    if amax == 0.0 || !amax.is_finite() {
+        return (amax, 1.0, 0.0);
+    }
+
+    let scale = pow2_above_f64(amax);
+    let inv   = 1.0 / scale;
+
+    let mut s = 0.0;
+    let mut c = 0.0;
+
+    for i in 0..n {
+        let v = x[i * incx] * inv;
+        let (p, e) = two_prod_f64(v, v);
+        let (t, q) = two_sum_f64(s, p);
+
+        s  = t;
+        c += q + e;
+    }
+
+    (scale, s, c)
+}
+
+/// smallest power of two `>= a` for finite `a > 0`, capped at `2^126` so its
+/// reciprocal stays normal.
+#[inline(always)]
+fn pow2_above_f32(a: f32) -> f32 {
+    let e = ((a.to_bits() >> 23) & 0xff) as i32 - 127;
+    let k = (e + 1).clamp(-126, 126);
+
+    f32::from_bits(((k + 127) as u32) << 23)
+}
+
+/// smallest power of two `>= a` for finite `a > 0`, capped at `2^1022` so its
+/// reciprocal stays normal.
+#[inline(always)]
+fn pow2_above_f64(a: f64) -> f64 {
+    let e = ((a.to_bits() >> 52) & 0x7ff) as i64 - 1023;
+    let k = (e + 1).clamp(-1022, 1022);
+
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
@@ -0,0 +1,196 @@
+//! `ROT`. Applies a plane rotation with a real cosine and a complex sine to
+//! two complex single precision vectors.
+//!
+//! \\[
+//! x_i' = c x_i + s y_i
+//! \\]
+//! \\[
+//! y_i' = c y_i - \bar{s} x_i
+//! \\]
+//!
+//! This function implements the LAPACK [`crot`] routine, the companion of
+//! [`crotg`](fn@crate::level1::crotg), replacing elements of vectors $x$ and
+//! $y$ over $n$ complex entries with specified strides.
+//!
+//! # Arguments
+//! - `n`    (usize)      : Number of complex elements to process.
+//! - `x`    (&mut [f32]) : Input/output slice containing interleaved complex vector elements.
+//! - `incx` (isize)      : Stride between consecutive complex elements of $x$; complex units.
+//! - `y`    (&mut [f32]) : Input/output slice containing interleaved complex vector elements.
+//! - `incy` (isize)      : Stride between consecutive complex elements of $y$; complex units.
+//! - `c`    (f32)        : Real cosine component of the rotation.
+//! - `s`    ([f32; 2])   : Complex sine component of the rotation, given as `[real, imag]`.
+//!
+//! # Returns
+//! - Nothing. The contents of $x$ and $y$ are updated in place.
+//!
+//! # Notes
+//! - For `incx == 1 && incy == 1`, [`crot`] deinterleaves four complex entries
+//!   at a time with NEON `ld2`/`st2` for optimized performance on AArch64.
+//! - For non unit strides, the function falls back to a scalar loop.
+//! - If `n == 0`, the function returns immediately.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//!
+//! # Author
+//! Deval Deliwala
+
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::{
+    float32x4_t,
+    float32x4x2_t,
+    vld2q_f32,
+    vst2q_f32,
+    vdupq_n_f32,
+    vmulq_f32,
+    vfmaq_f32,
+    vfmsq_f32,
+};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn crot(
+    n       : usize,
+    x       : &mut [f32],
+    incx    : isize,
+    y       : &mut [f32],
+    incy    : isize,
+    c       : f32,
+    s       : [f32; 2]
+) {
+    // quick return
+    if n == 0 { return; }
+
+    if !Check::new("crot")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 2);
+        crot(n, &mut xs, 1, y, incy, c, s);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        crot(n, x, incx, &mut ys, 1, c, s);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
+
+    let [sr, si] = s;
+
+    let px = x.as_mut_ptr();
+    let py = y.as_mut_ptr();
+
+    // fast path
+    if incx == 1 && incy == 1 {
+        unsafe {
+            let cv  = vdupq_n_f32(c);
+            let srv = vdupq_n_f32(sr);
+            let siv = vdupq_n_f32(si);
+
+            let mut i = 0;
+            while i + 8 <= n {
+                rot4(px.add(2 * i),     py.add(2 * i),     cv, srv, siv);
+                rot4(px.add(2 * i + 8), py.add(2 * i + 8), cv, srv, siv);
+
+                i += 8;
+            }
+
+            while i + 4 <= n {
+                rot4(px.add(2 * i), py.add(2 * i), cv, srv, siv);
+
+                i += 4;
+            }
+
+            // tail
+            while i < n {
+                rot1(px.add(2 * i), py.add(2 * i), c, sr, si);
+
+                i += 1;
+            }
+        }
+        return;
+    }
+
+    // non unit stride
+    unsafe {
+        let mut ix = 0;
+        let mut iy = 0;
+
+        for _ in 0..n {
+            rot1(px.add(2 * ix), py.add(2 * iy), c, sr, si);
+
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
+
+/// rotates four contiguous complex entries of `x` and `y`.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn rot4(
+    px  : *mut f32,
+    py  : *mut f32,
+    c   : float32x4_t,
+    sr  : float32x4_t,
+    si  : float32x4_t,
+) {
+    unsafe {
+        let xv = vld2q_f32(px);
+        let yv = vld2q_f32(py);
+
+        let (xr, xi) = (xv.0, xv.1);
+        let (yr, yi) = (yv.0, yv.1);
+
+        // x' = c x + s y
+        let xnr = vfmsq_f32(vfmaq_f32(vmulq_f32(c, xr), sr, yr), si, yi);
+        let xni = vfmaq_f32(vfmaq_f32(vmulq_f32(c, xi), sr, yi), si, yr);
+
+        // y' = c y - conj(s) x
+        let ynr = vfmsq_f32(vfmsq_f32(vmulq_f32(c, yr), sr, xr), si, xi);
+        let yni = vfmaq_f32(vfmsq_f32(vmulq_f32(c, yi), sr, xi), si, xr);
+
+        vst2q_f32(px, float32x4x2_t(xnr, xni));
+        vst2q_f32(py, float32x4x2_t(ynr, yni));
+    }
+}
+
+/// rotates one complex entry of `x` and `y`.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn rot1(
+    px  : *mut f32,
+    py  : *mut f32,
+    c   : f32,
+    sr  : f32,
+    si  : f32,
+) {
+    unsafe {
+        let xr = *px;
+        let xi = *px.add(1);
+        let yr = *py;
+        let yi = *py.add(1);
+
+        *px        = c * xr + (sr * yr - si * yi);
+        *px.add(1) = c * xi + (sr * yi + si * yr);
+        *py        = c * yr - (sr * xr + si * xi);
+        *py.add(1) = c * yi - (sr * xi - si * xr);
+    }
+}
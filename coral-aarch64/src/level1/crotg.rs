@@ -0,0 +1,179 @@
+//! `ROTG`. Constructs a Givens rotation for single precision complex scalars.
+//!
+//! This function implements the BLAS [`crotg`] routine, computing a real
+//! cosine $c$ and a complex sine $s$ such that
+//!
+//! \\[
+//! \begin{bmatrix}
+//! c & s \\\\ -\bar{s} & c
+//! \end{bmatrix} \begin{bmatrix}
+//! a \\\\ b
+//! \end{bmatrix} = \begin{bmatrix}
+//! r \\\\ 0
+//! \end{bmatrix}
+//! \\]
+//!
+//! with $c^2 + |s|^2 = 1$. The value of $a$ is overwritten with $r$.
+//!
+//! # Arguments
+//! - `a` (&mut [f32; 2]) : Input complex scalar `[real, imag]`, overwritten with $r$.
+//! - `b` ([f32; 2])      : Input complex scalar `[real, imag]` to be zeroed.
+//! - `c` (&mut f32)      : Output real cosine component of the rotation.
+//! - `s` (&mut [f32; 2]) : Output complex sine component of the rotation.
+//!
+//! # Returns
+//! - Nothing. The results are written in place to `a`, `c`, and `s`.
+//!
+//! # Notes
+//! - Follows reference BLAS 3.10 (Anderson, "Algorithm 978: Safe Scaling in
+//!   the Level 1 BLAS", ACM TOMS, 2017). $a$ and $b$ are used unscaled when
+//!   their components lie in $(\sqrt{\text{safmin}}, \sqrt{\text{safmax}/4})$,
+//!   and are scaled into that range otherwise, so no intermediate overflows
+//!   or underflows.
+//! - If `b == 0`, the routine sets `c = 1.0`, `s = 0` and leaves `a` as is.
+//! - If `a == 0` and `b != 0`, the routine sets `c = 0.0`, `s = conj(b) / |b|`
+//!   and `a = |b|`.
+//! - The sign of $r$ follows $a$: $r / a$ is real and positive.
+//!
+//! # Author
+//! Deval Deliwala
+
+
+#[inline]
+pub fn crotg(
+    a: &mut [f32; 2],
+    b: [f32; 2],
+    c: &mut f32,
+    s: &mut [f32; 2],
+) {
+    let safmin = f32::MIN_POSITIVE;
+    let safmax = 1.0 / safmin;
+    let rtmin  = safmin.sqrt();
+
+    let f = *a;
+    let g = b;
+
+    // degenerate quick returns
+    if g[0] == 0.0 && g[1] == 0.0 {
+        *c = 1.0;
+        *s = [0.0, 0.0];
+        return;
+    }
+
+    if f[0] == 0.0 && f[1] == 0.0 {
+        *c = 0.0;
+
+        // |g| is exact when one component is zero
+        if g[0] == 0.0 || g[1] == 0.0 {
+            let r = g[0].abs().max(g[1].abs());
+            *s = div(conj(g), r);
+            *a = [r, 0.0];
+            return;
+        }
+
+        let g1    = g[0].abs().max(g[1].abs());
+        let rtmax = (safmax / 2.0).sqrt();
+
+        let (gs, u) = if g1 > rtmin && g1 < rtmax {
+            (g, 1.0)
+        } else {
+            let u = g1.clamp(safmin, safmax);
+            (div(g, u), u)
+        };
+
+        let d = abssq(gs).sqrt();
+        *s = div(conj(gs), d);
+        *a = [d * u, 0.0];
+        return;
+    }
+
+    let f1    = f[0].abs().max(f[1].abs());
+    let g1    = g[0].abs().max(g[1].abs());
+    let rtmax = (safmax / 4.0).sqrt();
+
+    // unscaled
+    if f1 > rtmin && f1 < rtmax && g1 > rtmin && g1 < rtmax {
+        let f2 = abssq(f);
+        let h2 = f2 + abssq(g);
+
+        let (cc, r, ss) = rotate(f, g, f2, h2, rtmin, rtmax, safmin);
+        *c = cc;
+        *s = ss;
+        *a = r;
+        return;
+    }
+
+    // scaled by u, and f by v as well when it is tiny next to g
+    let u  = f1.max(g1).clamp(safmin, safmax);
+    let gs = div(g, u);
+    let g2 = abssq(gs);
+
+    let (fs, f2, h2, w) = if f1 / u < rtmin {
+        let v  = f1.clamp(safmin, safmax);
+        let w  = v / u;
+        let fs = div(f, v);
+        let f2 = abssq(fs);
+
+        (fs, f2, f2 * w * w + g2, w)
+    } else {
+        let fs = div(f, u);
+        let f2 = abssq(fs);
+
+        (fs, f2, f2 + g2, 1.0)
+    };
+
+    let (cc, r, ss) = rotate(fs, gs, f2, h2, rtmin, rtmax, safmin);
+    *c = cc * w;
+    *s = ss;
+    *a = mul(r, u);
+}
+
+/// `(c, r, s)` from in-range `f`, `g`, with `f2 = |f|^2` and `h2 = |f|^2 + |g|^2`.
+#[inline(always)]
+fn rotate(
+    f      : [f32; 2],
+    g      : [f32; 2],
+    f2     : f32,
+    h2     : f32,
+    rtmin  : f32,
+    rtmax  : f32,
+    safmin : f32,
+) -> (f32, [f32; 2], [f32; 2]) {
+    if f2 >= h2 * safmin {
+        let c = (f2 / h2).sqrt();
+        let r = div(f, c);
+
+        let s = if f2 > rtmin && h2 < 2.0 * rtmax {
+            cmul(conj(g), div(f, (f2 * h2).sqrt()))
+        } else {
+            cmul(conj(g), div(r, h2))
+        };
+
+        return (c, r, s);
+    }
+
+    // f2 / h2 underflows
+    let d = (f2 * h2).sqrt();
+    let c = f2 / d;
+    let r = if c >= safmin { div(f, c) } else { mul(f, h2 / d) };
+    let s = cmul(conj(g), div(f, d));
+
+    (c, r, s)
+}
+
+#[inline(always)]
+fn abssq(z: [f32; 2]) -> f32 { z[0] * z[0] + z[1] * z[1] }
+
+#[inline(always)]
+fn conj(z: [f32; 2]) -> [f32; 2] { [z[0], -z[1]] }
+
+#[inline(always)]
+fn mul(z: [f32; 2], t: f32) -> [f32; 2] { [z[0] * t, z[1] * t] }
+
+#[inline(always)]
+fn div(z: [f32; 2], t: f32) -> [f32; 2] { [z[0] / t, z[1] / t] }
+
+#[inline(always)]
+fn cmul(x: [f32; 2], y: [f32; 2]) -> [f32; 2] {
+    [x[0] * y[0] - x[1] * y[1], x[0] * y[1] + x[1] * y[0]]
+}
@@ -14,9 +14,9 @@
 //! # Arguments
 //! - `n`    (usize)      : Number of complex elements to process.
 //! - `x`    (&mut [f32]) : Input/output slice containing interleaved complex vector elements.
-//! - `incx` (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx` (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //! - `y`    (&mut [f32]) : Input/output slice containing interleaved complex vector elements.
-//! - `incy` (usize)      : Stride between consecutive complex elements of $y$; complex units. 
+//! - `incy` (isize)      : Stride between consecutive complex elements of $y$; complex units. 
 //! - `c`    (f32)        : Cosine component of the rotation.
 //! - `s`    (f32)        : Sine component of the rotation.
 //!
@@ -28,6 +28,8 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns immediately.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -42,7 +44,8 @@ use core::arch::aarch64::{
     vmulq_f32, 
     vsubq_f32,
 };
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 
 #[inline]
@@ -50,18 +53,41 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn csrot(
     n       : usize, 
     x       : &mut [f32], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f32],
-    incy    : usize,
+    incy    : isize,
     c       : f32, 
     s       : f32
 ) {
     // quick return
     if n == 0 { return; }
 
-    debug_assert!(incx != 0 && incy != 0, "increments must be nonzero");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx (complex)");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y too short for n/incy (complex)");
+    if !Check::new("csrot")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 2);
+        csrot(n, &mut xs, 1, y, incy, c, s);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        csrot(n, x, incx, &mut ys, 1, c, s);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_mut_ptr();
     let py = y.as_mut_ptr();
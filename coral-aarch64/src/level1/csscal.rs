@@ -11,7 +11,7 @@
 //! - `n`     (usize)      : Number of complex elements to scale. 
 //! - `alpha` (f32)        : Real scalar multiplier. 
 //! - `x`     (&mut [f32]) : Input/output slice containing interleaved complex vector elements.
-//! - `incx`  (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx`  (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //!
 //! # Returns 
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -24,6 +24,8 @@
 //! # Author 
 //! Deval Deliwala
 
+use crate::errors::Check;
+
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{ 
@@ -32,7 +34,6 @@ use core::arch::aarch64::{
     vmulq_f32, 
     vst1q_f32
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
 
 #[inline] 
 #[cfg(target_arch = "aarch64")]
@@ -40,12 +41,19 @@ pub fn csscal(
     n       : usize,
     alpha   : f32,
     x       : &mut [f32], 
-    incx    : usize
+    incx    : isize
 ) {
     // quick return
-    if n == 0 || incx == 0 { return; }
+    if n == 0 || incx <= 0 { return; }
+
+    if !Check::new("csscal")
+        .cvec(3, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
+    let incx = incx as usize;
 
     // fast path
     if incx == 1 {
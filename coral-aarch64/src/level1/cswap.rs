@@ -6,9 +6,9 @@
 //! # Arguments 
 //! - `n`    (usize)      : Number of complex elements to swap. 
 //! - `x`    (&mut [f32]) : First input/output slice containing interleaved complex vector elements. 
-//! - `incx` (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx` (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //! - `y`    (&mut [f32]) : Second input/output slice containing interleaved complex vector elements 
-//! - `incy` (usize)      : Stride between consecutive complex elements of $y$; complex units.  
+//! - `incy` (isize)      : Stride between consecutive complex elements of $y$; complex units.  
 //!
 //! # Returns 
 //! - Nothing. The contents of $x$ and $y$ are swapped in place.
@@ -18,6 +18,8 @@
 //!   for optimized performance on AArch64. 
 //! - For non-unit or negative strides, the function falls back to scalar iteration. 
 //! - If `n == 0`, the function returns immediately without modifying input slices.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author 
 //! Deval Deliwala
@@ -28,7 +30,8 @@ use core::arch::aarch64::{
     vld1q_f32, 
     vst1q_f32, 
 };
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 
 #[inline(always)]
@@ -36,16 +39,39 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn cswap(
     n       : usize, 
     x       : &mut [f32],
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f32], 
-    incy    : usize
+    incy    : isize
 ) {
     // quick return 
     if n == 0 { return; }
 
-    debug_assert!(incx > 0 && incy > 0);
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx));
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy));
+    if !Check::new("cswap")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 2);
+        cswap(n, &mut xs, 1, y, incy);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        cswap(n, x, incx, &mut ys, 1);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     unsafe {
         // fast path 
@@ -10,7 +10,7 @@
 //! # Arguments 
 //! - `n`    (usize)  : Number of elements to sum. 
 //! - `x`    (&[f64]) : Input slice containing vector elements 
-//! - `incx` (usize)  : Stride between consecutive elements of $x$ 
+//! - `incx` (isize)  : Stride between consecutive elements of $x$ 
 //!
 //! # Returns 
 //! - `f64` sum of absolute values of selected vector elements. 
@@ -19,7 +19,7 @@
 //! - For `incx == 1`, [`dasum`] uses unrolled NEON SIMD instructions for optimized 
 //!   performance on AArch64. 
 //! - For non-unit strides the function falls back to a scalar loop. 
-//! - If `n == 0 || incx == 0`, returns `0.0f64`
+//! - If `n == 0 || incx <= 0`, returns `0.0f64`
 //! 
 //! # Author 
 //! Deval Deliwala
@@ -33,7 +33,7 @@ use core::arch::aarch64::{
     vaddvq_f64,
     vabsq_f64,
 };
-use crate::level1::assert_length_helpers::required_len_ok;
+use crate::errors::Check;
 
 
 #[inline]
@@ -41,16 +41,23 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn dasum(
     n       : usize, 
     x       : &[f64], 
-    incx    : usize
+    incx    : isize
 ) -> f64 {
     let mut res = 0.0;
 
     // quick return 
-    if n == 0 || incx == 0 {
+    if n == 0 || incx <= 0 {
         return res;
     }
 
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("dasum")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         // fast path 
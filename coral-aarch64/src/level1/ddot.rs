@@ -11,9 +11,9 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of elements in the vectors.
 //! - `x`    (&[f64]) : Input slice containing the first vector.
-//! - `incx` (usize)  : Stride between consecutive elements of $x$.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
 //! - `y`    (&[f64]) : Input slice containing the second vector.
-//! - `incy` (usize)  : Stride between consecutive elements of $y$.
+//! - `incy` (isize)  : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - `f64` dot product of the selected vector elements.
@@ -23,6 +23,8 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns `0.0f64`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -35,7 +37,8 @@ use core::arch::aarch64::{
     vaddvq_f64, 
     vaddq_f64, 
 }; 
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 
 #[inline] 
@@ -43,16 +46,35 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn ddot(
     n       : usize, 
     x       : &[f64], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &[f64], 
-    incy    : usize
+    incy    : isize
 ) -> f64 { 
     // quick return 
     if n == 0 { return 0.0; }
 
-    debug_assert!(incx > 0 && incy > 0, "increments must be nonzero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("ddot")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        return ddot(n, &xs, 1, y, incy);
+    }
+    if incy < 0 && incx >= 0 {
+        let ys = gather(y, n, incy, 1);
+        return ddot(n, x, incx, &ys, 1);
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_ptr(); 
     let py = y.as_ptr(); 
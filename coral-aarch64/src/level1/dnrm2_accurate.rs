@@ -0,0 +1,58 @@
+//! `NRM2`, compensated. Computes the Euclidean norm of a double precision vector
+//! with a sum of squares carried in twice the working precision.
+//!
+//! \\[
+//! \sqrt{\sum\_{i=0}^{n-1} x_i^2}
+//! \\]
+//!
+//! The vector is scaled by the power of two just above $\max_i \lvert x_i \rvert$,
+//! which is exact, and the squares are summed with `Dot2` of Ogita, Rump and Oishi.
+//!
+//! # Arguments
+//! - `n`    (usize)  : Number of elements in the vector.
+//! - `x`    (&[f64]) : Input slice containing vector elements.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
+//!
+//! # Returns
+//! - `f64` Euclidean norm of the selected vector elements.
+//!
+//! # Notes
+//! - With $u = 2^{-53}$ and $\gamma_n = n u / (1 - n u)$, the relative error is at most
+//!   $2u + \gamma_n^2$: half the error of the sum of squares, plus the square root.
+//! - No overflow or harmful underflow for finite input. Entries whose squares vanish
+//!   next to the largest one may be flushed to zero, which is inside the bound.
+//! - Two scalar passes; expect several times the cost of [`dnrm2`](fn@crate::level1::dnrm2).
+//! - If `n == 0` or `incx == 0`, the function returns `0.0f64`.
+//! - A negative `incx` gives the same result as `|incx|`.
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::errors::Check;
+use crate::level1::compensated::ssq2_f64;
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn dnrm2_accurate(
+    n       : usize,
+    x       : &[f64],
+    incx    : isize
+) -> f64 {
+    // quick return
+    if n == 0 || incx == 0 {
+        return 0.0;
+    }
+
+    if !Check::new("dnrm2_accurate")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    // the norm does not depend on the direction
+    let (scale, hi, lo) = ssq2_f64(n, x, incx.unsigned_abs());
+
+    scale * (hi + lo).sqrt()
+}
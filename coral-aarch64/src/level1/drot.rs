@@ -13,9 +13,9 @@
 //! # Arguments
 //! - `n`    (usize)      : Number of elements to process.
 //! - `x`    (&mut [f64]) : Input/output slice containing the first vector.
-//! - `incx` (usize)      : Stride between consecutive elements of $x$.
+//! - `incx` (isize)      : Stride between consecutive elements of $x$.
 //! - `y`    (&mut [f64]) : Input/output slice containing the second vector.
-//! - `incy` (usize)      : Stride between consecutive elements of $y$.
+//! - `incy` (isize)      : Stride between consecutive elements of $y$.
 //! - `c`    (f64)        : Cosine component of the rotation.
 //! - `s`    (f64)        : Sine component of the rotation.
 //!
@@ -27,6 +27,8 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns immediately; no slice modification.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -40,7 +42,8 @@ use core::arch::aarch64::{
     vfmsq_f64,
     vmulq_f64,
 };
-use crate::level1::assert_length_helpers::required_len_ok;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 
 #[inline]
@@ -48,18 +51,41 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn drot(
     n       : usize, 
     x       : &mut [f64], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f64], 
-    incy    : usize,
+    incy    : isize,
     c       : f64, 
     s       : f64
 ) {
     // quick return
     if n == 0 { return; }
 
-    debug_assert!(incx != 0 && incy != 0, "increments must be nonzero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("drot")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 1);
+        drot(n, &mut xs, 1, y, incy, c, s);
+        scatter(&xs, x, n, incx, 1);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 1);
+        drot(n, x, incx, &mut ys, 1, c, s);
+        scatter(&ys, y, n, incy, 1);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_mut_ptr();
     let py = y.as_mut_ptr();
@@ -27,8 +27,14 @@
 //! - Nothing. The results are written in place to `a`, `b`, `c`, and `s`.
 //!
 //! # Notes
-//! - If both `a` and `b` are zero, the routine sets `c = 1.0`, `s = 0.0`,
-//!   and overwrites `a = b = 0.0`.
+//! - Uses the safe scaling of reference BLAS 3.10 (Anderson, "Algorithm 978:
+//!   Safe Scaling in the Level 1 BLAS", ACM TOMS, 2017): $a$ and $b$ are
+//!   scaled by their larger magnitude, clamped to the normal range, so $r$
+//!   never overflows or underflows unless it is itself out of range.
+//! - If `b == 0`, the routine sets `c = 1.0`, `s = 0.0` and `b = 0.0`, and
+//!   leaves `a` as is.
+//! - If `a == 0` and `b != 0`, the routine sets `c = 0.0`, `s = 1.0`,
+//!   `a = b` and `b = 1.0`.
 //! - The value $z$ stored in `b` allows reconstruction of the rotation without
 //!   recomputing `c` and `s`.
 //!
@@ -38,34 +44,43 @@
 
 #[inline]
 pub fn drotg(a: &mut f64, b: &mut f64, c: &mut f64, s: &mut f64) {
-    let roe = if a.abs() > b.abs() { *a } else { *b };
-    let scale = a.abs() + b.abs();
+    let safmin = f64::MIN_POSITIVE;
+    let safmax = 1.0 / safmin;
 
-    // degenerate quick return  
-    if scale == 0.0 {
+    let anorm = a.abs();
+    let bnorm = b.abs();
+
+    // degenerate quick returns
+    if bnorm == 0.0 {
         *c = 1.0;
         *s = 0.0;
-        *a = 0.0; 
-        *b = 0.0; 
+        *b = 0.0;
+        return;
+    }
+    if anorm == 0.0 {
+        *c = 0.0;
+        *s = 1.0;
+        *a = *b;
+        *b = 1.0;
         return;
     }
 
-    let mut r = scale * ((*a / scale).powi(2) + (*b / scale).powi(2)).sqrt();
-    if roe < 0.0 { r = -r; }
+    // the larger magnitude, clamped to the normal range
+    let scl = anorm.max(bnorm).clamp(safmin, safmax);
+    let roe = if anorm > bnorm { *a } else { *b };
+    let r   = (scl * ((*a / scl).powi(2) + (*b / scl).powi(2)).sqrt()).copysign(roe);
 
     *c = *a / r;
     *s = *b / r;
 
-    let mut z = 1.0f64;
-    if a.abs() > b.abs() {
-        z = *s;
-    }
-    if b.abs() >= a.abs() && *c != 0.0 {
-        z = 1.0 / *c;
-    }
+    let z = if anorm > bnorm {
+        *s
+    } else if *c != 0.0 {
+        1.0 / *c
+    } else {
+        1.0
+    };
 
     *a = r; 
     *b = z; 
 }
-
-
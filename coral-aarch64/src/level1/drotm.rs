@@ -12,9 +12,9 @@
 //! # Arguments
 //! - `n`     (usize)      : Number of elements to process.
 //! - `x`     (&mut [f64]) : First input/output slice containing vector elements.
-//! - `incx`  (usize)      : Stride between consecutive elements of $x$.
+//! - `incx`  (isize)      : Stride between consecutive elements of $x$.
 //! - `y`     (&mut [f64]) : Second input/output slice containing vector elements.
-//! - `incy`  (usize)      : Stride between consecutive elements of $y$.
+//! - `incy`  (isize)      : Stride between consecutive elements of $y$.
 //! - `param` (&[f64; 5])  : Array of 5 parameters defining the modified Givens rotation
 //!                          (`flag, h11, h21, h12, h22`).
 //!
@@ -26,6 +26,8 @@
 //! - For unit strides, [`drotm`] uses unrolled NEON SIMD instructions for optimized 
 //!   performance on AArch64. 
 //! - For non-unit strides, it falls back to scalar loops.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala 
@@ -39,7 +41,8 @@ use core::arch::aarch64::{
     vsubq_f64,
     vmulq_f64, 
 };
-use crate::level1::assert_length_helpers::required_len_ok;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 
 #[inline]
@@ -47,9 +50,9 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn drotm(
     n       : usize,
     x       : &mut [f64], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f64],
-    incy    : usize, 
+    incy    : isize, 
     param   : &[f64; 5]
 ) {
     // quick return
@@ -61,9 +64,32 @@ pub fn drotm(
     // identity 
     if flag == -2.0 { return; }
 
-    debug_assert!(incx != 0 && incy != 0, "increments must be nonzero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("drotm")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 1);
+        drotm(n, &mut xs, 1, y, incy, param);
+        scatter(&xs, x, n, incx, 1);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 1);
+        drotm(n, x, incx, &mut ys, 1, param);
+        scatter(&ys, y, n, incy, 1);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_mut_ptr();
     let py = y.as_mut_ptr();
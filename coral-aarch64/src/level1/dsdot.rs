@@ -0,0 +1,164 @@
+//! `DSDOT`. Dot product of two single precision vectors, accumulated in
+//! double precision.
+//!
+//! \\[
+//! \sum\_{i=0}^{n-1} x_i\\, y_i
+//! \\]
+//!
+//! This function implements the BLAS [`dsdot`] routine. Each $x_i$ and $y_i$
+//! is widened to `f64` before the multiply, so every product is exact and
+//! only the `f64` additions round.
+//!
+//! # Arguments
+//! - `n`    (usize)  : Number of elements in the vectors.
+//! - `x`    (&[f32]) : Input slice containing the first vector.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
+//! - `y`    (&[f32]) : Input slice containing the second vector.
+//! - `incy` (isize)  : Stride between consecutive elements of $y$.
+//!
+//! # Returns
+//! - `f64` dot product of the selected vector elements.
+//!
+//! # Notes
+//! - For `incx == 1 && incy == 1`, [`dsdot`] widens four lanes at a time with
+//!   NEON `fcvtl`/`fcvtl2` and accumulates in `f64x2` registers.
+//! - For non unit strides, the function falls back to a scalar loop.
+//! - If `n == 0`, the function returns `0.0f64`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//!
+//! # Author
+//! Deval Deliwala
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::{
+    float64x2_t,
+    vld1q_f32,
+    vget_low_f32,
+    vcvt_f64_f32,
+    vcvt_high_f64_f32,
+    vdupq_n_f64,
+    vfmaq_f64,
+    vaddq_f64,
+    vaddvq_f64,
+};
+use crate::errors::Check;
+use crate::level1::strides::gather;
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn dsdot(
+    n       : usize,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize
+) -> f64 {
+    // quick return
+    if n == 0 { return 0.0; }
+
+    if !Check::new("dsdot")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    dsdot_kernel(n, x, incx, y, incy)
+}
+
+/// [`dsdot`] after argument checks; shared with `sdsdot`.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn dsdot_kernel(
+    n       : usize,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize
+) -> f64 {
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        return dsdot_kernel(n, &xs, 1, y, incy);
+    }
+    if incy < 0 && incx >= 0 {
+        let ys = gather(y, n, incy, 1);
+        return dsdot_kernel(n, x, incx, &ys, 1);
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
+
+    let px = x.as_ptr();
+    let py = y.as_ptr();
+
+    // fast path
+    if incx == 1 && incy == 1 {
+        unsafe {
+            let mut acc = [vdupq_n_f64(0.0); 8];
+
+            let mut i = 0usize;
+
+            while i + 16 <= n {
+                for q in 0..4 {
+                    let ax = vld1q_f32(px.add(i + 4 * q));
+                    let ay = vld1q_f32(py.add(i + 4 * q));
+
+                    // acc += widen(ax) * widen(ay), low and high halves
+                    acc[2 * q]     = vfmaq_f64(acc[2 * q],
+                        vcvt_f64_f32(vget_low_f32(ax)), vcvt_f64_f32(vget_low_f32(ay)));
+                    acc[2 * q + 1] = vfmaq_f64(acc[2 * q + 1],
+                        vcvt_high_f64_f32(ax), vcvt_high_f64_f32(ay));
+                }
+
+                i += 16;
+            }
+
+            while i + 4 <= n {
+                let ax = vld1q_f32(px.add(i));
+                let ay = vld1q_f32(py.add(i));
+
+                acc[0] = vfmaq_f64(acc[0],
+                    vcvt_f64_f32(vget_low_f32(ax)), vcvt_f64_f32(vget_low_f32(ay)));
+                acc[1] = vfmaq_f64(acc[1], vcvt_high_f64_f32(ax), vcvt_high_f64_f32(ay));
+
+                i += 4;
+            }
+
+            let mut acc = vaddvq_f64(reduce(&acc));
+
+            // tail
+            while i < n {
+                acc += *px.add(i) as f64 * *py.add(i) as f64;
+
+                i += 1;
+            }
+
+            return acc;
+        }
+    }
+
+    // non unit stride
+    let mut acc = 0.0;
+    for i in 0..n {
+        acc += x[i * incx] as f64 * y[i * incy] as f64;
+    }
+
+    acc
+}
+
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn reduce(acc: &[float64x2_t; 8]) -> float64x2_t {
+    unsafe {
+        let a = vaddq_f64(vaddq_f64(acc[0], acc[1]), vaddq_f64(acc[2], acc[3]));
+        let b = vaddq_f64(vaddq_f64(acc[4], acc[5]), vaddq_f64(acc[6], acc[7]));
+
+        vaddq_f64(a, b)
+    }
+}
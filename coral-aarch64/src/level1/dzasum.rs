@@ -19,7 +19,7 @@
 //! - For `incx == 1`, [`dzasum`] uses unrolled NEON SIMD instructions for optimized 
 //!   performance on AArch64. 
 //! - For non-unit strides the function falls back to a scalar loop
-//! - If `n == 0 || incx == 0`, returns `0.0f64`
+//! - If `n == 0 || incx <= 0`, returns `0.0f64`
 //! 
 //! # Author 
 //! Deval Deliwala
@@ -32,7 +32,7 @@ use core::arch::aarch64::{
     vaddvq_f64, 
     vabsq_f64,
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx; 
+use crate::errors::Check;
 
 
 #[inline]
@@ -40,16 +40,23 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn dzasum(
     n       : usize, 
     x       : &[f64],
-    incx    : usize
+    incx    : isize
 ) -> f64 {
     let mut res = 0.0;
 
     // quick return 
-    if n == 0 || incx == 0 {
+    if n == 0 || incx <= 0 {
         return res;
     }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx (complex)");
+    if !Check::new("dzasum")
+        .cvec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         // fast path 
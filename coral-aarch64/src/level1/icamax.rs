@@ -10,7 +10,7 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of complex elements in the vector.
 //! - `x`    (&[f32]) : Input slice containing interleaved complex vector elements.
-//! - `incx` (usize)  : Stride between consecutive complex elements of $x$; complex units
+//! - `incx` (isize)  : Stride between consecutive complex elements of $x$; complex units
 //!
 //! # Returns
 //! - `usize` 0-based index of the first complex element with maximum absolute value.
@@ -40,7 +40,7 @@ use core::arch::aarch64::{
     vmaxvq_f32,
     vrev64q_f32
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx; 
+use crate::errors::Check;
 
 
 #[inline]
@@ -48,12 +48,19 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn icamax(
     n       : usize,
     x       : &[f32], 
-    incx    : usize
+    incx    : isize
 ) -> usize {
     // quick return 
     if n == 0 || incx <= 0 { return 0; }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("icamax")
+        .cvec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         let mut best_val = f32::NEG_INFINITY;
@@ -7,7 +7,7 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of elements in the vector.
 //! - `x`    (&[f64]) : Input slice containing vector elements.
-//! - `incx` (usize)  : Stride between consecutive elements of $x$.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
 //!
 //! # Returns
 //! - `usize` 0-based index of the first element with maximum absolute value.
@@ -35,7 +35,7 @@ use core::arch::aarch64::{
     vmaxvq_f64,
     vbslq_f64, 
 };
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
 
 
 #[inline]
@@ -43,12 +43,19 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn idamax(
     n       : usize,
     x       : &[f64],
-    incx    : usize
+    incx    : isize
 ) -> usize {
     // quick return 
     if n == 0 || incx <= 0 { return 0; }
 
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("idamax")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         let mut best_val = f64::NEG_INFINITY;
@@ -8,7 +8,7 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of complex elements in the vector.
 //! - `x`    (&[f64]) : Input slice containing interleaved complex vector elements.
-//! - `incx` (usize)  : Stride between consecutive complex elements of $x$
+//! - `incx` (isize)  : Stride between consecutive complex elements of $x$
 //!
 //! # Returns
 //! - `usize` 0-based index of the first complex element with maximum absolute value.
@@ -22,23 +22,31 @@
 //! # Author
 //! Deval Deliwala
 
+use crate::errors::Check;
+
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{ 
     vdupq_n_u64, vbslq_u64, vgetq_lane_u64, 
     vld1q_f64, vaddq_f64, vextq_f64, vdupq_n_f64, vabsq_f64, vceqq_f64, vmaxvq_f64, vbslq_f64, 
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
 
 #[inline]
 pub fn izamax(
     n       : usize,
     x       : &[f64], 
-    incx    : usize
+    incx    : isize
 ) -> usize {
     // quick return 
     if n == 0 || incx <= 0 { return 0; }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx (complex)");
+    if !Check::new("izamax")
+        .cvec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         let mut best_val = f64::NEG_INFINITY;
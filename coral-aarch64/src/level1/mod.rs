@@ -1,5 +1,7 @@
 pub(crate) mod assert_length_helpers;
 pub(crate) mod nrm2_helpers;
+pub(crate) mod strides;
+pub(crate) mod compensated;
 
 pub mod sasum;
 pub mod dasum; 
@@ -44,6 +46,8 @@ pub mod cdotu;
 pub mod zdotu;
 pub mod cdotc; 
 pub mod zdotc;
+pub mod sdsdot;
+pub mod dsdot;
 
 pub mod srot; 
 pub mod srotg; 
@@ -55,6 +59,17 @@ pub mod drotm;
 pub mod drotmg; 
 pub mod csrot; 
 pub mod zdrot; 
+pub mod crotg;
+pub mod zrotg;
+pub mod crot;
+pub mod zrot;
+
+pub mod sdot_accurate;
+pub mod ddot_accurate;
+pub mod sasum_accurate;
+pub mod dasum_accurate;
+pub mod snrm2_accurate;
+pub mod dnrm2_accurate;
 
 pub use sasum::sasum;
 pub use dasum::dasum;
@@ -99,6 +114,8 @@ pub use cdotu::cdotu;
 pub use zdotu::zdotu;
 pub use cdotc::cdotc;
 pub use zdotc::zdotc;
+pub use sdsdot::sdsdot;
+pub use dsdot::dsdot;
 
 pub use srot::srot;
 pub use srotg::srotg;
@@ -110,4 +127,14 @@ pub use drotm::drotm;
 pub use drotmg::drotmg;
 pub use csrot::csrot;
 pub use zdrot::zdrot;
-
+pub use crotg::crotg;
+pub use zrotg::zrotg;
+pub use crot::crot;
+pub use zrot::zrot;
+
+pub use sdot_accurate::sdot_accurate;
+pub use ddot_accurate::ddot_accurate;
+pub use sasum_accurate::sasum_accurate;
+pub use dasum_accurate::dasum_accurate;
+pub use snrm2_accurate::snrm2_accurate;
+pub use dnrm2_accurate::dnrm2_accurate;
@@ -1,65 +1,349 @@
 //! Internal helpers for NRM2 routines.
 //!
-//! These functions implement the scaled sum-of-squares update used to maintain
-//! numerical stability when accumulating the norm.  
+//! Blue's algorithm, as in reference BLAS 3.10 (Anderson, "Algorithm 978: Safe
+//! Scaling in the Level 1 BLAS", ACM TOMS, 2017). Each $|x_i|$ lands in one
+//! of three sums of squares:
 //!
-//! Given the current scaling factor `scale` and sum-of-squares `ssq`, they update both 
-//! with a new block’s maximum absolute value `cmax` and its normalized squared contribution 
-//! `cssq`.
+//! - `big` : $|x_i| > t_{big}$, squared after scaling by $s_{big}$.
+//! - `sml` : $|x_i| < t_{sml}$, squared after scaling by $s_{sml}$.
+//! - `med` : everything else, squared as is.
 //!
-//! - `upd_f32` : Single precision.  
-//! - `upd_f64` : Double precision.
+//! The thresholds are powers of two chosen so no square over- or underflows,
+//! so the scalings are exact and the hot loop has no division and no data
+//! dependent rescaling. `NaN` falls through to `med` and propagates.
+//!
+//! - `BlueF32`, `BlueF64`     : scalar accumulators and the final combine.
+//! - `blue_unit_f32`, `_f64`  : NEON lanes of all three sums over a contiguous run.
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::{
+    float32x4_t,
+    float64x2_t,
+    vld1q_f32,
+    vld1q_f64,
+    vdupq_n_f32,
+    vdupq_n_f64,
+    vabsq_f32,
+    vabsq_f64,
+    vcgtq_f32,
+    vcgtq_f64,
+    vcltq_f32,
+    vcltq_f64,
+    vbslq_f32,
+    vbslq_f64,
+    vmulq_n_f32,
+    vmulq_n_f64,
+    vfmaq_f32,
+    vfmaq_f64,
+    vaddq_f32,
+    vaddq_f64,
+    vaddvq_f32,
+    vaddvq_f64,
+};
+
+const fn pow2_f32(k: i32) -> f32 { f32::from_bits(((k + 127) as u32) << 23) }
+const fn pow2_f64(k: i32) -> f64 { f64::from_bits(((k + 1023) as u64) << 52) }
+
+// f32: minexponent -125, maxexponent 128, 24 digits
+const TSML_F32: f32 = pow2_f32(-63);
+const TBIG_F32: f32 = pow2_f32(52);
+const SSML_F32: f32 = pow2_f32(75);
+const SBIG_F32: f32 = pow2_f32(-76);
+
+// f64: minexponent -1021, maxexponent 1024, 53 digits
+const TSML_F64: f64 = pow2_f64(-511);
+const TBIG_F64: f64 = pow2_f64(486);
+const SSML_F64: f64 = pow2_f64(537);
+const SBIG_F64: f64 = pow2_f64(-538);
+
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct BlueF32 {
+    sml : f32,
+    med : f32,
+    big : f32,
+}
+
+impl BlueF32 {
+    #[inline(always)]
+    pub(crate) fn push(&mut self, x: f32) {
+        let ax = x.abs();
+
+        if ax > TBIG_F32 {
+            let t = ax * SBIG_F32;
+            self.big += t * t;
+        } else if ax < TSML_F32 {
+            let t = ax * SSML_F32;
+            self.sml += t * t;
+        } else {
+            self.med += ax * ax;
+        }
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: BlueF32) {
+        self.sml += other.sml;
+        self.med += other.med;
+        self.big += other.big;
+    }
+
+    /// combines the three sums into $\sqrt{\sum x_i^2}$.
+    #[inline]
+    pub(crate) fn finish(self) -> f32 {
+        let BlueF32 { sml, med, big } = self;
+
+        // a NaN in `med` must reach the result
+        let has_med = med > 0.0 || med.is_nan();
+
+        if big > 0.0 {
+            let big = if has_med { big + (med * SBIG_F32) * SBIG_F32 } else { big };
+            return big.sqrt() / SBIG_F32;
+        }
+
+        if sml > 0.0 {
+            if !has_med {
+                return sml.sqrt() / SSML_F32;
+            }
+
+            let med = med.sqrt();
+            let sml = sml.sqrt() / SSML_F32;
+            let (ymin, ymax) = if sml > med { (med, sml) } else { (sml, med) };
+            let r = ymin / ymax;
+
+            return (ymax * ymax * (1.0 + r * r)).sqrt();
+        }
+
+        med.sqrt()
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct BlueF64 {
+    sml : f64,
+    med : f64,
+    big : f64,
+}
+
+impl BlueF64 {
+    #[inline(always)]
+    pub(crate) fn push(&mut self, x: f64) {
+        let ax = x.abs();
+
+        if ax > TBIG_F64 {
+            let t = ax * SBIG_F64;
+            self.big += t * t;
+        } else if ax < TSML_F64 {
+            let t = ax * SSML_F64;
+            self.sml += t * t;
+        } else {
+            self.med += ax * ax;
+        }
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: BlueF64) {
+        self.sml += other.sml;
+        self.med += other.med;
+        self.big += other.big;
+    }
+
+    /// combines the three sums into $\sqrt{\sum x_i^2}$.
+    #[inline]
+    pub(crate) fn finish(self) -> f64 {
+        let BlueF64 { sml, med, big } = self;
+
+        let has_med = med > 0.0 || med.is_nan();
 
+        if big > 0.0 {
+            let big = if has_med { big + (med * SBIG_F64) * SBIG_F64 } else { big };
+            return big.sqrt() / SBIG_F64;
+        }
 
-#[inline(always)] 
-pub(crate) fn upd_f32(
-    scale   : &mut f32,
-    ssq     : &mut f32,
-    cmax    : f32,
-    cssq    : f32
-) {
-    if *scale < cmax {
+        if sml > 0.0 {
+            if !has_med {
+                return sml.sqrt() / SSML_F64;
+            }
 
-        let r  = *scale / cmax;
-        *ssq   = *ssq * (r*r) + cssq;
-        *scale = cmax;
+            let med = med.sqrt();
+            let sml = sml.sqrt() / SSML_F64;
+            let (ymin, ymax) = if sml > med { (med, sml) } else { (sml, med) };
+            let r = ymin / ymax;
 
-    } else if *scale > 0.0 {
+            return (ymax * ymax * (1.0 + r * r)).sqrt();
+        }
 
-        let r = cmax / *scale;
-        *ssq += cssq * (r*r);
+        med.sqrt()
+    }
+}
+
+
+#[cfg(target_arch = "aarch64")]
+#[derive(Clone, Copy)]
+struct LanesF32 {
+    sml : float32x4_t,
+    med : float32x4_t,
+    big : float32x4_t,
+}
+
+#[cfg(target_arch = "aarch64")]
+impl LanesF32 {
+    #[inline(always)]
+    unsafe fn new() -> Self {
+        unsafe {
+            let z = vdupq_n_f32(0.0);
+            LanesF32 { sml: z, med: z, big: z }
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn push(&mut self, v: float32x4_t) {
+        unsafe {
+            let zero = vdupq_n_f32(0.0);
+            let ax   = vabsq_f32(v);
 
-    } else {
+            let is_big = vcgtq_f32(ax, vdupq_n_f32(TBIG_F32));
+            let is_sml = vcltq_f32(ax, vdupq_n_f32(TSML_F32));
 
-        *scale = cmax;
-        *ssq   = cssq;
+            // each lane feeds exactly one of the three sums
+            let tb = vbslq_f32(is_big, vmulq_n_f32(ax, SBIG_F32), zero);
+            let ts = vbslq_f32(is_sml, vmulq_n_f32(ax, SSML_F32), zero);
+            let tm = vbslq_f32(is_big, zero, vbslq_f32(is_sml, zero, ax));
 
+            self.big = vfmaq_f32(self.big, tb, tb);
+            self.sml = vfmaq_f32(self.sml, ts, ts);
+            self.med = vfmaq_f32(self.med, tm, tm);
+        }
     }
+
+    #[inline(always)]
+    unsafe fn reduce(self, other: LanesF32) -> BlueF32 {
+        unsafe {
+            BlueF32 {
+                sml : vaddvq_f32(vaddq_f32(self.sml, other.sml)),
+                med : vaddvq_f32(vaddq_f32(self.med, other.med)),
+                big : vaddvq_f32(vaddq_f32(self.big, other.big)),
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[derive(Clone, Copy)]
+struct LanesF64 {
+    sml : float64x2_t,
+    med : float64x2_t,
+    big : float64x2_t,
 }
 
+#[cfg(target_arch = "aarch64")]
+impl LanesF64 {
+    #[inline(always)]
+    unsafe fn new() -> Self {
+        unsafe {
+            let z = vdupq_n_f64(0.0);
+            LanesF64 { sml: z, med: z, big: z }
+        }
+    }
 
-#[inline(always)] 
-pub(crate) fn upd_f64(
-    scale   : &mut f64, 
-    ssq     : &mut f64, 
-    cmax    : f64, 
-    cssq    : f64
-) {
-    if *scale < cmax {
+    #[inline(always)]
+    unsafe fn push(&mut self, v: float64x2_t) {
+        unsafe {
+            let zero = vdupq_n_f64(0.0);
+            let ax   = vabsq_f64(v);
 
-        let r  = *scale / cmax;
-        *ssq   = *ssq * (r*r) + cssq;
-        *scale = cmax;
+            let is_big = vcgtq_f64(ax, vdupq_n_f64(TBIG_F64));
+            let is_sml = vcltq_f64(ax, vdupq_n_f64(TSML_F64));
 
-    } else if *scale > 0.0 {
+            let tb = vbslq_f64(is_big, vmulq_n_f64(ax, SBIG_F64), zero);
+            let ts = vbslq_f64(is_sml, vmulq_n_f64(ax, SSML_F64), zero);
+            let tm = vbslq_f64(is_big, zero, vbslq_f64(is_sml, zero, ax));
 
-        let r = cmax / *scale;
-        *ssq += cssq * (r*r);
+            self.big = vfmaq_f64(self.big, tb, tb);
+            self.sml = vfmaq_f64(self.sml, ts, ts);
+            self.med = vfmaq_f64(self.med, tm, tm);
+        }
+    }
 
-    } else {
+    #[inline(always)]
+    unsafe fn reduce(self, other: LanesF64) -> BlueF64 {
+        unsafe {
+            BlueF64 {
+                sml : vaddvq_f64(vaddq_f64(self.sml, other.sml)),
+                med : vaddvq_f64(vaddq_f64(self.med, other.med)),
+                big : vaddvq_f64(vaddq_f64(self.big, other.big)),
+            }
+        }
+    }
+}
 
-        *scale = cmax;
-        *ssq   = cssq;
+/// Blue's sums over `len` contiguous floats from `p`.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub(crate) unsafe fn blue_unit_f32(p: *const f32, len: usize) -> BlueF32 {
+    unsafe {
+        let mut a0 = LanesF32::new();
+        let mut a1 = LanesF32::new();
 
+        let mut i = 0;
+        while i + 16 <= len {
+            a0.push(vld1q_f32(p.add(i)));
+            a1.push(vld1q_f32(p.add(i + 4)));
+            a0.push(vld1q_f32(p.add(i + 8)));
+            a1.push(vld1q_f32(p.add(i + 12)));
+
+            i += 16;
+        }
+        while i + 4 <= len {
+            a0.push(vld1q_f32(p.add(i)));
+
+            i += 4;
+        }
+
+        let mut acc  = a0.reduce(a1);
+        let mut tail = BlueF32::default();
+        while i < len {
+            tail.push(*p.add(i));
+
+            i += 1;
+        }
+        acc.merge(tail);
+
+        acc
     }
-} 
+}
+
+/// Blue's sums over `len` contiguous doubles from `p`.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub(crate) unsafe fn blue_unit_f64(p: *const f64, len: usize) -> BlueF64 {
+    unsafe {
+        let mut a0 = LanesF64::new();
+        let mut a1 = LanesF64::new();
+
+        let mut i = 0;
+        while i + 8 <= len {
+            a0.push(vld1q_f64(p.add(i)));
+            a1.push(vld1q_f64(p.add(i + 2)));
+            a0.push(vld1q_f64(p.add(i + 4)));
+            a1.push(vld1q_f64(p.add(i + 6)));
+
+            i += 8;
+        }
+        while i + 2 <= len {
+            a0.push(vld1q_f64(p.add(i)));
+
+            i += 2;
+        }
+
+        let mut acc  = a0.reduce(a1);
+        let mut tail = BlueF64::default();
+        while i < len {
+            tail.push(*p.add(i));
+
+            i += 1;
+        }
+        acc.merge(tail);
+
+        acc
+    }
+}
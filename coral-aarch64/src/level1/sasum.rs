@@ -10,7 +10,7 @@
 //! # Arguments 
 //! - `n`    (usize)  : Number of elements to sum. 
 //! - `x`    (&[f32]) : Input slice containing vector elements 
-//! - `incx` (usize)  : Stride between consecutive elements of $x$ 
+//! - `incx` (isize)  : Stride between consecutive elements of $x$ 
 //!
 //! # Returns 
 //! - `f32` sum of absolute values of selected vector elements. 
@@ -19,7 +19,8 @@
 //! - For `incx == 1`, [`sasum`] uses unrolled NEON SIMD instructions for optimized 
 //!   performance on AArch64. 
 //! - For non-unit strides the function falls back to a scalar loop
-//! - If `n == 0 || incx == 0`, returns `0.0f32`
+//! - If `n == 0 || incx <= 0`, returns `0.0f32`
+//! - The `reproducible` feature swaps in a fixed-order scalar sum.
 //! 
 //! # Author 
 //! Deval Deliwala 
@@ -32,7 +33,8 @@ use core::arch::aarch64::{
     vaddvq_f32,
     vabsq_f32,
 }; 
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
+use crate::reproducible;
 
 
 #[inline]
@@ -40,16 +42,27 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn sasum(
     n       : usize, 
     x       : &[f32],
-    incx    : usize
+    incx    : isize
 ) -> f32 {
     let mut res = 0.0;
 
     // quick return 
-    if n == 0 || incx == 0 {
+    if n == 0 || incx <= 0 {
         return res;
     }
 
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("sasum")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    let incx = incx as usize;
+
+    if cfg!(feature = "reproducible") {
+        return reproducible::sasum(n, x, incx);
+    }
 
     unsafe {
         // fast path 
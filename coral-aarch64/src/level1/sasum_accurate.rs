@@ -0,0 +1,55 @@
+//! `ASUM`, compensated. Computes the sum of absolute values of elements in a
+//! single precision vector as if in twice the working precision.
+//!
+//! \\[
+//! \sum\_{i=0}^{n-1} \lvert x_i \rvert
+//! \\]
+//!
+//! This function implements the `Sum2` algorithm of Ogita, Rump and Oishi, a
+//! cascaded `TwoSum` in the spirit of Kahan and Neumaier.
+//!
+//! # Arguments
+//! - `n`    (usize)  : Number of elements to sum.
+//! - `x`    (&[f32]) : Input slice containing vector elements.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
+//!
+//! # Returns
+//! - `f32` sum of absolute values of selected vector elements.
+//!
+//! # Notes
+//! - With $u = 2^{-24}$ and $\gamma_n = n u / (1 - n u)$, every term is nonnegative, so
+//!   the relative error is at most $u + \gamma_{n-1}^2$, against $\gamma_{n-1}$
+//!   for [`sasum`](fn@crate::level1::sasum).
+//! - Scalar loop; expect several times the cost of [`sasum`](fn@crate::level1::sasum).
+//! - If `n == 0 || incx <= 0`, returns `0.0f32`, as [`sasum`](fn@crate::level1::sasum) does.
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::errors::Check;
+use crate::level1::compensated::sum2_abs_f32;
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn sasum_accurate(
+    n       : usize,
+    x       : &[f32],
+    incx    : isize
+) -> f32 {
+    // quick return
+    if n == 0 || incx <= 0 {
+        return 0.0;
+    }
+
+    if !Check::new("sasum_accurate")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    let (hi, lo) = sum2_abs_f32(n, x, incx as usize);
+
+    hi + lo
+}
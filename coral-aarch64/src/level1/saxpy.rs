@@ -11,9 +11,9 @@
 //! - `n`     (usize)     : Number of elements to process.
 //! - `alpha` (f32)       : Scalar multiplier for $x$.
 //! - `x`     (&[f32])    : Input slice containing vector elements.
-//! - `incx`  (usize)     : Stride between consecutive elements of $x$.
+//! - `incx`  (isize)     : Stride between consecutive elements of $x$.
 //! - `y`     (&mut [f32] : Input/output slice containing vector elements, updated in place.
-//! - `incy`  (usize)     : Stride between consecutive elements of $y$.
+//! - `incy`  (isize)     : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place. 
@@ -23,10 +23,15 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0` or `alpha == 0.0`, the function returns immediately.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
+
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{ 
     vld1q_f32, 
@@ -34,7 +39,6 @@ use core::arch::aarch64::{
     vfmaq_f32, 
     vst1q_f32 
 }; 
-use crate::level1::assert_length_helpers::required_len_ok;
 
 
 #[inline(always)]
@@ -43,18 +47,40 @@ pub fn saxpy(
     n       : usize, 
     alpha   : f32, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f32], 
-    incy    : usize
+    incy    : isize
 ) { 
     // quick return 
     if n == 0 || alpha == 0.0 { 
         return; 
     } 
 
-    debug_assert!(incx > 0 && incy > 0, "BLAS increments must be non-zero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("saxpy")
+        .vec(3, x.len(), n, incx)
+        .vec(5, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        saxpy(n, alpha, &xs, 1, y, incy);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 1);
+        saxpy(n, alpha, x, incx, &mut ys, 1);
+        scatter(&ys, y, n, incy, 1);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     unsafe { 
         let av = vdupq_n_f32(alpha); 
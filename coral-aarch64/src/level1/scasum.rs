@@ -10,7 +10,7 @@
 //! # Arguments 
 //! - `n`    (usize)  : Number of elements to sum. 
 //! - `x`    (&[f32]) : Input slice containing interleaved complex vector elements. 
-//! - `incx` (usize)  : Stride between consecutive complex elements of $x$; complex units.  
+//! - `incx` (isize)  : Stride between consecutive complex elements of $x$; complex units.  
 //!
 //! # Returns 
 //! - `f32` sum of absolute values of the real and imag parts of selected vector elements. 
@@ -19,7 +19,7 @@
 //! - For `incx == 1`, [`scasum`] uses unrolled NEON SIMD instructions for optimized 
 //!   performance on AArch64. 
 //! - For non-unit strides the function falls back to a scalar loop. 
-//! - If `n == 0 || incx == 0`, returns `0.0f32`
+//! - If `n == 0 || incx <= 0`, returns `0.0f32`
 //! 
 //! # Author 
 //! Deval Deliwala
@@ -32,7 +32,7 @@ use core::arch::aarch64::{
     vaddvq_f32, 
     vabsq_f32,
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx; 
+use crate::errors::Check;
 
 
 #[inline]
@@ -40,16 +40,23 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn scasum(
     n       : usize,
     x       : &[f32], 
-    incx    : usize
+    incx    : isize
 ) -> f32 {
     let mut res = 0.0;
 
     // quick return 
-    if n == 0 || incx == 0 {
+    if n == 0 || incx <= 0 {
         return res;
     }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx (complex)");
+    if !Check::new("scasum")
+        .cvec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    let incx = incx as usize;
 
     unsafe {
         // fast path 
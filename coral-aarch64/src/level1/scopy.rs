@@ -6,9 +6,9 @@
 //! # Arguments
 //! - `n`    (usize)      : Number of elements to copy.
 //! - `x`    (&[f32])     : Input slice containing vector elements.
-//! - `incx` (usize)      : Stride between consecutive elements of $x$.
+//! - `incx` (isize)      : Stride between consecutive elements of $x$.
 //! - `y`    (&mut [f64]) : Output slice to receive copied elements.
-//! - `incy` (usize)      : Stride between consecutive elements of $y$.
+//! - `incy` (isize)      : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are overwritten with elements from $x$.
@@ -18,26 +18,51 @@
 //!   contiguous memory copying.
 //! - For non-unit strides the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns immediately without modifying $y$.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
 
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 #[inline(always)]
 pub fn scopy(
     n       : usize, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f32],
-    incy    : usize
+    incy    : isize
 ) {
     // quick return 
     if n == 0 { return; }
 
-    debug_assert!(incx > 0 && incy > 0, "BLAS increments must be non-zero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("scopy")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        scopy(n, &xs, 1, y, incy);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 1);
+        scopy(n, x, incx, &mut ys, 1);
+        scatter(&ys, y, n, incy, 1);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     unsafe {
         // fast path 
@@ -10,9 +10,9 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of elements in the vectors.
 //! - `x`    (&[f32]) : Input slice containing the first vector.
-//! - `incx` (usize)  : Stride between consecutive elements of $x$.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
 //! - `y`    (&[f32]) : Input slice containing the second vector.
-//! - `incy` (usize)  : Stride between consecutive elements of $y$.
+//! - `incy` (isize)  : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - `f32` dot product of the selected vector elements.
@@ -22,6 +22,10 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns `0.0f32`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//! - With the `reproducible` feature, the sum runs in a fixed order that does
+//!   not depend on the stride, alignment or `n % 32`.
 //!
 //! # Author
 //! Deval Deliwala
@@ -34,7 +38,9 @@ use core::arch::aarch64::{
     vaddvq_f32,
     vaddq_f32 
 };
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
+use crate::level1::strides::gather;
+use crate::reproducible;
 
 
 #[inline] 
@@ -42,16 +48,39 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn sdot(
     n       : usize, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &[f32], 
-    incy    : usize
+    incy    : isize
 ) -> f32 { 
     // quick return 
     if n == 0 { return 0.0; }
 
-    debug_assert!(incx > 0 && incy > 0, "increments must be nonzero");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("sdot")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    if cfg!(feature = "reproducible") {
+        return reproducible::sdot(n, x, incx, y, incy);
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        return sdot(n, &xs, 1, y, incy);
+    }
+    if incy < 0 && incx >= 0 {
+        let ys = gather(y, n, incy, 1);
+        return sdot(n, x, incx, &ys, 1);
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_ptr(); 
     let py = y.as_ptr(); 
@@ -0,0 +1,75 @@
+//! `DOT`, compensated. Computes the dot product of two single precision vectors
+//! as if in twice the working precision.
+//!
+//! \\[
+//! \sum\_{i=0}^{n-1} x_i\\, y_i
+//! \\]
+//!
+//! This function implements the `Dot2` algorithm of Ogita, Rump and Oishi: every
+//! product is split exactly with an FMA, every partial sum with `TwoSum`, and the
+//! rounding errors are carried in a second accumulator.
+//!
+//! # Arguments
+//! - `n`    (usize)  : Number of elements in the vectors.
+//! - `x`    (&[f32]) : Input slice containing the first vector.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
+//! - `y`    (&[f32]) : Input slice containing the second vector.
+//! - `incy` (isize)  : Stride between consecutive elements of $y$.
+//!
+//! # Returns
+//! - `f32` dot product of the selected vector elements.
+//!
+//! # Notes
+//! - With $u = 2^{-24}$ and $\gamma_n = n u / (1 - n u)$, the result $\hat s$ satisfies
+//!   $\lvert \hat s - x^T y \rvert \le u \lvert x^T y \rvert + \gamma_n^2 \sum \lvert x_i y_i \rvert$,
+//!   so the relative error stays near $u$ until the condition number reaches about $1/u$.
+//! - The plain [`sdot`](fn@crate::level1::sdot) only guarantees $\gamma_n \sum \lvert x_i y_i \rvert$.
+//! - Scalar loop; expect several times the cost of [`sdot`](fn@crate::level1::sdot).
+//! - If `n == 0`, the function returns `0.0f32`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::errors::Check;
+use crate::level1::strides::gather;
+use crate::level1::compensated::dot2_f32;
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn sdot_accurate(
+    n       : usize,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize
+) -> f32 {
+    // quick return
+    if n == 0 { return 0.0; }
+
+    if !Check::new("sdot_accurate")
+        .vec(2, x.len(), n, incx)
+        .vec(4, y.len(), n, incy)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 1);
+        return sdot_accurate(n, &xs, 1, y, incy);
+    }
+    if incy < 0 && incx >= 0 {
+        let ys = gather(y, n, incy, 1);
+        return sdot_accurate(n, x, incx, &ys, 1);
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let (hi, lo) = dot2_f32(n, x, incx.unsigned_abs(), y, incy.unsigned_abs());
+
+    hi + lo
+}
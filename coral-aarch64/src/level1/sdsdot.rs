@@ -0,0 +1,58 @@
+//! `SDSDOT`. Single precision dot product plus a scalar, accumulated in
+//! double precision.
+//!
+//! \\[
+//! s_b + \sum\_{i=0}^{n-1} x_i\\, y_i
+//! \\]
+//!
+//! This function implements the BLAS [`sdsdot`] routine. The sum runs in
+//! `f64` as in [`dsdot`](fn@crate::level1::dsdot) and is rounded to `f32`
+//! once, at the end.
+//!
+//! # Arguments
+//! - `n`    (usize)  : Number of elements in the vectors.
+//! - `sb`   (f32)    : Scalar added to the dot product.
+//! - `x`    (&[f32]) : Input slice containing the first vector.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
+//! - `y`    (&[f32]) : Input slice containing the second vector.
+//! - `incy` (isize)  : Stride between consecutive elements of $y$.
+//!
+//! # Returns
+//! - `f32` value of $s_b + x^T y$.
+//!
+//! # Notes
+//! - Shares the NEON widening kernel of `dsdot`.
+//! - If `n == 0`, the function returns `sb`; invalid arguments return `0.0`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::errors::Check;
+use crate::level1::dsdot::dsdot_kernel;
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn sdsdot(
+    n       : usize,
+    sb      : f32,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize
+) -> f32 {
+    // quick return
+    if n == 0 { return sb; }
+
+    if !Check::new("sdsdot")
+        .vec(3, x.len(), n, incx)
+        .vec(5, y.len(), n, incy)
+        .ok()
+    {
+        return 0.0;
+    }
+
+    (sb as f64 + dsdot_kernel(n, x, incx, y, incy)) as f32
+}
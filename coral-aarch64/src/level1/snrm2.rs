@@ -10,34 +10,28 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of elements in the vector.
 //! - `x`    (&[f32]) : Input slice containing vector elements.
-//! - `incx` (usize)  : Stride between consecutive elements of $x$.
+//! - `incx` (isize)  : Stride between consecutive elements of $x$.
 //!
 //! # Returns
 //! - `f32` Euclidean norm of the selected vector elements.
 //!
 //! # Notes
-//! - Uses the scaled sum-of-squares algorithm to avoid overflow and underflow.
-//! - For `incx == 1`, [`snrm2`] uses unrolled NEON SIMD instructions for optimized 
-//!   performance on AArch64.
+//! - Uses Blue's algorithm: three sums of squares (small, medium, big) with
+//!   exact power-of-two scalings, so nothing overflows or underflows and the
+//!   loop needs no division.
+//! - For `incx == 1`, [`snrm2`] uses NEON lanes for each of the three sums.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0` or `incx == 0`, the function returns `0.0f32`.
+//! - A negative `incx` gives the same result as `|incx|`.
+//! - The `reproducible` feature scales by a power of two instead, and sums the
+//!   squares in a fixed order.
 //!
 //! # Author
 //! Deval Deliwala
 
-#[cfg(target_arch = "aarch64")] 
-use core::arch::aarch64::{ 
-    vld1q_f32,
-    vdupq_n_f32,
-    vaddvq_f32,
-    vabsq_f32, 
-    vmulq_f32, 
-    vmaxq_f32, 
-    vmaxvq_f32, 
-    vfmaq_f32, 
-};
-use crate::level1::nrm2_helpers::upd_f32;
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::level1::nrm2_helpers::{BlueF32, blue_unit_f32};
+use crate::errors::Check;
+use crate::reproducible;
 
 
 #[inline]
@@ -45,106 +39,40 @@ use crate::level1::assert_length_helpers::required_len_ok;
 pub fn snrm2(
     n       : usize, 
     x       : &[f32], 
-    incx    : usize
+    incx    : isize
 ) -> f32 { 
     // quick return 
     if n == 0 || incx == 0 { 
         return 0.0; 
     } 
 
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-
-    let mut scale : f32 = 0.0; 
-    let mut ssq   : f32 = 1.0; 
-
-    unsafe { 
-        // fast path 
-        if incx == 1 { 
-            let mut i = 0; 
-            while i + 16 <= n { 
-                let v0 = vld1q_f32(x.as_ptr().add(i)); 
-                let v1 = vld1q_f32(x.as_ptr().add(i + 4)); 
-                let v2 = vld1q_f32(x.as_ptr().add(i + 8)); 
-                let v3 = vld1q_f32(x.as_ptr().add(i + 12));  
-
-                let a0 = vabsq_f32(v0); 
-                let a1 = vabsq_f32(v1); 
-                let a2 = vabsq_f32(v2); 
-                let a3 = vabsq_f32(v3); 
-
-                let m01 = vmaxq_f32(a0, a1); 
-                let m23 = vmaxq_f32(a2, a3); 
-                let m   = vmaxq_f32(m01, m23); 
-                let chunk_max = vmaxvq_f32(m); 
-
-                if chunk_max > 0.0 { 
-                    let inv  = 1.0 / chunk_max; 
-                    let vinv = vdupq_n_f32(inv); 
-
-                    // normalize
-                    let n0 = vmulq_f32(a0, vinv); 
-                    let n1 = vmulq_f32(a1, vinv); 
-                    let n2 = vmulq_f32(a2, vinv); 
-                    let n3 = vmulq_f32(a3, vinv); 
+    if !Check::new("snrm2")
+        .vec(2, x.len(), n, incx)
+        .ok()
+    {
+        return 0.0;
+    }
 
-                    let mut s = vdupq_n_f32(0.0);
-                    s = vfmaq_f32(s, n0, n0);
-                    s = vfmaq_f32(s, n1, n1); 
-                    s = vfmaq_f32(s, n2, n2); 
-                    s = vfmaq_f32(s, n3, n3); 
+    // the norm does not depend on the direction
+    let incx = incx.unsigned_abs();
 
-                    let chunk_ssq = vaddvq_f32(s);
-                    upd_f32(&mut scale, &mut ssq, chunk_max, chunk_ssq);
-                }
-                
-                i += 16; 
-            }
+    if cfg!(feature = "reproducible") {
+        return reproducible::snrm2(n, x, incx);
+    }
 
-            // tail
-            while i < n { 
-                let xi = *x.as_ptr().add(i);  
-                if xi != 0.0 { 
-                    let absxi = xi.abs(); 
-                    if scale < absxi { 
-                        let r = scale / absxi; 
-                        ssq   = 1.0 + ssq * (r * r); 
-                        scale = absxi; 
-                    } else if scale > 0.0 { 
-                        let r = absxi / scale;
-                        ssq  += r * r; 
-                    } else { 
-                        scale = absxi; 
-                    }
-                }
-                i += 1; 
-            }
-        } else { 
+    let acc = unsafe {
+        // fast path
+        if incx == 1 {
+            blue_unit_f32(x.as_ptr(), n)
+        } else {
             // non unit stride
-            
-            let mut ix = 0; 
-            for _ in 0..n { 
-                let xi = *x.get_unchecked(ix);
-
-                if xi != 0.0 { 
-                    let absxi = xi.abs();
-
-                    if scale < absxi { 
-                        let r = scale / absxi; 
-                        ssq   = 1.0 + ssq * (r * r); 
-                        scale = absxi; 
-                    } else if scale > 0.0 { 
-                        let r = absxi / scale; 
-                        ssq  += r * r; 
-                    } else { 
-                        scale = absxi;
-                    }
-                }
-
-                ix += incx;
+            let mut acc = BlueF32::default();
+            for i in 0..n {
+                acc.push(*x.get_unchecked(i * incx));
             }
+            acc
         }
-    }
+    };
 
-    scale * ssq.sqrt()
+    acc.finish()
 }
-
@@ -11,7 +11,7 @@
 //! - `n`     (usize)      : Number of elements to scale. 
 //! - `alpha` (f32)        : Scalar multiplier. 
 //! - `x`     (&mut [f32]) : Input/output slice containing vector elements. 
-//! - `incx`  (usize)      : Stride between consecutive elements of $x$. 
+//! - `incx`  (isize)      : Stride between consecutive elements of $x$. 
 //!
 //! # Returns 
 //! - Nothing. The contents of $x$ are updated in place.
@@ -19,24 +19,31 @@
 //! # Notes 
 //! - For `incx == 1`, [`sscal`] does not perform SIMD or unrolling; LLVM is enough. 
 //! - For non unit strides, fallbacks to scalar loop. 
-//! - If `n == 0` or `incx == 0`, the function returns immediately.
+//! - If `n == 0` or `incx <= 0`, the function returns immediately.
 //!
 //! # Author 
 //! Deval Deliwala
 
-use crate::level1::assert_length_helpers::required_len_ok; 
+use crate::errors::Check;
 
 #[inline(always)]
 pub fn sscal(
     n       : usize,
     alpha   : f32, 
     x       : &mut [f32],
-    incx    : usize
+    incx    : isize
 ) {
     // quick return
-    if n == 0 || incx == 0 { return; }
+    if n == 0 || incx <= 0 { return; }
 
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("sscal")
+        .vec(3, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    let incx = incx as usize;
 
     // fast path 
     if incx == 1 {
@@ -0,0 +1,42 @@
+//! Negative increments.
+//!
+//! With `inc < 0` element `i` of an `n`-vector lives at `(n - 1 - i) * |inc|`,
+//! as in reference BLAS. Two vectors whose increments share a sign pair up
+//! the same entries as with `|inc|`, so only opposite signs need these
+//! unit-stride copies of the negative-stride vector.
+//!
+//! A zero increment makes every entry the first one. It pairs with a
+//! negative increment through the same copy, and is otherwise walked in
+//! place, in order, since a copy of an updated entry would keep only the
+//! last update.
+
+/// offset in scalars of logical entry `i`, `w` scalars per entry.
+#[inline(always)]
+fn offset(i: usize, n: usize, inc: isize, w: usize) -> usize {
+    let step = inc.unsigned_abs();
+
+    if inc >= 0 { w * i * step } else { w * (n - 1 - i) * step }
+}
+
+/// unit-stride copy of the `n` entries of `x` at stride `inc`, `w` scalars
+/// each, in logical order.
+#[inline]
+pub(crate) fn gather<T: Copy>(x: &[T], n: usize, inc: isize, w: usize) -> Vec<T> {
+    let mut buf = Vec::with_capacity(n * w);
+
+    for i in 0..n {
+        let o = offset(i, n, inc, w);
+        buf.extend_from_slice(&x[o..o + w]);
+    }
+    buf
+}
+
+/// writes the unit-stride `buf` back to the `n` entries of `x` at stride
+/// `inc`, `w` scalars each.
+#[inline]
+pub(crate) fn scatter<T: Copy>(buf: &[T], x: &mut [T], n: usize, inc: isize, w: usize) {
+    for i in 0..n {
+        let o = offset(i, n, inc, w);
+        x[o..o + w].copy_from_slice(&buf[w * i..w * i + w]);
+    }
+}
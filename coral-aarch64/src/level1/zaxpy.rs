@@ -11,9 +11,9 @@
 //! - `n`     (usize)      : Number of complex elements to process.
 //! - `alpha` ([f64; 2])   : Complex scalar multiplier given as `[real, imag]`.
 //! - `x`     (&[f64])     : Input slice containing interleaved complex vector elements.
-//! - `incx`  (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx`  (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //! - `y`     (&mut [f64]) : Input/output slice containing interleaved complex vector elements.
-//! - `incy`  (usize)      : Stride between consecutive complex elements of $y$; complex units. 
+//! - `incy`  (isize)      : Stride between consecutive complex elements of $y$; complex units. 
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place.
@@ -23,6 +23,8 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0` or `alpha == [0.0, 0.0]`, the function returns immediately.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -36,7 +38,8 @@ use core::arch::aarch64::{
     vst2q_f64,
     float64x2x2_t
 };
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 
 
 #[inline(always)]
@@ -45,9 +48,9 @@ pub fn zaxpy(
     n       : usize,
     alpha   : [f64; 2],
     x       : &[f64], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &mut [f64],
-    incy    : usize
+    incy    : isize
 ) {
     let ar = alpha[0]; // real part 
     let ai = alpha[1]; // imag part 
@@ -57,9 +60,31 @@ pub fn zaxpy(
         return;
     }
 
-    debug_assert!(incx > 0 && incy > 0, "BLAS increments must be non-zero");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("zaxpy")
+        .cvec(3, x.len(), n, incx)
+        .cvec(5, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 2);
+        zaxpy(n, alpha, &xs, 1, y, incy);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        zaxpy(n, alpha, x, incx, &mut ys, 1);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     unsafe {
         let ar_v = vdupq_n_f64(ar);
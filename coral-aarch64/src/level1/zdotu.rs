@@ -10,9 +10,9 @@
 //! # Arguments
 //! - `n`    (usize)  : Number of complex elements in the vectors.
 //! - `x`    (&[f64]) : Input slice containing interleaved complex vector elements
-//! - `incx` (usize)  : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx` (isize)  : Stride between consecutive complex elements of $x$; complex units. 
 //! - `y`    (&[f64]) : Input slice containing interleaved complex vector elements.
-//! - `incy` (usize)  : Stride between consecutive complex elements of $y$; complex units. 
+//! - `incy` (isize)  : Stride between consecutive complex elements of $y$; complex units. 
 //!
 //! # Returns
 //! - `[f64; 2]` complex result of the dot product, `[real, imag]`.
@@ -22,6 +22,8 @@
 //!   for optimized performance on AArch64.
 //! - For non unit strides, the function falls back to a scalar loop.
 //! - If `n == 0`, the function returns `[0.0, 0.0]`.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -37,7 +39,8 @@ use core::arch::aarch64::{
     vuzp1q_f64, 
     vuzp2q_f64,
 };
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 
 #[inline]
@@ -45,16 +48,35 @@ use crate::level1::assert_length_helpers::required_len_ok_cplx;
 pub fn zdotu(
     n       : usize,
     x       : &[f64], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &[f64], 
-    incy    : usize
+    incy    : isize
 ) -> [f64; 2] {
     // quick return 
     if n == 0 { return [0.0, 0.0]; }
 
-    debug_assert!(incx > 0 && incy > 0, "increments must be nonzero");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y too short for n/incy");
+    if !Check::new("zdotu")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return [0.0, 0.0];
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let xs = gather(x, n, incx, 2);
+        return zdotu(n, &xs, 1, y, incy);
+    }
+    if incy < 0 && incx >= 0 {
+        let ys = gather(y, n, incy, 2);
+        return zdotu(n, x, incx, &ys, 1);
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
 
     let px = x.as_ptr();
     let py = y.as_ptr();
@@ -11,7 +11,7 @@
 //! - `n`     (usize)      : Number of complex elements to scale. 
 //! - `alpha` (f64)        : Real scalar multiplier. 
 //! - `x`     (&mut [f64]) : Input/output slice containing interleaved complex vector elements. 
-//! - `incx`  (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx`  (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //!
 //! # Returns 
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -19,11 +19,13 @@
 //! # Notes 
 //! - For `incx == 1`, [`zdscal`] uses NEON SIMD instructions for optimized performance on AArch64. 
 //! - For non unit strides, the function falls back to a scalar loop. 
-//! - If `n == 0` or `incx == 0`, the function returns immediately.
+//! - If `n == 0` or `incx <= 0`, the function returns immediately.
 //!
 //! # Author 
 //! Deval Deliwala
 
+use crate::errors::Check;
+
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{ 
     vdupq_n_f64, 
@@ -31,7 +33,6 @@ use core::arch::aarch64::{
     vmulq_f64, 
     vst1q_f64
 }; 
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
 
 #[inline] 
 #[cfg(target_arch = "aarch64")]
@@ -39,12 +40,19 @@ pub fn zdscal(
     n       : usize,
     alpha   : f64,
     x       : &mut [f64], 
-    incx    : usize
+    incx    : isize
 ) {
     // quick return
-    if n == 0 || incx == 0 { return; }
+    if n == 0 || incx <= 0 { return; }
+
+    if !Check::new("zdscal")
+        .cvec(3, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
+    let incx = incx as usize;
 
     // fast path
     if incx == 1 {
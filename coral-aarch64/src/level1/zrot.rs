@@ -0,0 +1,196 @@
+//! `ROT`. Applies a plane rotation with a real cosine and a complex sine to
+//! two complex double precision vectors.
+//!
+//! \\[
+//! x_i' = c x_i + s y_i
+//! \\]
+//! \\[
+//! y_i' = c y_i - \bar{s} x_i
+//! \\]
+//!
+//! This function implements the LAPACK [`zrot`] routine, the companion of
+//! [`zrotg`](fn@crate::level1::zrotg), replacing elements of vectors $x$ and
+//! $y$ over $n$ complex entries with specified strides.
+//!
+//! # Arguments
+//! - `n`    (usize)      : Number of complex elements to process.
+//! - `x`    (&mut [f64]) : Input/output slice containing interleaved complex vector elements.
+//! - `incx` (isize)      : Stride between consecutive complex elements of $x$; complex units.
+//! - `y`    (&mut [f64]) : Input/output slice containing interleaved complex vector elements.
+//! - `incy` (isize)      : Stride between consecutive complex elements of $y$; complex units.
+//! - `c`    (f64)        : Real cosine component of the rotation.
+//! - `s`    ([f64; 2])   : Complex sine component of the rotation, given as `[real, imag]`.
+//!
+//! # Returns
+//! - Nothing. The contents of $x$ and $y$ are updated in place.
+//!
+//! # Notes
+//! - For `incx == 1 && incy == 1`, [`zrot`] deinterleaves two complex entries
+//!   at a time with NEON `ld2`/`st2` for optimized performance on AArch64.
+//! - For non unit strides, the function falls back to a scalar loop.
+//! - If `n == 0`, the function returns immediately.
+//! - A negative increment walks its vector from the end and a zero one repeats
+//!   its first entry, as in reference BLAS.
+//!
+//! # Author
+//! Deval Deliwala
+
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::{
+    float64x2_t,
+    float64x2x2_t,
+    vld2q_f64,
+    vst2q_f64,
+    vdupq_n_f64,
+    vmulq_f64,
+    vfmaq_f64,
+    vfmsq_f64,
+};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
+
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn zrot(
+    n       : usize,
+    x       : &mut [f64],
+    incx    : isize,
+    y       : &mut [f64],
+    incy    : isize,
+    c       : f64,
+    s       : [f64; 2]
+) {
+    // quick return
+    if n == 0 { return; }
+
+    if !Check::new("zrot")
+        .cvec(2, x.len(), n, incx)
+        .cvec(4, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative against positive or zero: walk the negative one through a copy
+    if incx < 0 && incy >= 0 {
+        let mut xs = gather(x, n, incx, 2);
+        zrot(n, &mut xs, 1, y, incy, c, s);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+    if incy < 0 && incx >= 0 {
+        let mut ys = gather(y, n, incy, 2);
+        zrot(n, x, incx, &mut ys, 1, c, s);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    // same signs pair the same entries as |inc|
+    // and a zero one repeats its first entry, updated in order
+    let incx = incx.unsigned_abs();
+    let incy = incy.unsigned_abs();
+
+    let [sr, si] = s;
+
+    let px = x.as_mut_ptr();
+    let py = y.as_mut_ptr();
+
+    // fast path
+    if incx == 1 && incy == 1 {
+        unsafe {
+            let cv  = vdupq_n_f64(c);
+            let srv = vdupq_n_f64(sr);
+            let siv = vdupq_n_f64(si);
+
+            let mut i = 0;
+            while i + 4 <= n {
+                rot2(px.add(2 * i),     py.add(2 * i),     cv, srv, siv);
+                rot2(px.add(2 * i + 4), py.add(2 * i + 4), cv, srv, siv);
+
+                i += 4;
+            }
+
+            while i + 2 <= n {
+                rot2(px.add(2 * i), py.add(2 * i), cv, srv, siv);
+
+                i += 2;
+            }
+
+            // tail
+            while i < n {
+                rot1(px.add(2 * i), py.add(2 * i), c, sr, si);
+
+                i += 1;
+            }
+        }
+        return;
+    }
+
+    // non unit stride
+    unsafe {
+        let mut ix = 0;
+        let mut iy = 0;
+
+        for _ in 0..n {
+            rot1(px.add(2 * ix), py.add(2 * iy), c, sr, si);
+
+            ix += incx;
+            iy += incy;
+        }
+    }
+}
+
+/// rotates two contiguous complex entries of `x` and `y`.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn rot2(
+    px  : *mut f64,
+    py  : *mut f64,
+    c   : float64x2_t,
+    sr  : float64x2_t,
+    si  : float64x2_t,
+) {
+    unsafe {
+        let xv = vld2q_f64(px);
+        let yv = vld2q_f64(py);
+
+        let (xr, xi) = (xv.0, xv.1);
+        let (yr, yi) = (yv.0, yv.1);
+
+        // x' = c x + s y
+        let xnr = vfmsq_f64(vfmaq_f64(vmulq_f64(c, xr), sr, yr), si, yi);
+        let xni = vfmaq_f64(vfmaq_f64(vmulq_f64(c, xi), sr, yi), si, yr);
+
+        // y' = c y - conj(s) x
+        let ynr = vfmsq_f64(vfmsq_f64(vmulq_f64(c, yr), sr, xr), si, xi);
+        let yni = vfmaq_f64(vfmsq_f64(vmulq_f64(c, yi), sr, xi), si, xr);
+
+        vst2q_f64(px, float64x2x2_t(xnr, xni));
+        vst2q_f64(py, float64x2x2_t(ynr, yni));
+    }
+}
+
+/// rotates one complex entry of `x` and `y`.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn rot1(
+    px  : *mut f64,
+    py  : *mut f64,
+    c   : f64,
+    sr  : f64,
+    si  : f64,
+) {
+    unsafe {
+        let xr = *px;
+        let xi = *px.add(1);
+        let yr = *py;
+        let yi = *py.add(1);
+
+        *px        = c * xr + (sr * yr - si * yi);
+        *px.add(1) = c * xi + (sr * yi + si * yr);
+        *py        = c * yr - (sr * xr + si * xi);
+        *py.add(1) = c * yi - (sr * xi - si * xr);
+    }
+}
@@ -11,7 +11,7 @@
 //! - `n`     (usize)      : Number of complex elements to scale. 
 //! - `alpha` ([f64; 2])   : Complex scalar multiplier given as `[real, imag]`. 
 //! - `x`     (&mut [f64]) : Input/output slice containing interleaved complex vector elements. 
-//! - `incx`  (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx`  (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //!
 //! # Returns 
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -19,7 +19,7 @@
 //! # Notes 
 //! - For `incx == 1`, [`zscal`] uses NEON SIMD instructions for optimized performance on AArch64. 
 //! - For non unit strides, the function falls back to a scalar loop. 
-//! - If `n == 0` or `incx == 0`, the function returns immediately.
+//! - If `n == 0` or `incx <= 0`, the function returns immediately.
 //!
 //! # Author 
 //! Deval Deliwala
@@ -34,7 +34,7 @@ use core::arch::aarch64::{
     vst2q_f64, 
     float64x2x2_t
 };
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
+use crate::errors::Check;
 
 #[inline(always)]
 #[cfg(target_arch = "aarch64")]
@@ -42,12 +42,19 @@ pub fn zscal(
     n       : usize, 
     alpha   : [f64; 2],
     x       : &mut [f64], 
-    incx    : usize
+    incx    : isize
 ) {
     // quick return
-    if n == 0 || incx == 0 { return; }
+    if n == 0 || incx <= 0 { return; }
 
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
+    if !Check::new("zscal")
+        .cvec(3, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    let incx = incx as usize;
 
     let a_real = alpha[0];
     let a_imag = alpha[1];
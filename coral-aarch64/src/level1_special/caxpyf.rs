@@ -687,7 +687,7 @@ pub fn caxpyf(
                 if xr != 0.0 || xi != 0.0 {
                     let col_ptr = matrix.as_ptr().add(2 * (col_idx * lda));
                     let col = core::slice::from_raw_parts(col_ptr, 2 * n_rows);
-                    caxpy(n_rows, [xr, xi], col, 1, y, incy);
+                    caxpy(n_rows, [xr, xi], col, 1, y, incy as isize);
                 }
             }
         }
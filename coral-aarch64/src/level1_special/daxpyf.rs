@@ -24,6 +24,7 @@
 //! - For unit strides (`incx == 1`, `incy == 1`), the kernel uses
 //!   SIMD microkernels with blocking for high performance.
 //! - For non-unit strides, it falls back to scalar [`daxpy`] updates.
+//! - [`daxpyf_dot2`] is the compensated sibling, carrying a per-row error term.
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{
@@ -36,6 +37,7 @@ use core::arch::aarch64::{
 use crate::level1::assert_length_helpers::required_len_ok;
 use crate::level2::assert_length_helpers::required_len_ok_matrix;
 use crate::level1::daxpy::daxpy;
+use crate::level1::compensated::{two_prod_f64, two_sum_f64};
 
 const MR: usize = 128;
 const NR: usize = 8;
@@ -509,10 +511,51 @@ pub fn daxpyf(
                 if scaled != 0.0 {
                     let col_ptr = matrix.as_ptr().add(col_idx * lda);
                     let col = core::slice::from_raw_parts(col_ptr, n_rows);
-                    daxpy(n_rows, scaled, col, 1, y, incy);
+                    daxpy(n_rows, scaled, col, 1, y, incy as isize);
                 }
             }
         }
     }
 }
 
+/// Compensated [`daxpyf`] over unit-stride `y`. Column updates go through
+/// `TwoProduct` and `TwoSum`, with the rounding errors of row `i` gathered in
+/// `comp[i]`; `y[i] + comp[i]` is the sum in twice the working precision.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn daxpyf_dot2(
+    n_rows : usize,
+    n_cols : usize,
+    x      : &[f64],
+    incx   : usize,
+    matrix : &[f64],
+    lda    : usize,
+    y      : &mut [f64],
+    comp   : &mut [f64],
+) {
+    // quick return
+    if n_rows == 0 || n_cols == 0 { return; }
+
+    debug_assert!(incx > 0, "incx must be non-zero");
+    debug_assert!(lda >= n_rows, "lda must be >= n_rows");
+    debug_assert!(required_len_ok(x.len(), n_cols, incx), "x too short for n_cols/incx");
+    debug_assert!(
+        required_len_ok_matrix(matrix.len(), n_rows, n_cols, lda),
+        "matrix not large enough for n_rows, n_cols, lda"
+    );
+    debug_assert!(y.len() >= n_rows && comp.len() >= n_rows, "y or comp too small for n_rows");
+
+    for col_idx in 0..n_cols {
+        let xj = x[col_idx * incx];
+        if xj == 0.0 { continue; }
+
+        let col = &matrix[col_idx * lda .. col_idx * lda + n_rows];
+        for ((yi, ci), &aij) in y[..n_rows].iter_mut().zip(comp[..n_rows].iter_mut()).zip(col) {
+            let (p, e) = two_prod_f64(aij, xj);
+            let (s, q) = two_sum_f64(*yi, p);
+
+            *yi  = s;
+            *ci += q + e;
+        }
+    }
+}
@@ -16,6 +16,7 @@
 //! # Notes
 //! - Fast path when `incx == 1` uses NEON + blocking.
 //! - Otherwise falls back to scalar strided dots.
+//! - [`ddotf_dot2`] is the compensated sibling, one `Dot2` per column.
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{
@@ -28,6 +29,7 @@ use core::arch::aarch64::{
 use crate::level1::assert_length_helpers::required_len_ok;
 use crate::level2::assert_length_helpers::required_len_ok_matrix;
 use crate::level1::ddot::ddot;
+use crate::level1::compensated::{dot2_f64, two_sum_f64};
 
 const MC: usize = 128;
 const NR: usize = 8;
@@ -588,9 +590,43 @@ pub fn ddotf(
         unsafe {
             let col_ptr = matrix.as_ptr().add(col_idx * lda);
             let col = core::slice::from_raw_parts(col_ptr, n_rows);
-            let sum = ddot(n_rows, col, 1, x, incx);
+            let sum = ddot(n_rows, col, 1, x, incx as isize);
             *out.get_unchecked_mut(col_idx) += sum;
         }
     }
 }
 
+/// Compensated [`ddotf`]. Each column dot is a `Dot2` and is added to `out`
+/// with a `TwoSum`, so `out[j]` picks up a single rounding.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn ddotf_dot2(
+    n_rows : usize,
+    n_cols : usize,
+    matrix : &[f64],
+    lda    : usize,
+    x      : &[f64],
+    incx   : usize,
+    out    : &mut [f64],
+) {
+    // quick return
+    if n_rows == 0 || n_cols == 0 { return; }
+
+    debug_assert!(incx > 0, "incx must be non-zero");
+    debug_assert!(lda >= n_rows, "lda must be >= n_rows");
+    debug_assert!(required_len_ok(x.len(), n_rows, incx), "x too short for n_rows/incx");
+    debug_assert!(
+        required_len_ok_matrix(matrix.len(), n_rows, n_cols, lda),
+        "matrix not large enough for n_rows, n_cols, lda"
+    );
+    debug_assert!(out.len() >= n_cols, "out too small for n_cols");
+
+    for (col_idx, o) in out[..n_cols].iter_mut().enumerate() {
+        let col = &matrix[col_idx * lda .. col_idx * lda + n_rows];
+
+        let (hi, lo) = dot2_f64(n_rows, col, 1, x, incx);
+        let (s, e)   = two_sum_f64(*o, hi);
+
+        *o = s + (e + lo);
+    }
+}
@@ -15,6 +15,7 @@
 //!
 //! # Notes
 //! - For unit strides, uses NEON micro-kernels with MR x NR paneling.
+//! - [`saxpyf_dot2`] is the compensated sibling, carrying a per-row error term.
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::{
@@ -27,6 +28,7 @@ use core::arch::aarch64::{
 use crate::level1::assert_length_helpers::required_len_ok;
 use crate::level2::assert_length_helpers::required_len_ok_matrix;
 use crate::level1::saxpy::saxpy;
+use crate::level1::compensated::{two_prod_f32, two_sum_f32};
 
 const MR:  usize = 128;  // rows per panel
 const NR:  usize = 128;  // cols per panel
@@ -505,9 +507,50 @@ pub fn saxpyf(
             if scaled != 0.0 {
                 let col_ptr = matrix.as_ptr().add(col_idx * lda);
                 let col = core::slice::from_raw_parts(col_ptr, n_rows);
-                saxpy(n_rows, scaled, col, 1, y, incy);
+                saxpy(n_rows, scaled, col, 1, y, incy as isize);
             }
         }
     }
 }
 
+/// Compensated [`saxpyf`] over unit-stride `y`. Column updates go through
+/// `TwoProduct` and `TwoSum`, with the rounding errors of row `i` gathered in
+/// `comp[i]`; `y[i] + comp[i]` is the sum in twice the working precision.
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn saxpyf_dot2(
+    n_rows : usize,
+    n_cols : usize,
+    x      : &[f32],
+    incx   : usize,
+    matrix : &[f32],
+    lda    : usize,
+    y      : &mut [f32],
+    comp   : &mut [f32],
+) {
+    // quick return
+    if n_rows == 0 || n_cols == 0 { return; }
+
+    debug_assert!(incx > 0, "incx must be non-zero");
+    debug_assert!(lda >= n_rows, "lda must be >= n_rows");
+    debug_assert!(required_len_ok(x.len(), n_cols, incx), "x too short for n_cols/incx");
+    debug_assert!(
+        required_len_ok_matrix(matrix.len(), n_rows, n_cols, lda),
+        "matrix not large enough for n_rows, n_cols, lda"
+    );
+    debug_assert!(y.len() >= n_rows && comp.len() >= n_rows, "y or comp too small for n_rows");
+
+    for col_idx in 0..n_cols {
+        let xj = x[col_idx * incx];
+        if xj == 0.0 { continue; }
+
+        let col = &matrix[col_idx * lda .. col_idx * lda + n_rows];
+        for ((yi, ci), &aij) in y[..n_rows].iter_mut().zip(comp[..n_rows].iter_mut()).zip(col) {
+            let (p, e) = two_prod_f32(aij, xj);
+            let (s, q) = two_sum_f32(*yi, p);
+
+            *yi  = s;
+            *ci += q + e;
+        }
+    }
+}
@@ -688,7 +688,7 @@ pub fn zaxpyf(
                 if xr != 0.0 || xi != 0.0 {
                     let col_ptr = matrix.as_ptr().add(2 * (col_idx * lda));
                     let col = core::slice::from_raw_parts(col_ptr, 2 * n_rows);
-                    zaxpy(n_rows, [xr, xi], col, 1, y, incy);
+                    zaxpy(n_rows, [xr, xi], col, 1, y, incy as isize);
                 }
             }
         }
@@ -654,7 +654,7 @@ pub fn zdotuf(
             let col_ptr = matrix.as_ptr().add(2 * (col_idx * lda));
             let col = core::slice::from_raw_parts(col_ptr, 2 * n_rows);
 
-            let s = zdotu(n_rows, col, 1, x, incx);
+            let s = zdotu(n_rows, col, 1, x, incx as isize);
 
             let po = out.as_mut_ptr().add(2 * col_idx);
             *po.add(0) += s[0];
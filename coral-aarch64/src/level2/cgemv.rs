@@ -15,10 +15,10 @@
 //! - `matrix` (&[f32])     : Input slice containing the interleaved matrix $A$.
 //! - `lda`    (usize)      : Leading dimension of $A$; complex units. 
 //! - `x`      (&[f32])     : Input complex vector of length `n_cols`.
-//! - `incx`   (usize)      : Stride between consecutive complex elements of $x$; complex units. 
+//! - `incx`   (isize)      : Stride between consecutive complex elements of $x$; complex units. 
 //! - `beta`   ([f32; 2])   : Complex scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f32]) : Input/output complex vector of length `n_rows`.
-//! - `incy`   (usize)      : Stride between consecutive complex elements of $y$; complex units. 
+//! - `incy`   (isize)      : Stride between consecutive complex elements of $y$; complex units. 
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place.
@@ -56,6 +56,8 @@
 
 
 use crate::enums::CoralTranspose;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     cgemv_transpose::cgemv_transpose, 
     cgemv_notranspose::cgemv_notranspose, 
@@ -72,11 +74,40 @@ pub fn cgemv(
     matrix  : &[f32], 
     lda     : usize, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     beta    : [f32; 2], 
     y       : &mut [f32], 
-    incy    : usize
+    incy    : isize
 ) { 
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("cgemv")
+        .ld(6, lda, n_rows)
+        .inc(8, incx)
+        .inc(11, incy)
+        .cmat(5, matrix.len(), n_rows, n_cols, lda)
+        .cvec(7, x.len(), len_x, incx)
+        .cvec(10, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 2);
+        let mut ys = gather(y, len_y, incy, 2);
+        cgemv(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 2);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     match trans { 
         CoralTranspose::NoTranspose         => cgemv_notranspose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::Transpose           => cgemv_transpose    (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
@@ -88,8 +88,8 @@ pub(crate) fn cgemv_transpose(
 
     // y := beta * y
     if !(beta[0] == 1.0 && beta[1] == 0.0)  {
-        if beta[1] == 0.0 { csscal(n_cols, beta[0], y, incy); }
-        else              { cscal (n_cols, beta   , y, incy); }
+        if beta[1] == 0.0 { csscal(n_cols, beta[0], y, incy as isize); }
+        else              { cscal (n_cols, beta   , y, incy as isize); }
     }
     if alpha[0] == 0.0 && alpha[1] == 0.0 { return; }
 
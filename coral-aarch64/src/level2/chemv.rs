@@ -20,10 +20,10 @@
 //! - `matrix` (&[f32])          : Input slice containing the matrix $A$; interleaved complex.
 //! - `lda`    (usize)           : Leading dimension of $A$. 
 //! - `x`      (&[f32])          : Input complex vector of length $n$. 
-//! - `incx`   (usize)           : Stride between consecutive complex elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive complex elements of $x$.
 //! - `beta`   ([f32; 2])        : Scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f32])      : Input/output complex vector of complex length $n$
-//! - `incy`   (usize)           : Stride between consecutive complex elements of $y$.
+//! - `incy`   (isize)           : Stride between consecutive complex elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place. 
@@ -37,6 +37,7 @@
 //!   column panels of width `NC`. Off-diagonal panels are handled with a fused
 //!   `caxpyf`/`cdotcf` kernel on packed rectangles that lie entirely within the stored
 //!   triangle; diagonal blocks are handled by a triangular microkernel.
+//! - A negative increment walks its vector from the end, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -70,14 +71,12 @@
 use core::slice;
 
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level1::cscal::cscal;
 use crate::level1_special::caxpyf::caxpyf;
 use crate::level1_special::cdotcf::cdotcf;
 
-// assert length helpers
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
-use crate::level2::assert_length_helpers::required_len_ok_matrix_cplx;
-
 // contiguous packing helpers
 use crate::level2::{
     vector_packing::{
@@ -100,25 +99,39 @@ pub fn chemv(
     matrix  : &[f32],
     lda     : usize,
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     beta    : [f32; 2],
     y       : &mut [f32],
-    incy    : usize,
+    incy    : isize,
 ) {
+    if !Check::new("chemv")
+        .ld(5, lda, n)
+        .inc(7, incx)
+        .inc(10, incy)
+        .cmat(4, matrix.len(), n, n, lda)
+        .cvec(6, x.len(), n, incx)
+        .cvec(9, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n, incx, 2);
+        let mut ys = gather(y, n, incy, 2);
+        chemv(uplo, n, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, n, incy, 2);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     // quick return
     if n == 0 { return; }
     if alpha == [0.0, 0.0] && beta == [1.0, 0.0] { return; }
 
-
-    debug_assert!(incx > 0 && incy > 0, "vector increments must be nonzero");
-    debug_assert!(lda >= n, "matrix leading dimension must be >= n");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y too short for n/incy");
-    debug_assert!(
-        required_len_ok_matrix_cplx(matrix.len(), n, n, lda),
-        "matrix too short for given n and lda"
-    );
-
     // pack x into contiguous buff and scale by alpha 
     let mut xbuffer: Vec<f32> = Vec::new(); 
     pack_and_scale_c32(n, alpha, x, incx, &mut xbuffer); 
@@ -20,7 +20,7 @@
 //! - `n`      (usize)           : Dimension of the matrix $A$.
 //! - `alpha`  (f32)             : Real scalar multiplier applied to the outer product $x x^H$.
 //! - `x`      (&[f32])          : Input slice containing the complex vector $x$
-//! - `incx`   (usize)           : Stride between consecutive complex elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive complex elements of $x$.
 //! - `matrix` (&mut [f32])      : Input/output slice containing the matrix $A$.
 //! - `lda`    (usize)           : Leading dimension of $A$.
 //!
@@ -52,10 +52,9 @@
 
 use crate::level1::caxpy::caxpy;
 
-// assert length helpers
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
-use crate::level2::assert_length_helpers::required_len_ok_matrix_cplx;
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
@@ -64,23 +63,34 @@ pub fn cher(
     n       : usize,
     alpha   : f32,
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     matrix  : &mut [f32],
     lda     : usize,
 ) {
+    if !Check::new("cher")
+        .inc(5, incx)
+        .ld(7, lda, n)
+        .cvec(4, x.len(), n, incx)
+        .cmat(6, matrix.len(), n, n, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let xs = gather(x, n, incx, 2);
+        cher(uplo, n, alpha, &xs, 1, matrix, lda);
+        return;
+    }
+
+    let incx = incx as usize;
+
     // quick returns
     if n == 0 || alpha == 0.0 {
         return;
     }
 
-    debug_assert!(incx > 0, "incx stride must be nonzero");
-    debug_assert!(lda >= n, "leading dimension must be >= n");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x not large enough for n/incx");
-    debug_assert!(
-        required_len_ok_matrix_cplx(matrix.len(), n, n, lda),
-        "matrix not large enough for given n x n and lda"
-    );
-
     // fast path 
     if incx == 1 {
         match uplo {
@@ -163,7 +173,7 @@ pub fn cher(
                             j + 1,
                             aj,
                             std::slice::from_raw_parts(x_ptr, x.len()),
-                            incx,
+                            incx as isize,
                             std::slice::from_raw_parts_mut(
                                 a_ptr.add(2*col_start),
                                 2*(j + 1)
@@ -194,7 +204,7 @@ pub fn cher(
                             n - j,
                             aj,
                             std::slice::from_raw_parts(x_ptr.add(2*j*incx), x.len() - 2*j*incx),
-                            incx,
+                            incx as isize,
                             std::slice::from_raw_parts_mut(
                                 a_ptr.add(2*col_start),
                                 2*(n - j)
@@ -19,9 +19,9 @@
 //! - `n`      (usize)           : Dimension of the matrix $A$.
 //! - `alpha`  ([f32; 2])        : Complex scalar multiplier; `([real, imag])`.
 //! - `x`      (&[f32])          : Input slice containing complex vector $x$.
-//! - `incx`   (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive elements of $x$.
 //! - `y`      (&[f32])          : Input slice containing complex vector $y$.
-//! - `incy`   (usize)           : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)           : Stride between consecutive elements of $y$.
 //! - `matrix` (&mut [f32])      : Input/output slice containing the matrix $A$.
 //! - `lda`    (usize)           : Leading dimension of $A$. 
 //!
@@ -56,9 +56,9 @@
 //! ```
 
 use crate::level1::caxpy::caxpy;
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
-use crate::level2::assert_length_helpers::required_len_ok_matrix_cplx;
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
@@ -67,26 +67,40 @@ pub fn cher2(
     n       : usize,
     alpha   : [f32; 2],
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     y       : &[f32],
-    incy    : usize,
+    incy    : isize,
     matrix  : &mut [f32],
     lda     : usize,
 ) {
+    if !Check::new("cher2")
+        .inc(5, incx)
+        .inc(7, incy)
+        .ld(9, lda, n)
+        .cvec(4, x.len(), n, incx)
+        .cvec(6, y.len(), n, incy)
+        .cmat(8, matrix.len(), n, n, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n, incx, 2);
+        let ys = gather(y, n, incy, 2);
+        cher2(uplo, n, alpha, &xs, 1, &ys, 1, matrix, lda);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     // quick returns
     if n == 0 || (alpha[0] == 0.0 && alpha[1] == 0.0) {
         return;
     }
 
-    debug_assert!(incx > 0 && incy > 0, "incx/incy strides must be nonzero");
-    debug_assert!(lda >= n, "leading dimension must be >= n");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x not large enough for n/incx");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y not large enough for n/incy");
-    debug_assert!(
-        required_len_ok_matrix_cplx(matrix.len(), n, n, lda),
-        "matrix not large enough for given n x n and lda"
-    );
-
     // fast path 
     if incx == 1 && incy == 1 {
         match uplo {
@@ -218,7 +232,7 @@ pub fn cher2(
                                 j + 1,
                                 aj_y,
                                 std::slice::from_raw_parts(x_ptr, x.len()),
-                                incx,
+                                incx as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(j + 1)
@@ -231,7 +245,7 @@ pub fn cher2(
                                 j + 1,
                                 aj_x,
                                 std::slice::from_raw_parts(y_ptr, y.len()),
-                                incy,
+                                incy as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(j + 1)
@@ -276,7 +290,7 @@ pub fn cher2(
                                     x_ptr.add(2*j*incx), 
                                     x.len() - 2*j*incx
                                 ),
-                                incx,
+                                incx as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(n - j)
@@ -292,7 +306,7 @@ pub fn cher2(
                                     y_ptr.add(2*j*incy), 
                                     y.len() - 2*j*incy
                                 ),
-                                incy,
+                                incy as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(n - j)
@@ -15,7 +15,7 @@
 //! - `matrix`      (&[f32])          : Input slice containing the interleaved triangular complex matrix $A$.
 //! - `lda`         (usize)           : Leading dimension of $A$.
 //! - `x`           (&mut [f32])      : Input/output slice containing the interleaved complex vector $x$.
-//! - `incx`        (usize)           : Stride between consecutive complex elements of $x$.
+//! - `incx`        (isize)           : Stride between consecutive complex elements of $x$.
 //!
 //! # Returns
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -48,6 +48,8 @@
 //! ```
 
 use crate::enums::{CoralDiagonal, CoralTranspose, CoralTriangular}; 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     ctrlmv::ctrlmv, 
     ctrumv::ctrumv, 
@@ -63,8 +65,28 @@ pub fn ctrmv(
     matrix      : &[f32], 
     lda         : usize, 
     x           : &mut [f32], 
-    incx        : usize, 
+    incx        : isize, 
 ) { 
+    if !Check::new("ctrmv")
+        .ld(6, lda, n)
+        .inc(8, incx)
+        .cmat(5, matrix.len(), n, n, lda)
+        .cvec(7, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let mut xs = gather(x, n, incx, 2);
+        ctrmv(uplo, transpose, diagonal, n, matrix, lda, &mut xs, 1);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+
+    let incx = incx as usize;
+
     match uplo { 
         CoralTriangular::UpperTriangular => ctrumv(n, diagonal, transpose, matrix, lda, x, incx), 
         CoralTriangular::LowerTriangular => ctrlmv(n, diagonal, transpose, matrix, lda, x, incx), 
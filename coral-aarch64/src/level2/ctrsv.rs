@@ -16,7 +16,7 @@
 //! - `lda`         (usize)           : Leading dimension of $A$.
 //! - `x`           (&mut [f32])      : Input/output slice containing the right-hand side $b$ on
 //!                                     entry and exits as solution $x$. 
-//! - `incx`        (usize)           : Stride between consecutive complex elements of $x$.
+//! - `incx`        (isize)           : Stride between consecutive complex elements of $x$.
 //!
 //! # Returns
 //! - Nothing. $x$ is updated in place with the solution. 
@@ -49,6 +49,8 @@
 
 
 use crate::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{
     ctrlsv::ctrlsv, 
     ctrusv::ctrusv,
@@ -64,8 +66,28 @@ pub fn ctrsv(
     matrix      : &[f32], 
     lda         : usize, 
     x           : &mut [f32], 
-    incx        : usize, 
+    incx        : isize, 
 ) { 
+    if !Check::new("ctrsv")
+        .ld(6, lda, n)
+        .inc(8, incx)
+        .cmat(5, matrix.len(), n, n, lda)
+        .cvec(7, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let mut xs = gather(x, n, incx, 2);
+        ctrsv(uplo, transpose, diagonal, n, matrix, lda, &mut xs, 1);
+        scatter(&xs, x, n, incx, 2);
+        return;
+    }
+
+    let incx = incx as usize;
+
     match uplo { 
         CoralTriangular::UpperTriangular => ctrusv(n, transpose, diagonal, matrix, lda, x, incx), 
         CoralTriangular::LowerTriangular => ctrlsv(n, transpose, diagonal, matrix, lda, x, incx), 
@@ -14,10 +14,10 @@
 //! - `matrix` (&[f64])     : Input slice containing the matrix $A$.
 //! - `lda`    (usize)      : Leading dimension of $A$.
 //! - `x`      (&[f64])     : Input vector of length `n_cols`.
-//! - `incx`   (usize)      : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)      : Stride between consecutive elements of $x$.
 //! - `beta`   (f64)        : Scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f64]) : Input/output vector of length `n_rows`.
-//! - `incy`   (usize)      : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)      : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place. 
@@ -50,10 +50,13 @@
 //! ```
 
 
-use crate::enums::CoralTranspose; 
+use crate::enums::{Accuracy, CoralTranspose};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     dgemv_transpose::dgemv_transpose, 
     dgemv_notranspose::dgemv_notranspose, 
+    gemv_compensated::dgemv_compensated,
 }; 
 
 #[inline]
@@ -66,14 +69,97 @@ pub fn dgemv(
     matrix  : &[f64], 
     lda     : usize, 
     x       : &[f64], 
-    incx    : usize, 
+    incx    : isize, 
     beta    : f64, 
     y       : &mut [f64], 
-    incy    : usize
+    incy    : isize
 ) { 
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("dgemv")
+        .ld(6, lda, n_rows)
+        .inc(8, incx)
+        .inc(11, incy)
+        .mat(5, matrix.len(), n_rows, n_cols, lda)
+        .vec(7, x.len(), len_x, incx)
+        .vec(10, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 1);
+        let mut ys = gather(y, len_y, incy, 1);
+        dgemv(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     match trans { 
         CoralTranspose::NoTranspose         => dgemv_notranspose(n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::Transpose           => dgemv_transpose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::ConjugateTranspose  => dgemv_transpose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
     }
 }
+
+/// [`dgemv`] with a choice of [`Accuracy`]. `Accuracy::Fast` is [`dgemv`]
+/// itself; `Accuracy::Compensated` accumulates every entry of
+/// $\operatorname{op}(A) x$ with the fused `Dot2` kernels, so cancellation costs
+/// about twice the working precision before it shows. Arguments after
+/// `accuracy` are those of [`dgemv`].
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn dgemv_with(
+    accuracy : Accuracy,
+    trans    : CoralTranspose,
+    n_rows   : usize,
+    n_cols   : usize,
+    alpha    : f64,
+    matrix   : &[f64],
+    lda      : usize,
+    x        : &[f64],
+    incx     : isize,
+    beta     : f64,
+    y        : &mut [f64],
+    incy     : isize
+) {
+    if let Accuracy::Fast = accuracy {
+        return dgemv(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy);
+    }
+
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("dgemv_with")
+        .ld(7, lda, n_rows)
+        .inc(9, incx)
+        .inc(12, incy)
+        .mat(6, matrix.len(), n_rows, n_cols, lda)
+        .vec(8, x.len(), len_x, incx)
+        .vec(11, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 1);
+        let mut ys = gather(y, len_y, incy, 1);
+        dgemv_compensated(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 1);
+        return;
+    }
+
+    dgemv_compensated(trans, n_rows, n_cols, alpha, matrix, lda, x, incx as usize, beta, y, incy as usize);
+}
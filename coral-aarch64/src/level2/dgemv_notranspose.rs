@@ -85,7 +85,7 @@ pub(crate) fn dgemv_notranspose(
     ); 
 
     // y := beta * y 
-    if beta != 1.0  { dscal(n_rows, beta, y, incy); }
+    if beta != 1.0  { dscal(n_rows, beta, y, incy as isize); }
     if alpha == 0.0 { return; }
 
     // pack x into contiguous buffer and scale by alpha 
@@ -86,7 +86,7 @@ pub(crate) fn dgemv_transpose(
     );
 
     // y := beta * y
-    if beta != 1.0  { dscal(n_cols, beta, y, incy); }
+    if beta != 1.0  { dscal(n_cols, beta, y, incy as isize); }
     if alpha == 0.0 { return; }
 
     // pack x into contiguous buffer and scale by alpha
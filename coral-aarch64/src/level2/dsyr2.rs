@@ -17,9 +17,9 @@
 //! - `n`      (usize)           : Dimension of the matrix $A$.
 //! - `alpha`  (f64)             : Scalar multiplier applied to the outer product $x x^T$.
 //! - `x`      (&[f64])          : Input slice containing the vector $x$.
-//! - `incx`   (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive elements of $x$.
 //! - `y`      (&[f64])          : Input slice containing the vector $y$.
-//! - `incy`   (usize)           : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)           : Stride between consecutive elements of $y$.
 //! - `matrix` (&mut [f64])      : Input/output slice containing the matrix $A$.
 //! - `lda`    (usize)           : Leading dimension of $A$.
 //! 
@@ -54,9 +54,9 @@
 
 
 use crate::level1::daxpy::daxpy;
-use crate::level1::assert_length_helpers::required_len_ok;
-use crate::level2::assert_length_helpers::required_len_ok_matrix;
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
@@ -65,24 +65,38 @@ pub fn dsyr2(
     n       : usize,
     alpha   : f64,
     x       : &[f64],
-    incx    : usize,
+    incx    : isize,
     y       : &[f64],
-    incy    : usize,
+    incy    : isize,
     matrix  : &mut [f64],
     lda     : usize,
 ) {
-    if n == 0 || alpha == 0.0 {
+    if !Check::new("dsyr2")
+        .inc(5, incx)
+        .inc(7, incy)
+        .ld(9, lda, n)
+        .vec(4, x.len(), n, incx)
+        .vec(6, y.len(), n, incy)
+        .mat(8, matrix.len(), n, n, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n, incx, 1);
+        let ys = gather(y, n, incy, 1);
+        dsyr2(uplo, n, alpha, &xs, 1, &ys, 1, matrix, lda);
         return;
     }
 
-    debug_assert!(incx > 0 && incy > 0, "incx/incy strides must be nonzero");
-    debug_assert!(lda >= n, "leading dimension must be >= n");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x not large enough for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y not large enough for n/incy");
-    debug_assert!(
-        required_len_ok_matrix(matrix.len(), n, n, lda),
-        "matrix not large enough for given n x n and lda"
-    );
+    let incx = incx as usize;
+    let incy = incy as usize;
+
+    if n == 0 || alpha == 0.0 {
+        return;
+    }
 
     // fast path
     if incx == 1 && incy == 1 {
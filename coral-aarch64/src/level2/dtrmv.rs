@@ -15,7 +15,7 @@
 //! - `matrix`      (&[f64])          : Input slice containing the triangular matrix $A$.
 //! - `lda`         (usize)           : Leading dimension of $A$.
 //! - `x`           (&mut [f64])      : Input/output slice containing the vector $x$.
-//! - `incx`        (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`        (isize)           : Stride between consecutive elements of $x$.
 //!
 //! # Returns
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -48,6 +48,8 @@
 //! ```
 
 use crate::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     dtrlmv::dtrlmv, 
     dtrumv::dtrumv, 
@@ -63,8 +65,28 @@ pub fn dtrmv(
     matrix      : &[f64], 
     lda         : usize, 
     x           : &mut [f64], 
-    incx        : usize, 
+    incx        : isize, 
 ) { 
+    if !Check::new("dtrmv")
+        .ld(6, lda, n)
+        .inc(8, incx)
+        .mat(5, matrix.len(), n, n, lda)
+        .vec(7, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let mut xs = gather(x, n, incx, 1);
+        dtrmv(uplo, transpose, diagonal, n, matrix, lda, &mut xs, 1);
+        scatter(&xs, x, n, incx, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+
     match uplo { 
         CoralTriangular::UpperTriangular => dtrumv(n, diagonal, transpose, matrix, lda, x, incx), 
         CoralTriangular::LowerTriangular => dtrlmv(n, diagonal, transpose, matrix, lda, x, incx), 
@@ -17,7 +17,7 @@
 //! - `lda`         (usize)           : Leading dimension of $A$.
 //! - `x`           (&mut [f64])      : Input/output slice containing the right-hand side $b$ on
 //!                                     entry and the solution $x$ on exit.
-//! - `incx`        (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`        (isize)           : Stride between consecutive elements of $x$.
 //!
 //! # Returns
 //! - Nothing. The contents of $x$ are updated in place. 
@@ -52,6 +52,8 @@
 
 
 use crate::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{
     dtrlsv::dtrlsv, 
     dtrusv::dtrusv,
@@ -67,8 +69,28 @@ pub fn dtrsv(
     matrix      : &[f64], 
     lda         : usize, 
     x           : &mut [f64], 
-    incx        : usize, 
+    incx        : isize, 
 ) { 
+    if !Check::new("dtrsv")
+        .ld(6, lda, n)
+        .inc(8, incx)
+        .mat(5, matrix.len(), n, n, lda)
+        .vec(7, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let mut xs = gather(x, n, incx, 1);
+        dtrsv(uplo, transpose, diagonal, n, matrix, lda, &mut xs, 1);
+        scatter(&xs, x, n, incx, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+
     match uplo { 
         CoralTriangular::UpperTriangular => dtrusv(n, transpose, diagonal, matrix, lda, x, incx), 
         CoralTriangular::LowerTriangular => dtrlsv(n, transpose, diagonal, matrix, lda, x, incx), 
@@ -14,6 +14,7 @@ pub(crate) mod cgemv_conjtranspose;
 pub(crate) mod zgemv_notranspose; 
 pub(crate) mod zgemv_transpose; 
 pub(crate) mod zgemv_conjtranspose;
+pub(crate) mod gemv_compensated;
 pub mod sgemv; 
 pub mod dgemv;
 pub mod cgemv; 
@@ -70,6 +71,8 @@ pub mod zher2;
 // GEMV
 pub use sgemv::sgemv;
 pub use dgemv::dgemv;
+pub use sgemv::sgemv_with;
+pub use dgemv::dgemv_with;
 pub use cgemv::cgemv;
 pub use zgemv::zgemv;
 
@@ -14,13 +14,17 @@
 //! - `matrix` (&[f32])     : Input slice containing the matrix $A$.
 //! - `lda`    (usize)      : Leading dimension of $A$.
 //! - `x`      (&[f32])     : Input vector of length `n_cols`.
-//! - `incx`   (usize)      : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)      : Stride between consecutive elements of $x$.
 //! - `beta`   (f32)        : Scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f32]) : Input/output vector of length `n_rows`.
-//! - `incy`   (usize)      : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)      : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place.
+//!
+//! # Notes
+//! - With the `reproducible` feature, the transposed product takes each entry
+//!   as a fixed-order dot over its column, whatever the strides.
 //! 
 //! # Example
 //! ```rust
@@ -52,10 +56,14 @@
 //! ```
 
 
-use crate::enums::CoralTranspose; 
+use crate::enums::{Accuracy, CoralTranspose};
+use crate::errors::Check;
+use crate::reproducible;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     sgemv_transpose::sgemv_transpose, 
     sgemv_notranspose::sgemv_notranspose, 
+    gemv_compensated::sgemv_compensated,
 }; 
 
 #[inline]
@@ -68,14 +76,101 @@ pub fn sgemv(
     matrix  : &[f32], 
     lda     : usize, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     beta    : f32, 
     y       : &mut [f32], 
-    incy    : usize
+    incy    : isize
 ) { 
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("sgemv")
+        .ld(6, lda, n_rows)
+        .inc(8, incx)
+        .inc(11, incy)
+        .mat(5, matrix.len(), n_rows, n_cols, lda)
+        .vec(7, x.len(), len_x, incx)
+        .vec(10, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 1);
+        let mut ys = gather(y, len_y, incy, 1);
+        sgemv(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
+    if cfg!(feature = "reproducible") && !matches!(trans, CoralTranspose::NoTranspose) {
+        return reproducible::sgemv_transpose(n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy);
+    }
+
     match trans { 
         CoralTranspose::NoTranspose         => sgemv_notranspose(n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::Transpose           => sgemv_transpose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::ConjugateTranspose  => sgemv_transpose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
     }
 }
+
+/// [`sgemv`] with a choice of [`Accuracy`]. `Accuracy::Fast` is [`sgemv`]
+/// itself; `Accuracy::Compensated` accumulates every entry of
+/// $\operatorname{op}(A) x$ with the fused `Dot2` kernels, so cancellation costs
+/// about twice the working precision before it shows. Arguments after
+/// `accuracy` are those of [`sgemv`].
+#[inline]
+#[cfg(target_arch = "aarch64")]
+pub fn sgemv_with(
+    accuracy : Accuracy,
+    trans    : CoralTranspose,
+    n_rows   : usize,
+    n_cols   : usize,
+    alpha    : f32,
+    matrix   : &[f32],
+    lda      : usize,
+    x        : &[f32],
+    incx     : isize,
+    beta     : f32,
+    y        : &mut [f32],
+    incy     : isize
+) {
+    if let Accuracy::Fast = accuracy {
+        return sgemv(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy);
+    }
+
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("sgemv_with")
+        .ld(7, lda, n_rows)
+        .inc(9, incx)
+        .inc(12, incy)
+        .mat(6, matrix.len(), n_rows, n_cols, lda)
+        .vec(8, x.len(), len_x, incx)
+        .vec(11, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 1);
+        let mut ys = gather(y, len_y, incy, 1);
+        sgemv_compensated(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 1);
+        return;
+    }
+
+    sgemv_compensated(trans, n_rows, n_cols, alpha, matrix, lda, x, incx as usize, beta, y, incy as usize);
+}
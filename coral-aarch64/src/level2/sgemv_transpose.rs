@@ -87,7 +87,7 @@ pub(crate) fn sgemv_transpose(
     );
 
     // y := beta * y
-    if beta != 1.0  { sscal(n_cols, beta, y, incy); }
+    if beta != 1.0  { sscal(n_cols, beta, y, incy as isize); }
     if alpha == 0.0 { return; }
 
     // pack x into contiguous buffer and scale by alpha
@@ -18,9 +18,9 @@
 //! - `n_cols` (usize)      : Number of columns ($n$) in the matrix $A$.
 //! - `alpha`  (f32)        : Scalar multiplier applied to the outer product $x y^T$.
 //! - `x`      (&[f32])     : Input slice containing the vector $x$.
-//! - `incx`   (usize)      : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)      : Stride between consecutive elements of $x$.
 //! - `y`      (&[f32])     : Input slice containing the vector $y$.
-//! - `incy`   (usize)      : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)      : Stride between consecutive elements of $y$.
 //! - `matrix` (&mut [f32]) : Input/output slice containing the matrix $A$.
 //! - `lda`    (usize)      : Leading dimension of $A$.
 //!
@@ -58,10 +58,8 @@
 
 
 use crate::level1::saxpy::saxpy;
-
-// assert length helpers 
-use crate::level1::assert_length_helpers::required_len_ok; 
-use crate::level2::assert_length_helpers::required_len_ok_matrix; 
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline] 
 #[cfg(target_arch = "aarch64")]
@@ -70,24 +68,38 @@ pub fn sger(
     n_cols  : usize, 
     alpha   : f32, 
     x       : &[f32], 
-    incx    : usize, 
+    incx    : isize, 
     y       : &[f32], 
-    incy    : usize, 
+    incy    : isize, 
     matrix  : &mut [f32], 
     lda     : usize, 
 ) { 
+    if !Check::new("sger")
+        .inc(5, incx)
+        .inc(7, incy)
+        .ld(9, lda, n_rows)
+        .vec(4, x.len(), n_rows, incx)
+        .vec(6, y.len(), n_cols, incy)
+        .mat(8, matrix.len(), n_rows, n_cols, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n_rows, incx, 1);
+        let ys = gather(y, n_cols, incy, 1);
+        sger(n_rows, n_cols, alpha, &xs, 1, &ys, 1, matrix, lda);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     // quick return 
     if n_rows == 0 || n_cols == 0 || alpha == 0.0 { return; } 
 
-    debug_assert!(incx > 0 && incy > 0, "incx/incy strides must be nonzero"); 
-    debug_assert!(lda >= n_rows, "leading dimension must be >= n_rows"); 
-    debug_assert!(required_len_ok(x.len(), n_rows, incx), "x not large enough for n_rows/incx"); 
-    debug_assert!(required_len_ok(y.len(), n_cols, incy), "y not large enough for n_cols/incy"); 
-    debug_assert!(
-        required_len_ok_matrix(matrix.len(), n_rows, n_cols, lda), 
-        "matrix not large enough for given n_rows x n_cols and lda"
-    ); 
-
     // fast path 
     if incx == 1 && incy == 1 { 
         // A[:, j] += (alpha * y[j]) * x 
@@ -21,10 +21,10 @@
 //! - `matrix` (&[f32])          : Input slice containing the matrix $A$.
 //! - `lda`    (usize)           : Leading dimension of $A$. 
 //! - `x`      (&[f32])          : Input vector of length $n$.
-//! - `incx`   (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive elements of $x$.
 //! - `beta`   (f32)             : Scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f32])      : Input/output vector of length $n$.
-//! - `incy`   (usize)           : Stride between consecutive elements of $y$.
+//! - `incy`   (isize)           : Stride between consecutive elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place. 
@@ -38,6 +38,7 @@
 //!   column panels of width `NC`. Off-diagonal panels are handled with a fused
 //!   `saxpyf`/`sdotf` kernel on packed rectangles that lie entirely within the stored
 //!   triangle; diagonal blocks are handled by a triangular microkernel.
+//! - A negative increment walks its vector from the end, as in reference BLAS.
 //!
 //! # Author
 //! Deval Deliwala
@@ -73,14 +74,12 @@
 
 use core::slice;
 use crate::enums::CoralTriangular; 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level1::sscal::sscal;
 use crate::level1_special::saxpyf::saxpyf;
 use crate::level1_special::sdotf::sdotf;
 
-// assert length helpers
-use crate::level1::assert_length_helpers::required_len_ok;
-use crate::level2::assert_length_helpers::required_len_ok_matrix;
-
 // contiguous packing helpers
 use crate::level2::{
     vector_packing::{
@@ -103,24 +102,39 @@ pub fn ssymv(
     matrix  : &[f32],
     lda     : usize,
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     beta    : f32,
     y       : &mut [f32],
-    incy    : usize,
+    incy    : isize,
 ) {
+    if !Check::new("ssymv")
+        .ld(5, lda, n)
+        .inc(7, incx)
+        .inc(10, incy)
+        .mat(4, matrix.len(), n, n, lda)
+        .vec(6, x.len(), n, incx)
+        .vec(9, y.len(), n, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n, incx, 1);
+        let mut ys = gather(y, n, incy, 1);
+        ssymv(uplo, n, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, n, incy, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     // quick return
     if n == 0 { return; }
     if alpha == 0.0 && beta == 1.0 { return; }
 
-    debug_assert!(incx > 0 && incy > 0, "vector increments must be nonzero");
-    debug_assert!(lda >= n, "matrix leading dimension must be >= n");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x too short for n/incx");
-    debug_assert!(required_len_ok(y.len(), n, incy), "y too short for n/incy");
-    debug_assert!(
-        required_len_ok_matrix(matrix.len(), n, n, lda),
-        "matrix too short for given n and lda"
-    );
-
     // pack x into contiguous buff and scale by alpha 
     let mut xbuffer: Vec<f32> = Vec::new(); 
     pack_and_scale_f32(n, alpha, x, incx, &mut xbuffer); 
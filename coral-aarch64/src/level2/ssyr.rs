@@ -18,7 +18,7 @@
 //! - `n`      (usize)           : Order of the matrix $A$.
 //! - `alpha`  (f32)             : Scalar multiplier applied to the outer product $x x^T$.
 //! - `x`      (&[f32])          : Input slice containing the vector $x$.
-//! - `incx`   (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive elements of $x$.
 //! - `matrix` (&mut [f32])      : Input/output slice containing the matrix $A$. 
 //! - `lda`    (usize)           : Leading dimension of $A$.
 //!
@@ -53,10 +53,9 @@
 
 use crate::level1::saxpy::saxpy;
 
-// assert length helpers
-use crate::level1::assert_length_helpers::required_len_ok;
-use crate::level2::assert_length_helpers::required_len_ok_matrix;
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
@@ -65,23 +64,34 @@ pub fn ssyr(
     n       : usize,
     alpha   : f32,
     x       : &[f32],
-    incx    : usize,
+    incx    : isize,
     matrix  : &mut [f32],
     lda     : usize,
 ) {
+    if !Check::new("ssyr")
+        .inc(5, incx)
+        .ld(7, lda, n)
+        .vec(4, x.len(), n, incx)
+        .mat(6, matrix.len(), n, n, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let xs = gather(x, n, incx, 1);
+        ssyr(uplo, n, alpha, &xs, 1, matrix, lda);
+        return;
+    }
+
+    let incx = incx as usize;
+
     // quick returns
     if n == 0 || alpha == 0.0 {
         return;
     }
 
-    debug_assert!(incx > 0, "incx stride must be nonzero");
-    debug_assert!(lda >= n, "leading dimension must be >= n");
-    debug_assert!(required_len_ok(x.len(), n, incx), "x not large enough for n/incx");
-    debug_assert!(
-        required_len_ok_matrix(matrix.len(), n, n, lda),
-        "matrix not large enough for given n x n and lda"
-    );
-
     // fast path 
     if incx == 1 {
         match uplo {
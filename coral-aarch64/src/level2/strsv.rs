@@ -16,7 +16,7 @@
 //! - `lda`         (usize)           : Leading dimension of $A$.
 //! - `x`           (&mut [f32])      : Input/output slice containing the right-hand side $b$ on
 //!                                     entry and the solution $x$ on exit. 
-//! - `incx`        (usize)           : Stride between consecutive elements of $x$.
+//! - `incx`        (isize)           : Stride between consecutive elements of $x$.
 //!
 //! # Returns
 //! - Nothing. $x$ is updated in place with the solution. 
@@ -50,6 +50,8 @@
 //! ```
 
 use crate::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{
     strlsv::strlsv, 
     strusv::strusv,
@@ -65,8 +67,28 @@ pub fn strsv(
     matrix      : &[f32], 
     lda         : usize, 
     x           : &mut [f32], 
-    incx        : usize, 
+    incx        : isize, 
 ) { 
+    if !Check::new("strsv")
+        .ld(6, lda, n)
+        .inc(8, incx)
+        .mat(5, matrix.len(), n, n, lda)
+        .vec(7, x.len(), n, incx)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increment: walk x through a unit-stride copy
+    if incx < 0 {
+        let mut xs = gather(x, n, incx, 1);
+        strsv(uplo, transpose, diagonal, n, matrix, lda, &mut xs, 1);
+        scatter(&xs, x, n, incx, 1);
+        return;
+    }
+
+    let incx = incx as usize;
+
     match uplo { 
         CoralTriangular::UpperTriangular => strusv(n, transpose, diagonal, matrix, lda, x, incx), 
         CoralTriangular::LowerTriangular => strlsv(n, transpose, diagonal, matrix, lda, x, incx), 
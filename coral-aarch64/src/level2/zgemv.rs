@@ -13,10 +13,10 @@
 //! - `matrix` (&[f64])     : Input slice containing the matrix $A$.
 //! - `lda`    (usize)      : Leading dimension of $A$.
 //! - `x`      (&[f64])     : Input complex vector of length `n_rows`.
-//! - `incx`   (usize)      : Stride between consecutive complex elements of $x$.
+//! - `incx`   (isize)      : Stride between consecutive complex elements of $x$.
 //! - `beta`   ([f64; 2])   : Complex scalar multiplier applied to $y$ prior to accumulation.
 //! - `y`      (&mut [f64]) : Input/output complex vector of length `n_cols`.
-//! - `incy`   (usize)      : Stride between consecutive complex elements of $y$.
+//! - `incy`   (isize)      : Stride between consecutive complex elements of $y$.
 //!
 //! # Returns
 //! - Nothing. The contents of $y$ are updated in place.
@@ -52,6 +52,8 @@
 
 
 use crate::enums::CoralTranspose; 
+use crate::errors::Check;
+use crate::level1::strides::{gather, scatter};
 use crate::level2::{ 
     zgemv_notranspose::zgemv_notranspose, 
     zgemv_transpose::zgemv_transpose, 
@@ -68,11 +70,40 @@ pub fn zgemv(
     matrix  : &[f64], 
     lda     : usize, 
     x       : &[f64], 
-    incx    : usize, 
+    incx    : isize, 
     beta    : [f64; 2], 
     y       : &mut [f64], 
-    incy    : usize
+    incy    : isize
 ) { 
+    let (len_x, len_y) = match trans {
+        CoralTranspose::NoTranspose => (n_cols, n_rows),
+        _                           => (n_rows, n_cols),
+    };
+
+    if !Check::new("zgemv")
+        .ld(6, lda, n_rows)
+        .inc(8, incx)
+        .inc(11, incy)
+        .cmat(5, matrix.len(), n_rows, n_cols, lda)
+        .cvec(7, x.len(), len_x, incx)
+        .cvec(10, y.len(), len_y, incy)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, len_x, incx, 2);
+        let mut ys = gather(y, len_y, incy, 2);
+        zgemv(trans, n_rows, n_cols, alpha, matrix, lda, &xs, 1, beta, &mut ys, 1);
+        scatter(&ys, y, len_y, incy, 2);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     match trans { 
         CoralTranspose::NoTranspose         => zgemv_notranspose  (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
         CoralTranspose::Transpose           => zgemv_transpose    (n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
@@ -89,9 +89,9 @@ pub(crate) fn zgemv_notranspose(
     // y := beta * y 
     if !(beta[0] == 1.0 && beta[1] == 0.0) {
         if beta[1] == 0.0 {
-            zdscal(n_rows, beta[0], y, incy);
+            zdscal(n_rows, beta[0], y, incy as isize);
         } else {
-            zscal(n_rows, beta, y, incy);
+            zscal(n_rows, beta, y, incy as isize);
         }
     }
     if alpha[0] == 0.0 && alpha[1] == 0.0 { return; }
@@ -17,9 +17,9 @@
 //! - `n`      (usize)           : Order of the matrix $A$.
 //! - `alpha`  (f64)             : Real scalar multiplier applied to the outer product $x x^H$.
 //! - `x`      (&[f64])          : Input slice containing the complex vector $x$.
-//! - `incx`   (usize)           : Stride between consecutive complex elements of $x$.
+//! - `incx`   (isize)           : Stride between consecutive complex elements of $x$.
 //! - `y`      (&[f64])          : Input slice containing the complex vector $y$.
-//! - `incy`   (usize)           : Stride between consecutive complex elements of $y$.
+//! - `incy`   (isize)           : Stride between consecutive complex elements of $y$.
 //! - `matrix` (&mut [f64])      : Input/output slice containing the matrix $A$.
 //! - `lda`    (usize)           : Leading dimension of $A$.
 //!
@@ -54,9 +54,9 @@
 
 
 use crate::level1::zaxpy::zaxpy;
-use crate::level1::assert_length_helpers::required_len_ok_cplx;
-use crate::level2::assert_length_helpers::required_len_ok_matrix_cplx;
 use crate::enums::CoralTriangular;
+use crate::errors::Check;
+use crate::level1::strides::gather;
 
 #[inline]
 #[cfg(target_arch = "aarch64")]
@@ -65,26 +65,40 @@ pub fn zher2(
     n       : usize,
     alpha   : [f64; 2],
     x       : &[f64],
-    incx    : usize,
+    incx    : isize,
     y       : &[f64],
-    incy    : usize,
+    incy    : isize,
     matrix  : &mut [f64],
     lda     : usize,
 ) {
+    if !Check::new("zher2")
+        .inc(5, incx)
+        .inc(7, incy)
+        .ld(9, lda, n)
+        .cvec(4, x.len(), n, incx)
+        .cvec(6, y.len(), n, incy)
+        .cmat(8, matrix.len(), n, n, lda)
+        .ok()
+    {
+        return;
+    }
+
+    // negative increments: walk the vectors through unit-stride copies
+    if incx < 0 || incy < 0 {
+        let xs = gather(x, n, incx, 2);
+        let ys = gather(y, n, incy, 2);
+        zher2(uplo, n, alpha, &xs, 1, &ys, 1, matrix, lda);
+        return;
+    }
+
+    let incx = incx as usize;
+    let incy = incy as usize;
+
     // quick returns
     if n == 0 || (alpha[0] == 0.0 && alpha[1] == 0.0) {
         return;
     }
 
-    debug_assert!(incx > 0 && incy > 0, "incx/incy strides must be nonzero");
-    debug_assert!(lda >= n, "leading dimension must be >= n");
-    debug_assert!(required_len_ok_cplx(x.len(), n, incx), "x not large enough for n/incx");
-    debug_assert!(required_len_ok_cplx(y.len(), n, incy), "y not large enough for n/incy");
-    debug_assert!(
-        required_len_ok_matrix_cplx(matrix.len(), n, n, lda),
-        "matrix not large enough for given n x n and lda"
-    );
-
     // fast path 
     if incx == 1 && incy == 1 {
         match uplo {
@@ -216,7 +230,7 @@ pub fn zher2(
                                 j + 1,
                                 aj_y,
                                 std::slice::from_raw_parts(x_ptr, x.len()),
-                                incx,
+                                incx as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(j + 1)
@@ -229,7 +243,7 @@ pub fn zher2(
                                 j + 1,
                                 aj_x,
                                 std::slice::from_raw_parts(y_ptr, y.len()),
-                                incy,
+                                incy as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(j + 1)
@@ -274,7 +288,7 @@ pub fn zher2(
                                     x_ptr.add(2*j*incx),
                                     x.len() - 2*j*incx
                                 ),
-                                incx,
+                                incx as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(n - j)
@@ -290,7 +304,7 @@ pub fn zher2(
                                     y_ptr.add(2*j*incy), 
                                     y.len() - 2*j*incy
                                 ),
-                                incy,
+                                incy as isize,
                                 std::slice::from_raw_parts_mut(
                                     a_ptr.add(2*col_start),
                                     2*(n - j)
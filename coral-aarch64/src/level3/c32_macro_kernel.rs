@@ -1,4 +1,6 @@
 use crate::level3::c32_packers::{MR, NR};
+use crate::level3::gemmt_tiles::{Tile, classify, in_triangle};
+use crate::enums::CoralTriangular;
 use crate::level3::microkernel::{
     c32_edge::c32_edge,
     c32_mrxnr::{
@@ -64,3 +66,99 @@ pub(crate) fn macro_kernel(
     }
 }
 
+
+/// `macro_kernel` restricted to the `uplo` triangle of C.
+/// `(row0, col0)` is the global position of `c_base`.
+#[inline(always)]
+pub(crate) fn macro_kernel_tri(
+    mc         : usize,
+    nc         : usize,
+    kc         : usize,
+    alpha      : Complex32,
+    beta_panel : Complex32,
+    a_pack     : *const f32,
+    b_pack     : *const f32,
+    c_base     : *mut f32,
+    ldc        : usize,
+    uplo       : CoralTriangular,
+    row0       : usize,
+    col0       : usize,
+) {
+    unsafe {
+        let np = nc.div_ceil(NR);
+        let mp = mc.div_ceil(MR);
+
+        let a_panel_stride = kc * (2 * MR);
+        let b_panel_stride = kc * (2 * NR);
+
+        let beta_is_zero = beta_panel.re == 0.0 && beta_panel.im == 0.0;
+        let beta_is_one  = beta_panel.re == 1.0 && beta_panel.im == 0.0;
+
+        for jp in 0..np {
+            let nr = core::cmp::min(NR, nc - jp * NR);
+            let bp = b_pack.add(jp * b_panel_stride);
+
+            for ip in 0..mp {
+                let mr = core::cmp::min(MR, mc - ip * MR);
+                let ap = a_pack.add(ip * a_panel_stride);
+
+                let cptr = c_base.add(2 * (ip * MR + (jp * NR) * ldc));
+
+                let row = row0 + ip * MR;
+                let col = col0 + jp * NR;
+
+                match classify(uplo, row, col, mr, nr) {
+                    Tile::Outside => {}
+
+                    Tile::Inside if mr == MR && nr == NR => {
+                        if beta_is_zero {
+                            c32_mrxnr_beta0(kc, ap, bp, cptr, ldc, alpha);
+                        } else if beta_is_one {
+                            c32_mrxnr_beta1(kc, ap, bp, cptr, ldc, alpha);
+                        } else {
+                            c32_mrxnr_betax(kc, ap, bp, cptr, ldc, alpha, beta_panel);
+                        }
+                    }
+
+                    Tile::Inside => {
+                        c32_edge(mr, nr, kc, ap, bp, cptr, ldc, alpha, beta_panel);
+                    }
+
+                    Tile::Diagonal => {
+                        // alpha * A * B into scratch, then merge the triangle
+                        let zero = Complex32 { re: 0.0, im: 0.0 };
+                        let mut tmp = [0.0; 2 * MR * NR];
+                        c32_edge(mr, nr, kc, ap, bp, tmp.as_mut_ptr(), MR, alpha, zero);
+
+                        let br = beta_panel.re;
+                        let bi = beta_panel.im;
+
+                        for j in 0..nr {
+                            let colp = cptr.add(2 * j * ldc);
+
+                            for r in 0..mr {
+                                if !in_triangle(uplo, row + r, col + j) {
+                                    continue;
+                                }
+
+                                let tre = tmp[2 * (r + j * MR) + 0];
+                                let tim = tmp[2 * (r + j * MR) + 1];
+
+                                if beta_is_zero {
+                                    *colp.add(2 * r + 0) = tre;
+                                    *colp.add(2 * r + 1) = tim;
+                                } else {
+                                    let cre = *colp.add(2 * r + 0);
+                                    let cim = *colp.add(2 * r + 1);
+
+                                    *colp.add(2 * r + 0) = cre * br - cim * bi + tre;
+                                    *colp.add(2 * r + 1) = cre * bi + cim * br + tim;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -109,6 +109,7 @@ use crate::level3::{
 };
 
 use crate::enums::CoralTranspose;
+use crate::errors::Check;
 use crate::level3::microkernel::c32_mrxnr::Complex32; 
 
 pub(crate) const MC: usize = 384;
@@ -139,7 +140,18 @@ pub fn cgemm(
     c     : *mut f32,
     ldc   : usize,
 ) {
-    
+    let a_t = !matches!(op_a, CoralTranspose::NoTranspose);
+    let b_t = !matches!(op_b, CoralTranspose::NoTranspose);
+
+    if !Check::new("cgemm")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
     let alpha = c32(alpha); 
     let beta  = c32(beta); 
 
@@ -0,0 +1,275 @@
+//! `GEMM` via the 3M method. Single precision complex general matrix-multiply.
+//!
+//! \\[
+//! C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C, \quad
+//!    \operatorname{op}(A) \in \\{A, A^T, A^H\\}.
+//! \\]
+//!
+//! Writing $\operatorname{op}(A) = A_r + iA_i$ and
+//! $\operatorname{op}(B) = B_r + iB_i$, the product is formed from three real
+//! products on the `f32` microkernels,
+//!
+//! \\[
+//! P_1 = A_r B_r, \quad P_2 = A_i B_i, \quad P_3 = (A_r + A_i)(B_r + B_i),
+//! \\]
+//! \\[
+//! \operatorname{op}(A)\operatorname{op}(B) = (P_1 - P_2) + i\\,(P_3 - P_1 - P_2),
+//! \\]
+//!
+//! instead of the four real products [`crate::level3::cgemm`] does inside its
+//! complex microkernels. This saves 25% of the flops.
+//!
+//! Same arguments and storage as [`crate::level3::cgemm`].
+//!
+//! # Arguments
+//! - `op_a`  (CoralTranspose) : Whether to transpose or conjugate-transpose `A`.
+//! - `op_b`  (CoralTranspose) : Whether to transpose or conjugate-transpose `B`.
+//! - `m`     (usize)          : Number of rows of `op(A)` and `C`.
+//! - `n`     (usize)          : Number of columns of `op(B)` and `C`.
+//! - `k`     (usize)          : Shared inner dimension of `op(A)` and `op(B)`.
+//! - `alpha` ([f32; 2])       : Complex scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (*const f32)     : Pointer to matrix `A`.
+//! - `lda`   (usize)          : Leading dimension of `A`.
+//! - `b`     (*const f32)     : Pointer to matrix `B`.
+//! - `ldb`   (usize)          : Leading dimension of `B`.
+//! - `beta`  ([f32; 2])       : Complex scalar applied to `C`.
+//! - `c`     (*mut f32)       : Pointer to matrix `C`.
+//! - `ldc`   (usize)          : Leading dimension of `C`.
+//!
+//! # Returns
+//! - Nothing. The contents of `C` are updated in place.
+//!
+//! # Notes
+//! - Opt-in. `cgemm` never switches to this path on its own.
+//! - Accuracy: the real part is computed as accurately as in `cgemm`. The
+//!   imaginary part is not. Its error is bounded by a multiple of
+//!   $u\\,k\\,|A_r + A_i|\\,|B_r + B_i|$ plus the cancellation in
+//!   $P_3 - P_1 - P_2$, instead of $u\\,k\\,(|A_r||B_i| + |A_i||B_r|)$. When
+//!   $A_r \approx -A_i$ or $B_r \approx -B_i$, or when the imaginary part of the
+//!   result is much smaller than the real part, the relative error of the
+//!   imaginary part can be much larger than with `cgemm`. The normwise
+//!   error $\\|\Delta C\\| / (\\|A\\|\\|B\\|)$ stays within a small constant of
+//!   the conventional one. Prefer `cgemm` when small imaginary parts matter.
+//! - Allocates the real and imaginary planes of `op(A)` and `op(B)`, their
+//!   sums, and three `m x n` real products.
+//! - Worth it for large `m`, `n`, `k`; for small problems the plane
+//!   splitting dominates and `cgemm` is faster.
+//!
+//! # Author
+//! Deval Deliwala
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::{cgemm, cgemm_3m};
+//! use coral_aarch64::enums::CoralTranspose;
+//!
+//! fn main() {
+//!     let a = vec![
+//!         1.0, 2.0, 5.0, 6.0,
+//!         3.0, 4.0, 7.0, 8.0,
+//!     ];
+//!     let b = vec![
+//!         9.0, 1.0, 4.0, 5.0,
+//!         2.0, 3.0, 6.0, 7.0,
+//!     ];
+//!
+//!     let mut c_3m = vec![0.0; 8];
+//!     let mut c_4m = vec![0.0; 8];
+//!
+//!     cgemm_3m(
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::ConjugateTranspose,
+//!         2, 2, 2,
+//!         [1.0, 0.0],
+//!         a.as_ptr(), 2,
+//!         b.as_ptr(), 2,
+//!         [0.0, 0.0],
+//!         c_3m.as_mut_ptr(), 2,
+//!     );
+//!
+//!     cgemm(
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::ConjugateTranspose,
+//!         2, 2, 2,
+//!         [1.0, 0.0],
+//!         a.as_ptr(), 2,
+//!         b.as_ptr(), 2,
+//!         [0.0, 0.0],
+//!         c_4m.as_mut_ptr(), 2,
+//!     );
+//!
+//!     for (x, y) in c_3m.iter().zip(&c_4m) {
+//!         assert!((x - y).abs() < 1e-4);
+//!     }
+//! }
+//! ```
+
+use crate::level3::{cgemm::cgemm, sgemm::sgemm};
+use crate::enums::CoralTranspose;
+use crate::errors::Check;
+
+// (real op, conjugate)
+#[inline(always)]
+fn real_op(op: CoralTranspose) -> (CoralTranspose, bool) {
+    match op {
+        CoralTranspose::NoTranspose        => (CoralTranspose::NoTranspose, false),
+        CoralTranspose::Transpose          => (CoralTranspose::Transpose,   false),
+        CoralTranspose::ConjugateTranspose => (CoralTranspose::Transpose,   true),
+    }
+}
+
+/// splits the `rows x cols` complex matrix at `x` into packed
+/// (`ld = rows`) real, imaginary, and real + imaginary planes.
+/// negates the imaginary part if `conj`.
+#[inline(always)]
+fn split_planes(
+    rows : usize,
+    cols : usize,
+    x    : *const f32,
+    ldx  : usize,
+    conj : bool,
+) -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+    let mut re  = vec![0.0; rows * cols];
+    let mut im  = vec![0.0; rows * cols];
+    let mut sum = vec![0.0; rows * cols];
+
+    let sign = if conj { -1.0 } else { 1.0 };
+
+    unsafe {
+        for j in 0..cols {
+            let col = x.add(2 * j * ldx);
+
+            for i in 0..rows {
+                let r = *col.add(2 * i + 0);
+                let s = sign * *col.add(2 * i + 1);
+                let idx = i + j * rows;
+
+                re[idx]  = r;
+                im[idx]  = s;
+                sum[idx] = r + s;
+            }
+        }
+    }
+
+    (re, im, sum)
+}
+
+#[inline(always)]
+pub fn cgemm_3m(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    let a_t = !matches!(op_a, CoralTranspose::NoTranspose);
+    let b_t = !matches!(op_b, CoralTranspose::NoTranspose);
+
+    if !Check::new("cgemm_3m")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
+    // nothing to multiply; cgemm already handles the beta scaling
+    if (alpha[0] == 0.0 && alpha[1] == 0.0) || k == 0 || m == 0 || n == 0 {
+        cgemm(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+        return;
+    }
+
+    cgemm_3m_driver(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+fn cgemm_3m_driver(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    let (rop_a, conj_a) = real_op(op_a);
+    let (rop_b, conj_b) = real_op(op_b);
+
+    let (a_rows, a_cols) = match rop_a {
+        CoralTranspose::NoTranspose => (m, k),
+        _                           => (k, m),
+    };
+    let (b_rows, b_cols) = match rop_b {
+        CoralTranspose::NoTranspose => (k, n),
+        _                           => (n, k),
+    };
+
+    let (ar, ai, a_sum) = split_planes(a_rows, a_cols, a, lda, conj_a);
+    let (br, bi, b_sum) = split_planes(b_rows, b_cols, b, ldb, conj_b);
+
+    let mut p1 = vec![0.0; m * n];
+    let mut p2 = vec![0.0; m * n];
+    let mut p3 = vec![0.0; m * n];
+
+    for (x, y, p) in [
+        (&ar,    &br,    &mut p1),
+        (&ai,    &bi,    &mut p2),
+        (&a_sum, &b_sum, &mut p3),
+    ] {
+        sgemm(
+            rop_a, rop_b,
+            m, n, k,
+            1.0,
+            x.as_ptr(), a_rows,
+            y.as_ptr(), b_rows,
+            0.0,
+            p.as_mut_ptr(), m,
+        );
+    }
+
+    let [alr, ali] = alpha;
+    let [btr, bti] = beta;
+    let beta_is_zero = btr == 0.0 && bti == 0.0;
+
+    unsafe {
+        for j in 0..n {
+            let col = c.add(2 * j * ldc);
+
+            for i in 0..m {
+                let idx = i + j * m;
+
+                let re = p1[idx] - p2[idx];
+                let im = p3[idx] - p1[idx] - p2[idx];
+
+                // alpha * (re + i im)
+                let mut out_re = alr * re - ali * im;
+                let mut out_im = alr * im + ali * re;
+
+                if !beta_is_zero {
+                    let cre = *col.add(2 * i + 0);
+                    let cim = *col.add(2 * i + 1);
+
+                    out_re += btr * cre - bti * cim;
+                    out_im += btr * cim + bti * cre;
+                }
+
+                *col.add(2 * i + 0) = out_re;
+                *col.add(2 * i + 1) = out_im;
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! `GEMMT`. Single precision complex general matrix-multiply, one triangle of $C$.
+//!
+//! \\[
+//! C := \alpha \operatorname{op}(A)\operatorname{op}(B) + \beta C, \quad
+//! \operatorname{op}(A) \in \\{A, A^{T}, A^{H}\\},
+//! \\]
+//!
+//! where only the `uplo` triangle of the $n \times n$ matrix $C$, diagonal
+//! included, is referenced and updated.
+//!
+//! $A$, $B$, and $C$ are stored in column-major order as interleaved
+//! `[re, im]` pairs; leading dimensions count complex elements.
+//!
+//! # Arguments
+//! - `uplo`  (CoralTriangular) : Which triangle of `C` to update.
+//! - `op_a`  (CoralTranspose)  : Whether to transpose or conjugate-transpose `A`.
+//! - `op_b`  (CoralTranspose)  : Whether to transpose or conjugate-transpose `B`.
+//! - `n`     (usize)           : Order of `C`; rows of `op(A)`, columns of `op(B)`.
+//! - `k`     (usize)           : Shared inner dimension of `op(A)` and `op(B)`.
+//! - `alpha` ([f32; 2])        : Complex scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (*const f32)      : Pointer to matrix `A`.
+//! - `lda`   (usize)           : Leading dimension of `A`.
+//! - `b`     (*const f32)      : Pointer to matrix `B`.
+//! - `ldb`   (usize)           : Leading dimension of `B`.
+//! - `beta`  ([f32; 2])        : Complex scalar multiplier for `C`.
+//! - `c`     (*mut f32)        : Pointer to matrix `C`.
+//! - `ldc`   (usize)           : Leading dimension of `C`.
+//!
+//! # Returns
+//! - Nothing. The `uplo` triangle of `C` is updated in place; the other
+//!   strict triangle is not touched.
+//!
+//! # Notes
+//! - Macro-tiles entirely outside the triangle are skipped; micro-tiles
+//!   crossing the diagonal are computed by the edge kernel and masked.
+//! - No symmetry or hermitian structure is imposed on the result.
+//!
+//! # Author
+//! Deval Deliwala
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::cgemmt;
+//! use coral_aarch64::enums::{CoralTranspose, CoralTriangular};
+//!
+//! fn main() {
+//!     // A = [[1 + i],
+//!     //      [2    ]]
+//!     let a = vec![1.0, 1.0, 2.0, 0.0];
+//!
+//!     // strict upper entry is a sentinel
+//!     let mut c = vec![0.0; 8];
+//!     c[4] = -1.0;
+//!
+//!     // C := A * A^H, lower triangle only
+//!     cgemmt(
+//!         CoralTriangular::LowerTriangular,
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::ConjugateTranspose,
+//!         2, 1,
+//!         [1.0, 0.0],
+//!         a.as_ptr(), 2,
+//!         a.as_ptr(), 2,
+//!         [0.0, 0.0],
+//!         c.as_mut_ptr(), 2,
+//!     );
+//!
+//!     // [[2,       * ],
+//!     //  [2 - 2i,  4 ]]
+//!     assert_eq!(c, vec![2.0, 0.0, 2.0, -2.0, -1.0, 0.0, 4.0, 0.0]);
+//! }
+//! ```
+
+use crate::level3::{
+    cgemm::{MC, NC, KC},
+    c32_macro_kernel::macro_kernel_tri,
+    c32_packers::{
+        pack_a_block, pack_a_block_t, pack_a_block_ct,
+        pack_b_block, pack_b_block_t, pack_b_block_ct,
+        a_buf_len, b_buf_len,
+    },
+    gemmt_tiles::row_range,
+};
+use crate::level3::microkernel::c32_mrxnr::Complex32;
+use crate::enums::{CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+
+type Packer = fn(usize, usize, *const f32, usize, *mut f32);
+
+const ONE_C : Complex32 = Complex32 {
+    re: 1.0,
+    im: 0.0
+};
+
+#[inline(always)]
+fn is_zero(z: Complex32) -> bool {
+    z.re == 0.0 && z.im == 0.0
+}
+
+#[inline(always)]
+pub fn cgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    let a_t = !matches!(op_a, CoralTranspose::NoTranspose);
+    let b_t = !matches!(op_b, CoralTranspose::NoTranspose);
+
+    if !Check::new("cgemmt")
+        .ld(8, lda, if a_t { k } else { n })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, n)
+        .ok()
+    {
+        return;
+    }
+
+    let alpha = Complex32 { re: alpha[0], im: alpha[1] };
+    let beta  = Complex32 { re: beta[0],  im: beta[1]  };
+
+    cgemmt_driver(uplo, op_a, op_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+fn cgemmt_driver(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : Complex32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : Complex32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    unsafe {
+        if is_zero(alpha) || k == 0 {
+            // scale the triangle of C by beta
+            let br = beta.re;
+            let bi = beta.im;
+
+            for j in 0..n {
+                let (r0, r1) = row_range(uplo, n, j, 1);
+                let col = c.add(2 * j * ldc);
+
+                for i in r0..r1 {
+                    let re = *col.add(2 * i + 0);
+                    let im = *col.add(2 * i + 1);
+
+                    if is_zero(beta) {
+                        *col.add(2 * i + 0) = 0.0;
+                        *col.add(2 * i + 1) = 0.0;
+                    } else {
+                        *col.add(2 * i + 0) = re * br - im * bi;
+                        *col.add(2 * i + 1) = re * bi + im * br;
+                    }
+                }
+            }
+            return;
+        }
+
+        let (pack_a, a_t): (Packer, bool) = match op_a {
+            CoralTranspose::NoTranspose        => (pack_a_block,    false),
+            CoralTranspose::Transpose          => (pack_a_block_t,  true),
+            CoralTranspose::ConjugateTranspose => (pack_a_block_ct, true),
+        };
+        let (pack_b, b_t): (Packer, bool) = match op_b {
+            CoralTranspose::NoTranspose        => (pack_b_block,    false),
+            CoralTranspose::Transpose          => (pack_b_block_t,  true),
+            CoralTranspose::ConjugateTranspose => (pack_b_block_ct, true),
+        };
+
+        let mut a_buf = vec![0.0; a_buf_len(MC, KC)];
+        let mut b_buf = vec![0.0; b_buf_len(KC, NC)];
+
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            // rows of C this column block reaches into
+            let (r0, r1) = row_range(uplo, n, j0, nc);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+
+                {
+                    let b_block_base = if b_t {
+                        b.add(2 * (j0 + l0 * ldb))
+                    } else {
+                        b.add(2 * (l0 + j0 * ldb))
+                    };
+                    pack_b(kcblk, nc, b_block_base, ldb, b_buf.as_mut_ptr());
+                }
+
+                let beta_panel = if l0 == 0 { beta } else { ONE_C };
+
+                let mut i0 = r0;
+                while i0 < r1 {
+                    let mc = core::cmp::min(MC, r1 - i0);
+
+                    {
+                        let a_block_base = if a_t {
+                            a.add(2 * (l0 + i0 * lda))
+                        } else {
+                            a.add(2 * (i0 + l0 * lda))
+                        };
+                        pack_a(mc, kcblk, a_block_base, lda, a_buf.as_mut_ptr());
+                    }
+
+                    let c_base = c.add(2 * (i0 + j0 * ldc));
+
+                    macro_kernel_tri(
+                        mc,
+                        nc,
+                        kcblk,
+                        alpha,
+                        beta_panel,
+                        a_buf.as_ptr(),
+                        b_buf.as_ptr(),
+                        c_base,
+                        ldc,
+                        uplo,
+                        i0,
+                        j0,
+                    );
+
+                    i0 += mc;
+                }
+
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+    }
+}
@@ -89,13 +89,14 @@ use crate::level3::{
     dgemm_tt::dgemm_tt
 };
 use crate::enums::CoralTranspose;
+use crate::errors::Check;
 
 pub(crate) const MC: usize = 258; 
 pub(crate) const NC: usize = 384; 
 pub(crate) const KC: usize = 256;
 
 #[inline(always)]
-fn is_transpose(op: CoralTranspose) -> bool {
+pub(crate) fn is_transpose(op: CoralTranspose) -> bool {
     match op {
         CoralTranspose::NoTranspose        => false,
         CoralTranspose::Transpose          => true,
@@ -122,6 +123,15 @@ pub fn dgemm(
     let a_t = is_transpose(op_a);
     let b_t = is_transpose(op_b);
 
+    if !Check::new("dgemm")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
     match (a_t, b_t) {
         (false, false) => dgemm_nn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
         (false, true ) => dgemm_nt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
@@ -0,0 +1,277 @@
+//! Fused `GEMM` epilogue.
+//!
+//! \\[
+//! C := f\big(\alpha \operatorname{op}(A)\operatorname{op}(B) + \beta C\big)
+//! \\]
+//!
+//! An [`Epilogue`] describes the element-wise map $f$ applied to every
+//! entry of $C$ right after the last $k$-panel of a micro-tile is accumulated,
+//! while the tile is still in registers. This avoids a second full pass over
+//! $C$ for the usual bias + activation pattern.
+//!
+//! The stages run in a fixed order; each one is optional:
+//!
+//! 1. [`Bias`]       : add a per-row or per-column bias vector.
+//! 2. [`Activation`] : apply `ReLU` or `GELU`.
+//! 3. `clamp`        : clamp to `[lo, hi]`.
+//! 4. `map`          : user closure `Fn(i, j, x) -> f32`, where `(i, j)` is the
+//!    0-based position of the entry in $C$.
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::sgemm_epilogue;
+//! use coral_aarch64::level3::epilogue::{Epilogue, Bias, Activation};
+//! use coral_aarch64::enums::CoralTranspose;
+//!
+//! fn main() {
+//!     let a = vec![1.0, 2.0, 3.0, 4.0];
+//!     let b = vec![1.0, 0.0, 0.0, 1.0];
+//!     let mut c = vec![0.0; 4];
+//!
+//!     // one bias entry per row of C
+//!     let bias = vec![-2.0, -2.0];
+//!     let ep = Epilogue {
+//!         bias       : Bias::Row(&bias),
+//!         activation : Activation::Relu,
+//!         ..Default::default()
+//!     };
+//!
+//!     sgemm_epilogue(
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::NoTranspose,
+//!         2, 2, 2,
+//!         1.0,
+//!         a.as_ptr(), 2,
+//!         b.as_ptr(), 2,
+//!         0.0,
+//!         c.as_mut_ptr(), 2,
+//!         &ep,
+//!     );
+//!
+//!     // relu(A - 2)
+//!     assert_eq!(c, vec![0.0, 0.0, 1.0, 2.0]);
+//! }
+//! ```
+//!
+//! # Author
+//! Deval Deliwala
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::{
+    float32x4_t,
+    vaddq_f32,
+    vdupq_n_f32,
+    vld1q_f32,
+    vmaxq_f32,
+    vminq_f32,
+    vst1q_f32,
+};
+
+/// Bias vector added to $C$ before the activation.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Bias<'a> {
+    /// No bias.
+    #[default]
+    None,
+    /// One entry per row of $C$; `C[i, j] += bias[i]`. Length must be `>= m`.
+    Row(&'a [f32]),
+    /// One entry per column of $C$; `C[i, j] += bias[j]`. Length must be `>= n`.
+    Col(&'a [f32]),
+}
+
+/// Built-in element-wise activation.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Activation {
+    /// Identity.
+    #[default]
+    None,
+    /// $\max(x, 0)$.
+    Relu,
+    /// tanh approximation,
+    /// $\tfrac{1}{2} x \big(1 + \tanh(\sqrt{2/\pi}\\,(x + 0.044715 x^3))\big)$.
+    Gelu,
+}
+
+/// Element-wise epilogue applied by [`crate::level3::sgemm_epilogue`].
+///
+/// Construct with struct-update syntax over [`Default`], which disables
+/// every stage.
+#[derive(Copy, Clone, Default)]
+pub struct Epilogue<'a> {
+    /// Bias vector; applied first.
+    pub bias       : Bias<'a>,
+    /// Activation; applied after the bias.
+    pub activation : Activation,
+    /// Clamp bounds `(lo, hi)`; applied after the activation.
+    pub clamp      : Option<(f32, f32)>,
+    /// Custom per-element map `(i, j, x) -> y`; applied last.
+    pub map        : Option<&'a dyn Fn(usize, usize, f32) -> f32>,
+}
+
+impl<'a> Epilogue<'a> {
+    /// `true` if every stage is disabled.
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        matches!(self.bias, Bias::None)
+            && self.activation == Activation::None
+            && self.clamp.is_none()
+            && self.map.is_none()
+    }
+}
+
+const GELU_C0: f32 = 0.797_884_6; // sqrt(2/pi)
+const GELU_C1: f32 = 0.044_715;
+
+// scalar `max`/`min` with NEON `FMAX`/`FMIN` NaN semantics, so
+// edge tiles agree with full tiles
+#[inline(always)]
+fn fmax(a: f32, b: f32) -> f32 {
+    if a.is_nan() || a > b { a } else { b }
+}
+
+#[inline(always)]
+fn fmin(a: f32, b: f32) -> f32 {
+    if a.is_nan() || a < b { a } else { b }
+}
+
+#[inline(always)]
+fn gelu(x: f32) -> f32 {
+    0.5 * x * (1.0 + (GELU_C0 * (x + GELU_C1 * x * x * x)).tanh())
+}
+
+#[inline(always)]
+fn activate(act: Activation, x: f32) -> f32 {
+    match act {
+        Activation::None => x,
+        Activation::Relu => fmax(x, 0.0),
+        Activation::Gelu => gelu(x),
+    }
+}
+
+/// applies `ep` to a single entry `x` of C at global `(i, j)`.
+#[inline(always)]
+pub(crate) fn apply(
+    ep : &Epilogue<'_>,
+    i  : usize,
+    j  : usize,
+    x  : f32,
+) -> f32 {
+    let mut v = match ep.bias {
+        Bias::None    => x,
+        Bias::Row(bv) => x + bv[i],
+        Bias::Col(bv) => x + bv[j],
+    };
+
+    v = activate(ep.activation, v);
+
+    if let Some((lo, hi)) = ep.clamp {
+        v = fmin(fmax(v, lo), hi);
+    }
+
+    if let Some(f) = ep.map {
+        v = f(i, j, v);
+    }
+
+    v
+}
+
+/// applies `ep` to an `m x n` block of C whose top-left entry sits
+/// at global `(row, col)`. scalar; used on non-kernel paths.
+#[inline]
+pub(crate) fn apply_block(
+    ep  : &Epilogue<'_>,
+    m   : usize,
+    n   : usize,
+    c   : *mut f32,
+    ldc : usize,
+    row : usize,
+    col : usize,
+) {
+    unsafe {
+        for j in 0..n {
+            let colp = c.add(j * ldc);
+
+            for i in 0..m {
+                *colp.add(i) = apply(ep, row + i, col + j, *colp.add(i));
+            }
+        }
+    }
+}
+
+/// applies `ep` to eight consecutive entries of one C column held in
+/// registers. `row` and `col` are the global position of lane 0.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn apply_col8(
+    ep    : &Epilogue<'_>,
+    row   : usize,
+    col   : usize,
+    v0123 : float32x4_t,
+    v4567 : float32x4_t,
+) -> (float32x4_t, float32x4_t) {
+    unsafe {
+        let (mut v0, mut v1) = (v0123, v4567);
+
+        match ep.bias {
+            Bias::None => {}
+            Bias::Row(bv) => {
+                let bp = bv[row .. row + 8].as_ptr();
+
+                v0 = vaddq_f32(v0, vld1q_f32(bp.add(0)));
+                v1 = vaddq_f32(v1, vld1q_f32(bp.add(4)));
+            }
+            Bias::Col(bv) => {
+                let bj = vdupq_n_f32(bv[col]);
+
+                v0 = vaddq_f32(v0, bj);
+                v1 = vaddq_f32(v1, bj);
+            }
+        }
+
+        match ep.activation {
+            Activation::None => {}
+            Activation::Relu => {
+                let zero = vdupq_n_f32(0.0);
+
+                v0 = vmaxq_f32(v0, zero);
+                v1 = vmaxq_f32(v1, zero);
+            }
+            Activation::Gelu => {
+                // no vector tanh; lane-wise
+                let mut lanes = [0.0; 8];
+                vst1q_f32(lanes.as_mut_ptr().add(0), v0);
+                vst1q_f32(lanes.as_mut_ptr().add(4), v1);
+
+                for x in lanes.iter_mut() {
+                    *x = gelu(*x);
+                }
+
+                v0 = vld1q_f32(lanes.as_ptr().add(0));
+                v1 = vld1q_f32(lanes.as_ptr().add(4));
+            }
+        }
+
+        if let Some((lo, hi)) = ep.clamp {
+            let lov = vdupq_n_f32(lo);
+            let hiv = vdupq_n_f32(hi);
+
+            v0 = vminq_f32(vmaxq_f32(v0, lov), hiv);
+            v1 = vminq_f32(vmaxq_f32(v1, lov), hiv);
+        }
+
+        if let Some(f) = ep.map {
+            let mut lanes = [0.0; 8];
+            vst1q_f32(lanes.as_mut_ptr().add(0), v0);
+            vst1q_f32(lanes.as_mut_ptr().add(4), v1);
+
+            for (r, x) in lanes.iter_mut().enumerate() {
+                *x = f(row + r, col, *x);
+            }
+
+            v0 = vld1q_f32(lanes.as_ptr().add(0));
+            v1 = vld1q_f32(lanes.as_ptr().add(4));
+        }
+
+        (v0, v1)
+    }
+}
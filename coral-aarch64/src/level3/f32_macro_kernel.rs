@@ -1,4 +1,7 @@
 use crate::level3::f32_packers::{MR, NR};
+use crate::level3::epilogue::Epilogue;
+use crate::level3::gemmt_tiles::{Tile, classify, in_triangle};
+use crate::enums::CoralTriangular;
 use crate::level3::microkernel::{
     f32_edge::f32_edge,
     f32_mrxnr::{
@@ -19,6 +22,9 @@ pub(crate) fn macro_kernel(
     b_pack : *const f32,
     c_base : *mut f32,
     ldc    : usize,
+    ep     : Option<&Epilogue<'_>>,
+    row0   : usize,
+    col0   : usize,
 ) {
     unsafe {
         let np = (nc + NR - 1) / NR;
@@ -33,26 +39,108 @@ pub(crate) fn macro_kernel(
                 let ap = a_pack.add(ip * kc * MR);
                 let cptr = c_base.add(ip * MR + (jp * NR) * ldc);
 
+                // global position of this micro-tile in C
+                let row = row0 + ip * MR;
+                let col = col0 + jp * NR;
+
                 if mr == MR && nr == NR {
                     if beta_panel == 0.0 {
 
-                        f32_mrxnr_beta0(kc, ap, bp, cptr, ldc, alpha);
+                        f32_mrxnr_beta0(kc, ap, bp, cptr, ldc, alpha, ep, row, col);
 
                     } else if beta_panel == 1.0 {
 
-                        f32_mrxnr_beta1(kc, ap, bp, cptr, ldc, alpha);
+                        f32_mrxnr_beta1(kc, ap, bp, cptr, ldc, alpha, ep, row, col);
 
                     } else {
 
-                        f32_mrxnr_betax(kc, ap, bp, cptr, ldc, alpha, beta_panel);
+                        f32_mrxnr_betax(kc, ap, bp, cptr, ldc, alpha, beta_panel, ep, row, col);
 
                     }
                 } else {
 
-                    f32_edge(mr, nr, kc, ap, bp, cptr, ldc, alpha, beta_panel);
+                    f32_edge(mr, nr, kc, ap, bp, cptr, ldc, alpha, beta_panel, ep, row, col);
                 }
             }
         }
     }
 }
 
+
+/// `macro_kernel` restricted to the `uplo` triangle of C.
+/// `(row0, col0)` is the global position of `c_base`.
+#[inline(always)]
+pub(crate) fn macro_kernel_tri(
+    mc         : usize,
+    nc         : usize,
+    kc         : usize,
+    alpha      : f32,
+    beta_panel : f32,
+    a_pack     : *const f32,
+    b_pack     : *const f32,
+    c_base     : *mut f32,
+    ldc        : usize,
+    uplo       : CoralTriangular,
+    row0       : usize,
+    col0       : usize,
+) {
+    unsafe {
+        let np = nc.div_ceil(NR);
+        let mp = mc.div_ceil(MR);
+
+        for jp in 0..np {
+            let nr = core::cmp::min(NR, nc - jp * NR);
+            let bp = b_pack.add(jp * kc * NR);
+
+            for ip in 0..mp {
+                let mr = core::cmp::min(MR, mc - ip * MR);
+                let ap = a_pack.add(ip * kc * MR);
+                let cptr = c_base.add(ip * MR + (jp * NR) * ldc);
+
+                let row = row0 + ip * MR;
+                let col = col0 + jp * NR;
+
+                match classify(uplo, row, col, mr, nr) {
+                    Tile::Outside => {}
+
+                    Tile::Inside if mr == MR && nr == NR => {
+                        if beta_panel == 0.0 {
+                            f32_mrxnr_beta0(kc, ap, bp, cptr, ldc, alpha, None, row, col);
+                        } else if beta_panel == 1.0 {
+                            f32_mrxnr_beta1(kc, ap, bp, cptr, ldc, alpha, None, row, col);
+                        } else {
+                            f32_mrxnr_betax(kc, ap, bp, cptr, ldc, alpha, beta_panel, None, row, col);
+                        }
+                    }
+
+                    Tile::Inside => {
+                        f32_edge(mr, nr, kc, ap, bp, cptr, ldc, alpha, beta_panel, None, row, col);
+                    }
+
+                    Tile::Diagonal => {
+                        // alpha * A * B into scratch, then merge the triangle
+                        let mut tmp = [0.0; MR * NR];
+                        f32_edge(mr, nr, kc, ap, bp, tmp.as_mut_ptr(), MR, alpha, 0.0, None, row, col);
+
+                        for j in 0..nr {
+                            let colp = cptr.add(j * ldc);
+
+                            for r in 0..mr {
+                                if !in_triangle(uplo, row + r, col + j) {
+                                    continue;
+                                }
+
+                                let t = tmp[r + j * MR];
+                                *colp.add(r) = if beta_panel == 0.0 {
+                                    t
+                                } else {
+                                    beta_panel * *colp.add(r) + t
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
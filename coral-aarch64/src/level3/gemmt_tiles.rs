@@ -0,0 +1,71 @@
+use crate::enums::CoralTriangular;
+
+/// where a micro-tile of C sits relative to the stored triangle.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Tile {
+    /// no entry of the tile is in the triangle; skipped.
+    Outside,
+    /// every entry of the tile is in the triangle.
+    Inside,
+    /// the tile crosses the diagonal; masked.
+    Diagonal,
+}
+
+/// classifies the `mr x nr` tile whose top-left entry is C[row, col].
+#[inline(always)]
+pub(crate) fn classify(
+    uplo : CoralTriangular,
+    row  : usize,
+    col  : usize,
+    mr   : usize,
+    nr   : usize,
+) -> Tile {
+    let row_last = row + mr - 1;
+    let col_last = col + nr - 1;
+
+    match uplo {
+        // keep i <= j
+        CoralTriangular::UpperTriangular => {
+            if row > col_last {
+                Tile::Outside
+            } else if row_last <= col {
+                Tile::Inside
+            } else {
+                Tile::Diagonal
+            }
+        }
+        // keep i >= j
+        CoralTriangular::LowerTriangular => {
+            if row_last < col {
+                Tile::Outside
+            } else if row >= col_last {
+                Tile::Inside
+            } else {
+                Tile::Diagonal
+            }
+        }
+    }
+}
+
+/// `true` if C[i, j] is in the triangle.
+#[inline(always)]
+pub(crate) fn in_triangle(uplo: CoralTriangular, i: usize, j: usize) -> bool {
+    match uplo {
+        CoralTriangular::UpperTriangular => i <= j,
+        CoralTriangular::LowerTriangular => i >= j,
+    }
+}
+
+/// rows `[start, end)` of C touched by the column block `[j0, j0 + nc)`.
+#[inline(always)]
+pub(crate) fn row_range(
+    uplo : CoralTriangular,
+    n    : usize,
+    j0   : usize,
+    nc   : usize,
+) -> (usize, usize) {
+    match uplo {
+        CoralTriangular::UpperTriangular => (0, j0 + nc),
+        CoralTriangular::LowerTriangular => (j0, n),
+    }
+}
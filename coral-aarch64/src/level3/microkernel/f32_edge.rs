@@ -1,4 +1,5 @@
 use crate::level3::f32_packers::{MR, NR}; 
+use crate::level3::epilogue::{Epilogue, apply};
 
 #[inline(always)] 
 pub(crate) fn f32_edge( 
@@ -11,6 +12,9 @@ pub(crate) fn f32_edge(
     ldc   : usize, 
     alpha : f32, 
     beta  : f32, 
+    ep    : Option<&Epilogue<'_>>,
+    row   : usize,
+    col   : usize,
 ) { 
     unsafe { 
         let mut acc = [[0.0; NR]; MR]; 
@@ -55,6 +59,12 @@ pub(crate) fn f32_edge(
                     *colp.add(r) = beta * *colp.add(r) + alpha * acc[r][ccol];
                 } 
             }
+
+            if let Some(ep) = ep {
+                for r in 0..mr {
+                    *colp.add(r) = apply(ep, row + r, col + ccol, *colp.add(r));
+                }
+            }
         }
 
     }       
@@ -1,4 +1,5 @@
 use crate::level3::f32_packers::{MR, NR};
+use crate::level3::epilogue::{Epilogue, apply_col8};
 use core::arch::aarch64::{
     float32x4_t,
     vdupq_n_f32, 
@@ -103,6 +104,9 @@ pub(crate) fn f32_mrxnr_beta0(
     c: *mut f32,
     ldc: usize,
     alpha: f32,
+    ep: Option<&Epilogue<'_>>,
+    row: usize,
+    col: usize,
 ) {
     unsafe {
         let mut acc0123: [float32x4_t; NR] = [vdupq_n_f32(0.0); NR];
@@ -161,8 +165,12 @@ pub(crate) fn f32_mrxnr_beta0(
         for j in 0..NR {
             let colp = c.add(j * ldc);
 
-            let v0123 = vmulq_n_f32(acc0123[j], alpha);
-            let v4567 = vmulq_n_f32(acc4567[j], alpha);
+            let mut v0123 = vmulq_n_f32(acc0123[j], alpha);
+            let mut v4567 = vmulq_n_f32(acc4567[j], alpha);
+
+            if let Some(ep) = ep {
+                (v0123, v4567) = apply_col8(ep, row, col + j, v0123, v4567);
+            }
 
             store_col8(colp, v0123, v4567);
         }
@@ -178,6 +186,9 @@ pub(crate) fn f32_mrxnr_beta1(
     c: *mut f32,
     ldc: usize,
     alpha: f32,
+    ep: Option<&Epilogue<'_>>,
+    row: usize,
+    col: usize,
 ) {
     unsafe {
         let mut acc0123: [float32x4_t; NR] = [vdupq_n_f32(0.0); NR];
@@ -237,6 +248,10 @@ pub(crate) fn f32_mrxnr_beta1(
             c0123 = vfmaq_laneq_f32(c0123, acc0123[j], alphv, 0);
             c4567 = vfmaq_laneq_f32(c4567, acc4567[j], alphv, 0);
 
+            if let Some(ep) = ep {
+                (c0123, c4567) = apply_col8(ep, row, col + j, c0123, c4567);
+            }
+
             store_col8(colp, c0123, c4567);
         }
     }
@@ -252,6 +267,9 @@ pub(crate) fn f32_mrxnr_betax(
     ldc: usize,
     alpha: f32,
     beta: f32,
+    ep: Option<&Epilogue<'_>>,
+    row: usize,
+    col: usize,
 ) {
     unsafe {
         let mut acc0123: [float32x4_t; NR] = [vdupq_n_f32(0.0); NR];
@@ -319,6 +337,10 @@ pub(crate) fn f32_mrxnr_betax(
             c0123 = vfmaq_laneq_f32(c0123, acc0123[j], alphv, 0);
             c4567 = vfmaq_laneq_f32(c4567, acc4567[j], alphv, 0);
 
+            if let Some(ep) = ep {
+                (c0123, c4567) = apply_col8(ep, row, col + j, c0123, c4567);
+            }
+
             store_col8(colp, c0123, c4567);
         }
     }
@@ -9,18 +9,27 @@ pub(crate) mod c32_macro_kernel;
 pub(crate) mod c64_macro_kernel; 
 
 pub(crate) mod microkernel; 
+pub(crate) mod gemmt_tiles;
+
+pub mod epilogue;
+pub mod packed;
+pub mod slice;
 
 pub mod sgemm; 
+pub mod sgemm_epilogue;
+pub mod sgemm_packed;
 pub(crate) mod sgemm_nn;
 pub(crate) mod sgemm_nt; 
 pub(crate) mod sgemm_tn; 
 pub(crate) mod sgemm_tt;
+pub mod sgemmt;
 
 pub mod dgemm;
 pub(crate) mod dgemm_nn;
 pub(crate) mod dgemm_nt; 
 pub(crate) mod dgemm_tn; 
 pub(crate) mod dgemm_tt;
+pub mod dgemmt;
 
 pub mod cgemm; 
 pub(crate) mod cgemm_nn;
@@ -32,6 +41,8 @@ pub(crate) mod cgemm_tc;
 pub(crate) mod cgemm_cn;
 pub(crate) mod cgemm_ct;
 pub(crate) mod cgemm_cc;
+pub mod cgemmt;
+pub mod cgemm_3m;
 
 pub mod zgemm;
 pub(crate) mod zgemm_nn;
@@ -43,8 +54,20 @@ pub(crate) mod zgemm_tc;
 pub(crate) mod zgemm_cn;
 pub(crate) mod zgemm_ct;
 pub(crate) mod zgemm_cc;
+pub mod zgemmt;
+pub mod zgemm_3m;
 
 pub use sgemm::sgemm;
+pub use sgemm_epilogue::sgemm_epilogue;
+pub use sgemm_packed::{sgemm_packed_a, sgemm_packed_b};
 pub use dgemm::dgemm;
 pub use cgemm::cgemm;
 pub use zgemm::zgemm;
+
+pub use sgemmt::sgemmt;
+pub use dgemmt::dgemmt;
+pub use cgemmt::cgemmt;
+pub use zgemmt::zgemmt;
+
+pub use cgemm_3m::cgemm_3m;
+pub use zgemm_3m::zgemm_3m;
@@ -0,0 +1,320 @@
+//! Pre-packed `GEMM` operands.
+//!
+//! [`sgemm`](crate::level3::sgemm) repacks both $\operatorname{op}(A)$ and
+//! $\operatorname{op}(B)$ into micro-panels on every call. When one operand
+//! is reused across many products (e.g. a fixed weight matrix), pack it once
+//! into a [`PackedA`] or [`PackedB`] and call
+//! [`sgemm_packed_a`](crate::level3::sgemm_packed_a) or
+//! [`sgemm_packed_b`](crate::level3::sgemm_packed_b), which skip packing for
+//! that operand.
+//!
+//! A packed operand stores the [`Blocking`] it was built with. The packed
+//! entry points panic if it does not match the blocking of the running
+//! kernel.
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::{sgemm_packed_b, packed::PackedB};
+//! use coral_aarch64::enums::CoralTranspose;
+//!
+//! fn main() {
+//!     let a = vec![1.0, 2.0, 3.0, 4.0];
+//!
+//!     // B = identity; packed once
+//!     let b = vec![1.0, 0.0, 0.0, 1.0];
+//!     let pb = PackedB::new(CoralTranspose::NoTranspose, 2, 2, b.as_ptr(), 2);
+//!
+//!     for _ in 0..3 {
+//!         let mut c = vec![0.0; 4];
+//!
+//!         sgemm_packed_b(
+//!             CoralTranspose::NoTranspose,
+//!             2,
+//!             1.0,
+//!             a.as_ptr(), 2,
+//!             &pb,
+//!             0.0,
+//!             c.as_mut_ptr(), 2,
+//!         );
+//!
+//!         assert_eq!(c, a);
+//!     }
+//! }
+//! ```
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::level3::{
+    sgemm::{MC, NC, KC, is_transpose},
+    f32_packers::{
+        MR, NR,
+        pack_a_block, pack_a_block_t,
+        pack_b_block, pack_b_block_t,
+        a_buf_len, b_buf_len,
+    },
+};
+use crate::enums::CoralTranspose;
+use crate::errors::Check;
+
+/// Cache and register blocking a packed operand was built with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Blocking {
+    /// Rows of $A$ per cache block.
+    pub mc : usize,
+    /// Columns of $B$ per cache block.
+    pub nc : usize,
+    /// Inner dimension per cache block.
+    pub kc : usize,
+    /// Microkernel rows.
+    pub mr : usize,
+    /// Microkernel columns.
+    pub nr : usize,
+}
+
+impl Blocking {
+    /// Blocking used by the `f32` kernels in this build.
+    pub const SGEMM: Blocking = Blocking { mc: MC, nc: NC, kc: KC, mr: MR, nr: NR };
+}
+
+/// $\operatorname{op}(B)$, a `k x n` matrix, packed into `kc x nc` panels.
+#[derive(Debug, Clone)]
+pub struct PackedB {
+    k        : usize,
+    n        : usize,
+    blocking : Blocking,
+    buf      : Vec<f32>,
+    // start of panel (jb, lb) at index jb * k_blocks + lb
+    offsets  : Vec<usize>,
+}
+
+impl PackedB {
+    /// Packs `op(B)` (`k x n`) from column-major `b` with leading dimension `ldb`.
+    ///
+    /// An invalid `ldb` is reported and gives an empty `0 x 0` operand.
+    pub fn new(
+        op_b : CoralTranspose,
+        k    : usize,
+        n    : usize,
+        b    : *const f32,
+        ldb  : usize,
+    ) -> Self {
+        let b_t = is_transpose(op_b);
+
+        let blocking = Blocking::SGEMM;
+
+        if !Check::new("PackedB::new").ld(5, ldb, if b_t { n } else { k }).ok() {
+            return Self { k: 0, n: 0, blocking, buf: Vec::new(), offsets: Vec::new() };
+        }
+
+        let mut offsets = Vec::new();
+        let mut len = 0;
+
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+
+                offsets.push(len);
+                len += b_buf_len(kcblk, nc);
+
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+
+        let mut buf = vec![0.0; len];
+        pack_b_panels(b_t, k, n, b, ldb, &offsets, &mut buf);
+
+        Self { k, n, blocking, buf, offsets }
+    }
+
+    /// Rows of `op(B)`.
+    #[inline]
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Columns of `op(B)`.
+    #[inline]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Blocking this operand was packed with.
+    #[inline]
+    pub fn blocking(&self) -> Blocking {
+        self.blocking
+    }
+
+    /// packed `kc x nc` panel starting at `(l0, j0)` of op(B).
+    #[inline(always)]
+    pub(crate) fn panel(&self, j0: usize, l0: usize) -> *const f32 {
+        let k_blocks = self.k.div_ceil(self.blocking.kc);
+        let idx = (j0 / self.blocking.nc) * k_blocks + l0 / self.blocking.kc;
+
+        unsafe { self.buf.as_ptr().add(self.offsets[idx]) }
+    }
+}
+
+/// $\operatorname{op}(A)$, an `m x k` matrix, packed into `mc x kc` panels.
+#[derive(Debug, Clone)]
+pub struct PackedA {
+    m        : usize,
+    k        : usize,
+    blocking : Blocking,
+    buf      : Vec<f32>,
+    // start of panel (lb, ib) at index lb * m_blocks + ib
+    offsets  : Vec<usize>,
+}
+
+impl PackedA {
+    /// Packs `op(A)` (`m x k`) from column-major `a` with leading dimension `lda`.
+    ///
+    /// An invalid `lda` is reported and gives an empty `0 x 0` operand.
+    pub fn new(
+        op_a : CoralTranspose,
+        m    : usize,
+        k    : usize,
+        a    : *const f32,
+        lda  : usize,
+    ) -> Self {
+        let a_t = is_transpose(op_a);
+
+        let blocking = Blocking::SGEMM;
+
+        if !Check::new("PackedA::new").ld(5, lda, if a_t { k } else { m }).ok() {
+            return Self { m: 0, k: 0, blocking, buf: Vec::new(), offsets: Vec::new() };
+        }
+
+        let mut offsets = Vec::new();
+        let mut len = 0;
+
+        let mut l0 = 0;
+        while l0 < k {
+            let kcblk = core::cmp::min(KC, k - l0);
+
+            let mut i0 = 0;
+            while i0 < m {
+                let mc = core::cmp::min(MC, m - i0);
+
+                offsets.push(len);
+                len += a_buf_len(mc, kcblk);
+
+                i0 += mc;
+            }
+
+            l0 += kcblk;
+        }
+
+        let mut buf = vec![0.0; len];
+        pack_a_panels(a_t, m, k, a, lda, &offsets, &mut buf);
+
+        Self { m, k, blocking, buf, offsets }
+    }
+
+    /// Rows of `op(A)`.
+    #[inline]
+    pub fn m(&self) -> usize {
+        self.m
+    }
+
+    /// Columns of `op(A)`.
+    #[inline]
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Blocking this operand was packed with.
+    #[inline]
+    pub fn blocking(&self) -> Blocking {
+        self.blocking
+    }
+
+    /// packed `mc x kc` panel starting at `(i0, l0)` of op(A).
+    #[inline(always)]
+    pub(crate) fn panel(&self, i0: usize, l0: usize) -> *const f32 {
+        let m_blocks = self.m.div_ceil(self.blocking.mc);
+        let idx = (l0 / self.blocking.kc) * m_blocks + i0 / self.blocking.mc;
+
+        unsafe { self.buf.as_ptr().add(self.offsets[idx]) }
+    }
+}
+
+// packs every `kc x nc` panel of op(B) into `buf` at `offsets`
+fn pack_b_panels(
+    b_t     : bool,
+    k       : usize,
+    n       : usize,
+    b       : *const f32,
+    ldb     : usize,
+    offsets : &[usize],
+    buf     : &mut [f32],
+) {
+    let mut p = 0;
+
+    unsafe {
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+                let dst = buf.as_mut_ptr().add(offsets[p]);
+
+                if b_t {
+                    pack_b_block_t(kcblk, nc, b.add(j0 + l0 * ldb), ldb, dst);
+                } else {
+                    pack_b_block(kcblk, nc, b.add(l0 + j0 * ldb), ldb, dst);
+                }
+
+                p  += 1;
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+    }
+}
+
+// packs every `mc x kc` panel of op(A) into `buf` at `offsets`
+fn pack_a_panels(
+    a_t     : bool,
+    m       : usize,
+    k       : usize,
+    a       : *const f32,
+    lda     : usize,
+    offsets : &[usize],
+    buf     : &mut [f32],
+) {
+    let mut p = 0;
+
+    unsafe {
+        let mut l0 = 0;
+        while l0 < k {
+            let kcblk = core::cmp::min(KC, k - l0);
+
+            let mut i0 = 0;
+            while i0 < m {
+                let mc = core::cmp::min(MC, m - i0);
+                let dst = buf.as_mut_ptr().add(offsets[p]);
+
+                if a_t {
+                    pack_a_block_t(mc, kcblk, a.add(l0 + i0 * lda), lda, dst);
+                } else {
+                    pack_a_block(mc, kcblk, a.add(i0 + l0 * lda), lda, dst);
+                }
+
+                p  += 1;
+                i0 += mc;
+            }
+
+            l0 += kcblk;
+        }
+    }
+}
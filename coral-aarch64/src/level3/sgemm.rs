@@ -25,6 +25,10 @@
 //! # Returns
 //! - Nothing. The contents of `C` are updated in place.
 //!
+//! # Notes
+//! - With the `reproducible` feature, every entry of `C` is one FMA chain over
+//!   ascending `k`, independent of `MC`, `NC`, `KC` and the microkernel shape.
+//!
 //! # Author 
 //! Deval Deliwala 
 //!
@@ -88,13 +92,15 @@ use crate::level3::{
     sgemm_tt::sgemm_tt
 };
 use crate::enums::CoralTranspose;
+use crate::errors::Check;
+use crate::reproducible;
 
 pub(crate) const MC: usize = 384; 
 pub(crate) const NC: usize = 576; 
 pub(crate) const KC: usize = 256;
 
 #[inline(always)]
-fn is_transpose(op: CoralTranspose) -> bool {
+pub(crate) fn is_transpose(op: CoralTranspose) -> bool {
     match op {
         CoralTranspose::NoTranspose        => false,
         CoralTranspose::Transpose          => true, 
@@ -121,11 +127,24 @@ pub fn sgemm(
     let a_t = is_transpose(op_a);
     let b_t = is_transpose(op_b);
 
+    if !Check::new("sgemm")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
+    if cfg!(feature = "reproducible") {
+        return reproducible::sgemm(a_t, b_t, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    }
+
     match (a_t, b_t) {
-        (false, false) => sgemm_nn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
-        (false, true ) => sgemm_nt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
-        (true , false) => sgemm_tn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
-        (true , true ) => sgemm_tt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc),
+        (false, false) => sgemm_nn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, None),
+        (false, true ) => sgemm_nt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, None),
+        (true , false) => sgemm_tn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, None),
+        (true , true ) => sgemm_tt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, None),
     }
 }
 
@@ -0,0 +1,96 @@
+//! `GEMM` with a fused epilogue. Single precision general matrix-multiply.
+//!
+//! \\[
+//! C := f\big(\alpha \operatorname{op}(A)\operatorname{op}(B) + \beta C\big), \quad
+//! \operatorname{op}(A) \in \\{A, A^{T}\\}.
+//! \\]
+//!
+//! $f$ is the element-wise [`Epilogue`]: bias, activation, clamp, and an
+//! optional user closure. It is applied by the microkernels after the final
+//! $k$-panel of each tile, so $C$ is only written once.
+//!
+//! $A$, $B$, and $C$ are stored in column-major order.
+//!
+//! # Arguments
+//! - `op_a`     (CoralTranspose) : Whether to transpose `A`.
+//! - `op_b`     (CoralTranspose) : Whether to transpose `B`.
+//! - `m`        (usize)          : Number of rows of `op(A)` and `C`.
+//! - `n`        (usize)          : Number of columns of `op(B)` and `C`.
+//! - `k`        (usize)          : Shared inner dimension of `op(A)` and `op(B)`.
+//! - `alpha`    (f32)            : Scalar multiplier for `op(A) * op(B)`.
+//! - `a`        (*const f32)     : Pointer to matrix `A`.
+//! - `lda`      (usize)          : Leading dimension of `A`.
+//! - `b`        (*const f32)     : Pointer to matrix `B`.
+//! - `ldb`      (usize)          : Leading dimension of `B`.
+//! - `beta`     (f32)            : Scalar multiplier for `C`.
+//! - `c`        (*mut f32)       : Pointer to matrix `C`.
+//! - `ldc`      (usize)          : Leading dimension of `C`.
+//! - `epilogue` (&Epilogue)      : Element-wise map applied to the result.
+//!
+//! # Returns
+//! - Nothing. The contents of `C` are updated in place.
+//!
+//! # Notes
+//! - A [`crate::level3::epilogue::Bias::Row`] vector must hold at least `m`
+//!   entries, a [`crate::level3::epilogue::Bias::Col`] vector at least `n`.
+//! - The epilogue is applied even when `alpha == 0` or `k == 0`.
+//! - An identity epilogue reduces to [`crate::level3::sgemm`].
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::level3::{
+    sgemm::is_transpose,
+    sgemm_nn::sgemm_nn,
+    sgemm_nt::sgemm_nt,
+    sgemm_tn::sgemm_tn,
+    sgemm_tt::sgemm_tt,
+    epilogue::{Epilogue, Bias},
+};
+use crate::enums::CoralTranspose;
+use crate::errors::Check;
+
+#[inline(always)]
+pub fn sgemm_epilogue(
+    op_a     : CoralTranspose,
+    op_b     : CoralTranspose,
+    m        : usize,
+    n        : usize,
+    k        : usize,
+    alpha    : f32,
+    a        : *const f32,
+    lda      : usize,
+    b        : *const f32,
+    ldb      : usize,
+    beta     : f32,
+    c        : *mut f32,
+    ldc      : usize,
+    epilogue : &Epilogue<'_>,
+) {
+    match epilogue.bias {
+        Bias::None    => {}
+        Bias::Row(bv) => assert!(bv.len() >= m, "row bias shorter than m"),
+        Bias::Col(bv) => assert!(bv.len() >= n, "col bias shorter than n"),
+    }
+
+    let ep = (!epilogue.is_identity()).then_some(epilogue);
+
+    let a_t = is_transpose(op_a);
+    let b_t = is_transpose(op_b);
+
+    if !Check::new("sgemm_epilogue")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
+    match (a_t, b_t) {
+        (false, false) => sgemm_nn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, ep),
+        (false, true ) => sgemm_nt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, ep),
+        (true , false) => sgemm_tn(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, ep),
+        (true , true ) => sgemm_tt(m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, ep),
+    }
+}
@@ -1,5 +1,6 @@
 use crate::level3::{
     sgemm::{MC, NC, KC},
+    epilogue::{Epilogue, apply_block},
     f32_macro_kernel::macro_kernel,
     f32_packers::{
         pack_a_block, pack_b_block_t, 
@@ -19,6 +20,7 @@ pub(crate) fn sgemm_nt(
     beta  : f32,
     c     : *mut f32,
     ldc   : usize,
+    ep    : Option<&Epilogue<'_>>,
 ) {
     debug_assert!(
         ldc >= m && lda >= m && ldb >= n,
@@ -41,6 +43,10 @@ pub(crate) fn sgemm_nt(
                     }
                 }
             }
+
+            if let Some(ep) = ep {
+                apply_block(ep, m, n, c, ldc, 0, 0);
+            }
             return;
         }
 
@@ -63,6 +69,9 @@ pub(crate) fn sgemm_nt(
 
                 let beta_panel = if l0 == 0 { beta } else { 1.0 };
 
+                // epilogue only once C is final
+                let ep_panel = if l0 + kcblk == k { ep } else { None };
+
                 let mut i0 = 0;
                 while i0 < m {
                     let mc = core::cmp::min(MC, m - i0);
@@ -85,6 +94,9 @@ pub(crate) fn sgemm_nt(
                         b_buf.as_ptr(),
                         c_base,
                         ldc,
+                        ep_panel,
+                        i0,
+                        j0,
                     );
 
                     i0 += mc;
@@ -0,0 +1,297 @@
+//! `GEMM` with one pre-packed operand. Single precision general matrix-multiply.
+//!
+//! \\[
+//! C := \alpha \operatorname{op}(A)\operatorname{op}(B) + \beta C, \quad
+//! \operatorname{op}(A) \in \\{A, A^{T}\\}.
+//! \\]
+//!
+//! [`sgemm_packed_b`] takes $\operatorname{op}(B)$ as a [`PackedB`] and packs
+//! only $A$; [`sgemm_packed_a`] takes $\operatorname{op}(A)$ as a [`PackedA`]
+//! and packs only $B$. The transpose of the packed operand is fixed when it
+//! is built. Dimensions of the packed operand are taken from it.
+//!
+//! $A$, $B$, and $C$ are stored in column-major order.
+//!
+//! # Arguments (`sgemm_packed_b`)
+//! - `op_a`  (CoralTranspose) : Whether to transpose `A`.
+//! - `m`     (usize)          : Number of rows of `op(A)` and `C`.
+//! - `alpha` (f32)            : Scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (*const f32)     : Pointer to matrix `A`.
+//! - `lda`   (usize)          : Leading dimension of `A`.
+//! - `b`     (&PackedB)       : Packed `op(B)`; supplies `k` and `n`.
+//! - `beta`  (f32)            : Scalar multiplier for `C`.
+//! - `c`     (*mut f32)       : Pointer to matrix `C`.
+//! - `ldc`   (usize)          : Leading dimension of `C`.
+//!
+//! # Arguments (`sgemm_packed_a`)
+//! - `op_b`  (CoralTranspose) : Whether to transpose `B`.
+//! - `n`     (usize)          : Number of columns of `op(B)` and `C`.
+//! - `alpha` (f32)            : Scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (&PackedA)       : Packed `op(A)`; supplies `m` and `k`.
+//! - `b`     (*const f32)     : Pointer to matrix `B`.
+//! - `ldb`   (usize)          : Leading dimension of `B`.
+//! - `beta`  (f32)            : Scalar multiplier for `C`.
+//! - `c`     (*mut f32)       : Pointer to matrix `C`.
+//! - `ldc`   (usize)          : Leading dimension of `C`.
+//!
+//! # Returns
+//! - Nothing. The contents of `C` are updated in place.
+//!
+//! # Notes
+//! - Panics if the packed operand's [`Blocking`] differs from
+//!   [`Blocking::SGEMM`].
+//! - Results are identical to [`crate::level3::sgemm`] on the same inputs.
+//!
+//! # Author
+//! Deval Deliwala
+
+use crate::level3::{
+    sgemm::{MC, NC, KC, is_transpose},
+    packed::{Blocking, PackedA, PackedB},
+    f32_macro_kernel::macro_kernel,
+    f32_packers::{
+        pack_a_block, pack_a_block_t,
+        pack_b_block, pack_b_block_t,
+        a_buf_len, b_buf_len,
+    },
+};
+use crate::enums::CoralTranspose;
+use crate::errors::Check;
+
+// C := beta * C; for alpha == 0 or k == 0
+#[inline(always)]
+fn scale_c(
+    m    : usize,
+    n    : usize,
+    beta : f32,
+    c    : *mut f32,
+    ldc  : usize,
+) {
+    unsafe {
+        if beta == 0.0 {
+            for j in 0..n {
+                let col = c.add(j * ldc);
+                core::ptr::write_bytes(col, 0, m);
+            }
+        } else if beta != 1.0 {
+            for j in 0..n {
+                let col = c.add(j * ldc);
+                for i in 0..m {
+                    *col.add(i) *= beta;
+                }
+            }
+        }
+    }
+}
+
+pub fn sgemm_packed_b(
+    op_a  : CoralTranspose,
+    m     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : &PackedB,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    assert_eq!(
+        b.blocking(), Blocking::SGEMM,
+        "PackedB was built with a different blocking"
+    );
+
+    let n = b.n();
+    let k = b.k();
+    let a_t = is_transpose(op_a);
+
+    if !Check::new("sgemm_packed_b")
+        .ld(5, lda, if a_t { k } else { m })
+        .ld(9, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
+    if alpha == 0.0 || k == 0 {
+        scale_c(m, n, beta, c, ldc);
+        return;
+    }
+
+    packed_b_driver(a_t, m, n, k, alpha, a, lda, b, beta, c, ldc);
+}
+
+fn packed_b_driver(
+    a_t   : bool,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : &PackedB,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    unsafe {
+        let mut a_buf = vec![0.0; a_buf_len(MC, KC)];
+
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+
+                // B panel already packed
+                let b_panel = b.panel(j0, l0);
+
+                let beta_panel = if l0 == 0 { beta } else { 1.0 };
+
+                let mut i0 = 0;
+                while i0 < m {
+                    let mc = core::cmp::min(MC, m - i0);
+
+                    // pack A (mc x kcblk) at (i0, l0)
+                    if a_t {
+                        let a_block_base = a.add(l0 + i0 * lda);
+                        pack_a_block_t(mc, kcblk, a_block_base, lda, a_buf.as_mut_ptr());
+                    } else {
+                        let a_block_base = a.add(i0 + l0 * lda);
+                        pack_a_block(mc, kcblk, a_block_base, lda, a_buf.as_mut_ptr());
+                    }
+
+                    let c_base = c.add(i0 + j0 * ldc);
+
+                    macro_kernel(
+                        mc,
+                        nc,
+                        kcblk,
+                        alpha,
+                        beta_panel,
+                        a_buf.as_ptr(),
+                        b_panel,
+                        c_base,
+                        ldc,
+                        None,
+                        i0,
+                        j0,
+                    );
+
+                    i0 += mc;
+                }
+
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+    }
+}
+
+pub fn sgemm_packed_a(
+    op_b  : CoralTranspose,
+    n     : usize,
+    alpha : f32,
+    a     : &PackedA,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    assert_eq!(
+        a.blocking(), Blocking::SGEMM,
+        "PackedA was built with a different blocking"
+    );
+
+    let m = a.m();
+    let k = a.k();
+    let b_t = is_transpose(op_b);
+
+    if !Check::new("sgemm_packed_a")
+        .ld(6, ldb, if b_t { n } else { k })
+        .ld(9, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
+    if alpha == 0.0 || k == 0 {
+        scale_c(m, n, beta, c, ldc);
+        return;
+    }
+
+    packed_a_driver(b_t, m, n, k, alpha, a, b, ldb, beta, c, ldc);
+}
+
+fn packed_a_driver(
+    b_t   : bool,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : &PackedA,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    unsafe {
+        let mut b_buf = vec![0.0; b_buf_len(KC, NC)];
+
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+
+                // pack B (kcblk x nc) starting at (l0, j0)
+                if b_t {
+                    let b_block_base = b.add(j0 + l0 * ldb);
+                    pack_b_block_t(kcblk, nc, b_block_base, ldb, b_buf.as_mut_ptr());
+                } else {
+                    let b_block_base = b.add(l0 + j0 * ldb);
+                    pack_b_block(kcblk, nc, b_block_base, ldb, b_buf.as_mut_ptr());
+                }
+
+                let beta_panel = if l0 == 0 { beta } else { 1.0 };
+
+                let mut i0 = 0;
+                while i0 < m {
+                    let mc = core::cmp::min(MC, m - i0);
+
+                    // A panel already packed
+                    let a_panel = a.panel(i0, l0);
+
+                    let c_base = c.add(i0 + j0 * ldc);
+
+                    macro_kernel(
+                        mc,
+                        nc,
+                        kcblk,
+                        alpha,
+                        beta_panel,
+                        a_panel,
+                        b_buf.as_ptr(),
+                        c_base,
+                        ldc,
+                        None,
+                        i0,
+                        j0,
+                    );
+
+                    i0 += mc;
+                }
+
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+    }
+}
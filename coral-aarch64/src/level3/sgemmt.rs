@@ -0,0 +1,214 @@
+//! `GEMMT`. Single precision general matrix-multiply, one triangle of $C$.
+//!
+//! \\[
+//! C := \alpha \operatorname{op}(A)\operatorname{op}(B) + \beta C, \quad
+//! \operatorname{op}(A) \in \\{A, A^{T}\\},
+//! \\]
+//!
+//! where only the `uplo` triangle of the $n \times n$ matrix $C$, diagonal
+//! included, is referenced and updated. Useful when the product is known to
+//! be symmetric.
+//!
+//! $A$, $B$, and $C$ are stored in column-major order.
+//!
+//! # Arguments
+//! - `uplo`  (CoralTriangular) : Which triangle of `C` to update.
+//! - `op_a`  (CoralTranspose)  : Whether to transpose `A`.
+//! - `op_b`  (CoralTranspose)  : Whether to transpose `B`.
+//! - `n`     (usize)           : Order of `C`; rows of `op(A)`, columns of `op(B)`.
+//! - `k`     (usize)           : Shared inner dimension of `op(A)` and `op(B)`.
+//! - `alpha` (f32)             : Scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (*const f32)      : Pointer to matrix `A`.
+//! - `lda`   (usize)           : Leading dimension of `A`.
+//! - `b`     (*const f32)      : Pointer to matrix `B`.
+//! - `ldb`   (usize)           : Leading dimension of `B`.
+//! - `beta`  (f32)             : Scalar multiplier for `C`.
+//! - `c`     (*mut f32)        : Pointer to matrix `C`.
+//! - `ldc`   (usize)           : Leading dimension of `C`.
+//!
+//! # Returns
+//! - Nothing. The `uplo` triangle of `C` is updated in place; the other
+//!   strict triangle is not touched.
+//!
+//! # Notes
+//! - Macro-tiles entirely outside the triangle are skipped; micro-tiles
+//!   crossing the diagonal are computed by the edge kernel and masked.
+//!
+//! # Author
+//! Deval Deliwala
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::sgemmt;
+//! use coral_aarch64::enums::{CoralTranspose, CoralTriangular};
+//!
+//! fn main() {
+//!     // A = [[1, 3],
+//!     //      [2, 4]]
+//!     let a = vec![1.0, 2.0, 3.0, 4.0];
+//!
+//!     // strict lower entry is a sentinel
+//!     let mut c = vec![0.0, -1.0, 0.0, 0.0];
+//!
+//!     // C := A * A^T, upper triangle only
+//!     sgemmt(
+//!         CoralTriangular::UpperTriangular,
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::Transpose,
+//!         2, 2,
+//!         1.0,
+//!         a.as_ptr(), 2,
+//!         a.as_ptr(), 2,
+//!         0.0,
+//!         c.as_mut_ptr(), 2,
+//!     );
+//!
+//!     assert_eq!(c, vec![10.0, -1.0, 14.0, 20.0]);
+//! }
+//! ```
+
+use crate::level3::{
+    sgemm::{MC, NC, KC, is_transpose},
+    f32_macro_kernel::macro_kernel_tri,
+    f32_packers::{
+        pack_a_block, pack_a_block_t,
+        pack_b_block, pack_b_block_t,
+        a_buf_len, b_buf_len,
+    },
+    gemmt_tiles::row_range,
+};
+use crate::enums::{CoralTranspose, CoralTriangular};
+use crate::errors::Check;
+
+type Packer = fn(usize, usize, *const f32, usize, *mut f32);
+
+#[inline(always)]
+pub fn sgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    let a_t = is_transpose(op_a);
+    let b_t = is_transpose(op_b);
+
+    if !Check::new("sgemmt")
+        .ld(8, lda, if a_t { k } else { n })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, n)
+        .ok()
+    {
+        return;
+    }
+
+    sgemmt_driver(uplo, a_t, b_t, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+}
+
+fn sgemmt_driver(
+    uplo  : CoralTriangular,
+    a_t   : bool,
+    b_t   : bool,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    unsafe {
+        if alpha == 0.0 || k == 0 {
+            // scale the triangle of C by beta
+            for j in 0..n {
+                let (r0, r1) = row_range(uplo, n, j, 1);
+                let col = c.add(j * ldc);
+
+                for i in r0..r1 {
+                    *col.add(i) = if beta == 0.0 { 0.0 } else { beta * *col.add(i) };
+                }
+            }
+            return;
+        }
+
+        let pack_a: Packer = if a_t { pack_a_block_t } else { pack_a_block };
+        let pack_b: Packer = if b_t { pack_b_block_t } else { pack_b_block };
+
+        let mut a_buf = vec![0.0; a_buf_len(MC, KC)];
+        let mut b_buf = vec![0.0; b_buf_len(KC, NC)];
+
+        let mut j0 = 0;
+        while j0 < n {
+            let nc = core::cmp::min(NC, n - j0);
+
+            // rows of C this column block reaches into
+            let (r0, r1) = row_range(uplo, n, j0, nc);
+
+            let mut l0 = 0;
+            while l0 < k {
+                let kcblk = core::cmp::min(KC, k - l0);
+
+                // pack B (kcblk x nc) starting at (l0, j0)
+                {
+                    let b_block_base = if b_t {
+                        b.add(j0 + l0 * ldb)
+                    } else {
+                        b.add(l0 + j0 * ldb)
+                    };
+                    pack_b(kcblk, nc, b_block_base, ldb, b_buf.as_mut_ptr());
+                }
+
+                let beta_panel = if l0 == 0 { beta } else { 1.0 };
+
+                let mut i0 = r0;
+                while i0 < r1 {
+                    let mc = core::cmp::min(MC, r1 - i0);
+
+                    // pack A (mc x kcblk) at (i0, l0)
+                    {
+                        let a_block_base = if a_t {
+                            a.add(l0 + i0 * lda)
+                        } else {
+                            a.add(i0 + l0 * lda)
+                        };
+                        pack_a(mc, kcblk, a_block_base, lda, a_buf.as_mut_ptr());
+                    }
+
+                    let c_base = c.add(i0 + j0 * ldc);
+
+                    macro_kernel_tri(
+                        mc,
+                        nc,
+                        kcblk,
+                        alpha,
+                        beta_panel,
+                        a_buf.as_ptr(),
+                        b_buf.as_ptr(),
+                        c_base,
+                        ldc,
+                        uplo,
+                        i0,
+                        j0,
+                    );
+
+                    i0 += mc;
+                }
+
+                l0 += kcblk;
+            }
+
+            j0 += nc;
+        }
+    }
+}
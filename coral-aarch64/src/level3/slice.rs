@@ -0,0 +1,218 @@
+//! `GEMM` on slices.
+//!
+//! \\[
+//! C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C, \quad
+//!    \operatorname{op}(A) \in \\{A, A^T, A^H\\}.
+//! \\]
+//!
+//! Same arguments and storage as [`crate::level3::sgemm`],
+//! [`crate::level3::dgemm`], [`crate::level3::cgemm`] and
+//! [`crate::level3::zgemm`], with `A`, `B` and `C` passed as slices like the
+//! level1 and level2 routines.
+//!
+//! # Arguments
+//! - `op_a`  (CoralTranspose) : Whether to transpose or conjugate-transpose `A`.
+//! - `op_b`  (CoralTranspose) : Whether to transpose or conjugate-transpose `B`.
+//! - `m`     (usize)          : Number of rows of `op(A)` and `C`.
+//! - `n`     (usize)          : Number of columns of `op(B)` and `C`.
+//! - `k`     (usize)          : Shared inner dimension of `op(A)` and `op(B)`.
+//! - `alpha` (T)              : Scalar multiplier for `op(A) * op(B)`.
+//! - `a`     (&[T])           : Matrix `A`.
+//! - `lda`   (usize)          : Leading dimension of `A`.
+//! - `b`     (&[T])           : Matrix `B`.
+//! - `ldb`   (usize)          : Leading dimension of `B`.
+//! - `beta`  (T)              : Scalar multiplier for `C`.
+//! - `c`     (&mut [T])       : Matrix `C`.
+//! - `ldc`   (usize)          : Leading dimension of `C`.
+//!
+//! # Returns
+//! - Nothing. The contents of `C` are updated in place.
+//!
+//! # Notes
+//! - `lda`, `ldb`, `ldc` and the lengths of `a`, `b` and `c` are checked
+//!   against `m`, `n` and `k` before any packing. A failed check is
+//!   reported through [`crate::errors`] and `C` is left untouched.
+//! - Complex slices are interleaved `[re, im]`; leading dimensions count
+//!   complex elements.
+//!
+//! # Author
+//! Deval Deliwala
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::level3::slice::sgemm;
+//! use coral_aarch64::enums::CoralTranspose;
+//!
+//! fn main() {
+//!     let a = vec![1.0, 2.0, 3.0, 4.0];
+//!     let b = vec![5.0, 6.0, 7.0, 8.0];
+//!     let mut c = vec![0.0; 4];
+//!
+//!     sgemm(
+//!         CoralTranspose::NoTranspose,
+//!         CoralTranspose::NoTranspose,
+//!         2, 2, 2,
+//!         1.0,
+//!         &a, 2,
+//!         &b, 2,
+//!         0.0,
+//!         &mut c, 2,
+//!     );
+//!
+//!     assert_eq!(c, vec![23.0, 34.0, 31.0, 46.0]);
+//! }
+//! ```
+
+use crate::enums::CoralTranspose;
+use crate::errors::Check;
+use crate::level3;
+
+/// stored `(rows, cols)` of a matrix whose op is `rows x cols`.
+#[inline(always)]
+fn stored(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (rows, cols),
+        _                           => (cols, rows),
+    }
+}
+
+/// checks `lda`, `ldb`, `ldc` and the lengths of `a`, `b`, `c`.
+/// `cplx` counts two scalars per entry.
+#[inline(always)]
+fn gemm_ok(
+    routine : &'static str,
+    cplx    : bool,
+    op_a    : CoralTranspose,
+    op_b    : CoralTranspose,
+    m       : usize,
+    n       : usize,
+    k       : usize,
+    a_len   : usize,
+    lda     : usize,
+    b_len   : usize,
+    ldb     : usize,
+    c_len   : usize,
+    ldc     : usize,
+) -> bool {
+    let (a_rows, a_cols) = stored(op_a, m, k);
+    let (b_rows, b_cols) = stored(op_b, k, n);
+
+    let check = Check::new(routine)
+        .ld(8,  lda, a_rows)
+        .ld(10, ldb, b_rows)
+        .ld(13, ldc, m);
+
+    if cplx {
+        check
+            .cmat(7,  a_len, a_rows, a_cols, lda)
+            .cmat(9,  b_len, b_rows, b_cols, ldb)
+            .cmat(12, c_len, m, n, ldc)
+            .ok()
+    } else {
+        check
+            .mat(7,  a_len, a_rows, a_cols, lda)
+            .mat(9,  b_len, b_rows, b_cols, ldb)
+            .mat(12, c_len, m, n, ldc)
+            .ok()
+    }
+}
+
+#[inline]
+pub fn sgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : &[f32],
+    lda   : usize,
+    b     : &[f32],
+    ldb   : usize,
+    beta  : f32,
+    c     : &mut [f32],
+    ldc   : usize,
+) {
+    if !gemm_ok("sgemm", false, op_a, op_b, m, n, k, a.len(), lda, b.len(), ldb, c.len(), ldc) {
+        return;
+    }
+
+    level3::sgemm(
+        op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc,
+    );
+}
+
+#[inline]
+pub fn dgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f64,
+    a     : &[f64],
+    lda   : usize,
+    b     : &[f64],
+    ldb   : usize,
+    beta  : f64,
+    c     : &mut [f64],
+    ldc   : usize,
+) {
+    if !gemm_ok("dgemm", false, op_a, op_b, m, n, k, a.len(), lda, b.len(), ldb, c.len(), ldc) {
+        return;
+    }
+
+    level3::dgemm(
+        op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc,
+    );
+}
+
+#[inline]
+pub fn cgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : &[f32],
+    lda   : usize,
+    b     : &[f32],
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : &mut [f32],
+    ldc   : usize,
+) {
+    if !gemm_ok("cgemm", true, op_a, op_b, m, n, k, a.len(), lda, b.len(), ldb, c.len(), ldc) {
+        return;
+    }
+
+    level3::cgemm(
+        op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc,
+    );
+}
+
+#[inline]
+pub fn zgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f64; 2],
+    a     : &[f64],
+    lda   : usize,
+    b     : &[f64],
+    ldb   : usize,
+    beta  : [f64; 2],
+    c     : &mut [f64],
+    ldc   : usize,
+) {
+    if !gemm_ok("zgemm", true, op_a, op_b, m, n, k, a.len(), lda, b.len(), ldb, c.len(), ldc) {
+        return;
+    }
+
+    level3::zgemm(
+        op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc,
+    );
+}
@@ -108,6 +108,7 @@ use crate::level3::{
 };
 
 use crate::enums::CoralTranspose;
+use crate::errors::Check;
 use crate::level3::microkernel::c64_mrxnr::Complex64;
 
 pub(crate) const MC: usize = 256;
@@ -135,6 +136,18 @@ pub fn zgemm(
     c     : *mut f64,
     ldc   : usize,
 ) {
+    let a_t = !matches!(op_a, CoralTranspose::NoTranspose);
+    let b_t = !matches!(op_b, CoralTranspose::NoTranspose);
+
+    if !Check::new("zgemm")
+        .ld(8, lda, if a_t { k } else { m })
+        .ld(10, ldb, if b_t { n } else { k })
+        .ld(13, ldc, m)
+        .ok()
+    {
+        return;
+    }
+
     let alpha = z64(alpha);
     let beta  = z64(beta);
 
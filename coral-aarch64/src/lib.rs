@@ -2,8 +2,18 @@
 //! 
 //! - no dependencies.
 //! - column-major only. 
-//! - level1 and level2 routines fully implemented. 
-//! - level3 only has `GEMM`. 
+//! - level1 and level2 routines fully implemented, with signed increments
+//!   as in reference BLAS.
+//! - compensated `?dot_accurate`, `?asum_accurate` and `?nrm2_accurate`, and
+//!   `?gemv_with` taking an [`enums::Accuracy`], for ill-conditioned sums.
+//! - level3 has `GEMM` (plus a 3M complex variant) and `GEMMT`. `GEMM` also
+//!   takes slices through [`level3::slice`].
+//! - arguments are checked in release builds too; see [`errors`].
+//! - plain reference versions of every routine sit behind the `reference`
+//!   feature; see `reference`.
+//! - the `reproducible` feature fixes the summation order of `sdot`, `sasum`,
+//!   `snrm2`, transposed `sgemv` and `sgemm`, so they return the same bits on
+//!   every build, whatever the alignment, strides or blocking.
 //!
 //! benchmarks: <https://dev-undergrad.dev/posts/benchmarks/>
 //!
@@ -78,7 +88,16 @@ pub mod level3;
 
 pub mod enums;
 
+#[cfg_attr(not(target_arch = "aarch64"), allow(dead_code))]
+pub mod errors;
+
 #[cfg(target_arch = "aarch64")]
 pub(crate) mod level1_special;
 
+#[cfg(target_arch = "aarch64")]
+pub(crate) mod reproducible;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
 
@@ -0,0 +1,728 @@
+//! Reference level1 routines.
+//!
+//! Complex vectors are interleaved `[re, im]`; `w` below counts scalars per
+//! entry, `1` for real and `2` for complex.
+
+use super::{Real, at, cadd, cget, cmul, conj, cset, dot2};
+
+fn asum<T: Real>(n: usize, x: &[T], incx: isize, w: usize) -> T {
+    let mut sum = T::ZERO;
+    if n == 0 || incx <= 0 {
+        return sum;
+    }
+
+    for i in 0..n {
+        let o = w * i * incx as usize;
+        for p in 0..w {
+            sum = sum + x[o + p].abs();
+        }
+    }
+    sum
+}
+
+fn iamax<T: Real>(n: usize, x: &[T], incx: isize, w: usize) -> usize {
+    if n == 0 || incx <= 0 {
+        return 0;
+    }
+
+    // first index of the largest |re| + |im|
+    let mut best = 0;
+    let mut max  = T::ZERO;
+    for i in 0..n {
+        let o = w * i * incx as usize;
+
+        let mut v = T::ZERO;
+        for p in 0..w {
+            v = v + x[o + p].abs();
+        }
+        if i == 0 || v > max {
+            best = i;
+            max  = v;
+        }
+    }
+    best
+}
+
+fn nrm2<T: Real>(n: usize, x: &[T], incx: isize, w: usize) -> T {
+    if n == 0 || incx == 0 {
+        return T::ZERO;
+    }
+    let step = incx.unsigned_abs();
+
+    // sqrt(sum |x_i|^2), scaled by max |x_i| so the squares cannot overflow
+    let mut scale = T::ZERO;
+    for i in 0..n {
+        for p in 0..w {
+            let v = x[w * i * step + p].abs();
+            if v > scale {
+                scale = v;
+            }
+        }
+    }
+    if scale == T::ZERO {
+        return T::ZERO;
+    }
+
+    let mut ssq = T::ZERO;
+    for i in 0..n {
+        for p in 0..w {
+            let v = x[w * i * step + p] / scale;
+            ssq = ssq + v * v;
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+fn scal<T: Real>(n: usize, alpha: T, x: &mut [T], incx: isize, w: usize) {
+    if n == 0 || incx <= 0 {
+        return;
+    }
+
+    for i in 0..n {
+        let o = w * i * incx as usize;
+        for p in 0..w {
+            x[o + p] = alpha * x[o + p];
+        }
+    }
+}
+
+fn scal_c<T: Real>(n: usize, alpha: [T; 2], x: &mut [T], incx: isize) {
+    if n == 0 || incx <= 0 {
+        return;
+    }
+
+    for i in 0..n {
+        let k = i * incx as usize;
+        cset(x, k, cmul(alpha, cget(x, k)));
+    }
+}
+
+fn copy<T: Real>(n: usize, x: &[T], incx: isize, y: &mut [T], incy: isize, w: usize) {
+    for i in 0..n {
+        let (ox, oy) = (w * at(i, n, incx), w * at(i, n, incy));
+        y[oy..oy + w].copy_from_slice(&x[ox..ox + w]);
+    }
+}
+
+fn swap<T: Real>(n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize, w: usize) {
+    for i in 0..n {
+        let (ox, oy) = (w * at(i, n, incx), w * at(i, n, incy));
+        for p in 0..w {
+            core::mem::swap(&mut x[ox + p], &mut y[oy + p]);
+        }
+    }
+}
+
+fn axpy<T: Real>(n: usize, alpha: T, x: &[T], incx: isize, y: &mut [T], incy: isize) {
+    if n == 0 || alpha == T::ZERO {
+        return;
+    }
+
+    for i in 0..n {
+        let (ix, iy) = (at(i, n, incx), at(i, n, incy));
+        y[iy] = y[iy] + alpha * x[ix];
+    }
+}
+
+fn axpy_c<T: Real>(n: usize, alpha: [T; 2], x: &[T], incx: isize, y: &mut [T], incy: isize) {
+    if n == 0 || (alpha[0] == T::ZERO && alpha[1] == T::ZERO) {
+        return;
+    }
+
+    for i in 0..n {
+        let (ix, iy) = (at(i, n, incx), at(i, n, incy));
+        cset(y, iy, cadd(cget(y, iy), cmul(alpha, cget(x, ix))));
+    }
+}
+
+fn dot<T: Real>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T {
+    let mut sum = T::ZERO;
+    for i in 0..n {
+        sum = sum + x[at(i, n, incx)] * y[at(i, n, incy)];
+    }
+    sum
+}
+
+fn cdot<T: Real>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize, conjugate: bool) -> [T; 2] {
+    let mut sum = [T::ZERO; 2];
+    for i in 0..n {
+        let xi = cget(x, at(i, n, incx));
+        let xi = if conjugate { conj(xi) } else { xi };
+
+        sum = cadd(sum, cmul(xi, cget(y, at(i, n, incy))));
+    }
+    sum
+}
+
+fn dot_accurate<T: Real>(n: usize, x: &[T], incx: isize, y: &[T], incy: isize) -> T {
+    T::from_f64(dot2((0..n).map(|i| (x[at(i, n, incx)].to_f64(), y[at(i, n, incy)].to_f64()))))
+}
+
+fn asum_accurate<T: Real>(n: usize, x: &[T], incx: isize) -> T {
+    if n == 0 || incx <= 0 {
+        return T::ZERO;
+    }
+    T::from_f64(dot2((0..n).map(|i| (x[i * incx as usize].abs().to_f64(), 1.0))))
+}
+
+fn nrm2_accurate<T: Real>(n: usize, x: &[T], incx: isize) -> T {
+    if n == 0 || incx == 0 {
+        return T::ZERO;
+    }
+    let step = incx.unsigned_abs();
+
+    // scaled by max |x_i| as in nrm2, squares summed with dot2
+    let scale = (0..n).map(|i| x[i * step].abs().to_f64()).fold(0.0f64, |m, v| {
+        if m.is_nan() || v.is_nan() { f64::NAN } else { m.max(v) }
+    });
+    if scale == 0.0 || !scale.is_finite() {
+        return T::from_f64(scale);
+    }
+
+    let ssq = dot2((0..n).map(|i| {
+        let v = x[i * step].to_f64() / scale;
+        (v, v)
+    }));
+    T::from_f64(scale * ssq.sqrt())
+}
+
+/// `x := c x + s y`, `y := c y - s x`, each scalar of an entry on its own.
+fn rot<T: Real>(
+    n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize, c: T, s: T, w: usize,
+) {
+    for i in 0..n {
+        let (ox, oy) = (w * at(i, n, incx), w * at(i, n, incy));
+        for p in 0..w {
+            let (xv, yv) = (x[ox + p], y[oy + p]);
+            x[ox + p] = c * xv + s * yv;
+            y[oy + p] = c * yv - s * xv;
+        }
+    }
+}
+
+fn complex_rot<T: Real>(
+    n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize, c: T, s: [T; 2],
+) {
+    for i in 0..n {
+        let (ix, iy) = (at(i, n, incx), at(i, n, incy));
+        let (xv, yv) = (cget(x, ix), cget(y, iy));
+
+        // x' = c x + s y,  y' = c y - conj(s) x
+        let cx = [c * xv[0], c * xv[1]];
+        let cy = [c * yv[0], c * yv[1]];
+        let sx = cmul(conj(s), xv);
+        cset(x, ix, cadd(cx, cmul(s, yv)));
+        cset(y, iy, [cy[0] - sx[0], cy[1] - sx[1]]);
+    }
+}
+
+fn rotm<T: Real>(n: usize, x: &mut [T], incx: isize, y: &mut [T], incy: isize, param: &[T; 5]) {
+    let flag = param[0];
+    let two  = T::ONE + T::ONE;
+    if n == 0 || flag == -two {
+        return;
+    }
+
+    // H = [h11 h12; h21 h22], column-major in param[1..]
+    let (h11, h21, h12, h22) = if flag < T::ZERO {
+        (param[1], param[2], param[3], param[4])
+    } else if flag == T::ZERO {
+        (T::ONE, param[2], param[3], T::ONE)
+    } else {
+        (param[1], -T::ONE, T::ONE, param[4])
+    };
+
+    for i in 0..n {
+        let (ix, iy) = (at(i, n, incx), at(i, n, incy));
+        let (xv, yv) = (x[ix], y[iy]);
+        x[ix] = h11 * xv + h12 * yv;
+        y[iy] = h21 * xv + h22 * yv;
+    }
+}
+
+fn rotg<T: Real>(a: &mut T, b: &mut T, c: &mut T, s: &mut T) {
+    let scale = a.abs() + b.abs();
+    if scale == T::ZERO {
+        *c = T::ONE;
+        *s = T::ZERO;
+        *a = T::ZERO;
+        *b = T::ZERO;
+        return;
+    }
+
+    // r = sign(roe) sqrt(a^2 + b^2), roe the larger of a, b in magnitude
+    let roe = if a.abs() > b.abs() { *a } else { *b };
+    let (sa, sb) = (*a / scale, *b / scale);
+    let mut r = scale * (sa * sa + sb * sb).sqrt();
+    if roe < T::ZERO {
+        r = -r;
+    }
+
+    *c = *a / r;
+    *s = *b / r;
+
+    // z lets the caller rebuild (c, s)
+    let mut z = T::ONE;
+    if a.abs() > b.abs() {
+        z = *s;
+    }
+    if b.abs() >= a.abs() && *c != T::ZERO {
+        z = T::ONE / *c;
+    }
+
+    *a = r;
+    *b = z;
+}
+
+fn complex_rotg<T: Real>(a: &mut [T; 2], b: [T; 2], c: &mut T, s: &mut [T; 2]) {
+    let norm = |z: [T; 2]| (z[0] * z[0] + z[1] * z[1]).sqrt();
+
+    if b[0] == T::ZERO && b[1] == T::ZERO {
+        *c = T::ONE;
+        *s = [T::ZERO, T::ZERO];
+        return;
+    }
+
+    let fa = norm(*a);
+    if fa == T::ZERO {
+        let gb = norm(b);
+        *c = T::ZERO;
+        *s = [b[0] / gb, -b[1] / gb];
+        *a = [gb, T::ZERO];
+        return;
+    }
+
+    // c = |a| / d, s = (a / |a|) conj(b) / d, r = (a / |a|) d
+    let d     = (fa * fa + norm(b) * norm(b)).sqrt();
+    let alpha = [a[0] / fa, a[1] / fa];
+    let sb    = cmul(alpha, conj(b));
+
+    *c = fa / d;
+    *s = [sb[0] / d, sb[1] / d];
+    *a = [alpha[0] * d, alpha[1] * d];
+}
+
+fn rotmg<T: Real>(d1: &mut T, d2: &mut T, x1: &mut T, y1: T, param: &mut [T; 5], gam: T) {
+    let gamsq  = gam * gam;
+    let rgamsq = T::ONE / gamsq;
+    let two    = T::ONE + T::ONE;
+
+    let (mut h11, mut h12, mut h21, mut h22) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    let mut flag;
+
+    if *d1 < T::ZERO {
+        flag = -T::ONE;
+        *d1  = T::ZERO;
+        *d2  = T::ZERO;
+        *x1  = T::ZERO;
+    } else {
+        let p2 = *d2 * y1;
+        if p2 == T::ZERO {
+            param[0] = -two;
+            return;
+        }
+
+        let p1 = *d1 * *x1;
+        let q2 = p2 * y1;
+        let q1 = p1 * *x1;
+
+        if q1.abs() > q2.abs() {
+            h21 = -y1 / *x1;
+            h12 = p2 / p1;
+            let u = T::ONE - h12 * h21;
+
+            if u <= T::ZERO {
+                flag = -T::ONE;
+                (h11, h12, h21, h22) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+                *d1 = T::ZERO;
+                *d2 = T::ZERO;
+                *x1 = T::ZERO;
+            } else {
+                flag = T::ZERO;
+                *d1 = *d1 / u;
+                *d2 = *d2 / u;
+                *x1 = *x1 * u;
+            }
+        } else if q2 < T::ZERO {
+            flag = -T::ONE;
+            (h11, h12, h21, h22) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+            *d1 = T::ZERO;
+            *d2 = T::ZERO;
+            *x1 = T::ZERO;
+        } else {
+            flag = T::ONE;
+            h11 = p1 / p2;
+            h22 = *x1 / y1;
+            let u = T::ONE + h11 * h22;
+
+            let tmp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = tmp;
+            *x1 = y1 * u;
+        }
+
+        // rescale d1, d2 into [1 / gam^2, gam^2]
+        if *d1 != T::ZERO {
+            while *d1 <= rgamsq || *d1 >= gamsq {
+                if flag == T::ZERO {
+                    h11 = T::ONE;
+                    h22 = T::ONE;
+                } else {
+                    h21 = -T::ONE;
+                    h12 = T::ONE;
+                }
+                flag = -T::ONE;
+
+                if *d1 <= rgamsq {
+                    *d1 = *d1 * gamsq;
+                    *x1 = *x1 / gam;
+                    h11 = h11 / gam;
+                    h12 = h12 / gam;
+                } else {
+                    *d1 = *d1 / gamsq;
+                    *x1 = *x1 * gam;
+                    h11 = h11 * gam;
+                    h12 = h12 * gam;
+                }
+            }
+        }
+
+        if *d2 != T::ZERO {
+            while d2.abs() <= rgamsq || d2.abs() >= gamsq {
+                if flag == T::ZERO {
+                    h11 = T::ONE;
+                    h22 = T::ONE;
+                } else {
+                    h21 = -T::ONE;
+                    h12 = T::ONE;
+                }
+                flag = -T::ONE;
+
+                if d2.abs() <= rgamsq {
+                    *d2 = *d2 * gamsq;
+                    h21 = h21 / gam;
+                    h22 = h22 / gam;
+                } else {
+                    *d2 = *d2 / gamsq;
+                    h21 = h21 * gam;
+                    h22 = h22 * gam;
+                }
+            }
+        }
+    }
+
+    param[1] = T::ZERO;
+    param[2] = T::ZERO;
+    param[3] = T::ZERO;
+    param[4] = T::ZERO;
+
+    if flag < T::ZERO {
+        param[1] = h11;
+        param[2] = h21;
+        param[3] = h12;
+        param[4] = h22;
+    } else if flag == T::ZERO {
+        param[2] = h21;
+        param[3] = h12;
+    } else {
+        param[1] = h11;
+        param[4] = h22;
+    }
+    param[0] = flag;
+}
+
+/// `SASUM`: $\sum_i |x_i|$.
+pub fn sasum(n: usize, x: &[f32], incx: isize) -> f32 {
+    asum(n, x, incx, 1)
+}
+
+/// `DASUM`: $\sum_i |x_i|$.
+pub fn dasum(n: usize, x: &[f64], incx: isize) -> f64 {
+    asum(n, x, incx, 1)
+}
+
+/// `SCASUM`: $\sum_i |\operatorname{Re} x_i| + |\operatorname{Im} x_i|$.
+pub fn scasum(n: usize, x: &[f32], incx: isize) -> f32 {
+    asum(n, x, incx, 2)
+}
+
+/// `DZASUM`: $\sum_i |\operatorname{Re} x_i| + |\operatorname{Im} x_i|$.
+pub fn dzasum(n: usize, x: &[f64], incx: isize) -> f64 {
+    asum(n, x, incx, 2)
+}
+
+/// `ISAMAX`: 0-based index of the first largest $|x_i|$.
+pub fn isamax(n: usize, x: &[f32], incx: isize) -> usize {
+    iamax(n, x, incx, 1)
+}
+
+/// `IDAMAX`: 0-based index of the first largest $|x_i|$.
+pub fn idamax(n: usize, x: &[f64], incx: isize) -> usize {
+    iamax(n, x, incx, 1)
+}
+
+/// `ICAMAX`: 0-based index of the first largest $|\operatorname{Re} x_i| + |\operatorname{Im} x_i|$.
+pub fn icamax(n: usize, x: &[f32], incx: isize) -> usize {
+    iamax(n, x, incx, 2)
+}
+
+/// `IZAMAX`: 0-based index of the first largest $|\operatorname{Re} x_i| + |\operatorname{Im} x_i|$.
+pub fn izamax(n: usize, x: &[f64], incx: isize) -> usize {
+    iamax(n, x, incx, 2)
+}
+
+/// `SNRM2`: $\\|x\\|_2$.
+pub fn snrm2(n: usize, x: &[f32], incx: isize) -> f32 {
+    nrm2(n, x, incx, 1)
+}
+
+/// `DNRM2`: $\\|x\\|_2$.
+pub fn dnrm2(n: usize, x: &[f64], incx: isize) -> f64 {
+    nrm2(n, x, incx, 1)
+}
+
+/// `SCNRM2`: $\\|x\\|_2$.
+pub fn scnrm2(n: usize, x: &[f32], incx: isize) -> f32 {
+    nrm2(n, x, incx, 2)
+}
+
+/// `DZNRM2`: $\\|x\\|_2$.
+pub fn dznrm2(n: usize, x: &[f64], incx: isize) -> f64 {
+    nrm2(n, x, incx, 2)
+}
+
+/// `SSCAL`: $x := \alpha x$.
+pub fn sscal(n: usize, alpha: f32, x: &mut [f32], incx: isize) {
+    scal(n, alpha, x, incx, 1)
+}
+
+/// `DSCAL`: $x := \alpha x$.
+pub fn dscal(n: usize, alpha: f64, x: &mut [f64], incx: isize) {
+    scal(n, alpha, x, incx, 1)
+}
+
+/// `CSCAL`: $x := \alpha x$.
+pub fn cscal(n: usize, alpha: [f32; 2], x: &mut [f32], incx: isize) {
+    scal_c(n, alpha, x, incx)
+}
+
+/// `ZSCAL`: $x := \alpha x$.
+pub fn zscal(n: usize, alpha: [f64; 2], x: &mut [f64], incx: isize) {
+    scal_c(n, alpha, x, incx)
+}
+
+/// `CSSCAL`: $x := \alpha x$, real $\alpha$.
+pub fn csscal(n: usize, alpha: f32, x: &mut [f32], incx: isize) {
+    scal(n, alpha, x, incx, 2)
+}
+
+/// `ZDSCAL`: $x := \alpha x$, real $\alpha$.
+pub fn zdscal(n: usize, alpha: f64, x: &mut [f64], incx: isize) {
+    scal(n, alpha, x, incx, 2)
+}
+
+/// `SCOPY`: $y := x$.
+pub fn scopy(n: usize, x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    copy(n, x, incx, y, incy, 1)
+}
+
+/// `DCOPY`: $y := x$.
+pub fn dcopy(n: usize, x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    copy(n, x, incx, y, incy, 1)
+}
+
+/// `CCOPY`: $y := x$.
+pub fn ccopy(n: usize, x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    copy(n, x, incx, y, incy, 2)
+}
+
+/// `ZCOPY`: $y := x$.
+pub fn zcopy(n: usize, x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    copy(n, x, incx, y, incy, 2)
+}
+
+/// `SSWAP`: $x \leftrightarrow y$.
+pub fn sswap(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize) {
+    swap(n, x, incx, y, incy, 1)
+}
+
+/// `DSWAP`: $x \leftrightarrow y$.
+pub fn dswap(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize) {
+    swap(n, x, incx, y, incy, 1)
+}
+
+/// `CSWAP`: $x \leftrightarrow y$.
+pub fn cswap(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize) {
+    swap(n, x, incx, y, incy, 2)
+}
+
+/// `ZSWAP`: $x \leftrightarrow y$.
+pub fn zswap(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize) {
+    swap(n, x, incx, y, incy, 2)
+}
+
+/// `SAXPY`: $y := \alpha x + y$.
+pub fn saxpy(n: usize, alpha: f32, x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    axpy(n, alpha, x, incx, y, incy)
+}
+
+/// `DAXPY`: $y := \alpha x + y$.
+pub fn daxpy(n: usize, alpha: f64, x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    axpy(n, alpha, x, incx, y, incy)
+}
+
+/// `CAXPY`: $y := \alpha x + y$.
+pub fn caxpy(n: usize, alpha: [f32; 2], x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    axpy_c(n, alpha, x, incx, y, incy)
+}
+
+/// `ZAXPY`: $y := \alpha x + y$.
+pub fn zaxpy(n: usize, alpha: [f64; 2], x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    axpy_c(n, alpha, x, incx, y, incy)
+}
+
+/// `SDOT`: $x^T y$.
+pub fn sdot(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f32 {
+    dot(n, x, incx, y, incy)
+}
+
+/// `DDOT`: $x^T y$.
+pub fn ddot(n: usize, x: &[f64], incx: isize, y: &[f64], incy: isize) -> f64 {
+    dot(n, x, incx, y, incy)
+}
+
+/// `CDOTU`: $x^T y$.
+pub fn cdotu(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> [f32; 2] {
+    cdot(n, x, incx, y, incy, false)
+}
+
+/// `ZDOTU`: $x^T y$.
+pub fn zdotu(n: usize, x: &[f64], incx: isize, y: &[f64], incy: isize) -> [f64; 2] {
+    cdot(n, x, incx, y, incy, false)
+}
+
+/// `CDOTC`: $x^H y$.
+pub fn cdotc(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> [f32; 2] {
+    cdot(n, x, incx, y, incy, true)
+}
+
+/// `ZDOTC`: $x^H y$.
+pub fn zdotc(n: usize, x: &[f64], incx: isize, y: &[f64], incy: isize) -> [f64; 2] {
+    cdot(n, x, incx, y, incy, true)
+}
+
+/// `SDSDOT`: $s_b + x^T y$, summed in `f64`.
+pub fn sdsdot(n: usize, sb: f32, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f32 {
+    (sb as f64 + dsdot(n, x, incx, y, incy)) as f32
+}
+
+/// `DSDOT`: $x^T y$ over `f32` inputs, summed in `f64`.
+pub fn dsdot(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f64 {
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += x[at(i, n, incx)] as f64 * y[at(i, n, incy)] as f64;
+    }
+    sum
+}
+
+/// `sdot_accurate`: $x^T y$, compensated.
+pub fn sdot_accurate(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f32 {
+    dot_accurate(n, x, incx, y, incy)
+}
+
+/// `ddot_accurate`: $x^T y$, compensated.
+pub fn ddot_accurate(n: usize, x: &[f64], incx: isize, y: &[f64], incy: isize) -> f64 {
+    dot_accurate(n, x, incx, y, incy)
+}
+
+/// `sasum_accurate`: $\sum_i |x_i|$, compensated.
+pub fn sasum_accurate(n: usize, x: &[f32], incx: isize) -> f32 {
+    asum_accurate(n, x, incx)
+}
+
+/// `dasum_accurate`: $\sum_i |x_i|$, compensated.
+pub fn dasum_accurate(n: usize, x: &[f64], incx: isize) -> f64 {
+    asum_accurate(n, x, incx)
+}
+
+/// `snrm2_accurate`: $\\|x\\|_2$, compensated.
+pub fn snrm2_accurate(n: usize, x: &[f32], incx: isize) -> f32 {
+    nrm2_accurate(n, x, incx)
+}
+
+/// `dnrm2_accurate`: $\\|x\\|_2$, compensated.
+pub fn dnrm2_accurate(n: usize, x: &[f64], incx: isize) -> f64 {
+    nrm2_accurate(n, x, incx)
+}
+
+/// `SROT`: applies the plane rotation $(c, s)$ to $(x, y)$.
+pub fn srot(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, c: f32, s: f32) {
+    rot(n, x, incx, y, incy, c, s, 1)
+}
+
+/// `DROT`: applies the plane rotation $(c, s)$ to $(x, y)$.
+pub fn drot(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, c: f64, s: f64) {
+    rot(n, x, incx, y, incy, c, s, 1)
+}
+
+/// `CSROT`: applies the real plane rotation $(c, s)$ to complex $(x, y)$.
+pub fn csrot(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, c: f32, s: f32) {
+    rot(n, x, incx, y, incy, c, s, 2)
+}
+
+/// `ZDROT`: applies the real plane rotation $(c, s)$ to complex $(x, y)$.
+pub fn zdrot(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, c: f64, s: f64) {
+    rot(n, x, incx, y, incy, c, s, 2)
+}
+
+/// `SROTG`: Givens rotation zeroing `b`.
+pub fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
+    rotg(a, b, c, s)
+}
+
+/// `DROTG`: Givens rotation zeroing `b`.
+pub fn drotg(a: &mut f64, b: &mut f64, c: &mut f64, s: &mut f64) {
+    rotg(a, b, c, s)
+}
+
+/// `CROTG`: Givens rotation with real `c` and complex `s` zeroing `b`.
+pub fn crotg(a: &mut [f32; 2], b: [f32; 2], c: &mut f32, s: &mut [f32; 2]) {
+    complex_rotg(a, b, c, s)
+}
+
+/// `ZROTG`: Givens rotation with real `c` and complex `s` zeroing `b`.
+pub fn zrotg(a: &mut [f64; 2], b: [f64; 2], c: &mut f64, s: &mut [f64; 2]) {
+    complex_rotg(a, b, c, s)
+}
+
+/// `CROT`: applies the rotation with real $c$ and complex $s$ to complex $(x, y)$.
+pub fn crot(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, c: f32, s: [f32; 2]) {
+    complex_rot(n, x, incx, y, incy, c, s)
+}
+
+/// `ZROT`: applies the rotation with real $c$ and complex $s$ to complex $(x, y)$.
+pub fn zrot(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, c: f64, s: [f64; 2]) {
+    complex_rot(n, x, incx, y, incy, c, s)
+}
+
+/// `SROTM`: applies the modified rotation `H` in `param` to $(x, y)$.
+pub fn srotm(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, param: &[f32; 5]) {
+    rotm(n, x, incx, y, incy, param)
+}
+
+/// `DROTM`: applies the modified rotation `H` in `param` to $(x, y)$.
+pub fn drotm(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, param: &[f64; 5]) {
+    rotm(n, x, incx, y, incy, param)
+}
+
+/// `SROTMG`: modified Givens rotation zeroing the second entry of
+/// $(\sqrt{d_1} x_1, \sqrt{d_2} y_1)$.
+pub fn srotmg(sd1: &mut f32, sd2: &mut f32, sx1: &mut f32, sy1: f32, param: &mut [f32; 5]) {
+    rotmg(sd1, sd2, sx1, sy1, param, 4096.0)
+}
+
+/// `DROTMG`: modified Givens rotation zeroing the second entry of
+/// $(\sqrt{d_1} x_1, \sqrt{d_2} y_1)$.
+pub fn drotmg(sd1: &mut f64, sd2: &mut f64, sx1: &mut f64, sy1: f64, param: &mut [f64; 5]) {
+    rotmg(sd1, sd2, sx1, sy1, param, 4096.0)
+}
@@ -0,0 +1,884 @@
+//! Reference level2 routines.
+//!
+//! Matrices are column-major; complex ones are interleaved `[re, im]` with
+//! `lda` counting complex entries. Each routine builds the entries of the
+//! operator it applies, e.g. `op(A)[i, j]`, and takes plain sums over them.
+
+use crate::enums::{Accuracy, CoralDiagonal, CoralTranspose, CoralTriangular};
+
+use super::{Real, at, cadd, cdiv, cget, cmul, conj, cset, dot2};
+
+/// `true` if `(i, j)` lies in the `uplo` triangle, diagonal included.
+#[inline]
+fn stored(uplo: CoralTriangular, i: usize, j: usize) -> bool {
+    match uplo {
+        CoralTriangular::UpperTriangular => i <= j,
+        CoralTriangular::LowerTriangular => i >= j,
+    }
+}
+
+/// lengths of `x` and `y` in `y := op(A) x` for an `m x n` matrix `A`.
+#[inline]
+fn op_dims(trans: CoralTranspose, m: usize, n: usize) -> (usize, usize) {
+    match trans {
+        CoralTranspose::NoTranspose => (n, m),
+        _                           => (m, n),
+    }
+}
+
+/// `op(A)[i, j]` of a real matrix.
+#[inline]
+fn op_r<T: Real>(trans: CoralTranspose, a: &[T], lda: usize, i: usize, j: usize) -> T {
+    match trans {
+        CoralTranspose::NoTranspose => a[i + j * lda],
+        _                           => a[j + i * lda],
+    }
+}
+
+/// `op(A)[i, j]` of a complex matrix.
+#[inline]
+fn op_c<T: Real>(trans: CoralTranspose, a: &[T], lda: usize, i: usize, j: usize) -> [T; 2] {
+    match trans {
+        CoralTranspose::NoTranspose        => cget(a, i + j * lda),
+        CoralTranspose::Transpose          => cget(a, j + i * lda),
+        CoralTranspose::ConjugateTranspose => conj(cget(a, j + i * lda)),
+    }
+}
+
+/// `op(T)[i, j]` of a real triangular matrix.
+#[inline]
+fn tri_r<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    a: &[T], lda: usize, i: usize, j: usize,
+) -> T {
+    let (r, c) = match trans {
+        CoralTranspose::NoTranspose => (i, j),
+        _                           => (j, i),
+    };
+
+    if r == c && matches!(diag, CoralDiagonal::UnitDiagonal) {
+        T::ONE
+    } else if stored(uplo, r, c) {
+        a[r + c * lda]
+    } else {
+        T::ZERO
+    }
+}
+
+/// `op(T)[i, j]` of a complex triangular matrix.
+#[inline]
+fn tri_c<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    a: &[T], lda: usize, i: usize, j: usize,
+) -> [T; 2] {
+    let (r, c) = match trans {
+        CoralTranspose::NoTranspose => (i, j),
+        _                           => (j, i),
+    };
+
+    if r == c && matches!(diag, CoralDiagonal::UnitDiagonal) {
+        [T::ONE, T::ZERO]
+    } else if !stored(uplo, r, c) {
+        [T::ZERO; 2]
+    } else if matches!(trans, CoralTranspose::ConjugateTranspose) {
+        conj(cget(a, r + c * lda))
+    } else {
+        cget(a, r + c * lda)
+    }
+}
+
+/// `true` if `op(T)` is lower triangular.
+#[inline]
+fn op_lower(uplo: CoralTriangular, trans: CoralTranspose) -> bool {
+    matches!(uplo, CoralTriangular::LowerTriangular) == matches!(trans, CoralTranspose::NoTranspose)
+}
+
+fn gemv_r<T: Real>(
+    trans: CoralTranspose, m: usize, n: usize, alpha: T, a: &[T], lda: usize,
+    x: &[T], incx: isize, beta: T, y: &mut [T], incy: isize,
+) {
+    if m == 0 || n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+    let (lx, ly) = op_dims(trans, m, n);
+
+    for i in 0..ly {
+        let iy = at(i, ly, incy);
+
+        let mut sum = T::ZERO;
+        for j in 0..lx {
+            sum = sum + op_r(trans, a, lda, i, j) * x[at(j, lx, incx)];
+        }
+
+        let old = if beta == T::ZERO { T::ZERO } else { beta * y[iy] };
+        y[iy] = old + alpha * sum;
+    }
+}
+
+/// [`gemv_r`] with every entry of `op(A) x` summed by [`dot2`].
+fn gemv_dot2<T: Real>(
+    trans: CoralTranspose, m: usize, n: usize, alpha: T, a: &[T], lda: usize,
+    x: &[T], incx: isize, beta: T, y: &mut [T], incy: isize,
+) {
+    if m == 0 || n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+    let (lx, ly) = op_dims(trans, m, n);
+
+    for i in 0..ly {
+        let iy = at(i, ly, incy);
+
+        let terms = (0..lx).map(|j| (op_r(trans, a, lda, i, j).to_f64(), x[at(j, lx, incx)].to_f64()));
+        let sum   = T::from_f64(dot2(terms));
+
+        let old = if beta == T::ZERO { T::ZERO } else { beta * y[iy] };
+        y[iy] = old + alpha * sum;
+    }
+}
+
+fn gemv_c<T: Real>(
+    trans: CoralTranspose, m: usize, n: usize, alpha: [T; 2], a: &[T], lda: usize,
+    x: &[T], incx: isize, beta: [T; 2], y: &mut [T], incy: isize,
+) {
+    let zero = [T::ZERO; 2];
+    if m == 0 || n == 0 || (alpha == zero && beta == [T::ONE, T::ZERO]) {
+        return;
+    }
+    let (lx, ly) = op_dims(trans, m, n);
+
+    for i in 0..ly {
+        let iy = at(i, ly, incy);
+
+        let mut sum = zero;
+        for j in 0..lx {
+            sum = cadd(sum, cmul(op_c(trans, a, lda, i, j), cget(x, at(j, lx, incx))));
+        }
+
+        let old = if beta == zero { zero } else { cmul(beta, cget(y, iy)) };
+        cset(y, iy, cadd(old, cmul(alpha, sum)));
+    }
+}
+
+fn trmv_r<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    n: usize, a: &[T], lda: usize, x: &mut [T], incx: isize,
+) {
+    let b: Vec<T> = (0..n).map(|j| x[at(j, n, incx)]).collect();
+
+    for i in 0..n {
+        let mut sum = T::ZERO;
+        for (j, &bj) in b.iter().enumerate() {
+            sum = sum + tri_r(uplo, trans, diag, a, lda, i, j) * bj;
+        }
+        x[at(i, n, incx)] = sum;
+    }
+}
+
+fn trmv_c<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    n: usize, a: &[T], lda: usize, x: &mut [T], incx: isize,
+) {
+    let b: Vec<[T; 2]> = (0..n).map(|j| cget(x, at(j, n, incx))).collect();
+
+    for i in 0..n {
+        let mut sum = [T::ZERO; 2];
+        for (j, &bj) in b.iter().enumerate() {
+            sum = cadd(sum, cmul(tri_c(uplo, trans, diag, a, lda, i, j), bj));
+        }
+        cset(x, at(i, n, incx), sum);
+    }
+}
+
+fn trsv_r<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    n: usize, a: &[T], lda: usize, x: &mut [T], incx: isize,
+) {
+    // forward substitution for a lower op(T), backward for an upper one
+    let order: Vec<usize> = if op_lower(uplo, trans) {
+        (0..n).collect()
+    } else {
+        (0..n).rev().collect()
+    };
+
+    for (k, &i) in order.iter().enumerate() {
+        let mut sum = x[at(i, n, incx)];
+        for &j in &order[..k] {
+            sum = sum - tri_r(uplo, trans, diag, a, lda, i, j) * x[at(j, n, incx)];
+        }
+        x[at(i, n, incx)] = sum / tri_r(uplo, trans, diag, a, lda, i, i);
+    }
+}
+
+fn trsv_c<T: Real>(
+    uplo: CoralTriangular, trans: CoralTranspose, diag: CoralDiagonal,
+    n: usize, a: &[T], lda: usize, x: &mut [T], incx: isize,
+) {
+    let order: Vec<usize> = if op_lower(uplo, trans) {
+        (0..n).collect()
+    } else {
+        (0..n).rev().collect()
+    };
+
+    for (k, &i) in order.iter().enumerate() {
+        let mut sum = cget(x, at(i, n, incx));
+        for &j in &order[..k] {
+            let t = cmul(tri_c(uplo, trans, diag, a, lda, i, j), cget(x, at(j, n, incx)));
+            sum = [sum[0] - t[0], sum[1] - t[1]];
+        }
+        cset(x, at(i, n, incx), cdiv(sum, tri_c(uplo, trans, diag, a, lda, i, i)));
+    }
+}
+
+fn symv_r<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: T, a: &[T], lda: usize,
+    x: &[T], incx: isize, beta: T, y: &mut [T], incy: isize,
+) {
+    if n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+
+    for i in 0..n {
+        let iy = at(i, n, incy);
+
+        let mut sum = T::ZERO;
+        for j in 0..n {
+            let aij = if stored(uplo, i, j) { a[i + j * lda] } else { a[j + i * lda] };
+            sum = sum + aij * x[at(j, n, incx)];
+        }
+
+        let old = if beta == T::ZERO { T::ZERO } else { beta * y[iy] };
+        y[iy] = old + alpha * sum;
+    }
+}
+
+fn hemv_c<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: [T; 2], a: &[T], lda: usize,
+    x: &[T], incx: isize, beta: [T; 2], y: &mut [T], incy: isize,
+) {
+    let zero = [T::ZERO; 2];
+    if n == 0 || (alpha == zero && beta == [T::ONE, T::ZERO]) {
+        return;
+    }
+
+    for i in 0..n {
+        let iy = at(i, n, incy);
+
+        let mut sum = zero;
+        for j in 0..n {
+            // the imaginary part of the diagonal is taken to be zero
+            let aij = if i == j {
+                [a[2 * (i + i * lda)], T::ZERO]
+            } else if stored(uplo, i, j) {
+                cget(a, i + j * lda)
+            } else {
+                conj(cget(a, j + i * lda))
+            };
+            sum = cadd(sum, cmul(aij, cget(x, at(j, n, incx))));
+        }
+
+        let old = if beta == zero { zero } else { cmul(beta, cget(y, iy)) };
+        cset(y, iy, cadd(old, cmul(alpha, sum)));
+    }
+}
+
+fn ger_r<T: Real>(
+    m: usize, n: usize, alpha: T, x: &[T], incx: isize, y: &[T], incy: isize,
+    a: &mut [T], lda: usize,
+) {
+    if m == 0 || n == 0 || alpha == T::ZERO {
+        return;
+    }
+
+    for j in 0..n {
+        for i in 0..m {
+            a[i + j * lda] = a[i + j * lda] + alpha * x[at(i, m, incx)] * y[at(j, n, incy)];
+        }
+    }
+}
+
+fn ger_c<T: Real>(
+    m: usize, n: usize, alpha: [T; 2], x: &[T], incx: isize, y: &[T], incy: isize,
+    a: &mut [T], lda: usize, conjugate: bool,
+) {
+    if m == 0 || n == 0 || alpha == [T::ZERO; 2] {
+        return;
+    }
+
+    for j in 0..n {
+        let yj = cget(y, at(j, n, incy));
+        let yj = if conjugate { conj(yj) } else { yj };
+
+        for i in 0..m {
+            let t = cmul(alpha, cmul(cget(x, at(i, m, incx)), yj));
+            cset(a, i + j * lda, cadd(cget(a, i + j * lda), t));
+        }
+    }
+}
+
+fn syr_r<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: T, x: &[T], incx: isize, a: &mut [T], lda: usize,
+) {
+    if n == 0 || alpha == T::ZERO {
+        return;
+    }
+
+    for j in 0..n {
+        for i in (0..n).filter(|&i| stored(uplo, i, j)) {
+            a[i + j * lda] = a[i + j * lda] + alpha * x[at(i, n, incx)] * x[at(j, n, incx)];
+        }
+    }
+}
+
+fn her_c<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: T, x: &[T], incx: isize, a: &mut [T], lda: usize,
+) {
+    if n == 0 || alpha == T::ZERO {
+        return;
+    }
+
+    for j in 0..n {
+        let xj = conj(cget(x, at(j, n, incx)));
+
+        for i in (0..n).filter(|&i| stored(uplo, i, j)) {
+            let t = cmul([alpha, T::ZERO], cmul(cget(x, at(i, n, incx)), xj));
+            let mut v = cadd(cget(a, i + j * lda), t);
+            if i == j {
+                v[1] = T::ZERO;
+            }
+            cset(a, i + j * lda, v);
+        }
+    }
+}
+
+fn syr2_r<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: T, x: &[T], incx: isize, y: &[T], incy: isize,
+    a: &mut [T], lda: usize,
+) {
+    if n == 0 || alpha == T::ZERO {
+        return;
+    }
+
+    for j in 0..n {
+        for i in (0..n).filter(|&i| stored(uplo, i, j)) {
+            let (xi, xj) = (x[at(i, n, incx)], x[at(j, n, incx)]);
+            let (yi, yj) = (y[at(i, n, incy)], y[at(j, n, incy)]);
+            a[i + j * lda] = a[i + j * lda] + alpha * xi * yj + alpha * yi * xj;
+        }
+    }
+}
+
+fn her2_c<T: Real>(
+    uplo: CoralTriangular, n: usize, alpha: [T; 2], x: &[T], incx: isize, y: &[T], incy: isize,
+    a: &mut [T], lda: usize,
+) {
+    if n == 0 || alpha == [T::ZERO; 2] {
+        return;
+    }
+
+    for j in 0..n {
+        let (xj, yj) = (cget(x, at(j, n, incx)), cget(y, at(j, n, incy)));
+
+        for i in (0..n).filter(|&i| stored(uplo, i, j)) {
+            let (xi, yi) = (cget(x, at(i, n, incx)), cget(y, at(i, n, incy)));
+
+            // alpha x y^H + conj(alpha) y x^H
+            let t = cadd(
+                cmul(alpha, cmul(xi, conj(yj))),
+                cmul(conj(alpha), cmul(yi, conj(xj))),
+            );
+            let mut v = cadd(cget(a, i + j * lda), t);
+            if i == j {
+                v[1] = T::ZERO;
+            }
+            cset(a, i + j * lda, v);
+        }
+    }
+}
+
+/// `SGEMV`: $y := \alpha \operatorname{op}(A) x + \beta y$.
+pub fn sgemv(
+    trans   : CoralTranspose,
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : f32,
+    matrix  : &[f32],
+    lda     : usize,
+    x       : &[f32],
+    incx    : isize,
+    beta    : f32,
+    y       : &mut [f32],
+    incy    : isize,
+) {
+    gemv_r(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `DGEMV`: $y := \alpha \operatorname{op}(A) x + \beta y$.
+pub fn dgemv(
+    trans   : CoralTranspose,
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : f64,
+    matrix  : &[f64],
+    lda     : usize,
+    x       : &[f64],
+    incx    : isize,
+    beta    : f64,
+    y       : &mut [f64],
+    incy    : isize,
+) {
+    gemv_r(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `CGEMV`: $y := \alpha \operatorname{op}(A) x + \beta y$.
+pub fn cgemv(
+    trans   : CoralTranspose,
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f32; 2],
+    matrix  : &[f32],
+    lda     : usize,
+    x       : &[f32],
+    incx    : isize,
+    beta    : [f32; 2],
+    y       : &mut [f32],
+    incy    : isize,
+) {
+    gemv_c(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `ZGEMV`: $y := \alpha \operatorname{op}(A) x + \beta y$.
+pub fn zgemv(
+    trans   : CoralTranspose,
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f64; 2],
+    matrix  : &[f64],
+    lda     : usize,
+    x       : &[f64],
+    incx    : isize,
+    beta    : [f64; 2],
+    y       : &mut [f64],
+    incy    : isize,
+) {
+    gemv_c(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `sgemv_with`: [`sgemv`] with a choice of [`Accuracy`].
+pub fn sgemv_with(
+    accuracy : Accuracy,
+    trans    : CoralTranspose,
+    n_rows   : usize,
+    n_cols   : usize,
+    alpha    : f32,
+    matrix   : &[f32],
+    lda      : usize,
+    x        : &[f32],
+    incx     : isize,
+    beta     : f32,
+    y        : &mut [f32],
+    incy     : isize,
+) {
+    match accuracy {
+        Accuracy::Fast        => gemv_r   (trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
+        Accuracy::Compensated => gemv_dot2(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
+    }
+}
+
+/// `dgemv_with`: [`dgemv`] with a choice of [`Accuracy`].
+pub fn dgemv_with(
+    accuracy : Accuracy,
+    trans    : CoralTranspose,
+    n_rows   : usize,
+    n_cols   : usize,
+    alpha    : f64,
+    matrix   : &[f64],
+    lda      : usize,
+    x        : &[f64],
+    incx     : isize,
+    beta     : f64,
+    y        : &mut [f64],
+    incy     : isize,
+) {
+    match accuracy {
+        Accuracy::Fast        => gemv_r   (trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
+        Accuracy::Compensated => gemv_dot2(trans, n_rows, n_cols, alpha, matrix, lda, x, incx, beta, y, incy),
+    }
+}
+
+/// `STRMV`: $x := \operatorname{op}(A) x$, $A$ triangular.
+pub fn strmv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f32],
+    lda         : usize,
+    x           : &mut [f32],
+    incx        : isize,
+) {
+    trmv_r(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `DTRMV`: $x := \operatorname{op}(A) x$, $A$ triangular.
+pub fn dtrmv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f64],
+    lda         : usize,
+    x           : &mut [f64],
+    incx        : isize,
+) {
+    trmv_r(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `CTRMV`: $x := \operatorname{op}(A) x$, $A$ triangular.
+pub fn ctrmv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f32],
+    lda         : usize,
+    x           : &mut [f32],
+    incx        : isize,
+) {
+    trmv_c(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `ZTRMV`: $x := \operatorname{op}(A) x$, $A$ triangular.
+pub fn ztrmv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f64],
+    lda         : usize,
+    x           : &mut [f64],
+    incx        : isize,
+) {
+    trmv_c(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `STRSV`: $x := \operatorname{op}(A)^{-1} x$, $A$ triangular.
+pub fn strsv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f32],
+    lda         : usize,
+    x           : &mut [f32],
+    incx        : isize,
+) {
+    trsv_r(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `DTRSV`: $x := \operatorname{op}(A)^{-1} x$, $A$ triangular.
+pub fn dtrsv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f64],
+    lda         : usize,
+    x           : &mut [f64],
+    incx        : isize,
+) {
+    trsv_r(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `CTRSV`: $x := \operatorname{op}(A)^{-1} x$, $A$ triangular.
+pub fn ctrsv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f32],
+    lda         : usize,
+    x           : &mut [f32],
+    incx        : isize,
+) {
+    trsv_c(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `ZTRSV`: $x := \operatorname{op}(A)^{-1} x$, $A$ triangular.
+pub fn ztrsv(
+    uplo        : CoralTriangular,
+    transpose   : CoralTranspose,
+    diagonal    : CoralDiagonal,
+    n           : usize,
+    matrix      : &[f64],
+    lda         : usize,
+    x           : &mut [f64],
+    incx        : isize,
+) {
+    trsv_c(uplo, transpose, diagonal, n, matrix, lda, x, incx)
+}
+
+/// `SSYMV`: $y := \alpha A x + \beta y$, $A$ symmetric.
+pub fn ssymv(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f32,
+    matrix  : &[f32],
+    lda     : usize,
+    x       : &[f32],
+    incx    : isize,
+    beta    : f32,
+    y       : &mut [f32],
+    incy    : isize,
+) {
+    symv_r(uplo, n, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `DSYMV`: $y := \alpha A x + \beta y$, $A$ symmetric.
+pub fn dsymv(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f64,
+    matrix  : &[f64],
+    lda     : usize,
+    x       : &[f64],
+    incx    : isize,
+    beta    : f64,
+    y       : &mut [f64],
+    incy    : isize,
+) {
+    symv_r(uplo, n, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `CHEMV`: $y := \alpha A x + \beta y$, $A$ Hermitian.
+pub fn chemv(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : [f32; 2],
+    matrix  : &[f32],
+    lda     : usize,
+    x       : &[f32],
+    incx    : isize,
+    beta    : [f32; 2],
+    y       : &mut [f32],
+    incy    : isize,
+) {
+    hemv_c(uplo, n, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `ZHEMV`: $y := \alpha A x + \beta y$, $A$ Hermitian.
+pub fn zhemv(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : [f64; 2],
+    matrix  : &[f64],
+    lda     : usize,
+    x       : &[f64],
+    incx    : isize,
+    beta    : [f64; 2],
+    y       : &mut [f64],
+    incy    : isize,
+) {
+    hemv_c(uplo, n, alpha, matrix, lda, x, incx, beta, y, incy)
+}
+
+/// `SGER`: $A := \alpha x y^T + A$.
+pub fn sger(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : f32,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    ger_r(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda)
+}
+
+/// `DGER`: $A := \alpha x y^T + A$.
+pub fn dger(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : f64,
+    x       : &[f64],
+    incx    : isize,
+    y       : &[f64],
+    incy    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    ger_r(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda)
+}
+
+/// `CGERU`: $A := \alpha x y^T + A$.
+pub fn cgeru(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f32; 2],
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    ger_c(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda, false)
+}
+
+/// `CGERC`: $A := \alpha x y^H + A$.
+pub fn cgerc(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f32; 2],
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    ger_c(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda, true)
+}
+
+/// `ZGERU`: $A := \alpha x y^T + A$.
+pub fn zgeru(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f64; 2],
+    x       : &[f64],
+    incx    : isize,
+    y       : &[f64],
+    incy    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    ger_c(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda, false)
+}
+
+/// `ZGERC`: $A := \alpha x y^H + A$.
+pub fn zgerc(
+    n_rows  : usize,
+    n_cols  : usize,
+    alpha   : [f64; 2],
+    x       : &[f64],
+    incx    : isize,
+    y       : &[f64],
+    incy    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    ger_c(n_rows, n_cols, alpha, x, incx, y, incy, matrix, lda, true)
+}
+
+/// `SSYR`: $A := \alpha x x^T + A$ on the `uplo` triangle.
+pub fn ssyr(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f32,
+    x       : &[f32],
+    incx    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    syr_r(uplo, n, alpha, x, incx, matrix, lda)
+}
+
+/// `DSYR`: $A := \alpha x x^T + A$ on the `uplo` triangle.
+pub fn dsyr(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f64,
+    x       : &[f64],
+    incx    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    syr_r(uplo, n, alpha, x, incx, matrix, lda)
+}
+
+/// `CHER`: $A := \alpha x x^H + A$ on the `uplo` triangle.
+pub fn cher(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f32,
+    x       : &[f32],
+    incx    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    her_c(uplo, n, alpha, x, incx, matrix, lda)
+}
+
+/// `ZHER`: $A := \alpha x x^H + A$ on the `uplo` triangle.
+pub fn zher(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f64,
+    x       : &[f64],
+    incx    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    her_c(uplo, n, alpha, x, incx, matrix, lda)
+}
+
+/// `SSYR2`: $A := \alpha x y^T + \alpha y x^T + A$ on the `uplo` triangle.
+pub fn ssyr2(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f32,
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    syr2_r(uplo, n, alpha, x, incx, y, incy, matrix, lda)
+}
+
+/// `DSYR2`: $A := \alpha x y^T + \alpha y x^T + A$ on the `uplo` triangle.
+pub fn dsyr2(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : f64,
+    x       : &[f64],
+    incx    : isize,
+    y       : &[f64],
+    incy    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    syr2_r(uplo, n, alpha, x, incx, y, incy, matrix, lda)
+}
+
+/// `CHER2`: $A := \alpha x y^H + \bar\alpha y x^H + A$ on the `uplo` triangle.
+pub fn cher2(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : [f32; 2],
+    x       : &[f32],
+    incx    : isize,
+    y       : &[f32],
+    incy    : isize,
+    matrix  : &mut [f32],
+    lda     : usize,
+) {
+    her2_c(uplo, n, alpha, x, incx, y, incy, matrix, lda)
+}
+
+/// `ZHER2`: $A := \alpha x y^H + \bar\alpha y x^H + A$ on the `uplo` triangle.
+pub fn zher2(
+    uplo    : CoralTriangular,
+    n       : usize,
+    alpha   : [f64; 2],
+    x       : &[f64],
+    incx    : isize,
+    y       : &[f64],
+    incy    : isize,
+    matrix  : &mut [f64],
+    lda     : usize,
+) {
+    her2_c(uplo, n, alpha, x, incx, y, incy, matrix, lda)
+}
@@ -0,0 +1,493 @@
+//! Reference level3 routines.
+//!
+//! Same raw-pointer arguments as the optimized `GEMM` family: column-major
+//! storage, complex data interleaved `[re, im]` with leading dimensions
+//! counting complex entries. Every entry of `C` is one plain dot product
+//! over `k`.
+
+use crate::enums::{CoralTranspose, CoralTriangular};
+
+use super::{Real, cadd, cmul, conj};
+
+#[cfg(target_arch = "aarch64")]
+use crate::level3::epilogue::{Activation, Bias, Epilogue};
+
+/// `op(A)[i, l]` of a real matrix at `a`.
+#[inline]
+fn op_r<T: Real>(op: CoralTranspose, a: *const T, lda: usize, i: usize, l: usize) -> T {
+    unsafe {
+        match op {
+            CoralTranspose::NoTranspose => *a.add(i + l * lda),
+            _                           => *a.add(l + i * lda),
+        }
+    }
+}
+
+/// `op(A)[i, l]` of a complex matrix at `a`.
+#[inline]
+fn op_c<T: Real>(op: CoralTranspose, a: *const T, lda: usize, i: usize, l: usize) -> [T; 2] {
+    let idx = match op {
+        CoralTranspose::NoTranspose => i + l * lda,
+        _                           => l + i * lda,
+    };
+    let v = unsafe { [*a.add(2 * idx), *a.add(2 * idx + 1)] };
+
+    match op {
+        CoralTranspose::ConjugateTranspose => conj(v),
+        _                                  => v,
+    }
+}
+
+/// `C[i, j] := alpha (op(A) op(B))[i, j] + beta C[i, j]`.
+#[inline]
+fn entry_r<T: Real>(
+    op_a: CoralTranspose, op_b: CoralTranspose, k: usize, alpha: T,
+    a: *const T, lda: usize, b: *const T, ldb: usize, beta: T, c: *mut T, ldc: usize,
+    i: usize, j: usize,
+) {
+    let mut sum = T::ZERO;
+    for l in 0..k {
+        sum = sum + op_r(op_a, a, lda, i, l) * op_r(op_b, b, ldb, l, j);
+    }
+
+    unsafe {
+        let cij = c.add(i + j * ldc);
+        let old = if beta == T::ZERO { T::ZERO } else { beta * *cij };
+        *cij = old + alpha * sum;
+    }
+}
+
+#[inline]
+fn entry_c<T: Real>(
+    op_a: CoralTranspose, op_b: CoralTranspose, k: usize, alpha: [T; 2],
+    a: *const T, lda: usize, b: *const T, ldb: usize, beta: [T; 2], c: *mut T, ldc: usize,
+    i: usize, j: usize,
+) {
+    let mut sum = [T::ZERO; 2];
+    for l in 0..k {
+        sum = cadd(sum, cmul(op_c(op_a, a, lda, i, l), op_c(op_b, b, ldb, l, j)));
+    }
+
+    unsafe {
+        let cij = c.add(2 * (i + j * ldc));
+        let old = if beta == [T::ZERO; 2] {
+            [T::ZERO; 2]
+        } else {
+            cmul(beta, [*cij, *cij.add(1)])
+        };
+        let v = cadd(old, cmul(alpha, sum));
+        *cij        = v[0];
+        *cij.add(1) = v[1];
+    }
+}
+
+/// rows of column `j` that `GEMMT` updates.
+#[inline]
+fn rows(uplo: CoralTriangular, n: usize, j: usize) -> core::ops::Range<usize> {
+    match uplo {
+        CoralTriangular::UpperTriangular => 0..j + 1,
+        CoralTriangular::LowerTriangular => j..n,
+    }
+}
+
+fn gemm_r<T: Real>(
+    op_a: CoralTranspose, op_b: CoralTranspose, m: usize, n: usize, k: usize, alpha: T,
+    a: *const T, lda: usize, b: *const T, ldb: usize, beta: T, c: *mut T, ldc: usize,
+) {
+    if m == 0 || n == 0 || ((alpha == T::ZERO || k == 0) && beta == T::ONE) {
+        return;
+    }
+
+    for j in 0..n {
+        for i in 0..m {
+            entry_r(op_a, op_b, k, alpha, a, lda, b, ldb, beta, c, ldc, i, j);
+        }
+    }
+}
+
+fn gemm_c<T: Real>(
+    op_a: CoralTranspose, op_b: CoralTranspose, m: usize, n: usize, k: usize, alpha: [T; 2],
+    a: *const T, lda: usize, b: *const T, ldb: usize, beta: [T; 2], c: *mut T, ldc: usize,
+) {
+    let no_op = (alpha == [T::ZERO; 2] || k == 0) && beta == [T::ONE, T::ZERO];
+    if m == 0 || n == 0 || no_op {
+        return;
+    }
+
+    for j in 0..n {
+        for i in 0..m {
+            entry_c(op_a, op_b, k, alpha, a, lda, b, ldb, beta, c, ldc, i, j);
+        }
+    }
+}
+
+fn gemmt_r<T: Real>(
+    uplo: CoralTriangular, op_a: CoralTranspose, op_b: CoralTranspose, n: usize, k: usize,
+    alpha: T, a: *const T, lda: usize, b: *const T, ldb: usize, beta: T, c: *mut T, ldc: usize,
+) {
+    if n == 0 || ((alpha == T::ZERO || k == 0) && beta == T::ONE) {
+        return;
+    }
+
+    for j in 0..n {
+        for i in rows(uplo, n, j) {
+            entry_r(op_a, op_b, k, alpha, a, lda, b, ldb, beta, c, ldc, i, j);
+        }
+    }
+}
+
+fn gemmt_c<T: Real>(
+    uplo: CoralTriangular, op_a: CoralTranspose, op_b: CoralTranspose, n: usize, k: usize,
+    alpha: [T; 2], a: *const T, lda: usize, b: *const T, ldb: usize, beta: [T; 2],
+    c: *mut T, ldc: usize,
+) {
+    let no_op = (alpha == [T::ZERO; 2] || k == 0) && beta == [T::ONE, T::ZERO];
+    if n == 0 || no_op {
+        return;
+    }
+
+    for j in 0..n {
+        for i in rows(uplo, n, j) {
+            entry_c(op_a, op_b, k, alpha, a, lda, b, ldb, beta, c, ldc, i, j);
+        }
+    }
+}
+
+/// `SGEMM`: $C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C$.
+pub fn sgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    gemm_r(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `DGEMM`: $C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C$.
+pub fn dgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f64,
+    a     : *const f64,
+    lda   : usize,
+    b     : *const f64,
+    ldb   : usize,
+    beta  : f64,
+    c     : *mut f64,
+    ldc   : usize,
+) {
+    gemm_r(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `CGEMM`: $C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C$.
+pub fn cgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    gemm_c(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `ZGEMM`: $C := \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C$.
+pub fn zgemm(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f64; 2],
+    a     : *const f64,
+    lda   : usize,
+    b     : *const f64,
+    ldb   : usize,
+    beta  : [f64; 2],
+    c     : *mut f64,
+    ldc   : usize,
+) {
+    gemm_c(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `CGEMM3M`: same product as [`cgemm`]; the 3M split only changes rounding.
+pub fn cgemm_3m(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    gemm_c(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `ZGEMM3M`: same product as [`zgemm`]; the 3M split only changes rounding.
+pub fn zgemm_3m(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : [f64; 2],
+    a     : *const f64,
+    lda   : usize,
+    b     : *const f64,
+    ldb   : usize,
+    beta  : [f64; 2],
+    c     : *mut f64,
+    ldc   : usize,
+) {
+    gemm_c(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `SGEMMT`: [`sgemm`] restricted to the `uplo` triangle of the `n x n` `C`.
+pub fn sgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    gemmt_r(uplo, op_a, op_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `DGEMMT`: [`dgemm`] restricted to the `uplo` triangle of the `n x n` `C`.
+pub fn dgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : f64,
+    a     : *const f64,
+    lda   : usize,
+    b     : *const f64,
+    ldb   : usize,
+    beta  : f64,
+    c     : *mut f64,
+    ldc   : usize,
+) {
+    gemmt_r(uplo, op_a, op_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `CGEMMT`: [`cgemm`] restricted to the `uplo` triangle of the `n x n` `C`.
+pub fn cgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : [f32; 2],
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : [f32; 2],
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    gemmt_c(uplo, op_a, op_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// `ZGEMMT`: [`zgemm`] restricted to the `uplo` triangle of the `n x n` `C`.
+pub fn zgemmt(
+    uplo  : CoralTriangular,
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    n     : usize,
+    k     : usize,
+    alpha : [f64; 2],
+    a     : *const f64,
+    lda   : usize,
+    b     : *const f64,
+    ldb   : usize,
+    beta  : [f64; 2],
+    c     : *mut f64,
+    ldc   : usize,
+) {
+    gemmt_c(uplo, op_a, op_b, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+}
+
+/// the epilogue stages of `ep` applied to entry `(i, j)` of `C`.
+#[cfg(target_arch = "aarch64")]
+fn stages(ep: &Epilogue<'_>, i: usize, j: usize, x: f32) -> f32 {
+    let mut v = match ep.bias {
+        Bias::None    => x,
+        Bias::Row(bv) => x + bv[i],
+        Bias::Col(bv) => x + bv[j],
+    };
+
+    v = match ep.activation {
+        Activation::None => v,
+        Activation::Relu => if v < 0.0 { 0.0 } else { v },
+        Activation::Gelu => {
+            let t = (2.0 / core::f32::consts::PI).sqrt() * (v + 0.044_715 * v * v * v);
+            0.5 * v * (1.0 + t.tanh())
+        }
+    };
+
+    if let Some((lo, hi)) = ep.clamp {
+        v = if v < lo { lo } else if v > hi { hi } else { v };
+    }
+
+    if let Some(f) = ep.map {
+        v = f(i, j, v);
+    }
+    v
+}
+
+#[cfg(target_arch = "aarch64")]
+fn gemm_epilogue_r(
+    op_a: CoralTranspose, op_b: CoralTranspose, m: usize, n: usize, k: usize, alpha: f32,
+    a: *const f32, lda: usize, b: *const f32, ldb: usize, beta: f32, c: *mut f32, ldc: usize,
+    ep: &Epilogue<'_>,
+) {
+    // the epilogue runs even when the product is a no-op
+    for j in 0..n {
+        for i in 0..m {
+            entry_r(op_a, op_b, k, alpha, a, lda, b, ldb, beta, c, ldc, i, j);
+
+            unsafe {
+                let cij = c.add(i + j * ldc);
+                *cij = stages(ep, i, j, *cij);
+            }
+        }
+    }
+}
+
+/// `SGEMM` followed by the [`Epilogue`] stages, one entry at a time.
+#[cfg(target_arch = "aarch64")]
+pub fn sgemm_epilogue(
+    op_a     : CoralTranspose,
+    op_b     : CoralTranspose,
+    m        : usize,
+    n        : usize,
+    k        : usize,
+    alpha    : f32,
+    a        : *const f32,
+    lda      : usize,
+    b        : *const f32,
+    ldb      : usize,
+    beta     : f32,
+    c        : *mut f32,
+    ldc      : usize,
+    epilogue : &Epilogue<'_>,
+) {
+    gemm_epilogue_r(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, epilogue)
+}
+
+/// `GEMM` on slices, as in `level3::slice`.
+pub mod slice {
+    use crate::enums::CoralTranspose;
+
+    /// `SGEMM` with `A`, `B`, `C` as slices.
+    pub fn sgemm(
+        op_a  : CoralTranspose,
+        op_b  : CoralTranspose,
+        m     : usize,
+        n     : usize,
+        k     : usize,
+        alpha : f32,
+        a     : &[f32],
+        lda   : usize,
+        b     : &[f32],
+        ldb   : usize,
+        beta  : f32,
+        c     : &mut [f32],
+        ldc   : usize,
+    ) {
+        super::sgemm(op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc)
+    }
+
+    /// `DGEMM` with `A`, `B`, `C` as slices.
+    pub fn dgemm(
+        op_a  : CoralTranspose,
+        op_b  : CoralTranspose,
+        m     : usize,
+        n     : usize,
+        k     : usize,
+        alpha : f64,
+        a     : &[f64],
+        lda   : usize,
+        b     : &[f64],
+        ldb   : usize,
+        beta  : f64,
+        c     : &mut [f64],
+        ldc   : usize,
+    ) {
+        super::dgemm(op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc)
+    }
+
+    /// `CGEMM` with `A`, `B`, `C` as slices.
+    pub fn cgemm(
+        op_a  : CoralTranspose,
+        op_b  : CoralTranspose,
+        m     : usize,
+        n     : usize,
+        k     : usize,
+        alpha : [f32; 2],
+        a     : &[f32],
+        lda   : usize,
+        b     : &[f32],
+        ldb   : usize,
+        beta  : [f32; 2],
+        c     : &mut [f32],
+        ldc   : usize,
+    ) {
+        super::cgemm(op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc)
+    }
+
+    /// `ZGEMM` with `A`, `B`, `C` as slices.
+    pub fn zgemm(
+        op_a  : CoralTranspose,
+        op_b  : CoralTranspose,
+        m     : usize,
+        n     : usize,
+        k     : usize,
+        alpha : [f64; 2],
+        a     : &[f64],
+        lda   : usize,
+        b     : &[f64],
+        ldb   : usize,
+        beta  : [f64; 2],
+        c     : &mut [f64],
+        ldc   : usize,
+    ) {
+        super::zgemm(op_a, op_b, m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), ldc)
+    }
+}
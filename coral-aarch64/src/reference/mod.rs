@@ -0,0 +1,170 @@
+//! Reference implementations.
+//!
+//! Plain scalar loops for every routine in [`level1`], [`level2`] and
+//! [`level3`], with the same names, signatures and storage conventions as
+//! the optimized crate. They are written to be read, not to be fast: each
+//! one is the textbook definition of its routine, one entry at a time.
+//!
+//! Use them to A/B check a call, e.g. swap `coral_aarch64::level1::sdot` for
+//! `coral_aarch64::reference::level1::sdot`, or to validate on a machine
+//! without an external BLAS; the test suites here and in `coral-blas-safe`
+//! use them as their oracle. The module is enabled with the `reference`
+//! feature and is portable, except for `level3::sgemm_epilogue`: its
+//! `Epilogue` stages are defined by the NEON `level3` module, so it exists
+//! only on aarch64.
+//!
+//! # Notes
+//! - Arguments are not checked. The slice routines panic on an out-of-range
+//!   access; the raw-pointer level3 routines trust the caller, as the
+//!   optimized ones do. Zero and negative increments follow reference BLAS.
+//! - Quick returns and the handling of `beta == 0` (the old contents are
+//!   never read) match reference BLAS, so results agree with the optimized
+//!   routines up to rounding.
+//! - The compensated `?dot_accurate`, `?asum_accurate`, `?nrm2_accurate` and
+//!   `?gemv_with` run the same `Dot2` summation in scalar `f64`; for `f32`
+//!   input every product is already exact there.
+//! - The slice `GEMM` wrappers of `level3::slice` are in [`level3::slice`].
+//! - `sgemm_packed_a` and `sgemm_packed_b` take pre-packed operands and have
+//!   no reference counterpart; check them against [`level3::sgemm`] on the
+//!   unpacked matrix.
+//!
+//! # Author
+//! Deval Deliwala
+//!
+//! # Example
+//! ```rust
+//! use coral_aarch64::reference::level1::saxpy;
+//!
+//! fn main() {
+//!     let x = vec![1.0, 2.0, 3.0];
+//!     let mut y = vec![1.0; 3];
+//!
+//!     // walks x from the end
+//!     saxpy(3, 2.0, &x, -1, &mut y, 1);
+//!
+//!     assert_eq!(y, vec![7.0, 5.0, 3.0]);
+//! }
+//! ```
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+/// `f32` or `f64`.
+pub(crate) trait Real:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn to_f64(self) -> f64;
+    fn from_f64(v: f64) -> Self;
+}
+
+impl Real for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn abs(self) -> Self { f32::abs(self) }
+    #[inline]
+    fn sqrt(self) -> Self { f32::sqrt(self) }
+    #[inline]
+    fn to_f64(self) -> f64 { self as f64 }
+    #[inline]
+    fn from_f64(v: f64) -> Self { v as f32 }
+}
+
+impl Real for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[inline]
+    fn abs(self) -> Self { f64::abs(self) }
+    #[inline]
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+    #[inline]
+    fn to_f64(self) -> f64 { self }
+    #[inline]
+    fn from_f64(v: f64) -> Self { v }
+}
+
+/// `Dot2` of Ogita, Rump and Oishi over `f64` pairs: the sum of the
+/// products, with the rounding error of every product (`TwoProduct`) and
+/// every partial sum (`TwoSum`) carried in a second accumulator.
+pub(crate) fn dot2(terms: impl Iterator<Item = (f64, f64)>) -> f64 {
+    let (mut sum, mut err) = (0.0f64, 0.0f64);
+    for (a, b) in terms {
+        let p  = a * b;
+        let ep = a.mul_add(b, -p);
+
+        let t  = sum + p;
+        let z  = t - sum;
+        let es = (sum - (t - z)) + (p - z);
+
+        sum = t;
+        err += ep + es;
+    }
+    sum + err
+}
+
+/// position of entry `i` of an `n`-vector at stride `inc`, as in reference
+/// BLAS: a negative `inc` starts from the far end.
+#[inline]
+pub(crate) fn at(i: usize, n: usize, inc: isize) -> usize {
+    let step = inc.unsigned_abs();
+
+    if inc >= 0 { i * step } else { (n - 1 - i) * step }
+}
+
+#[inline]
+pub(crate) fn cmul<T: Real>(a: [T; 2], b: [T; 2]) -> [T; 2] {
+    [a[0] * b[0] - a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+}
+
+#[inline]
+pub(crate) fn cadd<T: Real>(a: [T; 2], b: [T; 2]) -> [T; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+#[inline]
+pub(crate) fn conj<T: Real>(a: [T; 2]) -> [T; 2] {
+    [a[0], -a[1]]
+}
+
+/// complex entry `i` of an interleaved slice.
+#[inline]
+pub(crate) fn cget<T: Real>(x: &[T], i: usize) -> [T; 2] {
+    [x[2 * i], x[2 * i + 1]]
+}
+
+#[inline]
+pub(crate) fn cset<T: Real>(x: &mut [T], i: usize, v: [T; 2]) {
+    x[2 * i]     = v[0];
+    x[2 * i + 1] = v[1];
+}
+
+/// `a / b` for complex `a`, `b`.
+#[inline]
+pub(crate) fn cdiv<T: Real>(a: [T; 2], b: [T; 2]) -> [T; 2] {
+    // Smith's algorithm, to keep |b|^2 from overflowing
+    if b[0].abs() >= b[1].abs() {
+        let r = b[1] / b[0];
+        let d = b[0] + b[1] * r;
+        [(a[0] + a[1] * r) / d, (a[1] - a[0] * r) / d]
+    } else {
+        let r = b[0] / b[1];
+        let d = b[0] * r + b[1];
+        [(a[0] * r + a[1]) / d, (a[1] * r - a[0]) / d]
+    }
+}
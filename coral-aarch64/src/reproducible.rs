@@ -0,0 +1,201 @@
+//! Kernels behind the `reproducible` feature.
+//!
+//! Each routine below fixes its summation order by logical index alone, so
+//! the result does not depend on alignment, blocking constants (`MC`, `NC`,
+//! `KC`), SIMD width or how the remainder tail is split off. Scalar code: the
+//! compiler may vectorize across independent accumulators but may not
+//! reassociate, and FMA is correctly rounded everywhere.
+//!
+//! # Orders
+//! - reductions (`sdot`, `sasum`, `snrm2`, the column dots of `sgemv`
+//!   transpose): term `i` goes to partial sum `i % 8`, in ascending `i`, and
+//!   the partials combine as `((s0 + s1) + (s2 + s3)) + ((s4 + s5) + (s6 + s7))`.
+//!   Products use one FMA each.
+//! - `snrm2` scales by the power of two at or above `max |x_i|`, which is
+//!   exact, then reduces the squares as above.
+//! - `sgemm`: each $c_{ij}$ accumulates $\operatorname{op}(A)_{ip}
+//!   \operatorname{op}(B)_{pj}$ in one FMA chain over ascending `p`.
+//! - `sgemv` and `sgemm` then store $\alpha d + \beta c$ as one FMA on the
+//!   rounded $\beta c$, or $\alpha d$ alone when $\beta = 0$.
+//!
+//! Logical order follows reference BLAS, so a negative increment walks its
+//! vector from the end. `snrm2` runs over `|incx|`, as the regular routine
+//! does, so both signs give the same bits.
+
+const LANES: usize = 8;
+
+/// offset of logical entry `i` of an `n`-vector at stride `inc`.
+#[inline(always)]
+fn at(i: usize, n: usize, inc: isize) -> usize {
+    let step = inc.unsigned_abs();
+
+    if inc >= 0 { i * step } else { (n - 1 - i) * step }
+}
+
+#[inline(always)]
+fn combine(s: &[f32; LANES]) -> f32 {
+    ((s[0] + s[1]) + (s[2] + s[3])) + ((s[4] + s[5]) + (s[6] + s[7]))
+}
+
+/// `alpha d + beta c` in the fixed form shared by `sgemv` and `sgemm`.
+#[inline(always)]
+fn update(alpha: f32, d: f32, beta: f32, c: f32) -> f32 {
+    let bc = if beta == 0.0 { 0.0 } else { beta * c };
+
+    alpha.mul_add(d, bc)
+}
+
+/// dot over `n` logical pairs.
+#[inline]
+pub(crate) fn sdot(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f32 {
+    let mut s = [0.0f32; LANES];
+
+    if incx == 1 && incy == 1 {
+        let (xc, xt) = x[..n].as_chunks::<LANES>();
+        let (yc, yt) = y[..n].as_chunks::<LANES>();
+
+        for (xv, yv) in xc.iter().zip(yc) {
+            for r in 0..LANES {
+                s[r] = xv[r].mul_add(yv[r], s[r]);
+            }
+        }
+        for (r, (&xi, &yi)) in xt.iter().zip(yt).enumerate() {
+            s[r] = xi.mul_add(yi, s[r]);
+        }
+    } else {
+        for i in 0..n {
+            let r = i % LANES;
+            s[r] = x[at(i, n, incx)].mul_add(y[at(i, n, incy)], s[r]);
+        }
+    }
+
+    combine(&s)
+}
+
+/// sum of `|x_i|` over `n` entries at positive stride `incx`.
+#[inline]
+pub(crate) fn sasum(n: usize, x: &[f32], incx: usize) -> f32 {
+    let mut s = [0.0f32; LANES];
+
+    for i in 0..n {
+        s[i % LANES] += x[i * incx].abs();
+    }
+
+    combine(&s)
+}
+
+/// Euclidean norm over `n` entries at positive stride `incx`.
+#[inline]
+pub(crate) fn snrm2(n: usize, x: &[f32], incx: usize) -> f32 {
+    // `max` drops a NaN, which must reach the result
+    let mut amax: f32 = 0.0;
+    for i in 0..n {
+        let v = x[i * incx];
+        amax = if amax.is_nan() || v.is_nan() { f32::NAN } else { amax.max(v.abs()) };
+    }
+
+    if amax == 0.0 || !amax.is_finite() {
+        return amax;
+    }
+
+    // 2^k >= amax, with k capped so 2^-k stays normal
+    let e     = ((amax.to_bits() >> 23) & 0xff) as i32 - 127;
+    let k     = (e + 1).clamp(-126, 126);
+    let scale = f32::from_bits(((k + 127) as u32) << 23);
+    let inv   = 1.0 / scale;
+
+    let mut s = [0.0f32; LANES];
+    for i in 0..n {
+        let t = x[i * incx] * inv;
+        s[i % LANES] = t.mul_add(t, s[i % LANES]);
+    }
+
+    scale * combine(&s).sqrt()
+}
+
+/// `y := alpha A^T x + beta y` over positive strides.
+#[inline]
+pub(crate) fn sgemv_transpose(
+    n_rows : usize,
+    n_cols : usize,
+    alpha  : f32,
+    matrix : &[f32],
+    lda    : usize,
+    x      : &[f32],
+    incx   : usize,
+    beta   : f32,
+    y      : &mut [f32],
+    incy   : usize,
+) {
+    // quick return
+    if n_rows == 0 || n_cols == 0 { return; }
+    if alpha == 0.0 && beta == 1.0 { return; }
+
+    for j in 0..n_cols {
+        let col = &matrix[j * lda .. j * lda + n_rows];
+        let d   = if alpha == 0.0 { 0.0 } else { sdot(n_rows, col, 1, x, incx as isize) };
+        let yj  = &mut y[j * incy];
+
+        *yj = update(alpha, d, beta, *yj);
+    }
+}
+
+/// `C := alpha op(A) op(B) + beta C`; pointers and leading dimensions as
+/// in [`crate::level3::sgemm`], already checked.
+#[inline]
+pub(crate) fn sgemm(
+    a_t   : bool,
+    b_t   : bool,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    a     : *const f32,
+    lda   : usize,
+    b     : *const f32,
+    ldb   : usize,
+    beta  : f32,
+    c     : *mut f32,
+    ldc   : usize,
+) {
+    // quick return
+    if m == 0 || n == 0 { return; }
+    if (alpha == 0.0 || k == 0) && beta == 1.0 { return; }
+
+    // op(A) as a dense column-major m x k copy, op(B) one column at a time
+    let mut ap = vec![0.0f32; if alpha == 0.0 { 0 } else { m * k }];
+    unsafe {
+        if alpha != 0.0 {
+            for p in 0..k {
+                for i in 0..m {
+                    ap[p * m + i] = if a_t { *a.add(i * lda + p) } else { *a.add(p * lda + i) };
+                }
+            }
+        }
+
+        let mut acc = vec![0.0f32; m];
+        let mut bj  = vec![0.0f32; k];
+
+        for j in 0..n {
+            acc.fill(0.0);
+
+            if alpha != 0.0 {
+                for (p, bp) in bj.iter_mut().enumerate() {
+                    *bp = if b_t { *b.add(p * ldb + j) } else { *b.add(j * ldb + p) };
+                }
+
+                for (p, &bp) in bj.iter().enumerate() {
+                    let col = &ap[p * m .. p * m + m];
+                    for (s, &aip) in acc.iter_mut().zip(col) {
+                        *s = aip.mul_add(bp, *s);
+                    }
+                }
+            }
+
+            let cj = c.add(j * ldc);
+            for (i, &d) in acc.iter().enumerate() {
+                *cj.add(i) = update(alpha, d, beta, *cj.add(i));
+            }
+        }
+    }
+}
@@ -0,0 +1,421 @@
+//! The `cblas_sys` signatures the suites call, over `coral_aarch64::reference`.
+//!
+//! The suites compare coral against a CBLAS oracle. These take the same
+//! arguments as `cblas_sys`, so a test reads the same either way, but run the
+//! reference routines instead of an external BLAS. Column-major only.
+
+#![allow(dead_code, non_camel_case_types)]
+#![allow(clippy::enum_variant_names, clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use core::ffi::c_int;
+
+use coral_aarch64::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use coral_aarch64::reference::{level1 as r1, level2 as r2, level3 as r3};
+
+pub type c_float_complex  = [f32; 2];
+pub type c_double_complex = [f64; 2];
+pub type CBLAS_INDEX      = c_int;
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_LAYOUT {
+    CblasRowMajor = 101,
+    CblasColMajor = 102,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_TRANSPOSE {
+    CblasNoTrans   = 111,
+    CblasTrans     = 112,
+    CblasConjTrans = 113,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_UPLO {
+    CblasUpper = 121,
+    CblasLower = 122,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_DIAG {
+    CblasNonUnit = 131,
+    CblasUnit    = 132,
+}
+
+fn trans(t: CBLAS_TRANSPOSE) -> CoralTranspose {
+    match t {
+        CBLAS_TRANSPOSE::CblasNoTrans   => CoralTranspose::NoTranspose,
+        CBLAS_TRANSPOSE::CblasTrans     => CoralTranspose::Transpose,
+        CBLAS_TRANSPOSE::CblasConjTrans => CoralTranspose::ConjugateTranspose,
+    }
+}
+
+fn uplo(u: CBLAS_UPLO) -> CoralTriangular {
+    match u {
+        CBLAS_UPLO::CblasUpper => CoralTriangular::UpperTriangular,
+        CBLAS_UPLO::CblasLower => CoralTriangular::LowerTriangular,
+    }
+}
+
+fn diag(d: CBLAS_DIAG) -> CoralDiagonal {
+    match d {
+        CBLAS_DIAG::CblasNonUnit => CoralDiagonal::NonUnitDiagonal,
+        CBLAS_DIAG::CblasUnit    => CoralDiagonal::UnitDiagonal,
+    }
+}
+
+fn col_major(layout: CBLAS_LAYOUT) {
+    assert!(matches!(layout, CBLAS_LAYOUT::CblasColMajor), "the oracle is column-major only");
+}
+
+// scalars spanned by n entries at stride |inc|, w per entry
+fn vlen(n: c_int, inc: c_int, w: usize) -> usize {
+    if n <= 0 { 0 } else { w * ((n as usize - 1) * inc.unsigned_abs() as usize + 1) }
+}
+
+// scalars spanned by a column-major rows x cols matrix, w per entry
+fn mlen(rows: c_int, cols: c_int, ld: c_int, w: usize) -> usize {
+    if rows <= 0 || cols <= 0 { 0 } else { w * ((cols as usize - 1) * ld as usize + rows as usize) }
+}
+
+unsafe fn view<'a, T, U>(p: *const U, len: usize) -> &'a [T] {
+    if len == 0 { &[] } else { unsafe { core::slice::from_raw_parts(p as *const T, len) } }
+}
+
+unsafe fn view_mut<'a, T, U>(p: *mut U, len: usize) -> &'a mut [T] {
+    if len == 0 { &mut [] } else { unsafe { core::slice::from_raw_parts_mut(p as *mut T, len) } }
+}
+
+// alpha and beta: real types by value, complex ones through a pointer
+trait Scalar {
+    type Value;
+    unsafe fn get(self) -> Self::Value;
+}
+
+impl Scalar for f32 {
+    type Value = f32;
+    unsafe fn get(self) -> f32 { self }
+}
+
+impl Scalar for f64 {
+    type Value = f64;
+    unsafe fn get(self) -> f64 { self }
+}
+
+impl Scalar for *const c_float_complex {
+    type Value = c_float_complex;
+    unsafe fn get(self) -> c_float_complex { unsafe { *self } }
+}
+
+impl Scalar for *const c_double_complex {
+    type Value = c_double_complex;
+    unsafe fn get(self) -> c_double_complex { unsafe { *self } }
+}
+
+fn size(n: c_int) -> usize {
+    n.max(0) as usize
+}
+
+// level1
+
+macro_rules! dot {
+    ($name:ident, $f:path, $t:ty, $r:ty) => {
+        pub unsafe fn $name(n: c_int, x: *const $t, incx: c_int, y: *const $t, incy: c_int) -> $r {
+            let (xs, ys) = unsafe { (view(x, vlen(n, incx, 1)), view(y, vlen(n, incy, 1))) };
+            $f(size(n), xs, incx as isize, ys, incy as isize)
+        }
+    };
+}
+dot!(cblas_sdot, r1::sdot, f32, f32);
+dot!(cblas_ddot, r1::ddot, f64, f64);
+
+macro_rules! dot_sub {
+    ($name:ident, $f:path, $t:ty) => {
+        pub unsafe fn $name(n: c_int, x: *const $t, incx: c_int, y: *const $t, incy: c_int, dot: *mut $t) {
+            let (xs, ys) = unsafe { (view(x, vlen(n, incx, 2)), view(y, vlen(n, incy, 2))) };
+            unsafe { *dot = $f(size(n), xs, incx as isize, ys, incy as isize) };
+        }
+    };
+}
+dot_sub!(cblas_cdotu_sub, r1::cdotu, c_float_complex);
+dot_sub!(cblas_cdotc_sub, r1::cdotc, c_float_complex);
+dot_sub!(cblas_zdotu_sub, r1::zdotu, c_double_complex);
+dot_sub!(cblas_zdotc_sub, r1::zdotc, c_double_complex);
+
+macro_rules! reduce {
+    ($name:ident, $f:path, $t:ty, $w:expr, $r:ty) => {
+        pub unsafe fn $name(n: c_int, x: *const $t, incx: c_int) -> $r {
+            let xs = unsafe { view(x, vlen(n, incx, $w)) };
+            $f(size(n), xs, incx as isize) as $r
+        }
+    };
+}
+reduce!(cblas_snrm2,  r1::snrm2,  f32,              1, f32);
+reduce!(cblas_dnrm2,  r1::dnrm2,  f64,              1, f64);
+reduce!(cblas_scnrm2, r1::scnrm2, c_float_complex,  2, f32);
+reduce!(cblas_dznrm2, r1::dznrm2, c_double_complex, 2, f64);
+reduce!(cblas_sasum,  r1::sasum,  f32,              1, f32);
+reduce!(cblas_dasum,  r1::dasum,  f64,              1, f64);
+reduce!(cblas_scasum, r1::scasum, c_float_complex,  2, f32);
+reduce!(cblas_dzasum, r1::dzasum, c_double_complex, 2, f64);
+reduce!(cblas_isamax, r1::isamax, f32,              1, CBLAS_INDEX);
+reduce!(cblas_idamax, r1::idamax, f64,              1, CBLAS_INDEX);
+reduce!(cblas_icamax, r1::icamax, c_float_complex,  2, CBLAS_INDEX);
+reduce!(cblas_izamax, r1::izamax, c_double_complex, 2, CBLAS_INDEX);
+
+macro_rules! swap {
+    ($name:ident, $f:path, $t:ty, $w:expr) => {
+        pub unsafe fn $name(n: c_int, x: *mut $t, incx: c_int, y: *mut $t, incy: c_int) {
+            let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx, $w)), view_mut(y, vlen(n, incy, $w))) };
+            $f(size(n), xs, incx as isize, ys, incy as isize)
+        }
+    };
+}
+swap!(cblas_sswap, r1::sswap, f32,              1);
+swap!(cblas_dswap, r1::dswap, f64,              1);
+swap!(cblas_cswap, r1::cswap, c_float_complex,  2);
+swap!(cblas_zswap, r1::zswap, c_double_complex, 2);
+
+macro_rules! copy {
+    ($name:ident, $f:path, $t:ty, $w:expr) => {
+        pub unsafe fn $name(n: c_int, x: *const $t, incx: c_int, y: *mut $t, incy: c_int) {
+            let (xs, ys) = unsafe { (view(x, vlen(n, incx, $w)), view_mut(y, vlen(n, incy, $w))) };
+            $f(size(n), xs, incx as isize, ys, incy as isize)
+        }
+    };
+}
+copy!(cblas_scopy, r1::scopy, f32,              1);
+copy!(cblas_dcopy, r1::dcopy, f64,              1);
+copy!(cblas_ccopy, r1::ccopy, c_float_complex,  2);
+copy!(cblas_zcopy, r1::zcopy, c_double_complex, 2);
+
+pub unsafe fn cblas_saxpy(n: c_int, alpha: f32, x: *const f32, incx: c_int, y: *mut f32, incy: c_int) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::saxpy(size(n), alpha, xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_daxpy(n: c_int, alpha: f64, x: *const f64, incx: c_int, y: *mut f64, incy: c_int) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::daxpy(size(n), alpha, xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_caxpy(
+    n: c_int, alpha: *const c_float_complex, x: *const c_float_complex, incx: c_int,
+    y: *mut c_float_complex, incy: c_int,
+) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx, 2)), view_mut(y, vlen(n, incy, 2))) };
+    r1::caxpy(size(n), unsafe { *alpha }, xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_zaxpy(
+    n: c_int, alpha: *const c_double_complex, x: *const c_double_complex, incx: c_int,
+    y: *mut c_double_complex, incy: c_int,
+) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx, 2)), view_mut(y, vlen(n, incy, 2))) };
+    r1::zaxpy(size(n), unsafe { *alpha }, xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    unsafe { r1::srotg(&mut *a, &mut *b, &mut *c, &mut *s) }
+}
+
+pub unsafe fn cblas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    unsafe { r1::drotg(&mut *a, &mut *b, &mut *c, &mut *s) }
+}
+
+pub unsafe fn cblas_srotmg(d1: *mut f32, d2: *mut f32, b1: *mut f32, b2: f32, p: *mut f32) {
+    unsafe { r1::srotmg(&mut *d1, &mut *d2, &mut *b1, b2, &mut *(p as *mut [f32; 5])) }
+}
+
+pub unsafe fn cblas_drotmg(d1: *mut f64, d2: *mut f64, b1: *mut f64, b2: f64, p: *mut f64) {
+    unsafe { r1::drotmg(&mut *d1, &mut *d2, &mut *b1, b2, &mut *(p as *mut [f64; 5])) }
+}
+
+pub unsafe fn cblas_srot(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int, c: f32, s: f32) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::srot(size(n), xs, incx as isize, ys, incy as isize, c, s)
+}
+
+pub unsafe fn cblas_drot(n: c_int, x: *mut f64, incx: c_int, y: *mut f64, incy: c_int, c: f64, s: f64) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::drot(size(n), xs, incx as isize, ys, incy as isize, c, s)
+}
+
+pub unsafe fn cblas_srotm(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int, p: *const f32) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::srotm(size(n), xs, incx as isize, ys, incy as isize, unsafe { &*(p as *const [f32; 5]) })
+}
+
+pub unsafe fn cblas_drotm(n: c_int, x: *mut f64, incx: c_int, y: *mut f64, incy: c_int, p: *const f64) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx, 1)), view_mut(y, vlen(n, incy, 1))) };
+    r1::drotm(size(n), xs, incx as isize, ys, incy as isize, unsafe { &*(p as *const [f64; 5]) })
+}
+
+pub unsafe fn cblas_sscal(n: c_int, alpha: f32, x: *mut f32, incx: c_int) {
+    r1::sscal(size(n), alpha, unsafe { view_mut(x, vlen(n, incx, 1)) }, incx as isize)
+}
+
+pub unsafe fn cblas_dscal(n: c_int, alpha: f64, x: *mut f64, incx: c_int) {
+    r1::dscal(size(n), alpha, unsafe { view_mut(x, vlen(n, incx, 1)) }, incx as isize)
+}
+
+pub unsafe fn cblas_cscal(n: c_int, alpha: *const c_float_complex, x: *mut c_float_complex, incx: c_int) {
+    r1::cscal(size(n), unsafe { *alpha }, unsafe { view_mut(x, vlen(n, incx, 2)) }, incx as isize)
+}
+
+pub unsafe fn cblas_zscal(n: c_int, alpha: *const c_double_complex, x: *mut c_double_complex, incx: c_int) {
+    r1::zscal(size(n), unsafe { *alpha }, unsafe { view_mut(x, vlen(n, incx, 2)) }, incx as isize)
+}
+
+// level2
+
+macro_rules! gemv {
+    ($name:ident, $f:path, $t:ty, $s:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, transa: CBLAS_TRANSPOSE, m: c_int, n: c_int,
+            alpha: $s, a: *const $t, lda: c_int, x: *const $t, incx: c_int,
+            beta: $s, y: *mut $t, incy: c_int,
+        ) {
+            col_major(layout);
+            let (lx, ly) = match transa {
+                CBLAS_TRANSPOSE::CblasNoTrans => (n, m),
+                _                             => (m, n),
+            };
+            let a_ = unsafe { view(a, mlen(m, n, lda, $w)) };
+            let xs = unsafe { view(x, vlen(lx, incx, $w)) };
+            let ys = unsafe { view_mut(y, vlen(ly, incy, $w)) };
+            $f(
+                trans(transa), size(m), size(n), unsafe { alpha.get() }, a_, lda as usize,
+                xs, incx as isize, unsafe { beta.get() }, ys, incy as isize,
+            )
+        }
+    };
+}
+gemv!(cblas_sgemv, r2::sgemv, f32,              f32,                     1);
+gemv!(cblas_dgemv, r2::dgemv, f64,              f64,                     1);
+gemv!(cblas_cgemv, r2::cgemv, c_float_complex,  *const c_float_complex,  2);
+gemv!(cblas_zgemv, r2::zgemv, c_double_complex, *const c_double_complex, 2);
+
+macro_rules! tr {
+    ($name:ident, $f:path, $t:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, u: CBLAS_UPLO, transa: CBLAS_TRANSPOSE, d: CBLAS_DIAG,
+            n: c_int, a: *const $t, lda: c_int, x: *mut $t, incx: c_int,
+        ) {
+            col_major(layout);
+            let a_ = unsafe { view(a, mlen(n, n, lda, $w)) };
+            let xs = unsafe { view_mut(x, vlen(n, incx, $w)) };
+            $f(uplo(u), trans(transa), diag(d), size(n), a_, lda as usize, xs, incx as isize)
+        }
+    };
+}
+tr!(cblas_strmv, r2::strmv, f32,              1);
+tr!(cblas_dtrmv, r2::dtrmv, f64,              1);
+tr!(cblas_ctrmv, r2::ctrmv, c_float_complex,  2);
+tr!(cblas_ztrmv, r2::ztrmv, c_double_complex, 2);
+tr!(cblas_strsv, r2::strsv, f32,              1);
+tr!(cblas_dtrsv, r2::dtrsv, f64,              1);
+tr!(cblas_ctrsv, r2::ctrsv, c_float_complex,  2);
+tr!(cblas_ztrsv, r2::ztrsv, c_double_complex, 2);
+
+macro_rules! symv {
+    ($name:ident, $f:path, $t:ty, $s:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, u: CBLAS_UPLO, n: c_int, alpha: $s, a: *const $t, lda: c_int,
+            x: *const $t, incx: c_int, beta: $s, y: *mut $t, incy: c_int,
+        ) {
+            col_major(layout);
+            let a_ = unsafe { view(a, mlen(n, n, lda, $w)) };
+            let xs = unsafe { view(x, vlen(n, incx, $w)) };
+            let ys = unsafe { view_mut(y, vlen(n, incy, $w)) };
+            $f(
+                uplo(u), size(n), unsafe { alpha.get() }, a_, lda as usize,
+                xs, incx as isize, unsafe { beta.get() }, ys, incy as isize,
+            )
+        }
+    };
+}
+symv!(cblas_ssymv, r2::ssymv, f32,              f32,                     1);
+symv!(cblas_dsymv, r2::dsymv, f64,              f64,                     1);
+symv!(cblas_chemv, r2::chemv, c_float_complex,  *const c_float_complex,  2);
+symv!(cblas_zhemv, r2::zhemv, c_double_complex, *const c_double_complex, 2);
+
+macro_rules! ger {
+    ($name:ident, $f:path, $t:ty, $s:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, m: c_int, n: c_int, alpha: $s, x: *const $t, incx: c_int,
+            y: *const $t, incy: c_int, a: *mut $t, lda: c_int,
+        ) {
+            col_major(layout);
+            let xs = unsafe { view(x, vlen(m, incx, $w)) };
+            let ys = unsafe { view(y, vlen(n, incy, $w)) };
+            let a_ = unsafe { view_mut(a, mlen(m, n, lda, $w)) };
+            $f(size(m), size(n), unsafe { alpha.get() }, xs, incx as isize, ys, incy as isize, a_, lda as usize)
+        }
+    };
+}
+ger!(cblas_sger,  r2::sger,  f32,              f32,                     1);
+ger!(cblas_dger,  r2::dger,  f64,              f64,                     1);
+ger!(cblas_cgeru, r2::cgeru, c_float_complex,  *const c_float_complex,  2);
+ger!(cblas_cgerc, r2::cgerc, c_float_complex,  *const c_float_complex,  2);
+ger!(cblas_zgeru, r2::zgeru, c_double_complex, *const c_double_complex, 2);
+ger!(cblas_zgerc, r2::zgerc, c_double_complex, *const c_double_complex, 2);
+
+macro_rules! syr {
+    ($name:ident, $f:path, $t:ty, $s:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, u: CBLAS_UPLO, n: c_int, alpha: $s,
+            x: *const $t, incx: c_int, a: *mut $t, lda: c_int,
+        ) {
+            col_major(layout);
+            let xs = unsafe { view(x, vlen(n, incx, $w)) };
+            let a_ = unsafe { view_mut(a, mlen(n, n, lda, $w)) };
+            $f(uplo(u), size(n), alpha, xs, incx as isize, a_, lda as usize)
+        }
+    };
+}
+syr!(cblas_ssyr, r2::ssyr, f32,              f32, 1);
+syr!(cblas_dsyr, r2::dsyr, f64,              f64, 1);
+syr!(cblas_cher, r2::cher, c_float_complex,  f32, 2);
+syr!(cblas_zher, r2::zher, c_double_complex, f64, 2);
+
+macro_rules! syr2 {
+    ($name:ident, $f:path, $t:ty, $s:ty, $w:expr) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, u: CBLAS_UPLO, n: c_int, alpha: $s, x: *const $t, incx: c_int,
+            y: *const $t, incy: c_int, a: *mut $t, lda: c_int,
+        ) {
+            col_major(layout);
+            let xs = unsafe { view(x, vlen(n, incx, $w)) };
+            let ys = unsafe { view(y, vlen(n, incy, $w)) };
+            let a_ = unsafe { view_mut(a, mlen(n, n, lda, $w)) };
+            $f(uplo(u), size(n), unsafe { alpha.get() }, xs, incx as isize, ys, incy as isize, a_, lda as usize)
+        }
+    };
+}
+syr2!(cblas_ssyr2, r2::ssyr2, f32,              f32,                     1);
+syr2!(cblas_dsyr2, r2::dsyr2, f64,              f64,                     1);
+syr2!(cblas_cher2, r2::cher2, c_float_complex,  *const c_float_complex,  2);
+syr2!(cblas_zher2, r2::zher2, c_double_complex, *const c_double_complex, 2);
+
+// level3
+
+macro_rules! gemm {
+    ($name:ident, $f:path, $t:ty, $e:ty, $s:ty) => {
+        pub unsafe fn $name(
+            layout: CBLAS_LAYOUT, transa: CBLAS_TRANSPOSE, transb: CBLAS_TRANSPOSE,
+            m: c_int, n: c_int, k: c_int, alpha: $s, a: *const $t, lda: c_int,
+            b: *const $t, ldb: c_int, beta: $s, c: *mut $t, ldc: c_int,
+        ) {
+            col_major(layout);
+            $f(
+                trans(transa), trans(transb), size(m), size(n), size(k), unsafe { alpha.get() },
+                a as *const $e, lda as usize, b as *const $e, ldb as usize,
+                unsafe { beta.get() }, c as *mut $e, ldc as usize,
+            )
+        }
+    };
+}
+gemm!(cblas_sgemm, r3::sgemm, f32,              f32, f32);
+gemm!(cblas_dgemm, r3::dgemm, f64,              f64, f64);
+gemm!(cblas_cgemm, r3::cgemm, c_float_complex,  f32, *const c_float_complex);
+gemm!(cblas_zgemm, r3::zgemm, c_double_complex, f64, *const c_double_complex);
@@ -0,0 +1,3 @@
+// argument validation
+#[path = "errors/validation_tests.rs"]
+mod validation_tests;
@@ -0,0 +1,293 @@
+use std::sync::{Mutex, MutexGuard};
+use std::panic;
+
+use coral_aarch64::errors::{set_error_handler, panic_handler, CoralError};
+use coral_aarch64::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use coral_aarch64::level1::{saxpy, sdot, sdsdot, sscal, zaxpy};
+use coral_aarch64::level2::{cher, sgemv, sger, strsv};
+use coral_aarch64::level3::{sgemm, sgemmt, packed::PackedB, slice};
+
+// the handler is global; tests that install one run one at a time
+static LOCK: Mutex<()> = Mutex::new(());
+
+static LAST: Mutex<Option<CoralError>> = Mutex::new(None);
+
+fn record(e: &CoralError) {
+    *LAST.lock().unwrap() = Some(*e);
+}
+
+fn recording() -> MutexGuard<'static, ()> {
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    *LAST.lock().unwrap() = None;
+    set_error_handler(record);
+    guard
+}
+
+fn last() -> Option<CoralError> {
+    LAST.lock().unwrap().take()
+}
+
+fn assert_param(routine: &str, param: usize) {
+    let e = last().expect("no error reported");
+    assert_eq!(e.routine(), routine);
+    assert_eq!(e.param(), param, "{e}");
+}
+
+#[test]
+fn level1_zero_increment() {
+    let _g = recording();
+
+    let x = vec![1.0, 2.0, 3.0, 4.0];
+    let mut y = vec![2.0; 4];
+
+    // not an error in level1: x is x[0] repeated
+    saxpy(4, 1.0, &x, 0, &mut y, 1);
+    assert_eq!(last(), None);
+    assert_eq!(y, vec![3.0; 4]);
+
+    // every update lands on y[0], in order
+    saxpy(4, 1.0, &x, 1, &mut y, 0);
+    assert_eq!(last(), None);
+    assert_eq!(y, vec![13.0, 3.0, 3.0, 3.0]);
+
+    // only the first entry of a zero-increment slice is needed
+    assert_eq!(sdot(4, &x, 1, &y[..1], 0), 130.0);
+    assert_eq!(last(), None);
+
+    set_error_handler(panic_handler);
+}
+
+#[test]
+fn level1_short_slices() {
+    let _g = recording();
+
+    let x = vec![1.0; 7];
+    let mut y = vec![2.0; 8];
+
+    // n = 4, incx = 2 needs 7 entries of x and 4 of y
+    saxpy(4, 1.0, &x[..6], 2, &mut y, 1);
+    assert_eq!(
+        last(),
+        Some(CoralError::BufferTooShort { routine: "saxpy", param: 3, len: 6, required: 7 }),
+    );
+    assert_eq!(y, vec![2.0; 8]);
+
+    saxpy(4, 1.0, &x, 2, &mut y[..3], 1);
+    assert_param("saxpy", 5);
+
+    assert_eq!(sdot(4, &x, 2, &y[..3], 1), 0.0);
+    assert_param("sdot", 4);
+
+    // zero, not sb
+    assert_eq!(sdsdot(4, 0.5, &x, 2, &y[..3], 1), 0.0);
+    assert_param("sdsdot", 5);
+
+    sscal(5, 2.0, &mut y[..4], 1);
+    assert_param("sscal", 3);
+    assert_eq!(y, vec![2.0; 8]);
+
+    // complex lengths count interleaved scalars
+    let zx = vec![1.0; 8];
+    let mut zy = vec![0.0; 7];
+    zaxpy(4, [1.0, 0.0], &zx, 1, &mut zy, 1);
+    assert_eq!(
+        last(),
+        Some(CoralError::BufferTooShort { routine: "zaxpy", param: 5, len: 7, required: 8 }),
+    );
+    assert_eq!(zy, vec![0.0; 7]);
+
+    // valid calls report nothing
+    saxpy(4, 1.0, &x, 2, &mut y, 2);
+    assert_eq!(last(), None);
+
+    set_error_handler(panic_handler);
+}
+
+#[test]
+fn level2_parameter_positions() {
+    let _g = recording();
+
+    let a = vec![1.0; 12];
+    let x = vec![1.0; 4];
+    let mut y = vec![0.0; 4];
+
+    // 3 x 4, lda = 3
+    sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a, 2, &x, 1, 0.0, &mut y, 1);
+    assert_param("sgemv", 6);
+
+    sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a, 3, &x, 0, 0.0, &mut y, 1);
+    assert_param("sgemv", 8);
+
+    sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a, 3, &x, 1, 0.0, &mut y, 0);
+    assert_param("sgemv", 11);
+
+    sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a[..11], 3, &x, 1, 0.0, &mut y, 1);
+    assert_param("sgemv", 5);
+
+    // op(A) = A^T takes x of length 3 and y of length 4
+    sgemv(CoralTranspose::Transpose, 3, 4, 1.0, &a, 3, &x, 1, 0.0, &mut y[..3], 1);
+    assert_param("sgemv", 10);
+    assert_eq!(y, vec![0.0; 4]);
+
+    // leading dimensions and increments come before slice lengths
+    sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a[..1], 3, &x, 0, 0.0, &mut y, 1);
+    assert_param("sgemv", 8);
+
+    let mut xs = vec![1.0; 3];
+    strsv(
+        CoralTriangular::UpperTriangular,
+        CoralTranspose::NoTranspose,
+        CoralDiagonal::NonUnitDiagonal,
+        3, &a, 2, &mut xs, 1,
+    );
+    assert_param("strsv", 6);
+    assert_eq!(xs, vec![1.0; 3]);
+
+    let mut m = vec![0.0; 12];
+    sger(3, 4, 1.0, &x, 1, &x, 1, &mut m, 2);
+    assert_param("sger", 9);
+    sger(3, 4, 1.0, &x, 1, &x[..3], 1, &mut m, 3);
+    assert_param("sger", 6);
+    assert_eq!(m, vec![0.0; 12]);
+
+    let cx = vec![1.0; 6];
+    let mut cm = vec![0.0; 17];
+    cher(CoralTriangular::LowerTriangular, 3, 1.0, &cx, 1, &mut cm, 3);
+    assert_param("cher", 6);
+    assert_eq!(cm, vec![0.0; 17]);
+
+    set_error_handler(panic_handler);
+}
+
+#[test]
+fn level3_leading_dimensions() {
+    let _g = recording();
+
+    let a = vec![1.0; 12];
+    let b = vec![1.0; 12];
+    let mut c = vec![5.0; 12];
+
+    // C (3 x 4) := A (3 x 2) * B (2 x 4)
+    sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, 1.0,
+        a.as_ptr(), 2,
+        b.as_ptr(), 2,
+        0.0,
+        c.as_mut_ptr(), 3,
+    );
+    assert_param("sgemm", 8);
+
+    // op(B) = B^T, B is 4 x 2
+    sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::Transpose,
+        3, 4, 2, 1.0,
+        a.as_ptr(), 3,
+        b.as_ptr(), 2,
+        0.0,
+        c.as_mut_ptr(), 3,
+    );
+    assert_param("sgemm", 10);
+
+    sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, 1.0,
+        a.as_ptr(), 3,
+        b.as_ptr(), 2,
+        0.0,
+        c.as_mut_ptr(), 2,
+    );
+    assert_param("sgemm", 13);
+    assert_eq!(c, vec![5.0; 12]);
+
+    sgemmt(
+        CoralTriangular::UpperTriangular,
+        CoralTranspose::Transpose, CoralTranspose::NoTranspose,
+        3, 2, 1.0,
+        a.as_ptr(), 1,
+        b.as_ptr(), 2,
+        0.0,
+        c.as_mut_ptr(), 3,
+    );
+    assert_param("sgemmt", 8);
+    assert_eq!(c, vec![5.0; 12]);
+
+    let pb = PackedB::new(CoralTranspose::Transpose, 2, 4, b.as_ptr(), 3);
+    assert_param("PackedB::new", 5);
+    assert_eq!((pb.k(), pb.n()), (0, 0));
+
+    set_error_handler(panic_handler);
+}
+
+#[test]
+fn level3_slice_lengths() {
+    let _g = recording();
+
+    let a = vec![1.0; 6];
+    let b = vec![1.0; 8];
+    let mut c = vec![5.0; 12];
+
+    // C (3 x 4) := A (3 x 2) * B (2 x 4)
+    slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, 1.0, &a[..5], 3, &b, 2, 0.0, &mut c, 3,
+    );
+    assert_eq!(
+        last(),
+        Some(CoralError::BufferTooShort { routine: "sgemm", param: 7, len: 5, required: 6 }),
+    );
+
+    // op(B) = B^T needs B (4 x 2) with ldb >= 4
+    slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::Transpose,
+        3, 4, 2, 1.0, &a, 3, &b, 2, 0.0, &mut c, 3,
+    );
+    assert_param("sgemm", 10);
+
+    slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, 1.0, &a, 3, &b, 2, 0.0, &mut c[..11], 3,
+    );
+    assert_param("sgemm", 12);
+    assert_eq!(c, vec![5.0; 12]);
+
+    let za = vec![1.0; 12];
+    let zb = vec![1.0; 15];
+    let mut zc = vec![0.0; 24];
+    slice::zgemm(
+        CoralTranspose::ConjugateTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, [1.0, 0.0], &za, 2, &zb, 2, [0.0, 0.0], &mut zc, 3,
+    );
+    assert_eq!(
+        last(),
+        Some(CoralError::BufferTooShort { routine: "zgemm", param: 9, len: 15, required: 16 }),
+    );
+    assert_eq!(zc, vec![0.0; 24]);
+
+    slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose,
+        3, 4, 2, 1.0, &a, 3, &b, 2, 0.0, &mut c, 3,
+    );
+    assert_eq!(last(), None);
+    assert_eq!(c, vec![2.0; 12]);
+
+    set_error_handler(panic_handler);
+}
+
+#[test]
+fn default_handler_panics() {
+    let _g = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    set_error_handler(panic_handler);
+
+    let a = vec![1.0; 12];
+    let x = vec![1.0; 4];
+    let mut y = vec![2.0; 3];
+
+    let err = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        sgemv(CoralTranspose::NoTranspose, 3, 4, 1.0, &a, 3, &x, 0, 0.0, &mut y, 1);
+    }))
+    .unwrap_err();
+
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert_eq!(msg, "coral: parameter 8 to sgemv is invalid: increment is zero");
+}
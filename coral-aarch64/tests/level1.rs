@@ -1,3 +1,6 @@
+#[path = "common/cblas.rs"]
+mod cblas;
+
 #[path = "level1/axpy_tests.rs"]
 mod axpy_tests; 
 #[path = "level1/copy_tests.rs"]
@@ -10,9 +13,21 @@ mod swap_tests;
 mod asum_tests;
 #[path = "level1/nrm2_tests.rs"] 
 mod nrm2_tests; 
+#[path = "level1/nrm2_blue_tests.rs"] 
+mod nrm2_blue_tests; 
 #[path = "level1/dot_tests.rs" ] 
 mod dot_tests; 
 #[path = "level1/rot_tests.rs" ] 
 mod rot_tests;
+#[path = "level1/rotg_tests.rs"] 
+mod rotg_tests;
 #[path = "level1/iamax_tests.rs"] 
 mod iamax_tests;
+#[path = "level1/neg_inc_tests.rs"] 
+mod neg_inc_tests;
+#[path = "level1/dsdot_tests.rs"] 
+mod dsdot_tests;
+
+// compensated variants
+#[path = "level1/accurate_tests.rs"] 
+mod accurate_tests;
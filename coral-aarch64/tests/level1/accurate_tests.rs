@@ -0,0 +1,237 @@
+use coral_aarch64::level1::{
+    sdot_accurate,
+    ddot_accurate,
+    sasum_accurate,
+    dasum_accurate,
+    snrm2_accurate,
+    dnrm2_accurate,
+    sdot,
+};
+
+const U32: f64 = 1.0 / (1u64 << 24) as f64;
+const U64: f64 = 1.0 / (1u64 << 53) as f64;
+
+fn gamma(n: usize, u: f64) -> f64 {
+    n as f64 * u / (1.0 - n as f64 * u)
+}
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // odd mantissa below 2^bits, random sign
+    fn mant(&mut self, bits: u32) -> i64 {
+        let m = (self.next() >> (64 - bits)) as i64 | 1;
+        if self.next() & 1 == 0 { m } else { -m }
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+// m_i * 2^e_i, exact in both i128 (scaled by 2^-shift) and the float type
+#[derive(Clone, Copy)]
+struct Exact {
+    m: i64,
+    e: i32,
+}
+
+impl Exact {
+    fn f64(self) -> f64 {
+        self.m as f64 * 2f64.powi(self.e)
+    }
+}
+
+// products a_i b_i whose large terms cancel in pairs; returns x, y and the
+// exact dot product and sum of |x_i y_i|, both as i128 (e_x + e_y >= 0)
+fn cancelling(n: usize, bits: u32, emax: i32, rng: &mut Rng) -> (Vec<Exact>, Vec<Exact>, i128, i128) {
+    let mut x = Vec::with_capacity(n);
+    let mut y = Vec::with_capacity(n);
+
+    let big = n / 2 - n / 8;
+    for _ in 0..big {
+        let (a, b) = (rng.mant(bits), rng.mant(bits));
+        let (ea, eb) = (rng.below(emax as u64 + 1) as i32, rng.below(emax as u64 + 1) as i32);
+
+        x.push(Exact { m: a, e: ea });
+        y.push(Exact { m: b, e: eb });
+        x.push(Exact { m: a, e: ea });
+        y.push(Exact { m: -b, e: eb });
+    }
+    while x.len() < n {
+        x.push(Exact { m: rng.mant(bits), e: 0 });
+        y.push(Exact { m: rng.mant(bits), e: 0 });
+    }
+
+    // shuffle so the partners are far apart
+    for i in (1..n).rev() {
+        let j = rng.below(i as u64 + 1) as usize;
+        x.swap(i, j);
+        y.swap(i, j);
+    }
+
+    let term = |a: Exact, b: Exact| (a.m as i128 * b.m as i128) << (a.e + b.e);
+    let dot  = x.iter().zip(&y).map(|(&a, &b)| term(a, b)).sum();
+    let abs  = x.iter().zip(&y).map(|(&a, &b)| term(a, b).abs()).sum();
+
+    (x, y, dot, abs)
+}
+
+fn strided<T: Copy + Default>(v: &[T], inc: isize) -> Vec<T> {
+    let n = v.len();
+    let step = inc.unsigned_abs();
+    let mut buf = vec![T::default(); (n - 1) * step + 1];
+
+    for (i, &vi) in v.iter().enumerate() {
+        let o = if inc > 0 { i * step } else { (n - 1 - i) * step };
+        buf[o] = vi;
+    }
+    buf
+}
+
+#[test]
+fn dot_single_bound() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for &n in &[64usize, 1000, 4097] {
+        for &emax in &[4, 12, 20] {
+            let (x, y, dot, abs) = cancelling(n, 24, emax, &mut rng);
+            let xs: Vec<f32> = x.iter().map(|v| v.f64() as f32).collect();
+            let ys: Vec<f32> = y.iter().map(|v| v.f64() as f32).collect();
+
+            for &(incx, incy) in &[(1isize, 1isize), (3, 2), (-2, 1), (-1, -3)] {
+                let xb = strided(&xs, incx);
+                let yb = strided(&ys, incy);
+
+                let got = sdot_accurate(n, &xb, incx, &yb, incy) as f64;
+
+                // integer valued, so the error is exact in i128
+                let err   = (got as i128 - dot).abs() as f64;
+                let bound = U32 * (dot as f64).abs() + gamma(n, U32).powi(2) * abs as f64;
+                assert!(err <= bound, "n {n}, emax {emax}, inc ({incx}, {incy}): {err} > {bound}");
+            }
+        }
+    }
+}
+
+#[test]
+fn dot_double_bound() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for &n in &[64usize, 1000, 4097] {
+        for &emax in &[5, 10] {
+            let (x, y, dot, abs) = cancelling(n, 40, emax, &mut rng);
+            let xs: Vec<f64> = x.iter().map(|v| v.f64()).collect();
+            let ys: Vec<f64> = y.iter().map(|v| v.f64()).collect();
+
+            for &(incx, incy) in &[(1isize, 1isize), (2, -1)] {
+                let xb = strided(&xs, incx);
+                let yb = strided(&ys, incy);
+
+                let got = ddot_accurate(n, &xb, incx, &yb, incy);
+
+                let err   = (got as i128 - dot).abs() as f64;
+                let bound = U64 * (dot as f64).abs() + gamma(n, U64).powi(2) * abs as f64;
+                assert!(err <= bound, "n {n}, emax {emax}, inc ({incx}, {incy}): {err} > {bound}");
+            }
+        }
+    }
+}
+
+#[test]
+fn dot_recovers_lost_terms() {
+    // plain f32 sums lose the 1.0 entirely
+    let x = [1.0e8f32, 1.0, -1.0e8];
+    let y = [1.0f32, 1.0, 1.0];
+
+    assert_eq!(sdot(3, &x, 1, &y, 1), 0.0);
+    assert_eq!(sdot_accurate(3, &x, 1, &y, 1), 1.0);
+    assert_eq!(ddot_accurate(3, &[1.0e17, 1.0, -1.0e17], 1, &[1.0; 3], 1), 1.0);
+
+    assert_eq!(sdot_accurate(0, &x, 1, &y, 1), 0.0);
+}
+
+#[test]
+fn asum_bound() {
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+
+    for &n in &[1usize, 33, 10_000] {
+        // spread over 2^-20 .. 2^20; exact as i128 scaled by 2^20
+        let v: Vec<Exact> = (0..n)
+            .map(|_| Exact { m: rng.mant(24), e: rng.below(41) as i32 - 20 })
+            .collect();
+        let exact: i128 = v.iter().map(|a| (a.m.abs() as i128) << (a.e + 20)).sum();
+        let exact = exact as f64 * 2f64.powi(-20);
+
+        for &inc in &[1isize, 4] {
+            let xs: Vec<f32> = strided(&v.iter().map(|a| a.f64() as f32).collect::<Vec<_>>(), inc);
+            let got = sasum_accurate(n, &xs, inc) as f64;
+            let bound = (U32 + gamma(n - 1, U32).powi(2)) * exact;
+            assert!((got - exact).abs() <= bound, "n {n}, inc {inc}: {got} vs {exact}");
+
+            let xd: Vec<f64> = strided(&v.iter().map(|a| a.f64()).collect::<Vec<_>>(), inc);
+            let got = dasum_accurate(n, &xd, inc);
+            // reference itself rounds once in f64
+            let bound = (2.0 * U64 + gamma(n - 1, U64).powi(2)) * exact;
+            assert!((got - exact).abs() <= bound, "n {n}, inc {inc}: {got} vs {exact}");
+        }
+    }
+
+    // many small terms after a large one
+    let mut x = vec![1.0f32; 1 << 16];
+    x[0] = 1.0e8;
+    assert_eq!(sasum_accurate(x.len(), &x, 1), 1.0e8 + 65535.0);
+    assert_eq!(sasum_accurate(x.len(), &x, -1), 0.0);
+}
+
+#[test]
+fn nrm2_bound() {
+    let mut rng = Rng(0x0f0f_1e1e_2d2d_3c3c);
+
+    for &n in &[1usize, 17, 5000] {
+        let v: Vec<Exact> = (0..n)
+            .map(|_| Exact { m: rng.mant(24), e: rng.below(21) as i32 - 10 })
+            .collect();
+        let ssq: i128 = v.iter().map(|a| ((a.m as i128).pow(2)) << (2 * a.e + 20)).sum();
+        let exact = (ssq as f64 * 2f64.powi(-20)).sqrt();
+
+        for &inc in &[1isize, -3] {
+            let xs: Vec<f32> = strided(&v.iter().map(|a| a.f64() as f32).collect::<Vec<_>>(), inc);
+            let got = snrm2_accurate(n, &xs, inc) as f64;
+            let bound = (2.0 * U32 + gamma(n, U32).powi(2)) * exact;
+            assert!((got - exact).abs() <= bound, "n {n}, inc {inc}: {got} vs {exact}");
+        }
+    }
+
+    // 100 x for n = 10^4 copies of x; 100 x is p + e exactly
+    for &x in &[0.1f64, 3.0e300, 7.0e-300] {
+        let v = vec![x; 10_000];
+        let got = dnrm2_accurate(v.len(), &v, 1);
+
+        let p = 100.0 * x;
+        let e = 100.0f64.mul_add(x, -p);
+        let err = ((got - p) - e).abs();
+        assert!(err <= (2.0 * U64 + gamma(10_000, U64).powi(2)) * p, "{x}: {got} vs {p}");
+    }
+
+    // no overflow or underflow in the scaling
+    for &k in &[100, -100] {
+        let s = 2f32.powi(k);
+        assert_eq!(snrm2_accurate(2, &[3.0 * s, 4.0 * s], 1), 5.0 * s);
+    }
+    assert_eq!(snrm2_accurate(3, &[0.0f32; 3], 1), 0.0);
+    assert_eq!(dnrm2_accurate(2, &[3.0, 4.0], 0), 0.0);
+
+    // a NaN must not be dropped by the max scan
+    assert!(snrm2_accurate(1, &[f32::NAN], 1).is_nan());
+    assert!(snrm2_accurate(3, &[0.0, f32::NAN, 0.0], 1).is_nan());
+    assert!(dnrm2_accurate(3, &[1.0, f64::NAN, f64::INFINITY], 1).is_nan());
+}
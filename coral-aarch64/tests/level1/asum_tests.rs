@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     sasum,
     dasum,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     dzasum
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_sasum,
     cblas_dasum,
     cblas_scasum,
@@ -147,7 +146,7 @@ fn real() {
         let coral = sasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_sasum(
@@ -196,7 +195,7 @@ fn real() {
         let coral = sasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_sasum(
@@ -250,7 +249,7 @@ fn real() {
         let coral = dasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_dasum(
@@ -299,7 +298,7 @@ fn real() {
         let coral = dasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_dasum(
@@ -364,7 +363,7 @@ fn complex() {
         let coral = scasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_scasum(
@@ -417,7 +416,7 @@ fn complex() {
         let coral = scasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_scasum(
@@ -479,7 +478,7 @@ fn complex() {
         let coral = dzasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_dzasum(
@@ -532,7 +531,7 @@ fn complex() {
         let coral = dzasum(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let blas  = unsafe {
             cblas_dzasum(
@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     saxpy,
     daxpy,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     zaxpy,
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_saxpy,
     cblas_daxpy,
     cblas_caxpy,
@@ -168,9 +167,9 @@ fn real() {
             n,
             alpha as f32,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_saxpy(
@@ -270,9 +269,9 @@ fn real() {
             n,
             alpha,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_daxpy(
@@ -375,9 +374,9 @@ fn complex() {
             n,
             alpha,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_caxpy(
@@ -522,9 +521,9 @@ fn complex() {
             n,
             alpha,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_zaxpy(
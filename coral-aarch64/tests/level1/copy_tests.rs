@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     scopy,
     dcopy,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     zcopy
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_scopy,
     cblas_dcopy,
     cblas_ccopy,
@@ -176,9 +175,9 @@ fn real() {
         scopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_scopy(
@@ -236,9 +235,9 @@ fn real() {
         scopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_scopy(
@@ -305,9 +304,9 @@ fn real() {
         dcopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_dcopy(
@@ -365,9 +364,9 @@ fn real() {
         dcopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_dcopy(
@@ -449,9 +448,9 @@ fn complex() {
         ccopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_ccopy(
@@ -517,9 +516,9 @@ fn complex() {
         ccopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_ccopy(
@@ -586,9 +585,9 @@ fn complex() {
         zcopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_zcopy(
@@ -654,9 +653,9 @@ fn complex() {
         zcopy(
             n,
             &x,
-            incx,
+            incx as isize,
             &mut yc,
-            incy,
+            incy as isize,
         );
         unsafe {
             cblas_zcopy(
@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     sdot,
     ddot,
@@ -8,7 +7,7 @@ use coral_aarch64::level1::{
     zdotu,
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_sdot,
     cblas_ddot,
     cblas_cdotc_sub,
@@ -229,9 +228,9 @@ fn real() {
         let s_coral = sdot(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let s_ref = unsafe {
             cblas_sdot(
@@ -285,9 +284,9 @@ fn real() {
         let s_coral = sdot(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let s_ref = unsafe {
             cblas_sdot(
@@ -350,9 +349,9 @@ fn real() {
         let s_coral = ddot(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let s_ref = unsafe {
             cblas_ddot(
@@ -406,9 +405,9 @@ fn real() {
         let s_coral = ddot(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let s_ref = unsafe {
             cblas_ddot(
@@ -476,9 +475,9 @@ fn complex() {
         let res_coral = cdotc(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f32; 2] = [0.0, 0.0];
         unsafe {
@@ -544,9 +543,9 @@ fn complex() {
         let res_coral = cdotc(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f32; 2] = [0.0, 0.0];
         unsafe {
@@ -613,9 +612,9 @@ fn complex() {
         let res_coral = cdotu(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f32; 2] = [0.0, 0.0];
         unsafe {
@@ -682,9 +681,9 @@ fn complex() {
         let res_coral = zdotc(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f64; 2] = [0.0, 0.0];
         unsafe {
@@ -750,9 +749,9 @@ fn complex() {
         let res_coral = zdotc(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f64; 2] = [0.0, 0.0];
         unsafe {
@@ -819,9 +818,9 @@ fn complex() {
         let res_coral = zdotu(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f64; 2] = [0.0, 0.0];
         unsafe {
@@ -887,9 +886,9 @@ fn complex() {
         let res_coral = zdotu(
             n,
             &x,
-            incx,
+            incx as isize,
             &y,
-            incy,
+            incy as isize,
         );
         let mut res_ref : [f64; 2] = [0.0, 0.0];
         unsafe {
@@ -0,0 +1,74 @@
+use coral_aarch64::level1::{dsdot, sdsdot, sdot};
+
+const U64: f64 = 1.0 / (1u64 << 53) as f64;
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn f32(&mut self) -> f32 {
+        let m = (self.next() >> 40) as f32 / (1u64 << 24) as f32 - 0.5;
+        m * 2f32.powi((self.next() % 16) as i32 - 8)
+    }
+}
+
+fn strided(v: &[f32], inc: isize) -> Vec<f32> {
+    let n = v.len();
+    let step = inc.unsigned_abs();
+    let mut buf = vec![0.0; (n - 1) * step + 1];
+
+    for (i, &vi) in v.iter().enumerate() {
+        buf[if inc > 0 { i * step } else { (n - 1 - i) * step }] = vi;
+    }
+    buf
+}
+
+#[test]
+fn matches_f64_sum() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for &n in &[1usize, 3, 4, 15, 16, 17, 100, 1027] {
+        let x: Vec<f32> = (0..n).map(|_| rng.f32()).collect();
+        let y: Vec<f32> = (0..n).map(|_| rng.f32()).collect();
+
+        // products are exact in f64; only the n - 1 additions round
+        let want: f64 = x.iter().zip(&y).map(|(&a, &b)| a as f64 * b as f64).sum();
+        let abs: f64  = x.iter().zip(&y).map(|(&a, &b)| (a as f64 * b as f64).abs()).sum();
+        let tol = 2.0 * n as f64 * U64 * abs;
+
+        for &(incx, incy) in &[(1isize, 1isize), (2, 3), (-1, 1), (1, -2), (-3, -1)] {
+            let xb = strided(&x, incx);
+            let yb = strided(&y, incy);
+
+            let dot = dsdot(n, &xb, incx, &yb, incy);
+            assert!((dot - want).abs() <= tol, "n {n}, inc ({incx}, {incy}): {dot} vs {want}");
+
+            // one rounding to f32, after sb joins the f64 sum
+            let sb = 0.375f32;
+            assert_eq!(sdsdot(n, sb, &xb, incx, &yb, incy), (sb as f64 + dot) as f32);
+        }
+    }
+}
+
+#[test]
+fn keeps_what_f32_loses() {
+    let x = [1.0e8f32, 1.0, -1.0e8, 3.0, 0.5];
+    let y = [1.0f32; 5];
+
+    assert_eq!(sdot(3, &x, 1, &y, 1), 0.0);
+    assert_eq!(dsdot(3, &x, 1, &y, 1), 1.0);
+    assert_eq!(dsdot(5, &x, 1, &y, 1), 4.5);
+
+    // 2^24 + 1 is not an f32, but the f64 sum holds it until the last rounding
+    assert_eq!(sdsdot(3, 16_777_216.0, &[1.0, 1.0, 1.0], 1, &[1.0, 0.5, 0.5], 1), 16_777_218.0);
+
+    assert_eq!(dsdot(0, &x, 1, &y, 1), 0.0);
+    assert_eq!(sdsdot(0, -2.5, &x, 1, &y, 1), -2.5);
+}
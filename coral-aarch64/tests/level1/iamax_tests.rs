@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     isamax,
     idamax,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     izamax, 
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_isamax,
     cblas_idamax,
     cblas_icamax,
@@ -120,7 +119,7 @@ fn real() {
         let i_coral = isamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_isamax(
@@ -163,7 +162,7 @@ fn real() {
         let i_coral = isamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_isamax(
@@ -219,7 +218,7 @@ fn real() {
         let i_coral = idamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_idamax(
@@ -262,7 +261,7 @@ fn real() {
         let i_coral = idamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_idamax(
@@ -329,7 +328,7 @@ fn complex() {
         let i_coral = icamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_icamax(
@@ -376,7 +375,7 @@ fn complex() {
         let i_coral = icamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_icamax(
@@ -440,7 +439,7 @@ fn complex() {
         let i_coral = izamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_izamax(
@@ -487,7 +486,7 @@ fn complex() {
         let i_coral = izamax(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let i_ref = unsafe {
             cblas_izamax(
@@ -0,0 +1,198 @@
+use coral_aarch64::level1::{
+    saxpy,
+    sdot,
+    scopy,
+    sswap,
+    srot,
+    snrm2,
+    sscal,
+    isamax,
+    daxpy,
+    ddot,
+    drotm,
+    zaxpy,
+    zdotc,
+    cswap,
+};
+
+// entry i of an n-vector at stride inc, w scalars per entry, as in reference BLAS
+fn at(i: usize, n: usize, inc: isize, w: usize) -> usize {
+    let step = inc.unsigned_abs();
+
+    if inc >= 0 { w * i * step } else { w * (n - 1 - i) * step }
+}
+
+fn store<T: Copy + Default>(v: &[T], n: usize, inc: isize, w: usize) -> Vec<T> {
+    let mut buf = vec![T::default(); w * ((n - 1) * inc.unsigned_abs() + 1)];
+    for i in 0..n {
+        let o = at(i, n, inc, w);
+        buf[o..o + w].copy_from_slice(&v[w * i..w * i + w]);
+    }
+    buf
+}
+
+fn load<T: Copy>(buf: &[T], n: usize, inc: isize, w: usize) -> Vec<T> {
+    (0..n)
+        .flat_map(|i| {
+            let o = at(i, n, inc, w);
+            buf[o..o + w].to_vec()
+        })
+        .collect()
+}
+
+fn make_f32(len: usize, seed: usize) -> Vec<f32> {
+    (0..len).map(|i| (((i * 13 + seed * 7) % 17) as f32 - 8.0) / 5.0).collect()
+}
+
+fn make_f64(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|i| (((i * 13 + seed * 7) % 17) as f64 - 8.0) / 5.0).collect()
+}
+
+fn assert_allclose_f32(a: &[f32], b: &[f32]) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= 1e-5 * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+fn assert_allclose_f64(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= 1e-12 * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+const INCS: [(isize, isize); 5] = [(-1, 1), (1, -1), (-1, -1), (-3, 2), (2, -3)];
+
+#[test]
+fn two_vector_real() {
+    let n = 37;
+    let x = make_f32(n, 1);
+    let y = make_f32(n, 2);
+
+    for &(incx, incy) in &INCS {
+        // the same call on forward strides is the reference
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let xs = store(&x, n, incx, 1);
+        let xf = store(&x, n, fx, 1);
+
+        let mut ys = store(&y, n, incy, 1);
+        let mut yf = store(&y, n, fy, 1);
+        saxpy(n, 0.75, &xs, incx, &mut ys, incy);
+        saxpy(n, 0.75, &xf, fx, &mut yf, fy);
+        assert_allclose_f32(&load(&ys, n, incy, 1), &load(&yf, n, fy, 1));
+
+        let ys = store(&y, n, incy, 1);
+        let yf = store(&y, n, fy, 1);
+        let got = sdot(n, &xs, incx, &ys, incy);
+        let exp = sdot(n, &xf, fx, &yf, fy);
+        assert_allclose_f32(&[got], &[exp]);
+
+        let mut ys = store(&y, n, incy, 1);
+        scopy(n, &xs, incx, &mut ys, incy);
+        assert_eq!(load(&ys, n, incy, 1), x);
+
+        let mut xm = xs.clone();
+        let mut ys = store(&y, n, incy, 1);
+        sswap(n, &mut xm, incx, &mut ys, incy);
+        assert_eq!(load(&xm, n, incx, 1), y);
+        assert_eq!(load(&ys, n, incy, 1), x);
+
+        let mut xm = xs.clone();
+        let mut ys = store(&y, n, incy, 1);
+        let mut xr = xf.clone();
+        let mut yr = store(&y, n, fy, 1);
+        srot(n, &mut xm, incx, &mut ys, incy, 0.6, 0.8);
+        srot(n, &mut xr, fx, &mut yr, fy, 0.6, 0.8);
+        assert_allclose_f32(&load(&xm, n, incx, 1), &load(&xr, n, fx, 1));
+        assert_allclose_f32(&load(&ys, n, incy, 1), &load(&yr, n, fy, 1));
+    }
+}
+
+#[test]
+fn two_vector_double() {
+    let n = 29;
+    let x = make_f64(n, 3);
+    let y = make_f64(n, 4);
+    let param = [-1.0, 0.5, -0.25, 0.75, 1.5];
+
+    for &(incx, incy) in &INCS {
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let xs = store(&x, n, incx, 1);
+        let xf = store(&x, n, fx, 1);
+
+        let mut ys = store(&y, n, incy, 1);
+        let mut yf = store(&y, n, fy, 1);
+        daxpy(n, -1.25, &xs, incx, &mut ys, incy);
+        daxpy(n, -1.25, &xf, fx, &mut yf, fy);
+        assert_allclose_f64(&load(&ys, n, incy, 1), &load(&yf, n, fy, 1));
+
+        let ys = store(&y, n, incy, 1);
+        let yf = store(&y, n, fy, 1);
+        let got = ddot(n, &xs, incx, &ys, incy);
+        let exp = ddot(n, &xf, fx, &yf, fy);
+        assert_allclose_f64(&[got], &[exp]);
+
+        let mut xm = xs.clone();
+        let mut ys = store(&y, n, incy, 1);
+        let mut xr = xf.clone();
+        let mut yr = store(&y, n, fy, 1);
+        drotm(n, &mut xm, incx, &mut ys, incy, &param);
+        drotm(n, &mut xr, fx, &mut yr, fy, &param);
+        assert_allclose_f64(&load(&xm, n, incx, 1), &load(&xr, n, fx, 1));
+        assert_allclose_f64(&load(&ys, n, incy, 1), &load(&yr, n, fy, 1));
+    }
+}
+
+#[test]
+fn two_vector_complex() {
+    let n = 23;
+    let x = make_f64(2 * n, 5);
+    let y = make_f64(2 * n, 6);
+
+    for &(incx, incy) in &INCS {
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let xs = store(&x, n, incx, 2);
+        let xf = store(&x, n, fx, 2);
+
+        let mut ys = store(&y, n, incy, 2);
+        let mut yf = store(&y, n, fy, 2);
+        zaxpy(n, [0.5, -1.5], &xs, incx, &mut ys, incy);
+        zaxpy(n, [0.5, -1.5], &xf, fx, &mut yf, fy);
+        assert_allclose_f64(&load(&ys, n, incy, 2), &load(&yf, n, fy, 2));
+
+        let ys = store(&y, n, incy, 2);
+        let yf = store(&y, n, fy, 2);
+        let got = zdotc(n, &xs, incx, &ys, incy);
+        let exp = zdotc(n, &xf, fx, &yf, fy);
+        assert_allclose_f64(&got, &exp);
+
+        let xc: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+        let yc: Vec<f32> = y.iter().map(|&v| v as f32).collect();
+        let mut xm = store(&xc, n, incx, 2);
+        let mut ym = store(&yc, n, incy, 2);
+        cswap(n, &mut xm, incx, &mut ym, incy);
+        assert_eq!(load(&xm, n, incx, 2), yc);
+        assert_eq!(load(&ym, n, incy, 2), xc);
+    }
+}
+
+#[test]
+fn single_vector() {
+    let n = 31;
+    let x = make_f32(n, 7);
+
+    // the norm does not depend on the direction
+    let xs = store(&x, n, -2, 1);
+    let xf = store(&x, n, 2, 1);
+    assert_allclose_f32(&[snrm2(n, &xs, -2)], &[snrm2(n, &xf, 2)]);
+
+    // as in reference BLAS, scal and iamax ignore non-positive increments
+    let mut xm = xs.clone();
+    sscal(n, 3.0, &mut xm, -2);
+    assert_eq!(xm, xs);
+    assert_eq!(isamax(n, &xs, -2), 0);
+}
@@ -0,0 +1,141 @@
+use coral_aarch64::level1::{snrm2, dnrm2, scnrm2, dznrm2};
+
+const U32: f64 = 1.0 / (1u64 << 24) as f64;
+const U64: f64 = 1.0 / (1u64 << 53) as f64;
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // uniform in [-0.5, 0.5) times 2^e, e in lo..hi
+    fn value(&mut self, lo: i32, hi: i32) -> f64 {
+        let m = (self.next() >> 11) as f64 / (1u64 << 53) as f64 - 0.5;
+        m * 2f64.powi(lo + (self.next() % (hi - lo) as u64) as i32)
+    }
+}
+
+fn strided<T: Copy + Default>(v: &[T], inc: usize, w: usize) -> Vec<T> {
+    let n = v.len() / w;
+    let mut buf = vec![T::default(); w * ((n - 1) * inc + 1)];
+
+    for i in 0..n {
+        buf[w * i * inc .. w * i * inc + w].copy_from_slice(&v[w * i .. w * i + w]);
+    }
+    buf
+}
+
+// sqrt(sum x_i^2) in f64, scaled by the largest magnitude's exponent
+fn norm(x: &[f64]) -> f64 {
+    let amax = x.iter().fold(0.0f64, |m, v| m.max(v.abs()));
+    let k = 2f64.powi(1023 - ((amax.to_bits() >> 52) & 0x7ff) as i32);
+
+    x.iter().map(|v| (v * k) * (v * k)).sum::<f64>().sqrt() / k
+}
+
+#[test]
+fn single_over_the_range() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    // all small, all medium, all big, and every mix of the three
+    for &(lo, hi) in &[(-140, -70), (-20, 20), (60, 126), (-140, 126)] {
+        for &n in &[1usize, 3, 4, 15, 16, 17, 100, 1001] {
+            let v: Vec<f64> = (0..n).map(|_| rng.value(lo, hi) as f32 as f64).collect();
+            let x: Vec<f32> = v.iter().map(|&a| a as f32).collect();
+
+            let want = norm(&v);
+
+            for &inc in &[1usize, 3] {
+                let got = snrm2(n, &strided(&x, inc, 1), inc as isize) as f64;
+                assert!((got - want).abs() <= 2.0 * (n as f64 + 2.0) * U32 * want,
+                    "range 2^{lo}..2^{hi}, n {n}, inc {inc}: {got} vs {want}");
+
+                // the same reals, read as n / 2 complex entries
+                if n % 2 == 0 {
+                    let got = scnrm2(n / 2, &strided(&x, inc, 2), inc as isize) as f64;
+                    assert!((got - want).abs() <= 2.0 * (n as f64 + 2.0) * U32 * want,
+                        "complex, range 2^{lo}..2^{hi}, n {n}, inc {inc}: {got} vs {want}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn double_over_the_range() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for &(lo, hi) in &[(-1000, -520), (-20, 20), (500, 1020), (-1000, 1020)] {
+        for &n in &[1usize, 2, 7, 8, 9, 100, 1001] {
+            let x: Vec<f64> = (0..n).map(|_| rng.value(lo, hi)).collect();
+
+            let want = norm(&x);
+
+            for &inc in &[1usize, 2] {
+                let got = dnrm2(n, &strided(&x, inc, 1), inc as isize);
+                assert!((got - want).abs() <= 2.0 * (n as f64 + 2.0) * U64 * want,
+                    "range 2^{lo}..2^{hi}, n {n}, inc {inc}: {got} vs {want}");
+
+                if n % 2 == 0 {
+                    let got = dznrm2(n / 2, &strided(&x, inc, 2), inc as isize);
+                    assert!((got - want).abs() <= 2.0 * (n as f64 + 2.0) * U64 * want,
+                        "complex, range 2^{lo}..2^{hi}, n {n}, inc {inc}: {got} vs {want}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn no_overflow_or_underflow() {
+    // (3, 4) * 2^k has norm 5 * 2^k exactly, even where the squares would
+    // overflow or vanish; 2^-140 is subnormal
+    for &k in &[-140, -100, -70, -63, 0, 52, 60, 100, 125] {
+        let s = 2f32.powi(k);
+        let x = [3.0 * s, 4.0 * s];
+        let y = [3.0 * s, 0.0, 0.0, 4.0 * s];
+        assert_eq!(snrm2(2, &x, 1), 5.0 * s, "2^{k}");
+        assert_eq!(snrm2(2, &y, 3), 5.0 * s, "2^{k}");
+        assert_eq!(scnrm2(1, &x, 1), 5.0 * s, "2^{k}");
+    }
+
+    for &k in &[-1070, -600, -511, 0, 486, 900, 1020] {
+        let s = 2f64.powi(k);
+        let x = [3.0 * s, 4.0 * s];
+        assert_eq!(dnrm2(2, &x, -1), 5.0 * s, "2^{k}");
+        assert_eq!(dznrm2(1, &x, 1), 5.0 * s, "2^{k}");
+    }
+
+    // one huge entry among many tiny ones, and the other way round
+    let mut x = vec![2f32.powi(-100); 33];
+    x[17] = 2f32.powi(100);
+    assert_eq!(snrm2(x.len(), &x, 1), 2f32.powi(100));
+
+    let mut x = vec![1.0f32; 17];
+    x[3] = 2f32.powi(-80);
+    assert_eq!(snrm2(x.len(), &x, 1), 4.0);
+}
+
+#[test]
+fn nan_and_inf() {
+    for &n in &[3usize, 20] {
+        let mut x = vec![1.0f32; n];
+
+        x[n - 2] = f32::INFINITY;
+        assert_eq!(snrm2(n, &x, 1), f32::INFINITY);
+
+        x[1] = f32::NAN;
+        assert!(snrm2(n, &x, 1).is_nan());
+
+        let mut z = vec![2f64.powi(-600); n];
+        z[n / 2] = f64::NAN;
+        assert!(dnrm2(n, &z, 1).is_nan());
+        assert!(dznrm2(n / 2, &z, 1).is_nan());
+    }
+}
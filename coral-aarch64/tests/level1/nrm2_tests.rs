@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     snrm2,
     dnrm2,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     dznrm2,
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_snrm2,
     cblas_dnrm2,
     cblas_scnrm2,
@@ -145,7 +144,7 @@ fn real() {
         let s_coral = snrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_snrm2(
@@ -188,7 +187,7 @@ fn real() {
         let s_coral = snrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_snrm2(
@@ -236,7 +235,7 @@ fn real() {
         let s_coral = dnrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_dnrm2(
@@ -279,7 +278,7 @@ fn real() {
         let s_coral = dnrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_dnrm2(
@@ -338,7 +337,7 @@ fn complex() {
         let s_coral = scnrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_scnrm2(
@@ -385,7 +384,7 @@ fn complex() {
         let s_coral = scnrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_scnrm2(
@@ -441,7 +440,7 @@ fn complex() {
         let s_coral = dznrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_dznrm2(
@@ -488,7 +487,7 @@ fn complex() {
         let s_coral = dznrm2(
             n,
             &x,
-            incx,
+            incx as isize,
         );
         let s_ref = unsafe {
             cblas_dznrm2(
@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     srot,
     srotg,
@@ -10,7 +9,7 @@ use coral_aarch64::level1::{
     drotmg
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_srot,
     cblas_srotg,
     cblas_srotm,
@@ -158,9 +157,9 @@ fn plane_rotations_real() {
         srot(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             c,
             s,
         );
@@ -244,9 +243,9 @@ fn plane_rotations_real() {
         srot(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             c,
             s,
         );
@@ -341,9 +340,9 @@ fn plane_rotations_real() {
         drot(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             c,
             s,
         );
@@ -427,9 +426,9 @@ fn plane_rotations_real() {
         drot(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             c,
             s,
         );
@@ -911,9 +910,9 @@ fn modified_rotations_real() {
         srotm(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             &param,
         );
 
@@ -1202,9 +1201,9 @@ fn modified_rotations_real() {
         drotm(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
             &param,
         );
 
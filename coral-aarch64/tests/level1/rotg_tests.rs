@@ -0,0 +1,238 @@
+use coral_aarch64::level1::{srotg, drotg, crotg, zrotg, crot, zrot};
+
+const U32: f32 = f32::EPSILON / 2.0;
+const U64: f64 = f64::EPSILON / 2.0;
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn f64(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+    }
+}
+
+// `powi` of a negative exponent is `1 / 2^-k`, which overflows for subnormals
+fn pow2(k: i32) -> f64 { 2f64.powi(k / 2) * 2f64.powi(k - k / 2) }
+
+fn strided(v: &[f64], inc: isize) -> Vec<f64> {
+    let n = v.len() / 2;
+    let step = inc.unsigned_abs();
+    let mut buf = vec![0.0; 2 * ((n - 1) * step + 1)];
+
+    for i in 0..n {
+        let k = if inc > 0 { i * step } else { (n - 1 - i) * step };
+        buf[2 * k]     = v[2 * i];
+        buf[2 * k + 1] = v[2 * i + 1];
+    }
+    buf
+}
+
+fn cmul(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] * b[0] - a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+}
+
+fn abs(z: [f64; 2]) -> f64 { z[0].hypot(z[1]) }
+
+#[test]
+fn real_extremes() {
+    // (3, 4) * 2^k gives r = 5 * 2^k, c = 0.6, s = 0.8, even where a^2 + b^2
+    // would overflow or vanish
+    for &k in &[-149, -140, -126, -70, 0, 70, 120, 125] {
+        let t = pow2(k) as f32;
+        let (mut a, mut b, mut c, mut s) = (3.0 * t, -4.0 * t, 0.0, 0.0);
+        srotg(&mut a, &mut b, &mut c, &mut s);
+
+        let tol = 4.0 * U32;
+        assert!((a / t + 5.0).abs() <= 5.0 * tol, "2^{k}: r {a}");
+        assert!((c + 0.6).abs() <= tol && (s - 0.8).abs() <= tol, "2^{k}: c {c}, s {s}");
+        assert!((b - 1.0 / c).abs() <= 4.0 * tol, "2^{k}: z {b}");
+    }
+
+    for &k in &[-1074, -1000, -1022, 0, 600, 1020] {
+        let t = pow2(k);
+        let (mut a, mut b, mut c, mut s) = (4.0 * t, 3.0 * t, 0.0, 0.0);
+        drotg(&mut a, &mut b, &mut c, &mut s);
+
+        let tol = 4.0 * U64;
+        assert!((a / t - 5.0).abs() <= 5.0 * tol, "2^{k}: r {a}");
+        assert!((c - 0.8).abs() <= tol && (s - 0.6).abs() <= tol, "2^{k}: c {c}, s {s}");
+        assert!((b - s).abs() <= tol, "2^{k}: z {b}");
+    }
+
+    // |a| + |b| overflows, r does not
+    let (mut a, mut b, mut c, mut s) = (0.6 * f32::MAX, 0.6 * f32::MAX, 0.0, 0.0);
+    srotg(&mut a, &mut b, &mut c, &mut s);
+    assert!((a as f64 / (0.6 * f32::MAX as f64 * 2f64.sqrt()) - 1.0).abs() <= 4.0 * U32 as f64);
+    assert!((c - 0.5f32.sqrt()).abs() <= 2.0 * U32 && c == s);
+
+    // s underflows to zero, c is exactly one
+    let (mut a, mut b, mut c, mut s) = (f32::MAX, 1e-30f32, 0.0, 0.0);
+    srotg(&mut a, &mut b, &mut c, &mut s);
+    assert_eq!((a, b, c, s), (f32::MAX, 0.0, 1.0, 0.0));
+}
+
+#[test]
+fn real_degenerate() {
+    let (mut a, mut b, mut c, mut s) = (-2.5f32, 0.0, 0.0, 0.0);
+    srotg(&mut a, &mut b, &mut c, &mut s);
+    assert_eq!((a, b, c, s), (-2.5, 0.0, 1.0, 0.0));
+
+    let (mut a, mut b, mut c, mut s) = (0.0f64, -3.0, 0.0, 0.0);
+    drotg(&mut a, &mut b, &mut c, &mut s);
+    assert_eq!((a, b, c, s), (-3.0, 1.0, 0.0, 1.0));
+}
+
+/// checks c real in [0, 1], c^2 + |s|^2 = 1, the rotation zeroes b and r / a
+/// is real and positive
+fn check_complex(a: [f64; 2], b: [f64; 2], r: [f64; 2], c: f64, s: [f64; 2], u: f64, what: &str) {
+    let tol = 16.0 * u;
+    let ra  = abs(r);
+
+    assert!((0.0..=1.0).contains(&c), "{what}: c {c}");
+    assert!((c * c + abs(s).powi(2) - 1.0).abs() <= tol, "{what}: c {c}, s {s:?}");
+
+    // scale everything by 1 / |r| before forming products
+    let an = [a[0] / ra, a[1] / ra];
+    let bn = [b[0] / ra, b[1] / ra];
+    let rn = [r[0] / ra, r[1] / ra];
+
+    let top = cmul(s, bn);
+    let top = [c * an[0] + top[0], c * an[1] + top[1]];
+    let bot = cmul([s[0], -s[1]], an);
+    let bot = [c * bn[0] - bot[0], c * bn[1] - bot[1]];
+
+    assert!(abs([top[0] - rn[0], top[1] - rn[1]]) <= tol, "{what}: {top:?} vs {rn:?}");
+    assert!(abs(bot) <= tol, "{what}: residual {bot:?}");
+
+    if a != [0.0, 0.0] {
+        // r conj(a) is real and positive; a / |a| keeps a tiny a from vanishing
+        let aa = abs(a);
+        let p  = cmul(rn, [a[0] / aa, -a[1] / aa]);
+        assert!(p[0] > 0.0 && p[1].abs() <= tol * p[0], "{what}: r / a = {p:?}");
+    }
+}
+
+#[test]
+fn complex_over_the_range() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    let scales32 = [-140, -130, -126, -64, -20, 0, 20, 62, 64, 100, 126];
+    for &ea in &scales32 {
+        for &eb in &scales32 {
+            let ta = pow2(ea);
+            let tb = pow2(eb);
+            let a  = [(rng.f64() * ta) as f32, (rng.f64() * ta) as f32];
+            let b  = [(rng.f64() * tb) as f32, (rng.f64() * tb) as f32];
+
+            let (mut r, mut c, mut s) = (a, 0.0f32, [0.0f32; 2]);
+            crotg(&mut r, b, &mut c, &mut s);
+
+            let w = |z: [f32; 2]| [z[0] as f64, z[1] as f64];
+            assert!(r[0].is_finite() && r[1].is_finite() && (r[0] != 0.0 || r[1] != 0.0),
+                "2^{ea}, 2^{eb}: r {r:?}");
+
+            // subnormal inputs lose digits before the routine sees them
+            let u = if ea < -126 || eb < -126 { 2f64.powi(-10) } else { U32 as f64 };
+            check_complex(w(a), w(b), w(r), c as f64, w(s), u, &format!("c 2^{ea}, 2^{eb}"));
+        }
+    }
+
+    let scales64 = [-1060, -1040, -1022, -600, -511, 0, 511, 600, 1000, 1022];
+    for &ea in &scales64 {
+        for &eb in &scales64 {
+            let ta = pow2(ea);
+            let tb = pow2(eb);
+            let a  = [rng.f64() * ta, rng.f64() * ta];
+            let b  = [rng.f64() * tb, rng.f64() * tb];
+
+            let (mut r, mut c, mut s) = (a, 0.0, [0.0; 2]);
+            zrotg(&mut r, b, &mut c, &mut s);
+
+            assert!(r[0].is_finite() && r[1].is_finite() && (r[0] != 0.0 || r[1] != 0.0),
+                "2^{ea}, 2^{eb}: r {r:?}");
+
+            let u = if ea < -1022 || eb < -1022 { 2f64.powi(-10) } else { U64 };
+            check_complex(a, b, r, c, s, u, &format!("z 2^{ea}, 2^{eb}"));
+        }
+    }
+}
+
+#[test]
+fn complex_degenerate() {
+    // b == 0 leaves a alone
+    let (mut a, mut c, mut s) = ([1.5f32, -2.0], 0.0, [9.0; 2]);
+    crotg(&mut a, [0.0, 0.0], &mut c, &mut s);
+    assert_eq!((a, c, s), ([1.5, -2.0], 1.0, [0.0, 0.0]));
+
+    // a == 0: r = |b|, s = conj(b) / |b|
+    let (mut a, mut c, mut s) = ([0.0f64, 0.0], 0.0, [0.0; 2]);
+    zrotg(&mut a, [3.0, 4.0], &mut c, &mut s);
+    assert_eq!((a, c, s), ([5.0, 0.0], 0.0, [0.6, -0.8]));
+
+    let (mut a, mut c, mut s) = ([0.0f32, 0.0], 0.0, [0.0; 2]);
+    crotg(&mut a, [0.0, -1e-40], &mut c, &mut s);
+    assert_eq!((a, c, s), ([1e-40, 0.0], 0.0, [0.0, 1.0]));
+
+    let (mut a, mut c, mut s) = ([0.0f64, 0.0], 0.0, [0.0; 2]);
+    zrotg(&mut a, [1e300, 1e300], &mut c, &mut s);
+    assert!((a[0] - 1e300 * 2f64.sqrt()).abs() <= 4.0 * U64 * a[0] && a[1] == 0.0);
+    assert_eq!(c, 0.0);
+}
+
+#[test]
+fn complex_apply() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    let c = 0.6;
+    let s = [0.48, -0.64];
+
+    for &n in &[1usize, 2, 3, 4, 5, 8, 9, 17, 100] {
+        let x: Vec<f64> = (0..2 * n).map(|_| rng.f64()).collect();
+        let y: Vec<f64> = (0..2 * n).map(|_| rng.f64()).collect();
+
+        // x' = c x + s y, y' = c y - conj(s) x
+        let mut xw = x.clone();
+        let mut yw = y.clone();
+        for i in 0..n {
+            let (xi, yi) = ([x[2 * i], x[2 * i + 1]], [y[2 * i], y[2 * i + 1]]);
+            let sy = cmul(s, yi);
+            let sx = cmul([s[0], -s[1]], xi);
+            xw[2 * i..2 * i + 2].copy_from_slice(&[c * xi[0] + sy[0], c * xi[1] + sy[1]]);
+            yw[2 * i..2 * i + 2].copy_from_slice(&[c * yi[0] - sx[0], c * yi[1] - sx[1]]);
+        }
+
+        for &(incx, incy) in &[(1isize, 1isize), (2, 3), (-1, 1), (1, -2), (-2, -1)] {
+            let mut xb = strided(&x, incx);
+            let mut yb = strided(&y, incy);
+            zrot(n, &mut xb, incx, &mut yb, incy, c, s);
+
+            let (xe, ye) = (strided(&xw, incx), strided(&yw, incy));
+            for k in 0..xb.len() {
+                assert!((xb[k] - xe[k]).abs() <= 4.0 * U64, "z n {n}, inc ({incx}, {incy})");
+            }
+            for k in 0..yb.len() {
+                assert!((yb[k] - ye[k]).abs() <= 4.0 * U64, "z n {n}, inc ({incx}, {incy})");
+            }
+
+            let f = |v: &[f64]| v.iter().map(|&a| a as f32).collect::<Vec<f32>>();
+            let mut xb = f(&strided(&x, incx));
+            let mut yb = f(&strided(&y, incy));
+            crot(n, &mut xb, incx, &mut yb, incy, c as f32, [s[0] as f32, s[1] as f32]);
+
+            for k in 0..xb.len() {
+                assert!((xb[k] as f64 - xe[k]).abs() <= 8.0 * U32 as f64, "c n {n}, inc ({incx}, {incy})");
+            }
+            for k in 0..yb.len() {
+                assert!((yb[k] as f64 - ye[k]).abs() <= 8.0 * U32 as f64, "c n {n}, inc ({incx}, {incy})");
+            }
+        }
+    }
+}
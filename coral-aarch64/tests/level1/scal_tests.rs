@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     sscal,
     dscal,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     zscal,
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_sscal,
     cblas_dscal,
     cblas_cscal,
@@ -157,7 +156,7 @@ fn real() {
             n,
             alpha,
             &mut x_coral,
-            incx,
+            incx as isize,
         );
 
         unsafe {
@@ -304,7 +303,7 @@ fn real() {
             n,
             alpha,
             &mut x_coral,
-            incx,
+            incx as isize,
         );
 
         unsafe {
@@ -454,7 +453,7 @@ fn complex() {
             n,
             alpha,
             &mut x_coral,
-            incx,
+            incx as isize,
         );
 
         unsafe {
@@ -635,7 +634,7 @@ fn complex() {
             n,
             alpha,
             &mut x_coral,
-            incx,
+            incx as isize,
         );
 
         unsafe {
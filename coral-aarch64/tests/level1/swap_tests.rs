@@ -1,4 +1,3 @@
-use blas_src as _;
 use coral_aarch64::level1::{
     sswap,
     dswap,
@@ -6,7 +5,7 @@ use coral_aarch64::level1::{
     zswap,
 };
 
-use cblas_sys::{
+use crate::cblas::{
     cblas_sswap,
     cblas_dswap,
     cblas_cswap,
@@ -172,9 +171,9 @@ fn real() {
         sswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -245,9 +244,9 @@ fn real() {
         sswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -326,9 +325,9 @@ fn real() {
         dswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -399,9 +398,9 @@ fn real() {
         dswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -483,9 +482,9 @@ fn complex() {
         cswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -564,9 +563,9 @@ fn complex() {
         cswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -645,9 +644,9 @@ fn complex() {
         zswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -726,9 +725,9 @@ fn complex() {
         zswap(
             n,
             &mut x_coral,
-            incx,
+            incx as isize,
             &mut y_coral,
-            incy,
+            incy as isize,
         );
 
         unsafe {
@@ -1,3 +1,6 @@
+#[path = "common/cblas.rs"]
+mod cblas;
+
 // single precision 
 #[path = "level2/sgemv_tests.rs"]
 mod sgemv_tests;
@@ -61,3 +64,11 @@ mod zher2_tests;
 mod ztrmv_tests;
 #[path = "level2/ztrsv_tests.rs"] 
 mod ztrsv_tests; 
+
+// negative increments 
+#[path = "level2/neg_inc_tests.rs"] 
+mod neg_inc_tests;
+
+// compensated gemv
+#[path = "level2/accurate_tests.rs"] 
+mod accurate_tests;
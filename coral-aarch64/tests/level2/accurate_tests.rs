@@ -0,0 +1,181 @@
+use coral_aarch64::enums::{Accuracy, CoralTranspose};
+use coral_aarch64::level2::{sgemv, sgemv_with, dgemv_with};
+
+const U32: f64 = 1.0 / (1u64 << 24) as f64;
+const U64: f64 = 1.0 / (1u64 << 53) as f64;
+
+fn gamma(n: usize, u: f64) -> f64 {
+    n as f64 * u / (1.0 - n as f64 * u)
+}
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // odd integer below 2^bits times 2^e, e in 0..=emax, random sign
+    fn int(&mut self, bits: u32, emax: u64) -> i128 {
+        let m = ((self.next() >> (64 - bits)) | 1) as i128;
+        let m = m << (self.next() % (emax + 1));
+        if self.next() & 1 == 0 { m } else { -m }
+    }
+}
+
+// op(A) as a dense k-column, len-row table whose big terms cancel pairwise
+// along each row: columns 2i and 2i + 1 share x and carry opposite entries.
+struct Case {
+    op : Vec<Vec<i128>>,
+    x  : Vec<i128>,
+    y  : Vec<i128>,
+}
+
+fn case(len: usize, k: usize, bits: u32, emax: u64, rng: &mut Rng) -> Case {
+    let pairs = k / 2 - k / 8;
+
+    let mut x = vec![0; k];
+    for p in 0..pairs {
+        x[2 * p] = rng.int(bits, emax);
+        x[2 * p + 1] = x[2 * p];
+    }
+    for xi in x.iter_mut().skip(2 * pairs) {
+        *xi = rng.int(bits, 0);
+    }
+
+    let op = (0..len)
+        .map(|_| {
+            let mut row = vec![0; k];
+            for p in 0..pairs {
+                row[2 * p] = rng.int(bits, emax);
+                row[2 * p + 1] = -row[2 * p];
+            }
+            for a in row.iter_mut().skip(2 * pairs) {
+                *a = rng.int(bits, 0);
+            }
+            row
+        })
+        .collect();
+
+    // even, so beta = 0.5 stays exact
+    let y = (0..len).map(|_| 2 * rng.int(bits, 0)).collect();
+
+    Case { op, x, y }
+}
+
+// column-major A with op(A) = case.op
+fn store(c: &Case, trans: CoralTranspose, lda_pad: usize) -> (Vec<f64>, usize, usize, usize) {
+    let (len, k) = (c.op.len(), c.x.len());
+    let (rows, cols) = match trans {
+        CoralTranspose::NoTranspose => (len, k),
+        _                           => (k, len),
+    };
+    let lda = rows + lda_pad;
+
+    let mut a = vec![0.0; lda * cols];
+    for (j, row) in c.op.iter().enumerate() {
+        for (i, &v) in row.iter().enumerate() {
+            let (r, s) = match trans {
+                CoralTranspose::NoTranspose => (j, i),
+                _                           => (i, j),
+            };
+            a[s * lda + r] = v as f64;
+        }
+    }
+    (a, rows, cols, lda)
+}
+
+fn check(c: &Case, got: &[f64], alpha: f64, u: f64) {
+    let k = c.x.len();
+
+    for (j, row) in c.op.iter().enumerate() {
+        let d: i128   = row.iter().zip(&c.x).map(|(a, x)| a * x).sum();
+        let abs: i128 = row.iter().zip(&c.x).map(|(a, x)| (a * x).abs()).sum();
+        let exact     = alpha as i128 * d + c.y[j] / 2;
+
+        let err   = (got[j] as i128 - exact).abs() as f64;
+        let bound = 2.0 * u * (alpha * d as f64).abs()
+                  + 2.0 * u * (c.y[j] as f64 / 2.0).abs()
+                  + alpha * gamma(k, u).powi(2) * abs as f64;
+
+        // first-order bound; leave room for the u^2 terms
+        assert!(err <= bound * (1.0 + 4.0 * u), "entry {j}: {err} > {bound}");
+    }
+}
+
+#[test]
+fn compensated_single() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for &trans in &[CoralTranspose::NoTranspose, CoralTranspose::Transpose] {
+        for &(len, k) in &[(17usize, 300usize), (40, 2049)] {
+            let c = case(len, k, 12, 12, &mut rng);
+            let (a, rows, cols, lda) = store(&c, trans, 3);
+            let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+
+            for &(incx, incy) in &[(1isize, 1isize), (2, -3)] {
+                let mut x = vec![0.0f32; (k - 1) * incx.unsigned_abs() + 1];
+                let mut y = vec![0.0f32; (len - 1) * incy.unsigned_abs() + 1];
+                for (i, &v) in c.x.iter().enumerate() {
+                    x[if incx > 0 { i * incx as usize } else { (k - 1 - i) * incx.unsigned_abs() }] = v as f32;
+                }
+                for (j, &v) in c.y.iter().enumerate() {
+                    y[if incy > 0 { j * incy as usize } else { (len - 1 - j) * incy.unsigned_abs() }] = v as f32;
+                }
+
+                sgemv_with(Accuracy::Compensated, trans, rows, cols, 2.0, &a, lda, &x, incx, 0.5, &mut y, incy);
+
+                let got: Vec<f64> = (0..len)
+                    .map(|j| y[if incy > 0 { j * incy as usize } else { (len - 1 - j) * incy.unsigned_abs() }] as f64)
+                    .collect();
+                check(&c, &got, 2.0, U32);
+            }
+        }
+    }
+}
+
+#[test]
+fn compensated_double() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for &trans in &[CoralTranspose::NoTranspose, CoralTranspose::ConjugateTranspose] {
+        let (len, k) = (23, 1500);
+        let c = case(len, k, 30, 10, &mut rng);
+        let (a, rows, cols, lda) = store(&c, trans, 1);
+
+        let x: Vec<f64> = c.x.iter().map(|&v| v as f64).collect();
+        let mut y: Vec<f64> = c.y.iter().map(|&v| v as f64).collect();
+
+        dgemv_with(Accuracy::Compensated, trans, rows, cols, 2.0, &a, lda, &x, 1, 0.5, &mut y, 1);
+        check(&c, &y, 2.0, U64);
+    }
+}
+
+#[test]
+fn fast_and_edge_cases() {
+    let (m, n) = (37, 29);
+    let a: Vec<f32> = (0..m * n).map(|i| ((i * 7 % 13) as f32 - 6.0) / 4.0).collect();
+    let x: Vec<f32> = (0..m).map(|i| ((i * 5 % 11) as f32 - 5.0) / 2.0).collect();
+    let y: Vec<f32> = (0..n).map(|i| i as f32 / 8.0).collect();
+
+    // Fast is sgemv itself
+    let (mut p, mut q) = (y.clone(), y.clone());
+    sgemv(CoralTranspose::Transpose, m, n, 1.5, &a, m, &x, 1, -1.0, &mut p, 1);
+    sgemv_with(Accuracy::Fast, CoralTranspose::Transpose, m, n, 1.5, &a, m, &x, 1, -1.0, &mut q, 1);
+    assert_eq!(p, q);
+
+    // beta == 0 never reads y, alpha == 0 never reads A
+    let mut p = vec![f32::NAN; n];
+    sgemv_with(Accuracy::Compensated, CoralTranspose::Transpose, m, n, 1.0, &a, m, &x, 1, 0.0, &mut p, 1);
+    assert!(p.iter().all(|v| v.is_finite()));
+
+    let nan = vec![f32::NAN; m * n];
+    let mut p = y.clone();
+    sgemv_with(Accuracy::Compensated, CoralTranspose::NoTranspose, n, m, 0.0, &nan, n, &x, 1, 2.0, &mut p, 1);
+    let twice: Vec<f32> = y.iter().map(|v| 2.0 * v).collect();
+    assert_eq!(p, twice);
+}
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{cblas_cgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use crate::cblas::{cblas_cgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
 use coral_aarch64::enums::CoralTranspose;
 use coral_aarch64::level2::cgemv;
 
@@ -162,10 +161,10 @@ fn run_case(
         &a, 
         lda, 
         &x, 
-        incx,
+        incx as isize,
         beta,
         &mut y_coral,
-        incy
+        incy as isize
     );
 
     // reference
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_cgeru, cblas_cgerc, CBLAS_LAYOUT };
+use crate::cblas::{ cblas_cgeru, cblas_cgerc, CBLAS_LAYOUT };
 use coral_aarch64::level2::{ cgeru, cgerc };
 
 #[inline(always)]
@@ -155,9 +154,9 @@ fn coral_fn_for(
                 n,
                 alpha,
                 x,
-                incx,
+                incx as isize,
                 y,
-                incy,
+                incy as isize,
                 a,
                 lda,
             )
@@ -168,9 +167,9 @@ fn coral_fn_for(
                 n,
                 alpha,
                 x,
-                incx,
+                incx as isize,
                 y,
-                incy,
+                incy as isize,
                 a,
                 lda,
             )
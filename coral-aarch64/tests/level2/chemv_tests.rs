@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_chemv, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_chemv, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::level2::chemv;
 use coral_aarch64::enums::CoralTriangular;
 
@@ -225,10 +224,10 @@ fn run_case(
         &a,
         lda,
         &x,
-        incx,
+        incx as isize,
         beta,
         &mut y_coral,
-        incy,
+        incy as isize,
     );
 
     // reference
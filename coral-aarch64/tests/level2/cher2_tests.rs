@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_cher2, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_cher2, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::level2::cher2;
 use coral_aarch64::enums::CoralTriangular;
 
@@ -199,9 +198,9 @@ fn run_case(
         n,
         alpha,
         &x,
-        incx,
+        incx as isize,
         &y,
-        incy,
+        incy as isize,
         &mut a_coral,
         lda,
     );
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_DIAG, CBLAS_UPLO, cblas_ctrsv };
+use crate::cblas::{ CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_DIAG, CBLAS_UPLO, cblas_ctrsv };
 use coral_aarch64::enums::{ CoralDiagonal, CoralTranspose, CoralTriangular };
 use coral_aarch64::level2::ctrsv;
 
@@ -297,7 +296,7 @@ fn run_case(
         &a,
         lda,
         &mut x_coral,
-        incx,
+        incx as isize,
     );
 
     let mut x_ref = x0.clone();
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_dgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE };
+use crate::cblas::{ cblas_dgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE };
 use coral_aarch64::enums::CoralTranspose;
 use coral_aarch64::level2::dgemv;
 
@@ -164,10 +163,10 @@ fn run_case(
         &a,
         lda,
         &x,
-        incx,
+        incx as isize,
         beta,
         &mut y_coral,
-        incy,
+        incy as isize,
     );
 
     let mut y_ref = y0.clone();
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_dger, CBLAS_LAYOUT };
+use crate::cblas::{ cblas_dger, CBLAS_LAYOUT };
 use coral_aarch64::level2::dger;
 
 #[inline(always)]
@@ -101,9 +100,9 @@ fn run_case(
         n,
         alpha,
         &x,
-        incx,
+        incx as isize,
         &y,
-        incy,
+        incy as isize,
         &mut a_coral,
         lda,
     );
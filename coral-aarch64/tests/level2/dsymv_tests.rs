@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_dsymv, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_dsymv, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::enums::CoralTriangular;
 use coral_aarch64::level2::dsymv;
 
@@ -173,10 +172,10 @@ fn run_case(
         &a,
         lda,
         &x,
-        incx,
+        incx as isize,
         beta,
         &mut y_coral,
-        incy,
+        incy as isize,
     );
 
     let mut y_ref = y0.clone();
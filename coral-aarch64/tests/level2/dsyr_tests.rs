@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_dsyr, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_dsyr, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::level2::dsyr;
 use coral_aarch64::enums::CoralTriangular;
 
@@ -146,7 +145,7 @@ fn run_case(
         n,
         alpha,
         &x,
-        incx,
+        incx as isize,
         &mut a_coral,
         lda,
     );
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ CBLAS_TRANSPOSE, CBLAS_LAYOUT, CBLAS_UPLO, CBLAS_DIAG, cblas_dtrmv };
+use crate::cblas::{ CBLAS_TRANSPOSE, CBLAS_LAYOUT, CBLAS_UPLO, CBLAS_DIAG, cblas_dtrmv };
 use coral_aarch64::enums::{ CoralDiagonal, CoralTranspose, CoralTriangular };
 use coral_aarch64::level2::dtrmv;
 
@@ -197,7 +196,7 @@ fn run_case(
         &a,
         lda,
         &mut x_coral,
-        incx,
+        incx as isize,
     );
 
     let mut x_ref = x0.clone();
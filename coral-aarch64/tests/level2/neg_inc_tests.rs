@@ -0,0 +1,212 @@
+use coral_aarch64::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use coral_aarch64::level2::{
+    sgemv,
+    strsv,
+    sger,
+    dsyr2,
+    zgemv,
+    zhemv,
+    ctrmv,
+    cher,
+};
+
+// entry i of an n-vector at stride inc, w scalars per entry, as in reference BLAS
+fn at(i: usize, n: usize, inc: isize, w: usize) -> usize {
+    let step = inc.unsigned_abs();
+
+    if inc >= 0 { w * i * step } else { w * (n - 1 - i) * step }
+}
+
+fn store<T: Copy + Default>(v: &[T], n: usize, inc: isize, w: usize) -> Vec<T> {
+    let mut buf = vec![T::default(); w * ((n - 1) * inc.unsigned_abs() + 1)];
+    for i in 0..n {
+        let o = at(i, n, inc, w);
+        buf[o..o + w].copy_from_slice(&v[w * i..w * i + w]);
+    }
+    buf
+}
+
+fn load<T: Copy>(buf: &[T], n: usize, inc: isize, w: usize) -> Vec<T> {
+    (0..n)
+        .flat_map(|i| {
+            let o = at(i, n, inc, w);
+            buf[o..o + w].to_vec()
+        })
+        .collect()
+}
+
+fn make_f32(len: usize, seed: usize) -> Vec<f32> {
+    (0..len).map(|i| (((i * 13 + seed * 7) % 17) as f32 - 8.0) / 5.0).collect()
+}
+
+fn make_f64(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|i| (((i * 13 + seed * 7) % 17) as f64 - 8.0) / 5.0).collect()
+}
+
+fn assert_allclose_f32(a: &[f32], b: &[f32], tol: f32) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+fn assert_allclose_f64(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= 1e-12 * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+const INCS: [(isize, isize); 4] = [(-1, 1), (2, -1), (-1, -3), (-2, -2)];
+
+#[test]
+fn gemv_and_ger() {
+    let (m, n) = (19, 13);
+    let lda = m + 2;
+    let a = make_f32(lda * n, 1);
+
+    for &trans in &[CoralTranspose::NoTranspose, CoralTranspose::Transpose] {
+        let (lx, ly) = match trans {
+            CoralTranspose::NoTranspose => (n, m),
+            _                           => (m, n),
+        };
+        let x = make_f32(lx, 2);
+        let y = make_f32(ly, 3);
+
+        for &(incx, incy) in &INCS {
+            // the same call on forward strides is the reference
+            let (fx, fy) = (incx.abs(), incy.abs());
+
+            let mut ys = store(&y, ly, incy, 1);
+            let mut yf = store(&y, ly, fy, 1);
+            sgemv(trans, m, n, 1.5, &a, lda, &store(&x, lx, incx, 1), incx, -0.5, &mut ys, incy);
+            sgemv(trans, m, n, 1.5, &a, lda, &store(&x, lx, fx, 1), fx, -0.5, &mut yf, fy);
+            assert_allclose_f32(&load(&ys, ly, incy, 1), &load(&yf, ly, fy, 1), 1e-5);
+        }
+    }
+
+    let x = make_f32(m, 4);
+    let y = make_f32(n, 5);
+    for &(incx, incy) in &INCS {
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let mut got = a.clone();
+        let mut exp = a.clone();
+        sger(m, n, 0.25, &store(&x, m, incx, 1), incx, &store(&y, n, incy, 1), incy, &mut got, lda);
+        sger(m, n, 0.25, &store(&x, m, fx, 1), fx, &store(&y, n, fy, 1), fy, &mut exp, lda);
+        assert_allclose_f32(&got, &exp, 1e-6);
+    }
+}
+
+#[test]
+fn triangular() {
+    let n = 17;
+    let lda = n + 1;
+
+    // diagonally dominant so the solve is well conditioned
+    let mut a = make_f32(lda * n, 6);
+    for j in 0..n {
+        a[j * lda + j] += 8.0;
+    }
+    let x = make_f32(n, 7);
+
+    for &uplo in &[CoralTriangular::UpperTriangular, CoralTriangular::LowerTriangular] {
+        for &trans in &[CoralTranspose::NoTranspose, CoralTranspose::Transpose] {
+            for &incx in &[-1isize, -3] {
+                let fx = incx.abs();
+
+                let mut xs = store(&x, n, incx, 1);
+                let mut xf = store(&x, n, fx, 1);
+                strsv(uplo, trans, CoralDiagonal::NonUnitDiagonal, n, &a, lda, &mut xs, incx);
+                strsv(uplo, trans, CoralDiagonal::NonUnitDiagonal, n, &a, lda, &mut xf, fx);
+                assert_allclose_f32(&load(&xs, n, incx, 1), &load(&xf, n, fx, 1), 1e-5);
+            }
+        }
+    }
+
+    let ca: Vec<f32> = make_f64(2 * lda * n, 8).iter().map(|&v| v as f32).collect();
+    let cx: Vec<f32> = make_f64(2 * n, 9).iter().map(|&v| v as f32).collect();
+    for &incx in &[-1isize, -2] {
+        let fx = incx.abs();
+
+        let mut xs = store(&cx, n, incx, 2);
+        let mut xf = store(&cx, n, fx, 2);
+        ctrmv(
+            CoralTriangular::LowerTriangular, CoralTranspose::ConjugateTranspose,
+            CoralDiagonal::UnitDiagonal, n, &ca, lda, &mut xs, incx,
+        );
+        ctrmv(
+            CoralTriangular::LowerTriangular, CoralTranspose::ConjugateTranspose,
+            CoralDiagonal::UnitDiagonal, n, &ca, lda, &mut xf, fx,
+        );
+        assert_allclose_f32(&load(&xs, n, incx, 2), &load(&xf, n, fx, 2), 1e-5);
+    }
+}
+
+#[test]
+fn symmetric_and_hermitian() {
+    let n = 15;
+    let lda = n + 3;
+
+    let a = make_f64(lda * n, 10);
+    let x = make_f64(n, 11);
+    let y = make_f64(n, 12);
+    for &(incx, incy) in &INCS {
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let mut got = a.clone();
+        let mut exp = a.clone();
+        dsyr2(
+            CoralTriangular::UpperTriangular, n, 0.5,
+            &store(&x, n, incx, 1), incx, &store(&y, n, incy, 1), incy, &mut got, lda,
+        );
+        dsyr2(
+            CoralTriangular::UpperTriangular, n, 0.5,
+            &store(&x, n, fx, 1), fx, &store(&y, n, fy, 1), fy, &mut exp, lda,
+        );
+        assert_allclose_f64(&got, &exp);
+    }
+
+    let za = make_f64(2 * lda * n, 13);
+    let zx = make_f64(2 * n, 14);
+    let zy = make_f64(2 * n, 15);
+    for &(incx, incy) in &INCS {
+        let (fx, fy) = (incx.abs(), incy.abs());
+
+        let mut ys = store(&zy, n, incy, 2);
+        let mut yf = store(&zy, n, fy, 2);
+        zhemv(
+            CoralTriangular::LowerTriangular, n, [1.0, 0.5], &za, lda,
+            &store(&zx, n, incx, 2), incx, [0.25, -1.0], &mut ys, incy,
+        );
+        zhemv(
+            CoralTriangular::LowerTriangular, n, [1.0, 0.5], &za, lda,
+            &store(&zx, n, fx, 2), fx, [0.25, -1.0], &mut yf, fy,
+        );
+        assert_allclose_f64(&load(&ys, n, incy, 2), &load(&yf, n, fy, 2));
+
+        let mut ys = store(&zy, n, incy, 2);
+        let mut yf = store(&zy, n, fy, 2);
+        zgemv(
+            CoralTranspose::ConjugateTranspose, n, n, [1.0, 0.5], &za, lda,
+            &store(&zx, n, incx, 2), incx, [0.25, -1.0], &mut ys, incy,
+        );
+        zgemv(
+            CoralTranspose::ConjugateTranspose, n, n, [1.0, 0.5], &za, lda,
+            &store(&zx, n, fx, 2), fx, [0.25, -1.0], &mut yf, fy,
+        );
+        assert_allclose_f64(&load(&ys, n, incy, 2), &load(&yf, n, fy, 2));
+    }
+
+    let ca: Vec<f32> = za.iter().map(|&v| v as f32).collect();
+    let cx: Vec<f32> = zx.iter().map(|&v| v as f32).collect();
+    for &incx in &[-1isize, -4] {
+        let fx = incx.abs();
+
+        let mut got = ca.clone();
+        let mut exp = ca.clone();
+        cher(CoralTriangular::UpperTriangular, n, 0.75, &store(&cx, n, incx, 2), incx, &mut got, lda);
+        cher(CoralTriangular::UpperTriangular, n, 0.75, &store(&cx, n, fx, 2), fx, &mut exp, lda);
+        assert_allclose_f32(&got, &exp, 1e-6);
+    }
+}
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_sger, CBLAS_LAYOUT };
+use crate::cblas::{ cblas_sger, CBLAS_LAYOUT };
 use coral_aarch64::level2::sger;
 
 #[inline(always)]
@@ -102,9 +101,9 @@ fn run_case(
         n,
         alpha,
         &x,
-        incx,
+        incx as isize,
         &y,
-        incy,
+        incy as isize,
         &mut a_coral,
         lda,
     );
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_ssyr2, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_ssyr2, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::level2::ssyr2;
 use coral_aarch64::enums::CoralTriangular;
 
@@ -152,9 +151,9 @@ fn run_case(
         n,
         alpha,
         &x,
-        incx,
+        incx as isize,
         &y,
-        incy,
+        incy as isize,
         &mut a_coral,
         lda,
     );
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_zgeru, cblas_zgerc, CBLAS_LAYOUT };
+use crate::cblas::{ cblas_zgeru, cblas_zgerc, CBLAS_LAYOUT };
 use coral_aarch64::level2::{ zgeru, zgerc };
 
 fn cblas_zgeru_ref(
@@ -148,9 +147,9 @@ fn coral_fn_for(
                 n,
                 alpha,
                 x,
-                incx,
+                incx as isize,
                 y,
-                incy,
+                incy as isize,
                 a,
                 lda,
             )
@@ -161,9 +160,9 @@ fn coral_fn_for(
                 n,
                 alpha,
                 x,
-                incx,
+                incx as isize,
                 y,
-                incy,
+                incy as isize,
                 a,
                 lda,
             )
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{ cblas_zhemv, CBLAS_LAYOUT, CBLAS_UPLO };
+use crate::cblas::{ cblas_zhemv, CBLAS_LAYOUT, CBLAS_UPLO };
 use coral_aarch64::level2::zhemv;
 use coral_aarch64::enums::CoralTriangular;
 
@@ -223,10 +222,10 @@ fn run_case(
         &a,
         lda,
         &x,
-        incx,
+        incx as isize,
         beta,
         &mut y_coral,
-        incy,
+        incy as isize,
     );
 
     // reference
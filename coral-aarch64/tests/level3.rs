@@ -1,3 +1,6 @@
+#[path = "common/cblas.rs"]
+mod cblas;
+
 // single precision 
 #[path = "level3/sgemm_tests.rs"] 
 mod sgemm_tests;
@@ -13,3 +16,23 @@ mod cgemm_tests;
 // complex double precision 
 #[path = "level3/zgemm_tests.rs"] 
 mod zgemm_tests;
+
+// fused epilogue 
+#[path = "level3/sgemm_epilogue_tests.rs"] 
+mod sgemm_epilogue_tests;
+
+// pre-packed operands 
+#[path = "level3/sgemm_packed_tests.rs"] 
+mod sgemm_packed_tests;
+
+// triangular update 
+#[path = "level3/gemmt_tests.rs"] 
+mod gemmt_tests;
+
+// 3M complex gemm 
+#[path = "level3/gemm_3m_tests.rs"] 
+mod gemm_3m_tests;
+
+// slice wrappers 
+#[path = "level3/slice_tests.rs"] 
+mod slice_tests;
@@ -0,0 +1,183 @@
+use crate::cblas::{cblas_cgemm, cblas_zgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level3::{cgemm_3m, zgemm_3m};
+
+#[inline(always)]
+fn to_cblas(op: CoralTranspose) -> CBLAS_TRANSPOSE {
+    match op {
+        CoralTranspose::NoTranspose        => CBLAS_TRANSPOSE::CblasNoTrans,
+        CoralTranspose::Transpose          => CBLAS_TRANSPOSE::CblasTrans,
+        CoralTranspose::ConjugateTranspose => CBLAS_TRANSPOSE::CblasConjTrans,
+    }
+}
+
+#[inline(always)]
+fn dims(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (rows, cols),
+        _                           => (cols, rows),
+    }
+}
+
+fn make_matrix_colmajor(
+    rows : usize,
+    cols : usize,
+    ld   : usize,
+    f    : impl Fn(usize, usize) -> [f64; 2],
+) -> Vec<f64> {
+    let mut a = vec![0.0; 2 * ld * cols];
+
+    for j in 0..cols {
+        for i in 0..rows {
+            let [re, im] = f(i, j);
+
+            a[2 * (i + j * ld) + 0] = re;
+            a[2 * (i + j * ld) + 1] = im;
+        }
+    }
+    a
+}
+
+// 3M is only normwise accurate; compare against max |C|
+fn assert_normwise_close(a: &[f64], b: &[f64], tol: f64) {
+    assert_eq!(a.len(), b.len());
+
+    let scale = b.iter().fold(1.0f64, |s, &y| s.max(y.abs()));
+
+    for (idx, (&x, &y)) in a.iter().zip(b.iter()).enumerate() {
+        let diff = (x - y).abs();
+
+        assert!(
+            diff <= tol * scale,
+            "mismatch at {idx}: coral={x:.8e} vs ref={y:.8e} delta={diff:.3e} scale={scale:.3e}"
+        );
+    }
+}
+
+const OPS: [CoralTranspose; 3] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+    CoralTranspose::ConjugateTranspose,
+];
+
+fn f_a(i: usize, j: usize) -> [f64; 2] {
+    [0.1 + (i as f64) * 0.025 - (j as f64) * 0.0125, -0.05 + (i as f64) * 0.02 + (j as f64) * 0.01]
+}
+
+fn f_b(i: usize, j: usize) -> [f64; 2] {
+    [-0.2 + (i as f64) * 0.005 + (j as f64) * 0.0075, 0.15 - (i as f64) * 0.01 + (j as f64) * 0.005]
+}
+
+fn f_c(i: usize, j: usize) -> [f64; 2] {
+    [0.3 - (i as f64) * 0.01 + (j as f64) * 0.02, 0.1 + (i as f64) * 0.005]
+}
+
+fn run_c(m: usize, n: usize, k: usize, alpha: [f32; 2], beta: [f32; 2]) {
+    for &op_a in &OPS {
+        for &op_b in &OPS {
+            let (ar, ac) = dims(op_a, m, k);
+            let (br, bc) = dims(op_b, k, n);
+            let (lda, ldb, ldc) = (ar + 1, br + 2, m + 3);
+
+            let to32 = |v: Vec<f64>| v.into_iter().map(|x| x as f32).collect::<Vec<f32>>();
+            let a = to32(make_matrix_colmajor(ar, ac, lda, f_a));
+            let b = to32(make_matrix_colmajor(br, bc, ldb, f_b));
+            let init = to32(make_matrix_colmajor(m, n, ldc, f_c));
+
+            let mut c = init.clone();
+            cgemm_3m(
+                op_a, op_b, m, n, k, alpha,
+                a.as_ptr(), lda, b.as_ptr(), ldb,
+                beta, c.as_mut_ptr(), ldc,
+            );
+
+            let mut c_ref = init.clone();
+            unsafe {
+                cblas_cgemm(
+                    CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                    m as i32, n as i32, k as i32,
+                    &alpha as *const [f32; 2],
+                    a.as_ptr() as *const [f32; 2], lda as i32,
+                    b.as_ptr() as *const [f32; 2], ldb as i32,
+                    &beta as *const [f32; 2],
+                    c_ref.as_mut_ptr() as *mut [f32; 2], ldc as i32,
+                );
+            }
+
+            let up = |v: &[f32]| v.iter().map(|&x| x as f64).collect::<Vec<f64>>();
+            assert_normwise_close(&up(&c), &up(&c_ref), 1e-4 * (k as f64).max(1.0).sqrt());
+        }
+    }
+}
+
+fn run_z(m: usize, n: usize, k: usize, alpha: [f64; 2], beta: [f64; 2]) {
+    for &op_a in &OPS {
+        for &op_b in &OPS {
+            let (ar, ac) = dims(op_a, m, k);
+            let (br, bc) = dims(op_b, k, n);
+            let (lda, ldb, ldc) = (ar + 1, br + 2, m + 3);
+
+            let a = make_matrix_colmajor(ar, ac, lda, f_a);
+            let b = make_matrix_colmajor(br, bc, ldb, f_b);
+            let init = make_matrix_colmajor(m, n, ldc, f_c);
+
+            let mut c = init.clone();
+            zgemm_3m(
+                op_a, op_b, m, n, k, alpha,
+                a.as_ptr(), lda, b.as_ptr(), ldb,
+                beta, c.as_mut_ptr(), ldc,
+            );
+
+            let mut c_ref = init.clone();
+            unsafe {
+                cblas_zgemm(
+                    CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                    m as i32, n as i32, k as i32,
+                    &alpha as *const [f64; 2],
+                    a.as_ptr() as *const [f64; 2], lda as i32,
+                    b.as_ptr() as *const [f64; 2], ldb as i32,
+                    &beta as *const [f64; 2],
+                    c_ref.as_mut_ptr() as *mut [f64; 2], ldc as i32,
+                );
+            }
+
+            assert_normwise_close(&c, &c_ref, 1e-12 * (k as f64).max(1.0).sqrt());
+        }
+    }
+}
+
+#[test]
+fn cgemm_3m_small_all_ops() {
+    run_c(1, 1, 1, [1.0, 0.0], [0.0, 0.0]);
+    run_c(7, 5, 3, [0.5, -0.25], [1.0, 0.0]);
+    run_c(13, 17, 11, [0.75, 0.5], [-0.25, 0.5]);
+}
+
+#[test]
+fn cgemm_3m_large_all_ops() {
+    run_c(131, 97, 300, [1.0, 0.25], [0.5, 0.0]);
+}
+
+#[test]
+fn cgemm_3m_alpha_zero() {
+    run_c(9, 4, 6, [0.0, 0.0], [0.5, -0.5]);
+    run_c(9, 4, 0, [1.0, 0.0], [0.5, -0.5]);
+}
+
+#[test]
+fn zgemm_3m_small_all_ops() {
+    run_z(1, 1, 1, [1.0, 0.0], [0.0, 0.0]);
+    run_z(7, 5, 3, [0.5, -0.25], [1.0, 0.0]);
+    run_z(13, 17, 11, [0.75, 0.5], [-0.25, 0.5]);
+}
+
+#[test]
+fn zgemm_3m_large_all_ops() {
+    run_z(131, 97, 300, [1.0, 0.25], [0.5, 0.0]);
+}
+
+#[test]
+fn zgemm_3m_alpha_zero() {
+    run_z(9, 4, 6, [0.0, 0.0], [0.5, -0.5]);
+}
@@ -0,0 +1,317 @@
+use crate::cblas::{
+    cblas_sgemm, cblas_dgemm, cblas_cgemm, cblas_zgemm,
+    CBLAS_LAYOUT, CBLAS_TRANSPOSE,
+};
+
+use coral_aarch64::enums::{CoralTranspose, CoralTriangular};
+use coral_aarch64::level3::{sgemmt, dgemmt, cgemmt, zgemmt};
+
+#[inline(always)]
+fn to_cblas(op: CoralTranspose) -> CBLAS_TRANSPOSE {
+    match op {
+        CoralTranspose::NoTranspose        => CBLAS_TRANSPOSE::CblasNoTrans,
+        CoralTranspose::Transpose          => CBLAS_TRANSPOSE::CblasTrans,
+        CoralTranspose::ConjugateTranspose => CBLAS_TRANSPOSE::CblasConjTrans,
+    }
+}
+
+#[inline(always)]
+fn dims(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (rows, cols),
+        _                           => (cols, rows),
+    }
+}
+
+// `w` scalars per element; 1 for real, 2 for complex
+fn make_matrix_colmajor(
+    rows : usize,
+    cols : usize,
+    ld   : usize,
+    w    : usize,
+    f    : impl Fn(usize, usize, usize) -> f64,
+) -> Vec<f64> {
+    let mut a = vec![0.0; w * ld * cols];
+
+    for j in 0..cols {
+        for i in 0..rows {
+            for p in 0..w {
+                a[w * (i + j * ld) + p] = f(i, j, p);
+            }
+        }
+    }
+    a
+}
+
+#[inline(always)]
+fn in_triangle(uplo: CoralTriangular, i: usize, j: usize) -> bool {
+    match uplo {
+        CoralTriangular::UpperTriangular => i <= j,
+        CoralTriangular::LowerTriangular => i >= j,
+    }
+}
+
+// triangle of `coral` matches `full`; the rest still matches `init`
+fn assert_triangle(
+    uplo  : CoralTriangular,
+    n     : usize,
+    ldc   : usize,
+    w     : usize,
+    coral : &[f64],
+    full  : &[f64],
+    init  : &[f64],
+    tol   : f64,
+) {
+    for j in 0..n {
+        for i in 0..n {
+            for p in 0..w {
+                let idx = w * (i + j * ldc) + p;
+
+                if in_triangle(uplo, i, j) {
+                    let (x, y) = (coral[idx], full[idx]);
+                    let diff = (x - y).abs();
+
+                    assert!(
+                        diff <= tol * (1.0 + x.abs().max(y.abs())),
+                        "mismatch at ({i}, {j}): coral={x:.8e} vs ref={y:.8e}"
+                    );
+                } else {
+                    assert_eq!(coral[idx], init[idx], "wrote outside triangle at ({i}, {j})");
+                }
+            }
+        }
+    }
+}
+
+const REAL_OPS: [CoralTranspose; 2] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+];
+
+const COMPLEX_OPS: [CoralTranspose; 3] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+    CoralTranspose::ConjugateTranspose,
+];
+
+const UPLOS: [CoralTriangular; 2] = [
+    CoralTriangular::UpperTriangular,
+    CoralTriangular::LowerTriangular,
+];
+
+fn f_a(i: usize, j: usize, p: usize) -> f64 {
+    0.1 + (i as f64) * 0.025 - (j as f64) * 0.0125 + (p as f64) * 0.03
+}
+
+fn f_b(i: usize, j: usize, p: usize) -> f64 {
+    -0.2 + (i as f64) * 0.005 + (j as f64) * 0.0075 - (p as f64) * 0.02
+}
+
+fn f_c(i: usize, j: usize, p: usize) -> f64 {
+    0.3 - (i as f64) * 0.01 + (j as f64) * 0.02 + (p as f64) * 0.1
+}
+
+fn run_s(n: usize, k: usize, alpha: f32, beta: f32) {
+    for &uplo in &UPLOS {
+        for &op_a in &REAL_OPS {
+            for &op_b in &REAL_OPS {
+                let (ar, ac) = dims(op_a, n, k);
+                let (br, bc) = dims(op_b, k, n);
+                let (lda, ldb, ldc) = (ar + 1, br + 2, n + 3);
+
+                let to32 = |v: Vec<f64>| v.into_iter().map(|x| x as f32).collect::<Vec<f32>>();
+                let a = to32(make_matrix_colmajor(ar, ac, lda, 1, f_a));
+                let b = to32(make_matrix_colmajor(br, bc, ldb, 1, f_b));
+                let init = to32(make_matrix_colmajor(n, n, ldc, 1, f_c));
+
+                let mut c = init.clone();
+                sgemmt(
+                    uplo, op_a, op_b, n, k, alpha,
+                    a.as_ptr(), lda, b.as_ptr(), ldb,
+                    beta, c.as_mut_ptr(), ldc,
+                );
+
+                let mut full = init.clone();
+                unsafe {
+                    cblas_sgemm(
+                        CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                        n as i32, n as i32, k as i32, alpha,
+                        a.as_ptr(), lda as i32, b.as_ptr(), ldb as i32,
+                        beta, full.as_mut_ptr(), ldc as i32,
+                    );
+                }
+
+                let up = |v: &[f32]| v.iter().map(|&x| x as f64).collect::<Vec<f64>>();
+                assert_triangle(uplo, n, ldc, 1, &up(&c), &up(&full), &up(&init), 1e-3);
+            }
+        }
+    }
+}
+
+fn run_d(n: usize, k: usize, alpha: f64, beta: f64) {
+    for &uplo in &UPLOS {
+        for &op_a in &REAL_OPS {
+            for &op_b in &REAL_OPS {
+                let (ar, ac) = dims(op_a, n, k);
+                let (br, bc) = dims(op_b, k, n);
+                let (lda, ldb, ldc) = (ar + 1, br + 2, n + 3);
+
+                let a = make_matrix_colmajor(ar, ac, lda, 1, f_a);
+                let b = make_matrix_colmajor(br, bc, ldb, 1, f_b);
+                let init = make_matrix_colmajor(n, n, ldc, 1, f_c);
+
+                let mut c = init.clone();
+                dgemmt(
+                    uplo, op_a, op_b, n, k, alpha,
+                    a.as_ptr(), lda, b.as_ptr(), ldb,
+                    beta, c.as_mut_ptr(), ldc,
+                );
+
+                let mut full = init.clone();
+                unsafe {
+                    cblas_dgemm(
+                        CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                        n as i32, n as i32, k as i32, alpha,
+                        a.as_ptr(), lda as i32, b.as_ptr(), ldb as i32,
+                        beta, full.as_mut_ptr(), ldc as i32,
+                    );
+                }
+
+                assert_triangle(uplo, n, ldc, 1, &c, &full, &init, 1e-10);
+            }
+        }
+    }
+}
+
+fn run_c(n: usize, k: usize, alpha: [f32; 2], beta: [f32; 2]) {
+    for &uplo in &UPLOS {
+        for &op_a in &COMPLEX_OPS {
+            for &op_b in &COMPLEX_OPS {
+                let (ar, ac) = dims(op_a, n, k);
+                let (br, bc) = dims(op_b, k, n);
+                let (lda, ldb, ldc) = (ar + 1, br + 2, n + 3);
+
+                let to32 = |v: Vec<f64>| v.into_iter().map(|x| x as f32).collect::<Vec<f32>>();
+                let a = to32(make_matrix_colmajor(ar, ac, lda, 2, f_a));
+                let b = to32(make_matrix_colmajor(br, bc, ldb, 2, f_b));
+                let init = to32(make_matrix_colmajor(n, n, ldc, 2, f_c));
+
+                let mut c = init.clone();
+                cgemmt(
+                    uplo, op_a, op_b, n, k, alpha,
+                    a.as_ptr(), lda, b.as_ptr(), ldb,
+                    beta, c.as_mut_ptr(), ldc,
+                );
+
+                let mut full = init.clone();
+                unsafe {
+                    cblas_cgemm(
+                        CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                        n as i32, n as i32, k as i32,
+                        &alpha as *const [f32; 2],
+                        a.as_ptr() as *const [f32; 2], lda as i32,
+                        b.as_ptr() as *const [f32; 2], ldb as i32,
+                        &beta as *const [f32; 2],
+                        full.as_mut_ptr() as *mut [f32; 2], ldc as i32,
+                    );
+                }
+
+                let up = |v: &[f32]| v.iter().map(|&x| x as f64).collect::<Vec<f64>>();
+                assert_triangle(uplo, n, ldc, 2, &up(&c), &up(&full), &up(&init), 3e-3);
+            }
+        }
+    }
+}
+
+fn run_z(n: usize, k: usize, alpha: [f64; 2], beta: [f64; 2]) {
+    for &uplo in &UPLOS {
+        for &op_a in &COMPLEX_OPS {
+            for &op_b in &COMPLEX_OPS {
+                let (ar, ac) = dims(op_a, n, k);
+                let (br, bc) = dims(op_b, k, n);
+                let (lda, ldb, ldc) = (ar + 1, br + 2, n + 3);
+
+                let a = make_matrix_colmajor(ar, ac, lda, 2, f_a);
+                let b = make_matrix_colmajor(br, bc, ldb, 2, f_b);
+                let init = make_matrix_colmajor(n, n, ldc, 2, f_c);
+
+                let mut c = init.clone();
+                zgemmt(
+                    uplo, op_a, op_b, n, k, alpha,
+                    a.as_ptr(), lda, b.as_ptr(), ldb,
+                    beta, c.as_mut_ptr(), ldc,
+                );
+
+                let mut full = init.clone();
+                unsafe {
+                    cblas_zgemm(
+                        CBLAS_LAYOUT::CblasColMajor, to_cblas(op_a), to_cblas(op_b),
+                        n as i32, n as i32, k as i32,
+                        &alpha as *const [f64; 2],
+                        a.as_ptr() as *const [f64; 2], lda as i32,
+                        b.as_ptr() as *const [f64; 2], ldb as i32,
+                        &beta as *const [f64; 2],
+                        full.as_mut_ptr() as *mut [f64; 2], ldc as i32,
+                    );
+                }
+
+                assert_triangle(uplo, n, ldc, 2, &c, &full, &init, 1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn sgemmt_small() {
+    run_s(1, 1, 1.0, 0.0);
+    run_s(13, 7, 0.5, 1.0);
+    run_s(29, 19, 0.75, -0.25);
+}
+
+#[test]
+fn sgemmt_multi_block() {
+    // crosses MC, NC and KC boundaries
+    run_s(601, 300, 1.0, 0.5);
+}
+
+#[test]
+fn sgemmt_alpha_zero() {
+    run_s(17, 5, 0.0, 0.5);
+    run_s(17, 0, 1.0, 0.0);
+}
+
+#[test]
+fn dgemmt_small() {
+    run_d(1, 1, 1.0, 0.0);
+    run_d(13, 7, 0.5, 1.0);
+    run_d(29, 19, 0.75, -0.25);
+}
+
+#[test]
+fn dgemmt_multi_block() {
+    run_d(401, 300, 1.0, 0.5);
+}
+
+#[test]
+fn cgemmt_small() {
+    run_c(1, 1, [1.0, 0.0], [0.0, 0.0]);
+    run_c(11, 7, [0.5, -0.25], [1.0, 0.0]);
+    run_c(21, 9, [0.75, 0.5], [-0.25, 0.5]);
+}
+
+#[test]
+fn cgemmt_multi_block() {
+    run_c(397, 270, [1.0, 0.25], [0.5, 0.0]);
+}
+
+#[test]
+fn zgemmt_small() {
+    run_z(1, 1, [1.0, 0.0], [0.0, 0.0]);
+    run_z(11, 7, [0.5, -0.25], [1.0, 0.0]);
+    run_z(21, 9, [0.75, 0.5], [-0.25, 0.5]);
+}
+
+#[test]
+fn zgemmt_alpha_zero() {
+    run_z(9, 4, [0.0, 0.0], [0.5, -0.5]);
+}
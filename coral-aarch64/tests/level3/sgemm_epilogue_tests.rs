@@ -0,0 +1,249 @@
+use crate::cblas::{cblas_sgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level3::sgemm_epilogue;
+use coral_aarch64::level3::epilogue::{Epilogue, Bias, Activation};
+
+#[inline(always)]
+fn to_cblas(op: CoralTranspose) -> CBLAS_TRANSPOSE {
+    match op {
+        CoralTranspose::NoTranspose        => CBLAS_TRANSPOSE::CblasNoTrans,
+        CoralTranspose::Transpose          => CBLAS_TRANSPOSE::CblasTrans,
+        CoralTranspose::ConjugateTranspose => CBLAS_TRANSPOSE::CblasTrans,
+    }
+}
+
+fn make_matrix_colmajor(
+    rows : usize,
+    cols : usize,
+    ld   : usize,
+    f    : impl Fn(usize, usize) -> f32,
+) -> Vec<f32> {
+    let mut a = vec![0.0; ld * cols];
+
+    for j in 0..cols {
+        for i in 0..rows {
+            a[i + j * ld] = f(i, j);
+        }
+    }
+    a
+}
+
+fn assert_allclose(
+    a    : &[f32],
+    b    : &[f32],
+    rtol : f32,
+    atol : f32
+) {
+    assert_eq!(a.len(), b.len());
+
+    for (idx, (&x, &y)) in a.iter().zip(b.iter()).enumerate() {
+        let diff = (x - y).abs();
+        let tol = atol + rtol * x.abs().max(y.abs());
+
+        assert!(
+            diff <= tol,
+            "mismatch at {idx}: coral={x:.8e} vs ref={y:.8e} delta={diff:.3e} tol={tol:.3e}"
+        );
+    }
+}
+
+const RTOL: f32 = 1e-3;
+const ATOL: f32 = 1e-3;
+
+// reference epilogue; separate pass over C
+fn epilogue_ref(
+    ep  : &Epilogue<'_>,
+    m   : usize,
+    n   : usize,
+    c   : &mut [f32],
+    ldc : usize,
+) {
+    for j in 0..n {
+        for i in 0..m {
+            let mut v = c[i + j * ldc];
+
+            v += match ep.bias {
+                Bias::None    => 0.0,
+                Bias::Row(bv) => bv[i],
+                Bias::Col(bv) => bv[j],
+            };
+
+            v = match ep.activation {
+                Activation::None => v,
+                Activation::Relu => v.max(0.0),
+                Activation::Gelu => {
+                    let t = (0.797_884_6 * (v + 0.044_715 * v * v * v)).tanh();
+                    0.5 * v * (1.0 + t)
+                }
+            };
+
+            if let Some((lo, hi)) = ep.clamp {
+                v = v.clamp(lo, hi);
+            }
+
+            if let Some(f) = ep.map {
+                v = f(i, j, v);
+            }
+
+            c[i + j * ldc] = v;
+        }
+    }
+}
+
+fn run_case(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    beta  : f32,
+    ep    : &Epilogue<'_>,
+) {
+    let (a_rows, a_cols) = match op_a {
+        CoralTranspose::NoTranspose => (m, k),
+        _                           => (k, m),
+    };
+    let (b_rows, b_cols) = match op_b {
+        CoralTranspose::NoTranspose => (k, n),
+        _                           => (n, k),
+    };
+    let lda = a_rows + 1;
+    let ldb = b_rows + 2;
+    let ldc = m + 3;
+
+    let a = make_matrix_colmajor(a_rows, a_cols, lda, |i, j| 0.1 + (i as f32) * 0.025 - (j as f32) * 0.0125);
+    let b = make_matrix_colmajor(b_rows, b_cols, ldb, |i, j| -0.2 + (i as f32) * 0.005 + (j as f32) * 0.0075);
+    let c_init = make_matrix_colmajor(m, n, ldc, |i, j| 0.3 - (i as f32) * 0.01 + (j as f32) * 0.02);
+
+    let mut c_coral = c_init.clone();
+    sgemm_epilogue(
+        op_a, op_b,
+        m, n, k,
+        alpha,
+        a.as_ptr(), lda,
+        b.as_ptr(), ldb,
+        beta,
+        c_coral.as_mut_ptr(), ldc,
+        ep,
+    );
+
+    let mut c_ref = c_init.clone();
+    unsafe {
+        cblas_sgemm(
+            CBLAS_LAYOUT::CblasColMajor,
+            to_cblas(op_a),
+            to_cblas(op_b),
+            m as i32, n as i32, k as i32,
+            alpha,
+            a.as_ptr(), lda as i32,
+            b.as_ptr(), ldb as i32,
+            beta,
+            c_ref.as_mut_ptr(), ldc as i32,
+        );
+    }
+    epilogue_ref(ep, m, n, &mut c_ref, ldc);
+
+    assert_allclose(&c_coral, &c_ref, RTOL, ATOL);
+}
+
+fn run_all_ops(
+    m  : usize,
+    n  : usize,
+    k  : usize,
+    ep : &Epilogue<'_>,
+) {
+    let ops = [
+        (CoralTranspose::NoTranspose, CoralTranspose::NoTranspose),
+        (CoralTranspose::NoTranspose, CoralTranspose::Transpose),
+        (CoralTranspose::Transpose,   CoralTranspose::NoTranspose),
+        (CoralTranspose::Transpose,   CoralTranspose::Transpose),
+    ];
+
+    for &(alpha, beta) in &[(1.0f32, 0.0f32), (0.5, 1.0), (0.75, -0.25)] {
+        for &(op_a, op_b) in &ops {
+            run_case(op_a, op_b, m, n, k, alpha, beta, ep);
+        }
+    }
+}
+
+#[test]
+fn row_bias_relu() {
+    let (m, n, k) = (37, 29, 19);
+    let bias: Vec<f32> = (0..m).map(|i| -0.5 + (i as f32) * 0.03).collect();
+    let ep = Epilogue {
+        bias       : Bias::Row(&bias),
+        activation : Activation::Relu,
+        ..Default::default()
+    };
+
+    run_all_ops(m, n, k, &ep);
+}
+
+#[test]
+fn col_bias_gelu() {
+    let (m, n, k) = (24, 36, 17);
+    let bias: Vec<f32> = (0..n).map(|j| 0.25 - (j as f32) * 0.02).collect();
+    let ep = Epilogue {
+        bias       : Bias::Col(&bias),
+        activation : Activation::Gelu,
+        ..Default::default()
+    };
+
+    run_all_ops(m, n, k, &ep);
+}
+
+#[test]
+fn clamp_only() {
+    let ep = Epilogue {
+        clamp : Some((-0.05, 0.05)),
+        ..Default::default()
+    };
+
+    run_all_ops(13, 14, 11, &ep);
+}
+
+#[test]
+fn custom_map_sees_global_indices() {
+    let f = |i: usize, j: usize, x: f32| x * 2.0 + (i as f32) - 0.5 * (j as f32);
+    let ep = Epilogue {
+        map : Some(&f),
+        ..Default::default()
+    };
+
+    run_all_ops(45, 31, 9, &ep);
+}
+
+#[test]
+fn all_stages_across_k_panels() {
+    // k > KC; the epilogue must only run after the last panel
+    let (m, n, k) = (67, 53, 300);
+    let bias: Vec<f32> = (0..m).map(|i| (i as f32) * 0.01 - 0.3).collect();
+    let f = |_i: usize, j: usize, x: f32| if j % 2 == 0 { x } else { -x };
+    let ep = Epilogue {
+        bias       : Bias::Row(&bias),
+        activation : Activation::Relu,
+        clamp      : Some((0.0, 1.5)),
+        map        : Some(&f),
+    };
+
+    run_all_ops(m, n, k, &ep);
+}
+
+#[test]
+fn alpha_zero_still_applies() {
+    let bias: Vec<f32> = (0..10).map(|j| j as f32).collect();
+    let ep = Epilogue {
+        bias : Bias::Col(&bias),
+        ..Default::default()
+    };
+
+    run_case(
+        CoralTranspose::NoTranspose,
+        CoralTranspose::NoTranspose,
+        9, 10, 4,
+        0.0, 0.5,
+        &ep,
+    );
+}
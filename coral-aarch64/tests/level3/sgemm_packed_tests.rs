@@ -0,0 +1,231 @@
+use crate::cblas::{cblas_sgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level3::{sgemm, sgemm_packed_a, sgemm_packed_b};
+use coral_aarch64::level3::packed::{Blocking, PackedA, PackedB};
+
+#[inline(always)]
+fn to_cblas(op: CoralTranspose) -> CBLAS_TRANSPOSE {
+    match op {
+        CoralTranspose::NoTranspose        => CBLAS_TRANSPOSE::CblasNoTrans,
+        CoralTranspose::Transpose          => CBLAS_TRANSPOSE::CblasTrans,
+        CoralTranspose::ConjugateTranspose => CBLAS_TRANSPOSE::CblasTrans,
+    }
+}
+
+fn make_matrix_colmajor(
+    rows : usize,
+    cols : usize,
+    ld   : usize,
+    f    : impl Fn(usize, usize) -> f32,
+) -> Vec<f32> {
+    let mut a = vec![0.0; ld * cols];
+
+    for j in 0..cols {
+        for i in 0..rows {
+            a[i + j * ld] = f(i, j);
+        }
+    }
+    a
+}
+
+fn assert_allclose(
+    a    : &[f32],
+    b    : &[f32],
+    rtol : f32,
+    atol : f32
+) {
+    assert_eq!(a.len(), b.len());
+
+    for (idx, (&x, &y)) in a.iter().zip(b.iter()).enumerate() {
+        let diff = (x - y).abs();
+        let tol = atol + rtol * x.abs().max(y.abs());
+
+        assert!(
+            diff <= tol,
+            "mismatch at {idx}: coral={x:.8e} vs ref={y:.8e} delta={diff:.3e} tol={tol:.3e}"
+        );
+    }
+}
+
+const RTOL: f32 = 1e-3;
+const ATOL: f32 = 1e-3;
+
+struct Case {
+    a      : Vec<f32>,
+    lda    : usize,
+    b      : Vec<f32>,
+    ldb    : usize,
+    c_init : Vec<f32>,
+    ldc    : usize,
+}
+
+fn make_case(
+    op_a : CoralTranspose,
+    op_b : CoralTranspose,
+    m    : usize,
+    n    : usize,
+    k    : usize,
+) -> Case {
+    let (a_rows, a_cols) = match op_a {
+        CoralTranspose::NoTranspose => (m, k),
+        _                           => (k, m),
+    };
+    let (b_rows, b_cols) = match op_b {
+        CoralTranspose::NoTranspose => (k, n),
+        _                           => (n, k),
+    };
+    let lda = a_rows + 1;
+    let ldb = b_rows + 2;
+    let ldc = m + 3;
+
+    Case {
+        a      : make_matrix_colmajor(a_rows, a_cols, lda, |i, j| 0.1 + (i as f32) * 0.025 - (j as f32) * 0.0125),
+        lda,
+        b      : make_matrix_colmajor(b_rows, b_cols, ldb, |i, j| -0.2 + (i as f32) * 0.005 + (j as f32) * 0.0075),
+        ldb,
+        c_init : make_matrix_colmajor(m, n, ldc, |i, j| 0.3 - (i as f32) * 0.01 + (j as f32) * 0.02),
+        ldc,
+    }
+}
+
+fn reference(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    beta  : f32,
+    t     : &Case,
+) -> Vec<f32> {
+    let mut c_ref = t.c_init.clone();
+    unsafe {
+        cblas_sgemm(
+            CBLAS_LAYOUT::CblasColMajor,
+            to_cblas(op_a),
+            to_cblas(op_b),
+            m as i32, n as i32, k as i32,
+            alpha,
+            t.a.as_ptr(), t.lda as i32,
+            t.b.as_ptr(), t.ldb as i32,
+            beta,
+            c_ref.as_mut_ptr(), t.ldc as i32,
+        );
+    }
+    c_ref
+}
+
+fn unpacked(
+    op_a  : CoralTranspose,
+    op_b  : CoralTranspose,
+    m     : usize,
+    n     : usize,
+    k     : usize,
+    alpha : f32,
+    beta  : f32,
+    t     : &Case,
+) -> Vec<f32> {
+    let mut c = t.c_init.clone();
+    sgemm(
+        op_a, op_b,
+        m, n, k,
+        alpha,
+        t.a.as_ptr(), t.lda,
+        t.b.as_ptr(), t.ldb,
+        beta,
+        c.as_mut_ptr(), t.ldc,
+    );
+    c
+}
+
+const OPS: [(CoralTranspose, CoralTranspose); 4] = [
+    (CoralTranspose::NoTranspose, CoralTranspose::NoTranspose),
+    (CoralTranspose::NoTranspose, CoralTranspose::Transpose),
+    (CoralTranspose::Transpose,   CoralTranspose::NoTranspose),
+    (CoralTranspose::Transpose,   CoralTranspose::Transpose),
+];
+
+fn run_packed_b(m: usize, n: usize, k: usize) {
+    for &(alpha, beta) in &[(1.0f32, 0.0f32), (0.5, 1.0), (0.75, -0.25)] {
+        for &(op_a, op_b) in &OPS {
+            let t = make_case(op_a, op_b, m, n, k);
+            let pb = PackedB::new(op_b, k, n, t.b.as_ptr(), t.ldb);
+
+            // reuse the packed operand
+            for _ in 0..2 {
+                let mut c = t.c_init.clone();
+                sgemm_packed_b(
+                    op_a,
+                    m,
+                    alpha,
+                    t.a.as_ptr(), t.lda,
+                    &pb,
+                    beta,
+                    c.as_mut_ptr(), t.ldc,
+                );
+
+                assert_eq!(c, unpacked(op_a, op_b, m, n, k, alpha, beta, &t));
+                assert_allclose(&c, &reference(op_a, op_b, m, n, k, alpha, beta, &t), RTOL, ATOL);
+            }
+        }
+    }
+}
+
+fn run_packed_a(m: usize, n: usize, k: usize) {
+    for &(alpha, beta) in &[(1.0f32, 0.0f32), (0.5, 1.0), (0.75, -0.25)] {
+        for &(op_a, op_b) in &OPS {
+            let t = make_case(op_a, op_b, m, n, k);
+            let pa = PackedA::new(op_a, m, k, t.a.as_ptr(), t.lda);
+
+            for _ in 0..2 {
+                let mut c = t.c_init.clone();
+                sgemm_packed_a(
+                    op_b,
+                    n,
+                    alpha,
+                    &pa,
+                    t.b.as_ptr(), t.ldb,
+                    beta,
+                    c.as_mut_ptr(), t.ldc,
+                );
+
+                assert_eq!(c, unpacked(op_a, op_b, m, n, k, alpha, beta, &t));
+                assert_allclose(&c, &reference(op_a, op_b, m, n, k, alpha, beta, &t), RTOL, ATOL);
+            }
+        }
+    }
+}
+
+#[test]
+fn packed_b_small() {
+    run_packed_b(13, 17, 11);
+}
+
+#[test]
+fn packed_b_multi_block() {
+    // crosses MC, NC and KC boundaries
+    run_packed_b(397, 589, 300);
+}
+
+#[test]
+fn packed_a_small() {
+    run_packed_a(13, 17, 11);
+}
+
+#[test]
+fn packed_a_multi_block() {
+    run_packed_a(397, 589, 300);
+}
+
+#[test]
+fn packed_records_blocking() {
+    let b = vec![0.0f32; 6];
+    let pb = PackedB::new(CoralTranspose::NoTranspose, 2, 3, b.as_ptr(), 2);
+    let pa = PackedA::new(CoralTranspose::Transpose, 3, 2, b.as_ptr(), 2);
+
+    assert_eq!(pb.blocking(), Blocking::SGEMM);
+    assert_eq!(pa.blocking(), Blocking::SGEMM);
+    assert_eq!((pb.k(), pb.n()), (2, 3));
+    assert_eq!((pa.m(), pa.k()), (3, 2));
+}
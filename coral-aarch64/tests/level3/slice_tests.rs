@@ -0,0 +1,113 @@
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level3::{self, slice};
+
+const OPS: [CoralTranspose; 3] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+    CoralTranspose::ConjugateTranspose,
+];
+
+#[inline(always)]
+fn dims(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (rows, cols),
+        _                           => (cols, rows),
+    }
+}
+
+// exactly the scalars a column-major rows x cols matrix spans, w per entry
+fn make_matrix(rows: usize, cols: usize, ld: usize, w: usize, seed: usize) -> Vec<f64> {
+    let len = w * ((cols - 1) * ld + rows);
+
+    (0..len)
+        .map(|i| (((i * 37 + seed * 11) % 23) as f64 - 11.0) / 7.0)
+        .collect()
+}
+
+#[test]
+fn real_matches_raw_pointer_gemm() {
+    let (m, n, k) = (37, 29, 41);
+
+    for &op_a in &OPS[..2] {
+        for &op_b in &OPS[..2] {
+            let (ar, ac) = dims(op_a, m, k);
+            let (br, bc) = dims(op_b, k, n);
+            let (lda, ldb, ldc) = (ar + 3, br + 1, m + 2);
+
+            let a = make_matrix(ar, ac, lda, 1, 1);
+            let b = make_matrix(br, bc, ldb, 1, 2);
+            let c = make_matrix(m, n, ldc, 1, 3);
+
+            let mut c_raw   = c.clone();
+            let mut c_slice = c.clone();
+
+            level3::dgemm(
+                op_a, op_b, m, n, k, 1.5,
+                a.as_ptr(), lda, b.as_ptr(), ldb, -0.5, c_raw.as_mut_ptr(), ldc,
+            );
+            slice::dgemm(op_a, op_b, m, n, k, 1.5, &a, lda, &b, ldb, -0.5, &mut c_slice, ldc);
+            assert_eq!(c_raw, c_slice);
+
+            let a: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+            let b: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+            let c: Vec<f32> = c.iter().map(|&x| x as f32).collect();
+
+            let mut c_raw   = c.clone();
+            let mut c_slice = c.clone();
+
+            level3::sgemm(
+                op_a, op_b, m, n, k, 1.5,
+                a.as_ptr(), lda, b.as_ptr(), ldb, -0.5, c_raw.as_mut_ptr(), ldc,
+            );
+            slice::sgemm(op_a, op_b, m, n, k, 1.5, &a, lda, &b, ldb, -0.5, &mut c_slice, ldc);
+            assert_eq!(c_raw, c_slice);
+        }
+    }
+}
+
+#[test]
+fn complex_matches_raw_pointer_gemm() {
+    let (m, n, k) = (19, 23, 17);
+
+    for &op_a in &OPS {
+        for &op_b in &OPS {
+            let (ar, ac) = dims(op_a, m, k);
+            let (br, bc) = dims(op_b, k, n);
+            let (lda, ldb, ldc) = (ar + 2, br, m + 1);
+
+            let a = make_matrix(ar, ac, lda, 2, 4);
+            let b = make_matrix(br, bc, ldb, 2, 5);
+            let c = make_matrix(m, n, ldc, 2, 6);
+
+            let alpha = [0.75, -0.25];
+            let beta  = [0.5, 1.0];
+
+            let mut c_raw   = c.clone();
+            let mut c_slice = c.clone();
+
+            level3::zgemm(
+                op_a, op_b, m, n, k, alpha,
+                a.as_ptr(), lda, b.as_ptr(), ldb, beta, c_raw.as_mut_ptr(), ldc,
+            );
+            slice::zgemm(op_a, op_b, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c_slice, ldc);
+            assert_eq!(c_raw, c_slice);
+
+            let a: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+            let b: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+            let c: Vec<f32> = c.iter().map(|&x| x as f32).collect();
+
+            let alpha = [0.75, -0.25];
+            let beta  = [0.5, 1.0];
+
+            let mut c_raw   = c.clone();
+            let mut c_slice = c.clone();
+
+            level3::cgemm(
+                op_a, op_b, m, n, k, alpha,
+                a.as_ptr(), lda, b.as_ptr(), ldb, beta, c_raw.as_mut_ptr(), ldc,
+            );
+            slice::cgemm(op_a, op_b, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c_slice, ldc);
+            assert_eq!(c_raw, c_slice);
+        }
+    }
+}
@@ -1,5 +1,4 @@
-use blas_src as _;
-use cblas_sys::{cblas_zgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use crate::cblas::{cblas_zgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
 
 use coral_aarch64::enums::CoralTranspose;
 use coral_aarch64::level3::zgemm;
@@ -0,0 +1,13 @@
+// coral against the reference module; needs no external BLAS
+
+// level 1
+#[path = "reference/level1_tests.rs"]
+mod level1_tests;
+
+// level 2
+#[path = "reference/level2_tests.rs"]
+mod level2_tests;
+
+// level 3
+#[path = "reference/level3_tests.rs"]
+mod level3_tests;
@@ -0,0 +1,322 @@
+use coral_aarch64::level1 as opt;
+use coral_aarch64::reference::level1 as naive;
+
+const NS: [usize; 4] = [0, 1, 7, 45];
+const INCS: [(isize, isize); 9] = [
+    (1, 1), (2, 3), (-1, 1), (1, -2), (-3, -1),
+    // a zero increment repeats one entry; updates to it land in order
+    (0, 1), (2, 0), (-1, 0), (0, 0),
+];
+
+fn make_f32(len: usize, seed: usize) -> Vec<f32> {
+    (0..len).map(|i| (((i * 29 + seed * 13) % 31) as f32 - 15.0) / 8.0).collect()
+}
+
+fn make_f64(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|i| (((i * 29 + seed * 13) % 31) as f64 - 15.0) / 8.0).collect()
+}
+
+// scalars spanned by n entries at stride |inc|, w per entry
+fn span(n: usize, inc: isize, w: usize) -> usize {
+    if n == 0 { 0 } else { w * ((n - 1) * inc.unsigned_abs() + 1) }
+}
+
+fn assert_close_f32(a: &[f32], b: &[f32], n: usize) {
+    let tol = 1e-5 * (n as f32 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+fn assert_close_f64(a: &[f64], b: &[f64], n: usize) {
+    let tol = 1e-13 * (n as f64 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+#[test]
+fn reductions() {
+    for &n in &NS {
+        for &inc in &[1isize, 3] {
+            let x = make_f32(span(n, inc, 2), 1);
+            let z = make_f64(span(n, inc, 2), 2);
+
+            assert_close_f32(&[opt::sasum(n, &x, inc)], &[naive::sasum(n, &x, inc)], n);
+            assert_close_f32(&[opt::scasum(n, &x, inc)], &[naive::scasum(n, &x, inc)], n);
+            assert_close_f64(&[opt::dasum(n, &z, inc)], &[naive::dasum(n, &z, inc)], n);
+            assert_close_f64(&[opt::dzasum(n, &z, inc)], &[naive::dzasum(n, &z, inc)], n);
+
+            assert_close_f32(&[opt::snrm2(n, &x, inc)], &[naive::snrm2(n, &x, inc)], n);
+            assert_close_f32(&[opt::scnrm2(n, &x, inc)], &[naive::scnrm2(n, &x, inc)], n);
+            assert_close_f64(&[opt::dnrm2(n, &z, inc)], &[naive::dnrm2(n, &z, inc)], n);
+            assert_close_f64(&[opt::dznrm2(n, &z, inc)], &[naive::dznrm2(n, &z, inc)], n);
+
+            assert_eq!(opt::isamax(n, &x, inc), naive::isamax(n, &x, inc));
+            assert_eq!(opt::icamax(n, &x, inc), naive::icamax(n, &x, inc));
+            assert_eq!(opt::idamax(n, &z, inc), naive::idamax(n, &z, inc));
+            assert_eq!(opt::izamax(n, &z, inc), naive::izamax(n, &z, inc));
+        }
+    }
+}
+
+#[test]
+fn scaling() {
+    for &n in &NS {
+        for &inc in &[1isize, 2] {
+            let x = make_f32(span(n, inc, 2), 3);
+            let z = make_f64(span(n, inc, 2), 4);
+
+            let (mut a, mut b) = (x.clone(), x.clone());
+            opt::sscal(n, -1.5, &mut a, inc);
+            naive::sscal(n, -1.5, &mut b, inc);
+            assert_close_f32(&a, &b, 1);
+
+            let (mut a, mut b) = (x.clone(), x.clone());
+            opt::cscal(n, [0.5, -2.0], &mut a, inc);
+            naive::cscal(n, [0.5, -2.0], &mut b, inc);
+            assert_close_f32(&a, &b, 1);
+
+            let (mut a, mut b) = (x.clone(), x.clone());
+            opt::csscal(n, 3.0, &mut a, inc);
+            naive::csscal(n, 3.0, &mut b, inc);
+            assert_close_f32(&a, &b, 1);
+
+            let (mut a, mut b) = (z.clone(), z.clone());
+            opt::dscal(n, 0.25, &mut a, inc);
+            naive::dscal(n, 0.25, &mut b, inc);
+            assert_close_f64(&a, &b, 1);
+
+            let (mut a, mut b) = (z.clone(), z.clone());
+            opt::zscal(n, [-1.0, 0.75], &mut a, inc);
+            naive::zscal(n, [-1.0, 0.75], &mut b, inc);
+            assert_close_f64(&a, &b, 1);
+
+            let (mut a, mut b) = (z.clone(), z.clone());
+            opt::zdscal(n, -4.0, &mut a, inc);
+            naive::zdscal(n, -4.0, &mut b, inc);
+            assert_close_f64(&a, &b, 1);
+        }
+    }
+}
+
+#[test]
+fn two_vector_real() {
+    let param = [-1.0, 0.5, -0.25, 0.75, 1.5];
+
+    for &n in &NS {
+        for &(incx, incy) in &INCS {
+            let x = make_f32(span(n, incx, 1), 5);
+            let y = make_f32(span(n, incy, 1), 6);
+
+            assert_close_f32(
+                &[opt::sdot(n, &x, incx, &y, incy)],
+                &[naive::sdot(n, &x, incx, &y, incy)],
+                n,
+            );
+
+            assert_close_f64(
+                &[opt::dsdot(n, &x, incx, &y, incy)],
+                &[naive::dsdot(n, &x, incx, &y, incy)],
+                n,
+            );
+            assert_close_f32(
+                &[opt::sdsdot(n, 0.5, &x, incx, &y, incy)],
+                &[naive::sdsdot(n, 0.5, &x, incx, &y, incy)],
+                1,
+            );
+
+            let (mut a, mut b) = (y.clone(), y.clone());
+            opt::saxpy(n, 0.75, &x, incx, &mut a, incy);
+            naive::saxpy(n, 0.75, &x, incx, &mut b, incy);
+            assert_close_f32(&a, &b, 1);
+
+            let (mut a, mut b) = (y.clone(), y.clone());
+            opt::scopy(n, &x, incx, &mut a, incy);
+            naive::scopy(n, &x, incx, &mut b, incy);
+            assert_eq!(a, b);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::sswap(n, &mut xa, incx, &mut ya, incy);
+            naive::sswap(n, &mut xb, incx, &mut yb, incy);
+            assert_eq!((xa, ya), (xb, yb));
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::srot(n, &mut xa, incx, &mut ya, incy, 0.6, -0.8);
+            naive::srot(n, &mut xb, incx, &mut yb, incy, 0.6, -0.8);
+            assert_close_f32(&xa, &xb, 1);
+            assert_close_f32(&ya, &yb, 1);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::srotm(n, &mut xa, incx, &mut ya, incy, &param);
+            naive::srotm(n, &mut xb, incx, &mut yb, incy, &param);
+            assert_close_f32(&xa, &xb, 1);
+            assert_close_f32(&ya, &yb, 1);
+
+            let x = make_f64(span(n, incx, 1), 7);
+            let y = make_f64(span(n, incy, 1), 8);
+
+            assert_close_f64(
+                &[opt::ddot(n, &x, incx, &y, incy)],
+                &[naive::ddot(n, &x, incx, &y, incy)],
+                n,
+            );
+
+            let (mut a, mut b) = (y.clone(), y.clone());
+            opt::daxpy(n, -1.25, &x, incx, &mut a, incy);
+            naive::daxpy(n, -1.25, &x, incx, &mut b, incy);
+            assert_close_f64(&a, &b, 1);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::drotm(n, &mut xa, incx, &mut ya, incy, &[0.0, 0.0, -0.5, 2.0, 0.0]);
+            naive::drotm(n, &mut xb, incx, &mut yb, incy, &[0.0, 0.0, -0.5, 2.0, 0.0]);
+            assert_close_f64(&xa, &xb, 1);
+            assert_close_f64(&ya, &yb, 1);
+        }
+    }
+}
+
+#[test]
+fn two_vector_complex() {
+    for &n in &NS {
+        for &(incx, incy) in &INCS {
+            let x = make_f32(span(n, incx, 2), 9);
+            let y = make_f32(span(n, incy, 2), 10);
+
+            assert_close_f32(
+                &opt::cdotu(n, &x, incx, &y, incy),
+                &naive::cdotu(n, &x, incx, &y, incy),
+                n,
+            );
+            assert_close_f32(
+                &opt::cdotc(n, &x, incx, &y, incy),
+                &naive::cdotc(n, &x, incx, &y, incy),
+                n,
+            );
+
+            let (mut a, mut b) = (y.clone(), y.clone());
+            opt::caxpy(n, [0.5, 1.5], &x, incx, &mut a, incy);
+            naive::caxpy(n, [0.5, 1.5], &x, incx, &mut b, incy);
+            assert_close_f32(&a, &b, 1);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::csrot(n, &mut xa, incx, &mut ya, incy, 0.8, 0.6);
+            naive::csrot(n, &mut xb, incx, &mut yb, incy, 0.8, 0.6);
+            assert_close_f32(&xa, &xb, 1);
+            assert_close_f32(&ya, &yb, 1);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::crot(n, &mut xa, incx, &mut ya, incy, 0.6, [0.48, -0.64]);
+            naive::crot(n, &mut xb, incx, &mut yb, incy, 0.6, [0.48, -0.64]);
+            assert_close_f32(&xa, &xb, 1);
+            assert_close_f32(&ya, &yb, 1);
+
+            let x = make_f64(span(n, incx, 2), 11);
+            let y = make_f64(span(n, incy, 2), 12);
+
+            assert_close_f64(
+                &opt::zdotc(n, &x, incx, &y, incy),
+                &naive::zdotc(n, &x, incx, &y, incy),
+                n,
+            );
+
+            let (mut a, mut b) = (y.clone(), y.clone());
+            opt::zcopy(n, &x, incx, &mut a, incy);
+            naive::zcopy(n, &x, incx, &mut b, incy);
+            assert_eq!(a, b);
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::zswap(n, &mut xa, incx, &mut ya, incy);
+            naive::zswap(n, &mut xb, incx, &mut yb, incy);
+            assert_eq!((xa, ya), (xb, yb));
+
+            let (mut xa, mut ya) = (x.clone(), y.clone());
+            let (mut xb, mut yb) = (x.clone(), y.clone());
+            opt::zrot(n, &mut xa, incx, &mut ya, incy, 0.6, [0.48, -0.64]);
+            naive::zrot(n, &mut xb, incx, &mut yb, incy, 0.6, [0.48, -0.64]);
+            assert_close_f64(&xa, &xb, 1);
+            assert_close_f64(&ya, &yb, 1);
+        }
+    }
+}
+
+#[test]
+fn rotation_setup() {
+    for &(a, b) in &[(3.0f32, 4.0f32), (-2.0, 0.5), (0.0, -1.0), (0.0, 0.0)] {
+        let (mut a1, mut b1, mut c1, mut s1) = (a, b, 0.0, 0.0);
+        let (mut a2, mut b2, mut c2, mut s2) = (a, b, 0.0, 0.0);
+        opt::srotg(&mut a1, &mut b1, &mut c1, &mut s1);
+        naive::srotg(&mut a2, &mut b2, &mut c2, &mut s2);
+        assert_close_f32(&[a1, b1, c1, s1], &[a2, b2, c2, s2], 1);
+    }
+
+    for &(a, b) in &[([3.0f64, -1.0], [4.0, 2.0]), ([0.0, 0.0], [0.0, -2.0]), ([1.5, 0.5], [0.0, 0.0])] {
+        let (mut a1, mut c1, mut s1) = (a, 0.0, [0.0; 2]);
+        let (mut a2, mut c2, mut s2) = (a, 0.0, [0.0; 2]);
+        opt::zrotg(&mut a1, b, &mut c1, &mut s1);
+        naive::zrotg(&mut a2, b, &mut c2, &mut s2);
+        assert_close_f64(&[a1[0], a1[1], c1, s1[0], s1[1]], &[a2[0], a2[1], c2, s2[0], s2[1]], 1);
+
+        let (a, b) = ([a[0] as f32, a[1] as f32], [b[0] as f32, b[1] as f32]);
+        let (mut a1, mut c1, mut s1) = (a, 0.0, [0.0; 2]);
+        let (mut a2, mut c2, mut s2) = (a, 0.0, [0.0; 2]);
+        opt::crotg(&mut a1, b, &mut c1, &mut s1);
+        naive::crotg(&mut a2, b, &mut c2, &mut s2);
+        assert_close_f32(&[a1[0], a1[1], c1, s1[0], s1[1]], &[a2[0], a2[1], c2, s2[0], s2[1]], 1);
+    }
+
+    for &(d1, d2, x1, y1) in &[(1.0f64, 2.0, 3.0, 4.0), (2.0, 1.0, 4.0, 1.0), (1e-9, 1.0, 1.0, 1e4)] {
+        let (mut d1a, mut d2a, mut x1a, mut pa) = (d1, d2, x1, [0.0; 5]);
+        let (mut d1b, mut d2b, mut x1b, mut pb) = (d1, d2, x1, [0.0; 5]);
+        opt::drotmg(&mut d1a, &mut d2a, &mut x1a, y1, &mut pa);
+        naive::drotmg(&mut d1b, &mut d2b, &mut x1b, y1, &mut pb);
+        assert_close_f64(&[d1a, d2a, x1a], &[d1b, d2b, x1b], 1);
+        assert_close_f64(&pa, &pb, 1);
+    }
+}
+
+#[test]
+fn compensated() {
+    for &n in &NS {
+        for &(incx, incy) in &INCS {
+            let x = make_f32(span(n, incx, 1), 7);
+            let y = make_f32(span(n, incy, 1), 8);
+            let z = make_f64(span(n, incx, 1), 9);
+            let w = make_f64(span(n, incy, 1), 10);
+
+            assert_close_f32(
+                &[opt::sdot_accurate(n, &x, incx, &y, incy)],
+                &[naive::sdot_accurate(n, &x, incx, &y, incy)],
+                1,
+            );
+            assert_close_f64(
+                &[opt::ddot_accurate(n, &z, incx, &w, incy)],
+                &[naive::ddot_accurate(n, &z, incx, &w, incy)],
+                1,
+            );
+
+            assert_close_f32(&[opt::sasum_accurate(n, &x, incx)], &[naive::sasum_accurate(n, &x, incx)], 1);
+            assert_close_f64(&[opt::dasum_accurate(n, &z, incx)], &[naive::dasum_accurate(n, &z, incx)], 1);
+            assert_close_f32(&[opt::snrm2_accurate(n, &x, incx)], &[naive::snrm2_accurate(n, &x, incx)], 1);
+            assert_close_f64(&[opt::dnrm2_accurate(n, &z, incx)], &[naive::dnrm2_accurate(n, &z, incx)], 1);
+        }
+    }
+
+    // 1e16 + 1 - 1e16 cancels to 0 in plain f64 and is exact here
+    let x = [1.0e16f64, 1.0, -1.0e16];
+    let y = [1.0f64; 3];
+    assert_eq!(naive::ddot(3, &x, 1, &y, 1), 0.0);
+    assert_eq!(naive::ddot_accurate(3, &x, 1, &y, 1), 1.0);
+    assert_eq!(opt::ddot_accurate(3, &x, 1, &y, 1), 1.0);
+
+    let x = [0.0f32, f32::NAN, 0.0];
+    assert!(naive::snrm2_accurate(3, &x, 1).is_nan());
+    assert!(opt::snrm2_accurate(3, &x, 1).is_nan());
+}
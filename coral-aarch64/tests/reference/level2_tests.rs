@@ -0,0 +1,256 @@
+use coral_aarch64::enums::{Accuracy, CoralDiagonal, CoralTranspose, CoralTriangular};
+use coral_aarch64::level2 as opt;
+use coral_aarch64::reference::level2 as naive;
+
+const UPLOS: [CoralTriangular; 2] = [
+    CoralTriangular::UpperTriangular,
+    CoralTriangular::LowerTriangular,
+];
+
+const TRANS: [CoralTranspose; 3] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+    CoralTranspose::ConjugateTranspose,
+];
+
+const DIAGS: [CoralDiagonal; 2] = [
+    CoralDiagonal::NonUnitDiagonal,
+    CoralDiagonal::UnitDiagonal,
+];
+
+fn make_f32(len: usize, seed: usize) -> Vec<f32> {
+    (0..len).map(|i| (((i * 29 + seed * 13) % 31) as f32 - 15.0) / 8.0).collect()
+}
+
+fn make_f64(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|i| (((i * 29 + seed * 13) % 31) as f64 - 15.0) / 8.0).collect()
+}
+
+fn span(n: usize, inc: isize, w: usize) -> usize {
+    if n == 0 { 0 } else { w * ((n - 1) * inc.unsigned_abs() + 1) }
+}
+
+fn assert_close_f32(a: &[f32], b: &[f32], n: usize) {
+    let tol = 1e-5 * (n as f32 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+fn assert_close_f64(a: &[f64], b: &[f64], n: usize) {
+    let tol = 1e-13 * (n as f64 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+// triangular with a heavy diagonal, so solves stay well conditioned
+fn make_triangular_f64(n: usize, lda: usize, w: usize, seed: usize) -> Vec<f64> {
+    let mut a: Vec<f64> = make_f64(w * lda * n, seed).iter().map(|v| v / n as f64).collect();
+    for j in 0..n {
+        a[w * (j * lda + j)] += 4.0;
+    }
+    a
+}
+
+#[test]
+fn gemv() {
+    let (m, n) = (23, 17);
+    let lda = m + 3;
+
+    for &trans in &TRANS {
+        let (lx, ly) = if let CoralTranspose::NoTranspose = trans { (n, m) } else { (m, n) };
+
+        for &(incx, incy) in &[(1isize, 1isize), (2, -1)] {
+            let a = make_f32(2 * lda * n, 1);
+            let x = make_f32(span(lx, incx, 2), 2);
+            let y = make_f32(span(ly, incy, 2), 3);
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::sgemv(trans, m, n, 1.5, &a, lda, &x, incx, -0.5, &mut p, incy);
+            naive::sgemv(trans, m, n, 1.5, &a, lda, &x, incx, -0.5, &mut q, incy);
+            assert_close_f32(&p, &q, lx);
+
+            let a = make_f64(2 * lda * n, 4);
+            let x = make_f64(span(lx, incx, 2), 5);
+            let y = make_f64(span(ly, incy, 2), 6);
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::dgemv(trans, m, n, 1.5, &a, lda, &x, incx, 0.0, &mut p, incy);
+            naive::dgemv(trans, m, n, 1.5, &a, lda, &x, incx, 0.0, &mut q, incy);
+            assert_close_f64(&p, &q, lx);
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::zgemv(trans, m, n, [0.5, -1.0], &a, lda, &x, incx, [1.0, 0.25], &mut p, incy);
+            naive::zgemv(trans, m, n, [0.5, -1.0], &a, lda, &x, incx, [1.0, 0.25], &mut q, incy);
+            assert_close_f64(&p, &q, lx);
+        }
+    }
+}
+
+#[test]
+fn gemv_with() {
+    let (m, n) = (23, 17);
+    let lda = m + 3;
+
+    for &trans in &TRANS[..2] {
+        let (lx, ly) = if let CoralTranspose::NoTranspose = trans { (n, m) } else { (m, n) };
+
+        for &accuracy in &[Accuracy::Fast, Accuracy::Compensated] {
+            for &(incx, incy) in &[(1isize, 1isize), (2, -1)] {
+                let a = make_f32(lda * n, 1);
+                let x = make_f32(span(lx, incx, 1), 2);
+                let y = make_f32(span(ly, incy, 1), 3);
+
+                let (mut p, mut q) = (y.clone(), y.clone());
+                opt::sgemv_with(accuracy, trans, m, n, 1.5, &a, lda, &x, incx, -0.5, &mut p, incy);
+                naive::sgemv_with(accuracy, trans, m, n, 1.5, &a, lda, &x, incx, -0.5, &mut q, incy);
+                assert_close_f32(&p, &q, lx);
+
+                let a = make_f64(lda * n, 4);
+                let x = make_f64(span(lx, incx, 1), 5);
+                let y = make_f64(span(ly, incy, 1), 6);
+
+                let (mut p, mut q) = (y.clone(), y.clone());
+                opt::dgemv_with(accuracy, trans, m, n, 1.5, &a, lda, &x, incx, 0.0, &mut p, incy);
+                naive::dgemv_with(accuracy, trans, m, n, 1.5, &a, lda, &x, incx, 0.0, &mut q, incy);
+                assert_close_f64(&p, &q, lx);
+            }
+        }
+    }
+}
+
+#[test]
+fn triangular() {
+    let n = 19;
+    let lda = n + 2;
+
+    for &uplo in &UPLOS {
+        for &trans in &TRANS {
+            for &diag in &DIAGS {
+                for &incx in &[1isize, -2] {
+                    let a = make_triangular_f64(n, lda, 1, 7);
+                    let x = make_f64(span(n, incx, 1), 8);
+
+                    let (mut p, mut q) = (x.clone(), x.clone());
+                    opt::dtrmv(uplo, trans, diag, n, &a, lda, &mut p, incx);
+                    naive::dtrmv(uplo, trans, diag, n, &a, lda, &mut q, incx);
+                    assert_close_f64(&p, &q, n);
+
+                    let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+                    let x: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+
+                    let (mut p, mut q) = (x.clone(), x.clone());
+                    opt::strsv(uplo, trans, diag, n, &a, lda, &mut p, incx);
+                    naive::strsv(uplo, trans, diag, n, &a, lda, &mut q, incx);
+                    assert_close_f32(&p, &q, n);
+
+                    let a = make_triangular_f64(n, lda, 2, 9);
+                    let x = make_f64(span(n, incx, 2), 10);
+
+                    let (mut p, mut q) = (x.clone(), x.clone());
+                    opt::ztrsv(uplo, trans, diag, n, &a, lda, &mut p, incx);
+                    naive::ztrsv(uplo, trans, diag, n, &a, lda, &mut q, incx);
+                    assert_close_f64(&p, &q, n);
+
+                    let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+                    let x: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+
+                    let (mut p, mut q) = (x.clone(), x.clone());
+                    opt::ctrmv(uplo, trans, diag, n, &a, lda, &mut p, incx);
+                    naive::ctrmv(uplo, trans, diag, n, &a, lda, &mut q, incx);
+                    assert_close_f32(&p, &q, n);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn symmetric_and_hermitian() {
+    let n = 21;
+    let lda = n + 1;
+
+    for &uplo in &UPLOS {
+        for &(incx, incy) in &[(1isize, 1isize), (-1, 3)] {
+            let a = make_f64(2 * lda * n, 9);
+            let x = make_f64(span(n, incx, 2), 10);
+            let y = make_f64(span(n, incy, 2), 11);
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::dsymv(uplo, n, 0.75, &a, lda, &x, incx, 2.0, &mut p, incy);
+            naive::dsymv(uplo, n, 0.75, &a, lda, &x, incx, 2.0, &mut q, incy);
+            assert_close_f64(&p, &q, n);
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::zhemv(uplo, n, [1.0, 0.5], &a, lda, &x, incx, [0.0, -1.0], &mut p, incy);
+            naive::zhemv(uplo, n, [1.0, 0.5], &a, lda, &x, incx, [0.0, -1.0], &mut q, incy);
+            assert_close_f64(&p, &q, n);
+
+            let (mut p, mut q) = (a.clone(), a.clone());
+            opt::dsyr2(uplo, n, -0.5, &x, incx, &y, incy, &mut p, lda);
+            naive::dsyr2(uplo, n, -0.5, &x, incx, &y, incy, &mut q, lda);
+            assert_close_f64(&p, &q, 1);
+
+            let (mut p, mut q) = (a.clone(), a.clone());
+            opt::zher(uplo, n, 1.25, &x, incx, &mut p, lda);
+            naive::zher(uplo, n, 1.25, &x, incx, &mut q, lda);
+            assert_close_f64(&p, &q, 1);
+
+            let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+            let x: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+            let y: Vec<f32> = y.iter().map(|&v| v as f32).collect();
+
+            let (mut p, mut q) = (y.clone(), y.clone());
+            opt::chemv(uplo, n, [-0.5, 1.0], &a, lda, &x, incx, [1.0, 0.0], &mut p, incy);
+            naive::chemv(uplo, n, [-0.5, 1.0], &a, lda, &x, incx, [1.0, 0.0], &mut q, incy);
+            assert_close_f32(&p, &q, n);
+
+            let (mut p, mut q) = (a.clone(), a.clone());
+            opt::ssyr(uplo, n, 0.5, &x, incx, &mut p, lda);
+            naive::ssyr(uplo, n, 0.5, &x, incx, &mut q, lda);
+            assert_close_f32(&p, &q, 1);
+
+            let (mut p, mut q) = (a.clone(), a.clone());
+            opt::cher2(uplo, n, [0.25, -0.75], &x, incx, &y, incy, &mut p, lda);
+            naive::cher2(uplo, n, [0.25, -0.75], &x, incx, &y, incy, &mut q, lda);
+            assert_close_f32(&p, &q, 1);
+        }
+    }
+}
+
+#[test]
+fn rank_one() {
+    let (m, n) = (18, 11);
+    let lda = m + 2;
+
+    for &(incx, incy) in &[(1isize, 1isize), (3, -2)] {
+        let a = make_f64(2 * lda * n, 12);
+        let x = make_f64(span(m, incx, 2), 13);
+        let y = make_f64(span(n, incy, 2), 14);
+
+        let (mut p, mut q) = (a.clone(), a.clone());
+        opt::dger(m, n, 0.5, &x, incx, &y, incy, &mut p, lda);
+        naive::dger(m, n, 0.5, &x, incx, &y, incy, &mut q, lda);
+        assert_close_f64(&p, &q, 1);
+
+        let (mut p, mut q) = (a.clone(), a.clone());
+        opt::zgerc(m, n, [1.0, -0.5], &x, incx, &y, incy, &mut p, lda);
+        naive::zgerc(m, n, [1.0, -0.5], &x, incx, &y, incy, &mut q, lda);
+        assert_close_f64(&p, &q, 1);
+
+        let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+        let x: Vec<f32> = x.iter().map(|&v| v as f32).collect();
+        let y: Vec<f32> = y.iter().map(|&v| v as f32).collect();
+
+        let (mut p, mut q) = (a.clone(), a.clone());
+        opt::sger(m, n, -1.5, &x, incx, &y, incy, &mut p, lda);
+        naive::sger(m, n, -1.5, &x, incx, &y, incy, &mut q, lda);
+        assert_close_f32(&p, &q, 1);
+
+        let (mut p, mut q) = (a.clone(), a.clone());
+        opt::cgeru(m, n, [0.75, 0.25], &x, incx, &y, incy, &mut p, lda);
+        naive::cgeru(m, n, [0.75, 0.25], &x, incx, &y, incy, &mut q, lda);
+        assert_close_f32(&p, &q, 1);
+    }
+}
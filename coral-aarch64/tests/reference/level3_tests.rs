@@ -0,0 +1,189 @@
+use coral_aarch64::enums::{CoralTranspose, CoralTriangular};
+use coral_aarch64::level3 as opt;
+use coral_aarch64::level3::epilogue::{Activation, Bias, Epilogue};
+use coral_aarch64::reference::level3 as naive;
+
+const OPS: [CoralTranspose; 3] = [
+    CoralTranspose::NoTranspose,
+    CoralTranspose::Transpose,
+    CoralTranspose::ConjugateTranspose,
+];
+
+fn dims(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (rows, cols),
+        _                           => (cols, rows),
+    }
+}
+
+fn make_f64(len: usize, seed: usize) -> Vec<f64> {
+    (0..len).map(|i| (((i * 29 + seed * 13) % 31) as f64 - 15.0) / 8.0).collect()
+}
+
+fn assert_close_f32(a: &[f32], b: &[f32], k: usize) {
+    let tol = 1e-5 * (k as f32 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+fn assert_close_f64(a: &[f64], b: &[f64], k: usize) {
+    let tol = 1e-13 * (k as f64 + 1.0);
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        assert!((x - y).abs() <= tol * (1.0 + y.abs()), "index {i}: {x} vs {y}");
+    }
+}
+
+#[test]
+fn gemm() {
+    // past one MC x NC block, with ragged edges
+    let (m, n, k) = (71, 53, 37);
+
+    for &op_a in &OPS {
+        for &op_b in &OPS {
+            let (ar, ac) = dims(op_a, m, k);
+            let (br, bc) = dims(op_b, k, n);
+            let (lda, ldb, ldc) = (ar + 1, br + 2, m + 3);
+
+            let a = make_f64(2 * lda * ac, 1);
+            let b = make_f64(2 * ldb * bc, 2);
+            let c = make_f64(2 * ldc * n, 3);
+
+            let (mut p, mut q) = (c.clone(), c.clone());
+            opt::zgemm(
+                op_a, op_b, m, n, k, [0.5, -1.0],
+                a.as_ptr(), lda, b.as_ptr(), ldb, [1.0, 0.5], p.as_mut_ptr(), ldc,
+            );
+            naive::zgemm(
+                op_a, op_b, m, n, k, [0.5, -1.0],
+                a.as_ptr(), lda, b.as_ptr(), ldb, [1.0, 0.5], q.as_mut_ptr(), ldc,
+            );
+            assert_close_f64(&p, &q, k);
+
+            let (mut p, mut q) = (c.clone(), c.clone());
+            opt::dgemm(
+                op_a, op_b, m, n, k, 1.5,
+                a.as_ptr(), lda, b.as_ptr(), ldb, 0.0, p.as_mut_ptr(), ldc,
+            );
+            naive::dgemm(
+                op_a, op_b, m, n, k, 1.5,
+                a.as_ptr(), lda, b.as_ptr(), ldb, 0.0, q.as_mut_ptr(), ldc,
+            );
+            assert_close_f64(&p, &q, k);
+
+            let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+            let b: Vec<f32> = b.iter().map(|&v| v as f32).collect();
+            let c: Vec<f32> = c.iter().map(|&v| v as f32).collect();
+
+            let (mut p, mut q) = (c.clone(), c.clone());
+            opt::sgemm(
+                op_a, op_b, m, n, k, -0.75,
+                a.as_ptr(), lda, b.as_ptr(), ldb, 2.0, p.as_mut_ptr(), ldc,
+            );
+            naive::sgemm(
+                op_a, op_b, m, n, k, -0.75,
+                a.as_ptr(), lda, b.as_ptr(), ldb, 2.0, q.as_mut_ptr(), ldc,
+            );
+            assert_close_f32(&p, &q, k);
+
+            let (mut p, mut q) = (c.clone(), c.clone());
+            opt::cgemm_3m(
+                op_a, op_b, m, n, k, [1.0, 0.25],
+                a.as_ptr(), lda, b.as_ptr(), ldb, [0.0, 0.0], p.as_mut_ptr(), ldc,
+            );
+            naive::cgemm(
+                op_a, op_b, m, n, k, [1.0, 0.25],
+                a.as_ptr(), lda, b.as_ptr(), ldb, [0.0, 0.0], q.as_mut_ptr(), ldc,
+            );
+            assert_close_f32(&p, &q, 4 * k);
+        }
+    }
+}
+
+#[test]
+fn gemmt() {
+    let (n, k) = (43, 29);
+
+    for &uplo in &[CoralTriangular::UpperTriangular, CoralTriangular::LowerTriangular] {
+        for &op_a in &OPS {
+            for &op_b in &OPS {
+                let (ar, ac) = dims(op_a, n, k);
+                let (br, bc) = dims(op_b, k, n);
+                let (lda, ldb, ldc) = (ar, br + 1, n + 2);
+
+                let a = make_f64(2 * lda * ac, 4);
+                let b = make_f64(2 * ldb * bc, 5);
+                let c = make_f64(2 * ldc * n, 6);
+
+                let (mut p, mut q) = (c.clone(), c.clone());
+                opt::zgemmt(
+                    uplo, op_a, op_b, n, k, [0.5, 0.5],
+                    a.as_ptr(), lda, b.as_ptr(), ldb, [-1.0, 0.0], p.as_mut_ptr(), ldc,
+                );
+                naive::zgemmt(
+                    uplo, op_a, op_b, n, k, [0.5, 0.5],
+                    a.as_ptr(), lda, b.as_ptr(), ldb, [-1.0, 0.0], q.as_mut_ptr(), ldc,
+                );
+                assert_close_f64(&p, &q, k);
+
+                let a: Vec<f32> = a.iter().map(|&v| v as f32).collect();
+                let b: Vec<f32> = b.iter().map(|&v| v as f32).collect();
+                let c: Vec<f32> = c.iter().map(|&v| v as f32).collect();
+
+                let (mut p, mut q) = (c.clone(), c.clone());
+                opt::sgemmt(
+                    uplo, op_a, op_b, n, k, 1.0,
+                    a.as_ptr(), lda, b.as_ptr(), ldb, 0.5, p.as_mut_ptr(), ldc,
+                );
+                naive::sgemmt(
+                    uplo, op_a, op_b, n, k, 1.0,
+                    a.as_ptr(), lda, b.as_ptr(), ldb, 0.5, q.as_mut_ptr(), ldc,
+                );
+                assert_close_f32(&p, &q, k);
+            }
+        }
+    }
+}
+
+#[test]
+fn epilogue_and_slices() {
+    let (m, n, k) = (33, 27, 19);
+    let (lda, ldb, ldc) = (m, k, m);
+
+    let a: Vec<f32> = make_f64(lda * k, 7).iter().map(|&v| v as f32).collect();
+    let b: Vec<f32> = make_f64(ldb * n, 8).iter().map(|&v| v as f32).collect();
+    let c: Vec<f32> = make_f64(ldc * n, 9).iter().map(|&v| v as f32).collect();
+    let bias: Vec<f32> = make_f64(n, 10).iter().map(|&v| v as f32).collect();
+    let map = |i: usize, j: usize, x: f32| x + (i * j) as f32 / 64.0;
+
+    for &activation in &[Activation::None, Activation::Relu, Activation::Gelu] {
+        let ep = Epilogue {
+            bias  : Bias::Col(&bias),
+            activation,
+            clamp : Some((-2.0, 3.0)),
+            map   : Some(&map),
+        };
+
+        let (mut p, mut q) = (c.clone(), c.clone());
+        opt::sgemm_epilogue(
+            CoralTranspose::NoTranspose, CoralTranspose::NoTranspose, m, n, k, 0.5,
+            a.as_ptr(), lda, b.as_ptr(), ldb, -1.0, p.as_mut_ptr(), ldc, &ep,
+        );
+        naive::sgemm_epilogue(
+            CoralTranspose::NoTranspose, CoralTranspose::NoTranspose, m, n, k, 0.5,
+            a.as_ptr(), lda, b.as_ptr(), ldb, -1.0, q.as_mut_ptr(), ldc, &ep,
+        );
+        assert_close_f32(&p, &q, k);
+    }
+
+    let (mut p, mut q) = (c.clone(), c.clone());
+    opt::slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose, m, n, k, 1.0,
+        &a, lda, &b, ldb, 1.0, &mut p, ldc,
+    );
+    naive::slice::sgemm(
+        CoralTranspose::NoTranspose, CoralTranspose::NoTranspose, m, n, k, 1.0,
+        &a, lda, &b, ldb, 1.0, &mut q, ldc,
+    );
+    assert_close_f32(&p, &q, k);
+}
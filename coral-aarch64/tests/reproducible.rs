@@ -0,0 +1,218 @@
+// bitwise checks for the `reproducible` feature; every comparison is on
+// `to_bits`, never a tolerance
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level1::{sdot, sasum, snrm2};
+use coral_aarch64::level2::sgemv;
+use coral_aarch64::level3::sgemm;
+
+// xorshift, so the cases are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // wide spread of magnitudes, so a change of order changes the bits
+    fn f32(&mut self) -> f32 {
+        let m = (self.next() >> 40) as f32 / (1u64 << 24) as f32 - 0.5;
+        m * 2f32.powi((self.next() % 24) as i32 - 12)
+    }
+
+    fn vec(&mut self, n: usize) -> Vec<f32> {
+        (0..n).map(|_| self.f32()).collect()
+    }
+}
+
+fn bits(v: &[f32]) -> Vec<u32> {
+    v.iter().map(|x| x.to_bits()).collect()
+}
+
+// the documented order: term i into partial i % 8, then a fixed tree
+fn spec_sum(terms: impl Iterator<Item = (f32, f32)>) -> f32 {
+    let mut s = [0.0f32; 8];
+    for (i, (a, b)) in terms.enumerate() {
+        s[i % 8] = a.mul_add(b, s[i % 8]);
+    }
+    ((s[0] + s[1]) + (s[2] + s[3])) + ((s[4] + s[5]) + (s[6] + s[7]))
+}
+
+// `v` laid out at stride `inc` after `pad` leading entries
+fn place(v: &[f32], inc: isize, pad: usize) -> Vec<f32> {
+    let n = v.len();
+    let step = inc.unsigned_abs();
+    let mut buf = vec![0.0; pad + (n - 1) * step + 1];
+
+    for (i, &vi) in v.iter().enumerate() {
+        buf[pad + if inc > 0 { i * step } else { (n - 1 - i) * step }] = vi;
+    }
+    buf
+}
+
+const SIZES: &[usize] = &[1, 3, 7, 8, 9, 31, 32, 33, 100, 1023, 4099];
+
+#[test]
+fn dot_order() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for &n in SIZES {
+        let x = rng.vec(n);
+        let y = rng.vec(n);
+        let want = spec_sum(x.iter().copied().zip(y.iter().copied())).to_bits();
+
+        for &(incx, incy) in &[(1isize, 1isize), (2, 3), (-1, 1), (3, -2), (-4, -1)] {
+            for pad in 0..4 {
+                let xb = place(&x, incx, pad);
+                let yb = place(&y, incy, 3 - pad);
+
+                let got = sdot(n, &xb[pad..], incx, &yb[3 - pad..], incy);
+                assert_eq!(got.to_bits(), want, "n {n}, inc ({incx}, {incy}), pad {pad}");
+            }
+        }
+    }
+}
+
+#[test]
+fn asum_and_nrm2_order() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for &n in SIZES {
+        let x = rng.vec(n);
+        let asum = spec_sum(x.iter().map(|v| (v.abs(), 1.0))).to_bits();
+
+        // power of two just above max |x_i|
+        let amax = x.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        let e = ((amax.to_bits() >> 23) & 0xff) as i32 - 127;
+        let scale = 2f32.powi(e + 1);
+        let nrm2 = (scale * spec_sum(x.iter().map(|v| (v / scale, v / scale))).sqrt()).to_bits();
+
+        for &inc in &[1isize, 2, 5] {
+            for pad in 0..4 {
+                let xb = place(&x, inc, pad);
+
+                assert_eq!(sasum(n, &xb[pad..], inc).to_bits(), asum, "n {n}, inc {inc}, pad {pad}");
+                assert_eq!(snrm2(n, &xb[pad..], inc).to_bits(), nrm2, "n {n}, inc {inc}, pad {pad}");
+                assert_eq!(snrm2(n, &xb[pad..], -inc).to_bits(), nrm2, "n {n}, inc -{inc}, pad {pad}");
+            }
+        }
+    }
+
+    // scaling stays exact at the ends of the range
+    for &k in &[100, -100] {
+        let s = 2f32.powi(k);
+        assert_eq!(snrm2(2, &[3.0 * s, 4.0 * s], 1), 5.0 * s);
+    }
+}
+
+#[test]
+fn gemv_transpose_is_column_dots() {
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+
+    for &(m, n) in &[(1usize, 1usize), (9, 5), (33, 17), (1023, 3), (4099, 2)] {
+        let lda = m + 3;
+        let a = rng.vec(lda * n);
+        let x = rng.vec(m);
+        let y0 = rng.vec(n);
+        let (alpha, beta) = (1.25f32, -0.75f32);
+
+        let want: Vec<u32> = (0..n)
+            .map(|j| {
+                let d = sdot(m, &a[j * lda..], 1, &x, 1);
+                alpha.mul_add(d, beta * y0[j]).to_bits()
+            })
+            .collect();
+
+        for &trans in &[CoralTranspose::Transpose, CoralTranspose::ConjugateTranspose] {
+            for &(incx, incy) in &[(1isize, 1isize), (3, 2), (-2, -1)] {
+                let xb = place(&x, incx, 0);
+                let mut yb = place(&y0, incy, 0);
+
+                sgemv(trans, m, n, alpha, &a, lda, &xb, incx, beta, &mut yb, incy);
+
+                let got: Vec<f32> = (0..n)
+                    .map(|j| yb[if incy > 0 { j * incy as usize } else { (n - 1 - j) * incy.unsigned_abs() }])
+                    .collect();
+                assert_eq!(bits(&got), want, "{m}x{n}, inc ({incx}, {incy})");
+            }
+        }
+    }
+}
+
+// op(A) is m x k, op(B) is k x n; store them for the given ops
+fn store(op: &[f32], rows: usize, cols: usize, trans: bool, pad: usize) -> (Vec<f32>, usize) {
+    let ld = if trans { cols } else { rows } + pad;
+    let mut s = vec![0.0; ld * if trans { rows } else { cols }];
+
+    for j in 0..cols {
+        for i in 0..rows {
+            let v = op[j * rows + i];
+            if trans { s[i * ld + j] = v; } else { s[j * ld + i] = v; }
+        }
+    }
+    (s, ld)
+}
+
+#[test]
+fn gemm_order() {
+    let mut rng = Rng(0x0f0f_1e1e_2d2d_3c3c);
+
+    // past MC = 384 and KC = 256, with ragged tails everywhere
+    for &(m, n, k) in &[(1usize, 1usize, 1usize), (7, 5, 3), (17, 13, 300), (390, 9, 517), (5, 601, 260)] {
+        let opa = rng.vec(m * k);
+        let opb = rng.vec(k * n);
+        let c0  = rng.vec(m * n);
+        let (alpha, beta) = (0.5f32, 1.5f32);
+
+        // each entry one FMA chain over ascending p
+        let mut want = vec![0u32; m * n];
+        for j in 0..n {
+            for i in 0..m {
+                let mut s = 0.0f32;
+                for p in 0..k {
+                    s = opa[p * m + i].mul_add(opb[j * k + p], s);
+                }
+                want[j * m + i] = alpha.mul_add(s, beta * c0[j * m + i]).to_bits();
+            }
+        }
+
+        for &(a_t, b_t) in &[(false, false), (false, true), (true, false), (true, true)] {
+            let (a, lda) = store(&opa, m, k, a_t, 1);
+            let (b, ldb) = store(&opb, k, n, b_t, 2);
+            let op = |t: bool| if t { CoralTranspose::Transpose } else { CoralTranspose::NoTranspose };
+
+            let mut c = c0.clone();
+            sgemm(op(a_t), op(b_t), m, n, k, alpha, a.as_ptr(), lda, b.as_ptr(), ldb, beta, c.as_mut_ptr(), m);
+            assert_eq!(bits(&c), want, "{m}x{n}x{k}, ops ({a_t}, {b_t})");
+        }
+    }
+}
+
+#[test]
+fn same_bits_on_every_thread() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    let (m, n, k) = (129, 65, 257);
+    let a = rng.vec(m * k);
+    let b = rng.vec(k * n);
+    let x = rng.vec(m * k);
+
+    let run = move || {
+        let mut c = vec![0.0f32; m * n];
+        sgemm(CoralTranspose::NoTranspose, CoralTranspose::NoTranspose, m, n, k,
+              1.0, a.as_ptr(), m, b.as_ptr(), k, 0.0, c.as_mut_ptr(), m);
+
+        (bits(&c), sdot(m * k, &x, 1, &a, 1).to_bits(), snrm2(m * k, &x, 1).to_bits())
+    };
+
+    let first = run.clone()();
+    let others: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(run.clone()))
+        .collect();
+
+    for t in others {
+        assert!(t.join().unwrap() == first);
+    }
+}
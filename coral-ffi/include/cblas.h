@@ -0,0 +1,199 @@
+/*
+ * cblas.h -- CBLAS interface exported by coral (libcoral_ffi).
+ *
+ * Standard CBLAS names, signatures and enum values. Only the routines
+ * listed here are exported: level1, level2, and ?gemm from level3.
+ */
+
+#ifndef CORAL_CBLAS_H
+#define CORAL_CBLAS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define CBLAS_INDEX size_t
+
+typedef enum CBLAS_LAYOUT    { CblasRowMajor = 101, CblasColMajor = 102 } CBLAS_LAYOUT;
+typedef enum CBLAS_TRANSPOSE { CblasNoTrans = 111, CblasTrans = 112, CblasConjTrans = 113 } CBLAS_TRANSPOSE;
+typedef enum CBLAS_UPLO      { CblasUpper = 121, CblasLower = 122 } CBLAS_UPLO;
+typedef enum CBLAS_DIAG      { CblasNonUnit = 131, CblasUnit = 132 } CBLAS_DIAG;
+typedef enum CBLAS_SIDE      { CblasLeft = 141, CblasRight = 142 } CBLAS_SIDE;
+
+typedef CBLAS_LAYOUT CBLAS_ORDER;
+
+/* ----------------------------------------------------------------------
+ * level1
+ * ---------------------------------------------------------------------- */
+
+float  cblas_sdot(const int N, const float  *X, const int incX, const float  *Y, const int incY);
+double cblas_ddot(const int N, const double *X, const int incX, const double *Y, const int incY);
+
+float  cblas_sdsdot(const int N, const float alpha, const float *X, const int incX, const float *Y, const int incY);
+double cblas_dsdot (const int N, const float *X, const int incX, const float *Y, const int incY);
+
+void cblas_cdotu_sub(const int N, const void *X, const int incX, const void *Y, const int incY, void *dotu);
+void cblas_cdotc_sub(const int N, const void *X, const int incX, const void *Y, const int incY, void *dotc);
+void cblas_zdotu_sub(const int N, const void *X, const int incX, const void *Y, const int incY, void *dotu);
+void cblas_zdotc_sub(const int N, const void *X, const int incX, const void *Y, const int incY, void *dotc);
+
+float  cblas_snrm2 (const int N, const float  *X, const int incX);
+float  cblas_sasum (const int N, const float  *X, const int incX);
+double cblas_dnrm2 (const int N, const double *X, const int incX);
+double cblas_dasum (const int N, const double *X, const int incX);
+float  cblas_scnrm2(const int N, const void   *X, const int incX);
+float  cblas_scasum(const int N, const void   *X, const int incX);
+double cblas_dznrm2(const int N, const void   *X, const int incX);
+double cblas_dzasum(const int N, const void   *X, const int incX);
+
+CBLAS_INDEX cblas_isamax(const int N, const float  *X, const int incX);
+CBLAS_INDEX cblas_idamax(const int N, const double *X, const int incX);
+CBLAS_INDEX cblas_icamax(const int N, const void   *X, const int incX);
+CBLAS_INDEX cblas_izamax(const int N, const void   *X, const int incX);
+
+void cblas_sswap(const int N, float  *X, const int incX, float  *Y, const int incY);
+void cblas_scopy(const int N, const float  *X, const int incX, float  *Y, const int incY);
+void cblas_saxpy(const int N, const float  alpha, const float  *X, const int incX, float  *Y, const int incY);
+void cblas_dswap(const int N, double *X, const int incX, double *Y, const int incY);
+void cblas_dcopy(const int N, const double *X, const int incX, double *Y, const int incY);
+void cblas_daxpy(const int N, const double alpha, const double *X, const int incX, double *Y, const int incY);
+void cblas_cswap(const int N, void *X, const int incX, void *Y, const int incY);
+void cblas_ccopy(const int N, const void *X, const int incX, void *Y, const int incY);
+void cblas_caxpy(const int N, const void *alpha, const void *X, const int incX, void *Y, const int incY);
+void cblas_zswap(const int N, void *X, const int incX, void *Y, const int incY);
+void cblas_zcopy(const int N, const void *X, const int incX, void *Y, const int incY);
+void cblas_zaxpy(const int N, const void *alpha, const void *X, const int incX, void *Y, const int incY);
+
+void cblas_srotg (float *a, float *b, float *c, float *s);
+void cblas_srotmg(float *d1, float *d2, float *b1, const float b2, float *P);
+void cblas_srot  (const int N, float *X, const int incX, float *Y, const int incY, const float c, const float s);
+void cblas_srotm (const int N, float *X, const int incX, float *Y, const int incY, const float *P);
+void cblas_drotg (double *a, double *b, double *c, double *s);
+void cblas_drotmg(double *d1, double *d2, double *b1, const double b2, double *P);
+void cblas_drot  (const int N, double *X, const int incX, double *Y, const int incY, const double c, const double s);
+void cblas_drotm (const int N, double *X, const int incX, double *Y, const int incY, const double *P);
+void cblas_csrot (const int N, void *X, const int incX, void *Y, const int incY, const float c, const float s);
+void cblas_zdrot (const int N, void *X, const int incX, void *Y, const int incY, const double c, const double s);
+void cblas_crotg (void *a, const void *b, float *c, void *s);
+void cblas_zrotg (void *a, const void *b, double *c, void *s);
+
+void cblas_sscal (const int N, const float  alpha, float  *X, const int incX);
+void cblas_dscal (const int N, const double alpha, double *X, const int incX);
+void cblas_cscal (const int N, const void  *alpha, void   *X, const int incX);
+void cblas_zscal (const int N, const void  *alpha, void   *X, const int incX);
+void cblas_csscal(const int N, const float  alpha, void   *X, const int incX);
+void cblas_zdscal(const int N, const double alpha, void   *X, const int incX);
+
+/* ----------------------------------------------------------------------
+ * level2
+ * ---------------------------------------------------------------------- */
+
+void cblas_sgemv(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const int M, const int N,
+                 const float alpha, const float *A, const int lda, const float *X, const int incX,
+                 const float beta, float *Y, const int incY);
+void cblas_dgemv(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const int M, const int N,
+                 const double alpha, const double *A, const int lda, const double *X, const int incX,
+                 const double beta, double *Y, const int incY);
+void cblas_cgemv(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const int M, const int N,
+                 const void *alpha, const void *A, const int lda, const void *X, const int incX,
+                 const void *beta, void *Y, const int incY);
+void cblas_zgemv(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const int M, const int N,
+                 const void *alpha, const void *A, const int lda, const void *X, const int incX,
+                 const void *beta, void *Y, const int incY);
+
+void cblas_strmv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const float *A, const int lda, float *X, const int incX);
+void cblas_dtrmv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const double *A, const int lda, double *X, const int incX);
+void cblas_ctrmv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const void *A, const int lda, void *X, const int incX);
+void cblas_ztrmv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const void *A, const int lda, void *X, const int incX);
+
+void cblas_strsv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const float *A, const int lda, float *X, const int incX);
+void cblas_dtrsv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const double *A, const int lda, double *X, const int incX);
+void cblas_ctrsv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const void *A, const int lda, void *X, const int incX);
+void cblas_ztrsv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const CBLAS_TRANSPOSE TransA,
+                 const CBLAS_DIAG Diag, const int N, const void *A, const int lda, void *X, const int incX);
+
+void cblas_ssymv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const float alpha,
+                 const float *A, const int lda, const float *X, const int incX,
+                 const float beta, float *Y, const int incY);
+void cblas_dsymv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const double alpha,
+                 const double *A, const int lda, const double *X, const int incX,
+                 const double beta, double *Y, const int incY);
+void cblas_chemv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const void *alpha,
+                 const void *A, const int lda, const void *X, const int incX,
+                 const void *beta, void *Y, const int incY);
+void cblas_zhemv(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const void *alpha,
+                 const void *A, const int lda, const void *X, const int incX,
+                 const void *beta, void *Y, const int incY);
+
+void cblas_sger (const CBLAS_LAYOUT layout, const int M, const int N, const float alpha,
+                 const float *X, const int incX, const float *Y, const int incY, float *A, const int lda);
+void cblas_dger (const CBLAS_LAYOUT layout, const int M, const int N, const double alpha,
+                 const double *X, const int incX, const double *Y, const int incY, double *A, const int lda);
+void cblas_cgeru(const CBLAS_LAYOUT layout, const int M, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+void cblas_cgerc(const CBLAS_LAYOUT layout, const int M, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+void cblas_zgeru(const CBLAS_LAYOUT layout, const int M, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+void cblas_zgerc(const CBLAS_LAYOUT layout, const int M, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+
+void cblas_ssyr(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const float alpha,
+                const float *X, const int incX, float *A, const int lda);
+void cblas_dsyr(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const double alpha,
+                const double *X, const int incX, double *A, const int lda);
+void cblas_cher(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const float alpha,
+                const void *X, const int incX, void *A, const int lda);
+void cblas_zher(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const double alpha,
+                const void *X, const int incX, void *A, const int lda);
+
+void cblas_ssyr2(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const float alpha,
+                 const float *X, const int incX, const float *Y, const int incY, float *A, const int lda);
+void cblas_dsyr2(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const double alpha,
+                 const double *X, const int incX, const double *Y, const int incY, double *A, const int lda);
+void cblas_cher2(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+void cblas_zher2(const CBLAS_LAYOUT layout, const CBLAS_UPLO Uplo, const int N, const void *alpha,
+                 const void *X, const int incX, const void *Y, const int incY, void *A, const int lda);
+
+/* ----------------------------------------------------------------------
+ * level3
+ * ---------------------------------------------------------------------- */
+
+void cblas_sgemm(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const CBLAS_TRANSPOSE TransB,
+                 const int M, const int N, const int K, const float alpha, const float *A, const int lda,
+                 const float *B, const int ldb, const float beta, float *C, const int ldc);
+void cblas_dgemm(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const CBLAS_TRANSPOSE TransB,
+                 const int M, const int N, const int K, const double alpha, const double *A, const int lda,
+                 const double *B, const int ldb, const double beta, double *C, const int ldc);
+void cblas_cgemm(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const CBLAS_TRANSPOSE TransB,
+                 const int M, const int N, const int K, const void *alpha, const void *A, const int lda,
+                 const void *B, const int ldb, const void *beta, void *C, const int ldc);
+void cblas_zgemm(const CBLAS_LAYOUT layout, const CBLAS_TRANSPOSE TransA, const CBLAS_TRANSPOSE TransB,
+                 const int M, const int N, const int K, const void *alpha, const void *A, const int lda,
+                 const void *B, const int ldb, const void *beta, void *C, const int ldc);
+
+/* ----------------------------------------------------------------------
+ * error handling
+ * ---------------------------------------------------------------------- */
+
+/* default handler; srname is not nul-terminated */
+void xerbla_(const char *srname, const int *info, size_t srname_len);
+
+/* installs handler in place of xerbla_; NULL restores the default */
+void coral_set_xerbla(void (*handler)(const char *srname, const int *info, size_t srname_len));
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CORAL_CBLAS_H */
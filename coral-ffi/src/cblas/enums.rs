@@ -0,0 +1,73 @@
+//! CBLAS enum values.
+//!
+//! Passed across the ABI as plain `int`s, matching `include/cblas.h`.
+//! Out-of-range values are reported through [`crate::cblas::xerbla`] rather
+//! than being transmuted into a Rust enum.
+
+use core::ffi::c_int;
+use coral_aarch64::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+
+#[allow(non_camel_case_types)]
+pub type CBLAS_LAYOUT = c_int;
+#[allow(non_camel_case_types)]
+pub type CBLAS_TRANSPOSE = c_int;
+#[allow(non_camel_case_types)]
+pub type CBLAS_UPLO = c_int;
+#[allow(non_camel_case_types)]
+pub type CBLAS_DIAG = c_int;
+#[allow(non_camel_case_types)]
+pub type CBLAS_SIDE = c_int;
+
+pub const CBLAS_ROW_MAJOR : CBLAS_LAYOUT    = 101;
+pub const CBLAS_COL_MAJOR : CBLAS_LAYOUT    = 102;
+
+pub const CBLAS_NO_TRANS   : CBLAS_TRANSPOSE = 111;
+pub const CBLAS_TRANS      : CBLAS_TRANSPOSE = 112;
+pub const CBLAS_CONJ_TRANS : CBLAS_TRANSPOSE = 113;
+
+pub const CBLAS_UPPER : CBLAS_UPLO = 121;
+pub const CBLAS_LOWER : CBLAS_UPLO = 122;
+
+pub const CBLAS_NON_UNIT : CBLAS_DIAG = 131;
+pub const CBLAS_UNIT     : CBLAS_DIAG = 132;
+
+pub const CBLAS_LEFT  : CBLAS_SIDE = 141;
+pub const CBLAS_RIGHT : CBLAS_SIDE = 142;
+
+#[inline]
+pub(crate) fn transpose(t: CBLAS_TRANSPOSE) -> Option<CoralTranspose> {
+    match t {
+        CBLAS_NO_TRANS   => Some(CoralTranspose::NoTranspose),
+        CBLAS_TRANS      => Some(CoralTranspose::Transpose),
+        CBLAS_CONJ_TRANS => Some(CoralTranspose::ConjugateTranspose),
+        _                => None,
+    }
+}
+
+#[inline]
+pub(crate) fn uplo(u: CBLAS_UPLO) -> Option<CoralTriangular> {
+    match u {
+        CBLAS_UPPER => Some(CoralTriangular::UpperTriangular),
+        CBLAS_LOWER => Some(CoralTriangular::LowerTriangular),
+        _           => None,
+    }
+}
+
+#[inline]
+pub(crate) fn diag(d: CBLAS_DIAG) -> Option<CoralDiagonal> {
+    match d {
+        CBLAS_NON_UNIT => Some(CoralDiagonal::NonUnitDiagonal),
+        CBLAS_UNIT     => Some(CoralDiagonal::UnitDiagonal),
+        _              => None,
+    }
+}
+
+/// the other triangle; row-major storage of one triangle is the
+/// column-major storage of the other.
+#[inline]
+pub(crate) fn flip_uplo(u: CoralTriangular) -> CoralTriangular {
+    match u {
+        CoralTriangular::UpperTriangular => CoralTriangular::LowerTriangular,
+        CoralTriangular::LowerTriangular => CoralTriangular::UpperTriangular,
+    }
+}
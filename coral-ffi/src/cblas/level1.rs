@@ -0,0 +1,833 @@
+//! CBLAS level1.
+//!
+//! Vector-vector routines. `n <= 0` is a quick return. Increments follow
+//! reference BLAS: `?asum`, `?nrm2`, `i?amax` and `?scal` do nothing (or
+//! return `0`) for `incx <= 0`; the rest walk the vector backwards for a
+//! negative increment and repeat the first entry for a zero one. Both are
+//! handled by `coral_aarch64::level1`, which gets the caller's increment.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_int, c_void};
+
+use coral_aarch64::level1 as l1;
+use crate::strided::{slice, slice_mut, vec_len};
+
+/// `CBLAS_INDEX`.
+pub type CblasIndex = usize;
+
+// f32
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sdot(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+    y    : *const f32,
+    incy : c_int,
+) -> f32 {
+    if n <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice(y, vec_len(n, incy, 1)) };
+
+    l1::sdot(n, xs, incx as isize, ys, incy as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sdsdot(
+    n    : c_int,
+    sb   : f32,
+    x    : *const f32,
+    incx : c_int,
+    y    : *const f32,
+    incy : c_int,
+) -> f32 {
+    if n <= 0 { return sb; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice(y, vec_len(n, incy, 1)) };
+
+    l1::sdsdot(n, sb, xs, incx as isize, ys, incy as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dsdot(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+    y    : *const f32,
+    incy : c_int,
+) -> f64 {
+    if n <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice(y, vec_len(n, incy, 1)) };
+
+    l1::dsdot(n, xs, incx as isize, ys, incy as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_snrm2(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+) -> f32 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::snrm2(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sasum(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+) -> f32 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::sasum(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_isamax(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+) -> CblasIndex {
+    if n <= 0 || incx <= 0 { return 0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::isamax(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sswap(
+    n    : c_int,
+    x    : *mut f32,
+    incx : c_int,
+    y    : *mut f32,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::sswap(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_scopy(
+    n    : c_int,
+    x    : *const f32,
+    incx : c_int,
+    y    : *mut f32,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::scopy(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_saxpy(
+    n     : c_int,
+    alpha : f32,
+    x     : *const f32,
+    incx  : c_int,
+    y     : *mut f32,
+    incy  : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::saxpy(n, alpha, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sscal(
+    n     : c_int,
+    alpha : f32,
+    x     : *mut f32,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    l1::sscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_srot(
+    n    : c_int,
+    x    : *mut f32,
+    incx : c_int,
+    y    : *mut f32,
+    incy : c_int,
+    c    : f32,
+    s    : f32,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::srot(n, xs, incx as isize, ys, incy as isize, c, s);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_srotm(
+    n     : c_int,
+    x     : *mut f32,
+    incx  : c_int,
+    y     : *mut f32,
+    incy  : c_int,
+    param : *const f32,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let param = unsafe { &*(param as *const [f32; 5]) };
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::srotm(n, xs, incx as isize, ys, incy as isize, param);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_srotg(
+    a : *mut f32,
+    b : *mut f32,
+    c : *mut f32,
+    s : *mut f32,
+) {
+    unsafe { l1::srotg(&mut *a, &mut *b, &mut *c, &mut *s); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_srotmg(
+    d1    : *mut f32,
+    d2    : *mut f32,
+    b1    : *mut f32,
+    b2    : f32,
+    param : *mut f32,
+) {
+    unsafe {
+        l1::srotmg(&mut *d1, &mut *d2, &mut *b1, b2, &mut *(param as *mut [f32; 5]));
+    }
+}
+
+// f64
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ddot(
+    n    : c_int,
+    x    : *const f64,
+    incx : c_int,
+    y    : *const f64,
+    incy : c_int,
+) -> f64 {
+    if n <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice(y, vec_len(n, incy, 1)) };
+
+    l1::ddot(n, xs, incx as isize, ys, incy as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dnrm2(
+    n    : c_int,
+    x    : *const f64,
+    incx : c_int,
+) -> f64 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::dnrm2(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dasum(
+    n    : c_int,
+    x    : *const f64,
+    incx : c_int,
+) -> f64 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::dasum(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_idamax(
+    n    : c_int,
+    x    : *const f64,
+    incx : c_int,
+) -> CblasIndex {
+    if n <= 0 || incx <= 0 { return 0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    l1::idamax(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dswap(
+    n    : c_int,
+    x    : *mut f64,
+    incx : c_int,
+    y    : *mut f64,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::dswap(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dcopy(
+    n    : c_int,
+    x    : *const f64,
+    incx : c_int,
+    y    : *mut f64,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::dcopy(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_daxpy(
+    n     : c_int,
+    alpha : f64,
+    x     : *const f64,
+    incx  : c_int,
+    y     : *mut f64,
+    incy  : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::daxpy(n, alpha, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dscal(
+    n     : c_int,
+    alpha : f64,
+    x     : *mut f64,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    l1::dscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_drot(
+    n    : c_int,
+    x    : *mut f64,
+    incx : c_int,
+    y    : *mut f64,
+    incy : c_int,
+    c    : f64,
+    s    : f64,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::drot(n, xs, incx as isize, ys, incy as isize, c, s);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_drotm(
+    n     : c_int,
+    x     : *mut f64,
+    incx  : c_int,
+    y     : *mut f64,
+    incy  : c_int,
+    param : *const f64,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let param = unsafe { &*(param as *const [f64; 5]) };
+
+    let xs = unsafe { slice_mut(x, vec_len(n, incx, 1)) };
+    let ys = unsafe { slice_mut(y, vec_len(n, incy, 1)) };
+
+    l1::drotm(n, xs, incx as isize, ys, incy as isize, param);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_drotg(
+    a : *mut f64,
+    b : *mut f64,
+    c : *mut f64,
+    s : *mut f64,
+) {
+    unsafe { l1::drotg(&mut *a, &mut *b, &mut *c, &mut *s); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_drotmg(
+    d1    : *mut f64,
+    d2    : *mut f64,
+    b1    : *mut f64,
+    b2    : f64,
+    param : *mut f64,
+) {
+    unsafe {
+        l1::drotmg(&mut *d1, &mut *d2, &mut *b1, b2, &mut *(param as *mut [f64; 5]));
+    }
+}
+
+// complex f32
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cdotu_sub(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *const c_void,
+    incy : c_int,
+    dotu : *mut c_void,
+) {
+    let out = dotu as *mut [f32; 2];
+    if n <= 0 {
+        unsafe { *out = [0.0, 0.0]; }
+        return;
+    }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice(y as *const f32, vec_len(n, incy, 2)) };
+
+    unsafe { *out = l1::cdotu(n, xs, incx as isize, ys, incy as isize); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cdotc_sub(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *const c_void,
+    incy : c_int,
+    dotc : *mut c_void,
+) {
+    let out = dotc as *mut [f32; 2];
+    if n <= 0 {
+        unsafe { *out = [0.0, 0.0]; }
+        return;
+    }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice(y as *const f32, vec_len(n, incy, 2)) };
+
+    unsafe { *out = l1::cdotc(n, xs, incx as isize, ys, incy as isize); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_scnrm2(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> f32 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    l1::scnrm2(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_scasum(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> f32 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    l1::scasum(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_icamax(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> CblasIndex {
+    if n <= 0 || incx <= 0 { return 0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    l1::icamax(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cswap(
+    n    : c_int,
+    x    : *mut c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f32, vec_len(n, incy, 2)) };
+
+    l1::cswap(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ccopy(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f32, vec_len(n, incy, 2)) };
+
+    l1::ccopy(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_caxpy(
+    n     : c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : c_int,
+    y     : *mut c_void,
+    incy  : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let alpha  = unsafe { *(alpha as *const [f32; 2]) };
+
+    let xs = unsafe { slice(x as *const f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f32, vec_len(n, incy, 2)) };
+
+    l1::caxpy(n, alpha, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cscal(
+    n     : c_int,
+    alpha : *const c_void,
+    x     : *mut c_void,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let xs    = unsafe { slice_mut(x as *mut f32, vec_len(n, incx, 2)) };
+
+    l1::cscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_csscal(
+    n     : c_int,
+    alpha : f32,
+    x     : *mut c_void,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f32, vec_len(n, incx, 2)) };
+    l1::csscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_csrot(
+    n    : c_int,
+    x    : *mut c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+    c    : f32,
+    s    : f32,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f32, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f32, vec_len(n, incy, 2)) };
+
+    l1::csrot(n, xs, incx as isize, ys, incy as isize, c, s);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_crotg(
+    a : *mut c_void,
+    b : *const c_void,
+    c : *mut f32,
+    s : *mut c_void,
+) {
+    unsafe {
+        let a = &mut *(a as *mut [f32; 2]);
+        let b = *(b as *const [f32; 2]);
+        let s = &mut *(s as *mut [f32; 2]);
+
+        l1::crotg(a, b, &mut *c, s);
+    }
+}
+
+// complex f64
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zdotu_sub(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *const c_void,
+    incy : c_int,
+    dotu : *mut c_void,
+) {
+    let out = dotu as *mut [f64; 2];
+    if n <= 0 {
+        unsafe { *out = [0.0, 0.0]; }
+        return;
+    }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice(y as *const f64, vec_len(n, incy, 2)) };
+
+    unsafe { *out = l1::zdotu(n, xs, incx as isize, ys, incy as isize); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zdotc_sub(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *const c_void,
+    incy : c_int,
+    dotc : *mut c_void,
+) {
+    let out = dotc as *mut [f64; 2];
+    if n <= 0 {
+        unsafe { *out = [0.0, 0.0]; }
+        return;
+    }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice(y as *const f64, vec_len(n, incy, 2)) };
+
+    unsafe { *out = l1::zdotc(n, xs, incx as isize, ys, incy as isize); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dznrm2(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> f64 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    l1::dznrm2(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dzasum(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> f64 {
+    if n <= 0 || incx <= 0 { return 0.0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    l1::dzasum(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_izamax(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+) -> CblasIndex {
+    if n <= 0 || incx <= 0 { return 0; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    l1::izamax(n, xs, incx as isize)
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zswap(
+    n    : c_int,
+    x    : *mut c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f64, vec_len(n, incy, 2)) };
+
+    l1::zswap(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zcopy(
+    n    : c_int,
+    x    : *const c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f64, vec_len(n, incy, 2)) };
+
+    l1::zcopy(n, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zaxpy(
+    n     : c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : c_int,
+    y     : *mut c_void,
+    incy  : c_int,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let alpha  = unsafe { *(alpha as *const [f64; 2]) };
+
+    let xs = unsafe { slice(x as *const f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f64, vec_len(n, incy, 2)) };
+
+    l1::zaxpy(n, alpha, xs, incx as isize, ys, incy as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zscal(
+    n     : c_int,
+    alpha : *const c_void,
+    x     : *mut c_void,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let xs    = unsafe { slice_mut(x as *mut f64, vec_len(n, incx, 2)) };
+
+    l1::zscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zdscal(
+    n     : c_int,
+    alpha : f64,
+    x     : *mut c_void,
+    incx  : c_int,
+) {
+    if n <= 0 || incx <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f64, vec_len(n, incx, 2)) };
+    l1::zdscal(n, alpha, xs, incx as isize);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zdrot(
+    n    : c_int,
+    x    : *mut c_void,
+    incx : c_int,
+    y    : *mut c_void,
+    incy : c_int,
+    c    : f64,
+    s    : f64,
+) {
+    if n <= 0 { return; }
+    let n = n as usize;
+
+    let xs = unsafe { slice_mut(x as *mut f64, vec_len(n, incx, 2)) };
+    let ys = unsafe { slice_mut(y as *mut f64, vec_len(n, incy, 2)) };
+
+    l1::zdrot(n, xs, incx as isize, ys, incy as isize, c, s);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zrotg(
+    a : *mut c_void,
+    b : *const c_void,
+    c : *mut f64,
+    s : *mut c_void,
+) {
+    unsafe {
+        let a = &mut *(a as *mut [f64; 2]);
+        let b = *(b as *const [f64; 2]);
+        let s = &mut *(s as *mut [f64; 2]);
+
+        l1::zrotg(a, b, &mut *c, s);
+    }
+}
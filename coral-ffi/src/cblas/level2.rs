@@ -0,0 +1,1390 @@
+//! CBLAS level2.
+//!
+//! Matrix-vector routines. A row-major matrix is the column-major storage of
+//! its transpose, so `CblasRowMajor` calls swap the dimensions and flip the
+//! transpose or the stored triangle. Where that leaves a complex routine
+//! needing $\bar{A}$, the vectors are conjugated around the column-major
+//! call instead, as reference CBLAS does.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_int, c_void};
+use core::ops::Neg;
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level2 as l2;
+
+use crate::cblas::xerbla;
+use crate::cblas::enums::{
+    CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_UPLO, CBLAS_DIAG,
+    CBLAS_ROW_MAJOR, CBLAS_COL_MAJOR,
+    transpose, uplo as to_uplo, diag as to_diag, flip_uplo,
+};
+use crate::strided::{VecRef, VecMut, conj, conj_in_place, slice, slice_mut, mat_len};
+
+/// `Some(row_major)`, or `None` for an invalid layout.
+#[inline]
+fn row_major(layout: CBLAS_LAYOUT) -> Option<bool> {
+    match layout {
+        CBLAS_ROW_MAJOR => Some(true),
+        CBLAS_COL_MAJOR => Some(false),
+        _               => None,
+    }
+}
+
+/// `op` of a row-major matrix as `(op, conjugate)` of its column-major view.
+/// $A^H$ of a row-major $A$ is $\bar{A}$ of the view, i.e. a conjugated
+/// no-transpose.
+#[inline]
+fn row_major_op(op: CoralTranspose) -> (CoralTranspose, bool) {
+    match op {
+        CoralTranspose::NoTranspose        => (CoralTranspose::Transpose,   false),
+        CoralTranspose::Transpose          => (CoralTranspose::NoTranspose, false),
+        CoralTranspose::ConjugateTranspose => (CoralTranspose::NoTranspose, true),
+    }
+}
+
+/// `(len(x), len(y))` of `y := op(A) x` for a `rows x cols` matrix.
+#[inline]
+fn op_lens(op: CoralTranspose, rows: usize, cols: usize) -> (usize, usize) {
+    match op {
+        CoralTranspose::NoTranspose => (cols, rows),
+        _                           => (rows, cols),
+    }
+}
+
+#[inline(always)]
+fn cconj<T: Neg<Output = T>>([re, im]: [T; 2]) -> [T; 2] {
+    [re, -im]
+}
+
+// GEMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sgemv(
+    layout : CBLAS_LAYOUT,
+    trans  : CBLAS_TRANSPOSE,
+    m      : c_int,
+    n      : c_int,
+    alpha  : f32,
+    a      : *const f32,
+    lda    : c_int,
+    x      : *const f32,
+    incx   : c_int,
+    beta   : f32,
+    y      : *mut f32,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_sgemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 2) };
+
+    if m < 0 { return xerbla(NAME, 3); }
+    if n < 0 { return xerbla(NAME, 4); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+    if incy == 0 { return xerbla(NAME, 12); }
+
+    if m == 0 || n == 0 { return; }
+
+    // column-major view of A
+    let (rows, cols, trans) = if row_major {
+        (n as usize, m as usize, row_major_op(trans).0)
+    } else {
+        (m as usize, n as usize, trans)
+    };
+    let (len_x, len_y) = op_lens(trans, rows, cols);
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(rows, cols, lda, 1)) };
+    let xv     = unsafe { VecRef::new(x, len_x, incx, 1) };
+    let mut yv = unsafe { VecMut::new(y, len_y, incy, 1) };
+    let incy   = yv.inc();
+
+    l2::sgemv(trans, rows, cols, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dgemv(
+    layout : CBLAS_LAYOUT,
+    trans  : CBLAS_TRANSPOSE,
+    m      : c_int,
+    n      : c_int,
+    alpha  : f64,
+    a      : *const f64,
+    lda    : c_int,
+    x      : *const f64,
+    incx   : c_int,
+    beta   : f64,
+    y      : *mut f64,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_dgemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 2) };
+
+    if m < 0 { return xerbla(NAME, 3); }
+    if n < 0 { return xerbla(NAME, 4); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+    if incy == 0 { return xerbla(NAME, 12); }
+
+    if m == 0 || n == 0 { return; }
+
+    // column-major view of A
+    let (rows, cols, trans) = if row_major {
+        (n as usize, m as usize, row_major_op(trans).0)
+    } else {
+        (m as usize, n as usize, trans)
+    };
+    let (len_x, len_y) = op_lens(trans, rows, cols);
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(rows, cols, lda, 1)) };
+    let xv     = unsafe { VecRef::new(x, len_x, incx, 1) };
+    let mut yv = unsafe { VecMut::new(y, len_y, incy, 1) };
+    let incy   = yv.inc();
+
+    l2::dgemv(trans, rows, cols, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cgemv(
+    layout : CBLAS_LAYOUT,
+    trans  : CBLAS_TRANSPOSE,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *const c_void,
+    incx   : c_int,
+    beta   : *const c_void,
+    y      : *mut c_void,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_cgemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 2) };
+
+    if m < 0 { return xerbla(NAME, 3); }
+    if n < 0 { return xerbla(NAME, 4); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+    if incy == 0 { return xerbla(NAME, 12); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let beta  = unsafe { *(beta as *const [f32; 2]) };
+    let (a, x, y) = (a as *const f32, x as *const f32, y as *mut f32);
+
+    // column-major view of A
+    let (rows, cols, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (n as usize, m as usize, op, cj)
+    } else {
+        (m as usize, n as usize, trans, false)
+    };
+    let (len_x, len_y) = op_lens(trans, rows, cols);
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(rows, cols, lda, 2)) };
+    let xv     = unsafe { VecRef::new(x, len_x, incx, 2) };
+    let mut yv = unsafe { VecMut::new(y, len_y, incy, 2) };
+    let incy   = yv.inc();
+
+    if conj_ {
+        // conj(y) := conj(alpha) A conj(x) + conj(beta) conj(y)
+        let xc = conj(xv.slice(), len_x, xv.inc());
+
+        conj_in_place(yv.slice(), len_y, incy);
+        l2::cgemv(trans, rows, cols, cconj(alpha), a, lda, &xc, 1, cconj(beta), yv.slice(), incy);
+        conj_in_place(yv.slice(), len_y, incy);
+    } else {
+        l2::cgemv(trans, rows, cols, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zgemv(
+    layout : CBLAS_LAYOUT,
+    trans  : CBLAS_TRANSPOSE,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *const c_void,
+    incx   : c_int,
+    beta   : *const c_void,
+    y      : *mut c_void,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_zgemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 2) };
+
+    if m < 0 { return xerbla(NAME, 3); }
+    if n < 0 { return xerbla(NAME, 4); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+    if incy == 0 { return xerbla(NAME, 12); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let beta  = unsafe { *(beta as *const [f64; 2]) };
+    let (a, x, y) = (a as *const f64, x as *const f64, y as *mut f64);
+
+    // column-major view of A
+    let (rows, cols, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (n as usize, m as usize, op, cj)
+    } else {
+        (m as usize, n as usize, trans, false)
+    };
+    let (len_x, len_y) = op_lens(trans, rows, cols);
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(rows, cols, lda, 2)) };
+    let xv     = unsafe { VecRef::new(x, len_x, incx, 2) };
+    let mut yv = unsafe { VecMut::new(y, len_y, incy, 2) };
+    let incy   = yv.inc();
+
+    if conj_ {
+        // conj(y) := conj(alpha) A conj(x) + conj(beta) conj(y)
+        let xc = conj(xv.slice(), len_x, xv.inc());
+
+        conj_in_place(yv.slice(), len_y, incy);
+        l2::zgemv(trans, rows, cols, cconj(alpha), a, lda, &xc, 1, cconj(beta), yv.slice(), incy);
+        conj_in_place(yv.slice(), len_y, incy);
+    } else {
+        l2::zgemv(trans, rows, cols, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+    }
+}
+
+// TRMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_strmv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const f32,
+    lda    : c_int,
+    x      : *mut f32,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_strmv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (uplo, trans) = if row_major {
+        (flip_uplo(uplo), row_major_op(trans).0)
+    } else {
+        (uplo, trans)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 1) };
+    let incx   = xv.inc();
+
+    l2::strmv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dtrmv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const f64,
+    lda    : c_int,
+    x      : *mut f64,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_dtrmv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (uplo, trans) = if row_major {
+        (flip_uplo(uplo), row_major_op(trans).0)
+    } else {
+        (uplo, trans)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 1) };
+    let incx   = xv.inc();
+
+    l2::dtrmv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ctrmv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *mut c_void,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_ctrmv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (a, x) = (a as *const f32, x as *mut f32);
+
+    let (uplo, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (flip_uplo(uplo), op, cj)
+    } else {
+        (uplo, trans, false)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 2) };
+    let incx   = xv.inc();
+
+    // conj(x) := A conj(x)
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+    l2::ctrmv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ztrmv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *mut c_void,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_ztrmv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (a, x) = (a as *const f64, x as *mut f64);
+
+    let (uplo, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (flip_uplo(uplo), op, cj)
+    } else {
+        (uplo, trans, false)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 2) };
+    let incx   = xv.inc();
+
+    // conj(x) := A conj(x)
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+    l2::ztrmv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+}
+
+// TRSV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_strsv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const f32,
+    lda    : c_int,
+    x      : *mut f32,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_strsv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (uplo, trans) = if row_major {
+        (flip_uplo(uplo), row_major_op(trans).0)
+    } else {
+        (uplo, trans)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 1) };
+    let incx   = xv.inc();
+
+    l2::strsv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dtrsv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const f64,
+    lda    : c_int,
+    x      : *mut f64,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_dtrsv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (uplo, trans) = if row_major {
+        (flip_uplo(uplo), row_major_op(trans).0)
+    } else {
+        (uplo, trans)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 1) };
+    let incx   = xv.inc();
+
+    l2::dtrsv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ctrsv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *mut c_void,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_ctrsv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (a, x) = (a as *const f32, x as *mut f32);
+
+    let (uplo, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (flip_uplo(uplo), op, cj)
+    } else {
+        (uplo, trans, false)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 2) };
+    let incx   = xv.inc();
+
+    // conj(x) := A^{-1} conj(x)
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+    l2::ctrsv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ztrsv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    trans  : CBLAS_TRANSPOSE,
+    diag   : CBLAS_DIAG,
+    n      : c_int,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *mut c_void,
+    incx   : c_int,
+) {
+    const NAME: &str = "cblas_ztrsv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+    let Some(trans)     = transpose(trans)  else { return xerbla(NAME, 3) };
+    let Some(diag)      = to_diag(diag)     else { return xerbla(NAME, 4) };
+
+    if n < 0 { return xerbla(NAME, 5); }
+    if lda < n.max(1) { return xerbla(NAME, 7); }
+    if incx == 0 { return xerbla(NAME, 9); }
+
+    if n == 0 { return; }
+
+    let (a, x) = (a as *const f64, x as *mut f64);
+
+    let (uplo, trans, conj_) = if row_major {
+        let (op, cj) = row_major_op(trans);
+        (flip_uplo(uplo), op, cj)
+    } else {
+        (uplo, trans, false)
+    };
+    let n   = n as usize;
+    let lda = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let mut xv = unsafe { VecMut::new(x, n, incx, 2) };
+    let incx   = xv.inc();
+
+    // conj(x) := A^{-1} conj(x)
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+    l2::ztrsv(uplo, trans, diag, n, a, lda, xv.slice(), incx);
+    if conj_ { conj_in_place(xv.slice(), n, incx); }
+}
+
+// SYMV, HEMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ssymv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f32,
+    a      : *const f32,
+    lda    : c_int,
+    x      : *const f32,
+    incx   : c_int,
+    beta   : f32,
+    y      : *mut f32,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_ssymv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if lda < n.max(1) { return xerbla(NAME, 6); }
+    if incx == 0 { return xerbla(NAME, 8); }
+    if incy == 0 { return xerbla(NAME, 11); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let xv     = unsafe { VecRef::new(x, n, incx, 1) };
+    let mut yv = unsafe { VecMut::new(y, n, incy, 1) };
+    let incy   = yv.inc();
+
+    l2::ssymv(uplo, n, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dsymv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f64,
+    a      : *const f64,
+    lda    : c_int,
+    x      : *const f64,
+    incx   : c_int,
+    beta   : f64,
+    y      : *mut f64,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_dsymv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if lda < n.max(1) { return xerbla(NAME, 6); }
+    if incx == 0 { return xerbla(NAME, 8); }
+    if incy == 0 { return xerbla(NAME, 11); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 1)) };
+    let xv     = unsafe { VecRef::new(x, n, incx, 1) };
+    let mut yv = unsafe { VecMut::new(y, n, incy, 1) };
+    let incy   = yv.inc();
+
+    l2::dsymv(uplo, n, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_chemv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : *const c_void,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *const c_void,
+    incx   : c_int,
+    beta   : *const c_void,
+    y      : *mut c_void,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_chemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if lda < n.max(1) { return xerbla(NAME, 6); }
+    if incx == 0 { return xerbla(NAME, 8); }
+    if incy == 0 { return xerbla(NAME, 11); }
+
+    if n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let beta  = unsafe { *(beta as *const [f32; 2]) };
+    let (a, x, y) = (a as *const f32, x as *const f32, y as *mut f32);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let xv     = unsafe { VecRef::new(x, n, incx, 2) };
+    let mut yv = unsafe { VecMut::new(y, n, incy, 2) };
+    let incy   = yv.inc();
+
+    if row_major {
+        // the view holds conj(A); conj(y) := conj(alpha) A conj(x) + conj(beta) conj(y)
+        let xc = conj(xv.slice(), n, xv.inc());
+
+        conj_in_place(yv.slice(), n, incy);
+        l2::chemv(uplo, n, cconj(alpha), a, lda, &xc, 1, cconj(beta), yv.slice(), incy);
+        conj_in_place(yv.slice(), n, incy);
+    } else {
+        l2::chemv(uplo, n, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zhemv(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : *const c_void,
+    a      : *const c_void,
+    lda    : c_int,
+    x      : *const c_void,
+    incx   : c_int,
+    beta   : *const c_void,
+    y      : *mut c_void,
+    incy   : c_int,
+) {
+    const NAME: &str = "cblas_zhemv";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if lda < n.max(1) { return xerbla(NAME, 6); }
+    if incx == 0 { return xerbla(NAME, 8); }
+    if incy == 0 { return xerbla(NAME, 11); }
+
+    if n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let beta  = unsafe { *(beta as *const [f64; 2]) };
+    let (a, x, y) = (a as *const f64, x as *const f64, y as *mut f64);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a      = unsafe { slice(a, mat_len(n, n, lda, 2)) };
+    let xv     = unsafe { VecRef::new(x, n, incx, 2) };
+    let mut yv = unsafe { VecMut::new(y, n, incy, 2) };
+    let incy   = yv.inc();
+
+    if row_major {
+        // the view holds conj(A); conj(y) := conj(alpha) A conj(x) + conj(beta) conj(y)
+        let xc = conj(xv.slice(), n, xv.inc());
+
+        conj_in_place(yv.slice(), n, incy);
+        l2::zhemv(uplo, n, cconj(alpha), a, lda, &xc, 1, cconj(beta), yv.slice(), incy);
+        conj_in_place(yv.slice(), n, incy);
+    } else {
+        l2::zhemv(uplo, n, alpha, a, lda, xv.slice(), xv.inc(), beta, yv.slice(), incy);
+    }
+}
+
+// GER
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sger(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : f32,
+    x      : *const f32,
+    incx   : c_int,
+    y      : *const f32,
+    incy   : c_int,
+    a      : *mut f32,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_sger";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 1) };
+    let yv = unsafe { VecRef::new(y, n, incy, 1) };
+
+    if row_major {
+        // A^T += alpha y x^T
+        let a = unsafe { slice_mut(a, mat_len(n, m, lda, 1)) };
+
+        l2::sger(n, m, alpha, yv.slice(), yv.inc(), xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 1)) };
+
+        l2::sger(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dger(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : f64,
+    x      : *const f64,
+    incx   : c_int,
+    y      : *const f64,
+    incy   : c_int,
+    a      : *mut f64,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_dger";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 1) };
+    let yv = unsafe { VecRef::new(y, n, incy, 1) };
+
+    if row_major {
+        // A^T += alpha y x^T
+        let a = unsafe { slice_mut(a, mat_len(n, m, lda, 1)) };
+
+        l2::dger(n, m, alpha, yv.slice(), yv.inc(), xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 1)) };
+
+        l2::dger(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cgeru(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_cgeru";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let (x, y, a) = (x as *const f32, y as *const f32, a as *mut f32);
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // A^T += alpha y x^T
+        let a = unsafe { slice_mut(a, mat_len(n, m, lda, 2)) };
+
+        l2::cgeru(n, m, alpha, yv.slice(), yv.inc(), xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 2)) };
+
+        l2::cgeru(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cgerc(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_cgerc";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let (x, y, a) = (x as *const f32, y as *const f32, a as *mut f32);
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // A^T += alpha conj(y) x^T
+        let a  = unsafe { slice_mut(a, mat_len(n, m, lda, 2)) };
+        let yc = conj(yv.slice(), n, yv.inc());
+
+        l2::cgeru(n, m, alpha, &yc, 1, xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 2)) };
+
+        l2::cgerc(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zgeru(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_zgeru";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let (x, y, a) = (x as *const f64, y as *const f64, a as *mut f64);
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // A^T += alpha y x^T
+        let a = unsafe { slice_mut(a, mat_len(n, m, lda, 2)) };
+
+        l2::zgeru(n, m, alpha, yv.slice(), yv.inc(), xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 2)) };
+
+        l2::zgeru(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zgerc(
+    layout : CBLAS_LAYOUT,
+    m      : c_int,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_zgerc";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+
+    if m < 0 { return xerbla(NAME, 2); }
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < (if row_major { n } else { m }).max(1) { return xerbla(NAME, 10); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let (x, y, a) = (x as *const f64, y as *const f64, a as *mut f64);
+
+    let (m, n) = (m as usize, n as usize);
+    let lda    = lda as usize;
+
+    let xv = unsafe { VecRef::new(x, m, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // A^T += alpha conj(y) x^T
+        let a  = unsafe { slice_mut(a, mat_len(n, m, lda, 2)) };
+        let yc = conj(yv.slice(), n, yv.inc());
+
+        l2::zgeru(n, m, alpha, &yc, 1, xv.slice(), xv.inc(), a, lda);
+    } else {
+        let a = unsafe { slice_mut(a, mat_len(m, n, lda, 2)) };
+
+        l2::zgerc(m, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+// SYR, HER
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ssyr(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f32,
+    x      : *const f32,
+    incx   : c_int,
+    a      : *mut f32,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_ssyr";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if lda < n.max(1) { return xerbla(NAME, 8); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 1)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 1) };
+
+    l2::ssyr(uplo, n, alpha, xv.slice(), xv.inc(), a, lda);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dsyr(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f64,
+    x      : *const f64,
+    incx   : c_int,
+    a      : *mut f64,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_dsyr";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if lda < n.max(1) { return xerbla(NAME, 8); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 1)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 1) };
+
+    l2::dsyr(uplo, n, alpha, xv.slice(), xv.inc(), a, lda);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cher(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f32,
+    x      : *const c_void,
+    incx   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_cher";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if lda < n.max(1) { return xerbla(NAME, 8); }
+
+    if n == 0 { return; }
+
+    let (x, a) = (x as *const f32, a as *mut f32);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 2)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 2) };
+
+    if row_major {
+        // the view holds conj(A); conj(A) += alpha conj(x) conj(x)^H
+        let xc = conj(xv.slice(), n, xv.inc());
+
+        l2::cher(uplo, n, alpha, &xc, 1, a, lda);
+    } else {
+        l2::cher(uplo, n, alpha, xv.slice(), xv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zher(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f64,
+    x      : *const c_void,
+    incx   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_zher";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if lda < n.max(1) { return xerbla(NAME, 8); }
+
+    if n == 0 { return; }
+
+    let (x, a) = (x as *const f64, a as *mut f64);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 2)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 2) };
+
+    if row_major {
+        // the view holds conj(A); conj(A) += alpha conj(x) conj(x)^H
+        let xc = conj(xv.slice(), n, xv.inc());
+
+        l2::zher(uplo, n, alpha, &xc, 1, a, lda);
+    } else {
+        l2::zher(uplo, n, alpha, xv.slice(), xv.inc(), a, lda);
+    }
+}
+
+// SYR2, HER2
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_ssyr2(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f32,
+    x      : *const f32,
+    incx   : c_int,
+    y      : *const f32,
+    incy   : c_int,
+    a      : *mut f32,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_ssyr2";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < n.max(1) { return xerbla(NAME, 10); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 1)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 1) };
+    let yv = unsafe { VecRef::new(y, n, incy, 1) };
+
+    l2::ssyr2(uplo, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dsyr2(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : f64,
+    x      : *const f64,
+    incx   : c_int,
+    y      : *const f64,
+    incy   : c_int,
+    a      : *mut f64,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_dsyr2";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < n.max(1) { return xerbla(NAME, 10); }
+
+    if n == 0 { return; }
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 1)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 1) };
+    let yv = unsafe { VecRef::new(y, n, incy, 1) };
+
+    l2::dsyr2(uplo, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cher2(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_cher2";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < n.max(1) { return xerbla(NAME, 10); }
+
+    if n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let (x, y, a) = (x as *const f32, y as *const f32, a as *mut f32);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 2)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // the view holds conj(A);
+        // conj(A) += alpha conj(y) conj(x)^H + conj(alpha) conj(x) conj(y)^H
+        let xc = conj(xv.slice(), n, xv.inc());
+        let yc = conj(yv.slice(), n, yv.inc());
+
+        l2::cher2(uplo, n, alpha, &yc, 1, &xc, 1, a, lda);
+    } else {
+        l2::cher2(uplo, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zher2(
+    layout : CBLAS_LAYOUT,
+    uplo   : CBLAS_UPLO,
+    n      : c_int,
+    alpha  : *const c_void,
+    x      : *const c_void,
+    incx   : c_int,
+    y      : *const c_void,
+    incy   : c_int,
+    a      : *mut c_void,
+    lda    : c_int,
+) {
+    const NAME: &str = "cblas_zher2";
+
+    let Some(row_major) = row_major(layout) else { return xerbla(NAME, 1) };
+    let Some(uplo)      = to_uplo(uplo)     else { return xerbla(NAME, 2) };
+
+    if n < 0 { return xerbla(NAME, 3); }
+    if incx == 0 { return xerbla(NAME, 6); }
+    if incy == 0 { return xerbla(NAME, 8); }
+    if lda < n.max(1) { return xerbla(NAME, 10); }
+
+    if n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let (x, y, a) = (x as *const f64, y as *const f64, a as *mut f64);
+
+    let uplo = if row_major { flip_uplo(uplo) } else { uplo };
+    let n    = n as usize;
+    let lda  = lda as usize;
+
+    let a  = unsafe { slice_mut(a, mat_len(n, n, lda, 2)) };
+    let xv = unsafe { VecRef::new(x, n, incx, 2) };
+    let yv = unsafe { VecRef::new(y, n, incy, 2) };
+
+    if row_major {
+        // the view holds conj(A);
+        // conj(A) += alpha conj(y) conj(x)^H + conj(alpha) conj(x) conj(y)^H
+        let xc = conj(xv.slice(), n, xv.inc());
+        let yc = conj(yv.slice(), n, yv.inc());
+
+        l2::zher2(uplo, n, alpha, &yc, 1, &xc, 1, a, lda);
+    } else {
+        l2::zher2(uplo, n, alpha, xv.slice(), xv.inc(), yv.slice(), yv.inc(), a, lda);
+    }
+}
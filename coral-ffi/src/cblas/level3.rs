@@ -0,0 +1,253 @@
+//! CBLAS level3.
+//!
+//! `?gemm`. A row-major $C = \operatorname{op}(A)\operatorname{op}(B)$ is
+//! the column-major $C^T = \operatorname{op}(B)^T\operatorname{op}(A)^T$, so
+//! `CblasRowMajor` swaps the operands and the dimensions `m` and `n`.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_int, c_void};
+
+use coral_aarch64::enums::CoralTranspose;
+use coral_aarch64::level3 as l3;
+
+use crate::cblas::xerbla;
+use crate::cblas::enums::{
+    CBLAS_LAYOUT, CBLAS_TRANSPOSE,
+    CBLAS_ROW_MAJOR, CBLAS_COL_MAJOR,
+    transpose,
+};
+
+/// rows of the stored matrix `X`, column-major, when `op(X)` is `rows x cols`.
+#[inline]
+fn stored_rows(op: CoralTranspose, rows: c_int, cols: c_int) -> c_int {
+    match op {
+        CoralTranspose::NoTranspose => rows,
+        _                           => cols,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_sgemm(
+    layout  : CBLAS_LAYOUT,
+    trans_a : CBLAS_TRANSPOSE,
+    trans_b : CBLAS_TRANSPOSE,
+    m       : c_int,
+    n       : c_int,
+    k       : c_int,
+    alpha   : f32,
+    a       : *const f32,
+    lda     : c_int,
+    b       : *const f32,
+    ldb     : c_int,
+    beta    : f32,
+    c       : *mut f32,
+    ldc     : c_int,
+) {
+    const NAME: &str = "cblas_sgemm";
+
+    let row_major = match layout {
+        CBLAS_ROW_MAJOR => true,
+        CBLAS_COL_MAJOR => false,
+        _               => return xerbla(NAME, 1),
+    };
+    let Some(op_a) = transpose(trans_a) else { return xerbla(NAME, 2) };
+    let Some(op_b) = transpose(trans_b) else { return xerbla(NAME, 3) };
+
+    if m < 0 { return xerbla(NAME, 4); }
+    if n < 0 { return xerbla(NAME, 5); }
+    if k < 0 { return xerbla(NAME, 6); }
+
+    // rows of the column-major views of A, B, C
+    let (rows_a, rows_b, rows_c) = if row_major {
+        (stored_rows(op_a, k, m), stored_rows(op_b, n, k), n)
+    } else {
+        (stored_rows(op_a, m, k), stored_rows(op_b, k, n), m)
+    };
+    if lda < rows_a.max(1) { return xerbla(NAME, 9); }
+    if ldb < rows_b.max(1) { return xerbla(NAME, 11); }
+    if ldc < rows_c.max(1) { return xerbla(NAME, 14); }
+
+    if m == 0 || n == 0 { return; }
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    if row_major {
+        // C^T := alpha op(B)^T op(A)^T + beta C^T
+        l3::sgemm(op_b, op_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc);
+    } else {
+        l3::sgemm(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_dgemm(
+    layout  : CBLAS_LAYOUT,
+    trans_a : CBLAS_TRANSPOSE,
+    trans_b : CBLAS_TRANSPOSE,
+    m       : c_int,
+    n       : c_int,
+    k       : c_int,
+    alpha   : f64,
+    a       : *const f64,
+    lda     : c_int,
+    b       : *const f64,
+    ldb     : c_int,
+    beta    : f64,
+    c       : *mut f64,
+    ldc     : c_int,
+) {
+    const NAME: &str = "cblas_dgemm";
+
+    let row_major = match layout {
+        CBLAS_ROW_MAJOR => true,
+        CBLAS_COL_MAJOR => false,
+        _               => return xerbla(NAME, 1),
+    };
+    let Some(op_a) = transpose(trans_a) else { return xerbla(NAME, 2) };
+    let Some(op_b) = transpose(trans_b) else { return xerbla(NAME, 3) };
+
+    if m < 0 { return xerbla(NAME, 4); }
+    if n < 0 { return xerbla(NAME, 5); }
+    if k < 0 { return xerbla(NAME, 6); }
+
+    // rows of the column-major views of A, B, C
+    let (rows_a, rows_b, rows_c) = if row_major {
+        (stored_rows(op_a, k, m), stored_rows(op_b, n, k), n)
+    } else {
+        (stored_rows(op_a, m, k), stored_rows(op_b, k, n), m)
+    };
+    if lda < rows_a.max(1) { return xerbla(NAME, 9); }
+    if ldb < rows_b.max(1) { return xerbla(NAME, 11); }
+    if ldc < rows_c.max(1) { return xerbla(NAME, 14); }
+
+    if m == 0 || n == 0 { return; }
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    if row_major {
+        // C^T := alpha op(B)^T op(A)^T + beta C^T
+        l3::dgemm(op_b, op_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc);
+    } else {
+        l3::dgemm(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_cgemm(
+    layout  : CBLAS_LAYOUT,
+    trans_a : CBLAS_TRANSPOSE,
+    trans_b : CBLAS_TRANSPOSE,
+    m       : c_int,
+    n       : c_int,
+    k       : c_int,
+    alpha   : *const c_void,
+    a       : *const c_void,
+    lda     : c_int,
+    b       : *const c_void,
+    ldb     : c_int,
+    beta    : *const c_void,
+    c       : *mut c_void,
+    ldc     : c_int,
+) {
+    const NAME: &str = "cblas_cgemm";
+
+    let row_major = match layout {
+        CBLAS_ROW_MAJOR => true,
+        CBLAS_COL_MAJOR => false,
+        _               => return xerbla(NAME, 1),
+    };
+    let Some(op_a) = transpose(trans_a) else { return xerbla(NAME, 2) };
+    let Some(op_b) = transpose(trans_b) else { return xerbla(NAME, 3) };
+
+    if m < 0 { return xerbla(NAME, 4); }
+    if n < 0 { return xerbla(NAME, 5); }
+    if k < 0 { return xerbla(NAME, 6); }
+
+    // rows of the column-major views of A, B, C
+    let (rows_a, rows_b, rows_c) = if row_major {
+        (stored_rows(op_a, k, m), stored_rows(op_b, n, k), n)
+    } else {
+        (stored_rows(op_a, m, k), stored_rows(op_b, k, n), m)
+    };
+    if lda < rows_a.max(1) { return xerbla(NAME, 9); }
+    if ldb < rows_b.max(1) { return xerbla(NAME, 11); }
+    if ldc < rows_c.max(1) { return xerbla(NAME, 14); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f32; 2]) };
+    let beta  = unsafe { *(beta  as *const [f32; 2]) };
+    let (a, b, c) = (a as *const f32, b as *const f32, c as *mut f32);
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    if row_major {
+        // C^T := alpha op(B)^T op(A)^T + beta C^T
+        l3::cgemm(op_b, op_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc);
+    } else {
+        l3::cgemm(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cblas_zgemm(
+    layout  : CBLAS_LAYOUT,
+    trans_a : CBLAS_TRANSPOSE,
+    trans_b : CBLAS_TRANSPOSE,
+    m       : c_int,
+    n       : c_int,
+    k       : c_int,
+    alpha   : *const c_void,
+    a       : *const c_void,
+    lda     : c_int,
+    b       : *const c_void,
+    ldb     : c_int,
+    beta    : *const c_void,
+    c       : *mut c_void,
+    ldc     : c_int,
+) {
+    const NAME: &str = "cblas_zgemm";
+
+    let row_major = match layout {
+        CBLAS_ROW_MAJOR => true,
+        CBLAS_COL_MAJOR => false,
+        _               => return xerbla(NAME, 1),
+    };
+    let Some(op_a) = transpose(trans_a) else { return xerbla(NAME, 2) };
+    let Some(op_b) = transpose(trans_b) else { return xerbla(NAME, 3) };
+
+    if m < 0 { return xerbla(NAME, 4); }
+    if n < 0 { return xerbla(NAME, 5); }
+    if k < 0 { return xerbla(NAME, 6); }
+
+    // rows of the column-major views of A, B, C
+    let (rows_a, rows_b, rows_c) = if row_major {
+        (stored_rows(op_a, k, m), stored_rows(op_b, n, k), n)
+    } else {
+        (stored_rows(op_a, m, k), stored_rows(op_b, k, n), m)
+    };
+    if lda < rows_a.max(1) { return xerbla(NAME, 9); }
+    if ldb < rows_b.max(1) { return xerbla(NAME, 11); }
+    if ldc < rows_c.max(1) { return xerbla(NAME, 14); }
+
+    if m == 0 || n == 0 { return; }
+
+    let alpha = unsafe { *(alpha as *const [f64; 2]) };
+    let beta  = unsafe { *(beta  as *const [f64; 2]) };
+    let (a, b, c) = (a as *const f64, b as *const f64, c as *mut f64);
+
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+
+    if row_major {
+        // C^T := alpha op(B)^T op(A)^T + beta C^T
+        l3::zgemm(op_b, op_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc);
+    } else {
+        l3::zgemm(op_a, op_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+    }
+}
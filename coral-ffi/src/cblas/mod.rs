@@ -0,0 +1,35 @@
+//! CBLAS interface.
+//!
+//! `extern "C"` wrappers with the standard CBLAS names, signatures and enum
+//! values (see `include/cblas.h`), mapped onto `coral_aarch64`.
+//!
+//! - `CblasRowMajor` is handled by reinterpreting a row-major matrix as the
+//!   column-major storage of its transpose, as reference CBLAS does.
+//! - Complex scalars and arrays are `void *` to interleaved `[re, im]`.
+//! - Complex dot products return through an out-pointer (`cblas_?dot?_sub`).
+//! - Zero and negative increments follow reference BLAS semantics.
+//! - Invalid arguments are reported through [`crate::xerbla`] with their
+//!   position in the CBLAS call (the layout is parameter 1), and the routine
+//!   returns without touching its outputs.
+//!
+//! # Safety
+//! Every routine is `unsafe`: as in C, the caller guarantees that each
+//! pointer is valid for the elements the dimensions, leading dimensions and
+//! increments say the routine reads or writes, and that output arrays do not
+//! alias inputs.
+
+// the # Safety section above covers every exported routine
+#![allow(clippy::missing_safety_doc)]
+
+pub mod enums;
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+use core::ffi::c_int;
+
+/// reports an invalid argument; `pos` is 1-based within the CBLAS call.
+#[cold]
+pub(crate) fn xerbla(routine: &str, pos: c_int) {
+    crate::xerbla::report(routine, pos);
+}
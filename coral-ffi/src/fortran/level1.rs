@@ -0,0 +1,575 @@
+//! Fortran level1.
+//!
+//! Vector-vector routines. Thin wrappers over [`crate::cblas::level1`];
+//! `i?amax_` returns a 1-based index, `0` if `n < 1` or `incx <= 0`.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_int, c_void};
+
+use crate::cblas::level1::*;
+use crate::fortran::{Complex32, Complex64};
+
+// f32
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sdot_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+    y    : *const f32,
+    incy : *const c_int,
+) -> f32 {
+    unsafe { cblas_sdot(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sdsdot_(
+    n    : *const c_int,
+    sb   : *const f32,
+    x    : *const f32,
+    incx : *const c_int,
+    y    : *const f32,
+    incy : *const c_int,
+) -> f32 {
+    unsafe { cblas_sdsdot(*n, *sb, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dsdot_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+    y    : *const f32,
+    incy : *const c_int,
+) -> f64 {
+    unsafe { cblas_dsdot(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn snrm2_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+) -> f32 {
+    unsafe { cblas_snrm2(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sasum_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+) -> f32 {
+    unsafe { cblas_sasum(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn isamax_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+) -> c_int {
+    let (n, incx) = unsafe { (*n, *incx) };
+    if n < 1 || incx <= 0 { return 0; }
+
+    unsafe { cblas_isamax(n, x, incx) as c_int + 1 }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sswap_(
+    n    : *const c_int,
+    x    : *mut f32,
+    incx : *const c_int,
+    y    : *mut f32,
+    incy : *const c_int,
+) {
+    unsafe { cblas_sswap(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn scopy_(
+    n    : *const c_int,
+    x    : *const f32,
+    incx : *const c_int,
+    y    : *mut f32,
+    incy : *const c_int,
+) {
+    unsafe { cblas_scopy(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn saxpy_(
+    n     : *const c_int,
+    alpha : *const f32,
+    x     : *const f32,
+    incx  : *const c_int,
+    y     : *mut f32,
+    incy  : *const c_int,
+) {
+    unsafe { cblas_saxpy(*n, *alpha, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sscal_(
+    n     : *const c_int,
+    alpha : *const f32,
+    x     : *mut f32,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_sscal(*n, *alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn srot_(
+    n    : *const c_int,
+    x    : *mut f32,
+    incx : *const c_int,
+    y    : *mut f32,
+    incy : *const c_int,
+    c    : *const f32,
+    s    : *const f32,
+) {
+    unsafe { cblas_srot(*n, x, *incx, y, *incy, *c, *s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn srotm_(
+    n     : *const c_int,
+    x     : *mut f32,
+    incx  : *const c_int,
+    y     : *mut f32,
+    incy  : *const c_int,
+    param : *const f32,
+) {
+    unsafe { cblas_srotm(*n, x, *incx, y, *incy, param) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn srotg_(
+    a : *mut f32,
+    b : *mut f32,
+    c : *mut f32,
+    s : *mut f32,
+) {
+    unsafe { cblas_srotg(a, b, c, s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn srotmg_(
+    d1    : *mut f32,
+    d2    : *mut f32,
+    x1    : *mut f32,
+    y1    : *const f32,
+    param : *mut f32,
+) {
+    unsafe { cblas_srotmg(d1, d2, x1, *y1, param) }
+}
+
+// f64
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ddot_(
+    n    : *const c_int,
+    x    : *const f64,
+    incx : *const c_int,
+    y    : *const f64,
+    incy : *const c_int,
+) -> f64 {
+    unsafe { cblas_ddot(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dnrm2_(
+    n    : *const c_int,
+    x    : *const f64,
+    incx : *const c_int,
+) -> f64 {
+    unsafe { cblas_dnrm2(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dasum_(
+    n    : *const c_int,
+    x    : *const f64,
+    incx : *const c_int,
+) -> f64 {
+    unsafe { cblas_dasum(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn idamax_(
+    n    : *const c_int,
+    x    : *const f64,
+    incx : *const c_int,
+) -> c_int {
+    let (n, incx) = unsafe { (*n, *incx) };
+    if n < 1 || incx <= 0 { return 0; }
+
+    unsafe { cblas_idamax(n, x, incx) as c_int + 1 }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dswap_(
+    n    : *const c_int,
+    x    : *mut f64,
+    incx : *const c_int,
+    y    : *mut f64,
+    incy : *const c_int,
+) {
+    unsafe { cblas_dswap(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dcopy_(
+    n    : *const c_int,
+    x    : *const f64,
+    incx : *const c_int,
+    y    : *mut f64,
+    incy : *const c_int,
+) {
+    unsafe { cblas_dcopy(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn daxpy_(
+    n     : *const c_int,
+    alpha : *const f64,
+    x     : *const f64,
+    incx  : *const c_int,
+    y     : *mut f64,
+    incy  : *const c_int,
+) {
+    unsafe { cblas_daxpy(*n, *alpha, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dscal_(
+    n     : *const c_int,
+    alpha : *const f64,
+    x     : *mut f64,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_dscal(*n, *alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn drot_(
+    n    : *const c_int,
+    x    : *mut f64,
+    incx : *const c_int,
+    y    : *mut f64,
+    incy : *const c_int,
+    c    : *const f64,
+    s    : *const f64,
+) {
+    unsafe { cblas_drot(*n, x, *incx, y, *incy, *c, *s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn drotm_(
+    n     : *const c_int,
+    x     : *mut f64,
+    incx  : *const c_int,
+    y     : *mut f64,
+    incy  : *const c_int,
+    param : *const f64,
+) {
+    unsafe { cblas_drotm(*n, x, *incx, y, *incy, param) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn drotg_(
+    a : *mut f64,
+    b : *mut f64,
+    c : *mut f64,
+    s : *mut f64,
+) {
+    unsafe { cblas_drotg(a, b, c, s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn drotmg_(
+    d1    : *mut f64,
+    d2    : *mut f64,
+    x1    : *mut f64,
+    y1    : *const f64,
+    param : *mut f64,
+) {
+    unsafe { cblas_drotmg(d1, d2, x1, *y1, param) }
+}
+
+// complex f32
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cdotu_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *const c_void,
+    incy : *const c_int,
+) -> Complex32 {
+    let mut dot = [0.0; 2];
+    unsafe { cblas_cdotu_sub(*n, x, *incx, y, *incy, dot.as_mut_ptr() as *mut c_void) };
+
+    Complex32 { re: dot[0], im: dot[1] }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cdotc_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *const c_void,
+    incy : *const c_int,
+) -> Complex32 {
+    let mut dot = [0.0; 2];
+    unsafe { cblas_cdotc_sub(*n, x, *incx, y, *incy, dot.as_mut_ptr() as *mut c_void) };
+
+    Complex32 { re: dot[0], im: dot[1] }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn scnrm2_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> f32 {
+    unsafe { cblas_scnrm2(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn scasum_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> f32 {
+    unsafe { cblas_scasum(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn icamax_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> c_int {
+    let (n, incx) = unsafe { (*n, *incx) };
+    if n < 1 || incx <= 0 { return 0; }
+
+    unsafe { cblas_icamax(n, x, incx) as c_int + 1 }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cswap_(
+    n    : *const c_int,
+    x    : *mut c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+) {
+    unsafe { cblas_cswap(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ccopy_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+) {
+    unsafe { cblas_ccopy(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn caxpy_(
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *mut c_void,
+    incy  : *const c_int,
+) {
+    unsafe { cblas_caxpy(*n, alpha, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cscal_(
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *mut c_void,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_cscal(*n, alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn csscal_(
+    n     : *const c_int,
+    alpha : *const f32,
+    x     : *mut c_void,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_csscal(*n, *alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn csrot_(
+    n    : *const c_int,
+    x    : *mut c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+    c    : *const f32,
+    s    : *const f32,
+) {
+    unsafe { cblas_csrot(*n, x, *incx, y, *incy, *c, *s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn crotg_(
+    a : *mut c_void,
+    b : *const c_void,
+    c : *mut f32,
+    s : *mut c_void,
+) {
+    unsafe { cblas_crotg(a, b, c, s) }
+}
+
+// complex f64
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zdotu_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *const c_void,
+    incy : *const c_int,
+) -> Complex64 {
+    let mut dot = [0.0; 2];
+    unsafe { cblas_zdotu_sub(*n, x, *incx, y, *incy, dot.as_mut_ptr() as *mut c_void) };
+
+    Complex64 { re: dot[0], im: dot[1] }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zdotc_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *const c_void,
+    incy : *const c_int,
+) -> Complex64 {
+    let mut dot = [0.0; 2];
+    unsafe { cblas_zdotc_sub(*n, x, *incx, y, *incy, dot.as_mut_ptr() as *mut c_void) };
+
+    Complex64 { re: dot[0], im: dot[1] }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dznrm2_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> f64 {
+    unsafe { cblas_dznrm2(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dzasum_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> f64 {
+    unsafe { cblas_dzasum(*n, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn izamax_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+) -> c_int {
+    let (n, incx) = unsafe { (*n, *incx) };
+    if n < 1 || incx <= 0 { return 0; }
+
+    unsafe { cblas_izamax(n, x, incx) as c_int + 1 }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zswap_(
+    n    : *const c_int,
+    x    : *mut c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+) {
+    unsafe { cblas_zswap(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zcopy_(
+    n    : *const c_int,
+    x    : *const c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+) {
+    unsafe { cblas_zcopy(*n, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zaxpy_(
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *mut c_void,
+    incy  : *const c_int,
+) {
+    unsafe { cblas_zaxpy(*n, alpha, x, *incx, y, *incy) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zscal_(
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *mut c_void,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_zscal(*n, alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zdscal_(
+    n     : *const c_int,
+    alpha : *const f64,
+    x     : *mut c_void,
+    incx  : *const c_int,
+) {
+    unsafe { cblas_zdscal(*n, *alpha, x, *incx) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zdrot_(
+    n    : *const c_int,
+    x    : *mut c_void,
+    incx : *const c_int,
+    y    : *mut c_void,
+    incy : *const c_int,
+    c    : *const f64,
+    s    : *const f64,
+) {
+    unsafe { cblas_zdrot(*n, x, *incx, y, *incy, *c, *s) }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zrotg_(
+    a : *mut c_void,
+    b : *const c_void,
+    c : *mut f64,
+    s : *mut c_void,
+) {
+    unsafe { cblas_zrotg(a, b, c, s) }
+}
@@ -0,0 +1,883 @@
+//! Fortran level2.
+//!
+//! Matrix-vector routines. Arguments are checked here, in reference BLAS
+//! order and numbering, then forwarded to the column-major
+//! [`crate::cblas::level2`] routine.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_char, c_int, c_void};
+
+use crate::cblas::enums::CBLAS_COL_MAJOR;
+use crate::cblas::level2::*;
+use crate::fortran::{transpose, uplo as to_uplo, diag as to_diag};
+use crate::xerbla::report;
+
+// GEMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sgemv_(
+    trans      : *const c_char,
+    m          : *const c_int,
+    n          : *const c_int,
+    alpha      : *const f32,
+    a          : *const f32,
+    lda        : *const c_int,
+    x          : *const f32,
+    incx       : *const c_int,
+    beta       : *const f32,
+    y          : *mut f32,
+    incy       : *const c_int,
+    _trans_len : usize,
+) {
+    const NAME: &str = "SGEMV";
+
+    let (m, n, lda, incx, incy) = unsafe { (*m, *n, *lda, *incx, *incy) };
+
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 1) };
+    if m < 0 { return report(NAME, 2); }
+    if n < 0 { return report(NAME, 3); }
+    if lda < m.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+    if incy == 0 { return report(NAME, 11); }
+
+    unsafe {
+        cblas_sgemv(CBLAS_COL_MAJOR, trans, m, n, *alpha, a, lda, x, incx, *beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dgemv_(
+    trans      : *const c_char,
+    m          : *const c_int,
+    n          : *const c_int,
+    alpha      : *const f64,
+    a          : *const f64,
+    lda        : *const c_int,
+    x          : *const f64,
+    incx       : *const c_int,
+    beta       : *const f64,
+    y          : *mut f64,
+    incy       : *const c_int,
+    _trans_len : usize,
+) {
+    const NAME: &str = "DGEMV";
+
+    let (m, n, lda, incx, incy) = unsafe { (*m, *n, *lda, *incx, *incy) };
+
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 1) };
+    if m < 0 { return report(NAME, 2); }
+    if n < 0 { return report(NAME, 3); }
+    if lda < m.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+    if incy == 0 { return report(NAME, 11); }
+
+    unsafe {
+        cblas_dgemv(CBLAS_COL_MAJOR, trans, m, n, *alpha, a, lda, x, incx, *beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cgemv_(
+    trans      : *const c_char,
+    m          : *const c_int,
+    n          : *const c_int,
+    alpha      : *const c_void,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *const c_void,
+    incx       : *const c_int,
+    beta       : *const c_void,
+    y          : *mut c_void,
+    incy       : *const c_int,
+    _trans_len : usize,
+) {
+    const NAME: &str = "CGEMV";
+
+    let (m, n, lda, incx, incy) = unsafe { (*m, *n, *lda, *incx, *incy) };
+
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 1) };
+    if m < 0 { return report(NAME, 2); }
+    if n < 0 { return report(NAME, 3); }
+    if lda < m.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+    if incy == 0 { return report(NAME, 11); }
+
+    unsafe {
+        cblas_cgemv(CBLAS_COL_MAJOR, trans, m, n, alpha, a, lda, x, incx, beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zgemv_(
+    trans      : *const c_char,
+    m          : *const c_int,
+    n          : *const c_int,
+    alpha      : *const c_void,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *const c_void,
+    incx       : *const c_int,
+    beta       : *const c_void,
+    y          : *mut c_void,
+    incy       : *const c_int,
+    _trans_len : usize,
+) {
+    const NAME: &str = "ZGEMV";
+
+    let (m, n, lda, incx, incy) = unsafe { (*m, *n, *lda, *incx, *incy) };
+
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 1) };
+    if m < 0 { return report(NAME, 2); }
+    if n < 0 { return report(NAME, 3); }
+    if lda < m.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+    if incy == 0 { return report(NAME, 11); }
+
+    unsafe {
+        cblas_zgemv(CBLAS_COL_MAJOR, trans, m, n, alpha, a, lda, x, incx, beta, y, incy);
+    }
+}
+
+// TRMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn strmv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const f32,
+    lda        : *const c_int,
+    x          : *mut f32,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "STRMV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_strmv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dtrmv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const f64,
+    lda        : *const c_int,
+    x          : *mut f64,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "DTRMV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_dtrmv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ctrmv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *mut c_void,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "CTRMV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_ctrmv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ztrmv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *mut c_void,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "ZTRMV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_ztrmv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+// TRSV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn strsv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const f32,
+    lda        : *const c_int,
+    x          : *mut f32,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "STRSV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_strsv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dtrsv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const f64,
+    lda        : *const c_int,
+    x          : *mut f64,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "DTRSV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_dtrsv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ctrsv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *mut c_void,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "CTRSV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_ctrsv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ztrsv_(
+    uplo       : *const c_char,
+    trans      : *const c_char,
+    diag       : *const c_char,
+    n          : *const c_int,
+    a          : *const c_void,
+    lda        : *const c_int,
+    x          : *mut c_void,
+    incx       : *const c_int,
+    _uplo_len  : usize,
+    _trans_len : usize,
+    _diag_len  : usize,
+) {
+    const NAME: &str = "ZTRSV";
+
+    let (n, lda, incx) = unsafe { (*n, *lda, *incx) };
+
+    let Some(uplo)  = (unsafe { to_uplo(uplo) })    else { return report(NAME, 1) };
+    let Some(trans) = (unsafe { transpose(trans) }) else { return report(NAME, 2) };
+    let Some(diag)  = (unsafe { to_diag(diag) })    else { return report(NAME, 3) };
+    if n < 0 { return report(NAME, 4); }
+    if lda < n.max(1) { return report(NAME, 6); }
+    if incx == 0 { return report(NAME, 8); }
+
+    unsafe {
+        cblas_ztrsv(CBLAS_COL_MAJOR, uplo, trans, diag, n, a, lda, x, incx);
+    }
+}
+
+// SYMV, HEMV
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ssymv_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f32,
+    a         : *const f32,
+    lda       : *const c_int,
+    x         : *const f32,
+    incx      : *const c_int,
+    beta      : *const f32,
+    y         : *mut f32,
+    incy      : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "SSYMV";
+
+    let (n, lda, incx, incy) = unsafe { (*n, *lda, *incx, *incy) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if lda < n.max(1) { return report(NAME, 5); }
+    if incx == 0 { return report(NAME, 7); }
+    if incy == 0 { return report(NAME, 10); }
+
+    unsafe {
+        cblas_ssymv(CBLAS_COL_MAJOR, uplo, n, *alpha, a, lda, x, incx, *beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dsymv_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f64,
+    a         : *const f64,
+    lda       : *const c_int,
+    x         : *const f64,
+    incx      : *const c_int,
+    beta      : *const f64,
+    y         : *mut f64,
+    incy      : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "DSYMV";
+
+    let (n, lda, incx, incy) = unsafe { (*n, *lda, *incx, *incy) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if lda < n.max(1) { return report(NAME, 5); }
+    if incx == 0 { return report(NAME, 7); }
+    if incy == 0 { return report(NAME, 10); }
+
+    unsafe {
+        cblas_dsymv(CBLAS_COL_MAJOR, uplo, n, *alpha, a, lda, x, incx, *beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn chemv_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const c_void,
+    a         : *const c_void,
+    lda       : *const c_int,
+    x         : *const c_void,
+    incx      : *const c_int,
+    beta      : *const c_void,
+    y         : *mut c_void,
+    incy      : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "CHEMV";
+
+    let (n, lda, incx, incy) = unsafe { (*n, *lda, *incx, *incy) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if lda < n.max(1) { return report(NAME, 5); }
+    if incx == 0 { return report(NAME, 7); }
+    if incy == 0 { return report(NAME, 10); }
+
+    unsafe {
+        cblas_chemv(CBLAS_COL_MAJOR, uplo, n, alpha, a, lda, x, incx, beta, y, incy);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zhemv_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const c_void,
+    a         : *const c_void,
+    lda       : *const c_int,
+    x         : *const c_void,
+    incx      : *const c_int,
+    beta      : *const c_void,
+    y         : *mut c_void,
+    incy      : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "ZHEMV";
+
+    let (n, lda, incx, incy) = unsafe { (*n, *lda, *incx, *incy) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if lda < n.max(1) { return report(NAME, 5); }
+    if incx == 0 { return report(NAME, 7); }
+    if incy == 0 { return report(NAME, 10); }
+
+    unsafe {
+        cblas_zhemv(CBLAS_COL_MAJOR, uplo, n, alpha, a, lda, x, incx, beta, y, incy);
+    }
+}
+
+// GER
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sger_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const f32,
+    x     : *const f32,
+    incx  : *const c_int,
+    y     : *const f32,
+    incy  : *const c_int,
+    a     : *mut f32,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "SGER";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_sger(CBLAS_COL_MAJOR, m, n, *alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dger_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const f64,
+    x     : *const f64,
+    incx  : *const c_int,
+    y     : *const f64,
+    incy  : *const c_int,
+    a     : *mut f64,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "DGER";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_dger(CBLAS_COL_MAJOR, m, n, *alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cgeru_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *const c_void,
+    incy  : *const c_int,
+    a     : *mut c_void,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "CGERU";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_cgeru(CBLAS_COL_MAJOR, m, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cgerc_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *const c_void,
+    incy  : *const c_int,
+    a     : *mut c_void,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "CGERC";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_cgerc(CBLAS_COL_MAJOR, m, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zgeru_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *const c_void,
+    incy  : *const c_int,
+    a     : *mut c_void,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "ZGERU";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_zgeru(CBLAS_COL_MAJOR, m, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zgerc_(
+    m     : *const c_int,
+    n     : *const c_int,
+    alpha : *const c_void,
+    x     : *const c_void,
+    incx  : *const c_int,
+    y     : *const c_void,
+    incy  : *const c_int,
+    a     : *mut c_void,
+    lda   : *const c_int,
+) {
+    const NAME: &str = "ZGERC";
+
+    let (m, n, incx, incy, lda) = unsafe { (*m, *n, *incx, *incy, *lda) };
+
+    if m < 0 { return report(NAME, 1); }
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < m.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_zgerc(CBLAS_COL_MAJOR, m, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+// SYR, HER
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ssyr_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f32,
+    x         : *const f32,
+    incx      : *const c_int,
+    a         : *mut f32,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "SSYR";
+
+    let (n, incx, lda) = unsafe { (*n, *incx, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if lda < n.max(1) { return report(NAME, 7); }
+
+    unsafe {
+        cblas_ssyr(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dsyr_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f64,
+    x         : *const f64,
+    incx      : *const c_int,
+    a         : *mut f64,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "DSYR";
+
+    let (n, incx, lda) = unsafe { (*n, *incx, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if lda < n.max(1) { return report(NAME, 7); }
+
+    unsafe {
+        cblas_dsyr(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cher_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f32,
+    x         : *const c_void,
+    incx      : *const c_int,
+    a         : *mut c_void,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "CHER";
+
+    let (n, incx, lda) = unsafe { (*n, *incx, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if lda < n.max(1) { return report(NAME, 7); }
+
+    unsafe {
+        cblas_cher(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zher_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f64,
+    x         : *const c_void,
+    incx      : *const c_int,
+    a         : *mut c_void,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "ZHER";
+
+    let (n, incx, lda) = unsafe { (*n, *incx, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if lda < n.max(1) { return report(NAME, 7); }
+
+    unsafe {
+        cblas_zher(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, a, lda);
+    }
+}
+
+// SYR2, HER2
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ssyr2_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f32,
+    x         : *const f32,
+    incx      : *const c_int,
+    y         : *const f32,
+    incy      : *const c_int,
+    a         : *mut f32,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "SSYR2";
+
+    let (n, incx, incy, lda) = unsafe { (*n, *incx, *incy, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < n.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_ssyr2(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dsyr2_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const f64,
+    x         : *const f64,
+    incx      : *const c_int,
+    y         : *const f64,
+    incy      : *const c_int,
+    a         : *mut f64,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "DSYR2";
+
+    let (n, incx, incy, lda) = unsafe { (*n, *incx, *incy, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < n.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_dsyr2(CBLAS_COL_MAJOR, uplo, n, *alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cher2_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const c_void,
+    x         : *const c_void,
+    incx      : *const c_int,
+    y         : *const c_void,
+    incy      : *const c_int,
+    a         : *mut c_void,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "CHER2";
+
+    let (n, incx, incy, lda) = unsafe { (*n, *incx, *incy, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < n.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_cher2(CBLAS_COL_MAJOR, uplo, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zher2_(
+    uplo      : *const c_char,
+    n         : *const c_int,
+    alpha     : *const c_void,
+    x         : *const c_void,
+    incx      : *const c_int,
+    y         : *const c_void,
+    incy      : *const c_int,
+    a         : *mut c_void,
+    lda       : *const c_int,
+    _uplo_len : usize,
+) {
+    const NAME: &str = "ZHER2";
+
+    let (n, incx, incy, lda) = unsafe { (*n, *incx, *incy, *lda) };
+
+    let Some(uplo) = (unsafe { to_uplo(uplo) }) else { return report(NAME, 1) };
+    if n < 0 { return report(NAME, 2); }
+    if incx == 0 { return report(NAME, 5); }
+    if incy == 0 { return report(NAME, 7); }
+    if lda < n.max(1) { return report(NAME, 9); }
+
+    unsafe {
+        cblas_zher2(CBLAS_COL_MAJOR, uplo, n, alpha, x, incx, y, incy, a, lda);
+    }
+}
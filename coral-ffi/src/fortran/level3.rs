@@ -0,0 +1,191 @@
+//! Fortran level3.
+//!
+//! `?gemm_`. Arguments are checked here, in reference BLAS order and
+//! numbering, then forwarded to the column-major [`crate::cblas::level3`]
+//! routine.
+//!
+//! # Author
+//! Deval Deliwala
+
+use core::ffi::{c_char, c_int, c_void};
+
+use crate::cblas::enums::{CBLAS_COL_MAJOR, CBLAS_NO_TRANS};
+use crate::cblas::level3::*;
+use crate::fortran::transpose;
+use crate::xerbla::report;
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sgemm_(
+    transa      : *const c_char,
+    transb      : *const c_char,
+    m           : *const c_int,
+    n           : *const c_int,
+    k           : *const c_int,
+    alpha       : *const f32,
+    a           : *const f32,
+    lda         : *const c_int,
+    b           : *const f32,
+    ldb         : *const c_int,
+    beta        : *const f32,
+    c           : *mut f32,
+    ldc         : *const c_int,
+    _transa_len : usize,
+    _transb_len : usize,
+) {
+    const NAME: &str = "SGEMM";
+
+    let (m, n, k)       = unsafe { (*m, *n, *k) };
+    let (lda, ldb, ldc) = unsafe { (*lda, *ldb, *ldc) };
+
+    let Some(op_a) = (unsafe { transpose(transa) }) else { return report(NAME, 1) };
+    let Some(op_b) = (unsafe { transpose(transb) }) else { return report(NAME, 2) };
+
+    let nrow_a = if op_a == CBLAS_NO_TRANS { m } else { k };
+    let nrow_b = if op_b == CBLAS_NO_TRANS { k } else { n };
+
+    if m < 0 { return report(NAME, 3); }
+    if n < 0 { return report(NAME, 4); }
+    if k < 0 { return report(NAME, 5); }
+    if lda < nrow_a.max(1) { return report(NAME, 8); }
+    if ldb < nrow_b.max(1) { return report(NAME, 10); }
+    if ldc < m.max(1) { return report(NAME, 13); }
+
+    unsafe {
+        cblas_sgemm(
+            CBLAS_COL_MAJOR, op_a, op_b, m, n, k,
+            *alpha, a, lda, b, ldb, *beta, c, ldc,
+        );
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dgemm_(
+    transa      : *const c_char,
+    transb      : *const c_char,
+    m           : *const c_int,
+    n           : *const c_int,
+    k           : *const c_int,
+    alpha       : *const f64,
+    a           : *const f64,
+    lda         : *const c_int,
+    b           : *const f64,
+    ldb         : *const c_int,
+    beta        : *const f64,
+    c           : *mut f64,
+    ldc         : *const c_int,
+    _transa_len : usize,
+    _transb_len : usize,
+) {
+    const NAME: &str = "DGEMM";
+
+    let (m, n, k)       = unsafe { (*m, *n, *k) };
+    let (lda, ldb, ldc) = unsafe { (*lda, *ldb, *ldc) };
+
+    let Some(op_a) = (unsafe { transpose(transa) }) else { return report(NAME, 1) };
+    let Some(op_b) = (unsafe { transpose(transb) }) else { return report(NAME, 2) };
+
+    let nrow_a = if op_a == CBLAS_NO_TRANS { m } else { k };
+    let nrow_b = if op_b == CBLAS_NO_TRANS { k } else { n };
+
+    if m < 0 { return report(NAME, 3); }
+    if n < 0 { return report(NAME, 4); }
+    if k < 0 { return report(NAME, 5); }
+    if lda < nrow_a.max(1) { return report(NAME, 8); }
+    if ldb < nrow_b.max(1) { return report(NAME, 10); }
+    if ldc < m.max(1) { return report(NAME, 13); }
+
+    unsafe {
+        cblas_dgemm(
+            CBLAS_COL_MAJOR, op_a, op_b, m, n, k,
+            *alpha, a, lda, b, ldb, *beta, c, ldc,
+        );
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn cgemm_(
+    transa      : *const c_char,
+    transb      : *const c_char,
+    m           : *const c_int,
+    n           : *const c_int,
+    k           : *const c_int,
+    alpha       : *const c_void,
+    a           : *const c_void,
+    lda         : *const c_int,
+    b           : *const c_void,
+    ldb         : *const c_int,
+    beta        : *const c_void,
+    c           : *mut c_void,
+    ldc         : *const c_int,
+    _transa_len : usize,
+    _transb_len : usize,
+) {
+    const NAME: &str = "CGEMM";
+
+    let (m, n, k)       = unsafe { (*m, *n, *k) };
+    let (lda, ldb, ldc) = unsafe { (*lda, *ldb, *ldc) };
+
+    let Some(op_a) = (unsafe { transpose(transa) }) else { return report(NAME, 1) };
+    let Some(op_b) = (unsafe { transpose(transb) }) else { return report(NAME, 2) };
+
+    let nrow_a = if op_a == CBLAS_NO_TRANS { m } else { k };
+    let nrow_b = if op_b == CBLAS_NO_TRANS { k } else { n };
+
+    if m < 0 { return report(NAME, 3); }
+    if n < 0 { return report(NAME, 4); }
+    if k < 0 { return report(NAME, 5); }
+    if lda < nrow_a.max(1) { return report(NAME, 8); }
+    if ldb < nrow_b.max(1) { return report(NAME, 10); }
+    if ldc < m.max(1) { return report(NAME, 13); }
+
+    unsafe {
+        cblas_cgemm(
+            CBLAS_COL_MAJOR, op_a, op_b, m, n, k,
+            alpha, a, lda, b, ldb, beta, c, ldc,
+        );
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn zgemm_(
+    transa      : *const c_char,
+    transb      : *const c_char,
+    m           : *const c_int,
+    n           : *const c_int,
+    k           : *const c_int,
+    alpha       : *const c_void,
+    a           : *const c_void,
+    lda         : *const c_int,
+    b           : *const c_void,
+    ldb         : *const c_int,
+    beta        : *const c_void,
+    c           : *mut c_void,
+    ldc         : *const c_int,
+    _transa_len : usize,
+    _transb_len : usize,
+) {
+    const NAME: &str = "ZGEMM";
+
+    let (m, n, k)       = unsafe { (*m, *n, *k) };
+    let (lda, ldb, ldc) = unsafe { (*lda, *ldb, *ldc) };
+
+    let Some(op_a) = (unsafe { transpose(transa) }) else { return report(NAME, 1) };
+    let Some(op_b) = (unsafe { transpose(transb) }) else { return report(NAME, 2) };
+
+    let nrow_a = if op_a == CBLAS_NO_TRANS { m } else { k };
+    let nrow_b = if op_b == CBLAS_NO_TRANS { k } else { n };
+
+    if m < 0 { return report(NAME, 3); }
+    if n < 0 { return report(NAME, 4); }
+    if k < 0 { return report(NAME, 5); }
+    if lda < nrow_a.max(1) { return report(NAME, 8); }
+    if ldb < nrow_b.max(1) { return report(NAME, 10); }
+    if ldc < m.max(1) { return report(NAME, 13); }
+
+    unsafe {
+        cblas_zgemm(
+            CBLAS_COL_MAJOR, op_a, op_b, m, n, k,
+            alpha, a, lda, b, ldb, beta, c, ldc,
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Fortran-77 interface.
+//!
+//! The classic `sgemm_`-style symbols, for LAPACK builds and other
+//! consumers that link against a Fortran BLAS.
+//!
+//! - every argument is passed by reference; integers are 32-bit
+//!   (`LP64`).
+//! - `CHARACTER` arguments are `char *`; only the first character is read,
+//!   case-insensitively. Their hidden lengths are appended as `size_t`
+//!   after the regular arguments, as gfortran does, and ignored.
+//! - `cdotu_` and friends return the complex value in registers
+//!   (gfortran convention), not through a hidden first argument.
+//! - arguments are checked in reference BLAS order and reported through
+//!   [`crate::xerbla`] with the reference parameter numbers, e.g.
+//!   `SGEMM` and `8` for a bad `lda`.
+//!
+//! Exported: level1, level2 `?gemv`, `?trmv`, `?trsv`, `?symv`/`?hemv`,
+//! `?ger*`, `?syr`/`?her`, `?syr2`/`?her2`, and level3 `?gemm`. The banded
+//! and packed level2 routines and the rest of level3 are not implemented
+//! by `coral_aarch64` yet.
+//!
+//! # Safety
+//! Same contract as the reference BLAS: each pointer must be valid for the
+//! elements its dimensions and increments describe.
+
+// the # Safety section above covers every exported routine
+#![allow(clippy::missing_safety_doc)]
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+use core::ffi::c_char;
+
+use crate::cblas::enums::{
+    CBLAS_TRANSPOSE, CBLAS_UPLO, CBLAS_DIAG,
+    CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS,
+    CBLAS_UPPER, CBLAS_LOWER,
+    CBLAS_NON_UNIT, CBLAS_UNIT,
+};
+
+/// `COMPLEX` function result.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex32 {
+    pub re: f32,
+    pub im: f32,
+}
+
+/// `COMPLEX*16` function result.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+/// first character of a `CHARACTER` argument, upper-cased.
+#[inline]
+unsafe fn first(c: *const c_char) -> u8 {
+    unsafe { (*c.cast::<u8>()).to_ascii_uppercase() }
+}
+
+#[inline]
+pub(crate) unsafe fn transpose(c: *const c_char) -> Option<CBLAS_TRANSPOSE> {
+    match unsafe { first(c) } {
+        b'N' => Some(CBLAS_NO_TRANS),
+        b'T' => Some(CBLAS_TRANS),
+        b'C' => Some(CBLAS_CONJ_TRANS),
+        _    => None,
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn uplo(c: *const c_char) -> Option<CBLAS_UPLO> {
+    match unsafe { first(c) } {
+        b'U' => Some(CBLAS_UPPER),
+        b'L' => Some(CBLAS_LOWER),
+        _    => None,
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn diag(c: *const c_char) -> Option<CBLAS_DIAG> {
+    match unsafe { first(c) } {
+        b'N' => Some(CBLAS_NON_UNIT),
+        b'U' => Some(CBLAS_UNIT),
+        _    => None,
+    }
+}
@@ -0,0 +1,36 @@
+//! C ABI for `coral`.
+//!
+//! Builds as a `cdylib` and `staticlib` exporting the standard CBLAS
+//! interface and the Fortran-77 BLAS symbols on top of [`coral_aarch64`], so
+//! existing C, C++ and Fortran code (LAPACK included) can link against
+//! `coral` in place of OpenBLAS. The matching header is `include/cblas.h`.
+//!
+//! - level1 and level2 routines fully exported.
+//! - level3 only has `?gemm`.
+//! - column-major and row-major layouts.
+//! - errors go through an overridable `xerbla_`, see [`xerbla`].
+//!
+//! ## example
+//!
+//! ```c
+//! #include "cblas.h"
+//!
+//! float a[4] = {1, 2, 3, 4};  // row-major [[1, 2], [3, 4]]
+//! float b[4] = {1, 0, 0, 1};
+//! float c[4] = {0};
+//!
+//! cblas_sgemm(CblasRowMajor, CblasNoTrans, CblasNoTrans,
+//!             2, 2, 2, 1.0f, a, 2, b, 2, 0.0f, c, 2);
+//! ```
+
+#[cfg(target_arch = "aarch64")]
+pub mod cblas;
+
+#[cfg(target_arch = "aarch64")]
+pub mod fortran;
+
+#[cfg(target_arch = "aarch64")]
+pub mod xerbla;
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) mod strided;
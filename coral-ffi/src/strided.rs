@@ -0,0 +1,195 @@
+//! Raw pointer + BLAS increment to slice + stride.
+//!
+//! coral's level1 routines take signed increments, zero included, with
+//! reference semantics, so the level1 wrappers pass the caller's buffer and
+//! increment straight through. Level2 wrappers reject a zero increment
+//! first; positive ones borrow the caller's buffer and negative ones go
+//! through a unit-stride copy, which [`VecMut`] writes back when dropped,
+//! so the conjugating wrappers only ever see forward strides. With
+//! `inc < 0` element `i` of the vector lives at `x[(n - 1 - i) * |inc|]`.
+
+use core::ffi::c_int;
+
+/// scalars spanned by `n` elements of `w` scalars each at stride `|inc|`.
+#[inline]
+pub(crate) fn vec_len(n: usize, inc: c_int, w: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        w * ((n - 1) * inc.unsigned_abs() as usize + 1)
+    }
+}
+
+/// scalars spanned by a column-major `rows x cols` matrix, `w` scalars per entry.
+#[inline]
+pub(crate) fn mat_len(rows: usize, cols: usize, ld: usize, w: usize) -> usize {
+    if rows == 0 || cols == 0 {
+        0
+    } else {
+        w * ((cols - 1) * ld + rows)
+    }
+}
+
+/// offset in elements of logical entry `i`.
+#[inline(always)]
+fn offset(i: usize, n: usize, inc: c_int) -> usize {
+    let step = inc.unsigned_abs() as usize;
+
+    if inc >= 0 { i * step } else { (n - 1 - i) * step }
+}
+
+/// `&[T]` of `len` scalars at `p`; empty (and `p` unread) if `len == 0`.
+///
+/// # Safety
+/// `p` must be valid for `len` reads if `len > 0`.
+#[inline]
+pub(crate) unsafe fn slice<'a, T>(p: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { core::slice::from_raw_parts(p, len) }
+    }
+}
+
+/// `&mut [T]` of `len` scalars at `p`; empty (and `p` unread) if `len == 0`.
+///
+/// # Safety
+/// `p` must be valid for `len` reads and writes if `len > 0`.
+#[inline]
+pub(crate) unsafe fn slice_mut<'a, T>(p: *mut T, len: usize) -> &'a mut [T] {
+    if len == 0 {
+        &mut []
+    } else {
+        unsafe { core::slice::from_raw_parts_mut(p, len) }
+    }
+}
+
+/// read-only strided vector of `n` elements, `w` scalars each.
+pub(crate) enum VecRef<'a, T> {
+    Borrowed(&'a [T], isize),
+    Owned(Vec<T>),
+}
+
+impl<'a, T: Copy + Default> VecRef<'a, T> {
+    /// # Safety
+    /// `p` must be valid for [`vec_len`]`(n, inc, w)` reads.
+    pub(crate) unsafe fn new(p: *const T, n: usize, inc: c_int, w: usize) -> Self {
+        if inc > 0 || n == 0 {
+            let s = unsafe { slice(p, vec_len(n, inc, w)) };
+            return VecRef::Borrowed(s, inc.max(1) as isize);
+        }
+
+        let mut buf = vec![T::default(); n * w];
+        for i in 0..n {
+            let src = w * offset(i, n, inc);
+
+            for p_ in 0..w {
+                buf[w * i + p_] = unsafe { *p.add(src + p_) };
+            }
+        }
+        VecRef::Owned(buf)
+    }
+
+    #[inline]
+    pub(crate) fn slice(&self) -> &[T] {
+        match self {
+            VecRef::Borrowed(s, _) => s,
+            VecRef::Owned(v)       => v,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn inc(&self) -> isize {
+        match self {
+            VecRef::Borrowed(_, inc) => *inc,
+            VecRef::Owned(_)         => 1,
+        }
+    }
+}
+
+/// mutable strided vector of `n` elements, `w` scalars each.
+/// copies are written back on drop.
+pub(crate) enum VecMut<'a, T: Copy> {
+    Borrowed(&'a mut [T], isize),
+    Owned {
+        buf : Vec<T>,
+        dst : *mut T,
+        n   : usize,
+        inc : c_int,
+        w   : usize,
+    },
+}
+
+impl<'a, T: Copy + Default> VecMut<'a, T> {
+    /// # Safety
+    /// `p` must be valid for [`vec_len`]`(n, inc, w)` reads and writes.
+    pub(crate) unsafe fn new(p: *mut T, n: usize, inc: c_int, w: usize) -> Self {
+        if inc > 0 || n == 0 {
+            let s = unsafe { slice_mut(p, vec_len(n, inc, w)) };
+            return VecMut::Borrowed(s, inc.max(1) as isize);
+        }
+
+        let mut buf = vec![T::default(); n * w];
+        for i in 0..n {
+            let src = w * offset(i, n, inc);
+
+            for p_ in 0..w {
+                buf[w * i + p_] = unsafe { *p.add(src + p_) };
+            }
+        }
+        VecMut::Owned { buf, dst: p, n, inc, w }
+    }
+
+    #[inline]
+    pub(crate) fn slice(&mut self) -> &mut [T] {
+        match self {
+            VecMut::Borrowed(s, _)     => s,
+            VecMut::Owned { buf, .. }  => buf,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn inc(&self) -> isize {
+        match self {
+            VecMut::Borrowed(_, inc) => *inc,
+            VecMut::Owned { .. }     => 1,
+        }
+    }
+}
+
+impl<'a, T: Copy> Drop for VecMut<'a, T> {
+    fn drop(&mut self) {
+        if let VecMut::Owned { buf, dst, n, inc, w } = self {
+            for i in 0..*n {
+                let o = *w * offset(i, *n, *inc);
+
+                for p_ in 0..*w {
+                    unsafe { *dst.add(o + p_) = buf[*w * i + p_]; }
+                }
+            }
+        }
+    }
+}
+
+/// unit-stride copy of `n` interleaved complex entries at forward stride
+/// `inc`, with the imaginary parts negated.
+#[inline]
+pub(crate) fn conj<T: Copy + core::ops::Neg<Output = T>>(x: &[T], n: usize, inc: isize) -> Vec<T> {
+    let inc = inc as usize;
+    let mut v = Vec::with_capacity(2 * n);
+    for i in 0..n {
+        v.push(x[2 * i * inc]);
+        v.push(-x[2 * i * inc + 1]);
+    }
+    v
+}
+
+/// negates the imaginary parts of `n` interleaved complex entries at
+/// forward stride `inc`, in place.
+#[inline]
+pub(crate) fn conj_in_place<T: Copy + core::ops::Neg<Output = T>>(x: &mut [T], n: usize, inc: isize) {
+    let inc = inc as usize;
+    for i in 0..n {
+        x[2 * i * inc + 1] = -x[2 * i * inc + 1];
+    }
+}
@@ -0,0 +1,66 @@
+//! `xerbla_`, the BLAS error handler.
+//!
+//! Every invalid argument, from either interface, ends up here with the
+//! routine name and the 1-based position of the offending parameter in that
+//! routine's argument list: `SGEMM` and `8` for a bad `lda` in `sgemm_`,
+//! `cblas_sgemm` and `9` for the same error through CBLAS. The routine then
+//! returns without touching its outputs.
+//!
+//! The default prints the reference BLAS message to stderr and returns; unlike
+//! reference `XERBLA` it does not `STOP` the program. Two ways to override it:
+//!
+//! - register a handler with [`coral_set_xerbla`]. Works for the static and
+//!   the shared library.
+//! - define your own `xerbla_` when linking against the shared library.
+//!   coral calls `xerbla_` through the dynamic symbol table, so the
+//!   executable's definition takes precedence, as with reference BLAS.
+//!   With the static library a second `xerbla_` is a duplicate symbol; use
+//!   the handler instead.
+
+use core::ffi::{c_char, c_int};
+use std::sync::RwLock;
+
+/// `void (*)(const char *srname, const int *info, size_t srname_len)`,
+/// the Fortran calling convention of `xerbla_`. `srname` is not
+/// nul-terminated.
+pub type XerblaHandler = unsafe extern "C" fn(*const c_char, *const c_int, usize);
+
+static HANDLER: RwLock<Option<XerblaHandler>> = RwLock::new(None);
+
+unsafe extern "C" {
+    // resolved at load time, so a user-defined `xerbla_` interposes ours
+    #[link_name = "xerbla_"]
+    fn xerbla_dyn(srname: *const c_char, info: *const c_int, len: usize);
+}
+
+/// installs `handler` in place of `xerbla_`; `NULL` restores the default.
+#[unsafe(no_mangle)]
+pub extern "C" fn coral_set_xerbla(handler: Option<XerblaHandler>) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = handler;
+}
+
+/// default error handler. Prints
+/// ` ** On entry to SGEMM  parameter number  8 had an illegal value`.
+///
+/// # Safety
+/// `srname` must be valid for `len` reads and `info` for one.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn xerbla_(srname: *const c_char, info: *const c_int, len: usize) {
+    let name  = unsafe { core::slice::from_raw_parts(srname.cast::<u8>(), len) };
+    let name  = String::from_utf8_lossy(name);
+    let info  = unsafe { *info };
+
+    eprintln!(" ** On entry to {} parameter number {info:2} had an illegal value", name.trim_end());
+}
+
+/// reports parameter `info` of `routine` as invalid.
+#[cold]
+pub(crate) fn report(routine: &str, info: c_int) {
+    let handler = *HANDLER.read().unwrap_or_else(|e| e.into_inner());
+    let name    = routine.as_ptr() as *const c_char;
+
+    match handler {
+        Some(h) => unsafe { h(name, &info, routine.len()) },
+        None    => unsafe { xerbla_dyn(name, &info, routine.len()) },
+    }
+}
@@ -0,0 +1,13 @@
+#![cfg(target_arch = "aarch64")]
+
+// level1
+#[path = "cblas/level1_tests.rs"]
+mod level1_tests;
+
+// level2
+#[path = "cblas/level2_tests.rs"]
+mod level2_tests;
+
+// level3
+#[path = "cblas/level3_tests.rs"]
+mod level3_tests;
@@ -0,0 +1,187 @@
+use coral_ffi::cblas::level1::*;
+
+fn make_vec(n: usize, seed: f32) -> Vec<f32> {
+    (0..n).map(|i| seed + 0.25 * i as f32 - 0.01 * (i * i) as f32).collect()
+}
+
+#[test]
+fn sdot_negative_inc_matches_reversed() {
+    let n = 37;
+    let x = make_vec(2 * n, 0.3);
+    let y = make_vec(n, -1.1);
+
+    // x at stride -2 visits x[2(n-1)], x[2(n-2)], ..., x[0]
+    let x_rev: Vec<f32> = (0..n).map(|i| x[2 * (n - 1 - i)]).collect();
+
+    let got = unsafe { cblas_sdot(n as i32, x.as_ptr(), -2, y.as_ptr(), 1) };
+    let exp = unsafe { cblas_sdot(n as i32, x_rev.as_ptr(), 1, y.as_ptr(), 1) };
+
+    assert_eq!(got, exp);
+}
+
+#[test]
+fn sdsdot_and_dsdot_sum_in_double() {
+    let x = [1.0e8f32, 1.0, -1.0e8];
+    let y = [1.0f32; 3];
+
+    unsafe {
+        assert_eq!(cblas_sdot(3, x.as_ptr(), 1, y.as_ptr(), 1), 0.0);
+        assert_eq!(cblas_dsdot(3, x.as_ptr(), 1, y.as_ptr(), 1), 1.0);
+        assert_eq!(cblas_sdsdot(3, 0.5, x.as_ptr(), 1, y.as_ptr(), 1), 1.5);
+        assert_eq!(cblas_sdsdot(0, 0.5, x.as_ptr(), 1, y.as_ptr(), 1), 0.5);
+    }
+}
+
+#[test]
+fn saxpy_negative_inc_writes_back() {
+    let n = 19;
+    let x = make_vec(n, 0.5);
+    let mut y     = make_vec(n, 2.0);
+    let mut y_exp = y.clone();
+
+    unsafe { cblas_saxpy(n as i32, 1.5, x.as_ptr(), 1, y.as_mut_ptr(), -1) };
+
+    for i in 0..n {
+        y_exp[n - 1 - i] += 1.5 * x[i];
+    }
+    assert_eq!(y, y_exp);
+}
+
+#[test]
+fn scopy_zero_inc_broadcasts() {
+    let n = 8;
+    let x = [3.0f32];
+    let mut y = vec![0.0f32; n];
+
+    unsafe { cblas_scopy(n as i32, x.as_ptr(), 0, y.as_mut_ptr(), 1) };
+
+    assert!(y.iter().all(|&v| v == 3.0));
+}
+
+#[test]
+fn saxpy_zero_incy_accumulates() {
+    let n = 13;
+    let x = make_vec(n, 0.7);
+    let mut y = [2.0f32];
+
+    unsafe { cblas_saxpy(n as i32, 1.5, x.as_ptr(), -1, y.as_mut_ptr(), 0) };
+
+    // reference BLAS: y += a * x_i for x_{n-1}, ..., x_0 in turn
+    let exp = x.iter().rev().fold(2.0f32, |y, &v| y + 1.5 * v);
+    assert_eq!(y, [exp]);
+}
+
+#[test]
+fn caxpy_zero_incy_accumulates() {
+    // x = [1 + 2i, 3 - i], alpha = 2 + i
+    let x     = [1.0f32, 2.0, 3.0, -1.0];
+    let alpha = [2.0f32, 1.0];
+    let mut y = [1.0f32, 1.0];
+
+    unsafe { cblas_caxpy(2, alpha.as_ptr().cast(), x.as_ptr().cast(), 1, y.as_mut_ptr().cast(), 0) };
+
+    // (1 + i) + (2 + i)(1 + 2i) + (2 + i)(3 - i) = (1 + i) + 5i + (7 + i)
+    assert_eq!(y, [8.0, 7.0]);
+}
+
+#[test]
+fn sswap_zero_inc_matches_reference() {
+    let mut x = [1.0f32, 2.0, 3.0, 4.0];
+    let mut y = [9.0f32];
+
+    unsafe { cblas_sswap(4, x.as_mut_ptr(), 1, y.as_mut_ptr(), 0) };
+
+    // each step swaps x_i with the single y, shifting x right by one
+    assert_eq!(x, [9.0, 1.0, 2.0, 3.0]);
+    assert_eq!(y, [4.0]);
+}
+
+#[test]
+fn srot_zero_inc_matches_reference() {
+    let (c, s) = (0.6f32, 0.8f32);
+    let mut x = [1.0f32, -2.0, 0.5];
+    let mut y = [3.0f32];
+
+    let mut x_exp = x;
+    let mut y_exp = y[0];
+    for xi in &mut x_exp {
+        let t = c * *xi + s * y_exp;
+        y_exp = c * y_exp - s * *xi;
+        *xi = t;
+    }
+
+    unsafe { cblas_srot(3, x.as_mut_ptr(), 1, y.as_mut_ptr(), 0, c, s) };
+
+    assert_eq!(x, x_exp);
+    assert_eq!(y, [y_exp]);
+}
+
+#[test]
+fn drotm_zero_inc_matches_reference() {
+    // flag 1: x' = h11 x + y, y' = -x + h22 y
+    let param = [1.0f64, 2.0, 0.0, 0.0, 3.0];
+    let mut x = [5.0f64];
+    let mut y = [1.0f64, 2.0];
+
+    unsafe { cblas_drotm(2, x.as_mut_ptr(), 0, y.as_mut_ptr(), 1, param.as_ptr()) };
+
+    // (5, 1) -> (11, -2), then (11, 2) -> (24, -5)
+    assert_eq!(x, [24.0]);
+    assert_eq!(y, [-2.0, -5.0]);
+}
+
+#[test]
+fn nonpositive_inc_reductions_return_zero() {
+    let x = make_vec(10, 1.0);
+
+    unsafe {
+        assert_eq!(cblas_sasum(10, x.as_ptr(), -1), 0.0);
+        assert_eq!(cblas_snrm2(10, x.as_ptr(), 0), 0.0);
+        assert_eq!(cblas_isamax(10, x.as_ptr(), -1), 0);
+        assert_eq!(cblas_sasum(0, x.as_ptr(), 1), 0.0);
+    }
+}
+
+#[test]
+fn cdotc_sub_writes_out_pointer() {
+    // x = [1 + 2i, 3 - i], y = [2 - i, 1 + i]
+    let x = [1.0f32, 2.0, 3.0, -1.0];
+    let y = [2.0f32, -1.0, 1.0, 1.0];
+
+    let mut dotu = [0.0f32; 2];
+    let mut dotc = [0.0f32; 2];
+
+    unsafe {
+        cblas_cdotu_sub(2, x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, dotu.as_mut_ptr().cast());
+        cblas_cdotc_sub(2, x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, dotc.as_mut_ptr().cast());
+    }
+
+    // (1 + 2i)(2 - i) + (3 - i)(1 + i) = (4 + 3i) + (4 + 2i)
+    assert_eq!(dotu, [8.0, 5.0]);
+    // (1 - 2i)(2 - i) + (3 + i)(1 + i) = (0 - 5i) + (2 + 4i)
+    assert_eq!(dotc, [2.0, -1.0]);
+}
+
+#[test]
+fn zdotu_sub_empty_is_zero() {
+    let x = [1.0f64, 1.0];
+    let mut out = [7.0f64, 7.0];
+
+    unsafe {
+        cblas_zdotu_sub(0, x.as_ptr().cast(), 1, x.as_ptr().cast(), 1, out.as_mut_ptr().cast());
+    }
+    assert_eq!(out, [0.0, 0.0]);
+}
+
+#[test]
+fn zrotg_writes_r_c_and_s() {
+    let mut a = [0.0f64, 0.0];
+    let b     = [3.0f64, 4.0];
+    let mut c = 7.0f64;
+    let mut s = [7.0f64, 7.0];
+
+    unsafe {
+        cblas_zrotg(a.as_mut_ptr().cast(), b.as_ptr().cast(), &mut c, s.as_mut_ptr().cast());
+    }
+    assert_eq!((a, c, s), ([5.0, 0.0], 0.0, [0.6, -0.8]));
+}
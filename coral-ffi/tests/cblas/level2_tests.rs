@@ -0,0 +1,309 @@
+use coral_ffi::cblas::enums::*;
+use coral_ffi::cblas::level2::*;
+
+const RTOL: f32 = 1e-5;
+
+fn make_mat(rows: usize, cols: usize, ld: usize, w: usize, seed: f32) -> Vec<f32> {
+    let mut a = vec![0.0; w * ld * rows.max(cols)];
+    for (k, v) in a.iter_mut().enumerate() {
+        *v = seed + 0.37 * ((k * 7) % 13) as f32 - 0.11 * ((k * 5) % 11) as f32;
+    }
+    a
+}
+
+/// column-major copy (`ld = rows`) of the row-major `rows x cols` matrix `a`.
+fn rm_to_cm(rows: usize, cols: usize, a: &[f32], lda: usize, w: usize) -> Vec<f32> {
+    let mut out = vec![0.0; w * rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            for p in 0..w {
+                out[w * (i + j * rows) + p] = a[w * (i * lda + j) + p];
+            }
+        }
+    }
+    out
+}
+
+fn assert_close(got: &[f32], exp: &[f32]) {
+    assert_eq!(got.len(), exp.len());
+    let scale = exp.iter().fold(1.0f32, |m, v| m.max(v.abs()));
+    for (i, (g, e)) in got.iter().zip(exp).enumerate() {
+        assert!((g - e).abs() <= RTOL * scale * 10.0, "index {i}: {g} vs {e}");
+    }
+}
+
+/// row-major `rows x cols` matrix `a_rm` equals the column-major `a_cm`.
+fn assert_same_matrix(rows: usize, cols: usize, a_rm: &[f32], lda: usize, a_cm: &[f32], w: usize) {
+    assert_close(&rm_to_cm(rows, cols, a_rm, lda, w), a_cm);
+}
+
+#[test]
+fn sgemv_row_major_matches_col_major() {
+    let (m, n, lda) = (23, 17, 20);
+    let a_rm = make_mat(m, n, lda, 1, 0.2);
+    let a_cm = rm_to_cm(m, n, &a_rm, lda, 1);
+
+    for trans in [CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS] {
+        let (lx, ly) = if trans == CBLAS_NO_TRANS { (n, m) } else { (m, n) };
+        let x = make_mat(lx, 1, 1, 1, -0.4);
+        let mut y_rm = make_mat(ly, 1, 1, 1, 0.9);
+        let mut y_cm = y_rm.clone();
+
+        unsafe {
+            cblas_sgemv(
+                CBLAS_ROW_MAJOR, trans, m as i32, n as i32, 1.3,
+                a_rm.as_ptr(), lda as i32, x.as_ptr(), 1, -0.7, y_rm.as_mut_ptr(), 1,
+            );
+            cblas_sgemv(
+                CBLAS_COL_MAJOR, trans, m as i32, n as i32, 1.3,
+                a_cm.as_ptr(), m as i32, x.as_ptr(), 1, -0.7, y_cm.as_mut_ptr(), 1,
+            );
+        }
+        assert_close(&y_rm, &y_cm);
+    }
+}
+
+#[test]
+fn cgemv_row_major_conj_transpose() {
+    let (m, n, lda) = (12, 16, 17);
+    let a_rm = make_mat(m, n, lda, 2, 0.1);
+    let a_cm = rm_to_cm(m, n, &a_rm, lda, 2);
+
+    let alpha = [0.6f32, -1.2];
+    let beta  = [0.3f32, 0.5];
+
+    for trans in [CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS] {
+        let (lx, ly) = if trans == CBLAS_NO_TRANS { (n, m) } else { (m, n) };
+        let x = make_mat(lx, 1, 1, 2, 0.7);
+        let mut y_rm = make_mat(ly, 1, 1, 2, -0.2);
+        let mut y_cm = y_rm.clone();
+
+        unsafe {
+            cblas_cgemv(
+                CBLAS_ROW_MAJOR, trans, m as i32, n as i32, alpha.as_ptr().cast(),
+                a_rm.as_ptr().cast(), lda as i32, x.as_ptr().cast(), 1,
+                beta.as_ptr().cast(), y_rm.as_mut_ptr().cast(), 1,
+            );
+            cblas_cgemv(
+                CBLAS_COL_MAJOR, trans, m as i32, n as i32, alpha.as_ptr().cast(),
+                a_cm.as_ptr().cast(), m as i32, x.as_ptr().cast(), 1,
+                beta.as_ptr().cast(), y_cm.as_mut_ptr().cast(), 1,
+            );
+        }
+        assert_close(&y_rm, &y_cm);
+    }
+}
+
+#[test]
+fn sgemv_negative_incs() {
+    let (m, n) = (9, 6);
+    let a = make_mat(m, n, m, 1, 0.3);
+    let x = make_mat(n, 1, 1, 1, 1.0);
+    let mut y = make_mat(2 * m, 1, 1, 1, -1.0);
+
+    let x_rev: Vec<f32> = x.iter().rev().copied().collect();
+    let mut y_rev: Vec<f32> = (0..m).map(|i| y[2 * (m - 1 - i)]).collect();
+
+    unsafe {
+        cblas_sgemv(
+            CBLAS_COL_MAJOR, CBLAS_NO_TRANS, m as i32, n as i32, 1.0,
+            a.as_ptr(), m as i32, x.as_ptr(), -1, 0.5, y.as_mut_ptr(), -2,
+        );
+        cblas_sgemv(
+            CBLAS_COL_MAJOR, CBLAS_NO_TRANS, m as i32, n as i32, 1.0,
+            a.as_ptr(), m as i32, x_rev.as_ptr(), 1, 0.5, y_rev.as_mut_ptr(), 1,
+        );
+    }
+
+    let y_strided: Vec<f32> = (0..m).map(|i| y[2 * (m - 1 - i)]).collect();
+    assert_close(&y_strided, &y_rev);
+}
+
+#[test]
+fn ctrmv_ctrsv_row_major_matches_col_major() {
+    let (n, lda) = (13, 16);
+    let mut a_rm = make_mat(n, n, lda, 2, 0.5);
+    for i in 0..n {
+        // well conditioned for the solve
+        a_rm[2 * (i * lda + i)] += 8.0;
+    }
+    let a_cm = rm_to_cm(n, n, &a_rm, lda, 2);
+
+    for uplo in [CBLAS_UPPER, CBLAS_LOWER] {
+        for trans in [CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS] {
+            for diag in [CBLAS_NON_UNIT, CBLAS_UNIT] {
+                let x = make_mat(n, 1, 1, 2, -0.3);
+
+                let mut x_rm = x.clone();
+                let mut x_cm = x.clone();
+                unsafe {
+                    cblas_ctrmv(
+                        CBLAS_ROW_MAJOR, uplo, trans, diag, n as i32,
+                        a_rm.as_ptr().cast(), lda as i32, x_rm.as_mut_ptr().cast(), 1,
+                    );
+                    cblas_ctrmv(
+                        CBLAS_COL_MAJOR, uplo, trans, diag, n as i32,
+                        a_cm.as_ptr().cast(), n as i32, x_cm.as_mut_ptr().cast(), 1,
+                    );
+                }
+                assert_close(&x_rm, &x_cm);
+
+                let mut x_rm = x.clone();
+                let mut x_cm = x.clone();
+                unsafe {
+                    cblas_ctrsv(
+                        CBLAS_ROW_MAJOR, uplo, trans, diag, n as i32,
+                        a_rm.as_ptr().cast(), lda as i32, x_rm.as_mut_ptr().cast(), 1,
+                    );
+                    cblas_ctrsv(
+                        CBLAS_COL_MAJOR, uplo, trans, diag, n as i32,
+                        a_cm.as_ptr().cast(), n as i32, x_cm.as_mut_ptr().cast(), 1,
+                    );
+                }
+                assert_close(&x_rm, &x_cm);
+            }
+        }
+    }
+}
+
+#[test]
+fn chemv_row_major_matches_col_major() {
+    let (n, lda) = (12, 12);
+    let a_rm = make_mat(n, n, lda, 2, -0.1);
+    let a_cm = rm_to_cm(n, n, &a_rm, lda, 2);
+
+    let alpha = [1.1f32, 0.4];
+    let beta  = [-0.5f32, 0.2];
+    let x = make_mat(n, 1, 1, 2, 0.8);
+
+    for uplo in [CBLAS_UPPER, CBLAS_LOWER] {
+        let mut y_rm = make_mat(n, 1, 1, 2, 0.3);
+        let mut y_cm = y_rm.clone();
+
+        unsafe {
+            cblas_chemv(
+                CBLAS_ROW_MAJOR, uplo, n as i32, alpha.as_ptr().cast(),
+                a_rm.as_ptr().cast(), lda as i32, x.as_ptr().cast(), 1,
+                beta.as_ptr().cast(), y_rm.as_mut_ptr().cast(), 1,
+            );
+            cblas_chemv(
+                CBLAS_COL_MAJOR, uplo, n as i32, alpha.as_ptr().cast(),
+                a_cm.as_ptr().cast(), n as i32, x.as_ptr().cast(), 1,
+                beta.as_ptr().cast(), y_cm.as_mut_ptr().cast(), 1,
+            );
+        }
+        assert_close(&y_rm, &y_cm);
+    }
+}
+
+#[test]
+fn ger_row_major_matches_col_major() {
+    let (m, n, lda) = (10, 7, 9);
+    let alpha = [0.7f32, -0.3];
+    let x = make_mat(m, 1, 1, 2, 0.2);
+    let y = make_mat(n, 1, 1, 2, -0.6);
+
+    type Ger = unsafe extern "C" fn(
+        i32, i32, i32, *const core::ffi::c_void,
+        *const core::ffi::c_void, i32, *const core::ffi::c_void, i32,
+        *mut core::ffi::c_void, i32,
+    );
+
+    for ger in [cblas_cgeru as Ger, cblas_cgerc as Ger] {
+        let mut a_rm = make_mat(m, n, lda, 2, 0.4);
+        let mut a_cm = rm_to_cm(m, n, &a_rm, lda, 2);
+
+        unsafe {
+            ger(
+                CBLAS_ROW_MAJOR, m as i32, n as i32, alpha.as_ptr().cast(),
+                x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, a_rm.as_mut_ptr().cast(), lda as i32,
+            );
+            ger(
+                CBLAS_COL_MAJOR, m as i32, n as i32, alpha.as_ptr().cast(),
+                x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, a_cm.as_mut_ptr().cast(), m as i32,
+            );
+        }
+        assert_same_matrix(m, n, &a_rm, lda, &a_cm, 2);
+    }
+}
+
+#[test]
+fn her_her2_row_major_matches_col_major() {
+    let (n, lda) = (9, 11);
+    let alpha = [0.9f32, 0.6];
+    let x = make_mat(n, 1, 1, 2, -0.5);
+    let y = make_mat(n, 1, 1, 2, 0.25);
+
+    for uplo in [CBLAS_UPPER, CBLAS_LOWER] {
+        let mut a_rm = make_mat(n, n, lda, 2, 0.15);
+        for i in 0..n {
+            a_rm[2 * (i * lda + i) + 1] = 0.0;
+        }
+        let mut a_cm = rm_to_cm(n, n, &a_rm, lda, 2);
+
+        unsafe {
+            cblas_cher(
+                CBLAS_ROW_MAJOR, uplo, n as i32, 0.8,
+                x.as_ptr().cast(), 1, a_rm.as_mut_ptr().cast(), lda as i32,
+            );
+            cblas_cher(
+                CBLAS_COL_MAJOR, uplo, n as i32, 0.8,
+                x.as_ptr().cast(), 1, a_cm.as_mut_ptr().cast(), n as i32,
+            );
+            cblas_cher2(
+                CBLAS_ROW_MAJOR, uplo, n as i32, alpha.as_ptr().cast(),
+                x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, a_rm.as_mut_ptr().cast(), lda as i32,
+            );
+            cblas_cher2(
+                CBLAS_COL_MAJOR, uplo, n as i32, alpha.as_ptr().cast(),
+                x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, a_cm.as_mut_ptr().cast(), n as i32,
+            );
+        }
+        assert_same_matrix(n, n, &a_rm, lda, &a_cm, 2);
+    }
+}
+
+#[test]
+fn ssyr2_row_major_matches_col_major() {
+    let (n, lda) = (14, 14);
+    let x = make_mat(n, 1, 1, 1, 0.3);
+    let y = make_mat(n, 1, 1, 1, -0.2);
+
+    for uplo in [CBLAS_UPPER, CBLAS_LOWER] {
+        let mut a_rm = make_mat(n, n, lda, 1, 0.05);
+        let mut a_cm = rm_to_cm(n, n, &a_rm, lda, 1);
+
+        unsafe {
+            cblas_ssyr2(
+                CBLAS_ROW_MAJOR, uplo, n as i32, 1.4,
+                x.as_ptr(), 1, y.as_ptr(), 1, a_rm.as_mut_ptr(), lda as i32,
+            );
+            cblas_ssyr2(
+                CBLAS_COL_MAJOR, uplo, n as i32, 1.4,
+                x.as_ptr(), 1, y.as_ptr(), 1, a_cm.as_mut_ptr(), n as i32,
+            );
+        }
+        assert_same_matrix(n, n, &a_rm, lda, &a_cm, 1);
+    }
+}
+
+#[test]
+fn invalid_arguments_leave_outputs_untouched() {
+    let (m, n) = (4, 3);
+    let a = make_mat(m, n, m, 1, 0.1);
+    let x = make_mat(n, 1, 1, 1, 0.2);
+    let y0 = make_mat(m, 1, 1, 1, 0.3);
+
+    let mut y = y0.clone();
+    unsafe {
+        // bad layout
+        cblas_sgemv(0, CBLAS_NO_TRANS, m as i32, n as i32, 1.0, a.as_ptr(), m as i32, x.as_ptr(), 1, 0.0, y.as_mut_ptr(), 1);
+        // bad transpose
+        cblas_sgemv(CBLAS_COL_MAJOR, 7, m as i32, n as i32, 1.0, a.as_ptr(), m as i32, x.as_ptr(), 1, 0.0, y.as_mut_ptr(), 1);
+        // lda < m
+        cblas_sgemv(CBLAS_COL_MAJOR, CBLAS_NO_TRANS, m as i32, n as i32, 1.0, a.as_ptr(), 2, x.as_ptr(), 1, 0.0, y.as_mut_ptr(), 1);
+        // incy == 0
+        cblas_sgemv(CBLAS_COL_MAJOR, CBLAS_NO_TRANS, m as i32, n as i32, 1.0, a.as_ptr(), m as i32, x.as_ptr(), 1, 0.0, y.as_mut_ptr(), 0);
+    }
+    assert_eq!(y, y0);
+}
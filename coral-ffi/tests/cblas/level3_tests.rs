@@ -0,0 +1,133 @@
+use coral_ffi::cblas::enums::*;
+use coral_ffi::cblas::level3::*;
+
+fn make_mat(len: usize, seed: f64) -> Vec<f64> {
+    (0..len).map(|k| seed + 0.31 * ((k * 7) % 17) as f64 - 0.13 * ((k * 3) % 11) as f64).collect()
+}
+
+/// column-major copy (`ld = rows`) of the row-major `rows x cols` matrix `a`.
+fn rm_to_cm(rows: usize, cols: usize, a: &[f64], lda: usize, w: usize) -> Vec<f64> {
+    let mut out = vec![0.0; w * rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            for p in 0..w {
+                out[w * (i + j * rows) + p] = a[w * (i * lda + j) + p];
+            }
+        }
+    }
+    out
+}
+
+fn assert_close(got: &[f64], exp: &[f64]) {
+    let scale = exp.iter().fold(1.0f64, |m, v| m.max(v.abs()));
+    for (i, (g, e)) in got.iter().zip(exp).enumerate() {
+        assert!((g - e).abs() <= 1e-12 * scale, "index {i}: {g} vs {e}");
+    }
+}
+
+fn op_dims(trans: i32, rows: usize, cols: usize) -> (usize, usize) {
+    if trans == CBLAS_NO_TRANS { (rows, cols) } else { (cols, rows) }
+}
+
+#[test]
+fn dgemm_row_major_matches_col_major() {
+    let (m, n, k) = (37, 29, 41);
+
+    for ta in [CBLAS_NO_TRANS, CBLAS_TRANS] {
+        for tb in [CBLAS_NO_TRANS, CBLAS_TRANS] {
+            // stored shapes of A and B
+            let (ar, ac) = op_dims(ta, m, k);
+            let (br, bc) = op_dims(tb, k, n);
+            let (lda, ldb, ldc) = (ac + 3, bc + 1, n + 2);
+
+            let a_rm = make_mat(ar * lda, 0.2);
+            let b_rm = make_mat(br * ldb, -0.1);
+            let mut c_rm = make_mat(m * ldc, 0.5);
+
+            let a_cm = rm_to_cm(ar, ac, &a_rm, lda, 1);
+            let b_cm = rm_to_cm(br, bc, &b_rm, ldb, 1);
+            let mut c_cm = rm_to_cm(m, n, &c_rm, ldc, 1);
+
+            unsafe {
+                cblas_dgemm(
+                    CBLAS_ROW_MAJOR, ta, tb, m as i32, n as i32, k as i32, 0.8,
+                    a_rm.as_ptr(), lda as i32, b_rm.as_ptr(), ldb as i32,
+                    -1.5, c_rm.as_mut_ptr(), ldc as i32,
+                );
+                cblas_dgemm(
+                    CBLAS_COL_MAJOR, ta, tb, m as i32, n as i32, k as i32, 0.8,
+                    a_cm.as_ptr(), ar as i32, b_cm.as_ptr(), br as i32,
+                    -1.5, c_cm.as_mut_ptr(), m as i32,
+                );
+            }
+            assert_close(&rm_to_cm(m, n, &c_rm, ldc, 1), &c_cm);
+        }
+    }
+}
+
+#[test]
+fn zgemm_row_major_matches_col_major() {
+    let (m, n, k) = (13, 9, 11);
+    let alpha = [0.4f64, -0.9];
+    let beta  = [1.2f64, 0.3];
+
+    for ta in [CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS] {
+        for tb in [CBLAS_NO_TRANS, CBLAS_TRANS, CBLAS_CONJ_TRANS] {
+            let (ar, ac) = op_dims(ta, m, k);
+            let (br, bc) = op_dims(tb, k, n);
+
+            let a_rm = make_mat(2 * ar * ac, 0.1);
+            let b_rm = make_mat(2 * br * bc, 0.6);
+            let mut c_rm = make_mat(2 * m * n, -0.3);
+
+            let a_cm = rm_to_cm(ar, ac, &a_rm, ac, 2);
+            let b_cm = rm_to_cm(br, bc, &b_rm, bc, 2);
+            let mut c_cm = rm_to_cm(m, n, &c_rm, n, 2);
+
+            unsafe {
+                cblas_zgemm(
+                    CBLAS_ROW_MAJOR, ta, tb, m as i32, n as i32, k as i32, alpha.as_ptr().cast(),
+                    a_rm.as_ptr().cast(), ac as i32, b_rm.as_ptr().cast(), bc as i32,
+                    beta.as_ptr().cast(), c_rm.as_mut_ptr().cast(), n as i32,
+                );
+                cblas_zgemm(
+                    CBLAS_COL_MAJOR, ta, tb, m as i32, n as i32, k as i32, alpha.as_ptr().cast(),
+                    a_cm.as_ptr().cast(), ar as i32, b_cm.as_ptr().cast(), br as i32,
+                    beta.as_ptr().cast(), c_cm.as_mut_ptr().cast(), m as i32,
+                );
+            }
+            assert_close(&rm_to_cm(m, n, &c_rm, n, 2), &c_cm);
+        }
+    }
+}
+
+#[test]
+fn sgemm_row_major_example() {
+    // row-major [[1, 2], [3, 4]] * I
+    let a = [1.0f32, 2.0, 3.0, 4.0];
+    let b = [1.0f32, 0.0, 0.0, 1.0];
+    let mut c = [0.0f32; 4];
+
+    unsafe {
+        cblas_sgemm(
+            CBLAS_ROW_MAJOR, CBLAS_NO_TRANS, CBLAS_NO_TRANS, 2, 2, 2, 1.0,
+            a.as_ptr(), 2, b.as_ptr(), 2, 0.0, c.as_mut_ptr(), 2,
+        );
+    }
+    assert_eq!(c, a);
+}
+
+#[test]
+fn sgemm_invalid_ldc_is_rejected() {
+    let a = [1.0f32; 6];
+    let mut c = [5.0f32; 6];
+
+    unsafe {
+        // row-major C is 2 x 3, needs ldc >= 3
+        cblas_sgemm(
+            CBLAS_ROW_MAJOR, CBLAS_NO_TRANS, CBLAS_NO_TRANS, 2, 3, 1, 1.0,
+            a.as_ptr(), 1, a.as_ptr(), 3, 0.0, c.as_mut_ptr(), 2,
+        );
+    }
+    assert_eq!(c, [5.0; 6]);
+}
@@ -0,0 +1,9 @@
+#![cfg(target_arch = "aarch64")]
+
+// routines
+#[path = "fortran/blas_tests.rs"]
+mod blas_tests;
+
+// argument checking
+#[path = "fortran/xerbla_tests.rs"]
+mod xerbla_tests;
@@ -0,0 +1,120 @@
+use coral_ffi::cblas::enums::*;
+use coral_ffi::cblas::{level1::*, level2::*, level3::*};
+use coral_ffi::fortran::{level1::*, level2::*, level3::*};
+use coral_ffi::fortran::Complex32;
+
+fn make_vec(len: usize, seed: f32) -> Vec<f32> {
+    (0..len).map(|k| seed + 0.29 * ((k * 7) % 13) as f32 - 0.17 * ((k * 3) % 5) as f32).collect()
+}
+
+#[test]
+fn level1_matches_cblas() {
+    let n = 21;
+    let x = make_vec(2 * n, 0.4);
+    let y = make_vec(2 * n, -0.2);
+
+    unsafe {
+        assert_eq!(sdot_(&(n as i32), x.as_ptr(), &-2, y.as_ptr(), &1),
+                   cblas_sdot(n as i32, x.as_ptr(), -2, y.as_ptr(), 1));
+        assert_eq!(snrm2_(&(n as i32), x.as_ptr(), &2), cblas_snrm2(n as i32, x.as_ptr(), 2));
+
+        let mut dot = [0.0f32; 2];
+        cblas_cdotc_sub(n as i32, x.as_ptr().cast(), 1, y.as_ptr().cast(), 1, dot.as_mut_ptr().cast());
+        let got = cdotc_(&(n as i32), x.as_ptr().cast(), &1, y.as_ptr().cast(), &1);
+        assert_eq!(got, Complex32 { re: dot[0], im: dot[1] });
+    }
+}
+
+#[test]
+fn saxpy_zero_incy_accumulates() {
+    let x = [1.0f32, 2.0, 3.0];
+    let mut y = [0.5f32];
+
+    unsafe { saxpy_(&3, &2.0, x.as_ptr(), &1, y.as_mut_ptr(), &0) };
+
+    assert_eq!(y, [12.5]);
+}
+
+#[test]
+fn iamax_is_one_based() {
+    let x = [1.0f32, -7.0, 3.0, 7.0];
+
+    unsafe {
+        assert_eq!(isamax_(&4, x.as_ptr(), &1), 2);
+        assert_eq!(isamax_(&2, x.as_ptr(), &2), 2);
+        assert_eq!(isamax_(&0, x.as_ptr(), &1), 0);
+        assert_eq!(isamax_(&4, x.as_ptr(), &-1), 0);
+    }
+}
+
+#[test]
+fn sgemv_lowercase_and_conj_trans() {
+    let (m, n) = (7, 5);
+    let a = make_vec(m * n, 0.1);
+    let x = make_vec(m, 0.3);
+
+    let mut y_f = make_vec(n, 0.9);
+    let mut y_c = y_f.clone();
+
+    unsafe {
+        // 'c' on a real matrix is a transpose
+        sgemv_(
+            c"c".as_ptr(), &(m as i32), &(n as i32), &1.5, a.as_ptr(), &(m as i32),
+            x.as_ptr(), &1, &0.5, y_f.as_mut_ptr(), &1, 1,
+        );
+        cblas_sgemv(
+            CBLAS_COL_MAJOR, CBLAS_TRANS, m as i32, n as i32, 1.5, a.as_ptr(), m as i32,
+            x.as_ptr(), 1, 0.5, y_c.as_mut_ptr(), 1,
+        );
+    }
+    assert_eq!(y_f, y_c);
+}
+
+#[test]
+fn ztrsv_matches_cblas() {
+    let n = 9;
+    let mut a: Vec<f64> = (0..2 * n * n).map(|k| 0.1 * ((k * 5) % 7) as f64).collect();
+    for i in 0..n {
+        a[2 * (i + i * n)] += 4.0;
+    }
+    let x: Vec<f64> = (0..2 * n).map(|k| 1.0 - 0.2 * k as f64).collect();
+
+    let mut x_f = x.clone();
+    let mut x_c = x.clone();
+
+    unsafe {
+        ztrsv_(
+            c"L".as_ptr(), c"C".as_ptr(), c"N".as_ptr(), &(n as i32),
+            a.as_ptr().cast(), &(n as i32), x_f.as_mut_ptr().cast(), &1, 1, 1, 1,
+        );
+        cblas_ztrsv(
+            CBLAS_COL_MAJOR, CBLAS_LOWER, CBLAS_CONJ_TRANS, CBLAS_NON_UNIT, n as i32,
+            a.as_ptr().cast(), n as i32, x_c.as_mut_ptr().cast(), 1,
+        );
+    }
+    assert_eq!(x_f, x_c);
+}
+
+#[test]
+fn dgemm_matches_cblas() {
+    let (m, n, k) = (19, 23, 17);
+    let a: Vec<f64> = (0..k * m).map(|i| 0.01 * i as f64 - 1.0).collect();
+    let b: Vec<f64> = (0..k * n).map(|i| 0.5 - 0.02 * i as f64).collect();
+
+    let mut c_f = vec![1.0; m * n];
+    let mut c_c = c_f.clone();
+
+    unsafe {
+        dgemm_(
+            c"T".as_ptr(), c"n".as_ptr(), &(m as i32), &(n as i32), &(k as i32),
+            &0.75, a.as_ptr(), &(k as i32), b.as_ptr(), &(k as i32),
+            &-1.0, c_f.as_mut_ptr(), &(m as i32), 1, 1,
+        );
+        cblas_dgemm(
+            CBLAS_COL_MAJOR, CBLAS_TRANS, CBLAS_NO_TRANS, m as i32, n as i32, k as i32,
+            0.75, a.as_ptr(), k as i32, b.as_ptr(), k as i32,
+            -1.0, c_c.as_mut_ptr(), m as i32,
+        );
+    }
+    assert_eq!(c_f, c_c);
+}
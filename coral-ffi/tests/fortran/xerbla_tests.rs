@@ -0,0 +1,81 @@
+use core::ffi::{c_char, c_int};
+use std::sync::Mutex;
+
+use coral_ffi::cblas::enums::*;
+use coral_ffi::cblas::level2::cblas_sgemv;
+use coral_ffi::fortran::{level2::*, level3::*};
+use coral_ffi::xerbla::coral_set_xerbla;
+
+static LAST: Mutex<Option<(String, c_int)>> = Mutex::new(None);
+
+unsafe extern "C" fn record(name: *const c_char, info: *const c_int, len: usize) {
+    let name = unsafe { core::slice::from_raw_parts(name.cast::<u8>(), len) };
+    let name = String::from_utf8(name.to_vec()).unwrap();
+
+    *LAST.lock().unwrap() = Some((name, unsafe { *info }));
+}
+
+fn take() -> Option<(String, c_int)> {
+    LAST.lock().unwrap().take()
+}
+
+// one test: the handler is process-wide
+#[test]
+fn reference_error_numbering() {
+    coral_set_xerbla(Some(record));
+
+    let a = [0.0f32; 16];
+    let mut y = [5.0f32; 4];
+    let (two, four, zero, one) = (2, 4, 0, 1);
+
+    unsafe {
+        // SGEMV(TRANS, M, N, ALPHA, A, LDA, X, INCX, BETA, Y, INCY)
+        sgemv_(c"X".as_ptr(), &two, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &one, &0.0, y.as_mut_ptr(), &one, 1);
+        assert_eq!(take(), Some(("SGEMV".into(), 1)));
+
+        sgemv_(c"N".as_ptr(), &-1, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &one, &0.0, y.as_mut_ptr(), &one, 1);
+        assert_eq!(take(), Some(("SGEMV".into(), 2)));
+
+        sgemv_(c"N".as_ptr(), &four, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &one, &0.0, y.as_mut_ptr(), &one, 1);
+        assert_eq!(take(), Some(("SGEMV".into(), 6)));
+
+        sgemv_(c"N".as_ptr(), &two, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &zero, &0.0, y.as_mut_ptr(), &one, 1);
+        assert_eq!(take(), Some(("SGEMV".into(), 8)));
+
+        sgemv_(c"N".as_ptr(), &two, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &one, &0.0, y.as_mut_ptr(), &zero, 1);
+        assert_eq!(take(), Some(("SGEMV".into(), 11)));
+
+        // STRSV(UPLO, TRANS, DIAG, N, A, LDA, X, INCX)
+        strsv_(c"U".as_ptr(), c"N".as_ptr(), c"Q".as_ptr(), &two, a.as_ptr(), &two, y.as_mut_ptr(), &one, 1, 1, 1);
+        assert_eq!(take(), Some(("STRSV".into(), 3)));
+
+        // SSYR2(UPLO, N, ALPHA, X, INCX, Y, INCY, A, LDA)
+        let mut a2 = a;
+        ssyr2_(c"L".as_ptr(), &four, &1.0, a.as_ptr(), &one, a.as_ptr(), &one, a2.as_mut_ptr(), &two, 1);
+        assert_eq!(take(), Some(("SSYR2".into(), 9)));
+
+        // SGEMM(TRANSA, TRANSB, M, N, K, ALPHA, A, LDA, B, LDB, BETA, C, LDC)
+        sgemm_(
+            c"N".as_ptr(), c"T".as_ptr(), &two, &two, &four, &1.0, a.as_ptr(), &two,
+            a.as_ptr(), &one, &0.0, a2.as_mut_ptr(), &two, 1, 1,
+        );
+        assert_eq!(take(), Some(("SGEMM".into(), 10)));
+
+        sgemm_(
+            c"T".as_ptr(), c"N".as_ptr(), &two, &two, &four, &1.0, a.as_ptr(), &two,
+            a.as_ptr(), &four, &0.0, a2.as_mut_ptr(), &two, 1, 1,
+        );
+        assert_eq!(take(), Some(("SGEMM".into(), 8)));
+
+        // CBLAS errors go through the same handler, in CBLAS numbering
+        cblas_sgemv(CBLAS_COL_MAJOR, CBLAS_NO_TRANS, 2, 2, 1.0, a.as_ptr(), 1, a.as_ptr(), 1, 0.0, y.as_mut_ptr(), 1);
+        assert_eq!(take(), Some(("cblas_sgemv".into(), 7)));
+
+        // valid call, no report
+        sgemv_(c"n".as_ptr(), &two, &two, &1.0, a.as_ptr(), &two, a.as_ptr(), &one, &0.0, y.as_mut_ptr(), &one, 1);
+        assert_eq!(take(), None);
+    }
+
+    assert_eq!(y, [0.0, 0.0, 5.0, 5.0]);
+    coral_set_xerbla(None);
+}
@@ -23,3 +23,38 @@ impl fmt::Display for BufferError {
     }
 }
 impl std::error::Error for BufferError {} 
+
+/// Failures of the [`lapack`](crate::lapack) routines that are a property
+/// of the matrix, not of the buffers. 
+#[derive(Debug, PartialEq, Eq)]
+pub enum LapackError { 
+    /// `U[index, index]` of an LU factorization or of a triangular matrix, 
+    /// or the block of `D` at `index` of an LDL^T factorization, is exactly
+    /// zero. A factorization is complete, but cannot be used to solve a system.
+    Singular { index: usize }, 
+    /// The leading minor of order `index + 1` is not positive definite, 
+    /// so the Cholesky factorization could not be completed. 
+    NotPositiveDefinite { index: usize }, 
+    /// `R[index, index]` of a QR or LQ factorization is negligible next to
+    /// the largest diagonal entry, so the matrix does not have full rank. 
+    RankDeficient { index: usize }, 
+    /// An iterative eigenvalue or singular value sweep hit its iteration 
+    /// limit with `unconverged` off-diagonal entries not yet negligible. 
+    NoConvergence { unconverged: usize }, 
+}
+
+impl fmt::Display for LapackError { 
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self { 
+            LapackError::Singular { index } => 
+                write!(f, "pivot {index} is exactly zero; the matrix is singular"), 
+            LapackError::NotPositiveDefinite { index } => 
+                write!(f, "the leading minor of order {} is not positive definite", index + 1), 
+            LapackError::RankDeficient { index } => 
+                write!(f, "R({index}, {index}) is negligible; the matrix is rank deficient"), 
+            LapackError::NoConvergence { unconverged } => 
+                write!(f, "{unconverged} off-diagonal entries failed to converge"), 
+        }
+    }
+}
+impl std::error::Error for LapackError {} 
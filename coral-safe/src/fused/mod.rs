@@ -1,5 +1,5 @@
 pub mod saxpyf; 
 pub mod sdotf; 
 
-pub use saxpyf::saxpyf; 
-pub use sdotf::sdotf; 
+pub use saxpyf::{saxpyf, saxpyf_with}; 
+pub use sdotf::{sdotf, sdotf_with}; 
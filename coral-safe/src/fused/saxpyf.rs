@@ -6,13 +6,23 @@
 //! y \leftarrow y + A x
 //! \\]
 //!
+//! [saxpyf_with] takes an [Accuracy]; with [Accuracy::Compensated] each $y_i$ is 
+//! accumulated with `Dot2`, and with $u = 2^{-24}$, $\gamma_n = nu / (1 - nu)$ 
+//! and $s_i = y_i + (A x)_i$ the exact result,
+//!
+//! \\[ 
+//! \lvert \hat s_i - s_i \rvert \le u \lvert s_i \rvert + 
+//! \gamma\_{n+1}^2 \Big( \lvert y_i \rvert + \sum\_{j} \lvert a\_{ij} x_j \rvert \Big).
+//! \\]
+//!
 //! # Author 
 //! Deval Deliwala
 
 
 use std::simd::{Simd, StdFloat};
-use crate::types::{MatrixRef, VectorRef, VectorMut}; 
+use crate::types::{Accuracy, MatrixRef, VectorRef, VectorMut}; 
 use crate::level1::saxpy::saxpy;
+use crate::level1::compensated::Dot2; 
 
 const MR    : usize = 256; 
 const NR    : usize = 4; 
@@ -99,6 +109,7 @@ fn saxpyf_contiguous(
             while col < n_cols { 
                 let alpha = x[col]; 
                 if alpha == 0.0 { 
+                    col += 1; 
                     continue; 
                 }
 
@@ -200,3 +211,69 @@ pub fn saxpyf (
     }
 }
 
+/// Performs a matrix-vector multiply with 
+/// no scaling constants, at the requested [Accuracy].
+///
+/// [Accuracy::Fast] is [saxpyf]. [Accuracy::Compensated] sweeps 
+/// the columns with one `Dot2` accumulator per row, within the bound above. 
+///
+/// Arguments: 
+/// * `accuracy`: [Accuracy]
+/// * `a`: [MatrixRef] - over [f32] 
+/// * `x`: [VectorRef] - over [f32] 
+/// * `y`: [VectorMut] - over [f32] 
+///
+/// Returns: 
+/// Nothing. `y.data` is overwritten. 
+#[inline]
+pub fn saxpyf_with (
+    accuracy: Accuracy, 
+    a: MatrixRef<'_, f32>, 
+    x: VectorRef<'_, f32>, 
+    mut y: VectorMut<'_, f32>, 
+) {
+    if let Accuracy::Fast = accuracy { 
+        return saxpyf(a, x, y); 
+    }
+
+    let n_rows = a.n_rows(); 
+    let n_cols = a.n_cols(); 
+    let lda = a.lda(); 
+
+    if n_rows == 0 || n_cols == 0 { 
+        return;
+    }
+
+    let incx = x.stride(); 
+    let incy = y.stride(); 
+    let aoff = a.offset(); 
+    let yoff = y.offset(); 
+    let xs = &x.as_slice()[x.offset()..]; 
+    let ys = y.as_slice_mut(); 
+
+    // y_i joins each sum as its first term
+    let mut acc: Vec<Dot2> = ys[yoff..].iter().step_by(incy).take(n_rows)
+        .map(|&v| { 
+            let mut d = Dot2::default(); 
+            d.add(v); 
+            d 
+        })
+        .collect(); 
+
+    for (col_idx, &alpha) in xs.iter().step_by(incx).take(n_cols).enumerate() { 
+        if alpha == 0.0 { 
+            continue; 
+        }
+
+        let col_beg = aoff + col_idx * lda; 
+        let col = &a.as_slice()[col_beg .. col_beg + n_rows]; 
+
+        for (d, &av) in acc.iter_mut().zip(col) { 
+            d.fma(av, alpha); 
+        }
+    }
+
+    for (yv, d) in ys[yoff..].iter_mut().step_by(incy).zip(&acc) { 
+        *yv = d.value(); 
+    }
+}
@@ -7,14 +7,24 @@
 //! y \leftarrow y + A^T x
 //! \\]
 //!
+//! [sdotf_with] takes an [Accuracy]; with [Accuracy::Compensated] each $y_j$ is 
+//! accumulated with `Dot2`, and with $u = 2^{-24}$, $\gamma_n = nu / (1 - nu)$ 
+//! and $s_j = y_j + a_j^T x$ the exact result,
+//!
+//! \\[ 
+//! \lvert \hat s_j - s_j \rvert \le u \lvert s_j \rvert + 
+//! \gamma\_{m+1}^2 \Big( \lvert y_j \rvert + \sum\_{i} \lvert a\_{ij} x_i \rvert \Big).
+//! \\]
+//!
 //! # Author 
 //! Deval Deliwalia
 
 
 use std::simd::{Simd, StdFloat}; 
 use std::simd::num::SimdFloat;
-use crate::types::{MatrixRef, VectorRef, VectorMut}; 
+use crate::types::{Accuracy, MatrixRef, VectorRef, VectorMut}; 
 use crate::level1::sdot; 
+use crate::level1::compensated::Dot2; 
 
 const MR    : usize = 256; 
 const NR    : usize = 4; 
@@ -201,3 +211,60 @@ pub fn sdotf (
         ys[col_idx * incy] += sum; 
     }
 }
+
+/// Performs a transpose matrix-vector multiply with 
+/// no scaling constants, at the requested [Accuracy].
+///
+/// [Accuracy::Fast] is [sdotf]. [Accuracy::Compensated] is 
+/// a scalar `Dot2` per column, within the bound above. 
+///
+/// Arguments: 
+/// * `accuracy`: [Accuracy]
+/// * `a`: [MatrixRef] - over [f32] 
+/// * `x`: [VectorRef] - over [f32] 
+/// * `y`: [VectorMut] - over [f32] 
+///
+/// Returns: 
+/// Nothing. `y.data` is overwritten. 
+#[inline] 
+pub fn sdotf_with ( 
+    accuracy: Accuracy, 
+    a: MatrixRef<'_, f32>, 
+    x: VectorRef<'_, f32>, 
+    mut y: VectorMut<'_, f32> 
+) { 
+    if let Accuracy::Fast = accuracy { 
+        return sdotf(a, x, y); 
+    }
+
+    let n_rows = a.n_rows(); 
+    let n_cols = a.n_cols(); 
+    let lda = a.lda(); 
+
+    if n_rows == 0 || n_cols == 0 { 
+        return; 
+    }
+
+    let incx = x.stride(); 
+    let incy = y.stride(); 
+    let aoff = a.offset(); 
+    let yoff = y.offset(); 
+    let xs = &x.as_slice()[x.offset()..]; 
+    let ys = y.as_slice_mut(); 
+
+    for col_idx in 0..n_cols { 
+        let col_beg = aoff + col_idx * lda; 
+        let col = &a.as_slice()[col_beg .. col_beg + n_rows]; 
+        let yj  = &mut ys[yoff + col_idx * incy]; 
+
+        // y_j joins the sum as its first term
+        let mut acc = Dot2::default(); 
+        acc.add(*yj); 
+
+        for (&av, &xv) in col.iter().zip(xs.iter().step_by(incx)) { 
+            acc.fma(av, xv); 
+        }
+
+        *yj = acc.value(); 
+    }
+}
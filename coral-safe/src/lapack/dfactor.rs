@@ -0,0 +1,130 @@
+//! Unblocked [f64] LU and Cholesky factorizations and solves, the fallback 
+//! of the mixed precision drivers when refinement does not converge. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::CoralTriangular; 
+
+
+/// LU with partial pivoting of the `n x n` `a`. Returns the first exactly
+/// zero pivot. 
+pub(crate) fn dgetrf_slices(n: usize, a: &mut [f64], lda: usize, ipiv: &mut [usize]) -> Option<usize> { 
+    let mut info = None; 
+
+    for j in 0..n { 
+        let col = &a[j * lda .. j * lda + n]; 
+        let p = (j..n).fold(j, |p, i| if col[i].abs() > col[p].abs() { i } else { p }); 
+        ipiv[j] = p; 
+
+        if a[p + j * lda] == 0.0 { 
+            info.get_or_insert(j); 
+            continue; 
+        }
+
+        if p != j { 
+            for c in 0..n { 
+                a.swap(j + c * lda, p + c * lda); 
+            }
+        }
+
+        let r = 1.0 / a[j + j * lda]; 
+        let (left, right) = a.split_at_mut((j + 1) * lda); 
+        let lcol = &mut left[j * lda + j + 1 .. j * lda + n]; 
+        lcol.iter_mut().for_each(|v| *v *= r); 
+
+        // A22 -= l u^T 
+        for c in 0..n - j - 1 { 
+            let ccol = &mut right[c * lda .. c * lda + n]; 
+            let t = ccol[j]; 
+            if t != 0.0 { 
+                for (v, &l) in ccol[j + 1..].iter_mut().zip(lcol.iter()) { 
+                    *v -= l * t; 
+                }
+            }
+        }
+    }
+
+    info
+}
+
+/// `A X = B` with the factors of [dgetrf_slices] 
+pub(crate) fn dgetrs_slices(n: usize, nrhs: usize, a: &[f64], lda: usize, ipiv: &[usize], b: &mut [f64], ldb: usize) { 
+    for j in 0..nrhs { 
+        let x = &mut b[j * ldb .. j * ldb + n]; 
+
+        for (i, &p) in ipiv.iter().enumerate().take(n) { 
+            x.swap(i, p); 
+        }
+
+        // L y = P b 
+        for k in 0..n { 
+            let xk = x[k]; 
+            for (v, &l) in x[k + 1..].iter_mut().zip(&a[k * lda + k + 1 .. k * lda + n]) { 
+                *v -= l * xk; 
+            }
+        }
+
+        // U x = y 
+        for k in (0..n).rev() { 
+            x[k] /= a[k + k * lda]; 
+            let xk = x[k]; 
+            for (v, &u) in x[..k].iter_mut().zip(&a[k * lda .. k * lda + k]) { 
+                *v -= u * xk; 
+            }
+        }
+    }
+}
+
+/// Cholesky factorization of the `uplo` triangle of the `n x n` `a`. Returns
+/// the order of the first minor that is not positive definite, less one. 
+pub(crate) fn dpotrf_slices(uplo: CoralTriangular, n: usize, a: &mut [f64], lda: usize) -> Option<usize> { 
+    for j in 0..n { 
+        // the factor's row (upper) or column (lower) j runs over a[at(k, j)] 
+        let at = |k: usize, c: usize| match uplo { 
+            CoralTriangular::Upper => k + c * lda, 
+            CoralTriangular::Lower => c + k * lda, 
+        }; 
+
+        let ajj = a[j + j * lda] - (0..j).map(|k| a[at(k, j)] * a[at(k, j)]).sum::<f64>(); 
+        if ajj <= 0.0 || ajj.is_nan() { 
+            return Some(j); 
+        }
+
+        let ajj = ajj.sqrt(); 
+        a[j + j * lda] = ajj; 
+
+        for c in j + 1..n { 
+            let s = (0..j).map(|k| a[at(k, j)] * a[at(k, c)]).sum::<f64>(); 
+            a[at(j, c)] = (a[at(j, c)] - s) / ajj; 
+        }
+    }
+
+    None 
+}
+
+/// `A X = B` with the factor of [dpotrf_slices] 
+pub(crate) fn dpotrs_slices(uplo: CoralTriangular, n: usize, nrhs: usize, a: &[f64], lda: usize, b: &mut [f64], ldb: usize) { 
+    // F[k, c], k <= c, of A = F^T F 
+    let f = |k: usize, c: usize| match uplo { 
+        CoralTriangular::Upper => a[k + c * lda], 
+        CoralTriangular::Lower => a[c + k * lda], 
+    }; 
+
+    for j in 0..nrhs { 
+        let x = &mut b[j * ldb .. j * ldb + n]; 
+
+        // F^T y = b 
+        for i in 0..n { 
+            let s = (0..i).map(|k| f(k, i) * x[k]).sum::<f64>(); 
+            x[i] = (x[i] - s) / f(i, i); 
+        }
+
+        // F x = y 
+        for i in (0..n).rev() { 
+            let s = (i + 1..n).map(|k| f(i, k) * x[k]).sum::<f64>(); 
+            x[i] = (x[i] - s) / f(i, i); 
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! LAPACK [`?DSGESV`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/dsgesv.f)
+//! routine in mixed precision. 
+//!
+//! Solves the [f64] system 
+//!
+//! \\[ 
+//! A X = B 
+//! \\]
+//!
+//! by factoring $A$ with [sgetrf](crate::lapack::sgetrf) in [f32] and 
+//! refining the solution against [f64] residuals. If $A$ or a residual does 
+//! not fit in [f32], the [f32] factorization fails, or refinement stagnates, 
+//! $A$ is factored and solved in [f64] instead. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{sgetrf, sgetrs}; 
+use crate::lapack::dfactor::{dgetrf_slices, dgetrs_slices}; 
+use crate::lapack::dsrefine::{refine, to_f32}; 
+use crate::types::{CoralRefinement, CoralTranspose, MatrixRef, MatrixMut}; 
+
+
+/// Solves a general [f64] system through an [f32] LU factorization and 
+/// iterative refinement. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f64], `n x n`. Left untouched on 
+///   [CoralRefinement::Converged], overwritten with its [f64] LU factors 
+///   on [CoralRefinement::FellBack] 
+/// * `ipiv`: &mut [[usize]] - length at least `n`, the pivots of the last 
+///   factorization 
+/// * `b`: [MatrixRef] - over [f64], `n x nrhs` 
+/// * `x`: [MatrixMut] - over [f64], `n x nrhs`, the solution 
+///
+/// Returns: 
+/// * `Ok(`[CoralRefinement]`)` with how the solution was reached 
+/// * [LapackError::Singular] if the [f64] fallback finds `U` singular 
+pub fn dsgesv ( 
+    mut a: MatrixMut<'_, f64>, 
+    ipiv: &mut [usize], 
+    b: MatrixRef<'_, f64>, 
+    mut x: MatrixMut<'_, f64>, 
+) -> Result<CoralRefinement, LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let nrhs = b.n_cols(); 
+    assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+    debug_assert_eq!(b.n_rows(), n, "rows of B must equal order of A"); 
+    debug_assert!(x.n_rows() == n && x.n_cols() == nrhs, "X must be the shape of B"); 
+
+    if n == 0 || nrhs == 0 { 
+        return Ok(CoralRefinement::Converged(0)); 
+    }
+
+    let lda  = a.lda(); 
+    let ldb  = b.lda(); 
+    let ldx  = x.lda(); 
+    let aoff = a.offset(); 
+    let xoff = x.offset(); 
+    let bd   = &b.as_slice()[b.offset()..]; 
+    let xd   = &mut x.as_slice_mut()[xoff..]; 
+    let ad   = &mut a.as_slice_mut()[aoff..]; 
+
+    // |A|_inf 
+    let anrm = (0..n)
+        .map(|i| (0..n).map(|j| ad[i + j * lda].abs()).sum::<f64>())
+        .fold(0.0, f64::max); 
+
+    let packed: Vec<f64> = (0..n).flat_map(|j| ad[j * lda .. j * lda + n].iter().copied()).collect(); 
+    if let Some(mut sa) = to_f32(&packed) { 
+        let factored = sgetrf(MatrixMut::new(&mut sa, n, n, n, 0).expect("a32 view"), ipiv).is_ok(); 
+
+        if factored { 
+            let ad: &[f64] = ad; 
+            let piv: &[usize] = ipiv; 
+
+            let solve = |w: &mut [f32]| { 
+                sgetrs( 
+                    CoralTranspose::NoTrans, 
+                    MatrixRef::new(&sa, n, n, n, 0).expect("a32 view"), 
+                    piv, 
+                    MatrixMut::new(w, n, nrhs, n, 0).expect("w view"), 
+                ); 
+            }; 
+
+            // r = b - A x 
+            let residual = |x: &[f64], r: &mut [f64]| { 
+                for j in 0..nrhs { 
+                    let rj = &mut r[j * n .. (j + 1) * n]; 
+                    rj.copy_from_slice(&bd[j * ldb .. j * ldb + n]); 
+                    for p in 0..n { 
+                        let xp = x[p + j * ldx]; 
+                        for (ri, &av) in rj.iter_mut().zip(&ad[p * lda .. p * lda + n]) { 
+                            *ri -= av * xp; 
+                        }
+                    }
+                }
+            }; 
+
+            if let Some(iter) = refine(n, nrhs, anrm, bd, ldb, xd, ldx, solve, residual) { 
+                return Ok(CoralRefinement::Converged(iter)); 
+            }
+        }
+    }
+
+    // f64 fallback 
+    if let Some(index) = dgetrf_slices(n, ad, lda, ipiv) { 
+        return Err(LapackError::Singular { index }); 
+    }
+    for j in 0..nrhs { 
+        xd[j * ldx .. j * ldx + n].copy_from_slice(&bd[j * ldb .. j * ldb + n]); 
+    }
+    dgetrs_slices(n, nrhs, ad, lda, ipiv, xd, ldx); 
+
+    Ok(CoralRefinement::FellBack) 
+}
@@ -0,0 +1,120 @@
+//! LAPACK [`?DSPOSV`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/dsposv.f)
+//! routine in mixed precision. 
+//!
+//! Solves the [f64] symmetric positive definite system 
+//!
+//! \\[ 
+//! A X = B 
+//! \\]
+//!
+//! by factoring $A$ with [spotrf](crate::lapack::spotrf) in [f32] and 
+//! refining the solution against [f64] residuals. If $A$ or a residual does 
+//! not fit in [f32], the [f32] factorization fails, or refinement stagnates, 
+//! $A$ is factored and solved in [f64] instead. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{spotrf, spotrs}; 
+use crate::lapack::dfactor::{dpotrf_slices, dpotrs_slices}; 
+use crate::lapack::dsrefine::{refine, to_f32}; 
+use crate::types::{CoralRefinement, CoralTriangular, MatrixRef, MatrixMut}; 
+
+
+/// Solves a symmetric positive definite [f64] system through an [f32] 
+/// Cholesky factorization and iterative refinement. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced 
+/// * `a`: [MatrixMut] - over [f64], `n x n`. Left untouched on 
+///   [CoralRefinement::Converged], the `uplo` triangle is overwritten with 
+///   the [f64] Cholesky factor on [CoralRefinement::FellBack] 
+/// * `b`: [MatrixRef] - over [f64], `n x nrhs` 
+/// * `x`: [MatrixMut] - over [f64], `n x nrhs`, the solution 
+///
+/// Returns: 
+/// * `Ok(`[CoralRefinement]`)` with how the solution was reached 
+/// * [LapackError::NotPositiveDefinite] if the [f64] fallback fails 
+pub fn dsposv ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f64>, 
+    b: MatrixRef<'_, f64>, 
+    mut x: MatrixMut<'_, f64>, 
+) -> Result<CoralRefinement, LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let nrhs = b.n_cols(); 
+    debug_assert_eq!(b.n_rows(), n, "rows of B must equal order of A"); 
+    debug_assert!(x.n_rows() == n && x.n_cols() == nrhs, "X must be the shape of B"); 
+
+    if n == 0 || nrhs == 0 { 
+        return Ok(CoralRefinement::Converged(0)); 
+    }
+
+    let lda  = a.lda(); 
+    let ldb  = b.lda(); 
+    let ldx  = x.lda(); 
+    let aoff = a.offset(); 
+    let xoff = x.offset(); 
+    let bd   = &b.as_slice()[b.offset()..]; 
+    let xd   = &mut x.as_slice_mut()[xoff..]; 
+    let ad   = &mut a.as_slice_mut()[aoff..]; 
+
+    // A[i, j] read from the uplo triangle 
+    let sym = |i: usize, j: usize| match uplo { 
+        CoralTriangular::Upper => i.min(j) + i.max(j) * lda, 
+        CoralTriangular::Lower => i.max(j) + i.min(j) * lda, 
+    }; 
+
+    // |A|_inf 
+    let anrm = (0..n)
+        .map(|i| (0..n).map(|j| ad[sym(i, j)].abs()).sum::<f64>())
+        .fold(0.0, f64::max); 
+
+    let packed: Vec<f64> = (0..n).flat_map(|j| (0..n).map(move |i| (i, j))).map(|(i, j)| ad[sym(i, j)]).collect(); 
+    if let Some(mut sa) = to_f32(&packed) { 
+        let factored = spotrf(uplo, MatrixMut::new(&mut sa, n, n, n, 0).expect("a32 view")).is_ok(); 
+
+        if factored { 
+            let solve = |w: &mut [f32]| { 
+                spotrs( 
+                    uplo, 
+                    MatrixRef::new(&sa, n, n, n, 0).expect("a32 view"), 
+                    MatrixMut::new(w, n, nrhs, n, 0).expect("w view"), 
+                ); 
+            }; 
+
+            // r = b - A x, A mirrored from the packed copy 
+            let residual = |x: &[f64], r: &mut [f64]| { 
+                for j in 0..nrhs { 
+                    let rj = &mut r[j * n .. (j + 1) * n]; 
+                    rj.copy_from_slice(&bd[j * ldb .. j * ldb + n]); 
+                    for p in 0..n { 
+                        let xp = x[p + j * ldx]; 
+                        for (ri, &av) in rj.iter_mut().zip(&packed[p * n .. (p + 1) * n]) { 
+                            *ri -= av * xp; 
+                        }
+                    }
+                }
+            }; 
+
+            if let Some(iter) = refine(n, nrhs, anrm, bd, ldb, xd, ldx, solve, residual) { 
+                return Ok(CoralRefinement::Converged(iter)); 
+            }
+        }
+    }
+
+    // f64 fallback 
+    if let Some(index) = dpotrf_slices(uplo, n, ad, lda) { 
+        return Err(LapackError::NotPositiveDefinite { index }); 
+    }
+    for j in 0..nrhs { 
+        xd[j * ldx .. j * ldx + n].copy_from_slice(&bd[j * ldb .. j * ldb + n]); 
+    }
+    dpotrs_slices(uplo, n, nrhs, ad, lda, xd, ldx); 
+
+    Ok(CoralRefinement::FellBack) 
+}
@@ -0,0 +1,78 @@
+pub mod slaswp; 
+pub mod sgetrf; 
+pub mod sgetrs; 
+pub mod sgesv; 
+
+pub mod spotrf; 
+pub mod spotrs; 
+pub mod sposv; 
+
+pub mod slarfg; 
+pub(crate) mod slarfb; 
+pub mod sgeqrf; 
+pub mod sormqr; 
+pub mod sorgqr; 
+pub mod sgels; 
+
+pub mod ssytrf; 
+pub mod ssytrs; 
+pub mod ssyinertia; 
+
+pub mod ssytrd; 
+pub mod sorgtr; 
+pub mod ssteqr; 
+pub mod ssyev; 
+
+pub mod sgesvd; 
+
+pub mod strtri; 
+pub mod sgetri; 
+pub mod spotri; 
+pub mod sdet; 
+
+pub mod slacn2; 
+pub mod sgecon; 
+pub mod spocon; 
+
+pub(crate) mod dfactor; 
+pub(crate) mod dsrefine; 
+pub mod dsgesv; 
+pub mod dsposv; 
+
+pub use slaswp::slaswp; 
+pub use sgetrf::sgetrf; 
+pub use sgetrs::sgetrs; 
+pub use sgesv::sgesv; 
+
+pub use spotrf::spotrf; 
+pub use spotrs::spotrs; 
+pub use sposv::sposv; 
+
+pub use slarfg::slarfg; 
+pub use sgeqrf::sgeqrf; 
+pub use sormqr::sormqr; 
+pub use sorgqr::sorgqr; 
+pub use sgels::sgels; 
+
+pub use ssytrf::ssytrf; 
+pub use ssytrs::ssytrs; 
+pub use ssyinertia::ssyinertia; 
+
+pub use ssytrd::ssytrd; 
+pub use sorgtr::sorgtr; 
+pub use ssteqr::ssteqr; 
+pub use ssyev::ssyev; 
+
+pub use sgesvd::sgesvd; 
+
+pub use strtri::strtri; 
+pub use sgetri::sgetri; 
+pub use spotri::spotri; 
+pub use sdet::{sgedet, sgelogdet, spologdet}; 
+
+pub use slacn2::slacn2; 
+pub use sgecon::sgecon; 
+pub use spocon::spocon; 
+
+pub use dsgesv::dsgesv; 
+pub use dsposv::dsposv; 
@@ -0,0 +1,118 @@
+//! Determinants from the LU and Cholesky factorizations in single precision. 
+//!
+//! With $A = P L U$ from [sgetrf](crate::lapack::sgetrf), 
+//!
+//! \\[ 
+//! \det A = (-1)^s \prod_i u_{ii}, 
+//! \\]
+//!
+//! where $s$ counts the rows that were interchanged, and with $A = L L^T$ 
+//! from [spotrf](crate::lapack::spotrf), $\log \det A = 2 \sum_i \log l_{ii}$. 
+//!
+//! # Notes 
+//! The determinant of even a well scaled matrix leaves the range of [f32] 
+//! quickly; $\det(2 I_{128})$ already overflows. The `logdet` variants sum 
+//! $\log \lvert u_{ii} \rvert$ in [f64] and never overflow. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::types::MatrixRef; 
+
+
+/// the diagonal of the square `a` 
+#[inline] 
+fn diagonal<'a>(a: &'a MatrixRef<'_, f32>) -> impl Iterator<Item = f32> + 'a { 
+    let n    = a.n_rows(); 
+    let lda  = a.lda(); 
+    let data = &a.as_slice()[a.offset()..]; 
+    (0..n).map(move |i| data[i + i * lda]) 
+}
+
+/// `-1.0` for an odd number of interchanges, `1.0` otherwise 
+#[inline] 
+fn permutation_sign(ipiv: &[usize]) -> f32 { 
+    let swaps = ipiv.iter().enumerate().filter(|&(i, &p)| i != p).count(); 
+    if swaps % 2 == 0 { 1.0 } else { -1.0 } 
+}
+
+
+/// Computes the determinant of a general matrix from its LU factors. 
+///
+/// Arguments: 
+/// * `a`: [MatrixRef] - over [f32], `n x n`, the factors from `sgetrf` 
+/// * `ipiv`: &[[usize]] - the pivots from `sgetrf` 
+///
+/// Returns: 
+/// * `Ok(det)`, accumulated in [f64]; infinite if it leaves the range of [f32] 
+/// * [LapackError::Singular] with the first exactly zero `U[i, i]` 
+pub fn sgedet ( 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[usize], 
+) -> Result<f32, LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+    assert!(ipiv.len() >= a.n_rows(), "ipiv must hold n pivots"); 
+
+    let mut det = permutation_sign(&ipiv[..a.n_rows()]) as f64; 
+    for (index, uii) in diagonal(&a).enumerate() { 
+        if uii == 0.0 { 
+            return Err(LapackError::Singular { index }); 
+        }
+        det *= uii as f64; 
+    }
+
+    Ok(det as f32) 
+}
+
+/// Computes the sign and the log of the absolute value of the determinant 
+/// of a general matrix from its LU factors. 
+///
+/// Arguments: 
+/// * `a`: [MatrixRef] - over [f32], `n x n`, the factors from `sgetrf` 
+/// * `ipiv`: &[[usize]] - the pivots from `sgetrf` 
+///
+/// Returns: 
+/// * `Ok((sign, logdet))` with `sign` either `1.0` or `-1.0` and 
+///   `det A = sign * exp(logdet)` 
+/// * [LapackError::Singular] with the first exactly zero `U[i, i]` 
+pub fn sgelogdet ( 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[usize], 
+) -> Result<(f32, f32), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+    assert!(ipiv.len() >= a.n_rows(), "ipiv must hold n pivots"); 
+
+    let mut sign   = permutation_sign(&ipiv[..a.n_rows()]); 
+    let mut logdet = 0.0f64; 
+    for (index, uii) in diagonal(&a).enumerate() { 
+        if uii == 0.0 { 
+            return Err(LapackError::Singular { index }); 
+        }
+        if uii < 0.0 { 
+            sign = -sign; 
+        }
+        logdet += (uii.abs() as f64).ln(); 
+    }
+
+    Ok((sign, logdet as f32)) 
+}
+
+/// Computes the log determinant of a symmetric positive definite matrix 
+/// from its Cholesky factor. 
+///
+/// Arguments: 
+/// * `a`: [MatrixRef] - over [f32], square, the factor from `spotrf` with 
+///   either triangle; only the diagonal is read 
+///
+/// Returns: 
+/// * [f32] `log det A`, always finite for a factor `spotrf` accepted 
+pub fn spologdet ( 
+    a: MatrixRef<'_, f32>, 
+) -> f32 { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let logdet: f64 = diagonal(&a).map(|lii| (lii as f64).ln()).sum(); 
+    (2.0 * logdet) as f32 
+}
@@ -0,0 +1,65 @@
+//! LAPACK [`?GECON`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgecon.f)
+//! routine in single precision. 
+//!
+//! Estimates the reciprocal 1-norm condition number of a general matrix 
+//! from its [sgetrf](crate::lapack::sgetrf) factors, 
+//!
+//! \\[ 
+//! \operatorname{rcond} = \frac{1}{\lVert A \rVert_1 \, \lVert A^{-1} \rVert_1}, 
+//! \\]
+//!
+//! with $\lVert A^{-1} \rVert_1$ from [slacn2](crate::lapack::slacn2) driven 
+//! by [sgetrs](crate::lapack::sgetrs) solves. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::lapack::{sgetrs, slacn2}; 
+use crate::types::{MatrixRef, MatrixMut}; 
+
+
+/// Estimates the reciprocal of the 1-norm condition number of a general 
+/// matrix. 
+///
+/// Arguments: 
+/// * `a`: [MatrixRef] - over [f32], `n x n`, the factors from `sgetrf` 
+/// * `ipiv`: &[[usize]] - the pivots from `sgetrf` 
+/// * `anorm`: [f32] - `|A|_1` of the matrix before it was factored 
+///
+/// Returns: 
+/// * [f32] an estimate of `1 / (|A|_1 |A^{-1}|_1)`; `0.0` when `U` is 
+///   exactly singular or `anorm == 0`, `1.0` when `n == 0` 
+pub fn sgecon ( 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[usize], 
+    anorm: f32, 
+) -> f32 { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+    assert!(anorm >= 0.0, "anorm must be non-negative"); 
+
+    let n = a.n_rows(); 
+    if n == 0 { 
+        return 1.0; 
+    }
+    if anorm == 0.0 { 
+        return 0.0; 
+    }
+
+    let lda  = a.lda(); 
+    let data = &a.as_slice()[a.offset()..]; 
+    if (0..n).any(|i| data[i + i * lda] == 0.0) { 
+        return 0.0; 
+    }
+
+    // A^{-1} x and A^{-T} x 
+    let ainvnm = slacn2(n, |trans, x| { 
+        sgetrs(trans, a, ipiv, MatrixMut::new(x, n, 1, n, 0).expect("x view")); 
+    }); 
+
+    if ainvnm.is_finite() && ainvnm > 0.0 { 
+        (1.0 / ainvnm) / anorm 
+    } else { 
+        0.0 
+    }
+}
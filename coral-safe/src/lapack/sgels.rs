@@ -0,0 +1,174 @@
+//! LAPACK [`?GELS`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgels.f)
+//! routine in single precision. 
+//!
+//! Solves full rank linear least squares problems with $\operatorname{op}(A)$
+//! `r x c`, $\operatorname{op}(A) \in \\{A, A^T \\}$: 
+//!
+//! * `r >= c`, overdetermined: minimizes $\lVert B - \operatorname{op}(A) X \rVert_F$
+//!   through the QR factorization of $\operatorname{op}(A)$. 
+//! * `r < c`, underdetermined: the minimum norm $X$ with 
+//!   $\operatorname{op}(A) X = B$ through the LQ factorization 
+//!   $\operatorname{op}(A) = L Q$. 
+//!
+//! # Notes 
+//! The LQ factorization of a wide matrix is the QR factorization of its
+//! transpose, $L = R^T$, so both cases run through 
+//! [`sgeqrf`](crate::lapack::sgeqrf) and [`sormqr`](crate::lapack::sormqr). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{sgeqrf, sormqr}; 
+use crate::level1::snrm2; 
+use crate::level3::strsm; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixRef, 
+    MatrixMut, 
+    VectorRef, 
+}; 
+
+
+/// `A := F^T` for the `n x m` factor `F` of `A^T`, so `A` holds `L` and the
+/// reflectors of `Q` by rows 
+fn store_rows(ad: &mut [f32], lda: usize, m: usize, n: usize, at: &[f32]) { 
+    for j in 0..n { 
+        for i in 0..m { 
+            ad[i + j * lda] = at[j + i * n]; 
+        }
+    }
+}
+
+
+/// Solves an overdetermined or underdetermined full rank system in the
+/// least squares sense. 
+///
+/// Arguments: 
+/// * `trans`: [CoralTranspose] - solve with `A` or `A^T` 
+/// * `a`: [MatrixMut] - over [f32], `m x n`; overwritten with its QR 
+///   factorization if `m >= n`, otherwise with its LQ factorization, the 
+///   QR factorization of `A^T` stored by rows. Also overwritten when rank 
+///   deficient 
+/// * `b`: [MatrixMut] - over [f32], at least `max(m, n)` rows and `nrhs` 
+///   columns. Input as `B` in the first `r` rows, output as `X` in the first
+///   `c` rows. For an overdetermined system, rows `c..r` hold the residual 
+///   in the basis of `Q`. 
+///
+/// Returns: 
+/// * `Ok(rss)`, the residual sum of squares of each column of `B`; zeros 
+///   for an underdetermined system 
+/// * [LapackError::RankDeficient] with the first negligible diagonal entry
+///   of `R`; `b` is left untouched 
+pub fn sgels ( 
+    trans: CoralTranspose, 
+    mut a: MatrixMut<'_, f32>, 
+    mut b: MatrixMut<'_, f32>, 
+) -> Result<Vec<f32>, LapackError> { 
+    let m    = a.n_rows(); 
+    let n    = a.n_cols(); 
+    let nrhs = b.n_cols(); 
+    let big  = m.max(n); 
+    let s    = m.min(n); 
+
+    assert!(b.n_rows() >= big, "b must have at least max(n_rows, n_cols) rows of a"); 
+
+    let mut rss = vec![0.0; nrhs]; 
+    if s == 0 { 
+        return Ok(rss); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    // the tall one of A and A^T, as big x s 
+    let mut at = Vec::new(); 
+    let (f, ldf, foff): (&mut [f32], usize, usize) = if m >= n { 
+        (a.as_slice_mut(), lda, aoff) 
+    } else { 
+        let ad = &a.as_slice()[aoff..]; 
+        at.extend((0..m).flat_map(|i| (0..n).map(move |j| ad[i + j * lda]))); 
+        (&mut at, n, 0) 
+    }; 
+
+    let mut tau = vec![0.0; s]; 
+    sgeqrf(MatrixMut::new(f, big, s, ldf, foff).expect("factor view"), &mut tau); 
+    let f: &[f32] = f; 
+
+    // full rank, judged from the diagonal of R 
+    let rdiag = |i: usize| f[foff + i * (ldf + 1)].abs(); 
+    let rmax  = (0..s).map(rdiag).fold(0.0, f32::max); 
+    let tol   = rmax * big as f32 * f32::EPSILON; 
+
+    if let Some(index) = (0..s).find(|&i| rdiag(i) <= tol) { 
+        if m < n { 
+            store_rows(&mut a.as_slice_mut()[aoff..], lda, m, n, &at); 
+        }
+        return Err(LapackError::RankDeficient { index }); 
+    }
+
+    let ldb  = b.lda(); 
+    let boff = b.offset(); 
+    let bs   = b.as_slice_mut(); 
+
+    let fq = || MatrixRef::new(f, big, s, ldf, foff).expect("factor view"); 
+    let fr = || MatrixRef::new(f, s, s, ldf, foff).expect("R view"); 
+
+    // op(A) is the factored matrix when it is tall 
+    let overdetermined = match trans { 
+        CoralTranspose::NoTrans => m >= n, 
+        CoralTranspose::Trans   => m < n, 
+    }; 
+
+    if overdetermined { 
+        // Q^T B, then R X = (Q^T B)[0..s] 
+        let bq = MatrixMut::new(bs, big, nrhs, ldb, boff).expect("b view"); 
+        sormqr(CoralSide::Left, CoralTranspose::Trans, fq(), &tau, bq); 
+
+        for (j, r) in rss.iter_mut().enumerate() { 
+            let col = &bs[boff + s + j * ldb .. boff + big + j * ldb]; 
+            let nrm = snrm2(VectorRef::new(col, big - s, 1, 0).expect("residual view")); 
+            *r = nrm * nrm; 
+        }
+
+        let bx = MatrixMut::new(bs, s, nrhs, ldb, boff).expect("b view"); 
+        strsm ( 
+            CoralSide::Left, 
+            CoralTriangular::Upper, 
+            CoralTranspose::NoTrans, 
+            CoralDiagonal::NonUnit, 
+            1.0, 
+            fr(), 
+            bx, 
+        ); 
+    } else { 
+        // R^T Y = B, then X = Q [Y; 0] 
+        let by = MatrixMut::new(bs, s, nrhs, ldb, boff).expect("b view"); 
+        strsm ( 
+            CoralSide::Left, 
+            CoralTriangular::Upper, 
+            CoralTranspose::Trans, 
+            CoralDiagonal::NonUnit, 
+            1.0, 
+            fr(), 
+            by, 
+        ); 
+
+        for j in 0..nrhs { 
+            bs[boff + s + j * ldb .. boff + big + j * ldb].fill(0.0); 
+        }
+
+        let bq = MatrixMut::new(bs, big, nrhs, ldb, boff).expect("b view"); 
+        sormqr(CoralSide::Left, CoralTranspose::NoTrans, fq(), &tau, bq); 
+    }
+
+    if m < n { 
+        store_rows(&mut a.as_slice_mut()[aoff..], lda, m, n, &at); 
+    }
+
+    Ok(rss) 
+}
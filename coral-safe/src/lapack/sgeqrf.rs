@@ -0,0 +1,129 @@
+//! LAPACK [`?GEQRF`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgeqrf.f)
+//! routine in single precision. 
+//!
+//! Householder QR factorization of an `m x n` matrix 
+//!
+//! \\[ 
+//! A = Q R, \quad Q = H_0 H_1 \cdots H\_{k-1}, \quad k = \min(m, n), 
+//! \\]
+//!
+//! with $H_i = I - \tau_i v_i v_i^T$. $R$ is stored on and above the diagonal
+//! and $v_i$, without its unit leading entry, below it. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{saxpy, sdot}; 
+use crate::lapack::slarfg::slarfg_slices; 
+use crate::lapack::slarfb::{pack_v, slarfb_slices, slarft_slices}; 
+use crate::types::{CoralSide, CoralTranspose, MatrixMut, VectorRef, VectorMut}; 
+
+// panel width
+const NB: usize = 32; 
+
+
+/// Unblocked QR of the `m x nb` panel at the start of `a`. Each reflector 
+/// is applied to the rest of the panel with a dot and an axpy per column. 
+fn sgeqr2 ( 
+    m: usize, 
+    nb: usize, 
+    a: &mut [f32], 
+    lda: usize, 
+    tau: &mut [f32], 
+) { 
+    for i in 0..nb { 
+        // the panel's last column may end before a full lda 
+        let split = ((i + 1) * lda).min(a.len()); 
+        let (left, right) = a.split_at_mut(split); 
+        let col = &mut left[i * lda + i .. i * lda + m]; 
+
+        let (alpha, x) = col.split_first_mut().expect("nonempty column"); 
+        let ti = slarfg_slices(m - i - 1, alpha, x, 1); 
+        tau[i] = ti; 
+
+        if ti == 0.0 { 
+            continue; 
+        }
+
+        // A[i.., c] -= tau v (v^T A[i.., c]), v = [1, x] 
+        let xv = VectorRef::new(x, m - i - 1, 1, 0).expect("v view"); 
+        for c in 0..nb - i - 1 { 
+            let dst = &mut right[c * lda + i .. c * lda + m]; 
+            let (head, tail) = dst.split_first_mut().expect("nonempty column"); 
+
+            let tv = VectorRef::new(tail, m - i - 1, 1, 0).expect("column view"); 
+            let w  = ti * (*head + sdot(xv, tv)); 
+
+            *head -= w; 
+            saxpy(-w, xv, VectorMut::new(tail, m - i - 1, 1, 0).expect("column view")); 
+        }
+    }
+}
+
+
+/// Computes the QR factorization of a general matrix. 
+///
+/// Panels of `32` columns are factored one reflector at a time; the 
+/// trailing matrix is updated with the panel's compact WY form 
+/// `I - V T V^T` through gemm. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f32], overwritten with `R` and the reflectors 
+/// * `tau`: &mut [[f32]] - length at least `min(m, n)`, the scalars `tau_i` 
+///
+/// Returns: 
+/// Nothing. `a.data` and `tau` are overwritten. 
+pub fn sgeqrf ( 
+    mut a: MatrixMut<'_, f32>, 
+    tau: &mut [f32], 
+) { 
+    let m = a.n_rows(); 
+    let n = a.n_cols(); 
+    let k = m.min(n); 
+
+    assert!(tau.len() >= k, "tau must hold min(n_rows, n_cols) scalars"); 
+
+    if k == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    let mut v    = Vec::new(); 
+    let mut t    = vec![0.0; NB * NB]; 
+    let mut work = Vec::new(); 
+
+    let mut j = 0; 
+    while j < k { 
+        let jb = (k - j).min(NB); 
+        let mj = m - j; 
+
+        sgeqr2(mj, jb, &mut data[j + j * lda..], lda, &mut tau[j .. j + jb]); 
+
+        // A[j.., j+jb..] := H^T A[j.., j+jb..] 
+        if j + jb < n { 
+            pack_v(mj, jb, &data[j + j * lda..], lda, &mut v); 
+            slarft_slices(mj, jb, &v, mj, &tau[j .. j + jb], &mut t, NB); 
+
+            slarfb_slices ( 
+                CoralSide::Left, 
+                CoralTranspose::Trans, 
+                mj, 
+                n - j - jb, 
+                jb, 
+                &v, 
+                mj, 
+                &t, 
+                NB, 
+                &mut data[j + (j + jb) * lda..], 
+                lda, 
+                &mut work, 
+            ); 
+        }
+
+        j += jb; 
+    }
+}
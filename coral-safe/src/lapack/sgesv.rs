@@ -0,0 +1,52 @@
+//! LAPACK [`?GESV`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgesv.f)
+//! routine in single precision. 
+//!
+//! Solves 
+//!
+//! \\[ 
+//! A X = B 
+//! \\]
+//!
+//! for a general `n x n` matrix $A$ through [`sgetrf`](crate::lapack::sgetrf)
+//! and [`sgetrs`](crate::lapack::sgetrs). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{sgetrf, sgetrs}; 
+use crate::types::{CoralTranspose, MatrixRef, MatrixMut}; 
+
+
+/// Factors `a` and solves a general system. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f32], `n x n`, overwritten with its LU factors
+/// * `ipiv`: &mut [[usize]] - length at least `n`, the pivots of `sgetrf`
+/// * `b`: [MatrixMut] - over [f32], `n x nrhs`, input as `B`, output as `X` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::Singular] if `U` is singular; `b` is left untouched 
+pub fn sgesv ( 
+    mut a: MatrixMut<'_, f32>, 
+    ipiv: &mut [usize], 
+    b: MatrixMut<'_, f32>, 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    let factors = MatrixMut::new(a.as_slice_mut(), n, n, lda, aoff)
+        .expect("a view"); 
+    sgetrf(factors, ipiv)?; 
+
+    let factors = MatrixRef::new(a.as_slice(), n, n, lda, aoff)
+        .expect("a view"); 
+    sgetrs(CoralTranspose::NoTrans, factors, ipiv, b); 
+
+    Ok(())
+}
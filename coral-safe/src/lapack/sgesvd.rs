@@ -0,0 +1,315 @@
+//! LAPACK [`?GESVD`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgesvd.f)
+//! routine in single precision. 
+//!
+//! Computes the singular value decomposition of an `m x n` matrix 
+//!
+//! \\[ 
+//! A = U \Sigma V^T, \quad \sigma_0 \ge \sigma_1 \ge \cdots \ge 0. 
+//! \\]
+//!
+//! # Notes 
+//! Unlike the reference, which goes through a bidiagonal QR iteration, this 
+//! is one-sided (Hestenes) Jacobi, as in `?GESVJ`. The columns of $G = A$ (or 
+//! $A^T$ when $m < n$) are rotated pairwise by [srot] until every pair is 
+//! numerically orthogonal, 
+//!
+//! \\[ 
+//! \lvert g_p^T g_q \rvert \le \sqrt{m}\, \epsilon \lVert g_p \rVert_2 \lVert g_q \rVert_2, 
+//! \\]
+//!
+//! accumulating the same rotations into $V$. Then $\sigma_j = \lVert g_j \rVert_2$ 
+//! through [snrm2] and $u_j = g_j / \sigma_j$. Singular vectors of zero 
+//! singular values, and the extra columns of a full factor, complete the 
+//! basis by orthogonalizing unit vectors. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::{saxpy, sdot, snrm2, srot, sscal, sswap}; 
+use crate::types::{CoralSvdJob, MatrixRef, MatrixMut, VectorRef, VectorMut}; 
+
+const MAXSWEEP: usize = 60; 
+
+
+/// columns `p < q` of the column major `rows x _` matrix `g` 
+#[inline] 
+fn col_pair(g: &mut [f32], rows: usize, p: usize, q: usize) -> (&mut [f32], &mut [f32]) { 
+    let (left, right) = g.split_at_mut(q * rows); 
+    (&mut left[p * rows .. (p + 1) * rows], &mut right[..rows]) 
+}
+
+#[inline] 
+fn dot(x: &[f32], y: &[f32]) -> f32 { 
+    let n = x.len(); 
+    sdot(VectorRef::new(x, n, 1, 0).expect("x view"), VectorRef::new(y, n, 1, 0).expect("y view")) 
+}
+
+#[inline] 
+fn nrm2(x: &[f32]) -> f32 { 
+    snrm2(VectorRef::new(x, x.len(), 1, 0).expect("x view")) 
+}
+
+#[inline] 
+fn rot(x: &mut [f32], y: &mut [f32], c: f32, s: f32) { 
+    let n = x.len(); 
+    srot(VectorMut::new(x, n, 1, 0).expect("x view"), VectorMut::new(y, n, 1, 0).expect("y view"), c, s); 
+}
+
+/// cyclic one-sided Jacobi sweeps over the `rows x cols` `g`, `rows >= cols`,
+/// accumulating into the `cols x cols` `v` 
+fn jacobi(rows: usize, cols: usize, g: &mut [f32], mut v: Option<&mut [f32]>) -> Result<(), LapackError> { 
+    let tol = (rows as f32).sqrt() * f32::EPSILON; 
+
+    let mut rotated = 0; 
+    for _ in 0..MAXSWEEP { 
+        rotated = 0; 
+
+        for p in 0..cols.saturating_sub(1) { 
+            for q in p + 1..cols { 
+                let (gp, gq) = col_pair(g, rows, p, q); 
+
+                let alpha = dot(gp, gp); 
+                let beta  = dot(gq, gq); 
+                let gamma = dot(gp, gq); 
+
+                if alpha == 0.0 || beta == 0.0 || gamma.abs() <= tol * alpha.sqrt() * beta.sqrt() { 
+                    continue; 
+                }
+                rotated += 1; 
+
+                // the rotation that diagonalizes [[alpha, gamma], [gamma, beta]] 
+                let zeta = (beta - alpha) / (2.0 * gamma); 
+                let t = 1.0f32.copysign(zeta) / (zeta.abs() + zeta.hypot(1.0)); 
+                let c = 1.0 / t.hypot(1.0); 
+                let s = c * t; 
+
+                // (g_p, g_q) := (c g_p - s g_q, s g_p + c g_q) 
+                rot(gp, gq, c, -s); 
+                if let Some(v) = v.as_deref_mut() { 
+                    let (vp, vq) = col_pair(v, cols, p, q); 
+                    rot(vp, vq, c, -s); 
+                }
+            }
+        }
+
+        if rotated == 0 { 
+            return Ok(()); 
+        }
+    }
+
+    Err(LapackError::NoConvergence { unconverged: rotated }) 
+}
+
+/// fills the columns of the `rows x ncols` `q` not yet `filled` with unit 
+/// vectors orthogonal to every other column 
+fn complete_basis(q: &mut [f32], rows: usize, ncols: usize, ldq: usize, filled: &mut [bool]) { 
+    // some e_k keeps at least 1 / sqrt(rows) of its norm outside the span 
+    let thresh = 0.5 / (rows as f32).sqrt(); 
+    let mut w = vec![0.0; rows]; 
+    let mut cand = 0; 
+
+    for j in 0..ncols { 
+        if filled[j] { 
+            continue; 
+        }
+
+        loop { 
+            assert!(cand < rows, "no unit vector left to complete the basis"); 
+            w.fill(0.0); 
+            w[cand] = 1.0; 
+            cand += 1; 
+
+            // twice is enough 
+            for _ in 0..2 { 
+                for i in (0..ncols).filter(|&i| filled[i]) { 
+                    let qi = &q[i * ldq .. i * ldq + rows]; 
+                    let h  = dot(qi, &w); 
+                    saxpy(-h, VectorRef::new(qi, rows, 1, 0).expect("q view"), VectorMut::new(&mut w, rows, 1, 0).expect("w view")); 
+                }
+            }
+
+            let nrm = nrm2(&w); 
+            if nrm > thresh { 
+                sscal(1.0 / nrm, VectorMut::new(&mut w, rows, 1, 0).expect("w view")); 
+                q[j * ldq .. j * ldq + rows].copy_from_slice(&w); 
+                filled[j] = true; 
+                break; 
+            }
+        }
+    }
+}
+
+/// the normalized columns of the `rows x cols` `g` as the first columns of 
+/// the `rows x ncols` `q`, completed to orthonormal 
+fn left_vectors(rows: usize, cols: usize, g: &[f32], sigma: &[f32], q: &mut [f32], ldq: usize, ncols: usize) { 
+    let mut filled = vec![false; ncols]; 
+
+    for j in 0..cols.min(ncols) { 
+        let dst = &mut q[j * ldq .. j * ldq + rows]; 
+        if sigma[j] > 0.0 { 
+            for (d, &v) in dst.iter_mut().zip(&g[j * rows .. (j + 1) * rows]) { 
+                *d = v / sigma[j]; 
+            }
+            filled[j] = true; 
+        }
+    }
+
+    complete_basis(q, rows, ncols, ldq, &mut filled); 
+}
+
+/// the `cols` expected of a `U` or `V^T` factor for `job` 
+#[inline] 
+fn factor_cols(job: CoralSvdJob, k: usize, full: usize) -> usize { 
+    match job { 
+        CoralSvdJob::NoVectors => 0, 
+        CoralSvdJob::Thin      => k, 
+        CoralSvdJob::Full      => full, 
+    }
+}
+
+
+/// Computes the singular values, and optionally the left and right singular 
+/// vectors, of a general matrix. 
+///
+/// Arguments: 
+/// * `jobu`: [CoralSvdJob] - which columns of `U` are computed 
+/// * `jobvt`: [CoralSvdJob] - which rows of `V^T` are computed 
+/// * `a`: [MatrixRef] - over [f32], `m x n`, not modified 
+/// * `s`: &mut [[f32]] - length `min(m, n)`, the singular values in 
+///   descending order 
+/// * `u`: [Option] of [MatrixMut] - over [f32], `m x min(m, n)` for 
+///   [CoralSvdJob::Thin] or `m x m` for [CoralSvdJob::Full]; `None` 
+///   with [CoralSvdJob::NoVectors] 
+/// * `vt`: [Option] of [MatrixMut] - over [f32], `min(m, n) x n` for 
+///   [CoralSvdJob::Thin] or `n x n` for [CoralSvdJob::Full]; `None` 
+///   with [CoralSvdJob::NoVectors] 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::NoConvergence] if the Jacobi sweeps did not converge; 
+///   `s`, `u` and `vt` are not written 
+pub fn sgesvd ( 
+    jobu: CoralSvdJob, 
+    jobvt: CoralSvdJob, 
+    a: MatrixRef<'_, f32>, 
+    s: &mut [f32], 
+    mut u: Option<MatrixMut<'_, f32>>, 
+    mut vt: Option<MatrixMut<'_, f32>>, 
+) -> Result<(), LapackError> { 
+    let m = a.n_rows(); 
+    let n = a.n_cols(); 
+    let k = m.min(n); 
+    assert!(s.len() >= k, "s must hold min(m, n) singular values"); 
+
+    let ucols = factor_cols(jobu, k, m); 
+    let vrows = factor_cols(jobvt, k, n); 
+    match &u { 
+        Some(u) => assert!(u.n_rows() == m && u.n_cols() == ucols && ucols > 0, "U must be m x min(m, n) or m x m"), 
+        None    => assert_eq!(ucols, 0, "U is required unless jobu is NoVectors"), 
+    }
+    match &vt { 
+        Some(vt) => assert!(vt.n_rows() == vrows && vt.n_cols() == n && vrows > 0, "V^T must be min(m, n) x n or n x n"), 
+        None     => assert_eq!(vrows, 0, "V^T is required unless jobvt is NoVectors"), 
+    }
+
+    // rotate the columns of A, or of A^T when it is wide, so G is tall 
+    let tall = m >= n; 
+    let (rows, cols) = if tall { (m, n) } else { (n, m) }; 
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let ad   = &a.as_slice()[aoff..]; 
+
+    let mut g = vec![0.0; rows * cols]; 
+    for j in 0..n { 
+        for i in 0..m { 
+            let (r, c) = if tall { (i, j) } else { (j, i) }; 
+            g[r + c * rows] = ad[i + j * lda]; 
+        }
+    }
+
+    // scale to max |a_ij| = 1, so the squared norms cannot overflow 
+    let amax = g.iter().fold(0.0f32, |mx, v| mx.max(v.abs())); 
+    if amax > 0.0 { 
+        g.iter_mut().for_each(|v| *v /= amax); 
+    }
+
+    // G's right vectors are V for a tall A and U for a wide one 
+    let (gjob, ojob) = if tall { (jobvt, jobu) } else { (jobu, jobvt) }; 
+    let want_right = gjob != CoralSvdJob::NoVectors; 
+    let want_left  = ojob != CoralSvdJob::NoVectors; 
+
+    let mut v = if want_right { 
+        let mut v = vec![0.0; cols * cols]; 
+        for j in 0..cols { 
+            v[j + j * cols] = 1.0; 
+        }
+        Some(v) 
+    } else { 
+        None 
+    }; 
+
+    jacobi(rows, cols, &mut g, v.as_deref_mut())?; 
+
+    let mut sigma: Vec<f32> = (0..cols).map(|j| nrm2(&g[j * rows .. (j + 1) * rows])).collect(); 
+
+    // descending, moving the columns of G and V along 
+    for i in 0..cols.saturating_sub(1) { 
+        let j = (i..cols).fold(i, |j, q| if sigma[q] > sigma[j] { q } else { j }); 
+        if j != i { 
+            sigma.swap(i, j); 
+
+            let (gi, gj) = col_pair(&mut g, rows, i, j); 
+            sswap(VectorMut::new(gi, rows, 1, 0).expect("g view"), VectorMut::new(gj, rows, 1, 0).expect("g view")); 
+            if let Some(v) = v.as_deref_mut() { 
+                let (vi, vj) = col_pair(v, cols, i, j); 
+                sswap(VectorMut::new(vi, cols, 1, 0).expect("v view"), VectorMut::new(vj, cols, 1, 0).expect("v view")); 
+            }
+        }
+    }
+
+    for (sj, &sg) in s.iter_mut().zip(&sigma) { 
+        *sj = sg * amax; 
+    }
+
+    // the left vectors of G, rows x (cols or rows) 
+    let ocols = if ojob == CoralSvdJob::Full { rows } else { cols }; 
+    let mut q = Vec::new(); 
+    if want_left { 
+        q = vec![0.0; rows * ocols]; 
+        left_vectors(rows, cols, &g, &sigma, &mut q, rows, ocols); 
+    }
+
+    // write U, and V^T transposed 
+    let write = |out: &mut MatrixMut<'_, f32>, src: &[f32], ld: usize, transpose: bool| { 
+        let (r, c) = (out.n_rows(), out.n_cols()); 
+        let ldo  = out.lda(); 
+        let ooff = out.offset(); 
+        let od   = &mut out.as_slice_mut()[ooff..]; 
+        for j in 0..c { 
+            for i in 0..r { 
+                od[i + j * ldo] = if transpose { src[j + i * ld] } else { src[i + j * ld] }; 
+            }
+        }
+    }; 
+
+    if tall { 
+        if let Some(u) = u.as_mut() { 
+            write(u, &q, rows, false); 
+        }
+        if let (Some(vt), Some(v)) = (vt.as_mut(), v.as_deref()) { 
+            write(vt, v, cols, true); 
+        }
+    } else { 
+        if let (Some(u), Some(v)) = (u.as_mut(), v.as_deref()) { 
+            write(u, v, cols, false); 
+        }
+        if let Some(vt) = vt.as_mut() { 
+            write(vt, &q, rows, true); 
+        }
+    }
+
+    Ok(()) 
+}
@@ -0,0 +1,221 @@
+//! LAPACK [`?GETRF`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgetrf.f)
+//! routine in single precision. 
+//!
+//! LU factorization with partial pivoting 
+//!
+//! \\[ 
+//! A = P L U
+//! \\]
+//!
+//! of an `m x n` matrix, where $L$ is unit lower triangular (lower 
+//! trapezoidal if `m > n`) and $U$ is upper triangular (upper trapezoidal
+//! if `m < n`). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::{isamax, sscal}; 
+use crate::level2::sger; 
+use crate::level3::sgemm::sgemm_slices; 
+use crate::level3::strsm::strsm_slices; 
+use crate::lapack::slaswp::slaswp_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralDirection, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixMut, 
+    VectorRef, 
+    VectorMut, 
+}; 
+
+// panel width
+const NB: usize = 64; 
+
+
+/// Unblocked, right-looking LU of the `m x nb` panel at the start of `a`,
+/// `m >= nb`. Pivots are relative to the panel. Returns the first zero
+/// pivot, if any. 
+fn sgetf2 ( 
+    m: usize, 
+    nb: usize, 
+    a: &mut [f32], 
+    lda: usize, 
+    ipiv: &mut [usize], 
+) -> Option<usize> { 
+    let sfmin = f32::MIN_POSITIVE; 
+    let mut info = None; 
+
+    for j in 0..nb { 
+        // largest |a| on or below the diagonal 
+        let col = VectorRef::new(&a[j + j * lda .. m + j * lda], m - j, 1, 0)
+            .expect("column view"); 
+        let p = j + isamax(col); 
+        ipiv[j] = p; 
+
+        let pivot = a[p + j * lda]; 
+        if pivot != 0.0 { 
+            if p != j { 
+                for c in 0..nb { 
+                    a.swap(j + c * lda, p + c * lda); 
+                }
+            }
+
+            // multipliers, without overflowing 1 / pivot 
+            if j + 1 < m { 
+                let below = &mut a[j + 1 + j * lda .. m + j * lda]; 
+
+                if pivot.abs() >= sfmin { 
+                    let view = VectorMut::new(below, m - j - 1, 1, 0)
+                        .expect("column view"); 
+                    sscal(1.0 / pivot, view); 
+                } else { 
+                    for v in below { 
+                        *v /= pivot; 
+                    }
+                }
+            }
+        } else if info.is_none() { 
+            info = Some(j); 
+        }
+
+        // A[j+1.., j+1..] -= A[j+1.., j] A[j, j+1..]
+        if j + 1 < m && j + 1 < nb { 
+            let (left, right) = a.split_at_mut((j + 1) * lda); 
+
+            let x = &left[j + 1 + j * lda .. m + j * lda]; 
+            let y: Vec<f32> = (0..nb - j - 1).map(|c| right[j + c * lda]).collect(); 
+
+            let xview = VectorRef::new(x, m - j - 1, 1, 0)
+                .expect("column view"); 
+            let yview = VectorRef::new(&y, nb - j - 1, 1, 0)
+                .expect("row view"); 
+            let aview = MatrixMut::new(&mut right[j + 1..], m - j - 1, nb - j - 1, lda, 0)
+                .expect("trailing view"); 
+
+            sger(-1.0, aview, xview, yview); 
+        }
+    }
+
+    info
+}
+
+
+/// Computes the LU factorization of a general matrix with partial pivoting. 
+///
+/// Panels of `64` columns are factored with [isamax], row swaps, [sscal]
+/// and [sger]; the trailing matrix is updated with a triangular solve and
+/// a gemm. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f32], overwritten with `L` (unit diagonal not
+///   stored) and `U`
+/// * `ipiv`: &mut [[usize]] - length at least `min(m, n)`; row `i` was
+///   interchanged with row `ipiv[i]`, 0-based
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::Singular] with the first `i` where `U[i, i] == 0`. The
+///   factorization is still completed. 
+pub fn sgetrf ( 
+    mut a: MatrixMut<'_, f32>, 
+    ipiv: &mut [usize], 
+) -> Result<(), LapackError> { 
+    let m   = a.n_rows(); 
+    let n   = a.n_cols(); 
+    let mn  = m.min(n); 
+
+    assert!(ipiv.len() >= mn, "ipiv must hold min(n_rows, n_cols) pivots"); 
+
+    if mn == 0 { 
+        return Ok(()); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    let mut info = None; 
+    let mut a12  = Vec::new(); 
+
+    let mut j = 0; 
+    while j < mn { 
+        let jb = (mn - j).min(NB); 
+
+        // panel A[j.., j..j+jb]
+        let panel_info = sgetf2 ( 
+            m - j, 
+            jb, 
+            &mut data[j + j * lda..], 
+            lda, 
+            &mut ipiv[j .. j + jb], 
+        ); 
+        if let (None, Some(i)) = (info, panel_info) { 
+            info = Some(j + i); 
+        }
+
+        for p in &mut ipiv[j .. j + jb] { 
+            *p += j; 
+        }
+
+        // same interchanges left of the panel
+        slaswp_slices(CoralDirection::Forward, j, data, lda, ipiv, j, j + jb); 
+
+        if j + jb < n { 
+            let (left, right) = data.split_at_mut((j + jb) * lda); 
+            let nr = n - j - jb; 
+
+            // and right of it
+            slaswp_slices(CoralDirection::Forward, nr, right, lda, ipiv, j, j + jb); 
+
+            // A12 := L11^{-1} A12 
+            strsm_slices ( 
+                CoralSide::Left, 
+                CoralTriangular::Lower, 
+                CoralTranspose::NoTrans, 
+                CoralDiagonal::Unit, 
+                jb, 
+                nr, 
+                1.0, 
+                &left[j + j * lda..], 
+                lda, 
+                &mut right[j..], 
+                lda, 
+            ); 
+
+            // A22 -= A21 A12, with A12 copied out of the columns being written 
+            if j + jb < m { 
+                a12.clear(); 
+                for c in 0..nr { 
+                    a12.extend_from_slice(&right[j + c * lda .. j + jb + c * lda]); 
+                }
+
+                sgemm_slices ( 
+                    CoralTranspose::NoTrans, 
+                    CoralTranspose::NoTrans, 
+                    m - j - jb, 
+                    nr, 
+                    jb, 
+                    -1.0, 
+                    &left[j + jb + j * lda..], 
+                    lda, 
+                    &a12, 
+                    jb, 
+                    1.0, 
+                    &mut right[j + jb..], 
+                    lda, 
+                ); 
+            }
+        }
+
+        j += jb; 
+    }
+
+    match info { 
+        Some(index) => Err(LapackError::Singular { index }), 
+        None        => Ok(()), 
+    }
+}
@@ -0,0 +1,137 @@
+//! LAPACK [`?GETRI`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgetri.f)
+//! routine in single precision. 
+//!
+//! Inverts a general matrix from its [sgetrf](crate::lapack::sgetrf) 
+//! factors, 
+//!
+//! \\[ 
+//! A^{-1} = U^{-1} L^{-1} P^T, 
+//! \\]
+//!
+//! by inverting $U$ with [strtri](crate::lapack::strtri) and solving 
+//! $X L = U^{-1}$ for $X$ one block of columns at a time, last block first. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::strtri; 
+use crate::level1::sswap; 
+use crate::level3::sgemm::sgemm_slices; 
+use crate::level3::strsm::strsm_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixMut, 
+    VectorMut, 
+}; 
+
+// column block width 
+const NB: usize = 64; 
+
+
+/// Overwrites the LU factors of `A` with `A^{-1}`. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f32], `n x n`, the factors from `sgetrf` 
+/// * `ipiv`: &[[usize]] - the pivots from `sgetrf` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::Singular] if `U` has an exactly zero diagonal entry; 
+///   `a` is left untouched 
+pub fn sgetri ( 
+    mut a: MatrixMut<'_, f32>, 
+    ipiv: &[usize], 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    strtri( 
+        CoralTriangular::Upper, 
+        CoralDiagonal::NonUnit, 
+        MatrixMut::new(a.as_slice_mut(), n, n, lda, aoff).expect("a view"), 
+    )?; 
+
+    let data = &mut a.as_slice_mut()[aoff..]; 
+    let mut work = vec![0.0; n * NB]; 
+
+    let n_blocks = n.div_ceil(NB); 
+    for blk in (0..n_blocks).rev() { 
+        let j0 = blk * NB; 
+        let j1 = (j0 + NB).min(n); 
+        let jb = j1 - j0; 
+
+        // move L[.., j0..j1] into work, leaving U^{-1} in its columns 
+        for j in j0..j1 { 
+            let w = &mut work[(j - j0) * n .. (j - j0 + 1) * n]; 
+            w[..j + 1].fill(0.0); 
+            for i in j + 1..n { 
+                w[i] = data[i + j * lda]; 
+                data[i + j * lda] = 0.0; 
+            }
+        }
+
+        // X[.., j0..j1] -= X[.., j1..] L[j1.., j0..j1] 
+        let (head, tail) = data.split_at_mut(j1 * lda); 
+        if j1 < n { 
+            sgemm_slices( 
+                CoralTranspose::NoTrans, 
+                CoralTranspose::NoTrans, 
+                n, 
+                jb, 
+                n - j1, 
+                -1.0, 
+                tail, 
+                lda, 
+                &work[j1..], 
+                n, 
+                1.0, 
+                &mut head[j0 * lda..], 
+                lda, 
+            ); 
+        }
+
+        // X[.., j0..j1] := X[.., j0..j1] L[j0..j1, j0..j1]^{-1} 
+        strsm_slices( 
+            CoralSide::Right, 
+            CoralTriangular::Lower, 
+            CoralTranspose::NoTrans, 
+            CoralDiagonal::Unit, 
+            n, 
+            jb, 
+            1.0, 
+            &work[j0..], 
+            n, 
+            &mut head[j0 * lda..], 
+            lda, 
+        ); 
+    }
+
+    // A^{-1} = X P^T: undo the row interchanges as column interchanges 
+    for j in (0..n - 1).rev() { 
+        let jp = ipiv[j]; 
+        if jp != j { 
+            let (lo, hi) = (j.min(jp), j.max(jp)); 
+            let (left, right) = data.split_at_mut(hi * lda); 
+            sswap( 
+                VectorMut::new(&mut left[lo * lda .. lo * lda + n], n, 1, 0).expect("column view"), 
+                VectorMut::new(&mut right[..n], n, 1, 0).expect("column view"), 
+            ); 
+        }
+    }
+
+    Ok(()) 
+}
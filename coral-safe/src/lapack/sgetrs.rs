@@ -0,0 +1,84 @@
+//! LAPACK [`?GETRS`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sgetrs.f)
+//! routine in single precision. 
+//!
+//! Solves 
+//!
+//! \\[ 
+//! \operatorname{op}(A) X = B, \quad \operatorname{op}(A) \in \\{A, A^T \\}, 
+//! \\]
+//!
+//! with the factors $A = P L U$ from [`sgetrf`](crate::lapack::sgetrf). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level3::strsm::strsm_slices; 
+use crate::lapack::slaswp::slaswp_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralDirection, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixRef, 
+    MatrixMut, 
+}; 
+
+
+/// Solves a general system with the LU factors from `sgetrf`. 
+///
+/// Arguments: 
+/// * `trans`: [CoralTranspose] - solve with `A` or `A^T` 
+/// * `a`: [MatrixRef] - over [f32], the `n x n` factors from `sgetrf`
+/// * `ipiv`: &[[usize]] - the `n` pivots from `sgetrf`
+/// * `b`: [MatrixMut] - over [f32], `n x nrhs`, input as `B`, output as `X` 
+///
+/// Returns: 
+/// Nothing. `b.data` is overwritten. 
+#[inline] 
+pub fn sgetrs ( 
+    trans: CoralTranspose, 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[usize], 
+    mut b: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let nrhs = b.n_cols(); 
+
+    debug_assert_eq!(b.n_rows(), n, "rows of b must equal the order of a"); 
+    debug_assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+
+    if n == 0 || nrhs == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let ldb  = b.lda(); 
+    let boff = b.offset(); 
+
+    let a  = &a.as_slice()[aoff..]; 
+    let bs = &mut b.as_slice_mut()[boff..]; 
+
+    let solve = |bs: &mut [f32], uplo, diag| { 
+        strsm_slices(CoralSide::Left, uplo, trans, diag, n, nrhs, 1.0, a, lda, bs, ldb); 
+    }; 
+
+    match trans { 
+        // X = U^{-1} L^{-1} P^T B
+        CoralTranspose::NoTrans => { 
+            slaswp_slices(CoralDirection::Forward, nrhs, bs, ldb, ipiv, 0, n); 
+            solve(bs, CoralTriangular::Lower, CoralDiagonal::Unit); 
+            solve(bs, CoralTriangular::Upper, CoralDiagonal::NonUnit); 
+        }
+        // X = P L^{-T} U^{-T} B
+        CoralTranspose::Trans => { 
+            solve(bs, CoralTriangular::Upper, CoralDiagonal::NonUnit); 
+            solve(bs, CoralTriangular::Lower, CoralDiagonal::Unit); 
+            slaswp_slices(CoralDirection::Backward, nrhs, bs, ldb, ipiv, 0, n); 
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! LAPACK [`?LACN2`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/slacn2.f)
+//! routine in single precision. 
+//!
+//! Estimates the 1-norm of a square matrix $B$ that is only available 
+//! through products, 
+//!
+//! \\[ 
+//! \gamma \le \lVert B \rVert_1 = \max_j \sum_i \lvert b_{ij} \rvert, 
+//! \\]
+//!
+//! typically $B = A^{-1}$ applied by solves with the factors of $A$. 
+//!
+//! # Notes 
+//! Hager's method with Higham's refinements. Starting from $x = e / n$, it 
+//! alternates $y = B x$ and $z = B^T \operatorname{sign}(y)$, moving to 
+//! $x = e_j$ with $j$ from [isamax] on $z$, for at most 5 iterations. The 
+//! estimate is the largest [sasum] seen, or the alternating-sign test vector 
+//!
+//! \\[ 
+//! x_i = (-1)^{i} \Big( 1 + \frac{i}{n - 1} \Big) 
+//! \\]
+//!
+//! when $\tfrac{2}{3n} \lVert B x \rVert_1$ is larger, which catches the 
+//! matrices the main iteration underestimates. Where the reference returns to 
+//! the caller with `KASE` for each product, here the products are a closure. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{isamax, sasum}; 
+use crate::types::{CoralTranspose, VectorRef}; 
+
+const ITMAX: usize = 5; 
+
+
+#[inline] 
+fn asum(x: &[f32]) -> f32 { 
+    sasum(VectorRef::new(x, x.len(), 1, 0).expect("x view")) 
+}
+
+#[inline] 
+fn iamax(x: &[f32]) -> usize { 
+    isamax(VectorRef::new(x, x.len(), 1, 0).expect("x view")) 
+}
+
+/// `x := sign(x)` with `sign(0) = 1`. Returns whether it matches `isgn`, 
+/// which is then overwritten. 
+#[inline] 
+fn sign_vector(x: &mut [f32], isgn: &mut [f32]) -> bool { 
+    let mut same = true; 
+    for (xi, si) in x.iter_mut().zip(isgn.iter_mut()) { 
+        *xi = if *xi >= 0.0 { 1.0 } else { -1.0 }; 
+        same &= *xi == *si; 
+        *si = *xi; 
+    }
+    same 
+}
+
+
+/// Estimates the 1-norm of an `n x n` matrix `B` from products with `B` 
+/// and `B^T`. 
+///
+/// Arguments: 
+/// * `n`: [usize] - the order of `B` 
+/// * `apply`: `FnMut(`[CoralTranspose]`, &mut [f32])` - overwrites its 
+///   length `n` argument `x` with `B x` for [CoralTranspose::NoTrans] or 
+///   `B^T x` for [CoralTranspose::Trans] 
+///
+/// Returns: 
+/// * [f32] a lower bound for `|B|_1`, usually within a factor of 3 and 
+///   often exact, after at most 11 products 
+pub fn slacn2<F> ( 
+    n: usize, 
+    mut apply: F, 
+) -> f32 
+where 
+    F: FnMut(CoralTranspose, &mut [f32]), 
+{ 
+    if n == 0 { 
+        return 0.0; 
+    }
+
+    let mut x = vec![1.0 / n as f32; n]; 
+    apply(CoralTranspose::NoTrans, &mut x); 
+
+    if n == 1 { 
+        return x[0].abs(); 
+    }
+
+    let mut est  = asum(&x); 
+    let mut isgn = vec![0.0; n]; 
+    sign_vector(&mut x, &mut isgn); 
+
+    apply(CoralTranspose::Trans, &mut x); 
+    let mut j    = iamax(&x); 
+    let mut iter = 2; 
+
+    loop { 
+        // y = B e_j 
+        x.fill(0.0); 
+        x[j] = 1.0; 
+        apply(CoralTranspose::NoTrans, &mut x); 
+
+        let estold = est; 
+        est = asum(&x); 
+
+        // a repeated sign vector or no growth, converged 
+        if sign_vector(&mut x, &mut isgn) || est <= estold { 
+            est = est.max(estold); 
+            break; 
+        }
+
+        apply(CoralTranspose::Trans, &mut x); 
+        let jlast = j; 
+        j = iamax(&x); 
+
+        if x[jlast] == x[j].abs() || iter >= ITMAX { 
+            break; 
+        }
+        iter += 1; 
+    }
+
+    // alternating sign test vector 
+    let denom = (n - 1) as f32; 
+    for (i, xi) in x.iter_mut().enumerate() { 
+        let v = 1.0 + i as f32 / denom; 
+        *xi = if i % 2 == 0 { v } else { -v }; 
+    }
+    apply(CoralTranspose::NoTrans, &mut x); 
+
+    let temp = 2.0 * asum(&x) / (3 * n) as f32; 
+    est.max(temp) 
+}
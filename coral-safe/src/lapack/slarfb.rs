@@ -0,0 +1,181 @@
+//! Compact WY blocks of Householder reflectors, after LAPACK 
+//! [`?LARFT`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/slarft.f) 
+//! and [`?LARFB`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/slarfb.f). 
+//!
+//! A block of `k` forward reflectors is written 
+//!
+//! \\[ 
+//! H_0 H_1 \cdots H_{k-1} = I - V T V^T, 
+//! \\]
+//!
+//! with `V` unit lower trapezoidal and `T` upper triangular, and applied 
+//! through gemm. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level3::sgemm::sgemm_slices; 
+use crate::types::{CoralSide, CoralTranspose}; 
+
+
+/// explicit `m x k` `V`, unit diagonal and zeros above, from reflectors
+/// stored below the diagonal of `a` 
+pub(crate) fn pack_v ( 
+    m: usize, 
+    k: usize, 
+    a: &[f32], 
+    lda: usize, 
+    v: &mut Vec<f32>, 
+) { 
+    v.clear(); 
+    v.resize(m * k, 0.0); 
+
+    for j in 0..k { 
+        let col = &mut v[j * m .. (j + 1) * m]; 
+        col[j] = 1.0; 
+        col[j + 1..].copy_from_slice(&a[j + 1 + j * lda .. m + j * lda]); 
+    }
+}
+
+/// upper triangular `k x k` `T` with `H_0 .. H_{k-1} = I - V T V^T` 
+pub(crate) fn slarft_slices ( 
+    m: usize, 
+    k: usize, 
+    v: &[f32], 
+    ldv: usize, 
+    tau: &[f32], 
+    t: &mut [f32], 
+    ldt: usize, 
+) { 
+    for i in 0..k { 
+        let ti = tau[i]; 
+
+        if ti == 0.0 { 
+            for r in 0..=i { 
+                t[r + i * ldt] = 0.0; 
+            }
+            continue; 
+        }
+
+        // T[0..i, i] := -tau_i V[:, 0..i]^T v_i 
+        if i > 0 { 
+            sgemm_slices ( 
+                CoralTranspose::Trans, 
+                CoralTranspose::NoTrans, 
+                i, 
+                1, 
+                m, 
+                -ti, 
+                v, 
+                ldv, 
+                &v[i * ldv..], 
+                ldv, 
+                0.0, 
+                &mut t[i * ldt..], 
+                ldt, 
+            ); 
+        }
+
+        // T[0..i, i] := T[0..i, 0..i] T[0..i, i] 
+        for r in 0..i { 
+            let mut acc = 0.0; 
+            for p in r..i { 
+                acc += t[r + p * ldt] * t[p + i * ldt]; 
+            }
+            t[r + i * ldt] = acc; 
+        }
+
+        t[i + i * ldt] = ti; 
+    }
+}
+
+/// `W := op(T) W` for `k x n` `W`, or `W := W op(T)` for `m x k` `W` 
+#[allow(clippy::too_many_arguments)]
+fn strmm_t ( 
+    side: CoralSide, 
+    op: CoralTranspose, 
+    k: usize, 
+    cols: usize, 
+    t: &[f32], 
+    ldt: usize, 
+    w: &mut [f32], 
+    ldw: usize, 
+) { 
+    match (side, op) { 
+        (CoralSide::Left, CoralTranspose::NoTrans) => { 
+            for c in 0..cols { 
+                let wc = &mut w[c * ldw .. c * ldw + k]; 
+                for r in 0..k { 
+                    wc[r] = (r..k).map(|p| t[r + p * ldt] * wc[p]).sum(); 
+                }
+            }
+        }
+        (CoralSide::Left, CoralTranspose::Trans) => { 
+            for c in 0..cols { 
+                let wc = &mut w[c * ldw .. c * ldw + k]; 
+                for r in (0..k).rev() { 
+                    wc[r] = (0..=r).map(|p| t[p + r * ldt] * wc[p]).sum(); 
+                }
+            }
+        }
+        // `cols` rows of W 
+        (CoralSide::Right, CoralTranspose::NoTrans) => { 
+            for c in (0..k).rev() { 
+                for r in 0..cols { 
+                    w[r + c * ldw] = (0..=c).map(|p| w[r + p * ldw] * t[p + c * ldt]).sum(); 
+                }
+            }
+        }
+        (CoralSide::Right, CoralTranspose::Trans) => { 
+            for c in 0..k { 
+                for r in 0..cols { 
+                    w[r + c * ldw] = (c..k).map(|p| w[r + p * ldw] * t[c + p * ldt]).sum(); 
+                }
+            }
+        }
+    }
+}
+
+/// Applies `H = I - V T V^T` or `H^T` to the `m x n` `C` from the left 
+/// (`V` is `m x k`) or the right (`V` is `n x k`). 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn slarfb_slices ( 
+    side: CoralSide, 
+    op: CoralTranspose, 
+    m: usize, 
+    n: usize, 
+    k: usize, 
+    v: &[f32], 
+    ldv: usize, 
+    t: &[f32], 
+    ldt: usize, 
+    c: &mut [f32], 
+    ldc: usize, 
+    work: &mut Vec<f32>, 
+) { 
+    if m == 0 || n == 0 || k == 0 { 
+        return; 
+    }
+
+    match side { 
+        CoralSide::Left => { 
+            work.clear(); 
+            work.resize(k * n, 0.0); 
+
+            // W := V^T C, op(T) W, C -= V W 
+            sgemm_slices(CoralTranspose::Trans, CoralTranspose::NoTrans, k, n, m, 1.0, v, ldv, c, ldc, 0.0, work, k); 
+            strmm_t(side, op, k, n, t, ldt, work, k); 
+            sgemm_slices(CoralTranspose::NoTrans, CoralTranspose::NoTrans, m, n, k, -1.0, v, ldv, work, k, 1.0, c, ldc); 
+        }
+        CoralSide::Right => { 
+            work.clear(); 
+            work.resize(m * k, 0.0); 
+
+            // W := C V, W op(T), C -= W V^T 
+            sgemm_slices(CoralTranspose::NoTrans, CoralTranspose::NoTrans, m, k, n, 1.0, c, ldc, v, ldv, 0.0, work, m); 
+            strmm_t(side, op, k, m, t, ldt, work, m); 
+            sgemm_slices(CoralTranspose::NoTrans, CoralTranspose::Trans, m, n, k, -1.0, work, m, v, ldv, 1.0, c, ldc); 
+        }
+    }
+}
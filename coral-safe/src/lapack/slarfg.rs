@@ -0,0 +1,91 @@
+//! LAPACK [`?LARFG`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/slarfg.f)
+//! routine in single precision. 
+//!
+//! Generates an elementary reflector $H = I - \tau v v^T$, $v_0 = 1$, with 
+//!
+//! \\[ 
+//! H \begin{bmatrix} \alpha \\\\ x \end{bmatrix} = 
+//! \begin{bmatrix} \beta \\\\ 0 \end{bmatrix}, \quad 
+//! H^T H = I. 
+//! \\]
+//!
+//! # Notes 
+//! $\beta = -\operatorname{sign}(\alpha) \lVert (\alpha, x) \rVert_2$ and $x$ 
+//! is rescaled first when $\lvert \beta \rvert$ would be subnormal, as in the 
+//! reference. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{snrm2, sscal}; 
+use crate::types::{VectorRef, VectorMut}; 
+
+
+/// `n` elements of `x` at stride `incx`, starting at `x[0]`. Returns `tau`. 
+pub(crate) fn slarfg_slices ( 
+    n: usize, 
+    alpha: &mut f32, 
+    x: &mut [f32], 
+    incx: usize, 
+) -> f32 { 
+    if n == 0 { 
+        return 0.0; 
+    }
+
+    let nrm2 = |x: &[f32]| snrm2(VectorRef::new(x, n, incx, 0).expect("x view")); 
+    let scal = |a: f32, x: &mut [f32]| sscal(a, VectorMut::new(x, n, incx, 0).expect("x view")); 
+
+    let mut xnorm = nrm2(x); 
+    if xnorm == 0.0 { 
+        // H = I 
+        return 0.0; 
+    }
+
+    let mut beta = -alpha.hypot(xnorm).copysign(*alpha); 
+
+    let safmin = f32::MIN_POSITIVE / f32::EPSILON; 
+    let rsafmn = 1.0 / safmin; 
+
+    // |beta| may be inaccurate, scale x and recompute 
+    let mut knt = 0; 
+    while beta.abs() < safmin && knt < 20 { 
+        knt += 1; 
+        scal(rsafmn, x); 
+        *alpha *= rsafmn; 
+
+        xnorm = nrm2(x); 
+        beta  = -alpha.hypot(xnorm).copysign(*alpha); 
+    }
+
+    let tau = (beta - *alpha) / beta; 
+    scal(1.0 / (*alpha - beta), x); 
+
+    for _ in 0..knt { 
+        beta *= safmin; 
+    }
+    *alpha = beta; 
+
+    tau 
+}
+
+
+/// Generates an elementary reflector that zeroes `x` below `alpha`. 
+///
+/// Arguments: 
+/// * `alpha`: &mut [f32] - input as $\alpha$, output as $\beta$ 
+/// * `x`: [VectorMut] - over [f32], input as `x`, output as `v[1..]` 
+///
+/// Returns: 
+/// `tau`, zero when `x` is already zero and `H = I`. 
+#[inline] 
+pub fn slarfg ( 
+    alpha: &mut f32, 
+    mut x: VectorMut<'_, f32>, 
+) -> f32 { 
+    let n    = x.n(); 
+    let incx = x.stride(); 
+    let xoff = x.offset(); 
+
+    slarfg_slices(n, alpha, &mut x.as_slice_mut()[xoff..], incx) 
+}
@@ -0,0 +1,95 @@
+//! LAPACK [`?LASWP`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/slaswp.f)
+//! routine in single precision. 
+//!
+//! Applies the row interchanges 
+//!
+//! \\[ 
+//! A_{i,:} \;\leftrightarrow\; A\_{\text{ipiv}[i],:}
+//! \\]
+//!
+//! for each `i` in `ipiv`, first to last or last to first. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::{CoralDirection, MatrixMut}; 
+
+// columns swapped together, so each row pair stays in cache
+const NC: usize = 32; 
+
+
+/// row interchanges `k1..k2` of `ipiv` over `n_cols` columns of a column
+/// major slice. `ipiv` holds absolute, 0-based row indices. 
+#[inline] 
+pub(crate) fn slaswp_slices ( 
+    direction: CoralDirection, 
+    n_cols: usize, 
+    a: &mut [f32], 
+    lda: usize, 
+    ipiv: &[usize], 
+    k1: usize, 
+    k2: usize, 
+) { 
+    let mut c0 = 0; 
+    while c0 < n_cols { 
+        let c1 = (c0 + NC).min(n_cols); 
+
+        let mut swap = |i: usize| { 
+            let p = ipiv[i]; 
+            if p != i { 
+                for c in c0..c1 { 
+                    a.swap(i + c * lda, p + c * lda); 
+                }
+            }
+        }; 
+
+        match direction { 
+            CoralDirection::Forward  => (k1..k2).for_each(&mut swap), 
+            CoralDirection::Backward => (k1..k2).rev().for_each(&mut swap), 
+        }
+
+        c0 = c1; 
+    }
+}
+
+
+/// Applies the row interchanges of an LU factorization to a [MatrixMut].
+///
+/// Arguments: 
+/// * `direction`: [CoralDirection] - [CoralDirection::Forward] applies `P^T`,
+///   [CoralDirection::Backward] applies `P` 
+/// * `a`: [MatrixMut] - over [f32] 
+/// * `ipiv`: &[[usize]] - row `i` is interchanged with row `ipiv[i]`; 0-based
+///
+/// Returns: 
+/// Nothing. `a.data` is overwritten. 
+#[inline] 
+pub fn slaswp ( 
+    direction: CoralDirection, 
+    mut a: MatrixMut<'_, f32>, 
+    ipiv: &[usize], 
+) { 
+    let n_rows = a.n_rows(); 
+    let n_cols = a.n_cols(); 
+
+    debug_assert!(ipiv.len() <= n_rows, "ipiv cannot be longer than n_rows"); 
+    debug_assert!(ipiv.iter().all(|&p| p < n_rows), "pivots must index rows of a"); 
+
+    if n_rows == 0 || n_cols == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    slaswp_slices ( 
+        direction, 
+        n_cols, 
+        &mut a.as_slice_mut()[aoff..], 
+        lda, 
+        ipiv, 
+        0, 
+        ipiv.len(), 
+    ); 
+}
@@ -0,0 +1,91 @@
+//! LAPACK [`?ORGQR`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sorgqr.f)
+//! routine in single precision. 
+//!
+//! Forms the first `n` columns of 
+//!
+//! \\[ 
+//! Q = H_0 H_1 \cdots H\_{k-1} 
+//! \\]
+//!
+//! explicitly from the reflectors returned by [`sgeqrf`](crate::lapack::sgeqrf). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::lapack::slarfb::{pack_v, slarfb_slices, slarft_slices}; 
+use crate::types::{CoralSide, CoralTranspose, MatrixMut}; 
+
+// reflectors per block
+const NB: usize = 32; 
+
+
+/// Overwrites the reflectors of a QR factorization with the orthonormal
+/// columns of `Q`. 
+///
+/// `Q` is built from the identity, the last block of reflectors first, 
+/// each applied in compact WY form through gemm. 
+///
+/// Arguments: 
+/// * `a`: [MatrixMut] - over [f32], `m x n` with `n <= m`; the first `k` 
+///   columns hold the reflectors from `sgeqrf` 
+/// * `tau`: &[[f32]] - the `k <= n` scalars from `sgeqrf` 
+///
+/// Returns: 
+/// Nothing. `a.data` is overwritten with `Q[:, 0..n]`. 
+pub fn sorgqr ( 
+    mut a: MatrixMut<'_, f32>, 
+    tau: &[f32], 
+) { 
+    let m = a.n_rows(); 
+    let n = a.n_cols(); 
+    let k = tau.len(); 
+
+    assert!(k <= n && n <= m, "sorgqr needs k <= n_cols <= n_rows"); 
+
+    if n == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    // every reflector, before they are overwritten 
+    let mut vall = Vec::new(); 
+    pack_v(m, k, data, lda, &mut vall); 
+
+    for j in 0..n { 
+        let col = &mut data[j * lda .. j * lda + m]; 
+        col.fill(0.0); 
+        col[j] = 1.0; 
+    }
+
+    let mut t    = vec![0.0; NB * NB]; 
+    let mut work = Vec::new(); 
+
+    // block i0.. only touches rows and columns i0.. of the identity 
+    let n_blocks = k.div_ceil(NB); 
+    for blk in (0..n_blocks).rev() { 
+        let i0  = blk * NB; 
+        let kb  = (k - i0).min(NB); 
+        let len = m - i0; 
+        let v   = &vall[i0 + i0 * m..]; 
+
+        slarft_slices(len, kb, v, m, &tau[i0 .. i0 + kb], &mut t, NB); 
+        slarfb_slices ( 
+            CoralSide::Left, 
+            CoralTranspose::NoTrans, 
+            len, 
+            n - i0, 
+            kb, 
+            v, 
+            m, 
+            &t, 
+            NB, 
+            &mut data[i0 + i0 * lda..], 
+            lda, 
+            &mut work, 
+        ); 
+    }
+}
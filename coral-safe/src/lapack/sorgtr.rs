@@ -0,0 +1,104 @@
+//! LAPACK [`?ORGTR`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sorgtr.f)
+//! routine in single precision. 
+//!
+//! Forms the orthogonal $Q$ of [ssytrd](crate::lapack::ssytrd), 
+//!
+//! \\[ 
+//! Q = H_{n-2} \cdots H_0 \quad \text{or} \quad Q = H_0 \cdots H_{n-2}, 
+//! \\]
+//!
+//! for `Upper` and `Lower` respectively. 
+//!
+//! # Notes 
+//! The reflectors are copied out and applied to the identity one at a time, 
+//! the last applied reflector first, so each only touches the rows and 
+//! columns it spans. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{saxpy, sdot}; 
+use crate::types::{CoralTriangular, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `Q[r, c] := H Q[r, c]` for the rows `r = lo..lo + len(v)` and columns `c` 
+#[inline] 
+fn reflect_cols(tau: f32, v: &[f32], q: &mut [f32], ldq: usize, lo: usize, cols: std::ops::Range<usize>) { 
+    let len = v.len(); 
+    let vview = || VectorRef::new(v, len, 1, 0).expect("v view"); 
+
+    for j in cols { 
+        let col = &mut q[lo + j * ldq .. lo + len + j * ldq]; 
+        let s = tau * sdot(vview(), VectorRef::new(col, len, 1, 0).expect("Q column view")); 
+        if s != 0.0 { 
+            saxpy(-s, vview(), VectorMut::new(col, len, 1, 0).expect("Q column view")); 
+        }
+    }
+}
+
+
+/// Overwrites the reflectors of a tridiagonal reduction with `Q`. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `ssytrd` was called with 
+/// * `a`: [MatrixMut] - over [f32], square, the output of `ssytrd` 
+/// * `tau`: &[[f32]] - length `n - 1`, the scalars from `ssytrd` 
+///
+/// Returns: 
+/// Nothing. `a.data` is overwritten with `Q`. 
+pub fn sorgtr ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+    tau: &[f32], 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    if n == 0 { 
+        return; 
+    }
+
+    assert!(tau.len() >= n - 1, "tau must hold n - 1 entries"); 
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    // v_i with its implicit unit entry, before the identity overwrites it 
+    let vs: Vec<Vec<f32>> = (0..n - 1)
+        .map(|i| match uplo { 
+            CoralTriangular::Upper => { 
+                let mut v = data[(i + 1) * lda .. (i + 1) * lda + i + 1].to_vec(); 
+                v[i] = 1.0; 
+                v 
+            }
+            CoralTriangular::Lower => { 
+                let mut v = data[i + 1 + i * lda .. n + i * lda].to_vec(); 
+                v[0] = 1.0; 
+                v 
+            }
+        })
+        .collect(); 
+
+    for j in 0..n { 
+        let col = &mut data[j * lda .. j * lda + n]; 
+        col.fill(0.0); 
+        col[j] = 1.0; 
+    }
+
+    match uplo { 
+        CoralTriangular::Upper => { 
+            // H_i spans rows and columns 0..=i 
+            for (i, v) in vs.iter().enumerate() { 
+                reflect_cols(tau[i], v, data, lda, 0, 0..i + 1); 
+            }
+        }
+        CoralTriangular::Lower => { 
+            // H_i spans rows and columns i + 1.. 
+            for (i, v) in vs.iter().enumerate().rev() { 
+                reflect_cols(tau[i], v, data, lda, i + 1, i + 1..n); 
+            }
+        }
+    }
+}
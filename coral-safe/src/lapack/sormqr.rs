@@ -0,0 +1,98 @@
+//! LAPACK [`?ORMQR`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sormqr.f)
+//! routine in single precision. 
+//!
+//! Overwrites the `m x n` matrix $C$ with 
+//!
+//! \\[ 
+//! \operatorname{op}(Q) C \quad \text{or} \quad C \operatorname{op}(Q), 
+//! \quad \operatorname{op}(Q) \in \\{Q, Q^T \\}, 
+//! \\]
+//!
+//! where $Q = H_0 H_1 \cdots H\_{k-1}$ is stored as returned by 
+//! [`sgeqrf`](crate::lapack::sgeqrf). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::lapack::slarfb::{pack_v, slarfb_slices, slarft_slices}; 
+use crate::types::{CoralSide, CoralTranspose, MatrixRef, MatrixMut}; 
+
+// reflectors per block
+const NB: usize = 32; 
+
+
+/// Multiplies a matrix by the orthogonal `Q` of a QR factorization. 
+///
+/// Reflectors are applied `32` at a time in compact WY form through gemm. 
+///
+/// Arguments: 
+/// * `side`: [CoralSide] - `op(Q) C` or `C op(Q)` 
+/// * `trans`: [CoralTranspose] - `Q` or `Q^T` 
+/// * `a`: [MatrixRef] - over [f32], the reflectors from `sgeqrf` in its 
+///   first `k` columns; `m` rows for [CoralSide::Left], `n` for [CoralSide::Right]
+/// * `tau`: &[[f32]] - the `k` scalars from `sgeqrf`
+/// * `c`: [MatrixMut] - over [f32], `m x n` 
+///
+/// Returns: 
+/// Nothing. `c.data` is overwritten. 
+pub fn sormqr ( 
+    side: CoralSide, 
+    trans: CoralTranspose, 
+    a: MatrixRef<'_, f32>, 
+    tau: &[f32], 
+    mut c: MatrixMut<'_, f32>, 
+) { 
+    let m  = c.n_rows(); 
+    let n  = c.n_cols(); 
+    let k  = tau.len(); 
+    let nq = match side { 
+        CoralSide::Left  => m, 
+        CoralSide::Right => n, 
+    }; 
+
+    debug_assert_eq!(a.n_rows(), nq, "rows of a must equal the order of Q"); 
+    assert!(k <= a.n_cols() && k <= nq, "more reflectors than a holds"); 
+
+    if m == 0 || n == 0 || k == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let ldc  = c.lda(); 
+    let coff = c.offset(); 
+
+    let a  = &a.as_slice()[aoff..]; 
+    let cs = &mut c.as_slice_mut()[coff..]; 
+
+    // Q^T C and C Q take H_0 first 
+    let forward = matches!( 
+        (side, trans), 
+        (CoralSide::Left, CoralTranspose::Trans) | 
+        (CoralSide::Right, CoralTranspose::NoTrans) 
+    ); 
+
+    let mut v    = Vec::new(); 
+    let mut t    = vec![0.0; NB * NB]; 
+    let mut work = Vec::new(); 
+
+    let n_blocks = k.div_ceil(NB); 
+    for blk in 0..n_blocks { 
+        let blk = if forward { blk } else { n_blocks - 1 - blk }; 
+        let i0  = blk * NB; 
+        let kb  = (k - i0).min(NB); 
+        let len = nq - i0; 
+
+        pack_v(len, kb, &a[i0 + i0 * lda..], lda, &mut v); 
+        slarft_slices(len, kb, &v, len, &tau[i0 .. i0 + kb], &mut t, NB); 
+
+        // H touches rows (left) or columns (right) i0.. 
+        let (cm, cn, cblk) = match side { 
+            CoralSide::Left  => (len, n, &mut cs[i0..]), 
+            CoralSide::Right => (m, len, &mut cs[i0 * ldc..]), 
+        }; 
+
+        slarfb_slices(side, trans, cm, cn, kb, &v, len, &t, NB, cblk, ldc, &mut work); 
+    }
+}
@@ -0,0 +1,59 @@
+//! LAPACK [`?POCON`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/spocon.f)
+//! routine in single precision. 
+//!
+//! Estimates the reciprocal 1-norm condition number of a symmetric positive 
+//! definite matrix from its [spotrf](crate::lapack::spotrf) factor, 
+//!
+//! \\[ 
+//! \operatorname{rcond} = \frac{1}{\lVert A \rVert_1 \, \lVert A^{-1} \rVert_1}, 
+//! \\]
+//!
+//! with $\lVert A^{-1} \rVert_1$ from [slacn2](crate::lapack::slacn2) driven 
+//! by [spotrs](crate::lapack::spotrs) solves. $A^{-1}$ is symmetric, so both 
+//! products are the same solve. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::lapack::{slacn2, spotrs}; 
+use crate::types::{CoralTriangular, MatrixRef, MatrixMut}; 
+
+
+/// Estimates the reciprocal of the 1-norm condition number of a symmetric 
+/// positive definite matrix. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `spotrf` was called with 
+/// * `a`: [MatrixRef] - over [f32], square, the factor from `spotrf` 
+/// * `anorm`: [f32] - `|A|_1` of the matrix before it was factored 
+///
+/// Returns: 
+/// * [f32] an estimate of `1 / (|A|_1 |A^{-1}|_1)`; `0.0` when 
+///   `anorm == 0`, `1.0` when `n == 0` 
+pub fn spocon ( 
+    uplo: CoralTriangular, 
+    a: MatrixRef<'_, f32>, 
+    anorm: f32, 
+) -> f32 { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+    assert!(anorm >= 0.0, "anorm must be non-negative"); 
+
+    let n = a.n_rows(); 
+    if n == 0 { 
+        return 1.0; 
+    }
+    if anorm == 0.0 { 
+        return 0.0; 
+    }
+
+    let ainvnm = slacn2(n, |_, x| { 
+        spotrs(uplo, a, MatrixMut::new(x, n, 1, n, 0).expect("x view")); 
+    }); 
+
+    if ainvnm.is_finite() && ainvnm > 0.0 { 
+        (1.0 / ainvnm) / anorm 
+    } else { 
+        0.0 
+    }
+}
@@ -0,0 +1,54 @@
+//! LAPACK [`?POSV`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/sposv.f)
+//! routine in single precision. 
+//!
+//! Solves 
+//!
+//! \\[ 
+//! A X = B 
+//! \\]
+//!
+//! for a symmetric positive definite `n x n` matrix $A$ through 
+//! [`spotrf`](crate::lapack::spotrf) and [`spotrs`](crate::lapack::spotrs). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{spotrf, spotrs}; 
+use crate::types::{CoralTriangular, MatrixRef, MatrixMut}; 
+
+
+/// Factors `a` and solves a symmetric positive definite system. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced 
+/// * `a`: [MatrixMut] - over [f32], `n x n`, the `uplo` triangle is 
+///   overwritten with its Cholesky factor 
+/// * `b`: [MatrixMut] - over [f32], `n x nrhs`, input as `B`, output as `X` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::NotPositiveDefinite] if `a` is not positive definite; 
+///   `b` is left untouched 
+pub fn sposv ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+    b: MatrixMut<'_, f32>, 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    let factor = MatrixMut::new(a.as_slice_mut(), n, n, lda, aoff)
+        .expect("a view"); 
+    spotrf(uplo, factor)?; 
+
+    let factor = MatrixRef::new(a.as_slice(), n, n, lda, aoff)
+        .expect("a view"); 
+    spotrs(uplo, factor, b); 
+
+    Ok(())
+}
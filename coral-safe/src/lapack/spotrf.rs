@@ -0,0 +1,230 @@
+//! LAPACK [`?POTRF`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/spotrf.f)
+//! routine in single precision. 
+//!
+//! Cholesky factorization of a symmetric positive definite matrix 
+//!
+//! \\[ 
+//! A = U^T U \quad \text{or} \quad A = L L^T. 
+//! \\]
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::sdot; 
+use crate::level2::strsv; 
+use crate::level3::ssyrk::ssyrk_slices; 
+use crate::level3::strsm::strsm_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixRef, 
+    MatrixMut, 
+    VectorRef, 
+    VectorMut, 
+}; 
+
+// diagonal block width
+const NB: usize = 64; 
+
+
+/// `v . v`
+#[inline] 
+fn self_dot(v: &[f32]) -> f32 { 
+    let x = VectorRef::new(v, v.len(), 1, 0).expect("dot view"); 
+    let y = VectorRef::new(v, v.len(), 1, 0).expect("dot view"); 
+    sdot(x, y) 
+}
+
+/// Unblocked Cholesky of the `nb x nb` block at the start of `a`. Each
+/// row of `L` (column of `U`) is a triangular solve against the part 
+/// already factored. Returns the first failing pivot, if any. 
+fn spotf2 ( 
+    uplo: CoralTriangular, 
+    nb: usize, 
+    a: &mut [f32], 
+    lda: usize, 
+    row: &mut Vec<f32>, 
+) -> Option<usize> { 
+    for j in 0..nb { 
+        let ajj = match uplo { 
+            // U[0..j, 0..j]^T u = A[0..j, j] 
+            CoralTriangular::Upper => { 
+                let (left, right) = a.split_at_mut(j * lda); 
+                let col = &mut right[..j]; 
+
+                if j > 0 { 
+                    let u11 = MatrixRef::new(left, j, j, lda, 0).expect("U11 view"); 
+                    let x   = VectorMut::new(col, j, 1, 0).expect("column view"); 
+                    strsv(CoralTriangular::Upper, CoralTranspose::Trans, CoralDiagonal::NonUnit, u11, x); 
+                }
+
+                right[j] - self_dot(&right[..j]) 
+            }
+            // L[0..j, 0..j] l = A[j, 0..j]^T, through a copy of row j
+            CoralTriangular::Lower => { 
+                row.clear(); 
+                row.extend((0..j).map(|c| a[j + c * lda])); 
+
+                if j > 0 { 
+                    let l11 = MatrixRef::new(a, j, j, lda, 0).expect("L11 view"); 
+                    let x   = VectorMut::new(row, j, 1, 0).expect("row view"); 
+                    strsv(CoralTriangular::Lower, CoralTranspose::NoTrans, CoralDiagonal::NonUnit, l11, x); 
+                }
+
+                for (c, &v) in row.iter().enumerate() { 
+                    a[j + c * lda] = v; 
+                }
+
+                a[j + j * lda] - self_dot(row) 
+            }
+        }; 
+
+        if ajj <= 0.0 || ajj.is_nan() { 
+            a[j + j * lda] = ajj; 
+            return Some(j); 
+        }
+
+        a[j + j * lda] = ajj.sqrt(); 
+    }
+
+    None
+}
+
+
+/// Computes the Cholesky factorization of a symmetric positive definite
+/// matrix. 
+///
+/// Diagonal blocks of `64` are factored with [strsv]; the panel beside 
+/// each is solved with a triangular solve and the trailing matrix is 
+/// updated with a symmetric rank-k update. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced and
+///   overwritten with `U` or `L`; the other is untouched 
+/// * `a`: [MatrixMut] - over [f32], square 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::NotPositiveDefinite] with the first `i` whose leading 
+///   minor of order `i + 1` is not positive definite. The factorization 
+///   stops there. 
+pub fn spotrf ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    let mut row  = Vec::with_capacity(NB); 
+    let mut diag = Vec::with_capacity(NB * NB); 
+    let mut pan  = Vec::new(); 
+
+    let mut j = 0; 
+    while j < n { 
+        let jb = (n - j).min(NB); 
+
+        if let Some(i) = spotf2(uplo, jb, &mut data[j + j * lda..], lda, &mut row) { 
+            return Err(LapackError::NotPositiveDefinite { index: j + i }); 
+        }
+
+        if j + jb == n { 
+            break; 
+        }
+
+        let nr = n - j - jb; 
+
+        // factored diagonal block, copied out of the columns being written 
+        diag.clear(); 
+        for c in 0..jb { 
+            diag.extend_from_slice(&data[j + (j + c) * lda .. j + jb + (j + c) * lda]); 
+        }
+
+        let (left, right) = data.split_at_mut((j + jb) * lda); 
+
+        match uplo { 
+            CoralTriangular::Upper => { 
+                // A12 := U11^{-T} A12 
+                strsm_slices ( 
+                    CoralSide::Left, 
+                    CoralTriangular::Upper, 
+                    CoralTranspose::Trans, 
+                    CoralDiagonal::NonUnit, 
+                    jb, 
+                    nr, 
+                    1.0, 
+                    &diag, 
+                    jb, 
+                    &mut right[j..], 
+                    lda, 
+                ); 
+
+                // A22 -= A12^T A12, with A12 copied out 
+                pan.clear(); 
+                for c in 0..nr { 
+                    pan.extend_from_slice(&right[j + c * lda .. j + jb + c * lda]); 
+                }
+
+                ssyrk_slices ( 
+                    CoralTriangular::Upper, 
+                    CoralTranspose::Trans, 
+                    nr, 
+                    jb, 
+                    -1.0, 
+                    &pan, 
+                    jb, 
+                    1.0, 
+                    &mut right[j + jb..], 
+                    lda, 
+                ); 
+            }
+            CoralTriangular::Lower => { 
+                let a21 = &mut left[j + jb + j * lda..]; 
+
+                // A21 := A21 L11^{-T} 
+                strsm_slices ( 
+                    CoralSide::Right, 
+                    CoralTriangular::Lower, 
+                    CoralTranspose::Trans, 
+                    CoralDiagonal::NonUnit, 
+                    nr, 
+                    jb, 
+                    1.0, 
+                    &diag, 
+                    jb, 
+                    a21, 
+                    lda, 
+                ); 
+
+                // A22 -= A21 A21^T 
+                ssyrk_slices ( 
+                    CoralTriangular::Lower, 
+                    CoralTranspose::NoTrans, 
+                    nr, 
+                    jb, 
+                    -1.0, 
+                    a21, 
+                    lda, 
+                    1.0, 
+                    &mut right[j + jb..], 
+                    lda, 
+                ); 
+            }
+        }
+
+        j += jb; 
+    }
+
+    Ok(())
+}
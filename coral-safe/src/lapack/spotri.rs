@@ -0,0 +1,74 @@
+//! LAPACK [`?POTRI`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/spotri.f)
+//! routine in single precision. 
+//!
+//! Inverts a symmetric positive definite matrix from its 
+//! [spotrf](crate::lapack::spotrf) factor, 
+//!
+//! \\[ 
+//! A^{-1} = U^{-1} U^{-T} \quad \text{or} \quad A^{-1} = L^{-T} L^{-1}, 
+//! \\]
+//!
+//! by inverting the factor with [strtri](crate::lapack::strtri) and forming 
+//! the product with a rank-`n` [ssyrk](crate::level3::ssyrk) update. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::strtri; 
+use crate::level3::ssyrk::ssyrk_slices; 
+use crate::types::{CoralDiagonal, CoralTranspose, CoralTriangular, MatrixMut}; 
+
+
+/// Overwrites the Cholesky factor of `A` with the `uplo` triangle of `A^{-1}`. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `spotrf` was called with 
+/// * `a`: [MatrixMut] - over [f32], square, the factor from `spotrf` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::Singular] if the factor has an exactly zero diagonal 
+///   entry; `a` is left untouched 
+pub fn spotri ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    strtri( 
+        uplo, 
+        CoralDiagonal::NonUnit, 
+        MatrixMut::new(a.as_slice_mut(), n, n, lda, aoff).expect("a view"), 
+    )?; 
+
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    // the inverted factor with its other triangle zeroed 
+    let mut w = vec![0.0; n * n]; 
+    for j in 0..n { 
+        let (lo, hi) = match uplo { 
+            CoralTriangular::Upper => (0, j + 1), 
+            CoralTriangular::Lower => (j, n), 
+        }; 
+        w[lo + j * n .. hi + j * n].copy_from_slice(&data[lo + j * lda .. hi + j * lda]); 
+    }
+
+    // U^{-1} U^{-T} or L^{-T} L^{-1} 
+    let op = match uplo { 
+        CoralTriangular::Upper => CoralTranspose::NoTrans, 
+        CoralTriangular::Lower => CoralTranspose::Trans, 
+    }; 
+    ssyrk_slices(uplo, op, n, n, 1.0, &w, n, 0.0, data, lda); 
+
+    Ok(()) 
+}
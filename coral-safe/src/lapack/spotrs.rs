@@ -0,0 +1,72 @@
+//! LAPACK [`?POTRS`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/spotrs.f)
+//! routine in single precision. 
+//!
+//! Solves 
+//!
+//! \\[ 
+//! A X = B 
+//! \\]
+//!
+//! with the Cholesky factors $A = U^T U$ or $A = L L^T$ from 
+//! [`spotrf`](crate::lapack::spotrf). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level3::strsm::strsm_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixRef, 
+    MatrixMut, 
+}; 
+
+
+/// Solves a symmetric positive definite system with the factors from
+/// `spotrf`. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `spotrf` was called with 
+/// * `a`: [MatrixRef] - over [f32], the `n x n` factor `U` or `L` 
+/// * `b`: [MatrixMut] - over [f32], `n x nrhs`, input as `B`, output as `X` 
+///
+/// Returns: 
+/// Nothing. `b.data` is overwritten. 
+#[inline] 
+pub fn spotrs ( 
+    uplo: CoralTriangular, 
+    a: MatrixRef<'_, f32>, 
+    mut b: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let nrhs = b.n_cols(); 
+
+    debug_assert_eq!(b.n_rows(), n, "rows of b must equal the order of a"); 
+
+    if n == 0 || nrhs == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let ldb  = b.lda(); 
+    let boff = b.offset(); 
+
+    let a  = &a.as_slice()[aoff..]; 
+    let bs = &mut b.as_slice_mut()[boff..]; 
+
+    // U^T U X = B or L L^T X = B 
+    let (first, second) = match uplo { 
+        CoralTriangular::Upper => (CoralTranspose::Trans, CoralTranspose::NoTrans), 
+        CoralTriangular::Lower => (CoralTranspose::NoTrans, CoralTranspose::Trans), 
+    }; 
+
+    for op in [first, second] { 
+        strsm_slices(CoralSide::Left, uplo, op, CoralDiagonal::NonUnit, n, nrhs, 1.0, a, lda, bs, ldb); 
+    }
+}
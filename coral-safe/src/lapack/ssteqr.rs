@@ -0,0 +1,182 @@
+//! LAPACK [`?STEQR`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/ssteqr.f)
+//! routine in single precision. 
+//!
+//! Computes all eigenvalues, and optionally eigenvectors, of a symmetric 
+//! tridiagonal matrix by the implicitly shifted QL method, 
+//!
+//! \\[ 
+//! T = S \Lambda S^T. 
+//! \\]
+//!
+//! When a matrix $Z$ is passed in, the rotations are accumulated into it, 
+//! $Z \leftarrow Z S$. Passing the $Q$ of a tridiagonal reduction gives the 
+//! eigenvectors of the original matrix, passing the identity those of $T$. 
+//!
+//! # Notes 
+//! Each sweep chases a Wilkinson shifted bulge up an unreduced block with 
+//! Givens rotations, applied to $Z$ through [srot]. An off diagonal $e_i$ 
+//! is set to zero once 
+//! 
+//! \\[ 
+//! \lvert e_i \rvert \le \epsilon \big( \lvert d_i \rvert + \lvert d_{i+1} \rvert \big), 
+//! \\]
+//!
+//! and at most $30 n$ sweeps are taken, as in the reference. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::{srot, sswap}; 
+use crate::types::{MatrixMut, VectorMut}; 
+
+const MAXIT: usize = 30; 
+
+
+/// columns `i` and `i + 1` of `z` as `(c x - s y, s x + c y)` 
+#[inline] 
+fn rotate_cols(z: &mut [f32], m: usize, ldz: usize, i: usize, c: f32, s: f32) { 
+    let (left, right) = z.split_at_mut((i + 1) * ldz); 
+    let x = VectorMut::new(&mut left[i * ldz .. i * ldz + m], m, 1, 0).expect("Z column view"); 
+    let y = VectorMut::new(&mut right[..m], m, 1, 0).expect("Z column view"); 
+    srot(x, y, c, -s); 
+}
+
+/// swaps columns `i < j` of `z` 
+#[inline] 
+fn swap_cols(z: &mut [f32], m: usize, ldz: usize, i: usize, j: usize) { 
+    let (left, right) = z.split_at_mut(j * ldz); 
+    let x = VectorMut::new(&mut left[i * ldz .. i * ldz + m], m, 1, 0).expect("Z column view"); 
+    let y = VectorMut::new(&mut right[..m], m, 1, 0).expect("Z column view"); 
+    sswap(x, y); 
+}
+
+
+/// Computes the eigenvalues and optionally the eigenvectors of a symmetric 
+/// tridiagonal matrix. 
+///
+/// Arguments: 
+/// * `d`: &mut [[f32]] - length `n`, input as the diagonal, output as the 
+///   eigenvalues in ascending order 
+/// * `e`: &mut [[f32]] - length `n - 1`, the off diagonal; destroyed 
+/// * `z`: [Option] of [MatrixMut] - over [f32], `m x n`; the rotations are 
+///   accumulated into its columns, which are reordered along with `d` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::NoConvergence] if the sweep limit was reached; `d` holds 
+///   the eigenvalues found so far, unordered 
+pub fn ssteqr ( 
+    d: &mut [f32], 
+    e: &mut [f32], 
+    mut z: Option<MatrixMut<'_, f32>>, 
+) -> Result<(), LapackError> { 
+    let n = d.len(); 
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    assert!(e.len() >= n - 1, "e must hold n - 1 entries"); 
+
+    let (m, ldz, zoff) = match &z { 
+        Some(z) => { 
+            debug_assert_eq!(z.n_cols(), n, "cols of Z must equal n"); 
+            (z.n_rows(), z.lda(), z.offset()) 
+        }
+        None => (0, 1, 0), 
+    }; 
+    let mut zs = z.as_mut().map(|z| &mut z.as_slice_mut()[zoff..]); 
+
+    // e[i] couples d[i] and d[i + 1]; the trailing zero ends every scan 
+    let mut off = e[..n - 1].to_vec(); 
+    off.push(0.0); 
+
+    let eps   = f32::EPSILON; 
+    let maxit = MAXIT * n; 
+    let mut sweeps = 0; 
+
+    for l in 0..n { 
+        loop { 
+            // find a negligible off diagonal at or below l 
+            let mut k = l; 
+            while k < n - 1 { 
+                if off[k].abs() <= eps * (d[k].abs() + d[k + 1].abs()) { 
+                    off[k] = 0.0; 
+                    break; 
+                }
+                k += 1; 
+            }
+
+            // d[l] has converged 
+            if k == l { 
+                break; 
+            }
+
+            sweeps += 1; 
+            if sweeps > maxit { 
+                e[..n - 1].copy_from_slice(&off[..n - 1]); 
+                let unconverged = off.iter().filter(|&&v| v != 0.0).count(); 
+                return Err(LapackError::NoConvergence { unconverged }); 
+            }
+
+            // Wilkinson shift from the leading 2 x 2 of the block l..=k 
+            let mut g = (d[l + 1] - d[l]) / (2.0 * off[l]); 
+            let mut r = g.hypot(1.0); 
+            g = d[k] - d[l] + off[l] / (g + r.copysign(g)); 
+
+            let (mut s, mut c, mut p) = (1.0f32, 1.0f32, 0.0f32); 
+            let mut deflated = false; 
+
+            for i in (l..k).rev() { 
+                let f = s * off[i]; 
+                let b = c * off[i]; 
+                r = f.hypot(g); 
+                off[i + 1] = r; 
+
+                // the rotation underflowed, split the block here 
+                if r == 0.0 { 
+                    d[i + 1] -= p; 
+                    off[k]   = 0.0; 
+                    deflated = true; 
+                    break; 
+                }
+
+                s = f / r; 
+                c = g / r; 
+                g = d[i + 1] - p; 
+                r = (d[i] - g) * s + 2.0 * c * b; 
+                p = s * r; 
+                d[i + 1] = g + p; 
+                g = c * r - b; 
+
+                if let Some(zs) = zs.as_deref_mut() { 
+                    rotate_cols(zs, m, ldz, i, c, s); 
+                }
+            }
+
+            if deflated { 
+                continue; 
+            }
+
+            d[l]  -= p; 
+            off[l] = g; 
+            off[k] = 0.0; 
+        }
+    }
+
+    e[..n - 1].fill(0.0); 
+
+    // selection sort, so each column of Z moves at most once 
+    for i in 0..n - 1 { 
+        let j = (i..n).fold(i, |j, q| if d[q] < d[j] { q } else { j }); 
+        if j != i { 
+            d.swap(i, j); 
+            if let Some(zs) = zs.as_deref_mut() { 
+                swap_cols(zs, m, ldz, i, j); 
+            }
+        }
+    }
+
+    Ok(()) 
+}
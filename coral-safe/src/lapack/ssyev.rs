@@ -0,0 +1,71 @@
+//! LAPACK [`?SYEV`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/ssyev.f)
+//! routine in single precision. 
+//!
+//! Computes all eigenvalues, and optionally eigenvectors, of a symmetric 
+//! matrix 
+//!
+//! \\[ 
+//! A = Z \Lambda Z^T, \quad Z^T Z = I, 
+//! \\]
+//!
+//! through [`ssytrd`](crate::lapack::ssytrd), [`sorgtr`](crate::lapack::sorgtr) 
+//! and [`ssteqr`](crate::lapack::ssteqr). 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::lapack::{sorgtr, ssteqr, ssytrd}; 
+use crate::types::{CoralJob, CoralTriangular, MatrixMut}; 
+
+
+/// Computes the eigen-decomposition of a symmetric matrix. 
+///
+/// Arguments: 
+/// * `job`: [CoralJob] - whether eigenvectors are computed 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced 
+/// * `a`: [MatrixMut] - over [f32], square. With [CoralJob::Vectors] it is 
+///   overwritten with the orthonormal eigenvectors as columns, in the order 
+///   of `w`; otherwise the `uplo` triangle is destroyed 
+/// * `w`: &mut [[f32]] - length `n`, the eigenvalues in ascending order 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::NoConvergence] from the tridiagonal QL iteration 
+pub fn ssyev ( 
+    job: CoralJob, 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+    w: &mut [f32], 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    assert!(w.len() >= n, "w must hold n eigenvalues"); 
+
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = a.as_slice_mut(); 
+
+    let mut e   = vec![0.0; n - 1]; 
+    let mut tau = vec![0.0; n - 1]; 
+
+    let reduced = MatrixMut::new(&mut *data, n, n, lda, aoff).expect("a view"); 
+    ssytrd(uplo, reduced, &mut w[..n], &mut e, &mut tau); 
+
+    match job { 
+        CoralJob::ValuesOnly => ssteqr(&mut w[..n], &mut e, None), 
+        CoralJob::Vectors    => { 
+            let q = MatrixMut::new(&mut *data, n, n, lda, aoff).expect("a view"); 
+            sorgtr(uplo, q, &tau); 
+
+            let z = MatrixMut::new(data, n, n, lda, aoff).expect("a view"); 
+            ssteqr(&mut w[..n], &mut e, Some(z)) 
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Inertia of a symmetric matrix from its Bunch-Kaufman factorization. 
+//!
+//! By Sylvester's law of inertia, $A = P L D L^T P^T$ and $D$ have the same 
+//! numbers of positive, negative and zero eigenvalues, so these are read 
+//! off the 1 x 1 and 2 x 2 blocks of $D$ left by 
+//! [ssytrf](crate::lapack::ssytrf). 
+//!
+//! # Notes 
+//! A 2 x 2 block 
+//!
+//! \\[ 
+//! \begin{bmatrix} a & b \\\\ b & c \end{bmatrix} 
+//! \\]
+//!
+//! has $\det = ac - b^2$. For $\det < 0$ its eigenvalues have opposite signs, 
+//! for $\det > 0$ both share the sign of $a + c$. The Bunch-Kaufman pivot test 
+//! makes $|ac| < b^2$, so in practice every 2 x 2 block is $(1, 1, 0)$. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::{CoralPivot, CoralTriangular, Inertia, MatrixRef}; 
+
+
+/// adds the eigenvalue signs of `[[a, b], [b, c]]`, `b != 0` 
+#[inline] 
+fn count_2x2(a: f32, b: f32, c: f32, inertia: &mut Inertia) { 
+    // det / b^2, scaled to stay finite 
+    let det   = (a / b) * (c / b) - 1.0; 
+    let trace = a + c; 
+
+    if det < 0.0 { 
+        inertia.positive += 1; 
+        inertia.negative += 1; 
+    } else if det > 0.0 { 
+        if trace > 0.0 { 
+            inertia.positive += 2; 
+        } else { 
+            inertia.negative += 2; 
+        }
+    } else { 
+        inertia.zero += 1; 
+        count_1x1(trace, inertia); 
+    }
+}
+
+#[inline] 
+fn count_1x1(d: f32, inertia: &mut Inertia) { 
+    if d > 0.0 { 
+        inertia.positive += 1; 
+    } else if d < 0.0 { 
+        inertia.negative += 1; 
+    } else { 
+        inertia.zero += 1; 
+    }
+}
+
+
+/// Counts the positive, negative and zero eigenvalues of a symmetric 
+/// matrix from the `D` factor left by [ssytrf](crate::lapack::ssytrf). 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `ssytrf` was called with 
+/// * `a`: [MatrixRef] - over [f32], the factored matrix 
+/// * `ipiv`: &[[CoralPivot]] - pivots from `ssytrf` 
+///
+/// Returns: 
+/// * [Inertia] of `A`. Zero counts exactly zero pivots only. 
+#[inline] 
+pub fn ssyinertia ( 
+    uplo: CoralTriangular, 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[CoralPivot], 
+) -> Inertia { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let aa   = &a.as_slice()[aoff..]; 
+
+    let mut inertia = Inertia::default(); 
+
+    let mut k = 0; 
+    while k < n { 
+        match ipiv[k] { 
+            CoralPivot::OneByOne(_) => { 
+                count_1x1(aa[k + k * lda], &mut inertia); 
+                k += 1; 
+            }
+            CoralPivot::TwoByTwo(_) => { 
+                let b = match uplo { 
+                    CoralTriangular::Upper => aa[k + (k + 1) * lda], 
+                    CoralTriangular::Lower => aa[k + 1 + k * lda], 
+                }; 
+
+                count_2x2(aa[k + k * lda], b, aa[k + 1 + (k + 1) * lda], &mut inertia); 
+                k += 2; 
+            }
+        }
+    }
+
+    inertia
+}
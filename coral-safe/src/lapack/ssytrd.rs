@@ -0,0 +1,149 @@
+//! LAPACK [`?SYTRD`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/ssytrd.f)
+//! routine in single precision. 
+//!
+//! Reduces a symmetric matrix to tridiagonal form by an orthogonal similarity 
+//!
+//! \\[ 
+//! Q^T A Q = T. 
+//! \\]
+//!
+//! With `Upper`, $Q = H_{n-2} \cdots H_0$ and $v_i$ is stored above the 
+//! superdiagonal in column $i + 1$. With `Lower`, $Q = H_0 \cdots H_{n-2}$ and 
+//! $v_i$ is stored below the subdiagonal in column $i$. 
+//!
+//! # Notes 
+//! Each step is the unblocked `?SYTD2` update, 
+//!
+//! \\[ 
+//! w = \tau A v - \tfrac{1}{2} \tau (\tau v^T A v) v, \quad 
+//! A \leftarrow A - v w^T - w v^T, 
+//! \\]
+//!
+//! through [ssymv] and [ssyr2]. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::lapack::slarfg::slarfg_slices; 
+use crate::level1::{saxpy, sdot}; 
+use crate::level2::{ssymv, ssyr2}; 
+use crate::types::{CoralTriangular, MatrixRef, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `A := H A H` for the order `m` trailing (or leading) block `a`, with the 
+/// reflector `v` and `w` as workspace 
+#[inline] 
+fn apply_reflector(uplo: CoralTriangular, m: usize, tau: f32, v: &[f32], a: &mut [f32], lda: usize, w: &mut [f32]) { 
+    // w := tau A v 
+    ssymv( 
+        uplo, 
+        tau, 
+        0.0, 
+        MatrixRef::new(a, m, m, lda, 0).expect("A view"), 
+        VectorRef::new(v, m, 1, 0).expect("v view"), 
+        VectorMut::new(w, m, 1, 0).expect("w view"), 
+    ); 
+
+    // w -= 1/2 tau (w^T v) v 
+    let alpha = -0.5 * tau * sdot( 
+        VectorRef::new(w, m, 1, 0).expect("w view"), 
+        VectorRef::new(v, m, 1, 0).expect("v view"), 
+    ); 
+    saxpy(alpha, VectorRef::new(v, m, 1, 0).expect("v view"), VectorMut::new(w, m, 1, 0).expect("w view")); 
+
+    // A -= v w^T + w v^T 
+    ssyr2( 
+        uplo, 
+        -1.0, 
+        VectorRef::new(v, m, 1, 0).expect("v view"), 
+        VectorRef::new(w, m, 1, 0).expect("w view"), 
+        MatrixMut::new(a, m, m, lda, 0).expect("A view"), 
+    ); 
+}
+
+
+/// Reduces a symmetric matrix to symmetric tridiagonal form. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced 
+/// * `a`: [MatrixMut] - over [f32], square. The `uplo` triangle is overwritten 
+///   with `T` and the reflectors of `Q` 
+/// * `d`: &mut [[f32]] - length `n`, the diagonal of `T` 
+/// * `e`: &mut [[f32]] - length `n - 1`, the off diagonal of `T` 
+/// * `tau`: &mut [[f32]] - length `n - 1`, the scalars of the reflectors 
+///
+/// Returns: 
+/// Nothing. `a.data`, `d`, `e` and `tau` are overwritten. 
+pub fn ssytrd ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+    d: &mut [f32], 
+    e: &mut [f32], 
+    tau: &mut [f32], 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    assert!(d.len() >= n, "d must hold n entries"); 
+
+    if n == 0 { 
+        return; 
+    }
+
+    assert!(e.len() >= n - 1 && tau.len() >= n - 1, "e and tau must hold n - 1 entries"); 
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    let mut w = vec![0.0; n]; 
+
+    match uplo { 
+        CoralTriangular::Upper => { 
+            for i in (0..n - 1).rev() { 
+                // H_i annihilates A[0..i, i + 1] 
+                let mut alpha = data[i + (i + 1) * lda]; 
+                let taui = slarfg_slices(i, &mut alpha, &mut data[(i + 1) * lda..], 1); 
+                e[i] = alpha; 
+
+                if taui != 0.0 { 
+                    data[i + (i + 1) * lda] = 1.0; 
+
+                    // v = A[0..=i, i + 1], updating A[0..=i, 0..=i] 
+                    let (left, right) = data.split_at_mut((i + 1) * lda); 
+                    apply_reflector(uplo, i + 1, taui, &right[..i + 1], left, lda, &mut w); 
+
+                    data[i + (i + 1) * lda] = e[i]; 
+                }
+
+                d[i + 1] = data[i + 1 + (i + 1) * lda]; 
+                tau[i]   = taui; 
+            }
+            d[0] = data[0]; 
+        }
+        CoralTriangular::Lower => { 
+            for i in 0..n - 1 { 
+                // H_i annihilates A[i + 2.., i] 
+                let m = n - i - 1; 
+                let mut alpha = data[i + 1 + i * lda]; 
+                let taui = slarfg_slices(m - 1, &mut alpha, &mut data[i + 2 + i * lda..], 1); 
+                e[i] = alpha; 
+
+                if taui != 0.0 { 
+                    data[i + 1 + i * lda] = 1.0; 
+
+                    // v = A[i + 1.., i], updating A[i + 1.., i + 1..] 
+                    let (left, right) = data.split_at_mut((i + 1) * lda); 
+                    apply_reflector(uplo, m, taui, &left[i + 1 + i * lda .. n + i * lda], &mut right[i + 1..], lda, &mut w); 
+
+                    data[i + 1 + i * lda] = e[i]; 
+                }
+
+                d[i]   = data[i + i * lda]; 
+                tau[i] = taui; 
+            }
+            d[n - 1] = data[n - 1 + (n - 1) * lda]; 
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! LAPACK [`?SYTRF`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/ssytrf.f)
+//! routine in single precision. 
+//!
+//! Bunch-Kaufman factorization of a symmetric, possibly indefinite matrix 
+//!
+//! \\[ 
+//! A = U D U^T \quad \text{or} \quad A = L D L^T, 
+//! \\]
+//!
+//! where $U$ ($L$) is a product of permutations and unit upper (lower) 
+//! triangular matrices and $D$ is block diagonal with 1 x 1 and 2 x 2 blocks. 
+//!
+//! # Notes 
+//! With $\alpha = (1 + \sqrt{17}) / 8$, a 1 x 1 pivot is taken when the 
+//! diagonal is at least $\alpha$ times the largest entry beside it, and 
+//! a 2 x 2 pivot otherwise, which bounds the growth in $L$ by 
+//! $(1 + 1 / \alpha)$ per step. This is the unblocked `?SYTF2` sweep. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::{isamax, saxpy, sscal, sswap}; 
+use crate::level2::ssyr::ssyr_slices; 
+use crate::types::{CoralPivot, CoralTriangular, MatrixMut, VectorRef, VectorMut}; 
+
+const ALPHA: f32 = 0.640_388_2; // (1 + sqrt(17)) / 8
+
+
+/// first index of the largest |x_i| over `n` elements at stride `inc` 
+#[inline] 
+fn iamax(x: &[f32], n: usize, inc: usize) -> usize { 
+    isamax(VectorRef::new(x, n, inc, 0).expect("search view")) 
+}
+
+/// swaps rows `r0..r1` of columns `c0 < c1` 
+#[inline] 
+fn swap_cols(a: &mut [f32], lda: usize, r0: usize, r1: usize, c0: usize, c1: usize) { 
+    if r0 >= r1 { 
+        return; 
+    }
+
+    let (left, right) = a.split_at_mut(c1 * lda); 
+    let x = VectorMut::new(&mut left[r0 + c0 * lda .. r1 + c0 * lda], r1 - r0, 1, 0).expect("column view"); 
+    let y = VectorMut::new(&mut right[r0 .. r1], r1 - r0, 1, 0).expect("column view"); 
+    sswap(x, y); 
+}
+
+/// `y -= alpha x` over contiguous slices 
+#[inline] 
+fn axpy(alpha: f32, x: &[f32], y: &mut [f32]) { 
+    let n = x.len(); 
+    saxpy(alpha, VectorRef::new(x, n, 1, 0).expect("x view"), VectorMut::new(y, n, 1, 0).expect("y view")); 
+}
+
+/// which pivot the Bunch-Kaufman test picks for column `k`, given 
+/// |a_kk|, the largest off diagonal in column k (`colmax`) and in row and 
+/// column `imax` (`rowmax`), and |a_{imax, imax}| 
+#[inline] 
+fn choose(absakk: f32, colmax: f32, rowmax: f32, absarr: f32) -> (bool, usize) { 
+    if absakk >= ALPHA * colmax * (colmax / rowmax) { 
+        (false, 1) 
+    } else if absarr >= ALPHA * rowmax { 
+        (true, 1) 
+    } else { 
+        (true, 2) 
+    }
+}
+
+fn sytf2_lower(n: usize, a: &mut [f32], lda: usize, ipiv: &mut [CoralPivot]) -> Option<usize> { 
+    let mut info = None; 
+
+    let mut k = 0; 
+    while k < n { 
+        let mut kstep = 1; 
+        let mut kp    = k; 
+
+        let absakk = a[k + k * lda].abs(); 
+        let (imax, colmax) = if k + 1 < n { 
+            let i = k + 1 + iamax(&a[k + 1 + k * lda..], n - k - 1, 1); 
+            (i, a[i + k * lda].abs()) 
+        } else { 
+            (k, 0.0) 
+        }; 
+
+        if absakk.max(colmax) == 0.0 || absakk.is_nan() { 
+            // column k is zero, D[k, k] = 0 and nothing to eliminate 
+            info.get_or_insert(k); 
+        } else { 
+            if absakk < ALPHA * colmax { 
+                // largest off diagonal in row and column imax 
+                let j = k + iamax(&a[imax + k * lda..], imax - k, lda); 
+                let mut rowmax = a[imax + j * lda].abs(); 
+                if imax + 1 < n { 
+                    let j = imax + 1 + iamax(&a[imax + 1 + imax * lda..], n - imax - 1, 1); 
+                    rowmax = rowmax.max(a[j + imax * lda].abs()); 
+                }
+
+                let (swap, step) = choose(absakk, colmax, rowmax, a[imax + imax * lda].abs()); 
+                if swap { 
+                    kp = imax; 
+                }
+                kstep = step; 
+            }
+
+            // interchange rows and columns kk and kp of A[k.., k..] 
+            let kk = k + kstep - 1; 
+            if kp != kk { 
+                swap_cols(a, lda, kp + 1, n, kk, kp); 
+                for i in kk + 1..kp { 
+                    a.swap(i + kk * lda, kp + i * lda); 
+                }
+                a.swap(kk + kk * lda, kp + kp * lda); 
+                if kstep == 2 { 
+                    a.swap(k + 1 + k * lda, kp + k * lda); 
+                }
+            }
+
+            if kstep == 1 { 
+                // A22 -= v v^T / d, then v := v / d 
+                if k + 1 < n { 
+                    let r1 = 1.0 / a[k + k * lda]; 
+                    let (left, right) = a.split_at_mut((k + 1) * lda); 
+                    let v = &mut left[k + 1 + k * lda .. n + k * lda]; 
+
+                    ssyr_slices(CoralTriangular::Lower, n - k - 1, -r1, v, &mut right[k + 1..], lda); 
+                    sscal(r1, VectorMut::new(v, n - k - 1, 1, 0).expect("column view")); 
+                }
+            } else if k + 2 < n { 
+                // A22 -= [v_k v_k+1] D^{-1} [v_k v_k+1]^T, columns of L stored in place 
+                let d21 = a[k + 1 + k * lda]; 
+                let d11 = a[k + 1 + (k + 1) * lda] / d21; 
+                let d22 = a[k + k * lda] / d21; 
+                let t   = 1.0 / (d11 * d22 - 1.0); 
+                let d21 = t / d21; 
+
+                for j in k + 2..n { 
+                    let wk   = d21 * (d11 * a[j + k * lda] - a[j + (k + 1) * lda]); 
+                    let wkp1 = d21 * (d22 * a[j + (k + 1) * lda] - a[j + k * lda]); 
+
+                    let (left, right) = a.split_at_mut(j * lda); 
+                    let cj = &mut right[j..n]; 
+                    axpy(-wk,   &left[j + k * lda .. n + k * lda], cj); 
+                    axpy(-wkp1, &left[j + (k + 1) * lda .. n + (k + 1) * lda], cj); 
+
+                    a[j + k * lda]       = wk; 
+                    a[j + (k + 1) * lda] = wkp1; 
+                }
+            }
+        }
+
+        if kstep == 1 { 
+            ipiv[k] = CoralPivot::OneByOne(kp); 
+        } else { 
+            ipiv[k]     = CoralPivot::TwoByTwo(kp); 
+            ipiv[k + 1] = CoralPivot::TwoByTwo(kp); 
+        }
+
+        k += kstep; 
+    }
+
+    info
+}
+
+fn sytf2_upper(n: usize, a: &mut [f32], lda: usize, ipiv: &mut [CoralPivot]) -> Option<usize> { 
+    let mut info = None; 
+
+    // k is one past the column being eliminated 
+    let mut k = n; 
+    while k > 0 { 
+        let kc = k - 1; 
+        let mut kstep = 1; 
+        let mut kp    = kc; 
+
+        let absakk = a[kc + kc * lda].abs(); 
+        let (imax, colmax) = if kc > 0 { 
+            let i = iamax(&a[kc * lda..], kc, 1); 
+            (i, a[i + kc * lda].abs()) 
+        } else { 
+            (kc, 0.0) 
+        }; 
+
+        if absakk.max(colmax) == 0.0 || absakk.is_nan() { 
+            info = Some(kc); 
+        } else { 
+            if absakk < ALPHA * colmax { 
+                let j = imax + 1 + iamax(&a[imax + (imax + 1) * lda..], kc - imax, lda); 
+                let mut rowmax = a[imax + j * lda].abs(); 
+                if imax > 0 { 
+                    let j = iamax(&a[imax * lda..], imax, 1); 
+                    rowmax = rowmax.max(a[j + imax * lda].abs()); 
+                }
+
+                let (swap, step) = choose(absakk, colmax, rowmax, a[imax + imax * lda].abs()); 
+                if swap { 
+                    kp = imax; 
+                }
+                kstep = step; 
+            }
+
+            // interchange rows and columns kk and kp of A[..=kc, ..=kc] 
+            let kk = kc + 1 - kstep; 
+            if kp != kk { 
+                swap_cols(a, lda, 0, kp, kp, kk); 
+                for j in kp + 1..kk { 
+                    a.swap(j + kk * lda, kp + j * lda); 
+                }
+                a.swap(kk + kk * lda, kp + kp * lda); 
+                if kstep == 2 { 
+                    a.swap(kc - 1 + kc * lda, kp + kc * lda); 
+                }
+            }
+
+            if kstep == 1 { 
+                if kc > 0 { 
+                    let r1 = 1.0 / a[kc + kc * lda]; 
+                    let (left, right) = a.split_at_mut(kc * lda); 
+                    let v = &mut right[..kc]; 
+
+                    ssyr_slices(CoralTriangular::Upper, kc, -r1, v, left, lda); 
+                    sscal(r1, VectorMut::new(v, kc, 1, 0).expect("column view")); 
+                }
+            } else if kc > 1 { 
+                let d12 = a[kc - 1 + kc * lda]; 
+                let d22 = a[kc - 1 + (kc - 1) * lda] / d12; 
+                let d11 = a[kc + kc * lda] / d12; 
+                let t   = 1.0 / (d11 * d22 - 1.0); 
+                let d12 = t / d12; 
+
+                for j in (0..kc - 1).rev() { 
+                    let wkm1 = d12 * (d11 * a[j + (kc - 1) * lda] - a[j + kc * lda]); 
+                    let wk   = d12 * (d22 * a[j + kc * lda] - a[j + (kc - 1) * lda]); 
+
+                    let (left, right) = a.split_at_mut((kc - 1) * lda); 
+                    let cj = &mut left[j * lda .. j * lda + j + 1]; 
+                    axpy(-wk,   &right[lda .. lda + j + 1], cj); 
+                    axpy(-wkm1, &right[..j + 1], cj); 
+
+                    a[j + kc * lda]       = wk; 
+                    a[j + (kc - 1) * lda] = wkm1; 
+                }
+            }
+        }
+
+        if kstep == 1 { 
+            ipiv[kc] = CoralPivot::OneByOne(kp); 
+        } else { 
+            ipiv[kc]     = CoralPivot::TwoByTwo(kp); 
+            ipiv[kc - 1] = CoralPivot::TwoByTwo(kp); 
+        }
+
+        k -= kstep; 
+    }
+
+    info
+}
+
+
+/// Computes the Bunch-Kaufman factorization of a symmetric indefinite 
+/// matrix. 
+///
+/// Each step searches with [isamax], interchanges with [sswap] and 
+/// updates the trailing matrix with a symmetric rank-1 ([ssyr]) or 
+/// rank-2 update. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced and 
+///   overwritten with `D` and the multipliers of `U` or `L` 
+/// * `a`: [MatrixMut] - over [f32], square 
+/// * `ipiv`: &mut [[CoralPivot]] - length at least `n`, the interchanges 
+///   and block structure of `D` 
+///
+/// Returns: 
+/// * `Ok(())` on success 
+/// * [LapackError::Singular] with the first `i` where `D[i, i]` is an 
+///   exactly zero 1 x 1 block. The factorization is still completed. 
+///
+/// [ssyr]: crate::level2::ssyr
+pub fn ssytrf ( 
+    uplo: CoralTriangular, 
+    mut a: MatrixMut<'_, f32>, 
+    ipiv: &mut [CoralPivot], 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+
+    if n == 0 { 
+        return Ok(()); 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+
+    let info = match uplo { 
+        CoralTriangular::Upper => sytf2_upper(n, data, lda, ipiv), 
+        CoralTriangular::Lower => sytf2_lower(n, data, lda, ipiv), 
+    }; 
+
+    match info { 
+        Some(index) => Err(LapackError::Singular { index }), 
+        None        => Ok(()), 
+    }
+}
@@ -0,0 +1,185 @@
+//! LAPACK [`?SYTRS`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/ssytrs.f)
+//! routine in single precision. 
+//!
+//! Solves $A X = B$ with the factorization $A = U D U^T$ or $A = L D L^T$ 
+//! computed by [ssytrf](crate::lapack::ssytrf), as 
+//!
+//! \\[ 
+//! X = P^T L^{-T} D^{-1} L^{-1} P B. 
+//! \\]
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{saxpy, sdot}; 
+use crate::types::{CoralPivot, CoralTriangular, MatrixRef, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `y -= alpha x` over contiguous slices 
+#[inline] 
+fn axpy(alpha: f32, x: &[f32], y: &mut [f32]) { 
+    let n = x.len(); 
+    saxpy(alpha, VectorRef::new(x, n, 1, 0).expect("x view"), VectorMut::new(y, n, 1, 0).expect("y view")); 
+}
+
+/// `x . y` over contiguous slices 
+#[inline] 
+fn dot(x: &[f32], y: &[f32]) -> f32 { 
+    let n = x.len(); 
+    sdot(VectorRef::new(x, n, 1, 0).expect("x view"), VectorRef::new(y, n, 1, 0).expect("y view")) 
+}
+
+/// solves the 2 x 2 block `[[akm1, akm1k], [akm1k, ak]]` in place on `(bkm1, bk)` 
+#[inline] 
+fn solve_2x2(akm1: f32, akm1k: f32, ak: f32, b: &mut [f32], km1: usize, k: usize) { 
+    let akm1  = akm1 / akm1k; 
+    let ak    = ak / akm1k; 
+    let denom = akm1 * ak - 1.0; 
+
+    let bkm1 = b[km1] / akm1k; 
+    let bk   = b[k] / akm1k; 
+    b[km1] = (ak * bkm1 - bk) / denom; 
+    b[k]   = (akm1 * bk - bkm1) / denom; 
+}
+
+fn sytrs_lower(n: usize, a: &[f32], lda: usize, ipiv: &[CoralPivot], x: &mut [f32]) { 
+    // x := D^{-1} L^{-1} P x 
+    let mut k = 0; 
+    while k < n { 
+        match ipiv[k] { 
+            CoralPivot::OneByOne(kp) => { 
+                x.swap(k, kp); 
+
+                let (head, tail) = x.split_at_mut(k + 1); 
+                axpy(-head[k], &a[k + 1 + k * lda .. n + k * lda], tail); 
+
+                x[k] /= a[k + k * lda]; 
+                k += 1; 
+            }
+            CoralPivot::TwoByTwo(kp) => { 
+                x.swap(k + 1, kp); 
+
+                let (head, tail) = x.split_at_mut(k + 2); 
+                axpy(-head[k],     &a[k + 2 + k * lda .. n + k * lda], tail); 
+                axpy(-head[k + 1], &a[k + 2 + (k + 1) * lda .. n + (k + 1) * lda], tail); 
+
+                solve_2x2(a[k + k * lda], a[k + 1 + k * lda], a[k + 1 + (k + 1) * lda], x, k, k + 1); 
+                k += 2; 
+            }
+        }
+    }
+
+    // x := P^T L^{-T} x 
+    let mut k = n; 
+    while k > 0 { 
+        let kc = k - 1; 
+        match ipiv[kc] { 
+            CoralPivot::OneByOne(kp) => { 
+                x[kc] -= dot(&x[kc + 1..n], &a[kc + 1 + kc * lda .. n + kc * lda]); 
+                x.swap(kc, kp); 
+                k -= 1; 
+            }
+            CoralPivot::TwoByTwo(kp) => { 
+                x[kc]     -= dot(&x[kc + 1..n], &a[kc + 1 + kc * lda .. n + kc * lda]); 
+                x[kc - 1] -= dot(&x[kc + 1..n], &a[kc + 1 + (kc - 1) * lda .. n + (kc - 1) * lda]); 
+                x.swap(kc, kp); 
+                k -= 2; 
+            }
+        }
+    }
+}
+
+fn sytrs_upper(n: usize, a: &[f32], lda: usize, ipiv: &[CoralPivot], x: &mut [f32]) { 
+    // x := D^{-1} U^{-1} P x 
+    let mut k = n; 
+    while k > 0 { 
+        let kc = k - 1; 
+        match ipiv[kc] { 
+            CoralPivot::OneByOne(kp) => { 
+                x.swap(kc, kp); 
+
+                let (head, tail) = x.split_at_mut(kc); 
+                axpy(-tail[0], &a[kc * lda .. kc * lda + kc], head); 
+
+                x[kc] /= a[kc + kc * lda]; 
+                k -= 1; 
+            }
+            CoralPivot::TwoByTwo(kp) => { 
+                x.swap(kc - 1, kp); 
+
+                let (head, tail) = x.split_at_mut(kc - 1); 
+                axpy(-tail[1], &a[kc * lda .. kc * lda + kc - 1], head); 
+                axpy(-tail[0], &a[(kc - 1) * lda .. (kc - 1) * lda + kc - 1], head); 
+
+                solve_2x2(a[kc - 1 + (kc - 1) * lda], a[kc - 1 + kc * lda], a[kc + kc * lda], x, kc - 1, kc); 
+                k -= 2; 
+            }
+        }
+    }
+
+    // x := P^T U^{-T} x 
+    let mut k = 0; 
+    while k < n { 
+        match ipiv[k] { 
+            CoralPivot::OneByOne(kp) => { 
+                x[k] -= dot(&x[..k], &a[k * lda .. k * lda + k]); 
+                x.swap(k, kp); 
+                k += 1; 
+            }
+            CoralPivot::TwoByTwo(kp) => { 
+                x[k]     -= dot(&x[..k], &a[k * lda .. k * lda + k]); 
+                x[k + 1] -= dot(&x[..k], &a[(k + 1) * lda .. (k + 1) * lda + k]); 
+                x.swap(k, kp); 
+                k += 2; 
+            }
+        }
+    }
+}
+
+
+/// Solves `A X = B` for symmetric indefinite `A` with the Bunch-Kaufman 
+/// factorization from [ssytrf](crate::lapack::ssytrf). 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - the triangle `ssytrf` was called with 
+/// * `a`: [MatrixRef] - over [f32], `D` and the multipliers from `ssytrf` 
+/// * `ipiv`: &[[CoralPivot]] - pivots from `ssytrf` 
+/// * `b`: [MatrixMut] - over [f32], input as `B`, output as `X` 
+///
+/// Returns: 
+/// Nothing. `b.data` is overwritten. 
+#[inline] 
+pub fn ssytrs ( 
+    uplo: CoralTriangular, 
+    a: MatrixRef<'_, f32>, 
+    ipiv: &[CoralPivot], 
+    mut b: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let nrhs = b.n_cols(); 
+    debug_assert_eq!(b.n_rows(), n, "rows of B must equal order of A"); 
+    assert!(ipiv.len() >= n, "ipiv must hold n pivots"); 
+
+    if n == 0 || nrhs == 0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let ldb  = b.lda(); 
+    let aoff = a.offset(); 
+    let boff = b.offset(); 
+    let aa   = &a.as_slice()[aoff..]; 
+    let bs   = &mut b.as_slice_mut()[boff..]; 
+
+    for j in 0..nrhs { 
+        let x = &mut bs[j * ldb .. j * ldb + n]; 
+
+        match uplo { 
+            CoralTriangular::Upper => sytrs_upper(n, aa, lda, ipiv, x), 
+            CoralTriangular::Lower => sytrs_lower(n, aa, lda, ipiv, x), 
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! LAPACK [`?TRTRI`](https://github.com/Reference-LAPACK/lapack/blob/master/SRC/strtri.f)
+//! routine in single precision. 
+//!
+//! Inverts a triangular matrix in place, 
+//!
+//! \\[ 
+//! A \leftarrow A^{-1}. 
+//! \\]
+//!
+//! # Notes 
+//! Column by column as in `?TRTI2`. With `Upper`, column $j$ of the inverse is 
+//!
+//! \\[ 
+//! X_{0:j, j} = -x_{jj} X_{0:j, 0:j} A_{0:j, j}, \quad x_{jj} = 1 / a_{jj}, 
+//! \\]
+//!
+//! from the leading block already inverted, and `Lower` runs backward 
+//! from the trailing block. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::errors::LapackError; 
+use crate::level1::{saxpy, sscal}; 
+use crate::types::{CoralDiagonal, CoralTriangular, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `x := T x` in place for the order `n` triangle `t`, `x` outside of `t` 
+#[inline] 
+fn trmv(uplo: CoralTriangular, unit: bool, n: usize, t: &[f32], ldt: usize, x: &mut [f32]) { 
+    match uplo { 
+        CoralTriangular::Upper => { 
+            // x[k] only feeds rows 0..=k 
+            for k in 0..n { 
+                let xk = x[k]; 
+                if xk != 0.0 { 
+                    saxpy( 
+                        xk, 
+                        VectorRef::new(&t[k * ldt .. k * ldt + k], k, 1, 0).expect("T column view"), 
+                        VectorMut::new(&mut x[..k], k, 1, 0).expect("x view"), 
+                    ); 
+                }
+                if !unit { 
+                    x[k] = xk * t[k + k * ldt]; 
+                }
+            }
+        }
+        CoralTriangular::Lower => { 
+            // x[k] only feeds rows k.. 
+            for k in (0..n).rev() { 
+                let xk = x[k]; 
+                if xk != 0.0 { 
+                    let len = n - k - 1; 
+                    saxpy( 
+                        xk, 
+                        VectorRef::new(&t[k + 1 + k * ldt .. n + k * ldt], len, 1, 0).expect("T column view"), 
+                        VectorMut::new(&mut x[k + 1..n], len, 1, 0).expect("x view"), 
+                    ); 
+                }
+                if !unit { 
+                    x[k] = xk * t[k + k * ldt]; 
+                }
+            }
+        }
+    }
+}
+
+
+/// Computes the inverse of a triangular matrix in place. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - whether `a` is upper or lower triangular 
+/// * `diag`: [CoralDiagonal] - whether `a` has a unit diagonal, which is 
+///   then not referenced 
+/// * `a`: [MatrixMut] - over [f32], square 
+///
+/// Returns: 
+/// * `Ok(())` on success, the `uplo` triangle of `a.data` overwritten 
+/// * [LapackError::Singular] with the first exactly zero diagonal entry; 
+///   `a` is left untouched 
+pub fn strtri ( 
+    uplo: CoralTriangular, 
+    diag: CoralDiagonal, 
+    mut a: MatrixMut<'_, f32>, 
+) -> Result<(), LapackError> { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n    = a.n_rows(); 
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let data = &mut a.as_slice_mut()[aoff..]; 
+    let unit = diag.is_unit(); 
+
+    let zero = (0..n).find(|&j| !unit && data[j + j * lda] == 0.0); 
+    if let Some(index) = zero { 
+        return Err(LapackError::Singular { index }); 
+    }
+
+    match uplo { 
+        CoralTriangular::Upper => { 
+            for j in 0..n { 
+                let ajj = if unit { 
+                    -1.0 
+                } else { 
+                    data[j + j * lda] = 1.0 / data[j + j * lda]; 
+                    -data[j + j * lda] 
+                }; 
+
+                // X[0..j, j] = -x_jj X[0..j, 0..j] A[0..j, j] 
+                let (left, right) = data.split_at_mut(j * lda); 
+                let x = &mut right[..j]; 
+                trmv(uplo, unit, j, left, lda, x); 
+                sscal(ajj, VectorMut::new(x, j, 1, 0).expect("column view")); 
+            }
+        }
+        CoralTriangular::Lower => { 
+            for j in (0..n).rev() { 
+                let ajj = if unit { 
+                    -1.0 
+                } else { 
+                    data[j + j * lda] = 1.0 / data[j + j * lda]; 
+                    -data[j + j * lda] 
+                }; 
+
+                // X[j + 1.., j] = -x_jj X[j + 1.., j + 1..] A[j + 1.., j] 
+                if j + 1 < n { 
+                    let len = n - j - 1; 
+                    let (left, right) = data.split_at_mut((j + 1) * lda); 
+                    let x = &mut left[j + 1 + j * lda .. n + j * lda]; 
+                    trmv(uplo, unit, len, &right[j + 1..], lda, x); 
+                    sscal(ajj, VectorMut::new(x, len, 1, 0).expect("column view")); 
+                }
+            }
+        }
+    }
+
+    Ok(()) 
+}
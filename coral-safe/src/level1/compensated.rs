@@ -0,0 +1,66 @@
+//! Error-free transformations behind the `*_accurate` routines and the
+//! [`Accuracy::Compensated`](crate::types::Accuracy::Compensated) fused kernels.
+//!
+//! Ogita, Rump and Oishi, "Accurate Sum and Dot Product", SIAM J. Sci. Comput. 26(6), 2005.
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+/// `a + b = s + e` exactly, with `s = fl(a + b)`. 
+#[inline(always)] 
+pub(crate) fn two_sum ( 
+    a: f32, 
+    b: f32
+) -> (f32, f32) { 
+    let s  = a + b; 
+    let bb = s - a; 
+
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// `a * b = p + e` exactly, with `p = fl(a * b)`, through one FMA. 
+#[inline(always)] 
+pub(crate) fn two_prod ( 
+    a: f32, 
+    b: f32
+) -> (f32, f32) { 
+    let p = a * b; 
+
+    (p, a.mul_add(b, -p))
+}
+
+/// `Dot2` accumulator. `hi + lo` carries the running sum in twice the working
+/// precision. 
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct Dot2 { 
+    pub(crate) hi: f32, 
+    pub(crate) lo: f32, 
+}
+
+impl Dot2 { 
+    /// adds `a * b` 
+    #[inline(always)] 
+    pub(crate) fn fma ( &mut self, a: f32, b: f32 ) { 
+        let (p, e) = two_prod(a, b); 
+        let (s, q) = two_sum(self.hi, p); 
+
+        self.hi  = s; 
+        self.lo += q + e; 
+    }
+
+    /// adds `a` 
+    #[inline(always)] 
+    pub(crate) fn add ( &mut self, a: f32 ) { 
+        let (s, q) = two_sum(self.hi, a); 
+
+        self.hi  = s; 
+        self.lo += q; 
+    }
+
+    /// rounds to working precision
+    #[inline(always)] 
+    pub(crate) fn value ( &self ) -> f32 { 
+        self.hi + self.lo
+    }
+}
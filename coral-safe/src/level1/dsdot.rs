@@ -0,0 +1,85 @@
+//! Level 1 [`?DSDOT`](https://www.netlib.org/lapack/explore-html/d1/dcc/group__dot.html)
+//! routine: single precision inputs, double precision sum.
+//!
+//! \\[ 
+//! \sum\_{i=0}^{n-1} x_i \\, y_i 
+//! \\]
+//!
+//! Every entry is widened to [f64] before the multiply, so the products are exact 
+//! and only the [f64] additions round.
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use std::simd::Simd;
+use std::simd::num::SimdFloat;
+use crate::types::VectorRef; 
+use crate::debug_assert_n_eq; 
+
+
+/// Takes the dot product over logical elements in [VectorRef] 
+/// `x` and `y`, accumulated in [f64].
+///
+/// Arguments: 
+/// * `x`: [VectorRef] - over [f32]
+/// * `y`: [VectorRef] - over [f32]
+/// 
+/// Returns: 
+/// - [f64] dot product.
+#[inline] 
+pub fn dsdot ( 
+    x: VectorRef<'_, f32>, 
+    y: VectorRef<'_, f32>, 
+) -> f64 {
+    debug_assert_n_eq!(x, y); 
+
+    let n = x.n(); 
+    let incx = x.stride(); 
+    let incy = y.stride(); 
+
+    if n == 0 { 
+        return 0.0;
+    }
+
+    // fast path
+    if let (Some(xs), Some(ys)) = (x.contiguous_slice(), y.contiguous_slice()) { 
+        const LANES: usize = 16; 
+        let mut acc = Simd::<f64, LANES>::splat(0.0);
+
+        let (xv, xt) = xs.as_chunks::<LANES>(); 
+        let (yv, yt) = ys.as_chunks::<LANES>(); 
+
+        for (&xc, &yc) in xv.iter().zip(yv.iter()) { 
+            // f32 -> f64 lane by lane
+            let xm: Simd<f64, LANES> = Simd::from_array(xc).cast(); 
+            let ym: Simd<f64, LANES> = Simd::from_array(yc).cast();
+
+            acc += xm * ym; 
+        }
+
+        let mut acc_tail = 0.0; 
+        for (&xf, &yf) in xt.iter().zip(yt.iter()) { 
+            acc_tail += xf as f64 * yf as f64; 
+        }
+
+        return acc.reduce_sum() + acc_tail;
+    }  
+
+    // slow path 
+    let mut acc = 0.0; 
+    let ix = x.offset(); 
+    let iy = y.offset(); 
+
+    let xs = x.as_slice(); 
+    let ys = y.as_slice(); 
+
+    let xs_it = xs[ix..].iter().step_by(incx).take(n); 
+    let ys_it = ys[iy..].iter().step_by(incy).take(n); 
+
+    for (&xv, &yv) in xs_it.zip(ys_it) { 
+        acc += xv as f64 * yv as f64; 
+    }
+
+    acc
+}
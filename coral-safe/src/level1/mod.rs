@@ -5,11 +5,18 @@ pub mod snrm2;
 pub mod scopy; 
 pub mod sswap;
 pub mod sdot; 
+pub mod sdsdot;
+pub mod dsdot;
 pub mod isamax;
 pub mod srot; 
 pub mod srotg;
 pub mod srotm; 
 pub mod srotmg;
+pub mod sdot_accurate;
+pub mod sasum_accurate;
+pub mod snrm2_accurate;
+
+pub(crate) mod compensated;
 
 pub use saxpy::saxpy;
 pub use sscal::sscal;
@@ -18,8 +25,13 @@ pub use snrm2::snrm2;
 pub use scopy::scopy;
 pub use sswap::sswap;
 pub use sdot::sdot;
+pub use sdsdot::sdsdot;
+pub use dsdot::dsdot;
 pub use isamax::isamax;
 pub use srot::srot;
 pub use srotg::srotg;
 pub use srotm::srotm;
 pub use srotmg::srotmg;
+pub use sdot_accurate::sdot_accurate;
+pub use sasum_accurate::sasum_accurate;
+pub use snrm2_accurate::snrm2_accurate;
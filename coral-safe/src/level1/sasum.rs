@@ -12,6 +12,7 @@
 use std::simd::Simd; 
 use std::simd::num::SimdFloat;
 use crate::types::VectorRef; 
+use crate::reproducible;
 
 
 /// Computes the sum of absolute values of elements in a 
@@ -22,6 +23,8 @@ use crate::types::VectorRef;
 ///
 /// Returns: 
 /// - [f32] sum of abs values of logical vector elements. 
+///
+/// Sums in a fixed order under the `reproducible` feature.
 #[inline] 
 pub fn sasum ( 
     x: VectorRef<'_, f32>
@@ -33,6 +36,10 @@ pub fn sasum (
         return 0.0; 
     }
 
+    if cfg!(feature = "reproducible") {
+        return reproducible::sasum(x);
+    }
+
     if let Some(xs) = x.contiguous_slice() { 
         const LANES: usize = 32; 
         let mut acc = Simd::<f32, LANES>::splat(0.0);
@@ -0,0 +1,51 @@
+//! Level 1 [`?ASUM`](https://www.netlib.org/lapack/explore-html/d5/d72/group__asum.html) 
+//! routine in single precision, compensated.
+//!
+//! \\[ 
+//! \sum\_{i=0}^{n-1} \lvert x_i \rvert
+//! \\]
+//!
+//! `Sum2` of Ogita, Rump and Oishi, a cascaded `TwoSum` in the spirit of Kahan and 
+//! Neumaier. Every term is nonnegative, so with $u = 2^{-24}$ and 
+//! $\gamma_n = nu / (1 - nu)$ the relative error is at most
+//!
+//! \\[ 
+//! u + \gamma\_{n-1}^2,
+//! \\]
+//!
+//! against $\gamma\_{n-1}$ for [sasum](crate::level1::sasum()). Scalar, so several 
+//! times slower. 
+//!
+//! # Author 
+//! Deval Deliwala 
+
+
+use crate::types::VectorRef; 
+use crate::level1::compensated::Dot2; 
+
+
+/// Computes the compensated sum of absolute values of elements in a 
+/// single precision [VectorRef].
+///
+/// Arguments: 
+/// * `x`: [VectorRef] - over [f32]
+///
+/// Returns: 
+/// - [f32] sum of abs values of logical vector elements, within the bound above. 
+#[inline] 
+pub fn sasum_accurate ( 
+    x: VectorRef<'_, f32>
+) -> f32 { 
+    let n = x.n(); 
+
+    if n == 0 { 
+        return 0.0; 
+    }
+
+    let mut acc = Dot2::default(); 
+    for &v in x.as_slice()[x.offset()..].iter().step_by(x.stride()).take(n) { 
+        acc.add(v.abs()); 
+    }
+
+    acc.value()
+}
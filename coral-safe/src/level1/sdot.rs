@@ -13,6 +13,7 @@ use std::simd::Simd;
 use std::simd::num::SimdFloat;
 use crate::types::VectorRef; 
 use crate::debug_assert_n_eq; 
+use crate::reproducible;
 
 
 /// Takes the dot product over logical elements in [VectorRef] 
@@ -24,6 +25,9 @@ use crate::debug_assert_n_eq;
 /// 
 /// Returns: 
 /// - [f32] dot product.
+///
+/// The `reproducible` feature fixes the summation order, so the bits do not
+/// depend on the strides or on `n % LANES`.
 #[inline] 
 pub fn sdot ( 
     x: VectorRef<'_, f32>, 
@@ -39,6 +43,10 @@ pub fn sdot (
         return 0.0;
     }
 
+    if cfg!(feature = "reproducible") {
+        return reproducible::sdot(x, y);
+    }
+
     // fast path
     if let (Some(xs), Some(ys)) = (x.contiguous_slice(), y.contiguous_slice()) { 
         const LANES: usize = 32; 
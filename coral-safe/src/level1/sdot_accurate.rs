@@ -0,0 +1,64 @@
+//! Level 1 [`?DOT`](https://www.netlib.org/lapack/explore-html/d1/dcc/group__dot.html)
+//! routine in single precision, compensated. 
+//!
+//! \\[ 
+//! \sum\_{i=0}^{n-1} x_i \\, y_i 
+//! \\]
+//!
+//! `Dot2` of Ogita, Rump and Oishi: products are split exactly with an FMA, partial 
+//! sums with `TwoSum`, and the rounding errors summed on the side. The result is as 
+//! accurate as if computed in twice the working precision, then rounded. 
+//!
+//! With $u = 2^{-24}$ and $\gamma_n = nu / (1 - nu)$,
+//!
+//! \\[ 
+//! \lvert \hat s - x^T y \rvert \le u \lvert x^T y \rvert + \gamma_n^2 \sum\_{i} \lvert x_i y_i \rvert,
+//! \\]
+//!
+//! against $\gamma_n \sum \lvert x_i y_i \rvert$ for [sdot](crate::level1::sdot()). 
+//! Scalar, so several times slower. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::VectorRef; 
+use crate::debug_assert_n_eq; 
+use crate::level1::compensated::Dot2; 
+
+
+/// Takes the compensated dot product over logical elements in [VectorRef] 
+/// `x` and `y`.
+///
+/// Arguments: 
+/// * `x`: [VectorRef] - over [f32]
+/// * `y`: [VectorRef] - over [f32]
+/// 
+/// Returns: 
+/// - [f32] dot product, within the bound above. 
+#[inline] 
+pub fn sdot_accurate ( 
+    x: VectorRef<'_, f32>, 
+    y: VectorRef<'_, f32>, 
+) -> f32 {
+    debug_assert_n_eq!(x, y); 
+
+    let n = x.n(); 
+
+    if n == 0 { 
+        return 0.0;
+    }
+
+    let xs = &x.as_slice()[x.offset()..]; 
+    let ys = &y.as_slice()[y.offset()..]; 
+
+    let mut acc = Dot2::default(); 
+    for (&xv, &yv) in xs.iter().step_by(x.stride())
+        .zip(ys.iter().step_by(y.stride()))
+        .take(n) 
+    { 
+        acc.fma(xv, yv); 
+    }
+
+    acc.value()
+}
@@ -0,0 +1,36 @@
+//! Level 1 [`?SDSDOT`](https://www.netlib.org/lapack/explore-html/d1/dcc/group__dot.html)
+//! routine: a scalar plus a dot product, summed in double precision.
+//!
+//! \\[ 
+//! s_b + \sum\_{i=0}^{n-1} x_i \\, y_i 
+//! \\]
+//!
+//! The sum is that of [dsdot](crate::level1::dsdot()), rounded to [f32] once 
+//! after `sb` is added.
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::VectorRef; 
+use crate::level1::dsdot::dsdot; 
+
+
+/// Adds `sb` to the dot product over logical elements in [VectorRef]
+/// `x` and `y`, accumulating in [f64].
+///
+/// Arguments: 
+/// * `sb`: [f32] - scalar added to the dot product
+/// * `x`: [VectorRef] - over [f32]
+/// * `y`: [VectorRef] - over [f32]
+/// 
+/// Returns: 
+/// - [f32] `sb + x^T y`.
+#[inline] 
+pub fn sdsdot ( 
+    sb: f32, 
+    x: VectorRef<'_, f32>, 
+    y: VectorRef<'_, f32>, 
+) -> f32 {
+    (sb as f64 + dsdot(x, y)) as f32
+}
@@ -5,13 +5,84 @@
 //! \sqrt{\sum\_{i=0}^{n-1} x_i^2}
 //! \\]
 //!
+//! Uses Blue's algorithm, as in reference BLAS 3.10. Each $|x_i|$ lands in
+//! one of three sums of squares (small, medium, big), scaled by exact powers
+//! of two so no square over- or underflows.
+//!
 //! # Author 
 //! Deval Deliwala
 
 
 use std::simd::Simd;
+use std::simd::Select;
+use std::simd::cmp::SimdPartialOrd;
 use std::simd::num::SimdFloat;
 use crate::types::VectorRef; 
+use crate::reproducible;
+
+
+const fn pow2(k: i32) -> f32 { f32::from_bits(((k + 127) as u32) << 23) }
+
+// minexponent -125, maxexponent 128, 24 digits
+const TSML: f32 = pow2(-63);
+const TBIG: f32 = pow2(52);
+const SSML: f32 = pow2(75);
+const SBIG: f32 = pow2(-76);
+
+const LANES: usize = 16;
+
+
+#[derive(Default)]
+struct Blue {
+    sml : f32,
+    med : f32,
+    big : f32,
+}
+
+impl Blue {
+    #[inline(always)]
+    fn push(&mut self, x: f32) {
+        let ax = x.abs();
+
+        if ax > TBIG {
+            let t = ax * SBIG;
+            self.big += t * t;
+        } else if ax < TSML {
+            let t = ax * SSML;
+            self.sml += t * t;
+        } else {
+            self.med += ax * ax;
+        }
+    }
+
+    #[inline]
+    fn finish(self) -> f32 {
+        let Blue { sml, med, big } = self;
+
+        // a NaN in `med` must reach the result
+        let has_med = med > 0.0 || med.is_nan();
+
+        if big > 0.0 {
+            let big = if has_med { big + (med * SBIG) * SBIG } else { big };
+            return big.sqrt() / SBIG;
+        }
+
+        if sml > 0.0 {
+            if !has_med {
+                return sml.sqrt() / SSML;
+            }
+
+            let med = med.sqrt();
+            let sml = sml.sqrt() / SSML;
+            let (ymin, ymax) = if sml > med { (med, sml) } else { (sml, med) };
+            let r = ymin / ymax;
+
+            return (ymax * ymax * (1.0 + r * r)).sqrt();
+        }
+
+        med.sqrt()
+    }
+}
 
 
 /// Computes the Euclidean norm of a single precision [VectorRef]
@@ -21,6 +92,10 @@ use crate::types::VectorRef;
 ///
 /// Returns: 
 /// - [f32] norm of the logical vector elements. 
+///
+/// Each of the three sums of Blue's algorithm keeps its own SIMD lanes on
+/// contiguous input. Under the `reproducible` feature the squares are scaled
+/// by a power of two and summed in a fixed order.
 #[inline] 
 pub fn snrm2 ( 
     x: VectorRef<'_, f32>
@@ -32,31 +107,52 @@ pub fn snrm2 (
          return 0.0; 
     }
 
-    let mut sum: f32 = 0.0; 
+    if cfg!(feature = "reproducible") {
+        return reproducible::snrm2(x);
+    }
+
+    let mut acc = Blue::default(); 
     if let Some(xs) = x.contiguous_slice() { 
-        const LANES: usize = 32; 
+        let zero = Simd::<f32, LANES>::splat(0.0); 
+
+        let mut sml = zero; 
+        let mut med = zero; 
+        let mut big = zero; 
 
         let (chunks, tail) = xs.as_chunks::<LANES>(); 
         for &chunk in chunks { 
-            let v = Simd::<f32, LANES>::from_array(chunk); 
+            let ax = Simd::<f32, LANES>::from_array(chunk).abs(); 
 
-            sum += (v * v).reduce_sum();
+            let is_big = ax.simd_gt(Simd::splat(TBIG)); 
+            let is_sml = ax.simd_lt(Simd::splat(TSML)); 
+
+            // each lane feeds exactly one of the three sums
+            let tb = is_big.select(ax * Simd::splat(SBIG), zero); 
+            let ts = is_sml.select(ax * Simd::splat(SSML), zero); 
+            let tm = (is_big | is_sml).select(zero, ax); 
+
+            big += tb * tb; 
+            sml += ts * ts; 
+            med += tm * tm; 
         }
 
-        // no fma
+        acc.sml = sml.reduce_sum(); 
+        acc.med = med.reduce_sum(); 
+        acc.big = big.reduce_sum(); 
+
         for &t in tail { 
-            sum += t * t;
+            acc.push(t);
         }
 
-        return sum.sqrt(); 
+        return acc.finish(); 
     }
 
     let ix = x.offset(); 
     let xs = x.as_slice(); 
 
     for &v in xs[ix..].iter().step_by(incx).take(n) { 
-        sum += v * v; 
+        acc.push(v); 
     }
 
-    sum.sqrt()
+    acc.finish()
 }
@@ -0,0 +1,67 @@
+//! Level 1 [`?NRM2`](https://www.netlib.org/lapack/explore-html/d1/d2a/group__nrm2.html)
+//! routine in single precision, compensated. 
+//!
+//! \\[ 
+//! \sqrt{\sum\_{i=0}^{n-1} x_i^2}
+//! \\]
+//!
+//! Scales by the power of two at or above $\max_i \lvert x_i \rvert$, which is exact 
+//! and rules out overflow and harmful underflow, then sums the squares with `Dot2`
+//! of Ogita, Rump and Oishi. With $u = 2^{-24}$ and $\gamma_n = nu / (1 - nu)$ the 
+//! relative error is at most
+//!
+//! \\[ 
+//! 2u + \gamma_n^2,
+//! \\]
+//!
+//! half that of the sum of squares plus the square root. Two scalar passes.
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::types::VectorRef; 
+use crate::level1::compensated::Dot2; 
+
+
+/// Computes the compensated Euclidean norm of a single precision [VectorRef]
+///
+/// Arguments: 
+/// * `x`: [VectorRef] - over [f32]
+///
+/// Returns: 
+/// - [f32] norm of the logical vector elements, within the bound above. 
+#[inline] 
+pub fn snrm2_accurate ( 
+    x: VectorRef<'_, f32>
+) -> f32 { 
+    let n = x.n(); 
+
+    if n == 0 { 
+         return 0.0; 
+    }
+
+    let xs = || x.as_slice()[x.offset()..].iter().step_by(x.stride()).take(n); 
+
+    // `max` drops a NaN, which must reach the result 
+    let amax = xs().fold(0.0f32, |m, &v| { 
+        if m.is_nan() || v.is_nan() { f32::NAN } else { m.max(v.abs()) } 
+    }); 
+    if amax == 0.0 || !amax.is_finite() { 
+        return amax; 
+    }
+
+    // 2^k >= amax, with k capped so 2^-k stays normal 
+    let e     = ((amax.to_bits() >> 23) & 0xff) as i32 - 127; 
+    let k     = (e + 1).clamp(-126, 126); 
+    let scale = f32::from_bits(((k + 127) as u32) << 23); 
+    let inv   = 1.0 / scale; 
+
+    let mut acc = Dot2::default(); 
+    for &v in xs() { 
+        let t = v * inv; 
+        acc.fma(t, t); 
+    }
+
+    scale * acc.value().sqrt()
+}
@@ -22,6 +22,10 @@
 /// `z` is an auxilary parameter related to re-constructing the 
 /// Givens rotation given `r`. 
 ///
+/// Uses the safe scaling of reference BLAS 3.10: `a` and `b` are scaled
+/// by their larger magnitude, clamped to the normal range, so `r` never
+/// overflows or underflows unless it is itself out of range.
+///
 /// Arguments: 
 /// * `a`: `&mut` [f32] - input scalar, overwritten with `r`
 /// * `b`: `&mut` [f32] - input scalar, overwritten with `z`
@@ -37,44 +41,53 @@ pub fn srotg (
     c: &mut f32, 
     s: &mut f32, 
 ) { 
+    let safmin = f32::MIN_POSITIVE; 
+    let safmax = 1.0 / safmin; 
 
     let a_abs = a.abs(); 
     let b_abs = b.abs(); 
 
+    // quick returns
+    if b_abs == 0.0 { 
+        *c = 1.0; 
+        *s = 0.0; 
+        *b = 0.0;
+        return;
+    }
+    if a_abs == 0.0 { 
+        *c = 0.0; 
+        *s = 1.0; 
+        *a = *b; 
+        *b = 1.0;
+        return;
+    }
+
     let p = if a_abs > b_abs { 
         *a
     } else { 
         *b 
     } ;
-    let scale = a_abs + b_abs;  
 
-    // quick return
-    if scale == 0.0 { 
-        *c = 1.0; 
-        *s = 0.0; 
-        *a = 0.0; 
-        *b = 0.0;
-        return;
-    }
+    // the larger magnitude, clamped to the normal range
+    let scale = a_abs.max(b_abs).clamp(safmin, safmax);  
 
     let r = { 
         let p1 = *a / scale;
         let p2 = *b / scale;
 
-        p.signum() * scale * p1.hypot(p2) 
+        (scale * (p1 * p1 + p2 * p2).sqrt()).copysign(p)
     };
 
     *c = *a / r; 
     *s = *b / r; 
        
-    let mut z = 1.0; 
-    if a_abs > b_abs { 
-        z = *s; 
-    } 
-
-    if b_abs >= a_abs && *c != 0.0 { 
-        z = 1.0 / *c; 
-    }
+    let z = if a_abs > b_abs { 
+        *s
+    } else if *c != 0.0 { 
+        1.0 / *c
+    } else { 
+        1.0
+    };
 
     *a = r; 
     *b = z;
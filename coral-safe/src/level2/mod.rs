@@ -6,6 +6,9 @@ pub(crate) mod strusv;
 pub(crate) mod strlsv; 
 
 pub mod sger; 
+pub mod ssyr; 
+pub mod ssymv; 
+pub mod ssyr2; 
 
 pub mod sgemv; 
 pub mod sgemv_n; 
@@ -16,6 +19,9 @@ pub(crate) use sgemv_n::sgemv_n;
 pub(crate) use sgemv_t::sgemv_t; 
 
 pub use sger::sger;
+pub use ssyr::ssyr;
+pub use ssymv::ssymv;
+pub use ssyr2::ssyr2;
 
 pub use strsv::strsv;
 pub(crate) use strlsv::strlsv; 
@@ -11,6 +11,7 @@
 
 use crate::level2::{sgemv_n, sgemv_t}; 
 use crate::types::{MatrixRef, VectorRef, VectorMut, CoralTranspose}; 
+use crate::reproducible;
 
 
 /// Performs a general matrix-vector multiply in single precision. 
@@ -25,6 +26,9 @@ use crate::types::{MatrixRef, VectorRef, VectorMut, CoralTranspose};
 ///
 /// Returns: 
 /// Nothing. `y.data` is overwritten. 
+///
+/// With the `reproducible` feature, `Trans` sums each column in a fixed
+/// order, independent of `lda`, strides and blocking.
 #[inline] 
 pub fn sgemv ( 
     op: CoralTranspose, 
@@ -34,6 +38,10 @@ pub fn sgemv (
     x: VectorRef<'_, f32>, 
     y: VectorMut<'_, f32>, 
 ) { 
+    if cfg!(feature = "reproducible") && matches!(op, CoralTranspose::Trans) { 
+        return reproducible::sgemv_t ( alpha, beta, a, x, y ); 
+    }
+
     match op { 
         CoralTranspose::NoTrans => sgemv_n ( alpha, beta, a, x, y ), 
         CoralTranspose::Trans   => sgemv_t ( alpha, beta, a, x, y ), 
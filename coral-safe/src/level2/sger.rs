@@ -71,13 +71,14 @@ fn sger_contiguous (
                         // full logical matrix  
                         let start = col0 * lda; 
                         let (_, right) = a.split_at_mut(start); 
-                        let (cols_block, _) = right.split_at_mut(lda * NR);
+                        let (cols_block, _) = right.split_at_mut((lda * NR).min(right.len()));
 
-                        // 4 cols from A
+                        // 4 cols from A; the last column of a sub-matrix
+                        // can end before a full lda 
                         let (col0_full, rem) = cols_block.split_at_mut(lda); 
                         let (col1_full, rem) = rem.split_at_mut(lda); 
                         let (col2_full, rem) = rem.split_at_mut(lda); 
-                        let (col3_full, _)   = rem.split_at_mut(lda);
+                        let (col3_full, _)   = rem.split_at_mut(lda.min(rem.len()));
 
                         // mr panels 
                         let col0_panel = &mut col0_full[row .. row + mr]; 
@@ -151,7 +152,7 @@ fn sger_contiguous (
 
                         let start = colj * lda;
                         let (_left, right) = a.split_at_mut(start);
-                        let (col_full, _rest) = right.split_at_mut(lda);
+                        let (col_full, _rest) = right.split_at_mut(lda.min(right.len()));
 
                         let col_panel = &mut col_full[row .. row + mr];
                         let (col_chunks, _) = col_panel.as_chunks_mut::<LANES>();
@@ -0,0 +1,93 @@
+//! Level 2 [`?SYMV`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/ssymv.f)
+//! routine in single precision. 
+//!
+//! \\[ 
+//! y \leftarrow \alpha A x + \beta y
+//! \\]
+//!
+//! for a symmetric $A$ of which only the upper or lower triangle is read. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::{saxpy, sdot}; 
+use crate::types::{CoralTriangular, MatrixRef, VectorRef, VectorMut}; 
+
+
+/// Performs a symmetric matrix-vector multiply in single precision. 
+///
+/// Each stored column `j` is used twice: as a dot product for `y_j` and 
+/// as an axpy for the other half of `y`. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced 
+/// * `alpha`: [f32] - scalar for `alpha * A x` 
+/// * `beta` : [f32] - scalar for `beta * y`; `y` is not read when `beta == 0`
+/// * `a` : [MatrixRef] - over [f32], square 
+/// * `x` : [VectorRef] - over [f32] 
+/// * `y` : [VectorMut] - over [f32] 
+///
+/// Returns: 
+/// Nothing. `y.data` is overwritten. 
+#[inline] 
+pub fn ssymv ( 
+    uplo: CoralTriangular, 
+    alpha: f32, 
+    beta: f32, 
+    a: MatrixRef<'_, f32>, 
+    x: VectorRef<'_, f32>, 
+    mut y: VectorMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    debug_assert_eq!(x.n(), n, "logical length of x must equal n"); 
+    debug_assert_eq!(y.n(), n, "logical length of y must equal n"); 
+
+    if n == 0 { 
+        return; 
+    }
+
+    let lda = a.lda(); 
+    let ad  = &a.as_slice()[a.offset()..]; 
+
+    let xs: Vec<f32> = x.as_slice()[x.offset()..]
+        .iter()
+        .step_by(x.stride())
+        .take(n)
+        .copied()
+        .collect(); 
+
+    // A x, then combined with y 
+    let mut ax = vec![0.0; n]; 
+    if alpha != 0.0 { 
+        for j in 0..n { 
+            let col = &ad[j * lda .. j * lda + n]; 
+
+            // strictly off the diagonal 
+            let (lo, hi) = match uplo { 
+                CoralTriangular::Upper => (0, j), 
+                CoralTriangular::Lower => (j + 1, n), 
+            }; 
+
+            let cview = VectorRef::new(&col[lo..hi], hi - lo, 1, 0).expect("A column view"); 
+            let xview = VectorRef::new(&xs[lo..hi], hi - lo, 1, 0).expect("x view"); 
+
+            ax[j] += col[j] * xs[j] + if hi > lo { sdot(cview, xview) } else { 0.0 }; 
+
+            if hi > lo && xs[j] != 0.0 { 
+                let yview = VectorMut::new(&mut ax[lo..hi], hi - lo, 1, 0).expect("y view"); 
+                saxpy(xs[j], cview, yview); 
+            }
+        }
+    }
+
+    let incy = y.stride(); 
+    let yoff = y.offset(); 
+    let ys   = &mut y.as_slice_mut()[yoff..]; 
+
+    for (yv, &v) in ys.iter_mut().step_by(incy).take(n).zip(&ax) { 
+        *yv = if beta == 0.0 { alpha * v } else { alpha * v + beta * *yv }; 
+    }
+}
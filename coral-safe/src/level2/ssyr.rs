@@ -0,0 +1,98 @@
+//! Level 2 [`?SYR`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/ssyr.f)
+//! routine in single precision. 
+//!
+//! \\[ 
+//! A \leftarrow A + \alpha x x^T
+//! \\]
+//!
+//! for a symmetric $A$ of which only the upper or lower triangle is updated. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::saxpy; 
+use crate::types::{CoralTriangular, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `A[lo..hi, j] += alpha x_j x[lo..hi]` for one triangle, `x` contiguous 
+#[inline] 
+pub(crate) fn ssyr_slices ( 
+    uplo: CoralTriangular, 
+    n: usize, 
+    alpha: f32, 
+    x: &[f32], 
+    a: &mut [f32], 
+    lda: usize, 
+) { 
+    if n == 0 || alpha == 0.0 { 
+        return; 
+    }
+
+    for (j, &xj) in x.iter().enumerate().take(n) { 
+        if xj == 0.0 { 
+            continue; 
+        }
+
+        let (lo, hi) = match uplo { 
+            CoralTriangular::Upper => (0, j + 1), 
+            CoralTriangular::Lower => (j, n), 
+        }; 
+
+        let xview = VectorRef::new(&x[lo..hi], hi - lo, 1, 0)
+            .expect("x view"); 
+        let acol  = VectorMut::new(&mut a[lo + j * lda .. hi + j * lda], hi - lo, 1, 0)
+            .expect("A column view"); 
+
+        saxpy(alpha * xj, xview, acol); 
+    }
+}
+
+
+/// Performs a symmetric rank-1 update of one triangle of `a`, 
+/// `A := A + alpha x x^T` 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced and updated
+/// * `alpha`: [f32] - scaling factor
+/// * `x`: [VectorRef] - over [f32] 
+/// * `a`: [MatrixMut] - over [f32], square 
+///
+/// Returns: 
+/// Nothing. The `uplo` triangle of `a.data` is overwritten. 
+#[inline]
+pub fn ssyr ( 
+    uplo: CoralTriangular, 
+    alpha: f32, 
+    x: VectorRef<'_, f32>, 
+    mut a: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    debug_assert_eq!(x.n(), n, "logical length of x must equal n"); 
+
+    if n == 0 || alpha == 0.0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let adata = &mut a.as_slice_mut()[aoff..]; 
+
+    // fast path 
+    if let Some(xs) = x.contiguous_slice() { 
+        ssyr_slices(uplo, n, alpha, xs, adata, lda); 
+        return; 
+    }
+
+    // slow path 
+    let xs: Vec<f32> = x.as_slice()[x.offset()..]
+        .iter()
+        .step_by(x.stride())
+        .take(n)
+        .copied()
+        .collect(); 
+
+    ssyr_slices(uplo, n, alpha, &xs, adata, lda); 
+}
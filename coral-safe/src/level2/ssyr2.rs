@@ -0,0 +1,116 @@
+//! Level 2 [`?SYR2`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/ssyr2.f)
+//! routine in single precision. 
+//!
+//! \\[ 
+//! A \leftarrow A + \alpha x y^T + \alpha y x^T
+//! \\]
+//!
+//! for a symmetric $A$ of which only the upper or lower triangle is updated. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level1::saxpy; 
+use crate::types::{CoralTriangular, MatrixMut, VectorRef, VectorMut}; 
+
+
+/// `A[lo..hi, j] += alpha (y_j x[lo..hi] + x_j y[lo..hi])` for one triangle, 
+/// `x` and `y` contiguous 
+#[inline] 
+pub(crate) fn ssyr2_slices ( 
+    uplo: CoralTriangular, 
+    n: usize, 
+    alpha: f32, 
+    x: &[f32], 
+    y: &[f32], 
+    a: &mut [f32], 
+    lda: usize, 
+) { 
+    if n == 0 || alpha == 0.0 { 
+        return; 
+    }
+
+    for j in 0..n { 
+        let (xj, yj) = (x[j], y[j]); 
+        if xj == 0.0 && yj == 0.0 { 
+            continue; 
+        }
+
+        let (lo, hi) = match uplo { 
+            CoralTriangular::Upper => (0, j + 1), 
+            CoralTriangular::Lower => (j, n), 
+        }; 
+
+        let col = &mut a[lo + j * lda .. hi + j * lda]; 
+
+        if yj != 0.0 { 
+            let xview = VectorRef::new(&x[lo..hi], hi - lo, 1, 0).expect("x view"); 
+            let acol  = VectorMut::new(col, hi - lo, 1, 0).expect("A column view"); 
+            saxpy(alpha * yj, xview, acol); 
+        }
+        if xj != 0.0 { 
+            let yview = VectorRef::new(&y[lo..hi], hi - lo, 1, 0).expect("y view"); 
+            let acol  = VectorMut::new(col, hi - lo, 1, 0).expect("A column view"); 
+            saxpy(alpha * xj, yview, acol); 
+        }
+    }
+}
+
+
+/// Performs a symmetric rank-2 update of one triangle of `a`, 
+/// `A := A + alpha x y^T + alpha y x^T` 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `a` is referenced and updated
+/// * `alpha`: [f32] - scaling factor
+/// * `x`: [VectorRef] - over [f32] 
+/// * `y`: [VectorRef] - over [f32] 
+/// * `a`: [MatrixMut] - over [f32], square 
+///
+/// Returns: 
+/// Nothing. The `uplo` triangle of `a.data` is overwritten. 
+#[inline]
+pub fn ssyr2 ( 
+    uplo: CoralTriangular, 
+    alpha: f32, 
+    x: VectorRef<'_, f32>, 
+    y: VectorRef<'_, f32>, 
+    mut a: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = a.n_rows(); 
+    debug_assert_eq!(x.n(), n, "logical length of x must equal n"); 
+    debug_assert_eq!(y.n(), n, "logical length of y must equal n"); 
+
+    if n == 0 || alpha == 0.0 { 
+        return; 
+    }
+
+    let lda  = a.lda(); 
+    let aoff = a.offset(); 
+    let adata = &mut a.as_slice_mut()[aoff..]; 
+
+    // fast path 
+    if let (Some(xs), Some(ys)) = (x.contiguous_slice(), y.contiguous_slice()) { 
+        ssyr2_slices(uplo, n, alpha, xs, ys, adata, lda); 
+        return; 
+    }
+
+    // slow path 
+    let xs: Vec<f32> = x.as_slice()[x.offset()..]
+        .iter()
+        .step_by(x.stride())
+        .take(n)
+        .copied()
+        .collect(); 
+    let ys: Vec<f32> = y.as_slice()[y.offset()..]
+        .iter()
+        .step_by(y.stride())
+        .take(n)
+        .copied()
+        .collect(); 
+
+    ssyr2_slices(uplo, n, alpha, &xs, &ys, adata, lda); 
+}
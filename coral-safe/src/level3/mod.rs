@@ -0,0 +1,7 @@
+pub mod sgemm; 
+pub mod strsm; 
+pub mod ssyrk; 
+
+pub use sgemm::sgemm; 
+pub use strsm::strsm; 
+pub use ssyrk::ssyrk; 
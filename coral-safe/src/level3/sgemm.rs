@@ -0,0 +1,217 @@
+//! Level 3 [`?GEMM`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/sgemm.f)
+//! routine in single precision. 
+//!
+//! \\[ 
+//! C \leftarrow \alpha \operatorname{op}(A) \operatorname{op}(B) + \beta C
+//! \\]
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::fused::saxpyf; 
+use crate::types::{CoralTranspose, MatrixRef, MatrixMut, VectorRef, VectorMut}; 
+
+const MC: usize = 128; 
+const KC: usize = 256; 
+
+
+/// packs `alpha op(A)[ic..ic+mc, pc..pc+kc]` column major into `apack` 
+#[inline] 
+#[allow(clippy::too_many_arguments)]
+fn pack_a ( 
+    op: CoralTranspose, 
+    alpha: f32, 
+    a: &[f32], 
+    lda: usize, 
+    ic: usize, 
+    pc: usize, 
+    mc: usize, 
+    kc: usize, 
+    apack: &mut Vec<f32>, 
+) { 
+    apack.clear(); 
+    apack.resize(mc * kc, 0.0); 
+
+    match op { 
+        CoralTranspose::NoTrans => { 
+            for p in 0..kc { 
+                let col = &a[ic + (pc + p) * lda ..][..mc]; 
+                let dst = &mut apack[p * mc .. (p + 1) * mc]; 
+
+                for (d, &v) in dst.iter_mut().zip(col) { 
+                    *d = alpha * v; 
+                }
+            }
+        }
+        CoralTranspose::Trans => { 
+            // row i of op(A) is column ic + i of A
+            for i in 0..mc { 
+                let col = &a[pc + (ic + i) * lda ..][..kc]; 
+
+                for (p, &v) in col.iter().enumerate() { 
+                    apack[i + p * mc] = alpha * v; 
+                }
+            }
+        }
+    }
+}
+
+/// `C := alpha op(A) op(B) + beta C` over column major slices that start at
+/// the first logical element of each matrix. `C` is `m x n` and `k` is the
+/// inner dimension. 
+#[inline] 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sgemm_slices ( 
+    op_a: CoralTranspose, 
+    op_b: CoralTranspose, 
+    m: usize, 
+    n: usize, 
+    k: usize, 
+    alpha: f32, 
+    a: &[f32], 
+    lda: usize, 
+    b: &[f32], 
+    ldb: usize, 
+    beta: f32, 
+    c: &mut [f32], 
+    ldc: usize, 
+) { 
+    if m == 0 || n == 0 { 
+        return; 
+    }
+
+    // beta == 0 never reads C 
+    if beta != 1.0 { 
+        for j in 0..n { 
+            let col = &mut c[j * ldc .. j * ldc + m]; 
+
+            if beta == 0.0 { 
+                col.fill(0.0); 
+            } else { 
+                for v in col { 
+                    *v *= beta; 
+                }
+            }
+        }
+    }
+
+    if alpha == 0.0 || k == 0 { 
+        return; 
+    }
+
+    let mut apack = Vec::with_capacity(MC * KC); 
+    let mut bcol  = vec![0.0; KC]; 
+
+    let mut pc = 0; 
+    while pc < k { 
+        let kc = (k - pc).min(KC); 
+
+        let mut ic = 0; 
+        while ic < m { 
+            let mc = (m - ic).min(MC); 
+
+            // mc x kc block of alpha op(A), reused across every column of C
+            pack_a(op_a, alpha, a, lda, ic, pc, mc, kc, &mut apack); 
+            let aview = MatrixRef::new(&apack, mc, kc, mc, 0)
+                .expect("a pack view"); 
+
+            for j in 0..n { 
+                // op(B)[pc..pc+kc, j]
+                let bs: &[f32] = match op_b { 
+                    CoralTranspose::NoTrans => &b[pc + j * ldb ..][..kc], 
+                    CoralTranspose::Trans   => { 
+                        for (p, v) in bcol[..kc].iter_mut().enumerate() { 
+                            *v = b[j + (pc + p) * ldb]; 
+                        }
+                        &bcol[..kc]
+                    }
+                }; 
+
+                let xview = VectorRef::new(bs, kc, 1, 0)
+                    .expect("b col view"); 
+                let yview = VectorMut::new(&mut c[ic + j * ldc ..][..mc], mc, 1, 0)
+                    .expect("c col view"); 
+
+                saxpyf(aview, xview, yview); 
+            }
+
+            ic += mc; 
+        }
+
+        pc += kc; 
+    }
+}
+
+
+/// Performs a general matrix-matrix multiply in single precision. 
+///
+/// Arguments: 
+/// * `op_a`: [CoralTranspose] - `A` transpose or not
+/// * `op_b`: [CoralTranspose] - `B` transpose or not
+/// * `alpha`: [f32] - scalar for `alpha * op(A) op(B)` 
+/// * `beta` : [f32] - scalar for `beta * C`; `C` is not read when `beta == 0`
+/// * `a` : [MatrixRef] - over [f32] 
+/// * `b` : [MatrixRef] - over [f32] 
+/// * `c` : [MatrixMut] - over [f32] 
+///
+/// Returns: 
+/// Nothing. `c.data` is overwritten. 
+#[inline] 
+pub fn sgemm ( 
+    op_a: CoralTranspose, 
+    op_b: CoralTranspose, 
+    alpha: f32, 
+    beta: f32, 
+    a: MatrixRef<'_, f32>, 
+    b: MatrixRef<'_, f32>, 
+    mut c: MatrixMut<'_, f32>, 
+) { 
+    let m = c.n_rows(); 
+    let n = c.n_cols(); 
+
+    let (am, k) = match op_a { 
+        CoralTranspose::NoTrans => (a.n_rows(), a.n_cols()), 
+        CoralTranspose::Trans   => (a.n_cols(), a.n_rows()), 
+    }; 
+    let (bk, bn) = match op_b { 
+        CoralTranspose::NoTrans => (b.n_rows(), b.n_cols()), 
+        CoralTranspose::Trans   => (b.n_cols(), b.n_rows()), 
+    }; 
+
+    debug_assert_eq!(am, m, "rows of op(A) must equal rows of C"); 
+    debug_assert_eq!(bn, n, "cols of op(B) must equal cols of C"); 
+    debug_assert_eq!(bk, k, "cols of op(A) must equal rows of op(B)"); 
+
+    if m == 0 || n == 0 { 
+        return; 
+    }
+
+    let ldc  = c.lda(); 
+    let coff = c.offset(); 
+    let cs   = &mut c.as_slice_mut()[coff..]; 
+
+    if k == 0 { 
+        sgemm_slices(op_a, op_b, m, n, 0, alpha, &[], 1, &[], 1, beta, cs, ldc); 
+        return; 
+    }
+
+    let aoff = a.offset(); 
+    let boff = b.offset(); 
+
+    sgemm_slices ( 
+        op_a, 
+        op_b, 
+        m, 
+        n, 
+        k, 
+        alpha, 
+        &a.as_slice()[aoff..], 
+        a.lda(), 
+        &b.as_slice()[boff..], 
+        b.lda(), 
+        beta, 
+        cs, 
+        ldc, 
+    ); 
+}
@@ -0,0 +1,173 @@
+//! Level 3 [`?SYRK`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/ssyrk.f)
+//! routine in single precision. 
+//!
+//! \\[ 
+//! C \leftarrow \alpha \operatorname{op}(A) \operatorname{op}(A)^T + \beta C, 
+//! \quad \operatorname{op}(A) \in \\{A, A^T \\}, 
+//! \\]
+//!
+//! updating only the upper or lower triangle of the symmetric `n x n` $C$. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level3::sgemm::sgemm_slices; 
+use crate::types::{CoralTranspose, CoralTriangular, MatrixRef, MatrixMut}; 
+
+const NB: usize = 64; 
+
+
+/// `op(A)[i, p]`
+#[inline(always)] 
+fn op_at(op: CoralTranspose, a: &[f32], lda: usize, i: usize, p: usize) -> f32 { 
+    match op { 
+        CoralTranspose::NoTrans => a[i + p * lda], 
+        CoralTranspose::Trans   => a[p + i * lda], 
+    }
+}
+
+/// start of row `r` of `op(A)`
+#[inline(always)] 
+fn op_row(op: CoralTranspose, lda: usize, r: usize) -> usize { 
+    match op { 
+        CoralTranspose::NoTrans => r, 
+        CoralTranspose::Trans   => r * lda, 
+    }
+}
+
+/// `C := alpha op(A) op(A)^T + beta C` on one triangle of a column major 
+/// slice. `op(A)` is `n x k`. Diagonal blocks are formed directly, the
+/// blocks off the diagonal with gemm. 
+#[inline] 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn ssyrk_slices ( 
+    uplo: CoralTriangular, 
+    op: CoralTranspose, 
+    n: usize, 
+    k: usize, 
+    alpha: f32, 
+    a: &[f32], 
+    lda: usize, 
+    beta: f32, 
+    c: &mut [f32], 
+    ldc: usize, 
+) { 
+    if n == 0 { 
+        return; 
+    }
+
+    // op(A)^T as the second gemm operand
+    let op_t = match op { 
+        CoralTranspose::NoTrans => CoralTranspose::Trans, 
+        CoralTranspose::Trans   => CoralTranspose::NoTrans, 
+    }; 
+
+    let mut j0 = 0; 
+    while j0 < n { 
+        let j1 = (j0 + NB).min(n); 
+        let jb = j1 - j0; 
+
+        // diagonal block, beta == 0 never reads C 
+        for j in j0..j1 { 
+            let rows = match uplo { 
+                CoralTriangular::Upper => j0..j + 1, 
+                CoralTriangular::Lower => j..j1, 
+            }; 
+
+            for i in rows { 
+                let mut acc = 0.0; 
+                if alpha != 0.0 { 
+                    for p in 0..k { 
+                        acc += op_at(op, a, lda, i, p) * op_at(op, a, lda, j, p); 
+                    }
+                }
+
+                let cij = &mut c[i + j * ldc]; 
+                *cij = if beta == 0.0 { alpha * acc } else { alpha * acc + beta * *cij }; 
+            }
+        }
+
+        // rows above (upper) or below (lower) the diagonal block 
+        let (r0, r1) = match uplo { 
+            CoralTriangular::Upper => (0, j0), 
+            CoralTriangular::Lower => (j1, n), 
+        }; 
+
+        if r0 < r1 { 
+            let (a_rows, a_cols) = if k == 0 { 
+                (&[][..], &[][..]) 
+            } else { 
+                (&a[op_row(op, lda, r0)..], &a[op_row(op, lda, j0)..]) 
+            }; 
+
+            sgemm_slices ( 
+                op, 
+                op_t, 
+                r1 - r0, 
+                jb, 
+                k, 
+                alpha, 
+                a_rows, 
+                lda, 
+                a_cols, 
+                lda, 
+                beta, 
+                &mut c[r0 + j0 * ldc..], 
+                ldc, 
+            ); 
+        }
+
+        j0 = j1; 
+    }
+}
+
+
+/// Performs a symmetric rank-k update of one triangle of `c`. 
+///
+/// Arguments: 
+/// * `uplo`: [CoralTriangular] - which triangle of `c` is referenced and updated
+/// * `trans`: [CoralTranspose] - `A` is `n x k` for [CoralTranspose::NoTrans],
+///   `k x n` for [CoralTranspose::Trans]
+/// * `alpha`: [f32] - scalar for `alpha * op(A) op(A)^T` 
+/// * `beta` : [f32] - scalar for `beta * C`; `C` is not read when `beta == 0`
+/// * `a`: [MatrixRef] - over [f32] 
+/// * `c`: [MatrixMut] - over [f32], square 
+///
+/// Returns: 
+/// Nothing. The `uplo` triangle of `c.data` is overwritten. 
+#[inline] 
+pub fn ssyrk ( 
+    uplo: CoralTriangular, 
+    trans: CoralTranspose, 
+    alpha: f32, 
+    beta: f32, 
+    a: MatrixRef<'_, f32>, 
+    mut c: MatrixMut<'_, f32>, 
+) { 
+    assert!(c.compare_m_n(), "n_cols must equal n_rows"); 
+
+    let n = c.n_rows(); 
+    let (an, k) = match trans { 
+        CoralTranspose::NoTrans => (a.n_rows(), a.n_cols()), 
+        CoralTranspose::Trans   => (a.n_cols(), a.n_rows()), 
+    }; 
+
+    debug_assert_eq!(an, n, "rows of op(A) must equal the order of C"); 
+
+    if n == 0 { 
+        return; 
+    }
+
+    let ldc  = c.lda(); 
+    let coff = c.offset(); 
+    let cs   = &mut c.as_slice_mut()[coff..]; 
+
+    if k == 0 { 
+        ssyrk_slices(uplo, trans, n, 0, alpha, &[], 1, beta, cs, ldc); 
+        return; 
+    }
+
+    let aoff = a.offset(); 
+    ssyrk_slices(uplo, trans, n, k, alpha, &a.as_slice()[aoff..], a.lda(), beta, cs, ldc); 
+}
@@ -0,0 +1,315 @@
+//! Level 3 [`?TRSM`](https://github.com/Reference-LAPACK/lapack/blob/master/BLAS/SRC/strsm.f)
+//! routine in single precision. 
+//!
+//! Solves the triangular systems 
+//!
+//! \\[ 
+//! \operatorname{op}(A) X = \alpha B \quad \text{or} \quad 
+//! X \operatorname{op}(A) = \alpha B, \quad \operatorname{op}(A) \in \\{A, A^T \\}, 
+//! \\]
+//!
+//! overwriting $B$ with $X$. 
+//!
+//! # Author 
+//! Deval Deliwala
+
+
+use crate::level3::sgemm::sgemm_slices; 
+use crate::types::{
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+    MatrixRef, 
+    MatrixMut
+}; 
+
+const NB: usize = 64; 
+
+
+/// `op(A)[i, j]`
+#[inline(always)] 
+fn op_at(op: CoralTranspose, a: &[f32], lda: usize, i: usize, j: usize) -> f32 { 
+    match op { 
+        CoralTranspose::NoTrans => a[i + j * lda], 
+        CoralTranspose::Trans   => a[j + i * lda], 
+    }
+}
+
+/// start of the `op(A)[r0.., c0..]` block and the op to pass to gemm 
+#[inline(always)] 
+fn op_block(op: CoralTranspose, lda: usize, r0: usize, c0: usize) -> usize { 
+    match op { 
+        CoralTranspose::NoTrans => r0 + c0 * lda, 
+        CoralTranspose::Trans   => c0 + r0 * lda, 
+    }
+}
+
+/// `op(A) X = B` for `m x n` `B`. Diagonal blocks are solved in place one
+/// column at a time, the rows below (or above) are updated with gemm. 
+#[allow(clippy::too_many_arguments)]
+fn strsm_left ( 
+    lower: bool, 
+    op: CoralTranspose, 
+    unit: bool, 
+    m: usize, 
+    n: usize, 
+    a: &[f32], 
+    lda: usize, 
+    b: &mut [f32], 
+    ldb: usize, 
+) { 
+    let n_blocks = m.div_ceil(NB); 
+    let mut xblk = Vec::new(); 
+
+    for blk in 0..n_blocks { 
+        // forward for lower op(A), backward for upper 
+        let blk = if lower { blk } else { n_blocks - 1 - blk }; 
+        let k0  = blk * NB; 
+        let k1  = (k0 + NB).min(m); 
+        let kb  = k1 - k0; 
+
+        for j in 0..n { 
+            let col = &mut b[j * ldb .. j * ldb + m]; 
+
+            if lower { 
+                for i in k0..k1 { 
+                    let mut v = col[i]; 
+                    for (p, &xp) in col.iter().enumerate().take(i).skip(k0) { 
+                        v -= op_at(op, a, lda, i, p) * xp; 
+                    }
+                    if !unit { 
+                        v /= op_at(op, a, lda, i, i); 
+                    }
+                    col[i] = v; 
+                }
+            } else { 
+                for i in (k0..k1).rev() { 
+                    let mut v = col[i]; 
+                    for (p, &xp) in col.iter().enumerate().take(k1).skip(i + 1) { 
+                        v -= op_at(op, a, lda, i, p) * xp; 
+                    }
+                    if !unit { 
+                        v /= op_at(op, a, lda, i, i); 
+                    }
+                    col[i] = v; 
+                }
+            }
+        }
+
+        // rows still to solve
+        let (r0, r1) = if lower { (k1, m) } else { (0, k0) }; 
+        if r0 == r1 { 
+            continue; 
+        }
+
+        // B[r0..r1, :] -= op(A)[r0..r1, k0..k1] X[k0..k1, :]
+        xblk.clear(); 
+        for j in 0..n { 
+            xblk.extend_from_slice(&b[k0 + j * ldb .. k1 + j * ldb]); 
+        }
+
+        sgemm_slices ( 
+            op, 
+            CoralTranspose::NoTrans, 
+            r1 - r0, 
+            n, 
+            kb, 
+            -1.0, 
+            &a[op_block(op, lda, r0, k0)..], 
+            lda, 
+            &xblk, 
+            kb, 
+            1.0, 
+            &mut b[r0..], 
+            ldb, 
+        ); 
+    }
+}
+
+/// `X op(A) = B` for `m x n` `B`. Diagonal blocks are solved in place one
+/// column at a time, the columns right (or left) are updated with gemm. 
+#[allow(clippy::too_many_arguments)]
+fn strsm_right ( 
+    lower: bool, 
+    op: CoralTranspose, 
+    unit: bool, 
+    m: usize, 
+    n: usize, 
+    a: &[f32], 
+    lda: usize, 
+    b: &mut [f32], 
+    ldb: usize, 
+) { 
+    let n_blocks = n.div_ceil(NB); 
+    let mut xblk = Vec::new(); 
+
+    for blk in 0..n_blocks { 
+        // column k of X needs the columns p with op(A)[p, k] != 0: 
+        // backward for lower op(A), forward for upper
+        let blk = if lower { n_blocks - 1 - blk } else { blk }; 
+        let k0  = blk * NB; 
+        let k1  = (k0 + NB).min(n); 
+        let kb  = k1 - k0; 
+
+        for t in 0..kb { 
+            let j = if lower { k1 - 1 - t } else { k0 + t }; 
+            let solved = if lower { j + 1..k1 } else { k0..j }; 
+
+            for p in solved { 
+                let t = op_at(op, a, lda, p, j); 
+                if t != 0.0 { 
+                    for i in 0..m { 
+                        b[i + j * ldb] -= t * b[i + p * ldb]; 
+                    }
+                }
+            }
+
+            if !unit { 
+                let d = op_at(op, a, lda, j, j); 
+                for v in &mut b[j * ldb .. j * ldb + m] { 
+                    *v /= d; 
+                }
+            }
+        }
+
+        // columns still to solve
+        let (c0, c1) = if lower { (0, k0) } else { (k1, n) }; 
+        if c0 == c1 { 
+            continue; 
+        }
+
+        // B[:, c0..c1] -= X[:, k0..k1] op(A)[k0..k1, c0..c1]
+        xblk.clear(); 
+        for j in k0..k1 { 
+            xblk.extend_from_slice(&b[j * ldb .. j * ldb + m]); 
+        }
+
+        sgemm_slices ( 
+            CoralTranspose::NoTrans, 
+            op, 
+            m, 
+            c1 - c0, 
+            kb, 
+            -1.0, 
+            &xblk, 
+            m, 
+            &a[op_block(op, lda, k0, c0)..], 
+            lda, 
+            1.0, 
+            &mut b[c0 * ldb..], 
+            ldb, 
+        ); 
+    }
+}
+
+/// `B := alpha op(A)^{-1} B` or `B := alpha B op(A)^{-1}` over column major
+/// slices that start at the first logical element. `B` is `m x n`. 
+#[inline] 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn strsm_slices ( 
+    side: CoralSide, 
+    uplo: CoralTriangular, 
+    op: CoralTranspose, 
+    diag: CoralDiagonal, 
+    m: usize, 
+    n: usize, 
+    alpha: f32, 
+    a: &[f32], 
+    lda: usize, 
+    b: &mut [f32], 
+    ldb: usize, 
+) { 
+    if m == 0 || n == 0 { 
+        return; 
+    }
+
+    if alpha != 1.0 { 
+        for j in 0..n { 
+            let col = &mut b[j * ldb .. j * ldb + m]; 
+
+            if alpha == 0.0 { 
+                col.fill(0.0); 
+            } else { 
+                for v in col { 
+                    *v *= alpha; 
+                }
+            }
+        }
+
+        if alpha == 0.0 { 
+            return; 
+        }
+    }
+
+    // whether op(A) is lower triangular 
+    let lower = matches!( 
+        (uplo, op), 
+        (CoralTriangular::Lower, CoralTranspose::NoTrans) | 
+        (CoralTriangular::Upper, CoralTranspose::Trans) 
+    ); 
+    let unit = diag.is_unit(); 
+
+    match side { 
+        CoralSide::Left  => strsm_left (lower, op, unit, m, n, a, lda, b, ldb), 
+        CoralSide::Right => strsm_right(lower, op, unit, m, n, a, lda, b, ldb), 
+    }
+}
+
+
+/// Performs a triangular solve with multiple right hand sides, where `a`
+/// is either upper or lower triangular. 
+///
+/// Arguments: 
+/// * `side`: [CoralSide] - whether `op(a)` multiplies `X` from the left or the right
+/// * `uplo`: [CoralTriangular] - whether `a` upper or lower triangular 
+/// * `trans`: [CoralTranspose] - whether `a` is transposed or not 
+/// * `diag`: [CoralDiagonal] - whether `a` has a unit-diagonal or not 
+/// * `alpha`: [f32] - scalar for `alpha * B`; `B` is not read when `alpha == 0`
+/// * `a`: [MatrixRef] - over [f32], square 
+/// * `b`: [MatrixMut] - over [f32], input as `B`, output as solved `X` 
+///
+/// Returns: 
+/// Nothing. `b.data` is overwritten. 
+#[inline]
+pub fn strsm ( 
+    side:  CoralSide, 
+    uplo:  CoralTriangular,
+    trans: CoralTranspose, 
+    diag:  CoralDiagonal, 
+    alpha: f32, 
+    a: MatrixRef<'_, f32>, 
+    mut b: MatrixMut<'_, f32>, 
+) { 
+    assert!(a.compare_m_n(), "n_cols must equal n_rows");
+
+    let m = b.n_rows(); 
+    let n = b.n_cols(); 
+
+    match side { 
+        CoralSide::Left  => debug_assert_eq!(a.n_rows(), m, "order of A must equal rows of B"), 
+        CoralSide::Right => debug_assert_eq!(a.n_rows(), n, "order of A must equal cols of B"), 
+    }
+
+    if m == 0 || n == 0 { 
+        return; 
+    }
+
+    let ldb  = b.lda(); 
+    let boff = b.offset(); 
+    let aoff = a.offset(); 
+
+    strsm_slices ( 
+        side, 
+        uplo, 
+        trans, 
+        diag, 
+        m, 
+        n, 
+        alpha, 
+        &a.as_slice()[aoff..], 
+        a.lda(), 
+        &mut b.as_slice_mut()[boff..], 
+        ldb, 
+    ); 
+}
@@ -2,9 +2,13 @@
 pub mod level1;
 pub mod fused; 
 pub mod level2; 
+pub mod level3; 
+pub mod lapack; 
 
 pub mod errors;
 pub mod types;
 
+pub(crate) mod reproducible;
+
 
 
@@ -0,0 +1,107 @@
+//! Kernels behind the `reproducible` feature.
+//!
+//! The summation order depends only on the logical index, never on the
+//! stride, the offset, `LANES` or the `MC`/`NC` blocking of [`crate::level2`]:
+//!
+//! - term `i` of a reduction goes to partial sum `i % 8` in ascending `i`,
+//!   one FMA per product, and the partials combine as
+//!   `((s0 + s1) + (s2 + s3)) + ((s4 + s5) + (s6 + s7))`.
+//! - `snrm2` first scales by the power of two at or above $\max |x_i|$, which
+//!   is exact.
+//! - `sgemv_t` stores $\alpha d_j + \beta y_j$ as one FMA on the rounded
+//!   $\beta y_j$, or $\alpha d_j$ alone when $\beta = 0$.
+//!
+//! This is the order `coral-aarch64` uses under the same feature, so the two
+//! crates agree bit for bit.
+//!
+//! # Author
+//! Deval Deliwala
+
+
+use crate::types::{MatrixRef, VectorRef, VectorMut};
+
+const LANES: usize = 8;
+
+
+#[inline(always)]
+fn reduce(terms: impl Iterator<Item = (f32, f32)>) -> f32 {
+    let mut s = [0.0f32; LANES];
+
+    for (i, (a, b)) in terms.enumerate() {
+        s[i % LANES] = a.mul_add(b, s[i % LANES]);
+    }
+
+    ((s[0] + s[1]) + (s[2] + s[3])) + ((s[4] + s[5]) + (s[6] + s[7]))
+}
+
+#[inline(always)]
+fn logical<'b>(x: &'b VectorRef<'_, f32>) -> impl Iterator<Item = f32> + 'b {
+    x.as_slice()[x.offset()..].iter().step_by(x.stride()).take(x.n()).copied()
+}
+
+#[inline]
+pub(crate) fn sdot(x: VectorRef<'_, f32>, y: VectorRef<'_, f32>) -> f32 {
+    reduce(logical(&x).zip(logical(&y)))
+}
+
+#[inline]
+pub(crate) fn sasum(x: VectorRef<'_, f32>) -> f32 {
+    // |x_i| * 1 + s rounds once, as an add would
+    reduce(logical(&x).map(|v| (v.abs(), 1.0)))
+}
+
+#[inline]
+pub(crate) fn snrm2(x: VectorRef<'_, f32>) -> f32 {
+    // `max` drops a NaN, which must reach the result
+    let amax = logical(&x).fold(0.0f32, |m, v| {
+        if m.is_nan() || v.is_nan() { f32::NAN } else { m.max(v.abs()) }
+    });
+
+    if amax == 0.0 || !amax.is_finite() {
+        return amax;
+    }
+
+    // 2^k >= amax, with k capped so 2^-k stays normal
+    let e     = ((amax.to_bits() >> 23) & 0xff) as i32 - 127;
+    let k     = (e + 1).clamp(-126, 126);
+    let scale = f32::from_bits(((k + 127) as u32) << 23);
+    let inv   = 1.0 / scale;
+
+    scale * reduce(logical(&x).map(|v| (v * inv, v * inv))).sqrt()
+}
+
+#[inline]
+pub(crate) fn sgemv_t(
+    alpha: f32,
+    beta: f32,
+    a: MatrixRef<'_, f32>,
+    x: VectorRef<'_, f32>,
+    mut y: VectorMut<'_, f32>,
+) {
+    let n_rows = a.n_rows();
+    let n_cols = a.n_cols();
+
+    if n_cols == 0 || n_rows == 0 {
+        return;
+    }
+
+    if alpha == 0.0 && beta == 1.0 {
+        return;
+    }
+
+    let lda  = a.lda();
+    let aoff = a.offset();
+    let incy = y.stride();
+    let yoff = y.offset();
+
+    let ys = y.as_slice_mut();
+    for j in 0..n_cols {
+        let col = &a.as_slice()[aoff + j * lda .. aoff + j * lda + n_rows];
+        let d   = if alpha == 0.0 { 0.0 } else { reduce(col.iter().copied().zip(logical(&x))) };
+
+        let yj = &mut ys[yoff + j * incy];
+        let by = if beta == 0.0 { 0.0 } else { beta * *yj };
+
+        *yj = alpha.mul_add(d, by);
+    }
+}
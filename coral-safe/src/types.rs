@@ -16,6 +16,22 @@ pub enum CoralTriangular {
     Lower
 }
 
+/// * [CoralSide::Left] for `op(A) X = B` variants
+/// * [CoralSide::Right] for `X op(A) = B` variants
+#[derive(Debug, Copy, Clone)]
+pub enum CoralSide { 
+    Left, 
+    Right, 
+}
+
+/// * [CoralDirection::Forward] to apply row interchanges first to last
+/// * [CoralDirection::Backward] to apply row interchanges last to first
+#[derive(Debug, Copy, Clone)]
+pub enum CoralDirection { 
+    Forward, 
+    Backward, 
+}
+
 /// * [CoralDiagonal::Unit] for unit diagonal variants
 /// * [CoralDiagonal::NonUnit] for non-unit diagonal variants
 #[derive(Debug, Copy, Clone)]
@@ -34,6 +50,62 @@ impl CoralDiagonal {
     }
 }
 
+/// Row and column interchange of a symmetric indefinite factorization 
+/// * [CoralPivot::OneByOne] - `k` was interchanged with the index held, 
+///   `D[k, k]` is a 1 x 1 block 
+/// * [CoralPivot::TwoByTwo] - stored at both rows of a 2 x 2 block of `D`;
+///   the second (lower) or first (upper) row was interchanged with the index held
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoralPivot { 
+    OneByOne(usize), 
+    TwoByTwo(usize), 
+}
+
+/// * [CoralJob::ValuesOnly] to compute eigenvalues only
+/// * [CoralJob::Vectors] to compute eigenvalues and eigenvectors
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoralJob { 
+    ValuesOnly, 
+    Vectors, 
+}
+
+/// * [CoralSvdJob::NoVectors] to skip a set of singular vectors
+/// * [CoralSvdJob::Thin] for the leading `min(m, n)` singular vectors
+/// * [CoralSvdJob::Full] for the complete square orthogonal factor
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoralSvdJob { 
+    NoVectors, 
+    Thin, 
+    Full, 
+}
+
+/// * [CoralRefinement::Converged] - the [f32] solution was refined to [f64] 
+///   accuracy in the number of iterations held
+/// * [CoralRefinement::FellBack] - refinement failed and the system was 
+///   factored and solved in [f64]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoralRefinement { 
+    Converged(usize), 
+    FellBack, 
+}
+
+/// Counts of positive, negative and zero eigenvalues of a symmetric matrix 
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Inertia { 
+    pub positive : usize, 
+    pub negative : usize, 
+    pub zero     : usize, 
+}
+
+/// * [Accuracy::Fast] for plain working-precision accumulation
+/// * [Accuracy::Compensated] for `Dot2` accumulation, as if in twice the working precision
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Accuracy { 
+    #[default]
+    Fast, 
+    Compensated, 
+}
+
 /// Immutable Vector Type
 #[derive(Debug, Copy, Clone)]
 pub struct VectorRef<'a, T> { 
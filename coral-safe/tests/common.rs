@@ -1,13 +1,17 @@
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::distributions::{Distribution, Standard, Uniform}; 
 use coral_safe::errors::BufferError;
 use coral_safe::types::{CoralTriangular, CoralDiagonal}; 
 
 pub type CoralResult = Result<(), BufferError>;
 
+#[allow(dead_code)]
 pub const RTOL: f32 = 1e-5; 
+#[allow(dead_code)]
 pub const ATOL: f32 = 1e-6; 
 
+#[allow(dead_code)]
 pub fn make_strided_vec (
     len: usize, 
     inc: usize
@@ -115,6 +119,66 @@ pub fn make_triangular_mat(
     buf
 }
 
+/// unit roundoff of f32
+#[allow(dead_code)]
+pub const U: f64 = 1.0 / (1u64 << 24) as f64;
+
+/// gamma_n = n u / (1 - n u)
+#[allow(dead_code)]
+pub fn gamma(n: usize) -> f64 {
+    n as f64 * U / (1.0 - n as f64 * U)
+}
+
+/// odd integer below 2^24 times 2^e, e in 0..=emax, random sign.
+/// exact in both f32 and i128.
+#[allow(dead_code)]
+pub fn exact_int(rng: &mut StdRng, emax: u32) -> i128 {
+    let m = (rng.gen_range(0..1i128 << 23) << 1) | 1;
+    let m = m << rng.gen_range(0..=emax);
+
+    if rng.r#gen::<bool>() { m } else { -m }
+}
+
+/// `n` integer valued pairs whose large products cancel two by two, 
+/// shuffled, plus a few small ones. Returns x, y, the exact dot product 
+/// and the exact sum of |x_i y_i|.
+#[allow(dead_code)]
+pub fn make_cancelling_pair(
+    n: usize,
+    emax: u32,
+    seed: u64,
+) -> (Vec<f32>, Vec<f32>, i128, i128) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut x: Vec<i128> = Vec::with_capacity(n);
+    let mut y: Vec<i128> = Vec::with_capacity(n);
+
+    for _ in 0..(n / 2 - n / 8) {
+        let (a, b) = (exact_int(&mut rng, emax), exact_int(&mut rng, emax));
+        x.extend([a, a]);
+        y.extend([b, -b]);
+    }
+    while x.len() < n {
+        x.push(exact_int(&mut rng, 0));
+        y.push(exact_int(&mut rng, 0));
+    }
+
+    for i in (1..n).rev() {
+        let j = rng.gen_range(0..=i);
+        x.swap(i, j);
+        y.swap(i, j);
+    }
+
+    let dot = x.iter().zip(&y).map(|(a, b)| a * b).sum();
+    let abs = x.iter().zip(&y).map(|(a, b)| (a * b).abs()).sum();
+
+    (
+        x.iter().map(|&v| v as f32).collect(),
+        y.iter().map(|&v| v as f32).collect(),
+        dot,
+        abs,
+    )
+}
+
 pub fn assert_close ( 
     a: &[f32], 
     b: &[f32], 
@@ -0,0 +1,182 @@
+//! The `cblas_sys` signatures the suites call, over the reference routines
+//! of `coral_aarch64::reference`.
+//!
+//! The suites compare coral against a CBLAS oracle. These take the same
+//! arguments as `cblas_sys`, so a test reads the same either way, but run
+//! portable reference code instead of an external BLAS. Column-major only.
+
+#![allow(dead_code, non_camel_case_types)]
+#![allow(clippy::enum_variant_names, clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use core::ffi::c_int;
+
+use coral_aarch64::enums::{CoralDiagonal, CoralTranspose, CoralTriangular};
+use coral_aarch64::reference::{level1 as r1, level2 as r2};
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_LAYOUT {
+    CblasRowMajor = 101,
+    CblasColMajor = 102,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_TRANSPOSE {
+    CblasNoTrans   = 111,
+    CblasTrans     = 112,
+    CblasConjTrans = 113,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_UPLO {
+    CblasUpper = 121,
+    CblasLower = 122,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum CBLAS_DIAG {
+    CblasNonUnit = 131,
+    CblasUnit    = 132,
+}
+
+fn trans(t: CBLAS_TRANSPOSE) -> CoralTranspose {
+    match t {
+        CBLAS_TRANSPOSE::CblasNoTrans   => CoralTranspose::NoTranspose,
+        CBLAS_TRANSPOSE::CblasTrans     => CoralTranspose::Transpose,
+        CBLAS_TRANSPOSE::CblasConjTrans => CoralTranspose::ConjugateTranspose,
+    }
+}
+
+fn uplo(u: CBLAS_UPLO) -> CoralTriangular {
+    match u {
+        CBLAS_UPLO::CblasUpper => CoralTriangular::UpperTriangular,
+        CBLAS_UPLO::CblasLower => CoralTriangular::LowerTriangular,
+    }
+}
+
+fn diag(d: CBLAS_DIAG) -> CoralDiagonal {
+    match d {
+        CBLAS_DIAG::CblasNonUnit => CoralDiagonal::NonUnitDiagonal,
+        CBLAS_DIAG::CblasUnit    => CoralDiagonal::UnitDiagonal,
+    }
+}
+
+fn col_major(layout: CBLAS_LAYOUT) {
+    assert!(matches!(layout, CBLAS_LAYOUT::CblasColMajor), "the oracle is column-major only");
+}
+
+// entries spanned by n entries at stride |inc|
+fn vlen(n: c_int, inc: c_int) -> usize {
+    if n <= 0 { 0 } else { (n as usize - 1) * inc.unsigned_abs() as usize + 1 }
+}
+
+// entries spanned by a column-major rows x cols matrix
+fn mlen(rows: c_int, cols: c_int, ld: c_int) -> usize {
+    if rows <= 0 || cols <= 0 { 0 } else { (cols as usize - 1) * ld as usize + rows as usize }
+}
+
+unsafe fn view<'a>(p: *const f32, len: usize) -> &'a [f32] {
+    if len == 0 { &[] } else { unsafe { core::slice::from_raw_parts(p, len) } }
+}
+
+unsafe fn view_mut<'a>(p: *mut f32, len: usize) -> &'a mut [f32] {
+    if len == 0 { &mut [] } else { unsafe { core::slice::from_raw_parts_mut(p, len) } }
+}
+
+fn size(n: c_int) -> usize {
+    n.max(0) as usize
+}
+
+// level1
+
+pub unsafe fn cblas_sasum(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    r1::sasum(size(n), unsafe { view(x, vlen(n, incx)) }, incx as isize)
+}
+
+pub unsafe fn cblas_snrm2(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    r1::snrm2(size(n), unsafe { view(x, vlen(n, incx)) }, incx as isize)
+}
+
+pub unsafe fn cblas_isamax(n: c_int, x: *const f32, incx: c_int) -> c_int {
+    r1::isamax(size(n), unsafe { view(x, vlen(n, incx)) }, incx as isize) as c_int
+}
+
+pub unsafe fn cblas_sdot(n: c_int, x: *const f32, incx: c_int, y: *const f32, incy: c_int) -> f32 {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx)), view(y, vlen(n, incy))) };
+    r1::sdot(size(n), xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_scopy(n: c_int, x: *const f32, incx: c_int, y: *mut f32, incy: c_int) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx)), view_mut(y, vlen(n, incy))) };
+    r1::scopy(size(n), xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_sswap(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx)), view_mut(y, vlen(n, incy))) };
+    r1::sswap(size(n), xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_saxpy(n: c_int, alpha: f32, x: *const f32, incx: c_int, y: *mut f32, incy: c_int) {
+    let (xs, ys) = unsafe { (view(x, vlen(n, incx)), view_mut(y, vlen(n, incy))) };
+    r1::saxpy(size(n), alpha, xs, incx as isize, ys, incy as isize)
+}
+
+pub unsafe fn cblas_sscal(n: c_int, alpha: f32, x: *mut f32, incx: c_int) {
+    r1::sscal(size(n), alpha, unsafe { view_mut(x, vlen(n, incx)) }, incx as isize)
+}
+
+pub unsafe fn cblas_srot(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int, c: f32, s: f32) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx)), view_mut(y, vlen(n, incy))) };
+    r1::srot(size(n), xs, incx as isize, ys, incy as isize, c, s)
+}
+
+pub unsafe fn cblas_srotm(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int, p: *const f32) {
+    let (xs, ys) = unsafe { (view_mut(x, vlen(n, incx)), view_mut(y, vlen(n, incy))) };
+    r1::srotm(size(n), xs, incx as isize, ys, incy as isize, unsafe { &*(p as *const [f32; 5]) })
+}
+
+pub unsafe fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    unsafe { r1::srotg(&mut *a, &mut *b, &mut *c, &mut *s) }
+}
+
+pub unsafe fn cblas_srotmg(d1: *mut f32, d2: *mut f32, b1: *mut f32, b2: f32, p: *mut f32) {
+    unsafe { r1::srotmg(&mut *d1, &mut *d2, &mut *b1, b2, &mut *(p as *mut [f32; 5])) }
+}
+
+// level2
+
+pub unsafe fn cblas_sgemv(
+    layout: CBLAS_LAYOUT, transa: CBLAS_TRANSPOSE, m: c_int, n: c_int,
+    alpha: f32, a: *const f32, lda: c_int, x: *const f32, incx: c_int,
+    beta: f32, y: *mut f32, incy: c_int,
+) {
+    col_major(layout);
+    let (lx, ly) = match transa {
+        CBLAS_TRANSPOSE::CblasNoTrans => (n, m),
+        _                             => (m, n),
+    };
+    let a_ = unsafe { view(a, mlen(m, n, lda)) };
+    let xs = unsafe { view(x, vlen(lx, incx)) };
+    let ys = unsafe { view_mut(y, vlen(ly, incy)) };
+    r2::sgemv(trans(transa), size(m), size(n), alpha, a_, lda as usize, xs, incx as isize, beta, ys, incy as isize)
+}
+
+pub unsafe fn cblas_sger(
+    layout: CBLAS_LAYOUT, m: c_int, n: c_int, alpha: f32, x: *const f32, incx: c_int,
+    y: *const f32, incy: c_int, a: *mut f32, lda: c_int,
+) {
+    col_major(layout);
+    let xs = unsafe { view(x, vlen(m, incx)) };
+    let ys = unsafe { view(y, vlen(n, incy)) };
+    let a_ = unsafe { view_mut(a, mlen(m, n, lda)) };
+    r2::sger(size(m), size(n), alpha, xs, incx as isize, ys, incy as isize, a_, lda as usize)
+}
+
+pub unsafe fn cblas_strsv(
+    layout: CBLAS_LAYOUT, u: CBLAS_UPLO, transa: CBLAS_TRANSPOSE, d: CBLAS_DIAG,
+    n: c_int, a: *const f32, lda: c_int, x: *mut f32, incx: c_int,
+) {
+    col_major(layout);
+    let a_ = unsafe { view(a, mlen(n, n, lda)) };
+    let xs = unsafe { view_mut(x, vlen(n, incx)) };
+    r2::strsv(uplo(u), trans(transa), diag(d), size(n), a_, lda as usize, xs, incx as isize)
+}
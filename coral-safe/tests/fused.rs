@@ -1,9 +1,13 @@
 #[path = "common.rs"] 
 mod common; 
+#[path = "common/cblas.rs"]
+mod cblas;
 
 #[path = "fused/saxpyf.rs"] 
 mod saxpyf; 
 #[path = "fused/sdotf.rs"] 
 mod sdotf; 
-
-
+#[path = "fused/sdotf_with.rs"] 
+mod sdotf_with; 
+#[path = "fused/saxpyf_with.rs"] 
+mod saxpyf_with; 
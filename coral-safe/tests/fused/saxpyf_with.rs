@@ -0,0 +1,73 @@
+use super::common::{
+    make_cancelling_pair, 
+    make_strided_mat, 
+    make_strided_vec, 
+    gamma, 
+    CoralResult, 
+    U, 
+}; 
+
+use coral_safe::fused::{saxpyf, saxpyf_with}; 
+use coral_safe::types::{Accuracy, VectorMut, VectorRef, MatrixRef}; 
+
+/// row i of A is 2^(i % 3) times the same cancelling vector
+#[test]
+fn compensated_error_bound() -> CoralResult { 
+    let (m, n) = (45, 1500); 
+    let lda = m + 1; 
+
+    for &incx in &[1, 3] { 
+        let (x, row, dot, abs) = make_cancelling_pair(n, 16, 5); 
+
+        let mut abuf = vec![0.0; lda * n]; 
+        for j in 0..n { 
+            for i in 0..m { 
+                abuf[j * lda + i] = (1 << (i % 3)) as f32 * row[j]; 
+            }
+        }
+
+        let mut xbuf = vec![0.0; (n - 1) * incx + 1]; 
+        for j in 0..n { 
+            xbuf[j * incx] = x[j]; 
+        }
+
+        let y0: Vec<i128> = (0..m as i128).map(|i| 3 * i - 40).collect(); 
+        let mut ybuf: Vec<f32> = y0.iter().map(|&v| v as f32).collect(); 
+
+        let xcoral = VectorRef::new(&xbuf, n, incx, 0)?; 
+        let acoral = MatrixRef::new(&abuf, m, n, lda, 0)?;
+        let ycoral = VectorMut::new(&mut ybuf, m, 1, 0)?; 
+
+        saxpyf_with(Accuracy::Compensated, acoral, xcoral, ycoral); 
+
+        for i in 0..m { 
+            let c     = 1i128 << (i % 3); 
+            let exact = y0[i] + c * dot; 
+
+            let err   = (ybuf[i] as i128 - exact).abs() as f64; 
+            let bound = U * (exact as f64).abs() 
+                      + gamma(n + 1).powi(2) * (y0[i].abs() + c * abs) as f64; 
+            assert!(err <= bound, "row {i}: {err} > {bound}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn fast_is_saxpyf() -> CoralResult { 
+    let (m, n) = (300, 37); 
+
+    let xbuf = make_strided_vec(n, 1); 
+    let abuf = make_strided_mat(m, n, m); 
+    let mut p = make_strided_vec(m, 1); 
+    let mut q = p.clone(); 
+
+    let xcoral = VectorRef::new(&xbuf, n, 1, 0)?; 
+    let acoral = MatrixRef::new(&abuf, m, n, m, 0)?;
+
+    saxpyf(acoral, xcoral, VectorMut::new(&mut p, m, 1, 0)?); 
+    saxpyf_with(Accuracy::Fast, acoral, xcoral, VectorMut::new(&mut q, m, 1, 0)?); 
+
+    assert_eq!(p, q); 
+    Ok(())
+}
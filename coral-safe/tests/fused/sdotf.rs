@@ -7,8 +7,7 @@ use super::common::{
     RTOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::{cblas_sgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE}; 
+use crate::cblas::{cblas_sgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE}; 
 use coral_safe::fused::sdotf; 
 use coral_safe::types::{VectorMut, VectorRef, MatrixRef}; 
 
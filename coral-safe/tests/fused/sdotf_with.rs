@@ -0,0 +1,71 @@
+use super::common::{
+    make_cancelling_pair, 
+    make_strided_mat, 
+    make_strided_vec, 
+    gamma, 
+    CoralResult, 
+    U, 
+}; 
+
+use coral_safe::fused::{sdotf, sdotf_with}; 
+use coral_safe::types::{Accuracy, VectorMut, VectorRef, MatrixRef}; 
+
+/// column j of A is 2^(j % 3) times the same cancelling vector
+#[test]
+fn compensated_error_bound() -> CoralResult { 
+    let (m, n) = (2049, 7); 
+    let lda = m + 3; 
+
+    for &incy in &[1, 2] { 
+        let (x, col, dot, abs) = make_cancelling_pair(m, 16, 3); 
+
+        let mut abuf = vec![0.0; lda * n]; 
+        for j in 0..n { 
+            for i in 0..m { 
+                abuf[j * lda + i] = (1 << (j % 3)) as f32 * col[i]; 
+            }
+        }
+
+        let y0: Vec<i128> = (0..n as i128).map(|j| 5 * j - 17).collect(); 
+        let mut ybuf = vec![0.0; (n - 1) * incy + 1]; 
+        for j in 0..n { 
+            ybuf[j * incy] = y0[j] as f32; 
+        }
+
+        let xcoral = VectorRef::new(&x, m, 1, 0)?; 
+        let acoral = MatrixRef::new(&abuf, m, n, lda, 0)?;
+        let ycoral = VectorMut::new(&mut ybuf, n, incy, 0)?; 
+
+        sdotf_with(Accuracy::Compensated, acoral, xcoral, ycoral); 
+
+        for j in 0..n { 
+            let c     = 1i128 << (j % 3); 
+            let exact = y0[j] + c * dot; 
+
+            let err   = (ybuf[j * incy] as i128 - exact).abs() as f64; 
+            let bound = U * (exact as f64).abs() 
+                      + gamma(m + 1).powi(2) * (y0[j].abs() + c * abs) as f64; 
+            assert!(err <= bound, "col {j}: {err} > {bound}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn fast_is_sdotf() -> CoralResult { 
+    let (m, n) = (300, 37); 
+
+    let xbuf = make_strided_vec(m, 1); 
+    let abuf = make_strided_mat(m, n, m); 
+    let mut p = make_strided_vec(n, 1); 
+    let mut q = p.clone(); 
+
+    let xcoral = VectorRef::new(&xbuf, m, 1, 0)?; 
+    let acoral = MatrixRef::new(&abuf, m, n, m, 0)?;
+
+    sdotf(acoral, xcoral, VectorMut::new(&mut p, n, 1, 0)?); 
+    sdotf_with(Accuracy::Fast, acoral, xcoral, VectorMut::new(&mut q, n, 1, 0)?); 
+
+    assert_eq!(p, q); 
+    Ok(())
+}
@@ -0,0 +1,25 @@
+#[path = "common.rs"] 
+mod common; 
+
+#[path = "lapack/sgetrf.rs"] 
+mod sgetrf; 
+#[path = "lapack/sgetrs.rs"] 
+mod sgetrs; 
+#[path = "lapack/spotrf.rs"] 
+mod spotrf; 
+#[path = "lapack/sgeqrf.rs"] 
+mod sgeqrf; 
+#[path = "lapack/sgels.rs"] 
+mod sgels; 
+#[path = "lapack/ssytrf.rs"] 
+mod ssytrf; 
+#[path = "lapack/ssyev.rs"] 
+mod ssyev; 
+#[path = "lapack/sgesvd.rs"] 
+mod sgesvd; 
+#[path = "lapack/sgetri.rs"] 
+mod sgetri; 
+#[path = "lapack/sgecon.rs"] 
+mod sgecon; 
+#[path = "lapack/dsgesv.rs"] 
+mod dsgesv; 
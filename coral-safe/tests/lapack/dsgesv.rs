@@ -0,0 +1,215 @@
+use super::common::CoralResult; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, MatrixRef, CoralRefinement, CoralTriangular}; 
+use coral_safe::lapack::{dsgesv, dsposv}; 
+
+/// deterministic entries in [-1, 1) 
+fn noise(len: usize, seed: u64) -> Vec<f64> { 
+    let mut s = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1); 
+    (0..len)
+        .map(|_| { 
+            s = s.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407); 
+            ((s >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0 
+        })
+        .collect() 
+}
+
+/// `n x n` with a dominant diagonal, stored in `lda` 
+fn general(n: usize, lda: usize) -> Vec<f64> { 
+    let mut a = noise(lda * n, n as u64); 
+    for j in 0..n { 
+        a[j + j * lda] += n as f64; 
+    }
+    a
+}
+
+/// `M^T M + I`, stored in `n` 
+fn spd(n: usize) -> Vec<f64> { 
+    let m = noise(n * n, 7 + n as u64); 
+    let mut a = vec![0.0; n * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            a[i + j * n] = (0..n).map(|p| m[p + i * n] * m[p + j * n]).sum::<f64>() + if i == j { 1.0 } else { 0.0 }; 
+        }
+    }
+    a
+}
+
+fn hilbert(n: usize) -> Vec<f64> { 
+    let mut h = vec![0.0; n * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            h[i + j * n] = 1.0 / (i + j + 1) as f64; 
+        }
+    }
+    h
+}
+
+/// `|B - A X|_inf / (|A|_inf |X|_inf)` 
+fn backward_error(a: &[f64], lda: usize, n: usize, x: &[f64], b: &[f64], nrhs: usize) -> f64 { 
+    let anrm = (0..n)
+        .map(|i| (0..n).map(|j| a[i + j * lda].abs()).sum::<f64>())
+        .fold(0.0, f64::max); 
+
+    let mut worst = 0.0f64; 
+    for j in 0..nrhs { 
+        let xj = &x[j * n .. (j + 1) * n]; 
+        let xnrm = xj.iter().fold(0.0f64, |m, v| m.max(v.abs())); 
+        for i in 0..n { 
+            let ax: f64 = (0..n).map(|p| a[i + p * lda] * xj[p]).sum(); 
+            worst = worst.max((b[i + j * n] - ax).abs() / (anrm * xnrm)); 
+        }
+    }
+    worst
+}
+
+#[test]
+fn dsgesv_converges_to_f64_accuracy() -> CoralResult { 
+    let nrhs = 3; 
+    for n in [1, 5, 40, 130] { 
+        let lda = n + 2; 
+        let a = general(n, lda); 
+        let b = noise(n * nrhs, 99); 
+
+        let mut f    = a.clone(); 
+        let mut x    = vec![0.0; n * nrhs]; 
+        let mut ipiv = vec![0; n]; 
+        let how = dsgesv ( 
+            MatrixMut::new(&mut f, n, n, lda, 0)?, 
+            &mut ipiv, 
+            MatrixRef::new(&b, n, nrhs, n, 0)?, 
+            MatrixMut::new(&mut x, n, nrhs, n, 0)?, 
+        ).unwrap(); 
+
+        assert!(matches!(how, CoralRefinement::Converged(_)), "n = {n}: {how:?}"); 
+        assert_eq!(f, a, "A must be untouched after refinement"); 
+
+        let err = backward_error(&a, lda, n, &x, &b, nrhs); 
+        assert!(err < 1e-15, "n = {n}: backward error {err:e}"); 
+    }
+    Ok(())
+}
+
+#[test]
+fn dsgesv_falls_back_on_ill_conditioned() -> CoralResult { 
+    // kappa(H_10) ~ 1.6e13 is far past 1 / eps_f32 
+    let n = 10; 
+    let a = hilbert(n); 
+    let b = noise(n, 3); 
+
+    let mut f    = a.clone(); 
+    let mut x    = vec![0.0; n]; 
+    let mut ipiv = vec![0; n]; 
+    let how = dsgesv ( 
+        MatrixMut::new(&mut f, n, n, n, 0)?, 
+        &mut ipiv, 
+        MatrixRef::new(&b, n, 1, n, 0)?, 
+        MatrixMut::new(&mut x, n, 1, n, 0)?, 
+    ).unwrap(); 
+
+    assert_eq!(how, CoralRefinement::FellBack); 
+    let err = backward_error(&a, n, n, &x, &b, 1); 
+    assert!(err < 1e-15, "backward error {err:e}"); 
+    Ok(())
+}
+
+#[test]
+fn dsgesv_out_of_f32_range_falls_back() -> CoralResult { 
+    let n = 4; 
+    let mut a = general(n, n); 
+    a.iter_mut().for_each(|v| *v *= 1e300); 
+    let b: Vec<f64> = noise(n, 5).iter().map(|v| v * 1e300).collect(); 
+
+    let mut f    = a.clone(); 
+    let mut x    = vec![0.0; n]; 
+    let mut ipiv = vec![0; n]; 
+    let how = dsgesv ( 
+        MatrixMut::new(&mut f, n, n, n, 0)?, 
+        &mut ipiv, 
+        MatrixRef::new(&b, n, 1, n, 0)?, 
+        MatrixMut::new(&mut x, n, 1, n, 0)?, 
+    ).unwrap(); 
+
+    assert_eq!(how, CoralRefinement::FellBack); 
+    assert!(backward_error(&a, n, n, &x, &b, 1) < 1e-15); 
+    Ok(())
+}
+
+#[test]
+fn dsgesv_singular() -> CoralResult { 
+    let n = 3; 
+    let mut a = vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 5.0]; 
+    let b = vec![1.0; n]; 
+    let mut x    = vec![0.0; n]; 
+    let mut ipiv = vec![0; n]; 
+
+    let res = dsgesv ( 
+        MatrixMut::new(&mut a, n, n, n, 0)?, 
+        &mut ipiv, 
+        MatrixRef::new(&b, n, 1, n, 0)?, 
+        MatrixMut::new(&mut x, n, 1, n, 0)?, 
+    ); 
+    assert_eq!(res, Err(LapackError::Singular { index: 1 })); 
+    Ok(())
+}
+
+#[test]
+fn dsposv_both_triangles() -> CoralResult { 
+    let nrhs = 2; 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for (n, ill) in [(1, false), (6, false), (70, false), (10, true)] { 
+            let a = if ill { hilbert(n) } else { spd(n) }; 
+            let b = noise(n * nrhs, 11); 
+
+            // the other triangle must never be read 
+            let mut f = a.clone(); 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let other = match uplo { 
+                        CoralTriangular::Upper => i > j, 
+                        CoralTriangular::Lower => i < j, 
+                    }; 
+                    if other { 
+                        f[i + j * n] = f64::NAN; 
+                    }
+                }
+            }
+
+            let mut x = vec![0.0; n * nrhs]; 
+            let how = dsposv ( 
+                uplo, 
+                MatrixMut::new(&mut f, n, n, n, 0)?, 
+                MatrixRef::new(&b, n, nrhs, n, 0)?, 
+                MatrixMut::new(&mut x, n, nrhs, n, 0)?, 
+            ).unwrap(); 
+
+            if ill { 
+                assert_eq!(how, CoralRefinement::FellBack); 
+            } else { 
+                assert!(matches!(how, CoralRefinement::Converged(_)), "n = {n}: {how:?}"); 
+            }
+
+            let err = backward_error(&a, n, n, &x, &b, nrhs); 
+            assert!(err < 1e-15, "n = {n}: backward error {err:e}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn dsposv_not_positive_definite() -> CoralResult { 
+    let n = 2; 
+    let mut a = vec![1.0, 2.0, 2.0, 1.0]; 
+    let b = vec![1.0; n]; 
+    let mut x = vec![0.0; n]; 
+
+    let res = dsposv ( 
+        CoralTriangular::Lower, 
+        MatrixMut::new(&mut a, n, n, n, 0)?, 
+        MatrixRef::new(&b, n, 1, n, 0)?, 
+        MatrixMut::new(&mut x, n, 1, n, 0)?, 
+    ); 
+    assert_eq!(res, Err(LapackError::NotPositiveDefinite { index: 1 })); 
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, MatrixRef, CoralTranspose, CoralTriangular}; 
+use coral_safe::lapack::{sgecon, sgetrf, sgetri, slacn2, spocon, spotrf}; 
+
+/// max_j sum_i |a_ij| 
+fn norm1(a: &[f32], lda: usize, n: usize) -> f32 { 
+    (0..n)
+        .map(|j| a[j * lda .. j * lda + n].iter().map(|v| v.abs()).sum::<f32>())
+        .fold(0.0, f32::max) 
+}
+
+/// exact `1 / (|A|_1 |A^{-1}|_1)` through an explicit inverse 
+fn exact_rcond(a: &[f32], n: usize) -> f32 { 
+    let mut x    = a.to_vec(); 
+    let mut ipiv = vec![0; n]; 
+    sgetrf(MatrixMut::new(&mut x, n, n, n, 0).unwrap(), &mut ipiv).unwrap(); 
+    sgetri(MatrixMut::new(&mut x, n, n, n, 0).unwrap(), &ipiv).unwrap(); 
+    1.0 / (norm1(a, n, n) * norm1(&x, n, n)) 
+}
+
+fn hilbert(n: usize) -> Vec<f32> { 
+    let mut h = vec![0.0; n * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            h[i + j * n] = 1.0 / (i + j + 1) as f32; 
+        }
+    }
+    h
+}
+
+#[test]
+fn slacn2_bounds_explicit_norm() -> CoralResult { 
+    for n in [1, 2, 10, 50] { 
+        let b = make_strided_mat(n, n, n); 
+        let exact = norm1(&b, n, n); 
+
+        let est = slacn2(n, |trans, x| { 
+            let y: Vec<f32> = (0..n)
+                .map(|i| (0..n)
+                    .map(|p| match trans { 
+                        CoralTranspose::NoTrans => b[i + p * n], 
+                        CoralTranspose::Trans   => b[p + i * n], 
+                    } * x[p])
+                    .sum())
+                .collect(); 
+            x.copy_from_slice(&y); 
+        }); 
+
+        assert!(est <= exact * (1.0 + 1e-5), "{est} > {exact} for n = {n}"); 
+        assert!(est >= exact / 3.0, "{est} << {exact} for n = {n}"); 
+    }
+
+    // one dominant column is found exactly 
+    let n = 8; 
+    let mut b = vec![0.01; n * n]; 
+    for i in 0..n { 
+        b[i + 5 * n] = if i % 2 == 0 { 3.0 } else { -3.0 }; 
+    }
+    let est = slacn2(n, |trans, x| { 
+        let y: Vec<f32> = (0..n)
+            .map(|i| (0..n)
+                .map(|p| match trans { 
+                    CoralTranspose::NoTrans => b[i + p * n], 
+                    CoralTranspose::Trans   => b[p + i * n], 
+                } * x[p])
+                .sum())
+            .collect(); 
+        x.copy_from_slice(&y); 
+    }); 
+    assert!((est - 24.0).abs() < 1e-4, "est = {est}"); 
+    Ok(())
+}
+
+#[test]
+fn general_condition() -> CoralResult { 
+    for n in [1, 5, 40, 100] { 
+        let mut a = make_strided_mat(n, n, n); 
+        for i in 0..n { 
+            a[i + i * n] += 1.0; 
+        }
+        let exact = exact_rcond(&a, n); 
+
+        let mut f    = a.clone(); 
+        let mut ipiv = vec![0; n]; 
+        sgetrf(MatrixMut::new(&mut f, n, n, n, 0)?, &mut ipiv).unwrap(); 
+        let rcond = sgecon(MatrixRef::new(&f, n, n, n, 0)?, &ipiv, norm1(&a, n, n)); 
+
+        // |A^{-1}|_1 is underestimated, so rcond is overestimated 
+        assert!(rcond >= exact * (1.0 - 1e-3), "{rcond} < {exact} for n = {n}"); 
+        assert!(rcond <= 10.0 * exact, "{rcond} >> {exact} for n = {n}"); 
+    }
+
+    // kappa_1(H_5) = 943656 
+    let n = 5; 
+    let h = hilbert(n); 
+    let mut f    = h.clone(); 
+    let mut ipiv = vec![0; n]; 
+    sgetrf(MatrixMut::new(&mut f, n, n, n, 0)?, &mut ipiv).unwrap(); 
+    let rcond = sgecon(MatrixRef::new(&f, n, n, n, 0)?, &ipiv, norm1(&h, n, n)); 
+    let kappa = 1.0 / rcond; 
+    assert!(kappa > 9.4e5 / 3.0 && kappa < 9.5e5 * 1.1, "kappa = {kappa}"); 
+
+    // an exactly singular U 
+    let mut s    = vec![1.0, 2.0, 2.0, 4.0]; 
+    let mut ipiv = vec![0; 2]; 
+    assert!(sgetrf(MatrixMut::new(&mut s, 2, 2, 2, 0)?, &mut ipiv).is_err()); 
+    assert_eq!(sgecon(MatrixRef::new(&s, 2, 2, 2, 0)?, &ipiv, 6.0), 0.0); 
+    Ok(())
+}
+
+#[test]
+fn positive_definite_condition() -> CoralResult { 
+    let n = 5; 
+    let h = hilbert(n); 
+    let exact = exact_rcond(&h, n); 
+
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        let mut f = h.clone(); 
+        spotrf(uplo, MatrixMut::new(&mut f, n, n, n, 0)?).unwrap(); 
+        let rcond = spocon(uplo, MatrixRef::new(&f, n, n, n, 0)?, norm1(&h, n, n)); 
+
+        assert!(rcond >= exact * 0.9 && rcond <= 10.0 * exact, "{rcond} vs. {exact}, {uplo:?}"); 
+    }
+
+    // the identity is perfectly conditioned 
+    let mut eye = vec![0.0; n * n]; 
+    for i in 0..n { 
+        eye[i + i * n] = 1.0; 
+    }
+    let rcond = spocon(CoralTriangular::Lower, MatrixRef::new(&eye, n, n, n, 0)?, 1.0); 
+    assert!((rcond - 1.0).abs() < 1e-6); 
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, CoralTranspose, CoralTriangular}; 
+use coral_safe::lapack::{sgels, sgeqrf, sposv}; 
+
+const TOL: f32 = 1e-3; 
+
+/// `r x c` op(A) of an `m x n` column major `A`, column major 
+fn op_mat(trans: CoralTranspose, a: &[f32], m: usize, n: usize) -> (Vec<f32>, usize, usize) { 
+    match trans { 
+        CoralTranspose::NoTrans => (a.to_vec(), m, n), 
+        CoralTranspose::Trans   => { 
+            let t = (0..m).flat_map(|i| (0..n).map(move |j| a[i + j * m])).collect(); 
+            (t, n, m) 
+        }
+    }
+}
+
+/// `r x k` times `k x c` 
+fn matmul(a: &[f32], b: &[f32], r: usize, k: usize, c: usize) -> Vec<f32> { 
+    let mut out = vec![0.0; r * c]; 
+    for j in 0..c { 
+        for i in 0..r { 
+            out[i + j * r] = (0..k).map(|p| a[i + p * r] as f64 * b[p + j * k] as f64).sum::<f64>() as f32; 
+        }
+    }
+    out
+}
+
+fn transpose(a: &[f32], r: usize, c: usize) -> Vec<f32> { 
+    (0..r).flat_map(|i| (0..c).map(move |j| a[i + j * r])).collect() 
+}
+
+/// what `sgels` leaves in the `m x n` `A`: its QR factors, or those of `A^T` by rows 
+fn factored(a: &[f32], m: usize, n: usize) -> Vec<f32> { 
+    let (big, s) = (m.max(n), m.min(n)); 
+    let mut f = if m >= n { a.to_vec() } else { transpose(a, m, n) }; 
+    let mut tau = vec![0.0; s]; 
+    sgeqrf(MatrixMut::new(&mut f, big, s, big, 0).unwrap(), &mut tau); 
+
+    if m >= n { f } else { transpose(&f, n, m) } 
+}
+
+fn run(trans: CoralTranspose, m: usize, n: usize, nrhs: usize) -> CoralResult { 
+    let big  = m.max(n); 
+    let a    = make_strided_mat(m, n, m); 
+    let (op, r, c) = op_mat(trans, &a, m, n); 
+
+    let b = make_strided_mat(r, nrhs, r); 
+    let mut bx = vec![0.0; big * nrhs]; 
+    for j in 0..nrhs { 
+        bx[j * big .. j * big + r].copy_from_slice(&b[j * r .. (j + 1) * r]); 
+    }
+
+    let mut af = a.clone(); 
+    let rss = sgels ( 
+        trans, 
+        MatrixMut::new(&mut af, m, n, m, 0)?, 
+        MatrixMut::new(&mut bx, big, nrhs, big, 0)?, 
+    ).unwrap(); 
+    assert_eq!(af, factored(&a, m, n)); 
+
+    let x: Vec<f32> = (0..nrhs).flat_map(|j| bx[j * big .. j * big + c].to_vec()).collect(); 
+    let opt = transpose(&op, r, c); 
+
+    if r >= c { 
+        // op(A)^T (B - op(A) X) = 0 and rss = |B - op(A) X|^2 
+        let ax  = matmul(&op, &x, r, c, nrhs); 
+        let res: Vec<f32> = b.iter().zip(&ax).map(|(b, a)| b - a).collect(); 
+        let ortho = matmul(&opt, &res, c, r, nrhs); 
+
+        // relative to |op(A)^T| (|B| + |op(A)| |X|) 
+        let abs  = |v: &[f32]| v.iter().map(|x| x.abs()).collect::<Vec<f32>>(); 
+        let aax  = matmul(&abs(&op), &abs(&x), r, c, nrhs); 
+        let bnd: Vec<f32> = b.iter().zip(&aax).map(|(b, a)| b.abs() + a).collect(); 
+        let scale = matmul(&abs(&opt), &bnd, c, r, nrhs); 
+        for (o, s) in ortho.iter().zip(&scale) { 
+            assert!(o.abs() <= 1e-4 * s, "op(A)^T res = {o}, scale {s}"); 
+        }
+
+        for (j, &rs) in rss.iter().enumerate() { 
+            let exp: f32 = res[j * r .. (j + 1) * r].iter().map(|v| v * v).sum(); 
+            assert!((rs - exp).abs() <= TOL * (1.0 + exp), "rss {rs} vs {exp}"); 
+        }
+    } else { 
+        // op(A) X = B, with X = op(A)^T W, (op(A) op(A)^T) W = B 
+        let ax = matmul(&op, &x, r, c, nrhs); 
+        assert_close(&ax, &b, TOL, TOL); 
+
+        let mut gram = matmul(&op, &opt, r, c, r); 
+        let mut w = b.clone(); 
+        sposv ( 
+            CoralTriangular::Lower, 
+            MatrixMut::new(&mut gram, r, r, r, 0)?, 
+            MatrixMut::new(&mut w, r, nrhs, r, 0)?, 
+        ).unwrap(); 
+        let xmin = matmul(&opt, &w, c, r, nrhs); 
+        assert_close(&x, &xmin, TOL, TOL); 
+
+        assert!(rss.iter().all(|&v| v == 0.0)); 
+    }
+    Ok(())
+}
+
+#[test]
+fn tall_and_wide_both_transposes() -> CoralResult { 
+    for trans in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+        run(trans, 1, 1, 1)?; 
+        run(trans, 30, 30, 2)?; 
+        run(trans, 120, 45, 3)?; 
+        run(trans, 45, 120, 3)?; 
+    }
+    Ok(())
+}
+
+#[test]
+fn consistent_square_system_is_exact() -> CoralResult { 
+    let mut a = vec![2.0, 1.0, 1.0, 3.0]; 
+    let mut b = vec![5.0, 10.0]; 
+
+    let rss = sgels ( 
+        CoralTranspose::NoTrans, 
+        MatrixMut::new(&mut a, 2, 2, 2, 0)?, 
+        MatrixMut::new(&mut b, 2, 1, 2, 0)?, 
+    ).unwrap(); 
+
+    // 2 x + y = 5, x + 3 y = 10 
+    assert_close(&b, &[1.0, 3.0], 1e-5, 1e-5); 
+    assert!(rss[0].abs() < 1e-10); 
+    Ok(())
+}
+
+#[test]
+fn rank_deficient_is_reported() -> CoralResult { 
+    for (m, n) in [(20, 6), (6, 20)] { 
+        let mut a = make_strided_mat(m, n, m); 
+
+        // the 4th column (row) of the tall (wide) matrix repeats the 2nd 
+        for k in 0..m.max(n) { 
+            if m >= n { 
+                a[k + 3 * m] = a[k + m]; 
+            } else { 
+                a[3 + k * m] = a[1 + k * m]; 
+            }
+        }
+
+        let a0  = a.clone(); 
+        let big = m.max(n); 
+        let b   = make_strided_mat(big, 2, big); 
+        let mut bx = b.clone(); 
+
+        let info = sgels ( 
+            CoralTranspose::NoTrans, 
+            MatrixMut::new(&mut a, m, n, m, 0)?, 
+            MatrixMut::new(&mut bx, big, 2, big, 0)?, 
+        ); 
+
+        assert_eq!(info, Err(LapackError::RankDeficient { index: 3 })); 
+        assert_eq!(bx, b); 
+        assert_eq!(a, factored(&a0, m, n)); 
+    }
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, MatrixRef, VectorMut, CoralSide, CoralTranspose}; 
+use coral_safe::lapack::{slarfg, sgeqrf, sormqr, sorgqr}; 
+
+const TOL: f32 = 1e-4; 
+
+/// `m x n` column major `op(A) B` 
+#[allow(clippy::too_many_arguments)]
+fn matmul(ta: bool, a: &[f32], lda: usize, b: &[f32], ldb: usize, m: usize, n: usize, k: usize) -> Vec<f32> { 
+    let mut c = vec![0.0; m * n]; 
+    for j in 0..n { 
+        for i in 0..m { 
+            c[i + j * m] = (0..k)
+                .map(|p| (if ta { a[p + i * lda] } else { a[i + p * lda] }) as f64 * b[p + j * ldb] as f64)
+                .sum::<f64>() as f32; 
+        }
+    }
+    c
+}
+
+fn qr(m: usize, n: usize, lda: usize) -> (Vec<f32>, Vec<f32>, Vec<f32>) { 
+    let a = make_strided_mat(m, n, lda); 
+    let mut f = a.clone(); 
+    let mut tau = vec![0.0; m.min(n)]; 
+
+    sgeqrf(MatrixMut::new(&mut f, m, n, lda, 0).unwrap(), &mut tau); 
+    (a, f, tau) 
+}
+
+#[test]
+fn slarfg_annihilates() -> CoralResult { 
+    let mut alpha = 3.0; 
+    let mut x = vec![4.0, 0.0, 12.0]; 
+    let tau = slarfg(&mut alpha, VectorMut::new(&mut x, 3, 1, 0)?); 
+
+    // beta = -13, H [3, 4, 0, 12] = [-13, 0, 0, 0] 
+    assert!((alpha + 13.0).abs() < 1e-5); 
+    let v  = [1.0, x[0], x[1], x[2]]; 
+    let a0 = [3.0, 4.0, 0.0, 12.0f32]; 
+    let w: f32 = v.iter().zip(&a0).map(|(v, a)| v * a).sum(); 
+    let ha: Vec<f32> = a0.iter().zip(&v).map(|(a, v)| a - tau * w * v).collect(); 
+    assert_close(&ha, &[-13.0, 0.0, 0.0, 0.0], 1e-5, 1e-5); 
+
+    // x == 0 gives H = I 
+    let mut alpha = -2.0; 
+    let mut x = vec![0.0; 4]; 
+    assert_eq!(slarfg(&mut alpha, VectorMut::new(&mut x, 4, 1, 0)?), 0.0); 
+    assert_eq!(alpha, -2.0); 
+
+    // tiny inputs are rescaled, not flushed 
+    let s = f32::MIN_POSITIVE; 
+    let mut alpha = 3.0 * s; 
+    let mut x = vec![4.0 * s]; 
+    let tau = slarfg(&mut alpha, VectorMut::new(&mut x, 1, 1, 0)?); 
+    assert!((alpha / s + 5.0).abs() < 1e-4); 
+    assert!((tau - 1.6).abs() < 1e-5); 
+    Ok(())
+}
+
+#[test]
+fn q_times_r_is_a() -> CoralResult { 
+    for (m, n) in [(1, 1), (5, 3), (3, 5), (40, 40), (150, 70), (70, 100)] { 
+        let lda = m + 2; 
+        let (a, f, tau) = qr(m, n, lda); 
+        let k = m.min(n); 
+
+        // explicit R, then Q R through sormqr 
+        let mut r = vec![0.0; m * n]; 
+        for j in 0..n { 
+            for i in 0..=j.min(m - 1) { 
+                r[i + j * m] = f[i + j * lda]; 
+            }
+        }
+        sormqr ( 
+            CoralSide::Left, 
+            CoralTranspose::NoTrans, 
+            MatrixRef::new(&f, m, k, lda, 0)?, 
+            &tau, 
+            MatrixMut::new(&mut r, m, n, m, 0)?, 
+        ); 
+
+        let exp: Vec<f32> = (0..n).flat_map(|j| a[j * lda .. j * lda + m].to_vec()).collect(); 
+        assert_close(&r, &exp, TOL, TOL); 
+    }
+    Ok(())
+}
+
+#[test]
+fn sorgqr_is_orthonormal() -> CoralResult { 
+    for (m, n) in [(4, 4), (90, 40), (100, 100)] { 
+        let (a, mut q, tau) = qr(m, n, m); 
+
+        sorgqr(MatrixMut::new(&mut q, m, n, m, 0)?, &tau); 
+
+        // Q^T Q = I 
+        let qtq = matmul(true, &q, m, &q, m, n, n, m); 
+        let eye: Vec<f32> = (0..n * n).map(|i| if i % (n + 1) == 0 { 1.0 } else { 0.0 }).collect(); 
+        assert_close(&qtq, &eye, TOL, TOL); 
+
+        // Q^T A is upper triangular 
+        let qta = matmul(true, &q, m, &a, m, n, n, m); 
+        for j in 0..n { 
+            for i in j + 1..n { 
+                assert!(qta[i + j * n].abs() < 1e-3, "Q^T A[{i}, {j}] = {}", qta[i + j * n]); 
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn sormqr_all_sides_match_explicit_q() -> CoralResult { 
+    let (m, k) = (75, 45); 
+    let (_, f, tau) = qr(m, k, m); 
+
+    // full m x m Q from the k reflectors 
+    let mut q = vec![0.0; m * m]; 
+    for j in 0..k { 
+        q[j * m .. (j + 1) * m].copy_from_slice(&f[j * m .. (j + 1) * m]); 
+    }
+    sorgqr(MatrixMut::new(&mut q, m, m, m, 0)?, &tau); 
+
+    let p = 11; 
+    for side in [CoralSide::Left, CoralSide::Right] { 
+        for trans in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+            let (cm, cn) = match side { CoralSide::Left => (m, p), CoralSide::Right => (p, m) }; 
+            let c = make_strided_mat(cm, cn, cm); 
+            let mut got = c.clone(); 
+
+            sormqr ( 
+                side, 
+                trans, 
+                MatrixRef::new(&f, m, k, m, 0)?, 
+                &tau, 
+                MatrixMut::new(&mut got, cm, cn, cm, 0)?, 
+            ); 
+
+            let t = matches!(trans, CoralTranspose::Trans); 
+            let exp = match side { 
+                CoralSide::Left => matmul(t, &q, m, &c, m, m, p, m), 
+                CoralSide::Right => { 
+                    let mut e = vec![0.0; p * m]; 
+                    for j in 0..m { 
+                        for i in 0..p { 
+                            e[i + j * p] = (0..m)
+                                .map(|l| c[i + l * p] * if t { q[j + l * m] } else { q[l + j * m] })
+                                .sum(); 
+                        }
+                    }
+                    e 
+                }
+            }; 
+
+            assert_close(&got, &exp, TOL, TOL); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn offset_view() -> CoralResult { 
+    let (m, n, ld) = (50, 40, 64); 
+    let off = 7 + 5 * ld; 
+
+    let buf = make_strided_mat(ld, ld, ld); 
+    let mut f = buf.clone(); 
+    let mut tau = vec![0.0; n]; 
+    sgeqrf(MatrixMut::new(&mut f, m, n, ld, off)?, &mut tau); 
+
+    let mut plain: Vec<f32> = (0..n).flat_map(|j| buf[off + j * ld .. off + m + j * ld].to_vec()).collect(); 
+    let mut tau2 = vec![0.0; n]; 
+    sgeqrf(MatrixMut::new(&mut plain, m, n, m, 0)?, &mut tau2); 
+
+    let got: Vec<f32> = (0..n).flat_map(|j| f[off + j * ld .. off + m + j * ld].to_vec()).collect(); 
+    assert_eq!(got, plain); 
+    assert_eq!(tau, tau2); 
+
+    for j in 0..ld { 
+        for i in 0..ld { 
+            let inside = (7..7 + m).contains(&i) && (5..5 + n).contains(&j); 
+            if !inside { 
+                assert_eq!(f[i + j * ld], buf[i + j * ld]); 
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, MatrixRef, CoralSvdJob}; 
+use coral_safe::lapack::sgesvd; 
+
+const TOL: f32 = 1e-4; 
+
+/// max |Q^T Q - I| for the columns (or rows) of the `r x c` `q` 
+fn orthogonality(q: &[f32], r: usize, c: usize, rows: bool) -> f32 { 
+    let at = |i: usize, j: usize| if rows { q[j + i * r] } else { q[i + j * r] }; 
+    let (len, cnt) = if rows { (c, r) } else { (r, c) }; 
+
+    let mut err = 0.0f32; 
+    for j in 0..cnt { 
+        for i in 0..cnt { 
+            let v: f64 = (0..len).map(|p| at(p, i) as f64 * at(p, j) as f64).sum(); 
+            let id = if i == j { 1.0 } else { 0.0 }; 
+            err = err.max((v as f32 - id).abs()); 
+        }
+    }
+    err
+}
+
+struct Svd { 
+    s: Vec<f32>, 
+    u: Vec<f32>, 
+    vt: Vec<f32>, 
+    ucols: usize, 
+    vrows: usize, 
+}
+
+fn svd(job: CoralSvdJob, a: &[f32], m: usize, n: usize, lda: usize) -> Svd { 
+    let k = m.min(n); 
+    let (ucols, vrows) = match job { 
+        CoralSvdJob::NoVectors => (0, 0), 
+        CoralSvdJob::Thin      => (k, k), 
+        CoralSvdJob::Full      => (m, n), 
+    }; 
+
+    let mut s  = vec![0.0; k]; 
+    let mut u  = vec![0.0; m * ucols]; 
+    let mut vt = vec![0.0; vrows * n]; 
+
+    let uview  = (ucols > 0).then(|| MatrixMut::new(&mut u, m, ucols, m, 0).unwrap()); 
+    let vtview = (vrows > 0).then(|| MatrixMut::new(&mut vt, vrows, n, vrows, 0).unwrap()); 
+
+    sgesvd(job, job, MatrixRef::new(a, m, n, lda, 0).unwrap(), &mut s, uview, vtview).unwrap(); 
+    Svd { s, u, vt, ucols, vrows } 
+}
+
+/// max |A - U S V^T| / max(s_0, 1) 
+fn reconstruction(a: &[f32], m: usize, n: usize, lda: usize, f: &Svd) -> f32 { 
+    let k = m.min(n); 
+    let mut err = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..m { 
+            let v: f64 = (0..k)
+                .map(|p| f.u[i + p * m] as f64 * f.s[p] as f64 * f.vt[p + j * f.vrows] as f64)
+                .sum(); 
+            err = err.max((v as f32 - a[i + j * lda]).abs()); 
+        }
+    }
+    err / f.s.first().copied().unwrap_or(0.0).max(1.0) 
+}
+
+#[test]
+fn thin_and_full_factors() -> CoralResult { 
+    for (m, n) in [(1, 1), (5, 3), (3, 5), (40, 17), (17, 40), (64, 64)] { 
+        let lda = m + 2; 
+        let a = make_strided_mat(m, n, lda); 
+
+        for job in [CoralSvdJob::Thin, CoralSvdJob::Full] { 
+            let f = svd(job, &a, m, n, lda); 
+
+            assert!(f.s.windows(2).all(|p| p[0] >= p[1]), "not descending for {m} x {n}"); 
+            assert!(f.s.iter().all(|&v| v >= 0.0)); 
+
+            assert!(orthogonality(&f.u, m, f.ucols, false) < TOL, "U for {m} x {n}, {job:?}"); 
+            assert!(orthogonality(&f.vt, f.vrows, n, true) < TOL, "V^T for {m} x {n}, {job:?}"); 
+
+            let err = reconstruction(&a, m, n, lda, &f); 
+            assert!(err < TOL, "A - U S V^T = {err} for {m} x {n}, {job:?}"); 
+        }
+
+        // the values do not depend on the job 
+        let thin = svd(CoralSvdJob::Thin, &a, m, n, lda); 
+        let vals = svd(CoralSvdJob::NoVectors, &a, m, n, lda); 
+        for (x, y) in thin.s.iter().zip(&vals.s) { 
+            assert!((x - y).abs() <= TOL * thin.s[0], "{x} vs. {y}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn rank_deficient_completes_basis() -> CoralResult { 
+    // x y^T with |x| = 3 and |y| = 5 has one singular value, 15 
+    let (m, n) = (6, 4); 
+    let x = [1.0, 2.0, 0.0, 2.0, 0.0, 0.0f32]; 
+    let y = [0.0, 3.0, 4.0, 0.0f32]; 
+    let mut a = vec![0.0; m * n]; 
+    for j in 0..n { 
+        for i in 0..m { 
+            a[i + j * m] = x[i] * y[j]; 
+        }
+    }
+
+    let f = svd(CoralSvdJob::Full, &a, m, n, m); 
+    assert!((f.s[0] - 15.0).abs() < 1e-4, "s_0 = {}", f.s[0]); 
+    assert!(f.s[1..].iter().all(|&v| v.abs() < 1e-5), "{:?}", f.s); 
+
+    assert!(orthogonality(&f.u, m, m, false) < TOL); 
+    assert!(orthogonality(&f.vt, n, n, true) < TOL); 
+    assert!(reconstruction(&a, m, n, m, &f) < TOL); 
+
+    // the zero matrix 
+    let z = vec![0.0; m * n]; 
+    let f = svd(CoralSvdJob::Full, &z, m, n, m); 
+    assert!(f.s.iter().all(|&v| v == 0.0)); 
+    assert!(orthogonality(&f.u, m, m, false) < TOL); 
+    assert!(orthogonality(&f.vt, n, n, true) < TOL); 
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, CoralDirection}; 
+use coral_safe::lapack::{sgetrf, slaswp}; 
+
+/// max |P L U - A| / (max |A| * n)
+fn lu_residual(a: &[f32], lu: &[f32], ipiv: &[usize], m: usize, n: usize, lda: usize) -> f32 { 
+    let k = m.min(n); 
+
+    let mut prod = vec![0.0f32; m * n]; 
+    for j in 0..n { 
+        for i in 0..m { 
+            let mut acc = 0.0f64; 
+            for p in 0..=i.min(j).min(k - 1) { 
+                let l = if p == i { 1.0 } else { lu[i + p * lda] }; 
+                acc += l as f64 * lu[p + j * lda] as f64; 
+            }
+            prod[i + j * m] = acc as f32; 
+        }
+    }
+
+    // undo the interchanges, P (L U)
+    slaswp ( 
+        CoralDirection::Backward, 
+        MatrixMut::new(&mut prod, m, n, m, 0).unwrap(), 
+        &ipiv[..k], 
+    ); 
+
+    let mut amax = 0.0f32; 
+    let mut err  = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..m { 
+            amax = amax.max(a[i + j * lda].abs()); 
+            err  = err.max((prod[i + j * m] - a[i + j * lda]).abs()); 
+        }
+    }
+
+    err / (amax * n.max(1) as f32) 
+}
+
+fn run(m: usize, n: usize) -> CoralResult { 
+    let lda  = m + 3; 
+    let abuf = make_strided_mat(m, n, lda); 
+    let mut lu   = abuf.clone(); 
+    let mut ipiv = vec![0; m.min(n)]; 
+
+    sgetrf(MatrixMut::new(&mut lu, m, n, lda, 0)?, &mut ipiv).unwrap(); 
+
+    // partial pivoting keeps |L| <= 1 
+    for j in 0..m.min(n) { 
+        assert!(ipiv[j] >= j && ipiv[j] < m); 
+        for i in j + 1..m { 
+            assert!(lu[i + j * lda].abs() <= 1.0); 
+        }
+    }
+
+    let res = lu_residual(&abuf, &lu, &ipiv, m, n, lda); 
+    assert!(res < 1e-5, "residual {res} for {m} x {n}"); 
+    Ok(())
+}
+
+#[test]
+fn square() -> CoralResult { 
+    for n in [1, 2, 5, 63, 64, 65, 200] { 
+        run(n, n)?; 
+    }
+    Ok(())
+}
+
+#[test]
+fn rectangular() -> CoralResult { 
+    run(150, 70)?; 
+    run(70, 150)?; 
+    run(1, 9)?; 
+    run(9, 1)?; 
+    Ok(())
+}
+
+#[test]
+fn pivots_on_largest() -> CoralResult { 
+    // column 0 is [1, 3, -4]; row 2 is the first pivot 
+    let mut a = vec![
+        1.0, 3.0, -4.0, 
+        2.0, 1.0,  0.5, 
+        0.0, 5.0,  1.0, 
+    ]; 
+    let mut ipiv = [0; 3]; 
+
+    sgetrf(MatrixMut::new(&mut a, 3, 3, 3, 0)?, &mut ipiv).unwrap(); 
+
+    assert_eq!(ipiv[0], 2); 
+    assert_eq!(a[0], -4.0); 
+    Ok(())
+}
+
+#[test]
+fn singular_reports_first_zero_pivot() -> CoralResult { 
+    let n   = 80; 
+    let mut a = make_strided_mat(n, n, n); 
+
+    // column 70 repeats column 3 
+    for i in 0..n { 
+        a[i + 70 * n] = a[i + 3 * n]; 
+    }
+    // and column 0 is zero 
+    for v in &mut a[..n] { 
+        *v = 0.0; 
+    }
+
+    let mut ipiv = vec![0; n]; 
+    let info = sgetrf(MatrixMut::new(&mut a, n, n, n, 0)?, &mut ipiv); 
+
+    assert_eq!(info, Err(LapackError::Singular { index: 0 })); 
+    // the factorization still ran to the end 
+    assert!(ipiv.iter().enumerate().all(|(j, &p)| p >= j && p < n)); 
+    Ok(())
+}
+
+#[test]
+fn exactly_singular_2x2() -> CoralResult { 
+    let mut a = vec![1.0, 2.0, 2.0, 4.0]; 
+    let mut ipiv = [0; 2]; 
+
+    let info = sgetrf(MatrixMut::new(&mut a, 2, 2, 2, 0)?, &mut ipiv); 
+    assert_eq!(info, Err(LapackError::Singular { index: 1 })); 
+    Ok(())
+}
+
+#[test]
+fn offset_view() -> CoralResult { 
+    let (n, ld) = (70, 90); 
+    let off = 5 + 4 * ld; 
+
+    let buf = make_strided_mat(ld, ld, ld); 
+    let mut lu = buf.clone(); 
+    let mut ipiv = vec![0; n]; 
+
+    sgetrf(MatrixMut::new(&mut lu, n, n, ld, off)?, &mut ipiv).unwrap(); 
+
+    let res = lu_residual(&buf[off..], &lu[off..], &ipiv, n, n, ld); 
+    assert!(res < 1e-5, "residual {res}"); 
+
+    for j in 0..ld { 
+        for i in 0..ld { 
+            let inside = (5..5 + n).contains(&i) && (4..4 + n).contains(&j); 
+            if !inside { 
+                assert_eq!(lu[i + j * ld], buf[i + j * ld]); 
+            }
+        }
+    }
+    Ok(())
+}
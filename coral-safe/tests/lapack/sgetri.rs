@@ -0,0 +1,199 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, MatrixRef, CoralDiagonal, CoralTriangular}; 
+use coral_safe::lapack::{
+    sgedet, 
+    sgelogdet, 
+    sgetrf, 
+    sgetri, 
+    spologdet, 
+    spotrf, 
+    spotri, 
+    strtri, 
+}; 
+
+const TOL: f32 = 1e-3; 
+
+/// max |A X - I| over full `n x n` `a` and `x` 
+fn identity_error(a: &[f32], lda: usize, x: &[f32], ldx: usize, n: usize) -> f32 { 
+    let mut err = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let v: f64 = (0..n).map(|p| a[i + p * lda] as f64 * x[p + j * ldx] as f64).sum(); 
+            let id = if i == j { 1.0 } else { 0.0 }; 
+            err = err.max((v as f32 - id).abs()); 
+        }
+    }
+    err
+}
+
+/// diagonally weighted, so the inverse stays well conditioned 
+fn make_system(n: usize, lda: usize) -> Vec<f32> { 
+    let mut a = make_strided_mat(n, n, lda); 
+    for i in 0..n { 
+        a[i + i * lda] += n as f32 / 4.0 + 1.0; 
+    }
+    a
+}
+
+/// B B^T / n + I, full storage 
+fn make_spd(n: usize, lda: usize) -> Vec<f32> { 
+    let b = make_strided_mat(n, n, n); 
+    let mut a = vec![0.0; lda * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let dot: f32 = (0..n).map(|p| b[i + p * n] * b[j + p * n]).sum(); 
+            a[i + j * lda] = dot / n as f32 + if i == j { 1.0 } else { 0.0 }; 
+        }
+    }
+    a
+}
+
+#[test]
+fn triangular_inverse() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for diag in [CoralDiagonal::NonUnit, CoralDiagonal::Unit] { 
+            let n   = 70; 
+            let lda = n + 1; 
+
+            // the triangle, diagonal weighted, the other triangle zero 
+            let r = make_strided_mat(n, n, lda); 
+            let mut t = vec![0.0; lda * n]; 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let inside = match uplo { 
+                        CoralTriangular::Upper => i < j, 
+                        CoralTriangular::Lower => i > j, 
+                    }; 
+                    if inside { 
+                        t[i + j * lda] = r[i + j * lda] / n as f32; 
+                    }
+                }
+                t[j + j * lda] = if diag.is_unit() { 1.0 } else { 1.0 + r[j + j * lda] }; 
+            }
+
+            let mut x = t.clone(); 
+            strtri(uplo, diag, MatrixMut::new(&mut x, n, n, lda, 0)?).unwrap(); 
+
+            // the other triangle is untouched 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let other = match uplo { 
+                        CoralTriangular::Upper => i > j, 
+                        CoralTriangular::Lower => i < j, 
+                    }; 
+                    if other { 
+                        assert_eq!(x[i + j * lda], 0.0); 
+                    }
+                }
+            }
+
+            let err = identity_error(&t, lda, &x, lda, n); 
+            assert!(err < TOL, "T T^-1 - I = {err}, {uplo:?}, {diag:?}"); 
+        }
+    }
+
+    // zero diagonal 
+    let mut t = vec![1.0, 0.0, 2.0, 0.0]; 
+    let res = strtri(CoralTriangular::Upper, CoralDiagonal::NonUnit, MatrixMut::new(&mut t, 2, 2, 2, 0)?); 
+    assert_eq!(res, Err(LapackError::Singular { index: 1 })); 
+    assert_eq!(t, vec![1.0, 0.0, 2.0, 0.0]); 
+    Ok(())
+}
+
+#[test]
+fn lu_inverse() -> CoralResult { 
+    for n in [1, 5, 64, 65, 150] { 
+        let lda  = n + 2; 
+        let abuf = make_system(n, lda); 
+
+        let mut x    = abuf.clone(); 
+        let mut ipiv = vec![0; n]; 
+        sgetrf(MatrixMut::new(&mut x, n, n, lda, 0)?, &mut ipiv).unwrap(); 
+        sgetri(MatrixMut::new(&mut x, n, n, lda, 0)?, &ipiv).unwrap(); 
+
+        let err = identity_error(&abuf, lda, &x, lda, n); 
+        assert!(err < TOL, "A A^-1 - I = {err} for n = {n}"); 
+    }
+    Ok(())
+}
+
+#[test]
+fn cholesky_inverse() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for n in [1, 9, 100] { 
+            let lda  = n + 1; 
+            let abuf = make_spd(n, lda); 
+
+            let mut x = abuf.clone(); 
+            spotrf(uplo, MatrixMut::new(&mut x, n, n, lda, 0)?).unwrap(); 
+            spotri(uplo, MatrixMut::new(&mut x, n, n, lda, 0)?).unwrap(); 
+
+            // mirror the computed triangle 
+            for j in 0..n { 
+                for i in 0..j { 
+                    match uplo { 
+                        CoralTriangular::Upper => x[j + i * lda] = x[i + j * lda], 
+                        CoralTriangular::Lower => x[i + j * lda] = x[j + i * lda], 
+                    }
+                }
+            }
+
+            let err = identity_error(&abuf, lda, &x, lda, n); 
+            assert!(err < TOL, "A A^-1 - I = {err} for n = {n}, {uplo:?}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn determinants() -> CoralResult { 
+    // det [[4, 3], [6, 3]] = -6, with a row interchange 
+    let mut a = vec![4.0, 6.0, 3.0, 3.0]; 
+    let mut ipiv = vec![0; 2]; 
+    sgetrf(MatrixMut::new(&mut a, 2, 2, 2, 0)?, &mut ipiv).unwrap(); 
+
+    let det = sgedet(MatrixRef::new(&a, 2, 2, 2, 0)?, &ipiv).unwrap(); 
+    assert!((det + 6.0).abs() < 1e-5, "det = {det}"); 
+
+    let (sign, logdet) = sgelogdet(MatrixRef::new(&a, 2, 2, 2, 0)?, &ipiv).unwrap(); 
+    assert_eq!(sign, -1.0); 
+    assert!((logdet - 6.0f32.ln()).abs() < 1e-5); 
+
+    // det(2 I_200) overflows f32, its log does not 
+    let n = 200; 
+    let mut a = vec![0.0; n * n]; 
+    for i in 0..n { 
+        a[i + i * n] = 2.0; 
+    }
+    let mut l = a.clone(); 
+    let mut ipiv = vec![0; n]; 
+    sgetrf(MatrixMut::new(&mut a, n, n, n, 0)?, &mut ipiv).unwrap(); 
+    assert_eq!(sgedet(MatrixRef::new(&a, n, n, n, 0)?, &ipiv).unwrap(), f32::INFINITY); 
+
+    let (sign, logdet) = sgelogdet(MatrixRef::new(&a, n, n, n, 0)?, &ipiv).unwrap(); 
+    let exact = n as f32 * 2.0f32.ln(); 
+    assert_eq!(sign, 1.0); 
+    assert!((logdet - exact).abs() < 1e-3 * exact); 
+
+    // the Cholesky factor gives the same log determinant 
+    spotrf(CoralTriangular::Lower, MatrixMut::new(&mut l, n, n, n, 0)?).unwrap(); 
+    let chol = spologdet(MatrixRef::new(&l, n, n, n, 0)?); 
+    assert!((chol - exact).abs() < 1e-3 * exact); 
+
+    // a zero pivot is reported, not returned as det = 0 
+    let mut s = vec![1.0, 2.0, 2.0, 4.0]; 
+    let mut ipiv = vec![0; 2]; 
+    let info = sgetrf(MatrixMut::new(&mut s, 2, 2, 2, 0)?, &mut ipiv); 
+    assert!(info.is_err()); 
+    assert_eq!( 
+        sgelogdet(MatrixRef::new(&s, 2, 2, 2, 0)?, &ipiv), 
+        Err(LapackError::Singular { index: 1 }) 
+    ); 
+    assert!(sgedet(MatrixRef::new(&s, 2, 2, 2, 0)?, &ipiv).is_err()); 
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, MatrixRef, CoralDirection, CoralTranspose}; 
+use coral_safe::lapack::{sgesv, sgetrf, sgetrs, slaswp}; 
+
+const RTOL: f32 = 1e-3; 
+const ATOL: f32 = 1e-3; 
+
+/// diagonally weighted, so the solves stay well conditioned
+fn make_system(n: usize, lda: usize) -> Vec<f32> { 
+    let mut a = make_strided_mat(n, n, lda); 
+    for i in 0..n { 
+        a[i + i * lda] += n as f32 / 4.0; 
+    }
+    a
+}
+
+fn op_times(trans: CoralTranspose, a: &[f32], lda: usize, x: &[f32], n: usize, nrhs: usize) -> Vec<f32> { 
+    let mut out = vec![0.0; n * nrhs]; 
+    for j in 0..nrhs { 
+        for i in 0..n { 
+            out[i + j * n] = (0..n)
+                .map(|p| match trans { 
+                    CoralTranspose::NoTrans => a[i + p * lda], 
+                    CoralTranspose::Trans   => a[p + i * lda], 
+                } * x[p + j * n])
+                .sum(); 
+        }
+    }
+    out
+}
+
+fn run(trans: CoralTranspose, n: usize, nrhs: usize) -> CoralResult { 
+    let lda  = n + 1; 
+    let abuf = make_system(n, lda); 
+    let xexp = make_strided_mat(n, nrhs, n); 
+    let mut b = op_times(trans, &abuf, lda, &xexp, n, nrhs); 
+
+    let mut lu   = abuf.clone(); 
+    let mut ipiv = vec![0; n]; 
+    sgetrf(MatrixMut::new(&mut lu, n, n, lda, 0)?, &mut ipiv).unwrap(); 
+
+    sgetrs ( 
+        trans, 
+        MatrixRef::new(&lu, n, n, lda, 0)?, 
+        &ipiv, 
+        MatrixMut::new(&mut b, n, nrhs, n, 0)?, 
+    ); 
+
+    assert_close(&b, &xexp, RTOL, ATOL); 
+    Ok(())
+}
+
+#[test]
+fn both_transposes() -> CoralResult { 
+    for trans in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+        run(trans, 1, 1)?; 
+        run(trans, 17, 3)?; 
+        run(trans, 130, 9)?; 
+    }
+    Ok(())
+}
+
+#[test]
+fn sgesv_solves_and_reports_singular() -> CoralResult { 
+    let n = 40; 
+    let abuf = make_system(n, n); 
+    let xexp = make_strided_mat(n, 2, n); 
+    let mut b = op_times(CoralTranspose::NoTrans, &abuf, n, &xexp, n, 2); 
+
+    let mut a    = abuf.clone(); 
+    let mut ipiv = vec![0; n]; 
+    sgesv ( 
+        MatrixMut::new(&mut a, n, n, n, 0)?, 
+        &mut ipiv, 
+        MatrixMut::new(&mut b, n, 2, n, 0)?, 
+    ).unwrap(); 
+    assert_close(&b, &xexp, RTOL, ATOL); 
+
+    let mut a = vec![0.0; 4]; 
+    let mut b = vec![1.0, 2.0]; 
+    let info = sgesv ( 
+        MatrixMut::new(&mut a, 2, 2, 2, 0)?, 
+        &mut ipiv, 
+        MatrixMut::new(&mut b, 2, 1, 2, 0)?, 
+    ); 
+    assert_eq!(info, Err(LapackError::Singular { index: 0 })); 
+    assert_eq!(b, [1.0, 2.0]); 
+    Ok(())
+}
+
+#[test]
+fn slaswp_forward_then_backward() -> CoralResult { 
+    let (m, n) = (6, 3); 
+    let orig: Vec<f32> = (0..m * n).map(|v| v as f32).collect(); 
+    let mut a = orig.clone(); 
+    let ipiv = [3, 1, 5, 3]; 
+
+    slaswp(CoralDirection::Forward, MatrixMut::new(&mut a, m, n, m, 0)?, &ipiv); 
+
+    // rows 0 <-> 3, 2 <-> 5, then 3 <-> 3 
+    let rows = [3, 1, 5, 0, 4, 2]; 
+    for j in 0..n { 
+        for i in 0..m { 
+            assert_eq!(a[i + j * m], orig[rows[i] + j * m]); 
+        }
+    }
+
+    slaswp(CoralDirection::Backward, MatrixMut::new(&mut a, m, n, m, 0)?, &ipiv); 
+    assert_eq!(a, orig); 
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, MatrixRef, CoralTriangular}; 
+use coral_safe::lapack::{spotrf, spotrs, sposv}; 
+
+/// B B^T + n I, full storage
+fn make_spd(n: usize, lda: usize) -> Vec<f32> { 
+    let b = make_strided_mat(n, n, n); 
+    let mut a = vec![0.0; lda * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let dot: f32 = (0..n).map(|p| b[i + p * n] * b[j + p * n]).sum(); 
+            a[i + j * lda] = dot + if i == j { n as f32 } else { 0.0 }; 
+        }
+    }
+    a
+}
+
+/// max |F^T F - A| / max |A| with F the stored factor 
+fn chol_residual(uplo: CoralTriangular, a: &[f32], f: &[f32], n: usize, lda: usize) -> f32 { 
+    let fac = |i: usize, j: usize| match uplo { 
+        CoralTriangular::Upper => if i <= j { f[i + j * lda] } else { 0.0 }, 
+        CoralTriangular::Lower => if i >= j { f[i + j * lda] } else { 0.0 }, 
+    }; 
+
+    let mut amax = 0.0f32; 
+    let mut err  = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let v: f64 = match uplo { 
+                CoralTriangular::Upper => (0..n).map(|p| fac(p, i) as f64 * fac(p, j) as f64).sum(), 
+                CoralTriangular::Lower => (0..n).map(|p| fac(i, p) as f64 * fac(j, p) as f64).sum(), 
+            }; 
+            amax = amax.max(a[i + j * lda].abs()); 
+            err  = err.max((v as f32 - a[i + j * lda]).abs()); 
+        }
+    }
+
+    err / amax 
+}
+
+#[test]
+fn factors_both_triangles() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for n in [1, 2, 7, 64, 65, 150] { 
+            let lda  = n + 2; 
+            let abuf = make_spd(n, lda); 
+            let mut f = abuf.clone(); 
+
+            spotrf(uplo, MatrixMut::new(&mut f, n, n, lda, 0)?).unwrap(); 
+
+            let res = chol_residual(uplo, &abuf, &f, n, lda); 
+            assert!(res < 1e-5, "residual {res} for n = {n}"); 
+
+            // the other triangle is untouched 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let other = match uplo { 
+                        CoralTriangular::Upper => i > j, 
+                        CoralTriangular::Lower => i < j, 
+                    }; 
+                    if other { 
+                        assert_eq!(f[i + j * lda], abuf[i + j * lda]); 
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn not_positive_definite_reports_minor() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        // leading minors of order <= 100 are positive definite 
+        let n = 120; 
+        let mut a = make_spd(n, n); 
+        a[100 + 100 * n] = -1.0e6; 
+
+        let info = spotrf(uplo, MatrixMut::new(&mut a, n, n, n, 0)?); 
+        assert_eq!(info, Err(LapackError::NotPositiveDefinite { index: 100 })); 
+
+        let mut a = vec![1.0, 2.0, 2.0, 1.0]; 
+        let info = spotrf(uplo, MatrixMut::new(&mut a, 2, 2, 2, 0)?); 
+        assert_eq!(info, Err(LapackError::NotPositiveDefinite { index: 1 })); 
+
+        let mut a = vec![f32::NAN]; 
+        let info = spotrf(uplo, MatrixMut::new(&mut a, 1, 1, 1, 0)?); 
+        assert_eq!(info, Err(LapackError::NotPositiveDefinite { index: 0 })); 
+    }
+    Ok(())
+}
+
+#[test]
+fn solves_both_triangles() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        let (n, nrhs) = (90, 4); 
+        let abuf = make_spd(n, n); 
+        let xexp = make_strided_mat(n, nrhs, n); 
+
+        let mut b = vec![0.0; n * nrhs]; 
+        for j in 0..nrhs { 
+            for i in 0..n { 
+                b[i + j * n] = (0..n).map(|p| abuf[i + p * n] * xexp[p + j * n]).sum(); 
+            }
+        }
+        let mut b2 = b.clone(); 
+
+        let mut f = abuf.clone(); 
+        spotrf(uplo, MatrixMut::new(&mut f, n, n, n, 0)?).unwrap(); 
+        spotrs(uplo, MatrixRef::new(&f, n, n, n, 0)?, MatrixMut::new(&mut b, n, nrhs, n, 0)?); 
+        assert_close(&b, &xexp, 1e-3, 1e-3); 
+
+        let mut a = abuf.clone(); 
+        sposv(uplo, MatrixMut::new(&mut a, n, n, n, 0)?, MatrixMut::new(&mut b2, n, nrhs, n, 0)?).unwrap(); 
+        assert_eq!(b, b2); 
+    }
+    Ok(())
+}
+
+#[test]
+fn offset_view() -> CoralResult { 
+    let (n, ld) = (70, 80); 
+    let off = 6 + 3 * ld; 
+
+    let spd  = make_spd(n, n); 
+    let mut buf = make_strided_mat(ld, ld, ld); 
+    for j in 0..n { 
+        for i in 0..n { 
+            buf[off + i + j * ld] = spd[i + j * n]; 
+        }
+    }
+    let orig = buf.clone(); 
+
+    spotrf(CoralTriangular::Lower, MatrixMut::new(&mut buf, n, n, ld, off)?).unwrap(); 
+
+    let res = chol_residual(CoralTriangular::Lower, &orig[off..], &buf[off..], n, ld); 
+    assert!(res < 1e-5, "residual {res}"); 
+
+    for j in 0..ld { 
+        for i in 0..ld { 
+            let inside = (6..6 + n).contains(&i) && (3..3 + n).contains(&j); 
+            if !inside { 
+                assert_eq!(buf[i + j * ld], orig[i + j * ld]); 
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, CoralJob, CoralTriangular}; 
+use coral_safe::lapack::{sorgtr, ssteqr, ssyev, ssytrd}; 
+
+const TOL: f32 = 1e-4; 
+
+/// symmetric in [-1, 1), full storage 
+fn make_symmetric(n: usize, lda: usize) -> Vec<f32> { 
+    let r = make_strided_mat(n, n, n); 
+    let mut a = vec![0.0; lda * n]; 
+    for j in 0..n { 
+        for i in 0..=j { 
+            let v = 2.0 * r[i + j * n] - 1.0; 
+            a[i + j * lda] = v; 
+            a[j + i * lda] = v; 
+        }
+    }
+    a
+}
+
+/// max |Q^T Q - I| over the `n x n` `q` 
+fn orthogonality(q: &[f32], ldq: usize, n: usize) -> f32 { 
+    let mut err = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let v: f64 = (0..n).map(|p| q[p + i * ldq] as f64 * q[p + j * ldq] as f64).sum(); 
+            let id = if i == j { 1.0 } else { 0.0 }; 
+            err = err.max((v as f32 - id).abs()); 
+        }
+    }
+    err
+}
+
+/// max |(Q^T A Q)[i, j] - B[i, j]| / max |A|, `b(i, j)` the expected entries 
+fn similarity_error(a: &[f32], lda: usize, q: &[f32], n: usize, b: impl Fn(usize, usize) -> f32) -> f32 { 
+    let amax = a.iter().fold(0.0f32, |m, v| m.max(v.abs())); 
+
+    let mut aq = vec![0.0f64; n * n]; 
+    for j in 0..n { 
+        for i in 0..n { 
+            aq[i + j * n] = (0..n).map(|p| a[i + p * lda] as f64 * q[p + j * lda] as f64).sum(); 
+        }
+    }
+
+    let mut err = 0.0f32; 
+    for j in 0..n { 
+        for i in 0..n { 
+            let v: f64 = (0..n).map(|p| q[p + i * lda] as f64 * aq[p + j * n]).sum(); 
+            err = err.max((v as f32 - b(i, j)).abs()); 
+        }
+    }
+    err / amax 
+}
+
+#[test]
+fn tridiagonal_reduction() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for n in [1, 2, 5, 40, 97] { 
+            let lda  = n + 3; 
+            let abuf = make_symmetric(n, lda); 
+
+            let mut q   = abuf.clone(); 
+            let mut d   = vec![0.0; n]; 
+            let mut e   = vec![0.0; n.saturating_sub(1)]; 
+            let mut tau = vec![0.0; n.saturating_sub(1)]; 
+
+            ssytrd(uplo, MatrixMut::new(&mut q, n, n, lda, 0)?, &mut d, &mut e, &mut tau); 
+            sorgtr(uplo, MatrixMut::new(&mut q, n, n, lda, 0)?, &tau); 
+
+            assert!(orthogonality(&q, lda, n) < TOL, "Q not orthogonal for n = {n}"); 
+
+            let t = |i: usize, j: usize| { 
+                if i == j { 
+                    d[i] 
+                } else if i + 1 == j { 
+                    e[i] 
+                } else if j + 1 == i { 
+                    e[j] 
+                } else { 
+                    0.0 
+                }
+            }; 
+            let err = similarity_error(&abuf, lda, &q, n, t); 
+            assert!(err < TOL, "Q^T A Q != T by {err} for n = {n}, {uplo:?}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn steqr_second_difference() -> CoralResult { 
+    // tridiag(-1, 2, -1) has eigenvalues 2 - 2 cos(k pi / (n + 1)) 
+    let n = 50; 
+    let mut d = vec![2.0; n]; 
+    let mut e = vec![-1.0; n - 1]; 
+    ssteqr(&mut d, &mut e, None).unwrap(); 
+
+    for (k, &v) in d.iter().enumerate() { 
+        let exact = 2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos(); 
+        assert!((v as f64 - exact).abs() < 1e-5, "eigenvalue {k}: {v} vs. {exact}"); 
+    }
+    Ok(())
+}
+
+#[test]
+fn eigen_decomposition() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for n in [1, 2, 7, 64, 101] { 
+            let lda  = n + 1; 
+            let abuf = make_symmetric(n, lda); 
+
+            let mut z = abuf.clone(); 
+            let mut w = vec![0.0; n]; 
+            ssyev(CoralJob::Vectors, uplo, MatrixMut::new(&mut z, n, n, lda, 0)?, &mut w).unwrap(); 
+
+            assert!(w.windows(2).all(|p| p[0] <= p[1]), "eigenvalues not ascending"); 
+            assert!(orthogonality(&z, lda, n) < TOL, "Z not orthogonal for n = {n}"); 
+
+            let lambda = |i: usize, j: usize| if i == j { w[i] } else { 0.0 }; 
+            let err = similarity_error(&abuf, lda, &z, n, lambda); 
+            assert!(err < TOL, "Z^T A Z != diag(w) by {err} for n = {n}, {uplo:?}"); 
+
+            // the eigenvalues do not depend on the job 
+            let mut a2 = abuf.clone(); 
+            let mut w2 = vec![0.0; n]; 
+            ssyev(CoralJob::ValuesOnly, uplo, MatrixMut::new(&mut a2, n, n, lda, 0)?, &mut w2).unwrap(); 
+            for (x, y) in w.iter().zip(&w2) { 
+                assert!((x - y).abs() < TOL, "{x} vs. {y}"); 
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use super::common::{
+    make_strided_mat, 
+    CoralResult, 
+}; 
+
+use coral_safe::errors::LapackError; 
+use coral_safe::types::{MatrixMut, MatrixRef, CoralPivot, CoralTriangular, Inertia}; 
+use coral_safe::lapack::{ssyinertia, ssytrf, ssytrs}; 
+
+/// symmetric in [-1, 1) with a zero diagonal, full storage, so every 
+/// first pivot test fails and 2 x 2 blocks are taken 
+fn make_indefinite(n: usize, lda: usize) -> Vec<f32> { 
+    let r = make_strided_mat(n, n, n); 
+    let mut a = vec![0.0; lda * n]; 
+    for j in 0..n { 
+        for i in 0..j { 
+            let v = 2.0 * r[i + j * n] - 1.0; 
+            a[i + j * lda] = v; 
+            a[j + i * lda] = v; 
+        }
+    }
+    a
+}
+
+/// max |B - A X| / (max |A| max |X| n), A in full storage 
+fn backward_error(a: &[f32], lda: usize, x: &[f32], b: &[f32], n: usize, nrhs: usize) -> f32 { 
+    let amax = a.iter().fold(0.0f32, |m, v| m.max(v.abs())); 
+    let xmax = x.iter().fold(0.0f32, |m, v| m.max(v.abs())); 
+
+    let mut err = 0.0f32; 
+    for j in 0..nrhs { 
+        for i in 0..n { 
+            let ax: f64 = (0..n).map(|p| a[i + p * lda] as f64 * x[p + j * n] as f64).sum(); 
+            err = err.max((ax as f32 - b[i + j * n]).abs()); 
+        }
+    }
+
+    err / (amax * xmax * n as f32)
+}
+
+#[test]
+fn solves_both_triangles() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for n in [1, 2, 3, 8, 33, 100] { 
+            let nrhs = 3; 
+            let lda  = n + 2; 
+            let abuf = make_indefinite(n, lda); 
+            let bbuf: Vec<f32> = make_strided_mat(n, nrhs, n)
+                .iter()
+                .map(|v| 2.0 * v - 1.0)
+                .collect(); 
+
+            let mut f    = abuf.clone(); 
+            let mut ipiv = vec![CoralPivot::OneByOne(0); n]; 
+
+            // a zero 1 x 1 matrix is singular 
+            let info = ssytrf(uplo, MatrixMut::new(&mut f, n, n, lda, 0)?, &mut ipiv); 
+            if n == 1 { 
+                assert_eq!(info, Err(LapackError::Singular { index: 0 })); 
+                continue; 
+            }
+            info.unwrap(); 
+
+            assert!(
+                ipiv.iter().any(|p| matches!(p, CoralPivot::TwoByTwo(_))), 
+                "no 2 x 2 pivot for n = {n}"
+            ); 
+
+            let mut x = bbuf.clone(); 
+            ssytrs(
+                uplo, 
+                MatrixRef::new(&f, n, n, lda, 0)?, 
+                &ipiv, 
+                MatrixMut::new(&mut x, n, nrhs, n, 0)?, 
+            ); 
+
+            let err = backward_error(&abuf, lda, &x, &bbuf, n, nrhs); 
+            assert!(err < 1e-5, "backward error {err} for n = {n}, {uplo:?}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn saddle_point_inertia() -> CoralResult { 
+    // [[S, C^T], [C, 0]] with S = I + R R^T / m positive definite and 
+    // C of full row rank has inertia (m, p, 0) 
+    let (m, p) = (12, 5); 
+    let n   = m + p; 
+    let lda = n; 
+
+    let r = make_strided_mat(m, m, m); 
+    let mut a = vec![0.0; lda * n]; 
+    for j in 0..m { 
+        for i in 0..m { 
+            let dot: f32 = (0..m).map(|q| r[i + q * m] * r[j + q * m]).sum(); 
+            a[i + j * lda] = dot / m as f32 + if i == j { 1.0 } else { 0.0 }; 
+        }
+    }
+    for k in 0..p { 
+        // C = [I_p + noise, noise] 
+        for j in 0..m { 
+            let v = 0.1 * r[k + j * m] + if j == k { 1.0 } else { 0.0 }; 
+            a[m + k + j * lda] = v; 
+            a[j + (m + k) * lda] = v; 
+        }
+    }
+
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        let mut f    = a.clone(); 
+        let mut ipiv = vec![CoralPivot::OneByOne(0); n]; 
+        ssytrf(uplo, MatrixMut::new(&mut f, n, n, lda, 0)?, &mut ipiv).unwrap(); 
+
+        let inertia = ssyinertia(uplo, MatrixRef::new(&f, n, n, lda, 0)?, &ipiv); 
+        assert_eq!(inertia, Inertia { positive: m, negative: p, zero: 0 }, "{uplo:?}"); 
+    }
+    Ok(())
+}
+
+#[test]
+fn singular_reports_zero_pivot() -> CoralResult { 
+    // diag(2, -3, 0, 1): the zero column is skipped and counted 
+    let n = 4; 
+    let d = [2.0, -3.0, 0.0, 1.0]; 
+
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        let mut f = vec![0.0; n * n]; 
+        for (i, &v) in d.iter().enumerate() { 
+            f[i + i * n] = v; 
+        }
+
+        let mut ipiv = vec![CoralPivot::OneByOne(0); n]; 
+        let info = ssytrf(uplo, MatrixMut::new(&mut f, n, n, n, 0)?, &mut ipiv); 
+        assert_eq!(info, Err(LapackError::Singular { index: 2 })); 
+
+        let inertia = ssyinertia(uplo, MatrixRef::new(&f, n, n, n, 0)?, &ipiv); 
+        assert_eq!(inertia, Inertia { positive: 2, negative: 1, zero: 1 }); 
+    }
+    Ok(())
+}
@@ -1,5 +1,7 @@
 #[path = "common.rs"]
 mod common;
+#[path = "common/cblas.rs"]
+mod cblas;
 
 #[path = "level1/saxpy.rs" ]
 mod saxpy;
@@ -19,8 +21,19 @@ mod isamax;
 mod srot; 
 #[path = "level1/srotg.rs"  ] 
 mod srotg; 
+#[path = "level1/srotg_extremes.rs"  ] 
+mod srotg_extremes; 
 #[path = "level1/srotm.rs"  ] 
 mod srotm; 
 #[path = "level1/srotmg.rs"  ] 
 mod srotmg; 
-
+#[path = "level1/dsdot.rs"  ]
+mod dsdot; 
+#[path = "level1/sdot_accurate.rs"  ]
+mod sdot_accurate; 
+#[path = "level1/sasum_accurate.rs"  ]
+mod sasum_accurate; 
+#[path = "level1/snrm2_accurate.rs"  ]
+mod snrm2_accurate; 
+#[path = "level1/snrm2_blue.rs"  ]
+mod snrm2_blue; 
@@ -0,0 +1,59 @@
+use super::common::CoralResult; 
+
+use rand::{Rng, SeedableRng}; 
+use rand::rngs::StdRng; 
+use coral_safe::level1::{dsdot, sdsdot, sdot}; 
+use coral_safe::types::VectorRef; 
+
+const U64: f64 = 1.0 / (1u64 << 53) as f64; 
+
+fn strided(v: &[f32], inc: usize) -> Vec<f32> { 
+    let mut buf = vec![0.0; (v.len() - 1) * inc + 1]; 
+    for (i, &vi) in v.iter().enumerate() { 
+        buf[i * inc] = vi; 
+    }
+    buf
+}
+
+/// products are exact in f64, so only the n - 1 additions round
+#[test]
+fn matches_f64_sum() -> CoralResult { 
+    let mut rng = StdRng::seed_from_u64(38); 
+
+    for &n in &[1, 15, 16, 17, 100, 1027] { 
+        let x: Vec<f32> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect(); 
+        let y: Vec<f32> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect(); 
+
+        let want: f64 = x.iter().zip(&y).map(|(&a, &b)| a as f64 * b as f64).sum(); 
+        let abs: f64  = x.iter().zip(&y).map(|(&a, &b)| (a as f64 * b as f64).abs()).sum(); 
+
+        for &(incx, incy) in &[(1, 1), (2, 3), (1, 4)] { 
+            let xbuf = strided(&x, incx); 
+            let ybuf = strided(&y, incy); 
+            let xvec = VectorRef::new(&xbuf, n, incx, 0)?; 
+            let yvec = VectorRef::new(&ybuf, n, incy, 0)?; 
+
+            let dot = dsdot(xvec, yvec); 
+            assert!((dot - want).abs() <= 2.0 * n as f64 * U64 * abs, "n {n}: {dot} vs {want}"); 
+            assert_eq!(sdsdot(0.375, xvec, yvec), (0.375 + dot) as f32); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn keeps_what_f32_loses() -> CoralResult { 
+    let xbuf = [1.0e8, 1.0, -1.0e8]; 
+    let ybuf = [1.0; 3]; 
+    let xvec = VectorRef::new(&xbuf, 3, 1, 0)?; 
+    let yvec = VectorRef::new(&ybuf, 3, 1, 0)?; 
+
+    assert_eq!(sdot(xvec, yvec), 0.0); 
+    assert_eq!(dsdot(xvec, yvec), 1.0); 
+    assert_eq!(sdsdot(0.5, xvec, yvec), 1.5); 
+
+    let empty = VectorRef::new(&xbuf, 0, 1, 0)?; 
+    assert_eq!(dsdot(empty, empty), 0.0); 
+    assert_eq!(sdsdot(-2.5, empty, empty), -2.5); 
+    Ok(())
+}
@@ -3,8 +3,7 @@ use super::common::{
     CoralResult,
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_isamax; 
+use crate::cblas::cblas_isamax; 
 use coral_safe::level1::isamax; 
 use coral_safe::types::VectorRef; 
 
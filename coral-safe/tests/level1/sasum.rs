@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_sasum; 
+use crate::cblas::cblas_sasum; 
 use coral_safe::level1::sasum; 
 use coral_safe::types::VectorRef; 
 
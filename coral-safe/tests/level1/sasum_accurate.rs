@@ -0,0 +1,47 @@
+use super::common::{
+    exact_int, 
+    gamma, 
+    CoralResult,
+    U, 
+}; 
+
+use rand::SeedableRng; 
+use rand::rngs::StdRng; 
+use coral_safe::level1::sasum_accurate; 
+use coral_safe::types::VectorRef; 
+
+/// |s - sum |x_i|| <= (u + gamma_{n-1}^2) sum |x_i|
+#[test]
+fn error_bound() -> CoralResult { 
+    let mut rng = StdRng::seed_from_u64(7); 
+
+    for &n in &[1, 33, 10_000] { 
+        for &incx in &[1, 4] { 
+            let x: Vec<i128> = (0..n).map(|_| exact_int(&mut rng, 30)).collect(); 
+            let exact: i128  = x.iter().map(|v| v.abs()).sum(); 
+
+            let mut xbuf = vec![0.0; (n - 1) * incx + 1]; 
+            for (i, &v) in x.iter().enumerate() { 
+                xbuf[i * incx] = v as f32; 
+            }
+            let xvec = VectorRef::new(&xbuf, n, incx, 0)?; 
+
+            let coral_val = sasum_accurate(xvec); 
+
+            let err   = (coral_val as i128 - exact).abs() as f64; 
+            let bound = (U + gamma(n - 1).powi(2)) * exact as f64; 
+            assert!(err <= bound, "n {n}, incx {incx}: {err} > {bound}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn small_after_large() -> CoralResult { 
+    let mut xbuf = vec![1.0; 1 << 16]; 
+    xbuf[0] = 1.0e8; 
+    let xvec = VectorRef::new(&xbuf, 1 << 16, 1, 0)?; 
+
+    assert_eq!(sasum_accurate(xvec), 1.0e8 + 65535.0); 
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use core::f32::consts::PI;
+
 use super::common::{
     make_strided_vec, 
     assert_close, 
@@ -6,8 +8,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_saxpy; 
+use crate::cblas::cblas_saxpy; 
 use coral_safe::level1::saxpy; 
 use coral_safe::types::{VectorRef, VectorMut}; 
 
@@ -16,7 +17,7 @@ fn unit_stride() -> CoralResult {
     let n = 1024; 
     let incx = 1; 
     let incy = 1;
-    let alpha = 3.1415926; 
+    let alpha = PI; 
 
     let xbuf = make_strided_vec(n, incx); 
     let mut ybuf = make_strided_vec(n, incy);
@@ -48,7 +49,7 @@ fn strided() -> CoralResult {
     let n = 1024; 
     let incx = 3; 
     let incy = 5;
-    let alpha = 3.1415926; 
+    let alpha = PI; 
 
     let xbuf = make_strided_vec(n, incx); 
     let mut ybuf = make_strided_vec(n, incy);
@@ -80,7 +81,7 @@ fn n_zero() -> CoralResult {
     let n = 0; 
     let incx = 1; 
     let incy = 1;
-    let alpha = 3.1415926; 
+    let alpha = PI; 
 
     let xbuf = make_strided_vec(n, incx); 
     let mut ybuf = make_strided_vec(n, incy);
@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_scopy; 
+use crate::cblas::cblas_scopy; 
 use coral_safe::level1::scopy; 
 use coral_safe::types::{VectorRef, VectorMut}; 
 
@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_sdot; 
+use crate::cblas::cblas_sdot; 
 use coral_safe::level1::sdot; 
 use coral_safe::types::VectorRef; 
 
@@ -0,0 +1,63 @@
+use super::common::{
+    make_cancelling_pair, 
+    gamma, 
+    CoralResult,
+    U, 
+}; 
+
+use coral_safe::level1::{sdot, sdot_accurate}; 
+use coral_safe::types::VectorRef; 
+
+fn strided(v: &[f32], inc: usize) -> Vec<f32> { 
+    let mut buf = vec![0.0; (v.len() - 1) * inc + 1]; 
+    for (i, &vi) in v.iter().enumerate() { 
+        buf[i * inc] = vi; 
+    }
+    buf
+}
+
+/// |s - x^T y| <= u |x^T y| + gamma_n^2 sum |x_i y_i|
+#[test]
+fn error_bound() -> CoralResult { 
+    for &n in &[64, 1000, 4097] { 
+        for &emax in &[4, 12, 20] { 
+            let (x, y, dot, abs) = make_cancelling_pair(n, emax, n as u64 + emax as u64); 
+
+            for &(incx, incy) in &[(1, 1), (3, 2)] { 
+                let xbuf = strided(&x, incx); 
+                let ybuf = strided(&y, incy); 
+                let xvec = VectorRef::new(&xbuf, n, incx, 0)?; 
+                let yvec = VectorRef::new(&ybuf, n, incy, 0)?; 
+
+                let coral_val = sdot_accurate(xvec, yvec); 
+
+                // integer valued, so the error is exact 
+                let err   = (coral_val as i128 - dot).abs() as f64; 
+                let bound = U * (dot as f64).abs() + gamma(n).powi(2) * abs as f64; 
+                assert!(err <= bound, "n {n}, emax {emax}: {err} > {bound}"); 
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn recovers_lost_terms() -> CoralResult { 
+    let xbuf = [1.0e8, 1.0, -1.0e8]; 
+    let ybuf = [1.0; 3]; 
+    let xvec = VectorRef::new(&xbuf, 3, 1, 0)?; 
+    let yvec = VectorRef::new(&ybuf, 3, 1, 0)?; 
+
+    assert_eq!(sdot(xvec, yvec), 0.0); 
+    assert_eq!(sdot_accurate(xvec, yvec), 1.0); 
+    Ok(())
+}
+
+#[test]
+fn n_zero() -> CoralResult { 
+    let xbuf = [1.0]; 
+    let xvec = VectorRef::new(&xbuf, 0, 1, 0)?; 
+
+    assert_eq!(sdot_accurate(xvec, xvec), 0.0); 
+    Ok(())
+}
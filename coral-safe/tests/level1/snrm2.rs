@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_snrm2; 
+use crate::cblas::cblas_snrm2; 
 use coral_safe::level1::snrm2; 
 use coral_safe::types::VectorRef; 
 
@@ -0,0 +1,67 @@
+use super::common::{
+    exact_int, 
+    gamma, 
+    CoralResult,
+    U, 
+}; 
+
+use rand::SeedableRng; 
+use rand::rngs::StdRng; 
+use coral_safe::level1::snrm2_accurate; 
+use coral_safe::types::VectorRef; 
+
+/// |r - ||x||| <= (2u + gamma_n^2) ||x||
+#[test]
+fn error_bound() -> CoralResult { 
+    let mut rng = StdRng::seed_from_u64(11); 
+
+    for &n in &[1, 17, 5000] { 
+        for &incx in &[1, 3] { 
+            let x: Vec<i128> = (0..n).map(|_| exact_int(&mut rng, 20)).collect(); 
+            let ssq: i128    = x.iter().map(|v| v * v).sum(); 
+
+            // f64 rounding of the reference is far below the f32 bound
+            let exact = (ssq as f64).sqrt(); 
+
+            let mut xbuf = vec![0.0; (n - 1) * incx + 1]; 
+            for (i, &v) in x.iter().enumerate() { 
+                xbuf[i * incx] = v as f32; 
+            }
+            let xvec = VectorRef::new(&xbuf, n, incx, 0)?; 
+
+            let coral_val = snrm2_accurate(xvec) as f64; 
+
+            let bound = (2.0 * U + gamma(n).powi(2)) * exact; 
+            assert!((coral_val - exact).abs() <= bound, "n {n}, incx {incx}: {coral_val} vs {exact}"); 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn no_overflow_or_underflow() -> CoralResult { 
+    for &k in &[100, -100] { 
+        let s = 2f32.powi(k); 
+        let xbuf = [3.0 * s, 4.0 * s]; 
+        let xvec = VectorRef::new(&xbuf, 2, 1, 0)?; 
+
+        assert_eq!(snrm2_accurate(xvec), 5.0 * s); 
+    }
+
+    let xbuf = [0.0; 3]; 
+    let xvec = VectorRef::new(&xbuf, 3, 1, 0)?; 
+    assert_eq!(snrm2_accurate(xvec), 0.0); 
+    Ok(())
+}
+
+#[test]
+fn nan_propagates() -> CoralResult { 
+    let xbuf = [f32::NAN]; 
+    let xvec = VectorRef::new(&xbuf, 1, 1, 0)?; 
+    assert!(snrm2_accurate(xvec).is_nan()); 
+
+    let xbuf = [0.0, f32::NAN, 0.0]; 
+    let xvec = VectorRef::new(&xbuf, 3, 1, 0)?; 
+    assert!(snrm2_accurate(xvec).is_nan()); 
+    Ok(())
+}
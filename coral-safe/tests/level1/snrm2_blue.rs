@@ -0,0 +1,58 @@
+use super::common::CoralResult; 
+
+use coral_safe::level1::snrm2; 
+use coral_safe::types::VectorRef; 
+
+/// (3, 4) * 2^k has norm 5 * 2^k exactly, even where the squares would
+/// overflow or vanish; 2^-140 is subnormal
+#[test]
+fn no_overflow_or_underflow() -> CoralResult { 
+    for &k in &[-140, -100, -70, -63, 0, 52, 60, 100, 125] { 
+        let s = 2f32.powi(k); 
+
+        // contiguous, padded past one SIMD chunk, and strided
+        let mut xbuf = vec![0.0; 40]; 
+        xbuf[5]  = 3.0 * s; 
+        xbuf[33] = 4.0 * s; 
+        let xvec = VectorRef::new(&xbuf, 40, 1, 0)?; 
+        assert_eq!(snrm2(xvec), 5.0 * s, "2^{k}"); 
+
+        let ybuf = [3.0 * s, 0.0, 0.0, 4.0 * s]; 
+        let yvec = VectorRef::new(&ybuf, 2, 3, 0)?; 
+        assert_eq!(snrm2(yvec), 5.0 * s, "2^{k}"); 
+    }
+    Ok(())
+}
+
+#[test]
+fn mixed_magnitudes() -> CoralResult { 
+    // one huge entry among many tiny ones
+    let mut xbuf = vec![2f32.powi(-100); 33]; 
+    xbuf[17] = 2f32.powi(100); 
+    let xvec = VectorRef::new(&xbuf, 33, 1, 0)?; 
+    assert_eq!(snrm2(xvec), 2f32.powi(100)); 
+
+    // a tiny entry among ones, in both the SIMD body and the tail
+    for &n in &[17, 36] { 
+        let mut xbuf = vec![1.0f32; n]; 
+        xbuf[3]     = 2f32.powi(-80); 
+        xbuf[n - 1] = 2f32.powi(-90); 
+        let xvec = VectorRef::new(&xbuf, n, 1, 0)?; 
+        assert_eq!(snrm2(xvec), ((n - 2) as f32).sqrt()); 
+    }
+    Ok(())
+}
+
+#[test]
+fn nan_and_inf() -> CoralResult { 
+    for &n in &[3, 20] { 
+        let mut xbuf = vec![1.0f32; n]; 
+
+        xbuf[n - 2] = f32::INFINITY; 
+        assert_eq!(snrm2(VectorRef::new(&xbuf, n, 1, 0)?), f32::INFINITY); 
+
+        xbuf[1] = f32::NAN; 
+        assert!(snrm2(VectorRef::new(&xbuf, n, 1, 0)?).is_nan()); 
+    }
+    Ok(())
+}
@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_srot; 
+use crate::cblas::cblas_srot; 
 use coral_safe::level1::srot; 
 use coral_safe::types::VectorMut; 
 
@@ -5,8 +5,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_srotg; 
+use crate::cblas::cblas_srotg; 
 use coral_safe::level1::srotg; 
 
 #[test]
@@ -0,0 +1,41 @@
+use super::common::CoralResult; 
+
+use coral_safe::level1::srotg; 
+
+/// (3, 4) * 2^k gives r = 5 * 2^k, c = 0.6, s = 0.8, even where a^2 + b^2
+/// would overflow or vanish
+#[test]
+fn no_overflow_or_underflow() -> CoralResult { 
+    for &k in &[-149, -140, -126, -70, 0, 70, 120, 125] { 
+        // 2f32.powi(-149) is 1 / 2^149, which overflows
+        let t = 2f64.powi(k) as f32; 
+
+        let (mut a, mut b, mut c, mut s) = (3.0 * t, 4.0 * t, 0.0, 0.0); 
+        srotg(&mut a, &mut b, &mut c, &mut s); 
+
+        let tol = 2.0 * f32::EPSILON; 
+        assert!((a / t - 5.0).abs() <= 5.0 * tol, "2^{k}: r {a}"); 
+        assert!((c - 0.6).abs() <= tol && (s - 0.8).abs() <= tol, "2^{k}: c {c}, s {s}"); 
+    }
+
+    // |a| + |b| overflows, r does not
+    let (mut a, mut b, mut c, mut s) = (-0.6 * f32::MAX, 0.6 * f32::MAX, 0.0, 0.0); 
+    srotg(&mut a, &mut b, &mut c, &mut s); 
+    assert!(a.is_finite() && a > 0.0, "r {a}"); 
+    assert!((s - 0.5f32.sqrt()).abs() <= f32::EPSILON && c == -s); 
+
+    Ok(())
+}
+
+#[test]
+fn degenerate() -> CoralResult { 
+    let (mut a, mut b, mut c, mut s) = (-2.5f32, 0.0, 0.0, 0.0); 
+    srotg(&mut a, &mut b, &mut c, &mut s); 
+    assert_eq!((a, b, c, s), (-2.5, 0.0, 1.0, 0.0)); 
+
+    let (mut a, mut b, mut c, mut s) = (0.0f32, -3.0, 0.0, 0.0); 
+    srotg(&mut a, &mut b, &mut c, &mut s); 
+    assert_eq!((a, b, c, s), (-3.0, 1.0, 0.0, 1.0)); 
+
+    Ok(())
+}
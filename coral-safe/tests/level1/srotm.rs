@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_srotm; 
+use crate::cblas::cblas_srotm; 
 use coral_safe::level1::srotm; 
 use coral_safe::types::VectorMut; 
 
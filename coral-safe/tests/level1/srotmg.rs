@@ -5,8 +5,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_srotmg; 
+use crate::cblas::cblas_srotmg; 
 use coral_safe::level1::srotmg;
 
 #[test]
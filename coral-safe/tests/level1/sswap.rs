@@ -6,8 +6,7 @@ use super::common::{
     ATOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::cblas_sswap; 
+use crate::cblas::cblas_sswap; 
 use coral_safe::level1::sswap; 
 use coral_safe::types::VectorMut; 
 
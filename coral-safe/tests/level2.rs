@@ -1,5 +1,7 @@
 #[path = "common.rs"] 
 mod common; 
+#[path = "common/cblas.rs"]
+mod cblas;
 
 #[path = "level2/sgemv.rs"] 
 mod sgemv; 
@@ -7,3 +9,5 @@ mod sgemv;
 mod sger; 
 #[path = "level2/strsv.rs"] 
 mod strsv; 
+#[path = "level2/ssymv.rs"] 
+mod ssymv; 
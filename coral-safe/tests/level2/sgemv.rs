@@ -1,3 +1,5 @@
+use core::f32::consts::{E, PI};
+
 use super::common::{
     make_strided_mat, 
     make_strided_vec, 
@@ -7,8 +9,7 @@ use super::common::{
     RTOL, 
 }; 
 
-use blas_src as _; 
-use cblas_sys::{cblas_sgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE}; 
+use crate::cblas::{cblas_sgemv, CBLAS_LAYOUT, CBLAS_TRANSPOSE}; 
 use coral_safe::types::{VectorMut, VectorRef, MatrixRef, CoralTranspose}; 
 use coral_safe::level2::sgemv; 
 
@@ -20,8 +21,8 @@ fn unit_stride_n() -> CoralResult {
     let incy = 1; 
     let lda = m; 
 
-    let alpha = 3.14159265;
-    let beta  = 2.71828182; 
+    let alpha = PI;
+    let beta  = E; 
 
     let xbuf = make_strided_vec(n, incx); 
     let abuf = make_strided_mat(m, n, lda); 
@@ -65,8 +66,8 @@ fn strided_n() -> CoralResult {
     let incy = 3; 
     let lda = m + 32; 
 
-    let alpha = 3.14159265;
-    let beta  = 2.71828182; 
+    let alpha = PI;
+    let beta  = E; 
 
     let xbuf = make_strided_vec(n, incx); 
     let abuf = make_strided_mat(m, n, lda); 
@@ -108,8 +109,8 @@ fn unit_stride_t() -> CoralResult {
     let incy = 1; 
     let lda = m; 
 
-    let alpha = 3.14159265;
-    let beta  = 2.71828182; 
+    let alpha = PI;
+    let beta  = E; 
 
     let xbuf = make_strided_vec(m, incx); 
     let abuf = make_strided_mat(m, n, lda); 
@@ -152,8 +153,8 @@ fn strided_t() -> CoralResult {
     let incy = 3; 
     let lda = m + 32; 
 
-    let alpha = 3.14159265;
-    let beta  = 2.71828182; 
+    let alpha = PI;
+    let beta  = E; 
 
     let xbuf = make_strided_vec(m, incx); 
     let abuf = make_strided_mat(m, n, lda); 
@@ -1,3 +1,5 @@
+use core::f32::consts::PI;
+
 use super::common::{ 
     make_strided_mat, 
     make_strided_vec, 
@@ -7,8 +9,7 @@ use super::common::{
     RTOL
 }; 
 
-use blas_src as _; 
-use cblas_sys::{cblas_sger, CBLAS_LAYOUT}; 
+use crate::cblas::{cblas_sger, CBLAS_LAYOUT}; 
 use coral_safe::types::{VectorRef, MatrixMut}; 
 use coral_safe::level2::sger;
 
@@ -23,7 +24,7 @@ fn unit_stride() -> CoralResult {
     let xoff = 2; 
     let yoff = 3; 
 
-    let alpha = 3.1415926535; 
+    let alpha = PI; 
 
     let xbuf = make_strided_vec(m + xoff, incx); 
     let ybuf = make_strided_vec(n + yoff, incy); 
@@ -66,7 +67,7 @@ fn strided() -> CoralResult {
     let incy = 3; 
     let lda = m + 32; 
 
-    let alpha = 3.1415926535; 
+    let alpha = PI; 
 
     let xbuf = make_strided_vec(m, incx); 
     let ybuf = make_strided_vec(n, incy); 
@@ -0,0 +1,139 @@
+use super::common::{ 
+    make_strided_mat, 
+    make_strided_vec, 
+    assert_close, 
+    CoralResult, 
+    ATOL, 
+    RTOL
+}; 
+
+use coral_safe::types::{VectorRef, VectorMut, MatrixRef, MatrixMut, CoralTriangular}; 
+use coral_safe::level2::{ssymv, ssyr, ssyr2};
+
+/// the full symmetric matrix read from the `uplo` triangle 
+fn symmetric_at(uplo: CoralTriangular, a: &[f32], lda: usize, i: usize, j: usize) -> f32 { 
+    let (r, c) = match uplo { 
+        CoralTriangular::Upper => (i.min(j), i.max(j)), 
+        CoralTriangular::Lower => (i.max(j), i.min(j)), 
+    }; 
+    a[r + c * lda]
+}
+
+#[test] 
+fn ssymv_strided() -> CoralResult { 
+    let n = 133; 
+    let lda = n + 3; 
+    let incx = 2; 
+    let incy = 3; 
+    let xoff = 1; 
+    let yoff = 2; 
+
+    let alpha = 1.25; 
+    let beta  = -0.5; 
+
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        // the other triangle is garbage and must not be read 
+        let abuf = make_strided_mat(n, n, lda); 
+        let xbuf = make_strided_vec(xoff + n * incx, 1); 
+        let ybuf = make_strided_vec(yoff + n * incy, 1); 
+
+        let mut expected = ybuf.clone(); 
+        for i in 0..n { 
+            let ax: f64 = (0..n)
+                .map(|p| symmetric_at(uplo, &abuf, lda, i, p) as f64 * xbuf[xoff + p * incx] as f64)
+                .sum(); 
+            expected[yoff + i * incy] = alpha * ax as f32 + beta * ybuf[yoff + i * incy]; 
+        }
+
+        let mut ycoral = ybuf.clone(); 
+        ssymv(
+            uplo, 
+            alpha, 
+            beta, 
+            MatrixRef::new(&abuf, n, n, lda, 0)?, 
+            VectorRef::new(&xbuf, n, incx, xoff)?, 
+            VectorMut::new(&mut ycoral, n, incy, yoff)?, 
+        ); 
+
+        assert_close(&ycoral, &expected, RTOL, ATOL); 
+    }
+    Ok(())
+}
+
+#[test] 
+fn ssyr_updates_one_triangle() -> CoralResult { 
+    let n = 97; 
+    let lda = n + 1; 
+    let alpha = -0.75; 
+
+    for incx in [1, 2] { 
+        for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+            let abuf = make_strided_mat(n, n, lda); 
+            let xbuf = make_strided_vec(n * incx, 1); 
+
+            let mut expected = abuf.clone(); 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let inside = match uplo { 
+                        CoralTriangular::Upper => i <= j, 
+                        CoralTriangular::Lower => i >= j, 
+                    }; 
+                    if inside { 
+                        expected[i + j * lda] += alpha * xbuf[i * incx] * xbuf[j * incx]; 
+                    }
+                }
+            }
+
+            let mut acoral = abuf.clone(); 
+            ssyr(
+                uplo, 
+                alpha, 
+                VectorRef::new(&xbuf, n, incx, 0)?, 
+                MatrixMut::new(&mut acoral, n, n, lda, 0)?, 
+            ); 
+
+            assert_close(&acoral, &expected, RTOL, ATOL); 
+        }
+    }
+    Ok(())
+}
+
+#[test] 
+fn ssyr2_updates_one_triangle() -> CoralResult { 
+    let n = 75; 
+    let lda = n + 2; 
+    let alpha = 0.5; 
+
+    for (incx, incy) in [(1, 1), (2, 3)] { 
+        for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+            let abuf = make_strided_mat(n, n, lda); 
+            let xbuf = make_strided_vec(n * incx, 1); 
+            let ybuf = make_strided_vec(n * incy, 1); 
+
+            let mut expected = abuf.clone(); 
+            for j in 0..n { 
+                for i in 0..n { 
+                    let inside = match uplo { 
+                        CoralTriangular::Upper => i <= j, 
+                        CoralTriangular::Lower => i >= j, 
+                    }; 
+                    if inside { 
+                        expected[i + j * lda] += alpha * (xbuf[i * incx] * ybuf[j * incy] + ybuf[i * incy] * xbuf[j * incx]); 
+                    }
+                }
+            }
+
+            let mut acoral = abuf.clone(); 
+            ssyr2(
+                uplo, 
+                alpha, 
+                VectorRef::new(&xbuf, n, incx, 0)?, 
+                VectorRef::new(&ybuf, n, incy, 0)?, 
+                MatrixMut::new(&mut acoral, n, n, lda, 0)?, 
+            ); 
+
+            assert_close(&acoral, &expected, RTOL, ATOL); 
+        }
+    }
+    Ok(())
+}
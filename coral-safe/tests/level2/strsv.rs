@@ -7,8 +7,7 @@ use super::common::{
     RTOL
 };
 
-use blas_src as _; 
-use cblas_sys::{cblas_strsv, CBLAS_DIAG, CBLAS_TRANSPOSE, CBLAS_UPLO, CBLAS_LAYOUT}; 
+use crate::cblas::{cblas_strsv, CBLAS_DIAG, CBLAS_TRANSPOSE, CBLAS_UPLO, CBLAS_LAYOUT}; 
 use coral_safe::level2::strsv; 
 use coral_safe::types::{CoralDiagonal, CoralTranspose, CoralTriangular, VectorMut, MatrixRef}; 
 
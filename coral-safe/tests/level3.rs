@@ -0,0 +1,9 @@
+#[path = "common.rs"] 
+mod common; 
+
+#[path = "level3/sgemm.rs"] 
+mod sgemm; 
+#[path = "level3/strsm.rs"] 
+mod strsm; 
+#[path = "level3/ssyrk.rs"] 
+mod ssyrk; 
@@ -0,0 +1,173 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, MatrixRef, CoralTranspose}; 
+use coral_safe::level3::sgemm; 
+
+const RTOL: f32 = 1e-4; 
+const ATOL: f32 = 1e-4; 
+
+/// op(M)[i, j] of a column major buffer
+fn op_at(op: CoralTranspose, m: &[f32], ld: usize, off: usize, i: usize, j: usize) -> f32 { 
+    match op { 
+        CoralTranspose::NoTrans => m[off + i + j * ld], 
+        CoralTranspose::Trans   => m[off + j + i * ld], 
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn naive_sgemm ( 
+    op_a: CoralTranspose, 
+    op_b: CoralTranspose, 
+    m: usize, 
+    n: usize, 
+    k: usize, 
+    alpha: f32, 
+    a: &[f32], 
+    lda: usize, 
+    b: &[f32], 
+    ldb: usize, 
+    beta: f32, 
+    c: &mut [f32], 
+    ldc: usize, 
+    coff: usize, 
+) { 
+    for j in 0..n { 
+        for i in 0..m { 
+            let mut acc = 0.0f64; 
+            for p in 0..k { 
+                acc += op_at(op_a, a, lda, 0, i, p) as f64 * op_at(op_b, b, ldb, 0, p, j) as f64; 
+            }
+            let cij = &mut c[coff + i + j * ldc]; 
+            *cij = alpha * acc as f32 + if beta == 0.0 { 0.0 } else { beta * *cij }; 
+        }
+    }
+}
+
+fn run(op_a: CoralTranspose, op_b: CoralTranspose, m: usize, n: usize, k: usize) -> CoralResult { 
+    let (ar, ac) = match op_a { CoralTranspose::NoTrans => (m, k), CoralTranspose::Trans => (k, m) }; 
+    let (br, bc) = match op_b { CoralTranspose::NoTrans => (k, n), CoralTranspose::Trans => (n, k) }; 
+
+    let lda = ar + 3; 
+    let ldb = br + 1; 
+    let ldc = m + 2; 
+
+    let abuf = make_strided_mat(ar, ac, lda); 
+    let bbuf = make_strided_mat(br, bc, ldb); 
+
+    let mut cbuf  = make_strided_mat(m, n, ldc); 
+    let mut cnaive = cbuf.clone(); 
+
+    let alpha = 1.25; 
+    let beta  = -0.5; 
+
+    sgemm ( 
+        op_a, 
+        op_b, 
+        alpha, 
+        beta, 
+        MatrixRef::new(&abuf, ar, ac, lda, 0)?, 
+        MatrixRef::new(&bbuf, br, bc, ldb, 0)?, 
+        MatrixMut::new(&mut cbuf, m, n, ldc, 0)?, 
+    ); 
+
+    naive_sgemm(op_a, op_b, m, n, k, alpha, &abuf, lda, &bbuf, ldb, beta, &mut cnaive, ldc, 0); 
+
+    assert_close(&cbuf, &cnaive, RTOL, ATOL); 
+    Ok(())
+}
+
+#[test]
+fn all_ops_small() -> CoralResult { 
+    for op_a in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+        for op_b in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+            run(op_a, op_b, 1, 1, 1)?; 
+            run(op_a, op_b, 7, 5, 3)?; 
+            run(op_a, op_b, 33, 17, 65)?; 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn all_ops_across_blocks() -> CoralResult { 
+    // m > MC and k > KC 
+    for op_a in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+        for op_b in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+            run(op_a, op_b, 150, 21, 300)?; 
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn beta_zero_ignores_nan() -> CoralResult { 
+    let (m, n, k) = (9, 4, 6); 
+
+    let abuf = make_strided_mat(m, k, m); 
+    let bbuf = make_strided_mat(k, n, k); 
+    let mut cbuf = vec![f32::NAN; m * n]; 
+    let mut cnaive = vec![0.0; m * n]; 
+
+    sgemm ( 
+        CoralTranspose::NoTrans, 
+        CoralTranspose::NoTrans, 
+        1.0, 
+        0.0, 
+        MatrixRef::new(&abuf, m, k, m, 0)?, 
+        MatrixRef::new(&bbuf, k, n, k, 0)?, 
+        MatrixMut::new(&mut cbuf, m, n, m, 0)?, 
+    ); 
+
+    naive_sgemm(CoralTranspose::NoTrans, CoralTranspose::NoTrans, m, n, k, 1.0, &abuf, m, &bbuf, k, 0.0, &mut cnaive, m, 0); 
+
+    assert_close(&cbuf, &cnaive, RTOL, ATOL); 
+    Ok(())
+}
+
+#[test]
+fn offset_views() -> CoralResult { 
+    // the operands are 10 x 10 blocks inside 16 x 16 buffers 
+    let (m, n, k) = (10, 10, 10); 
+    let ld  = 16; 
+    let off = 3 + 2 * ld; 
+
+    let abuf = make_strided_mat(ld, ld, ld); 
+    let bbuf = make_strided_mat(ld, ld, ld); 
+    let mut cbuf   = make_strided_mat(ld, ld, ld); 
+    let mut cnaive = cbuf.clone(); 
+
+    sgemm ( 
+        CoralTranspose::Trans, 
+        CoralTranspose::NoTrans, 
+        2.0, 
+        1.0, 
+        MatrixRef::new(&abuf, k, m, ld, off)?, 
+        MatrixRef::new(&bbuf, k, n, ld, off)?, 
+        MatrixMut::new(&mut cbuf, m, n, ld, off)?, 
+    ); 
+
+    naive_sgemm ( 
+        CoralTranspose::Trans, 
+        CoralTranspose::NoTrans, 
+        m, 
+        n, 
+        k, 
+        2.0, 
+        &abuf[off..], 
+        ld, 
+        &bbuf[off..], 
+        ld, 
+        1.0, 
+        &mut cnaive, 
+        ld, 
+        off, 
+    ); 
+
+    // entries outside the block are untouched 
+    assert_close(&cbuf, &cnaive, RTOL, ATOL); 
+    Ok(())
+}
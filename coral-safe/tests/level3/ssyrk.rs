@@ -0,0 +1,71 @@
+use super::common::{
+    make_strided_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{MatrixMut, MatrixRef, CoralTranspose, CoralTriangular}; 
+use coral_safe::level3::ssyrk; 
+
+const RTOL: f32 = 1e-4; 
+const ATOL: f32 = 1e-4; 
+
+fn run(uplo: CoralTriangular, trans: CoralTranspose, n: usize, k: usize) -> CoralResult { 
+    let (ar, ac) = match trans { 
+        CoralTranspose::NoTrans => (n, k), 
+        CoralTranspose::Trans   => (k, n), 
+    }; 
+    let lda = ar + 2; 
+    let ldc = n + 3; 
+
+    let abuf = make_strided_mat(ar, ac, lda); 
+    let cbuf = make_strided_mat(n, n, ldc); 
+    let mut got = cbuf.clone(); 
+
+    let (alpha, beta) = (-0.5, 1.5); 
+
+    ssyrk ( 
+        uplo, 
+        trans, 
+        alpha, 
+        beta, 
+        MatrixRef::new(&abuf, ar, ac, lda, 0)?, 
+        MatrixMut::new(&mut got, n, n, ldc, 0)?, 
+    ); 
+
+    let op = |i: usize, p: usize| match trans { 
+        CoralTranspose::NoTrans => abuf[i + p * lda], 
+        CoralTranspose::Trans   => abuf[p + i * lda], 
+    }; 
+
+    let mut exp = cbuf.clone(); 
+    for j in 0..n { 
+        for i in 0..n { 
+            let in_tri = match uplo { 
+                CoralTriangular::Upper => i <= j, 
+                CoralTriangular::Lower => i >= j, 
+            }; 
+            if in_tri { 
+                let dot: f32 = (0..k).map(|p| op(i, p) * op(j, p)).sum(); 
+                exp[i + j * ldc] = alpha * dot + beta * cbuf[i + j * ldc]; 
+            }
+        }
+    }
+
+    // the other triangle is untouched 
+    assert_close(&got, &exp, RTOL, ATOL); 
+    Ok(())
+}
+
+#[test]
+fn both_triangles_and_transposes() -> CoralResult { 
+    for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+        for trans in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+            run(uplo, trans, 1, 1)?; 
+            run(uplo, trans, 9, 4)?; 
+            run(uplo, trans, 150, 37)?; 
+            run(uplo, trans, 70, 0)?; 
+        }
+    }
+    Ok(())
+}
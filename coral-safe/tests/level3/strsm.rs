@@ -0,0 +1,186 @@
+use super::common::{
+    make_strided_mat, 
+    make_triangular_mat, 
+    assert_close, 
+    CoralResult, 
+}; 
+
+use coral_safe::types::{
+    MatrixMut, 
+    MatrixRef, 
+    CoralDiagonal, 
+    CoralSide, 
+    CoralTranspose, 
+    CoralTriangular, 
+}; 
+use coral_safe::level3::strsm; 
+
+const RTOL: f32 = 1e-4; 
+const ATOL: f32 = 1e-4; 
+
+/// op(A)[i, j] of a triangular `A`, honoring `diag` 
+fn tri_at ( 
+    uplo: CoralTriangular, 
+    trans: CoralTranspose, 
+    diag: CoralDiagonal, 
+    a: &[f32], 
+    lda: usize, 
+    i: usize, 
+    j: usize, 
+) -> f32 { 
+    let (r, c) = match trans { 
+        CoralTranspose::NoTrans => (i, j), 
+        CoralTranspose::Trans   => (j, i), 
+    }; 
+
+    let stored = match uplo { 
+        CoralTriangular::Upper => r <= c, 
+        CoralTriangular::Lower => r >= c, 
+    }; 
+
+    if r == c && diag.is_unit() { 
+        1.0 
+    } else if stored { 
+        a[r + c * lda] 
+    } else { 
+        0.0 
+    }
+}
+
+fn run ( 
+    side: CoralSide, 
+    uplo: CoralTriangular, 
+    trans: CoralTranspose, 
+    diag: CoralDiagonal, 
+    m: usize, 
+    n: usize, 
+) -> CoralResult { 
+    let k   = match side { CoralSide::Left => m, CoralSide::Right => n }; 
+    let lda = k + 2; 
+    let ldb = m + 1; 
+    let alpha = 0.75; 
+
+    let abuf = make_triangular_mat(uplo, diag, k, lda); 
+    let bbuf = make_strided_mat(m, n, ldb); 
+    let mut xbuf = bbuf.clone(); 
+
+    strsm ( 
+        side, 
+        uplo, 
+        trans, 
+        diag, 
+        alpha, 
+        MatrixRef::new(&abuf, k, k, lda, 0)?, 
+        MatrixMut::new(&mut xbuf, m, n, ldb, 0)?, 
+    ); 
+
+    // residual op(A) X or X op(A) against alpha B 
+    let mut got = Vec::with_capacity(m * n); 
+    let mut exp = Vec::with_capacity(m * n); 
+    for j in 0..n { 
+        for i in 0..m { 
+            let v: f32 = match side { 
+                CoralSide::Left => (0..k)
+                    .map(|p| tri_at(uplo, trans, diag, &abuf, lda, i, p) * xbuf[p + j * ldb])
+                    .sum(), 
+                CoralSide::Right => (0..k)
+                    .map(|p| xbuf[i + p * ldb] * tri_at(uplo, trans, diag, &abuf, lda, p, j))
+                    .sum(), 
+            }; 
+            got.push(v); 
+            exp.push(alpha * bbuf[i + j * ldb]); 
+        }
+    }
+
+    assert_close(&got, &exp, RTOL, ATOL); 
+    Ok(())
+}
+
+#[test]
+fn all_combinations() -> CoralResult { 
+    for side in [CoralSide::Left, CoralSide::Right] { 
+        for uplo in [CoralTriangular::Upper, CoralTriangular::Lower] { 
+            for trans in [CoralTranspose::NoTrans, CoralTranspose::Trans] { 
+                for diag in [CoralDiagonal::Unit, CoralDiagonal::NonUnit] { 
+                    run(side, uplo, trans, diag, 1, 1)?; 
+                    run(side, uplo, trans, diag, 13, 7)?; 
+                    // more than one 64 wide block 
+                    run(side, uplo, trans, diag, 150, 131)?; 
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn alpha_zero_clears() -> CoralResult { 
+    let (m, n) = (5, 4); 
+    let abuf = make_triangular_mat(CoralTriangular::Upper, CoralDiagonal::NonUnit, m, m); 
+    let mut bbuf = make_strided_mat(m, n, m); 
+
+    strsm ( 
+        CoralSide::Left, 
+        CoralTriangular::Upper, 
+        CoralTranspose::NoTrans, 
+        CoralDiagonal::NonUnit, 
+        0.0, 
+        MatrixRef::new(&abuf, m, m, m, 0)?, 
+        MatrixMut::new(&mut bbuf, m, n, m, 0)?, 
+    ); 
+
+    assert!(bbuf.iter().all(|&v| v == 0.0)); 
+    Ok(())
+}
+
+#[test]
+fn offset_views() -> CoralResult { 
+    let (m, n) = (9, 6); 
+    let ld  = 12; 
+    let off = 2 + ld; 
+
+    let mut abuf = vec![0.0; ld * ld]; 
+    let tri = make_triangular_mat(CoralTriangular::Lower, CoralDiagonal::NonUnit, m, m); 
+    for j in 0..m { 
+        for i in 0..m { 
+            abuf[off + i + j * ld] = tri[i + j * m]; 
+        }
+    }
+
+    let bbuf = make_strided_mat(ld, ld, ld); 
+    let mut xbuf = bbuf.clone(); 
+    let mut xplain: Vec<f32> = (0..n).flat_map(|j| bbuf[off + j * ld .. off + m + j * ld].to_vec()).collect(); 
+
+    strsm ( 
+        CoralSide::Left, 
+        CoralTriangular::Lower, 
+        CoralTranspose::Trans, 
+        CoralDiagonal::NonUnit, 
+        1.0, 
+        MatrixRef::new(&abuf, m, m, ld, off)?, 
+        MatrixMut::new(&mut xbuf, m, n, ld, off)?, 
+    ); 
+    strsm ( 
+        CoralSide::Left, 
+        CoralTriangular::Lower, 
+        CoralTranspose::Trans, 
+        CoralDiagonal::NonUnit, 
+        1.0, 
+        MatrixRef::new(&tri, m, m, m, 0)?, 
+        MatrixMut::new(&mut xplain, m, n, m, 0)?, 
+    ); 
+
+    let got: Vec<f32> = (0..n).flat_map(|j| xbuf[off + j * ld .. off + m + j * ld].to_vec()).collect(); 
+    assert_close(&got, &xplain, RTOL, ATOL); 
+
+    // rows outside the block are untouched
+    for j in 0..ld { 
+        for i in 0..ld { 
+            let inside = (2..2 + m).contains(&i) && (1..1 + n).contains(&j); 
+            if !inside { 
+                assert_eq!(xbuf[i + j * ld], bbuf[i + j * ld]); 
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// bitwise checks for the `reproducible` feature
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use coral_safe::level1::{sdot, sasum, snrm2};
+use coral_safe::level2::sgemv;
+use coral_safe::errors::BufferError;
+use coral_safe::types::{CoralTranspose, MatrixRef, VectorRef, VectorMut};
+
+type CoralResult = Result<(), BufferError>;
+
+const SIZES: &[usize] = &[1, 3, 7, 8, 9, 31, 32, 33, 100, 1023, 4099];
+
+// wide spread of magnitudes, so a change of order changes the bits
+fn values(rng: &mut StdRng, n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|_| (rng.r#gen::<f32>() - 0.5) * 2f32.powi(rng.gen_range(-12..12)))
+        .collect()
+}
+
+// the documented order: term i into partial i % 8, then a fixed tree
+fn spec_sum(terms: impl Iterator<Item = (f32, f32)>) -> f32 {
+    let mut s = [0.0f32; 8];
+    for (i, (a, b)) in terms.enumerate() {
+        s[i % 8] = a.mul_add(b, s[i % 8]);
+    }
+    ((s[0] + s[1]) + (s[2] + s[3])) + ((s[4] + s[5]) + (s[6] + s[7]))
+}
+
+// `v` at stride `inc` after `offset` leading entries
+fn place(v: &[f32], inc: usize, offset: usize) -> Vec<f32> {
+    let mut buf = vec![0.0; offset + (v.len() - 1) * inc + 1];
+    for (i, &vi) in v.iter().enumerate() {
+        buf[offset + i * inc] = vi;
+    }
+    buf
+}
+
+#[test]
+fn reductions_follow_fixed_order() -> CoralResult {
+    let mut rng = StdRng::seed_from_u64(37);
+
+    for &n in SIZES {
+        let x = values(&mut rng, n);
+        let y = values(&mut rng, n);
+
+        let dot  = spec_sum(x.iter().copied().zip(y.iter().copied())).to_bits();
+        let asum = spec_sum(x.iter().map(|v| (v.abs(), 1.0))).to_bits();
+
+        // power of two just above max |x_i|
+        let amax  = x.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        let scale = 2f32.powi(((amax.to_bits() >> 23) & 0xff) as i32 - 126);
+        let nrm2  = (scale * spec_sum(x.iter().map(|v| (v / scale, v / scale))).sqrt()).to_bits();
+
+        for &(incx, incy) in &[(1usize, 1usize), (2, 3), (5, 1)] {
+            for offset in 0..4 {
+                let xb = place(&x, incx, offset);
+                let yb = place(&y, incy, 3 - offset);
+                let xv = VectorRef::new(&xb, n, incx, offset)?;
+                let yv = VectorRef::new(&yb, n, incy, 3 - offset)?;
+
+                let at = format!("n {n}, inc ({incx}, {incy}), offset {offset}");
+                assert_eq!(sdot(xv, yv).to_bits(), dot, "{at}");
+                assert_eq!(sasum(xv).to_bits(), asum, "{at}");
+                assert_eq!(snrm2(xv).to_bits(), nrm2, "{at}");
+            }
+        }
+    }
+
+    // scaling keeps the extremes finite and exact
+    for &k in &[100, -100] {
+        let s = 2f32.powi(k);
+        let buf = [3.0 * s, 4.0 * s];
+        assert_eq!(snrm2(VectorRef::new(&buf, 2, 1, 0)?), 5.0 * s);
+    }
+    Ok(())
+}
+
+#[test]
+fn gemv_trans_is_column_dots() -> CoralResult {
+    let mut rng = StdRng::seed_from_u64(38);
+    let (alpha, beta) = (1.25f32, -0.75f32);
+
+    // past the MC = NC = 128 blocking, with ragged tails
+    for &(m, n) in &[(1usize, 1usize), (9, 5), (130, 129), (1023, 3), (4099, 2)] {
+        let a0 = values(&mut rng, m * n);
+        let x  = values(&mut rng, m);
+        let y0 = values(&mut rng, n);
+
+        let want: Vec<u32> = (0..n)
+            .map(|j| {
+                let col = VectorRef::new(&a0[j * m..], m, 1, 0).unwrap();
+                let d = sdot(col, VectorRef::new(&x, m, 1, 0).unwrap());
+                alpha.mul_add(d, beta * y0[j]).to_bits()
+            })
+            .collect();
+
+        for &(pad, incx, incy) in &[(0usize, 1usize, 1usize), (3, 2, 3), (1, 1, 4)] {
+            let lda = m + pad;
+            let mut a = vec![0.0; pad + lda * n];
+            for j in 0..n {
+                a[pad + j * lda .. pad + j * lda + m].copy_from_slice(&a0[j * m .. (j + 1) * m]);
+            }
+
+            let xb = place(&x, incx, 0);
+            let mut yb = place(&y0, incy, 0);
+
+            sgemv(
+                CoralTranspose::Trans,
+                alpha,
+                beta,
+                MatrixRef::new(&a, m, n, lda, pad)?,
+                VectorRef::new(&xb, m, incx, 0)?,
+                VectorMut::new(&mut yb, n, incy, 0)?,
+            );
+
+            let got: Vec<u32> = (0..n).map(|j| yb[j * incy].to_bits()).collect();
+            assert_eq!(got, want, "{m}x{n}, lda {lda}, inc ({incx}, {incy})");
+        }
+    }
+    Ok(())
+}